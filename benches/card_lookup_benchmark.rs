@@ -2,7 +2,9 @@ use std::collections::HashMap;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use deckgym::card_ids::CardId;
-use deckgym::types::{Attack, Card, EnergyType, PokemonCard};
+use deckgym::database::get_card_by_enum;
+use deckgym::sets::SetId;
+use deckgym::types::{Attack, CardData, EnergyType, PokemonCard};
 use lazy_static::lazy_static;
 
 fn num_match_approach(id: u16) -> Option<CardId> {
@@ -38,7 +40,7 @@ fn lazy_static_map_approach(id: &str) -> Option<CardId> {
 }
 
 lazy_static! {
-    static ref BULBASAUR: Card = Card::Pokemon(PokemonCard {
+    static ref BULBASAUR: CardData = CardData::Pokemon(PokemonCard {
         id: "A1 001".to_string(),
         name: "Bulbasaur".to_string(),
         stage: 0,
@@ -55,20 +57,20 @@ lazy_static! {
         weakness: Some(EnergyType::Fire),
         retreat_cost: vec![EnergyType::Colorless],
         rarity: "◇".to_string(),
-        booster_pack: "Genetic Apex (A1) Mewtwo".to_string(),
+        set: SetId::A1,
     });
 }
 
-pub fn get_card_lazy_map(id: CardId) -> &'static Card {
+pub fn get_card_lazy_map(id: CardId) -> &'static CardData {
     match id {
         CardId::A1001Bulbasaur => &BULBASAUR,
         _ => panic!("Card not found"),
     }
 }
 
-pub fn get_card_create(id: CardId) -> Card {
+pub fn get_card_create(id: CardId) -> CardData {
     match id {
-        CardId::A1001Bulbasaur => Card::Pokemon(PokemonCard {
+        CardId::A1001Bulbasaur => CardData::Pokemon(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -85,7 +87,7 @@ pub fn get_card_create(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless],
             rarity: "◇".to_string(),
-            booster_pack: "Genetic Apex (A1) Mewtwo".to_string(),
+            set: SetId::A1,
         }),
         _ => panic!("Card not found"),
     }
@@ -108,6 +110,9 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("get_card_lazy_map", |b| {
         b.iter(|| get_card_lazy_map(black_box(CardId::A1001Bulbasaur)))
     });
+    c.bench_function("get_card_by_enum", |b| {
+        b.iter(|| get_card_by_enum(black_box(CardId::A1001Bulbasaur)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
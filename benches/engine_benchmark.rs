@@ -0,0 +1,105 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use deckgym::{
+    generate_possible_actions,
+    players::{CheatingPlayer, MctsPlayer, Player, RandomPlayer},
+    test_helpers::load_test_decks,
+    Game, State,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+const FIXTURE_SEED: u64 = 7;
+// (name, turn) of the fixtures, taken from the same seeded random game
+const FIXTURE_TURNS: [(&str, u8); 3] = [("early", 3), ("mid", 6), ("late", 9)];
+
+fn random_players() -> Vec<Box<dyn Player>> {
+    let (deck_a, deck_b) = load_test_decks();
+    vec![
+        Box::new(RandomPlayer { deck: deck_a }),
+        Box::new(RandomPlayer { deck: deck_b }),
+    ]
+}
+
+/// States at the start of the fixture turns of a random game, so every run of the
+/// benchmarks measures the same positions.
+fn fixtures() -> Vec<(&'static str, State)> {
+    let mut game = Game::new(random_players(), FIXTURE_SEED);
+    let mut fixtures = vec![];
+    for (name, turn) in FIXTURE_TURNS {
+        while game.get_state().turn_count < turn {
+            assert!(
+                !game.get_state().is_game_over(),
+                "Fixture game ended before turn {turn}"
+            );
+            game.play_tick();
+        }
+        fixtures.push((name, game.get_state_clone()));
+    }
+    fixtures
+}
+
+fn bench_generate_possible_actions(c: &mut Criterion) {
+    for (name, state) in fixtures() {
+        c.bench_function(&format!("generate_possible_actions/{name}"), |b| {
+            b.iter(|| generate_possible_actions(black_box(&state)))
+        });
+    }
+}
+
+fn bench_apply_action(c: &mut Criterion) {
+    for (name, state) in fixtures() {
+        let (_, actions) = generate_possible_actions(&state);
+        c.bench_function(&format!("apply_action/{name}"), |b| {
+            b.iter_batched(
+                || Game::from_state(state.clone(), random_players(), FIXTURE_SEED),
+                |mut game| {
+                    for action in &actions {
+                        game.set_state(state.clone());
+                        game.apply_action(black_box(action));
+                    }
+                    game
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
+
+fn bench_state_clone(c: &mut Criterion) {
+    for (name, state) in fixtures() {
+        c.bench_function(&format!("state_clone/{name}"), |b| {
+            b.iter(|| black_box(&state).clone())
+        });
+    }
+}
+
+fn bench_mcts_decision(c: &mut Criterion) {
+    let (deck_a, deck_b) = load_test_decks();
+    let mut group = c.benchmark_group("mcts_decision");
+    group.sample_size(10);
+    for (name, state) in fixtures() {
+        let (actor, actions) = generate_possible_actions(&state);
+        let deck = if actor == 0 { &deck_a } else { &deck_b };
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    (
+                        MctsPlayer::new(deck.clone(), 100),
+                        StdRng::seed_from_u64(FIXTURE_SEED),
+                    )
+                },
+                |(mut player, mut rng)| player.decide_with_full_state(&mut rng, &state, actions.clone()),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_generate_possible_actions,
+    bench_apply_action,
+    bench_state_clone,
+    bench_mcts_decision
+);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+fn main() {
+    #[cfg(feature = "grpc")]
+    compile_protos();
+    #[cfg(feature = "ffi")]
+    generate_c_header();
+}
+
+// Generates the protobuf types and gRPC service of proto/deckgym.proto, with a vendored
+// protoc so that building doesn't need one installed.
+#[cfg(feature = "grpc")]
+fn compile_protos() {
+    let protoc = protoc_bin_vendored::protoc_bin_path().expect("protoc should be vendored");
+    std::env::set_var("PROTOC", protoc);
+    tonic_prost_build::compile_protos("proto/deckgym.proto").expect("Failed to compile protos");
+}
+
+// Keeps include/deckgym.h in sync with the C API of src/ffi.rs (configured in cbindgen.toml).
+#[cfg(feature = "ffi")]
+fn generate_c_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file("cbindgen.toml").expect("Invalid cbindgen.toml");
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("Failed to generate the C header")
+        .write_to_file("include/deckgym.h");
+}
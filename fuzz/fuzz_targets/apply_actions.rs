@@ -0,0 +1,10 @@
+#![no_main]
+
+use deckgym::test_helpers::play_checked_game;
+use libfuzzer_sys::fuzz_target;
+
+// Plays random but legal action sequences, see `play_checked_game` for the invariants.
+// Decks are read from example_decks/, so run it from the root of the repo.
+fuzz_target!(|data: &[u8]| {
+    play_checked_game(data);
+});
@@ -0,0 +1,85 @@
+/* C API of deckgym, see src/ffi.rs. Generated by cbindgen, do not edit. */
+
+#ifndef DECKGYM_H
+#define DECKGYM_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A game driven through the C API, to embed the simulator in other engines (see
+ * `include/deckgym.h`). Build it as a C library with
+ * `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+ *
+ * Games are opaque pointers, decided one action at a time: list the legal actions, then
+ * apply one of them by its index. Strings handed out are JSON and must be given back to
+ * `deckgym_string_free`. Should the engine panic, functions return NULL (or -1) instead,
+ * and the game should not be used anymore.
+ */
+typedef struct DeckgymGame DeckgymGame;
+
+/**
+ * Creates a game between two decks (in the deck file format), or returns NULL if either
+ * can't be parsed or isn't legal (see `Deck::validate`). Free it with `deckgym_game_free`.
+ *
+ * # Safety
+ *
+ * `deck_a` and `deck_b` must be valid NUL-terminated strings.
+ */
+struct DeckgymGame *deckgym_game_new(const char *deck_a, const char *deck_b, uint64_t seed);
+
+/**
+ * The actions that can be applied next, as a JSON array of
+ * `{"index": 0, "actor": 1, "action": "EndTurn"}` objects (empty once the game is over).
+ * Returns NULL on a NULL game.
+ *
+ * # Safety
+ *
+ * `game` must be NULL or come from `deckgym_game_new` and not have been freed.
+ */
+char *deckgym_game_legal_actions_json(const struct DeckgymGame *game);
+
+/**
+ * Applies the legal action at `index` (see `deckgym_game_legal_actions_json`). Returns 0
+ * on success, or -1 if there is no such action (e.g. the game is over) or on a NULL game.
+ * The first opening board is only played once the other player chose theirs, so that
+ * neither sees the other's before choosing.
+ *
+ * # Safety
+ *
+ * `game` must be NULL or come from `deckgym_game_new` and not have been freed.
+ */
+int32_t deckgym_game_apply_action(struct DeckgymGame *game, size_t index);
+
+/**
+ * The game as `player` (0 or 1) sees it, as the JSON of a `MaskedState`. Its `winner`
+ * tells whether the game is over. Returns NULL for any other player, or on a NULL game.
+ *
+ * # Safety
+ *
+ * `game` must be NULL or come from `deckgym_game_new` and not have been freed.
+ */
+char *deckgym_game_state_json(const struct DeckgymGame *game, size_t player);
+
+/**
+ * Frees a game. Does nothing on NULL.
+ *
+ * # Safety
+ *
+ * `game` must come from `deckgym_game_new` and not have been freed already.
+ */
+void deckgym_game_free(struct DeckgymGame *game);
+
+/**
+ * Frees a string returned by this API. Does nothing on NULL.
+ *
+ * # Safety
+ *
+ * `string` must come from this API and not have been freed already.
+ */
+void deckgym_string_free(char *string);
+
+#endif  /* DECKGYM_H */
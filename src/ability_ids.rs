@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::types::{EnergyType, TrainerType};
+
 // TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AbilityId {
@@ -7,6 +9,134 @@ pub enum AbilityId {
     A1007Butterfree,
     A1132Gardevoir,
     A2a071Arceus,
+    // Triggered abilities (see hooks::triggers)
+    A1061Poliwrath,
+    A1a056Druddigon,
+    A2b028Pawmot,
+    A3a052Ferrothorn,
+    A3054Pyukumuku,
+    A2110DarkraiEx,
+    PA037CresseliaEx,
+    A3b034SylveonEx,
+    // Passive abilities (see PASSIVE_ABILITIES)
+    A1067Cloyster,
+    A1182Melmetal,
+    A2087Regirock,
+    A3109AlolanPersian,
+    A2032Piloswine,
+    A2033Mamoswine,
+    PA047Staraptor,
+    A2078Giratina,
+    A2a069Shaymin,
+    A1123GengarEx,
+}
+
+/// How often a Pokémon's Ability can be used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsageFrequency {
+    /// Once during each of its player's turns.
+    OncePerTurn,
+    /// Once while it is in play.
+    OncePerGame,
+}
+
+/// Where a Pokémon has to be to use its Ability.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsablePositions {
+    Anywhere,
+    ActiveOnly,
+    BenchOnly,
+}
+
+impl UsablePositions {
+    pub fn contains(&self, in_play_idx: usize) -> bool {
+        match self {
+            UsablePositions::Anywhere => true,
+            UsablePositions::ActiveOnly => in_play_idx == 0,
+            UsablePositions::BenchOnly => in_play_idx != 0,
+        }
+    }
+}
+
+/// When an Ability can be used, for the ones players use (as opposed to the ones that go
+/// off on their own or are always on).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbilityUsage {
+    pub frequency: UsageFrequency,
+    pub positions: UsablePositions,
+}
+
+const USABLE_ABILITIES: &[(AbilityId, AbilityUsage)] = &[
+    (
+        AbilityId::A1007Butterfree,
+        once_per_turn(UsablePositions::Anywhere),
+    ),
+    (
+        AbilityId::A1177Weezing,
+        once_per_turn(UsablePositions::ActiveOnly),
+    ),
+    (
+        AbilityId::A1132Gardevoir,
+        once_per_turn(UsablePositions::Anywhere),
+    ),
+];
+
+const fn once_per_turn(positions: UsablePositions) -> AbilityUsage {
+    AbilityUsage {
+        frequency: UsageFrequency::OncePerTurn,
+        positions,
+    }
+}
+
+/// Rules a passive Ability changes just by its Pokémon being in play. The hooks answering
+/// each rule query (damage taken, retreat cost, playable cards) look them up.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PassiveEffect {
+    /// This Pokémon takes `amount` less damage from attacks (only from Pokémon of
+    /// `from_types`, unless empty).
+    DamageReduction {
+        amount: u32,
+        from_types: &'static [EnergyType],
+    },
+    /// If this Pokémon has any Energy attached, it has no Retreat Cost.
+    NoRetreatCostWithEnergy,
+    /// While this Pokémon is on the Bench, its player's Active Basic Pokémon's Retreat Cost
+    /// is this much less.
+    BenchedBasicRetreatReduction(usize),
+    /// While this Pokémon is in the Active Spot, the opponent can't play Trainer cards of
+    /// this type from their hand.
+    ActiveBlocksOpponentTrainers(TrainerType),
+}
+
+const PASSIVE_ABILITIES: &[(AbilityId, PassiveEffect)] = &[
+    (AbilityId::A1067Cloyster, reduction(10, &[])),
+    (AbilityId::A1182Melmetal, reduction(20, &[])),
+    (AbilityId::A2087Regirock, reduction(20, &[])),
+    (AbilityId::A3109AlolanPersian, reduction(20, &[])),
+    (AbilityId::A2032Piloswine, reduction(20, FIRE_OR_WATER)),
+    (AbilityId::A2033Mamoswine, reduction(30, FIRE_OR_WATER)),
+    (
+        AbilityId::PA047Staraptor,
+        reduction(30, &[EnergyType::Fighting]),
+    ),
+    (
+        AbilityId::A2078Giratina,
+        PassiveEffect::NoRetreatCostWithEnergy,
+    ),
+    (
+        AbilityId::A2a069Shaymin,
+        PassiveEffect::BenchedBasicRetreatReduction(1),
+    ),
+    (
+        AbilityId::A1123GengarEx,
+        PassiveEffect::ActiveBlocksOpponentTrainers(TrainerType::Supporter),
+    ),
+];
+
+const FIRE_OR_WATER: &[EnergyType] = &[EnergyType::Fire, EnergyType::Water];
+
+const fn reduction(amount: u32, from_types: &'static [EnergyType]) -> PassiveEffect {
+    PassiveEffect::DamageReduction { amount, from_types }
 }
 
 // Create a static HashMap for fast (pokemon, index) lookup
@@ -20,6 +150,37 @@ lazy_static::lazy_static! {
         m.insert("A2a 086", AbilityId::A2a071Arceus);
         m.insert("A2a 095", AbilityId::A2a071Arceus);
         m.insert("A2a 096", AbilityId::A2a071Arceus);
+        m.insert("A1 061", AbilityId::A1061Poliwrath);
+        m.insert("A1a 056", AbilityId::A1a056Druddigon);
+        m.insert("A2b 028", AbilityId::A2b028Pawmot);
+        m.insert("P-A 054", AbilityId::A2b028Pawmot);
+        m.insert("A3a 052", AbilityId::A3a052Ferrothorn);
+        m.insert("A3 054", AbilityId::A3054Pyukumuku);
+        m.insert("A3 163", AbilityId::A3054Pyukumuku);
+        m.insert("A2 110", AbilityId::A2110DarkraiEx);
+        m.insert("A2 187", AbilityId::A2110DarkraiEx);
+        m.insert("A2 202", AbilityId::A2110DarkraiEx);
+        m.insert("P-A 042", AbilityId::A2110DarkraiEx);
+        m.insert("P-A 037", AbilityId::PA037CresseliaEx);
+        m.insert("A3b 034", AbilityId::A3b034SylveonEx);
+        m.insert("A3b 081", AbilityId::A3b034SylveonEx);
+        m.insert("A3b 089", AbilityId::A3b034SylveonEx);
+        m.insert("A1 067", AbilityId::A1067Cloyster);
+        m.insert("A1 182", AbilityId::A1182Melmetal);
+        m.insert("A2 087", AbilityId::A2087Regirock);
+        m.insert("A3 109", AbilityId::A3109AlolanPersian);
+        m.insert("A2 032", AbilityId::A2032Piloswine);
+        m.insert("A2 033", AbilityId::A2033Mamoswine);
+        m.insert("A2 160", AbilityId::A2033Mamoswine);
+        m.insert("P-A 047", AbilityId::PA047Staraptor);
+        m.insert("A2 078", AbilityId::A2078Giratina);
+        m.insert("A2 167", AbilityId::A2078Giratina);
+        m.insert("A2a 069", AbilityId::A2a069Shaymin);
+        m.insert("A2a 081", AbilityId::A2a069Shaymin);
+        m.insert("A1 123", AbilityId::A1123GengarEx);
+        m.insert("A1 261", AbilityId::A1123GengarEx);
+        m.insert("A1 277", AbilityId::A1123GengarEx);
+        m.insert("A3 234", AbilityId::A1123GengarEx);
         m
     };
 }
@@ -28,4 +189,20 @@ impl AbilityId {
     pub fn from_pokemon_id(pokemon_id: &str) -> Option<Self> {
         ABILITY_ID_MAP.get(&pokemon_id).copied()
     }
+
+    /// When this Ability can be used, or None if players don't use it (e.g. Arceus's).
+    pub fn usage(&self) -> Option<&'static AbilityUsage> {
+        USABLE_ABILITIES
+            .iter()
+            .find(|(id, _)| id == self)
+            .map(|(_, usage)| usage)
+    }
+
+    /// The rule this Ability changes while in play, if it's a passive one.
+    pub fn passive_effect(&self) -> Option<&'static PassiveEffect> {
+        PASSIVE_ABILITIES
+            .iter()
+            .find(|(id, _)| id == self)
+            .map(|(_, effect)| effect)
+    }
 }
@@ -0,0 +1,285 @@
+use crate::{
+    generate_possible_actions,
+    observation::{card_index, NUM_CARDS, NUM_ENERGY_TYPES, NUM_SLOTS},
+    state::OPENING_HAND_SIZE,
+    tool_ids::ToolId,
+    types::{Card, TrainerCard},
+    State,
+};
+
+use super::{Action, SimpleAction};
+
+/// Most attacks a Pokémon has in the database.
+const MAX_ATTACKS: usize = 2;
+const NUM_TOOLS: usize = 3;
+
+// Where each kind of action starts in the action space.
+const DRAW_CARD: usize = 0;
+const END_TURN: usize = DRAW_CARD + 1;
+const PLAY: usize = END_TURN + 1;
+const PLACE: usize = PLAY + NUM_CARDS;
+const EVOLVE: usize = PLACE + NUM_CARDS * NUM_SLOTS;
+const USE_ABILITY: usize = EVOLVE + NUM_CARDS * NUM_SLOTS;
+const ATTACK: usize = USE_ABILITY + NUM_SLOTS;
+const RETREAT: usize = ATTACK + MAX_ATTACKS;
+const ATTACH: usize = RETREAT + NUM_SLOTS;
+const ATTACH_TOOL: usize = ATTACH + 2 * NUM_ENERGY_TYPES * NUM_SLOTS;
+const HEAL: usize = ATTACH_TOOL + NUM_TOOLS * NUM_SLOTS;
+const APPLY_DAMAGE: usize = HEAL + NUM_SLOTS;
+const MOVE_ENERGY: usize = APPLY_DAMAGE + NUM_SLOTS;
+const SWAP_WITH_DECK: usize = MOVE_ENERGY + NUM_ENERGY_TYPES * NUM_SLOTS * NUM_SLOTS;
+const DISCARD_ENERGY: usize = SWAP_WITH_DECK + NUM_CARDS;
+const CHOOSE_DAMAGE_TARGET: usize = DISCARD_ENERGY + NUM_ENERGY_TYPES * NUM_SLOTS;
+const ACTIVATE: usize = CHOOSE_DAMAGE_TARGET + 2 * NUM_SLOTS;
+const PROMOTE: usize = ACTIVATE + NUM_SLOTS;
+const COPY_ATTACK: usize = PROMOTE + NUM_SLOTS;
+const SETUP: usize = COPY_ATTACK + MAX_ATTACKS;
+
+/// Stable id of an action in a fixed-size action space, for policy networks and compact
+/// replays. Actions are indexed by kind, card (in `ALL_CARD_IDS` order) and board slot.
+///
+/// Amounts are not part of the index: attaching or healing is indexed by its (first)
+/// target only, and damage by its first target. The legal actions of a state never differ
+/// by amount alone, so different legal actions always have different indices. Setups are
+/// indexed by the positions of their cards in the opening hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ActionIndex(pub usize);
+
+impl ActionIndex {
+    /// Number of possible indices.
+    pub const SPACE_SIZE: usize = SETUP + (OPENING_HAND_SIZE << OPENING_HAND_SIZE);
+
+    pub fn of(action: &SimpleAction) -> Self {
+        let index = match action {
+            SimpleAction::DrawCard => DRAW_CARD,
+            SimpleAction::EndTurn => END_TURN,
+            SimpleAction::Play { trainer_card } => PLAY + trainer_index(trainer_card),
+            SimpleAction::Place(card, slot) => PLACE + slot_card_index(card, *slot),
+            SimpleAction::Evolve(card, slot) => EVOLVE + slot_card_index(card, *slot),
+            SimpleAction::UseAbility(slot) => USE_ABILITY + slot,
+            SimpleAction::Attack(index) => {
+                debug_assert!(*index < MAX_ATTACKS);
+                ATTACK + index
+            }
+            SimpleAction::Retreat(slot) => RETREAT + slot,
+            SimpleAction::Attach {
+                attachments,
+                is_turn_energy,
+            } => {
+                let (energy_type, slot) = attachments
+                    .first()
+                    .map_or((0, 0), |(_, energy_type, slot)| {
+                        (*energy_type as usize, *slot)
+                    });
+                ATTACH
+                    + (*is_turn_energy as usize * NUM_ENERGY_TYPES + energy_type) * NUM_SLOTS
+                    + slot
+            }
+            SimpleAction::AttachTool {
+                in_play_idx,
+                tool_id,
+            } => ATTACH_TOOL + tool_index(*tool_id) * NUM_SLOTS + in_play_idx,
+            SimpleAction::Heal { in_play_idx, .. } => HEAL + in_play_idx,
+            SimpleAction::ApplyDamage { targets } => {
+                APPLY_DAMAGE + targets.first().map_or(0, |(_, slot)| *slot)
+            }
+            SimpleAction::MoveEnergy {
+                from_in_play_idx,
+                to_in_play_idx,
+                energy,
+            } => {
+                MOVE_ENERGY
+                    + (*energy as usize * NUM_SLOTS + from_in_play_idx) * NUM_SLOTS
+                    + to_in_play_idx
+            }
+            SimpleAction::SwapWithDeck(card) => {
+                SWAP_WITH_DECK + card_index(card).expect("Cards should be in the database")
+            }
+            SimpleAction::DiscardEnergy {
+                in_play_idx,
+                energy,
+            } => DISCARD_ENERGY + *energy as usize * NUM_SLOTS + in_play_idx,
+            SimpleAction::ChooseDamageTarget {
+                player,
+                in_play_idx,
+                ..
+            } => CHOOSE_DAMAGE_TARGET + player * NUM_SLOTS + in_play_idx,
+            SimpleAction::Activate { in_play_idx } => ACTIVATE + in_play_idx,
+            SimpleAction::Promote { bench_idx } => PROMOTE + bench_idx,
+            SimpleAction::CopyAttack(index) => {
+                debug_assert!(*index < MAX_ATTACKS);
+                COPY_ATTACK + index
+            }
+            SimpleAction::Setup { active, bench } => {
+                debug_assert!(*active < OPENING_HAND_SIZE);
+                let bench_mask: usize = bench.iter().map(|x| 1 << x).sum();
+                SETUP + (active << OPENING_HAND_SIZE) + bench_mask
+            }
+        };
+        Self(index)
+    }
+
+    /// `ActionIndex::of` as a plain number (usable as a `players::ActionEncoder`).
+    pub fn encode(action: &Action) -> usize {
+        Self::of(&action.action).0
+    }
+
+    /// The action among `actions` (e.g. the legal ones) with this index.
+    pub fn find(self, actions: &[Action]) -> Option<&Action> {
+        actions.iter().find(|x| Self::of(&x.action) == self)
+    }
+}
+
+/// Which indices of the action space are legal in the state (`ActionIndex::SPACE_SIZE`
+/// long, for masking a policy's output).
+pub fn legal_action_mask(state: &State) -> Vec<bool> {
+    let (_, actions) = generate_possible_actions(state);
+    let mut mask = vec![false; ActionIndex::SPACE_SIZE];
+    for action in &actions {
+        mask[ActionIndex::encode(action)] = true;
+    }
+    mask
+}
+
+fn trainer_index(trainer_card: &'static TrainerCard) -> usize {
+    card_index(&Card::Trainer(trainer_card)).expect("Cards should be in the database")
+}
+
+fn slot_card_index(card: &Card, slot: usize) -> usize {
+    let index = card_index(card).expect("Cards should be in the database");
+    index * NUM_SLOTS + slot
+}
+
+fn tool_index(tool_id: ToolId) -> usize {
+    match tool_id {
+        ToolId::A2147GiantCape => 0,
+        ToolId::A2148RockyHelmet => 1,
+        ToolId::A2149LumBerry => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        game_rules::GameRules,
+        players::create_players,
+        players::PlayerCode,
+        state::{MAX_BENCH_SIZE, MAX_IN_PLAY},
+        test_helpers::load_test_decks,
+        types::EnergyType,
+        Game,
+    };
+
+    #[test]
+    fn test_action_indices() {
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        assert_eq!(ActionIndex::of(&SimpleAction::DrawCard), ActionIndex(0));
+        assert_eq!(
+            ActionIndex::of(&SimpleAction::Place(bulbasaur.clone(), 2)),
+            ActionIndex(PLACE + 2)
+        );
+        assert_eq!(
+            ActionIndex::of(&SimpleAction::Evolve(bulbasaur.clone(), 0)),
+            ActionIndex(EVOLVE)
+        );
+        let attach = |amount, is_turn_energy| SimpleAction::Attach {
+            attachments: vec![(amount, EnergyType::Water, 1)],
+            is_turn_energy,
+        };
+        assert_eq!(
+            ActionIndex::of(&attach(1, false)),
+            ActionIndex::of(&attach(3, false))
+        );
+        assert_ne!(
+            ActionIndex::of(&attach(1, false)),
+            ActionIndex::of(&attach(1, true))
+        );
+        assert_eq!(
+            ActionIndex::of(&SimpleAction::Promote { bench_idx: 3 }),
+            ActionIndex(PROMOTE + 3)
+        );
+        assert_eq!(
+            ActionIndex::of(&SimpleAction::CopyAttack(1)),
+            ActionIndex(SETUP - 1)
+        );
+        let setup = SimpleAction::Setup {
+            active: 4,
+            bench: vec![0, 2],
+        };
+        assert_eq!(ActionIndex::of(&setup), ActionIndex(SETUP + 4 * 32 + 0b101));
+        assert!(ActionIndex::of(&setup).0 < ActionIndex::SPACE_SIZE);
+
+        // Every slot of the biggest board has its own indices
+        let mut indices = HashSet::new();
+        for slot in 0..MAX_IN_PLAY {
+            let actions = [
+                SimpleAction::Place(bulbasaur.clone(), slot),
+                SimpleAction::Evolve(bulbasaur.clone(), slot),
+                SimpleAction::UseAbility(slot),
+                SimpleAction::Retreat(slot),
+                SimpleAction::Attach {
+                    attachments: vec![(1, EnergyType::Water, slot)],
+                    is_turn_energy: true,
+                },
+                SimpleAction::Heal {
+                    in_play_idx: slot,
+                    amount: 20,
+                },
+                SimpleAction::Activate { in_play_idx: slot },
+                SimpleAction::Promote { bench_idx: slot },
+            ];
+            for action in actions {
+                assert!(indices.insert(ActionIndex::of(&action)), "{action:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_legal_actions_have_distinct_indices() {
+        let (deck_a, deck_b) = load_test_decks();
+        // Every other game with the biggest bench, so that every slot gets used
+        let big_bench = GameRules {
+            bench_size: MAX_BENCH_SIZE,
+            ..Default::default()
+        };
+        for seed in 0..10 {
+            let players = create_players(
+                deck_a.clone(),
+                deck_b.clone(),
+                vec![PlayerCode::R, PlayerCode::R],
+            );
+            let rules = if seed % 2 == 0 {
+                GameRules::default()
+            } else {
+                big_bench
+            };
+            let mut game = Game::with_rules(players, seed, rules);
+            while !game.get_state().is_game_over() {
+                let state = game.get_state();
+                let (_, legal_actions) = generate_possible_actions(state);
+                // Two copies of a card in hand make the same action twice
+                let mut actions: Vec<Action> = vec![];
+                for action in legal_actions {
+                    if !actions.contains(&action) {
+                        actions.push(action);
+                    }
+                }
+                let indices: HashSet<usize> = actions.iter().map(ActionIndex::encode).collect();
+                assert_eq!(indices.len(), actions.len(), "{actions:?}");
+
+                let mask = legal_action_mask(state);
+                assert_eq!(mask.iter().filter(|x| **x).count(), actions.len());
+                for action in &actions {
+                    let index = ActionIndex(ActionIndex::encode(action));
+                    assert_eq!(index.find(&actions), Some(action));
+                }
+                game.play_tick();
+            }
+        }
+    }
+}
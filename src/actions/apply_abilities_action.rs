@@ -1,15 +1,23 @@
 use log::debug;
 
-use crate::{ability_ids::AbilityId, types::EnergyType, State};
+use crate::{ability_ids::AbilityId, hooks::can_use_ability, types::EnergyType, State};
 
 // This is a reducer of all actions relating to abilities.
 pub(crate) fn apply_abilities_action(acting_player: usize, state: &mut State, index: usize) {
     let pokemon = state.in_play_pokemon[acting_player][index]
-        .as_mut()
+        .as_ref()
         .expect("Pokemon should be there if using ability");
-    pokemon.ability_used = true;
     let ability_id = AbilityId::from_pokemon_id(&pokemon.get_id()[..])
         .expect("Pokemon should have ability implemented");
+    assert!(
+        can_use_ability(state, acting_player, index),
+        "{:?}'s ability cant be used",
+        ability_id
+    );
+    state.in_play_pokemon[acting_player][index]
+        .as_mut()
+        .expect("Pokemon should be there if using ability")
+        .ability_used = true;
     match ability_id {
         AbilityId::A1007Butterfree => {
             // Once during your turn, you may heal 20 damage from each of your Pokemon.
@@ -33,8 +41,7 @@ pub(crate) fn apply_abilities_action(acting_player: usize, state: &mut State, in
             let active = state.get_active_mut(acting_player);
             active.attach_energy(&EnergyType::Psychic, 1);
         }
-        AbilityId::A2a071Arceus => {
-            panic!("Arceus's ability cant be used");
-        }
+        // Every other ability has no AbilityUsage, so can_use_ability already panicked
+        _ => unreachable!("{:?}'s ability cant be used", ability_id),
     }
 }
@@ -3,7 +3,10 @@ use std::panic;
 use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng};
 
 use crate::{
-    hooks::{get_retreat_cost, on_attach_tool, to_playable_card},
+    hooks::{
+        can_evolve, fire_trigger, get_retreat_cost, on_attach_tool, to_evolved_card,
+        to_playable_card, Trigger,
+    },
     state::State,
     types::{Card, PlayedCard, TrainerCard, TrainerType},
 };
@@ -11,9 +14,12 @@ use crate::{
 use super::{
     apply_abilities_action::apply_abilities_action,
     apply_action_helpers::{
-        apply_common_mutation, forecast_end_turn, handle_attack_damage, Mutations, Probabilities,
+        apply_common_mutation, damage_own_pokemon, forecast_end_turn, forecast_setup,
+        handle_attack_damage, handle_fainted_pokemon, queue_energy_discards,
+        take_pending_energy_discards, Mutation, Mutations, Probabilities,
     },
-    apply_attack_action::forecast_attack,
+    apply_attack_action::{forecast_attack, forecast_copied_attack},
+    apply_trainer_action::forecast_swap_with_deck,
     safe_trainer_actions::forecast_trainer_action_safe,
     Action, SimpleAction,
 };
@@ -22,19 +28,56 @@ use super::{
 /// and then chooses one of them to apply. This is so that bot implementations can re-use the
 /// `forecast_action` function.
 pub fn apply_action(rng: &mut StdRng, state: &mut State, action: &Action) {
+    let mutation = choose_outcome(rng, state, action);
+    mutation(rng, state, action);
+}
+
+/// Like `apply_action`, but the outcome is chosen with `outcome_rng` and `rng` is only used
+/// within it, so that e.g. the coin flips of a Pokémon Checkup don't shift the energy
+/// generated by the turn it ends.
+pub fn apply_action_with_outcome_rng(
+    outcome_rng: &mut StdRng,
+    rng: &mut StdRng,
+    state: &mut State,
+    action: &Action,
+) {
+    let mutation = choose_outcome(outcome_rng, state, action);
+    mutation(rng, state, action);
+}
+
+// Forecasts the action and picks which of its outcomes happens
+fn choose_outcome(rng: &mut StdRng, state: &mut State, action: &Action) -> Mutation {
     let (probabilities, mut lazy_mutations) = forecast_action(state, action);
+    state.coin_flips.clear();
+    state.checkup_events.clear();
     if probabilities.len() == 1 {
-        lazy_mutations.remove(0)(rng, state, action);
+        lazy_mutations.remove(0)
     } else {
         let dist = WeightedIndex::new(&probabilities).unwrap();
         let chosen_index = dist.sample(rng);
-        lazy_mutations.remove(chosen_index)(rng, state, action);
+        lazy_mutations.remove(chosen_index)
     }
 }
 
+/// Forecasts the possible outcomes of the action, each of which also moves the turn phase
+/// along (see `State::phase`).
+pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutations) {
+    let (probabilities, mutations) = route_forecast(state, action);
+    let mutations = mutations
+        .into_iter()
+        .map(|mutation| -> Mutation {
+            Box::new(move |rng, state, action| {
+                mutation(rng, state, action);
+                state.update_phase(&action.action);
+            })
+        })
+        .collect();
+    (probabilities, mutations)
+}
+
 /// This should be mostly a "router" function that calls the appropriate forecast function
 /// based on the action type.
-pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutations) {
+fn route_forecast(state: &State, action: &Action) -> (Probabilities, Mutations) {
     match &action.action {
         // Deterministic Actions
         SimpleAction::DrawCard // TODO: DrawCard should return actual deck probabilities.
@@ -44,8 +87,12 @@ pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutati
         | SimpleAction::Evolve(_, _)
         | SimpleAction::UseAbility(_)
         | SimpleAction::Activate { .. }
+        | SimpleAction::Promote { .. }
         | SimpleAction::Retreat(_)
         | SimpleAction::ApplyDamage { .. }
+        | SimpleAction::MoveEnergy { .. }
+        | SimpleAction::DiscardEnergy { .. }
+        | SimpleAction::ChooseDamageTarget { .. }
         | SimpleAction::Heal { .. } => (
             vec![1.0],
             vec![Box::new({
@@ -55,11 +102,14 @@ pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutati
             })],
         ),
         SimpleAction::Attack(index) => forecast_attack(action.actor, state, *index),
+        SimpleAction::CopyAttack(index) => forecast_copied_attack(action.actor, state, *index),
+        SimpleAction::SwapWithDeck(card) => forecast_swap_with_deck(card.clone()),
         SimpleAction::Play { trainer_card } => {
             forecast_trainer_action_safe(action.actor, state, trainer_card)
         }
         // acting_player is not passed here, because there is only 1 turn to end. The current turn.
         SimpleAction::EndTurn => forecast_end_turn(state),
+        SimpleAction::Setup { .. } => forecast_setup(),
     }
 }
 
@@ -83,6 +133,11 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
             }
             if *is_turn_energy {
                 state.current_energy = None;
+                for (_, energy, in_play_idx) in attachments {
+                    let trigger = Trigger::EnergyAttached { energy: *energy };
+                    fire_trigger(state, action.actor, *in_play_idx, trigger);
+                }
+                handle_fainted_pokemon(state, action.actor);
             }
         }
         SimpleAction::AttachTool {
@@ -109,12 +164,48 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
         SimpleAction::Activate { in_play_idx } => {
             apply_retreat(action.actor, state, *in_play_idx, true);
         }
+        SimpleAction::Promote { bench_idx } => {
+            apply_promote(action.actor, state, *bench_idx);
+        }
         SimpleAction::Retreat(position) => {
             apply_retreat(action.actor, state, *position, false);
         }
         SimpleAction::ApplyDamage { targets } => {
             handle_attack_damage(state, action.actor, targets);
         }
+        SimpleAction::MoveEnergy {
+            from_in_play_idx,
+            to_in_play_idx,
+            energy,
+        } => {
+            state.in_play_pokemon[action.actor][*from_in_play_idx]
+                .as_mut()
+                .expect("Pokemon should be there if moving its energy")
+                .discard_energy(energy);
+            state.in_play_pokemon[action.actor][*to_in_play_idx]
+                .as_mut()
+                .expect("Pokemon should be there if moving energy to it")
+                .attach_energy(energy, 1);
+        }
+        SimpleAction::DiscardEnergy {
+            in_play_idx,
+            energy,
+        } => {
+            state.discard_energy(action.actor, *in_play_idx, *energy);
+            let remaining = take_pending_energy_discards(state, action.actor, *in_play_idx);
+            queue_energy_discards(state, action.actor, *in_play_idx, remaining);
+        }
+        SimpleAction::ChooseDamageTarget {
+            player,
+            in_play_idx,
+            damage,
+        } => {
+            if *player == action.actor {
+                damage_own_pokemon(state, *player, *in_play_idx, *damage);
+            } else {
+                handle_attack_damage(state, action.actor, &vec![(*damage, *in_play_idx)]);
+            }
+        }
         // Trainer-Specific Actions
         SimpleAction::Heal {
             in_play_idx,
@@ -134,23 +225,18 @@ fn apply_healing(acting_player: usize, state: &mut State, position: usize, amoun
 }
 
 fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_free: bool) {
-    if !is_free {
+    let retreat_cost = if is_free {
+        0
+    } else {
         let active = state.in_play_pokemon[acting_player][0]
             .as_ref()
             .expect("Active Pokemon should be there if paid retreating");
-        let retreat_cost = get_retreat_cost(state, active);
-        let attached_energy: &mut Vec<_> = state.in_play_pokemon[acting_player][0]
-            .as_mut()
-            .expect("Active Pokemon should be there if paid retreating")
-            .attached_energy
-            .as_mut();
-
-        // TODO: Maybe give option to user to select which energy to discard
-        let count = retreat_cost.len();
-        attached_energy.truncate(attached_energy.len() - count);
-    }
+        get_retreat_cost(state, active).len()
+    };
 
     state.in_play_pokemon[acting_player].swap(0, bench_idx);
+    // The retreated Pokémon (now at bench_idx) pays the cost, with the player's pick of Energy
+    queue_energy_discards(state, acting_player, bench_idx, retreat_cost);
 
     // Cure any status conditions
     if let Some(pokemon) = &state.in_play_pokemon[acting_player][bench_idx] {
@@ -158,6 +244,8 @@ fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_f
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             ..pokemon.clone()
         });
     }
@@ -165,30 +253,33 @@ fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_f
     state.has_retreated = true;
 }
 
+// Unlike Activate, promoting doesn't count as the turn's retreat (the Active Spot is empty).
+fn apply_promote(acting_player: usize, state: &mut State, bench_idx: usize) {
+    debug_assert!(state.in_play_pokemon[acting_player][0].is_none());
+    state.in_play_pokemon[acting_player].swap(0, bench_idx);
+}
+
 // We will replace the PlayedCard, but taking into account the attached energy
 //  and the remaining HP.
 fn apply_evolve(acting_player: usize, state: &mut State, card: &Card, position: usize) {
     // This removes status conditions
-    let mut played_card = to_playable_card(card, true);
+    let Card::Pokemon(pokemon_card) = card else {
+        panic!("Only Pokemon cards can be evolved, not {card:?}");
+    };
+    if pokemon_card.stage == 0 {
+        panic!("Only stage 1 or 2 pokemons can be evolved");
+    }
+    assert!(
+        can_evolve(state, acting_player, card, position),
+        "Can't evolve the Pokemon at position {position} into {card:?}"
+    );
 
     let old_pokemon = state.in_play_pokemon[acting_player][position]
         .as_ref()
         .expect("Pokemon should be there if evolving it");
-    if let Card::Pokemon(pokemon_card) = &played_card.card {
-        if pokemon_card.stage == 0 {
-            panic!("Only stage 1 or 2 pokemons can be evolved");
-        }
-
-        let damage_taken = old_pokemon.total_hp - old_pokemon.remaining_hp;
-        played_card.remaining_hp -= damage_taken;
-        played_card.attached_energy = old_pokemon.attached_energy.clone();
-        played_card.cards_behind = old_pokemon.cards_behind.clone();
-        played_card.cards_behind.push(old_pokemon.card.clone());
-        state.in_play_pokemon[acting_player][position] = Some(played_card);
-    } else {
-        panic!("Only Pokemon cards can be evolved");
-    }
+    state.in_play_pokemon[acting_player][position] = Some(to_evolved_card(old_pokemon, card));
     state.remove_card_from_hand(acting_player, card);
+    fire_trigger(state, acting_player, position, Trigger::Evolved);
     // NOTE: Phantomly leave the Stage 0 card behind the newly evolved card
 }
 
@@ -200,13 +291,36 @@ mod tests {
     use super::*;
     use crate::card_ids::CardId;
     use crate::database::get_card_by_enum;
+    use crate::sets::SetId;
+    use crate::test_helpers::to_trainer_card;
     use crate::tool_ids::ToolId;
     use crate::types::{PlayedCard, TrainerCard, TrainerType};
     use crate::{types::EnergyType, Deck};
 
+    #[test]
+    fn test_evolve_keeps_damage_and_tool() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        state.turn_count = 3;
+        let mankey = get_card_by_enum(CardId::PA017Mankey);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&mankey, false));
+        state.get_active_mut(0).attached_tool = Some(ToolId::A2147GiantCape);
+        on_attach_tool(&mut state, 0, 0, ToolId::A2147GiantCape);
+        state.get_active_mut(0).apply_damage(30);
+        state.hands[0] = vec![primeape.clone()];
+
+        apply_evolve(0, &mut state, &primeape, 0);
+        let primeape = state.get_active(0);
+        assert_eq!(primeape.attached_tool, Some(ToolId::A2147GiantCape));
+        assert_eq!(primeape.total_hp, 110); // 90 + 20
+        assert_eq!(primeape.remaining_hp, 80);
+        assert_eq!(primeape.damage_taken(), 30);
+    }
+
     #[test]
     fn test_apply_evolve() {
         let mut state = State::new(&Deck::default(), &Deck::default());
+        state.turn_count = 3;
         let energy = EnergyType::Colorless;
         let mankey = get_card_by_enum(CardId::PA017Mankey);
         let primeape = get_card_by_enum(CardId::A1142Primeape);
@@ -235,7 +349,10 @@ mod tests {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
-                cards_behind: vec![mankey.clone()]
+                burned: false,
+                confused: false,
+                cards_behind: vec![mankey.clone()],
+                effects: vec![],
             })
         );
 
@@ -254,7 +371,10 @@ mod tests {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
-                cards_behind: vec![mankey.clone()]
+                burned: false,
+                confused: false,
+                cards_behind: vec![mankey.clone()],
+                effects: vec![],
             })
         );
         assert_eq!(
@@ -270,7 +390,10 @@ mod tests {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
-                cards_behind: vec![mankey.clone()]
+                burned: false,
+                confused: false,
+                cards_behind: vec![mankey.clone()],
+                effects: vec![],
             })
         );
     }
@@ -310,7 +433,7 @@ mod tests {
         
         // Add card to deck
         let card = get_card_by_enum(CardId::A1141Mankey);
-        state.decks[0].cards.push(card.clone());
+        state.deck_mut(0).cards.push(card.clone());
         
         let action = Action {
             actor: 0,
@@ -426,6 +549,93 @@ mod tests {
         assert!(state.has_retreated);
     }
 
+    #[test]
+    fn test_retreat_lets_player_choose_energy_to_discard() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        // Mankey retreats for 1 with a Fighting and a Water Energy attached
+        let mankey = get_card_by_enum(CardId::A1141Mankey);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        let mut active_pokemon = to_playable_card(&mankey, false);
+        active_pokemon.attached_energy = vec![EnergyType::Fighting, EnergyType::Water];
+        state.in_play_pokemon[0][0] = Some(active_pokemon);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&primeape, false));
+
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Retreat(1),
+            is_stack: false,
+        };
+        apply_action(&mut rng, &mut state, &action);
+
+        let discard = |energy| SimpleAction::DiscardEnergy {
+            in_play_idx: 1,
+            energy,
+        };
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(
+                0,
+                vec![discard(EnergyType::Water), discard(EnergyType::Fighting)]
+            )]
+        );
+        let action = Action {
+            actor: 0,
+            action: discard(EnergyType::Fighting),
+            is_stack: true,
+        };
+        apply_action(&mut rng, &mut state, &action);
+
+        let bench_pokemon = state.in_play_pokemon[0][1].as_ref().unwrap();
+        assert_eq!(bench_pokemon.attached_energy, vec![EnergyType::Water]);
+        assert!(state.move_generation_stack.is_empty());
+    }
+
+    #[test]
+    fn test_only_asks_for_energy_discards_that_matter() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut pokemon = to_playable_card(&bulbasaur, false);
+        pokemon.attached_energy = vec![
+            EnergyType::Grass,
+            EnergyType::Water,
+            EnergyType::Water,
+            EnergyType::Fire,
+        ];
+        state.in_play_pokemon[0][0] = Some(pokemon);
+
+        // Two to pick: discarding the Fire Energy leaves only Water and Grass to choose from
+        queue_energy_discards(&mut state, 0, 0, 2);
+        assert_eq!(state.move_generation_stack.len(), 2);
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::DiscardEnergy {
+                in_play_idx: 0,
+                energy: EnergyType::Fire,
+            },
+            is_stack: true,
+        };
+        apply_action(&mut rng, &mut state, &action);
+        assert_eq!(state.move_generation_stack.len(), 1);
+        assert_eq!(state.move_generation_stack[0].1.len(), 2);
+
+        // Once all remaining Energy is of one type there is nothing to choose
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::DiscardEnergy {
+                in_play_idx: 0,
+                energy: EnergyType::Grass,
+            },
+            is_stack: true,
+        };
+        apply_action(&mut rng, &mut state, &action);
+        queue_energy_discards(&mut state, 0, 0, 1);
+        assert!(state.move_generation_stack.is_empty());
+        assert_eq!(state.get_active(0).attached_energy, vec![EnergyType::Water]);
+    }
+
     #[test]
     fn test_retreat_removes_status_conditions() {
         let mut state = State::new(&Deck::default(), &Deck::default());
@@ -485,6 +695,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_knockout_queues_promote_choice() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let mankey = get_card_by_enum(CardId::A1141Mankey);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&mankey, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&mankey, false));
+        state.in_play_pokemon[1][2] = Some(to_playable_card(&primeape, false));
+
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::ApplyDamage {
+                targets: vec![(200, 0)],
+            },
+            is_stack: false,
+        };
+        apply_action(&mut rng, &mut state, &action);
+
+        // Promotion is a choice for the knocked out player, not automatic
+        assert!(state.in_play_pokemon[1][0].is_none());
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(1, vec![SimpleAction::Promote { bench_idx: 2 }])]
+        );
+
+        let promote = Action {
+            actor: 1,
+            action: SimpleAction::Promote { bench_idx: 2 },
+            is_stack: true,
+        };
+        apply_action(&mut rng, &mut state, &promote);
+
+        assert_eq!(state.in_play_pokemon[1][0].as_ref().unwrap().card, primeape);
+        assert!(state.in_play_pokemon[1][2].is_none());
+        assert!(state.move_generation_stack.is_empty());
+        assert!(!state.has_retreated);
+    }
+
+    #[test]
+    fn test_fossil_knockout_gives_no_points() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let mankey = get_card_by_enum(CardId::A1141Mankey);
+        let helix_fossil = get_card_by_enum(CardId::A1216HelixFossil);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&mankey, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&mankey, false));
+        state.in_play_pokemon[1][1] = Some(to_playable_card(&helix_fossil, false));
+        assert_eq!(state.get_remaining_hp(1, 1), 40);
+
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::ApplyDamage {
+                targets: vec![(40, 1)],
+            },
+            is_stack: false,
+        };
+        apply_action(&mut rng, &mut state, &action);
+
+        assert!(state.in_play_pokemon[1][1].is_none());
+        assert_eq!(state.discard_piles[1], vec![helix_fossil]);
+        assert_eq!(state.points, [0, 0]);
+    }
+
     #[test]
     fn test_heal_action() {
         let mut state = State::new(&Deck::default(), &Deck::default());
@@ -524,18 +800,73 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Unplayable Trainer Card")]
+    #[should_panic(expected = "Can't evolve the Pokemon at position 0")]
+    fn test_evolve_wrong_line_panics() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        state.turn_count = 3;
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.hands[0].push(primeape.clone());
+
+        apply_evolve(0, &mut state, &primeape, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't evolve the Pokemon at position 0")]
+    fn test_evolve_played_this_turn_panics() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        state.turn_count = 3;
+        let mankey = get_card_by_enum(CardId::A1141Mankey);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&mankey, true));
+        state.hands[0].push(primeape.clone());
+
+        apply_evolve(0, &mut state, &primeape, 0);
+    }
+
+    #[test]
+    fn test_only_legal_evolutions_are_generated() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        state.turn_count = 3;
+        let mankey = get_card_by_enum(CardId::A1141Mankey);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&mankey, true));
+        state.in_play_pokemon[0][2] = Some(to_playable_card(&bulbasaur, true));
+        state.hands[0] = vec![primeape.clone(), ivysaur.clone()];
+
+        let (_, actions) = crate::generate_possible_actions(&state);
+        let evolutions: Vec<_> = actions
+            .into_iter()
+            .filter(|x| matches!(x.action, SimpleAction::Evolve(_, _)))
+            .map(|x| x.action)
+            .collect();
+        assert_eq!(evolutions, vec![SimpleAction::Evolve(ivysaur.clone(), 0)]);
+
+        // Not during the first turns
+        state.turn_count = 2;
+        let (_, actions) = crate::generate_possible_actions(&state);
+        assert!(!actions
+            .iter()
+            .any(|x| matches!(x.action, SimpleAction::Evolve(_, _))));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only Pokemon cards can be evolved")]
     fn test_evolve_with_trainer_card_panics() {
         let mut state = State::new(&Deck::default(), &Deck::default());
         let mankey = get_card_by_enum(CardId::A1141Mankey);
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "1".to_string(),
             numeric_id: 1,
             name: "Test Trainer".to_string(),
             trainer_card_type: TrainerType::Item,
             effect: "Test effect".to_string(),
             rarity: "Common".to_string(),
-            booster_pack: "Test".to_string(),
+            set: SetId::A1,
         });
         
         state.in_play_pokemon[0][0] = Some(to_playable_card(&mankey, false));
@@ -2,10 +2,17 @@ use log::debug;
 use rand::rngs::StdRng;
 
 use crate::{
-    actions::SimpleAction, hooks::get_counterattack_damage, state::GameOutcome, types::Card, State,
+    actions::SimpleAction,
+    hooks::{
+        fire_trigger, fire_turn_start, get_counterattack_damage, get_damage_reduction,
+        to_playable_card, Trigger,
+    },
+    state::GameOutcome,
+    types::{Card, EnergyType},
+    State,
 };
 
-use super::Action;
+use super::{checkup::forecast_pokemon_checkup, Action};
 
 pub(crate) type Probabilities = Vec<f64>;
 
@@ -18,121 +25,47 @@ pub(crate) type Mutations = Vec<Mutation>;
 
 /// Advance state to the next turn (i.e. maintain current_player and turn_count)
 pub(crate) fn forecast_end_turn(state: &State) -> (Probabilities, Mutations) {
-    let in_initial_setup_phase = state.turn_count == 0;
-    if in_initial_setup_phase {
-        (
-            vec![1.0],
-            vec![Box::new({
-                |_, state, action| {
-                    apply_common_mutation(state, action);
-                    // advance current_player, but only advance "turn" (i.e. stay in 0) when both players done.
-                    state.current_player = (state.current_player + 1) % 2;
-                    let both_players_initiated = state.in_play_pokemon[0][0].is_some()
-                        && state.in_play_pokemon[1][0].is_some();
-                    if both_players_initiated {
-                        // Actually start game (no energy generation)
-                        state.turn_count = 1;
-                        state.reset_turn_states();
-                        state.queue_draw_action(state.current_player);
-                    }
-                }
-            })],
-        )
-    } else {
-        forecast_pokemon_checkup(state)
-    }
+    forecast_pokemon_checkup(state)
 }
 
-/// Handle Status Effects
-fn forecast_pokemon_checkup(state: &State) -> (Probabilities, Mutations) {
-    let mut sleeps_to_handle = vec![];
-    let mut paralyzed_to_handle = vec![];
-    let mut poisons_to_handle = vec![];
-    for player in 0..2 {
-        for (i, pokemon) in state.enumerate_in_play_pokemon(player) {
-            if pokemon.asleep {
-                sleeps_to_handle.push((player, i));
-            }
-            if pokemon.paralyzed {
-                paralyzed_to_handle.push((player, i));
-            }
-            if pokemon.poisoned {
-                poisons_to_handle.push((player, i));
-                debug!("{}'s Pokemon {} is poisoned", player, i);
-            }
-        }
-    }
-
-    // Get all binary vectors representing the possible outcomes.
-    // These are the "outcome_ids" (e.g. outcome [true, false] might represent
-    // waking up one pokemon and not another).
-    let outcome_ids = generate_boolean_vectors(sleeps_to_handle.len());
-    let probabilities = vec![1.0 / outcome_ids.len() as f64; outcome_ids.len()];
-    let mut outcomes: Mutations = vec![];
-    for outcome in outcome_ids {
-        let sleeps_to_handle = sleeps_to_handle.clone();
-        let paralyzed_to_handle = paralyzed_to_handle.clone();
-        let poisons_to_handle = poisons_to_handle.clone();
-        outcomes.push(Box::new({
-            |_, state, action| {
+/// Puts the player's opening board in play. Once both players set up, the game starts
+/// with the player going first (current_player doesn't move during setup).
+pub(crate) fn forecast_setup() -> (Probabilities, Mutations) {
+    (
+        vec![1.0],
+        vec![Box::new({
+            |rng, state, action| {
                 apply_common_mutation(state, action);
-                apply_pokemon_checkup(
-                    state,
-                    sleeps_to_handle,
-                    paralyzed_to_handle,
-                    poisons_to_handle,
-                    outcome,
-                );
-            }
-        }));
-    }
-    (probabilities, outcomes)
-}
-
-fn apply_pokemon_checkup(
-    mutated_state: &mut State,
-    sleeps_to_handle: Vec<(usize, usize)>,
-    paralyzed_to_handle: Vec<(usize, usize)>,
-    poisons_to_handle: Vec<(usize, usize)>,
-    outcome: Vec<bool>,
-) {
-    for (i, is_awake) in sleeps_to_handle.iter().zip(outcome) {
-        if is_awake {
-            let (player, in_play_idx) = i;
-            let pokemon = mutated_state.in_play_pokemon[*player][*in_play_idx]
-                .as_mut()
-                .expect("Pokemon should be there...");
-            pokemon.asleep = false;
-            debug!("{}'s Pokemon {} woke up", player, in_play_idx);
-        }
-    }
-    // These always happen regardless of outcome_binary_vector
-    for (player, in_play_idx) in paralyzed_to_handle {
-        let pokemon = mutated_state.in_play_pokemon[player][in_play_idx]
-            .as_mut()
-            .expect("Pokemon should be there...");
-        pokemon.paralyzed = false;
-        debug!("{}'s Pokemon {} is un-paralyzed", player, in_play_idx);
-    }
-    for (player, in_play_idx) in poisons_to_handle {
-        let opponent = (player + 1) % 2;
-        handle_attack_damage(mutated_state, opponent, &vec![(10, in_play_idx)]);
-    }
-    // Advance turn
-    mutated_state.advance_turn();
-}
-
-fn generate_boolean_vectors(n: usize) -> Vec<Vec<bool>> {
-    // The total number of combinations is 2^n
-    let total_combinations = 1 << n; // 2^n
+                let SimpleAction::Setup { active, bench } = &action.action else {
+                    panic!("Expected a Setup action, got {action:?}");
+                };
+                let player = action.actor;
+                let hand = &state.hands[player];
+                let board: Vec<Card> = [*active]
+                    .iter()
+                    .chain(bench)
+                    .map(|x| hand[*x].clone())
+                    .collect();
+                for (slot, card) in board.iter().enumerate() {
+                    state.in_play_pokemon[player][slot] = Some(to_playable_card(card, true));
+                    state.remove_card_from_hand(player, card);
+                }
 
-    // Generate all combinations
-    (0..total_combinations)
-        .map(|i| {
-            // Convert the number `i` to its binary representation as a vector of booleans
-            (0..n).map(|bit| (i & (1 << bit)) != 0).collect()
-        })
-        .collect()
+                let both_players_initiated =
+                    state.in_play_pokemon[0][0].is_some() && state.in_play_pokemon[1][0].is_some();
+                if both_players_initiated {
+                    // Actually start game (no energy generation unless the rules allow it)
+                    state.turn_count = 1;
+                    state.reset_turn_states();
+                    state.queue_draw_action(state.current_player);
+                    if state.rules.first_turn_energy {
+                        state.generate_energy(rng);
+                    }
+                    fire_turn_start(state, state.current_player);
+                }
+            }
+        })],
+    )
 }
 
 pub(crate) fn handle_attack_damage(
@@ -141,9 +74,16 @@ pub(crate) fn handle_attack_damage(
     targets: &Vec<(u32, usize)>, // damage, in_play_idx
 ) {
     let defending_player = (attacking_player + 1) % 2;
-    let mut knockouts: Vec<(usize, usize)> = vec![];
     for (damage, target_pokemon_idx) in targets {
-        if *damage == 0 {
+        // Passive abilities (e.g. Melmetal's) can lower the damage taken
+        let reduction = get_damage_reduction(
+            state,
+            attacking_player,
+            defending_player,
+            *target_pokemon_idx,
+        );
+        let damage = damage.saturating_sub(reduction);
+        if damage == 0 {
             continue;
         }
 
@@ -152,14 +92,11 @@ pub(crate) fn handle_attack_damage(
             let target_pokemon = state.in_play_pokemon[defending_player][*target_pokemon_idx]
                 .as_mut()
                 .expect("Pokemon should be there if taking damage");
-            target_pokemon.apply_damage(*damage); // Applies without surpassing 0 HP
+            target_pokemon.apply_damage(damage); // Applies without surpassing 0 HP
             debug!(
                 "Dealt {} damage to opponent's {} Pokemon. Remaining HP: {}",
                 damage, target_pokemon_idx, target_pokemon.remaining_hp
             );
-            if target_pokemon.remaining_hp == 0 {
-                knockouts.push((defending_player, *target_pokemon_idx));
-            }
 
             if *target_pokemon_idx == 0 {
                 get_counterattack_damage(target_pokemon)
@@ -178,11 +115,173 @@ pub(crate) fn handle_attack_damage(
                 "Dealt {} counterattack damage to active Pokemon. Remaining HP: {}",
                 counter_damage, attacking_pokemon.remaining_hp
             );
-            if attacking_pokemon.remaining_hp == 0 {
-                knockouts.push((attacking_player, 0));
-            }
+        }
+
+        let trigger = Trigger::AttackDamaged {
+            attacking_player,
+            damage,
+        };
+        fire_trigger(state, defending_player, *target_pokemon_idx, trigger);
+        let knocked_out = state.in_play_pokemon[defending_player][*target_pokemon_idx]
+            .as_ref()
+            .is_some_and(|pokemon| pokemon.remaining_hp == 0);
+        if knocked_out {
+            let trigger = Trigger::KnockedOutByAttack { attacking_player };
+            fire_trigger(state, defending_player, *target_pokemon_idx, trigger);
+        }
+    }
+
+    handle_fainted_pokemon(state, attacking_player);
+}
+
+/// Knocks Out every Pokémon left with 0 HP (e.g. after Abilities went off), defending
+/// player's first.
+pub(crate) fn handle_fainted_pokemon(state: &mut State, acting_player: usize) {
+    let defending_player = (acting_player + 1) % 2;
+    let knockouts: Vec<(usize, usize)> = [defending_player, acting_player]
+        .into_iter()
+        .flat_map(|player| {
+            state
+                .enumerate_in_play_pokemon(player)
+                .filter(|(_, pokemon)| pokemon.remaining_hp == 0)
+                .map(move |(in_play_idx, _)| (player, in_play_idx))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    if !knockouts.is_empty() {
+        handle_knockouts(state, acting_player, knockouts);
+    }
+}
+
+/// Damages the player's own Active Pokémon (e.g. recoil from its attack), knocking it out
+/// if it runs out of HP.
+pub(crate) fn apply_self_damage(state: &mut State, player: usize, damage: u32) {
+    damage_own_pokemon(state, player, 0, damage);
+}
+
+/// Damages one of the player's own Pokémon (e.g. a Benched one hit by its own attack),
+/// knocking it out if it runs out of HP. Tools don't counterattack their own player.
+pub(crate) fn damage_own_pokemon(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+    damage: u32,
+) {
+    let pokemon = state.in_play_pokemon[player][in_play_idx]
+        .as_mut()
+        .expect("Pokemon should be there if taking damage");
+    pokemon.apply_damage(damage);
+    debug!(
+        "Dealt {} damage to own {} Pokemon. Remaining HP: {}",
+        damage, in_play_idx, pokemon.remaining_hp
+    );
+    if pokemon.remaining_hp == 0 {
+        handle_knockouts(state, player, vec![(player, in_play_idx)]);
+    }
+}
+
+/// Discards energy from one of the player's Pokémon. Colorless entries can be paid with any
+/// Energy, so the player gets to choose those (see `queue_energy_discards`).
+pub(crate) fn discard_own_energy(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+    to_discard: &[EnergyType],
+) {
+    let mut any_count = 0;
+    for energy in to_discard {
+        if *energy == EnergyType::Colorless {
+            any_count += 1;
+        } else {
+            state.discard_energy(player, in_play_idx, *energy);
         }
     }
+    queue_energy_discards(state, player, in_play_idx, any_count);
+}
+
+/// Makes the player discard `count` Energy of their choice from one of their Pokémon (e.g. to
+/// pay a retreat cost). Only asks (one DiscardEnergy at a time) when the choice matters, that
+/// is, when some but not all of the attached Energy goes and it isn't all of the same type.
+pub(crate) fn queue_energy_discards(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+    count: usize,
+) {
+    if count == 0 {
+        return;
+    }
+    let attached_energy = &state.in_play_pokemon[player][in_play_idx]
+        .as_ref()
+        .expect("Pokemon should be there if discarding its energy")
+        .attached_energy;
+    let mut energy_types = attached_energy.clone();
+    energy_types.sort();
+    energy_types.dedup();
+    if count >= attached_energy.len() {
+        state.discard_all_energy(player, in_play_idx);
+        return;
+    } else if energy_types.len() == 1 {
+        for _ in 0..count {
+            state.discard_energy(player, in_play_idx, energy_types[0]);
+        }
+        return;
+    }
+
+    let choices: Vec<_> = energy_types
+        .into_iter()
+        .map(|energy| SimpleAction::DiscardEnergy {
+            in_play_idx,
+            energy,
+        })
+        .collect();
+    for _ in 0..count {
+        state.move_generation_stack.push((player, choices.clone()));
+    }
+}
+
+/// Number of DiscardEnergy choices still queued for the player's Pokémon, taking them off the
+/// stack so they can be re-queued with the Energy left after a discard.
+pub(crate) fn take_pending_energy_discards(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+) -> usize {
+    let mut count = 0;
+    while let Some((actor, choices)) = state.move_generation_stack.last() {
+        let is_pending_discard = *actor == player
+            && choices.iter().all(|x| {
+                matches!(x, SimpleAction::DiscardEnergy { in_play_idx: idx, .. } if *idx == in_play_idx)
+            });
+        if !is_pending_discard {
+            break;
+        }
+        state.move_generation_stack.pop();
+        count += 1;
+    }
+    count
+}
+
+/// Points the opponent gets for knocking out the card: 2 for ex Pokémon, none for fossils
+/// and 1 otherwise.
+pub(crate) fn points_for_knockout(card: &Card) -> u8 {
+    if card.is_fossil() {
+        0
+    } else if card.is_ex() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Discards the knocked out (player, in_play_idx) Pokémon, awarding points for them, and
+/// either ends the game or queues up the promotions. Every Knock Out should go through here.
+pub(crate) fn handle_knockouts(
+    state: &mut State,
+    attacking_player: usize,
+    knockouts: Vec<(usize, usize)>,
+) {
+    let defending_player = (attacking_player + 1) % 2;
 
     // Handle knockouts: Discard cards and award points (to potentially short-circuit promotions)
     for (ko_receiver, ko_pokemon_idx) in knockouts.clone() {
@@ -192,7 +291,7 @@ pub(crate) fn handle_attack_damage(
 
         // Award points
         let ko_initiator = (ko_receiver + 1) % 2;
-        let points_won = if ko_pokemon.card.is_ex() { 2 } else { 1 };
+        let points_won = points_for_knockout(&ko_pokemon.card);
         state.points[ko_initiator] += points_won;
         debug!(
             "Pokemon {:?} fainted. Player {} won {} points for a total of {}",
@@ -202,25 +301,31 @@ pub(crate) fn handle_attack_damage(
         // Move card (and evolution chain) into discard pile
         let mut cards_to_discard = ko_pokemon.cards_behind.clone();
         cards_to_discard.push(ko_pokemon.card.clone());
+        let energy_to_discard = std::mem::take(&mut ko_pokemon.attached_energy);
         debug!("Discarding: {:?}", cards_to_discard);
         state.discard_piles[ko_receiver].extend(cards_to_discard);
+        state.record_discarded_energy(ko_receiver, &energy_to_discard);
         state.in_play_pokemon[ko_receiver][ko_pokemon_idx] = None;
     }
 
     // If game ends because of knockouts, set winner and return so as to short-circuit promotion logic
-    if state.points[attacking_player] >= 3 && state.points[defending_player] >= 3 {
-        debug!("Both players have 3 points, it's a tie");
+    let points_to_win = state.rules.points_to_win;
+    if state.points[attacking_player] >= points_to_win
+        && state.points[defending_player] >= points_to_win
+    {
+        debug!("Both players have {} points, it's a tie", points_to_win);
         state.winner = Some(GameOutcome::Tie);
         return;
-    } else if state.points[attacking_player] >= 3 {
+    } else if state.points[attacking_player] >= points_to_win {
         state.winner = Some(GameOutcome::Win(attacking_player));
         return; // attacking player could lose by attacking into a RockyHelmet e.g.
-    } else if state.points[defending_player] >= 3 {
+    } else if state.points[defending_player] >= points_to_win {
         state.winner = Some(GameOutcome::Win(defending_player));
         return;
     }
 
     // Queue up promotion actions if the game is still on after a knockout
+    let mut cant_promote = vec![];
     for (ko_receiver, ko_pokemon_idx) in knockouts {
         if ko_pokemon_idx != 0 {
             continue; // Only promote if K.O. was on Active
@@ -231,17 +336,15 @@ pub(crate) fn handle_attack_damage(
             .enumerate_bench_pokemon(ko_receiver)
             .collect::<Vec<_>>();
         if enumerated_bench_pokemon.is_empty() {
-            // If no bench pokemon, opponent loses
-            let ko_initiator = (ko_receiver + 1) % 2;
-            state.winner = Some(GameOutcome::Win(ko_initiator));
-            debug!("Player {} lost due to no bench pokemon", ko_receiver);
+            debug!("Player {} has no bench pokemon to promote", ko_receiver);
+            cant_promote.push(ko_receiver);
         } else {
             let possible_moves = state
                 .enumerate_bench_pokemon(ko_receiver)
-                .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
+                .map(|(i, _)| SimpleAction::Promote { bench_idx: i })
                 .collect::<Vec<_>>();
             debug!(
-                "Triggering Activate moves: {:?} to player {}",
+                "Triggering Promote moves: {:?} to player {}",
                 possible_moves, ko_receiver
             );
             state
@@ -249,6 +352,16 @@ pub(crate) fn handle_attack_damage(
                 .push((ko_receiver, possible_moves));
         }
     }
+
+    // If no bench pokemon, opponent wins (a tie if both players are left without any)
+    match cant_promote[..] {
+        [] => {}
+        [ko_receiver] => state.winner = Some(GameOutcome::Win((ko_receiver + 1) % 2)),
+        _ => {
+            debug!("Neither player has bench pokemon, it's a tie");
+            state.winner = Some(GameOutcome::Tie);
+        }
+    }
 }
 
 // Apply common mutations for all outcomes
@@ -258,10 +371,119 @@ pub(crate) fn apply_common_mutation(state: &mut State, action: &Action) {
         state.move_generation_stack.pop();
     }
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let card = Card::Trainer(trainer_card.clone());
+        let card = Card::Trainer(trainer_card);
         state.discard_card_from_hand(action.actor, &card);
         if card.is_support() {
             state.has_played_support = true;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        actions::apply_action, card_ids::CardId, database::get_card_by_enum,
+        hooks::to_playable_card, test_helpers::load_test_decks,
+    };
+
+    // Both players with Bulbasaurs in play, player 1's one at `in_play_idx` being a Venusaur
+    // ex with `hp` left instead.
+    fn state_with_ex(hp: u32, in_play_idx: usize) -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 3;
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut venusaur = to_playable_card(&get_card_by_enum(CardId::A1004VenusaurEx), false);
+        venusaur.remaining_hp = hp;
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][1] = Some(bulbasaur);
+        state.in_play_pokemon[1][in_play_idx] = Some(venusaur);
+        state
+    }
+
+    #[test]
+    fn test_points_for_knockout() {
+        let points = |card_id| points_for_knockout(&get_card_by_enum(card_id));
+        assert_eq!(points(CardId::A1001Bulbasaur), 1);
+        assert_eq!(points(CardId::A1004VenusaurEx), 2);
+        assert_eq!(points(CardId::A1216HelixFossil), 0);
+    }
+
+    #[test]
+    fn test_abilities_triggered_by_a_knock_out_can_knock_out_the_attacker() {
+        let mut state = state_with_ex(30, 1);
+        let mut pyukumuku = to_playable_card(&get_card_by_enum(CardId::A3054Pyukumuku), false);
+        pyukumuku.remaining_hp = 10;
+        state.in_play_pokemon[1][0] = Some(pyukumuku);
+        state.in_play_pokemon[0][0].as_mut().unwrap().remaining_hp = 50;
+        handle_attack_damage(&mut state, 0, &vec![(10, 0)]);
+        assert!(state.in_play_pokemon[0][0].is_none());
+        assert!(state.in_play_pokemon[1][0].is_none());
+        assert_eq!(state.points, [1, 1]);
+    }
+
+    #[test]
+    fn test_both_actives_knocked_out_without_bench_is_a_tie() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur);
+        handle_knockouts(&mut state, 0, vec![(1, 0), (0, 0)]);
+        assert_eq!(state.points, [1, 1]);
+        assert_eq!(state.winner, Some(GameOutcome::Tie));
+
+        // With a bench to promote from, only the other player loses
+        let mut state = state_with_ex(30, 1);
+        handle_knockouts(&mut state, 0, vec![(1, 0), (0, 0)]);
+        assert_eq!(state.winner, Some(GameOutcome::Win(1)));
+        assert_eq!(state.move_generation_stack.last().unwrap().0, 1);
+    }
+
+    #[test]
+    fn test_ex_knocked_out_on_bench_gives_two_points() {
+        let mut state = state_with_ex(30, 1);
+        handle_attack_damage(&mut state, 0, &vec![(30, 1)]);
+        assert!(state.in_play_pokemon[1][1].is_none());
+        assert_eq!(state.points, [2, 0]);
+    }
+
+    #[test]
+    fn test_knocked_out_pokemon_energy_is_discarded() {
+        let mut state = state_with_ex(30, 1);
+        state.in_play_pokemon[1][1]
+            .as_mut()
+            .unwrap()
+            .attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        handle_attack_damage(&mut state, 0, &vec![(30, 1)]);
+        assert_eq!(state.discarded_energy[1][&EnergyType::Grass], 2);
+    }
+
+    #[test]
+    fn test_ex_knocked_out_by_poison_gives_two_points() {
+        let mut state = state_with_ex(10, 0);
+        state.get_active_mut(1).poisoned = true;
+        let end_turn = Action {
+            actor: 0,
+            action: SimpleAction::EndTurn,
+            is_stack: false,
+        };
+        apply_action(&mut StdRng::seed_from_u64(0), &mut state, &end_turn);
+        assert!(state.in_play_pokemon[1][0].is_none());
+        assert_eq!(state.points, [2, 0]);
+    }
+
+    #[test]
+    fn test_ex_knocked_out_by_own_attack_gives_two_points() {
+        let mut state = state_with_ex(20, 0);
+        apply_self_damage(&mut state, 1, 20);
+        assert!(state.in_play_pokemon[1][0].is_none());
+        assert_eq!(state.points, [2, 0]);
+        // The player still gets to pick its new Active Pokémon
+        assert_eq!(state.move_generation_stack.last().unwrap().0, 1);
+    }
+}
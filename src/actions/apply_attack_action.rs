@@ -1,19 +1,23 @@
 use log::trace;
-use rand::Rng;
+use rand::{rngs::StdRng, Rng};
 
 use crate::{
+    attack_effects::AttackEffect,
     attack_ids::AttackId,
-    hooks::get_damage_from_attack,
-    types::{EnergyType, StatusCondition},
+    hooks::{contains_energy, get_damage_from_attack},
+    types::{Attack, Card, EnergyType, StatusCondition},
     State,
 };
 
 use super::{
-    apply_action_helpers::{Mutations, Probabilities},
+    apply_action_helpers::{
+        apply_common_mutation, apply_self_damage, discard_own_energy, Mutations, Probabilities,
+    },
+    coin_flips::{recording_flips, CoinFlipper, CoinFlips},
     mutations::{
         active_damage_doutcome, active_damage_effect_doutcome, active_damage_effect_mutation,
-        active_damage_mutation, build_status_effect, damage_effect_doutcome,
-        index_active_damage_doutcome,
+        active_damage_mutation, attack_active_damage_doutcome, build_status_effect,
+        damage_effect_doutcome, damage_effect_mutation,
     },
     SimpleAction,
 };
@@ -25,128 +29,202 @@ pub(crate) fn forecast_attack(
     index: usize,
 ) -> (Probabilities, Mutations) {
     let active = state.get_active(acting_player);
-    let attack = active.card.get_attacks()[index].clone();
-    trace!("Forecasting attack: {:?} {:?}", active, attack);
+    trace!(
+        "Forecasting attack: {:?} {:?}",
+        active,
+        active.get_attacks()[index]
+    );
+    let outcomes = forecast_attack_of(acting_player, state, &active.card, index);
+    gate_attack(&attack_gates(acting_player, state, index), outcomes)
+}
+
+/// Resolves the opponent's Active Pokémon's attack at `index` as the acting player's Active
+/// Pokémon's own (see `SimpleAction::CopyAttack`). The attack that copies it already went
+/// through Confusion.
+pub(crate) fn forecast_copied_attack(
+    acting_player: usize,
+    state: &State,
+    index: usize,
+) -> (Probabilities, Mutations) {
+    let source = state.get_active((acting_player + 1) % 2).card.clone();
+    trace!(
+        "Forecasting copied attack: {:?}",
+        source.get_attacks()[index]
+    );
+    let outcomes = forecast_attack_of(acting_player, state, &source, index);
+    gate_attack(&effect_gates(&source, index), outcomes)
+}
+
+// Outcomes of the acting player's Active Pokémon using the attack at `index` of `card` (its
+// own card, unless it copies the attack), before any gate.
+fn forecast_attack_of(
+    acting_player: usize,
+    state: &State,
+    card: &Card,
+    index: usize,
+) -> (Probabilities, Mutations) {
+    let attack = &card.get_attacks()[index];
     if attack.effect.is_none() {
-        let damage = get_damage_from_attack(state, acting_player, index, 0);
+        let damage = get_damage_from_attack(state, acting_player, attack, 0);
         active_damage_doutcome(damage)
     } else {
-        forecast_effect_attack(acting_player, state, index)
+        forecast_effect_attack(acting_player, state, card, index)
     }
 }
 
-fn celebi_powerful_bloom(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
-    let active_pokemon = state.get_active(acting_player);
-    let total_energy = active_pokemon.attached_energy.len();
+/// A coin flipped before an attack, which does nothing (but still ends the turn) on tails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttackGate {
+    /// The attacking Pokémon is Confused.
+    Confusion,
+    /// "Flip a coin. If tails, this attack does nothing." (see `AttackEffect::CoinFlipOrNothing`)
+    CoinFlipOrNothing,
+}
 
-    if total_energy == 0 {
-        // No energy attached, no coins to flip
-        return probabilistic_damage_attack(vec![1.0], vec![0]);
+/// The coins that have to come up heads for the attack to happen, in the order they're flipped.
+fn attack_gates(acting_player: usize, state: &State, index: usize) -> Vec<AttackGate> {
+    let active = state.get_active(acting_player);
+    let mut gates = vec![];
+    if active.confused {
+        gates.push(AttackGate::Confusion);
     }
+    gates.extend(effect_gates(&active.card, index));
+    gates
+}
 
-    // Generate all possible outcomes for flipping N coins
-    // Each coin can be heads (1) or tails (0)
-    let num_outcomes = 2_usize.pow(total_energy as u32);
-    let mut probabilities = vec![0.0; total_energy + 1]; // 0 to total_energy heads
-    let mut damages = Vec::new();
+/// The coins the attack at `index` of `card` itself flips (e.g. when copied, see
+/// `forecast_copied_attack`).
+fn effect_gates(card: &Card, index: usize) -> Vec<AttackGate> {
+    data_driven_effects(card, index)
+        .unwrap_or_default()
+        .iter()
+        .filter(|x| **x == AttackEffect::CoinFlipOrNothing)
+        .map(|_| AttackGate::CoinFlipOrNothing)
+        .collect()
+}
 
-    // For each possible outcome (0 to total_energy heads)
-    for (heads, prob) in probabilities.iter_mut().enumerate() {
-        // Probability of getting exactly 'heads' heads out of 'total_energy' coins
-        // This follows a binomial distribution: C(n,k) * (1/2)^n
-        *prob = binomial_coefficient(total_energy, heads) as f64 / (num_outcomes as f64);
-        damages.push((heads as u32) * 50); // 50 damage per heads
+/// Puts the attack's outcomes behind the gates' coin flips: flipping stops at the first tails,
+/// in which case the attack does nothing. The attack's own outcomes need all of them to be heads.
+fn gate_attack(
+    gates: &[AttackGate],
+    (probabilities, mutations): (Probabilities, Mutations),
+) -> (Probabilities, Mutations) {
+    if gates.is_empty() {
+        return (probabilities, mutations);
     }
-
-    probabilistic_damage_attack(probabilities, damages)
+    let coins = gates.len() as u8;
+    let mut gated_probabilities = vec![];
+    let mut gated_mutations: Mutations = vec![];
+    for (probability, flips) in CoinFlipper::until_tails(coins - 1) {
+        gated_probabilities.push(probability);
+        gated_mutations.push(recording_flips(flips, active_damage_mutation(0)));
+    }
+    let all_heads = CoinFlips {
+        flips: coins,
+        heads: coins,
+    };
+    let all_heads_probability = 0.5_f64.powi(coins as i32);
+    for (probability, mutation) in probabilities.into_iter().zip(mutations) {
+        gated_probabilities.push(probability * all_heads_probability);
+        gated_mutations.push(recording_flips(all_heads, mutation));
+    }
+    (gated_probabilities, gated_mutations)
 }
 
-fn binomial_coefficient(n: usize, k: usize) -> usize {
-    if k > n {
-        return 0;
-    }
-    if k == 0 || k == n {
-        return 1;
+// Effects of attacks resolved by the interpreter. Attacks with an AttackId are resolved by code.
+fn data_driven_effects(card: &Card, index: usize) -> Option<Vec<AttackEffect>> {
+    let pokemon_id = card.get_id();
+    if AttackId::from_pokemon_index(&pokemon_id, index).is_some() {
+        return None;
     }
+    AttackEffect::from_attack(&pokemon_id, index, &card.get_attacks()[index])
+}
 
-    let mut result = 1;
-    for i in 0..k {
-        result = result * (n - i) / (i + 1);
+fn celebi_powerful_bloom(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
+    let active_pokemon = state.get_active(acting_player);
+    let total_energy = active_pokemon.attached_energy.len();
+
+    if total_energy == 0 {
+        // No energy attached, no coins to flip
+        return active_damage_doutcome(0);
     }
-    result
+
+    // Flip a coin for each energy, 50 damage per heads
+    coin_flip_damage_attack(CoinFlipper::fixed(total_energy as u8), |heads| heads * 50)
 }
 
-/// Handles attacks that have effects.
+/// Handles attacks that have effects, `card` being the one the attack is printed on.
 fn forecast_effect_attack(
     acting_player: usize,
     state: &State,
+    card: &Card,
     index: usize,
 ) -> (Probabilities, Mutations) {
-    let attack_id = {
-        let active = state.get_active(acting_player);
-        AttackId::from_pokemon_index(&active.get_id()[..], index).unwrap_or_else(|| {
-            panic!(
-                "Attack not found for Pokemon: {:?} {:?} {:?}",
-                active.card,
-                active.card.get_attacks(),
-                index
-            )
-        })
-    };
+    if let Some(effects) = data_driven_effects(card, index) {
+        let attack = &card.get_attacks()[index];
+        return interpret_attack_effects(acting_player, state, attack, index, &effects);
+    }
+    let attack_id = AttackId::from_pokemon_index(&card.get_id(), index).unwrap_or_else(|| {
+        panic!(
+            "Attack not found for Pokemon: {:?} {:?} {:?}",
+            card,
+            card.get_attacks(),
+            index
+        )
+    });
+    // The card's own attacks, e.g. for those that use the damage of another one
+    let attack = |i: usize| card.get_attacks()[i].clone();
     match attack_id {
-        AttackId::A1003VenusaurMegaDrain => self_heal_attack(30, index),
-        AttackId::A1004VenusaurExGiantBloom => self_heal_attack(30, index),
+        AttackId::A1003VenusaurMegaDrain => self_heal_attack(30, attack(index)),
+        AttackId::A1004VenusaurExGiantBloom => self_heal_attack(30, attack(index)),
         AttackId::A1013VileplumeSoothingScent => damage_status_attack(80, StatusCondition::Asleep),
         AttackId::A1017VenomothPoisonPowder => damage_status_attack(30, StatusCondition::Poisoned),
-        AttackId::A1022ExeggutorStomp => probabilistic_damage_attack(vec![0.5, 0.5], vec![30, 60]),
-        AttackId::A1023ExeggutorExTropicalSwing => {
-            probabilistic_damage_attack(vec![0.5, 0.5], vec![40, 80])
-        }
-        AttackId::A1024TangelaAbsorb => self_heal_attack(10, index),
-        AttackId::A1026PinsirDoubleHorn => {
-            probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 50, 100])
-        }
-        AttackId::A1029PetililBlot => self_heal_attack(10, index),
-        AttackId::A1030LilligantLeafSupply => energy_bench_attack(0, 1, EnergyType::Grass),
-        AttackId::A1031Skiddo => probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 40]),
-        AttackId::A1033CharmanderEmber => self_energy_discard_attack(0, vec![EnergyType::Fire]),
+        AttackId::A1022ExeggutorStomp => fixed_coins_damage_attack(1, 30, 30),
+        AttackId::A1023ExeggutorExTropicalSwing => fixed_coins_damage_attack(1, 40, 40),
+        AttackId::A1024TangelaAbsorb => self_heal_attack(10, attack(index)),
+        AttackId::A1026PinsirDoubleHorn => fixed_coins_damage_attack(2, 0, 50),
+        AttackId::A1029PetililBlot => self_heal_attack(10, attack(index)),
+        AttackId::A1030LilligantLeafSupply => energy_bench_attack(attack(0), 1, EnergyType::Grass),
+        AttackId::A1033CharmanderEmber => {
+            self_energy_discard_attack(attack(0), vec![EnergyType::Fire])
+        }
         AttackId::A1035CharizardFireSpin => {
-            self_energy_discard_attack(0, vec![EnergyType::Fire, EnergyType::Fire])
+            self_energy_discard_attack(attack(0), vec![EnergyType::Fire, EnergyType::Fire])
         }
         AttackId::A1036CharizardExCrimsonStorm => {
-            self_energy_discard_attack(1, vec![EnergyType::Fire, EnergyType::Fire])
+            self_energy_discard_attack(attack(1), vec![EnergyType::Fire, EnergyType::Fire])
         }
         AttackId::A1038NinetalesFlamethrower => {
-            self_energy_discard_attack(0, vec![EnergyType::Fire])
+            self_energy_discard_attack(attack(0), vec![EnergyType::Fire])
         }
         AttackId::A1040ArcanineHeatTackle => self_damage_attack(100, 20),
         AttackId::A1041ArcanineExInfernoOnrush => self_damage_attack(120, 20),
-        AttackId::A1045FlareonFlamethrower => self_energy_discard_attack(0, vec![EnergyType::Fire]),
-        AttackId::A1046MoltresSkyAttack => {
-            probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 130])
+        AttackId::A1045FlareonFlamethrower => {
+            self_energy_discard_attack(attack(0), vec![EnergyType::Fire])
         }
         AttackId::A1047MoltresExInfernoDance => moltres_inferno_dance(),
         AttackId::A1052CentiskorchFireBlast => {
-            self_energy_discard_attack(0, vec![EnergyType::Fire])
+            self_energy_discard_attack(attack(0), vec![EnergyType::Fire])
         }
         AttackId::A1055BlastoiseHydroPump => hydro_pump_attack(acting_player, state, 80, 5, 60),
         AttackId::A1056BlastoiseExHydroBazooka => {
             hydro_pump_attack(acting_player, state, 100, 5, 60)
         }
-        AttackId::A1057PsyduckHeadache => damage_and_turn_effect_attack(0, 1),
+        AttackId::A1057PsyduckHeadache => damage_and_turn_effect_attack(attack(0), card, 1),
         AttackId::A1063TentacruelPoisonTentacles => {
             damage_status_attack(50, StatusCondition::Poisoned)
         }
+        // 80 more damage only if both coins are heads
         AttackId::A1069KinglerKOCrab => {
-            probabilistic_damage_attack(vec![0.25, 0.75], vec![160, 80])
+            coin_flip_damage_attack(CoinFlipper::fixed(2), |heads| match heads {
+                2 => 160,
+                _ => 80,
+            })
         }
         AttackId::A1071SeadraWaterArrow => direct_damage(50, false),
-        AttackId::A1073SeakingHornHazard => {
-            probabilistic_damage_attack(vec![0.5, 0.5], vec![80, 0])
-        }
         AttackId::A1078GyaradosHyperBeam => damage_and_discard_energy(100, 1),
         AttackId::A1079LaprasHydroPump => hydro_pump_attack(acting_player, state, 20, 4, 70),
-        AttackId::A1080VaporeonBubbleDrain => self_heal_attack(30, 0),
+        AttackId::A1080VaporeonBubbleDrain => self_heal_attack(30, attack(0)),
         AttackId::A1083ArticunoIceBeam => {
             damage_chance_status_attack(60, 0.5, StatusCondition::Paralyzed)
         }
@@ -158,22 +236,14 @@ fn forecast_effect_attack(
             bench_count_attack(acting_player, state, 0, 30, Some(EnergyType::Lightning))
         }
         AttackId::A1101ElectabuzzThunderPunch => extra_or_self_damage_attack(40, 40, 20),
-        AttackId::A1102JolteonPinMissile => probabilistic_damage_attack(
-            vec![0.0625, 0.25, 0.375, 0.25, 0.0625],
-            vec![0, 40, 80, 120, 160],
-        ),
-        AttackId::A1103ZapdosRagingThunder => self_benched_damage(30, index),
-        AttackId::A1104ZapdosExThunderingHurricane => probabilistic_damage_attack(
-            vec![0.0625, 0.25, 0.375, 0.25, 0.0625],
-            vec![0, 50, 100, 150, 200],
-        ),
+        AttackId::A1102JolteonPinMissile => fixed_coins_damage_attack(4, 0, 40),
+        AttackId::A1103ZapdosRagingThunder => self_benched_damage(30, attack(index)),
+        AttackId::A1104ZapdosExThunderingHurricane => fixed_coins_damage_attack(4, 0, 50),
         AttackId::A1106ZebstrikaThunderSpear => direct_damage(30, false),
         AttackId::A1109EelektrossThunderFang => {
             damage_chance_status_attack(80, 0.5, StatusCondition::Paralyzed)
         }
-        AttackId::A1111HelioliskQuickAttack => {
-            probabilistic_damage_attack(vec![0.5, 0.5], vec![40, 80])
-        }
+        AttackId::A1111HelioliskQuickAttack => fixed_coins_damage_attack(1, 40, 40),
         AttackId::A1112PincurchinThunderShock => {
             damage_chance_status_attack(30, 0.5, StatusCondition::Paralyzed)
         }
@@ -183,36 +253,29 @@ fn forecast_effect_attack(
         }
         AttackId::A1127JynxPsychic => damage_based_on_opponent_energy(acting_player, state, 30, 20),
         AttackId::A1128MewtwoPowerBlast => {
-            self_energy_discard_attack(index, vec![EnergyType::Psychic])
-        }
-        AttackId::A1129MewtwoExPsydrive => {
-            self_energy_discard_attack(index, vec![EnergyType::Psychic, EnergyType::Psychic])
-        }
-        AttackId::A1136GolurkDoubleLariat => {
-            probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 100, 200])
+            self_energy_discard_attack(attack(index), vec![EnergyType::Psychic])
         }
+        AttackId::A1129MewtwoExPsydrive => self_energy_discard_attack(
+            attack(index),
+            vec![EnergyType::Psychic, EnergyType::Psychic],
+        ),
+        AttackId::A1136GolurkDoubleLariat => fixed_coins_damage_attack(2, 0, 100),
         AttackId::A1142PrimeapeFightBack => extra_damage_if_hurt(40, 60, acting_player, state),
         AttackId::A1149GolemDoubleEdge => self_damage_attack(150, 50),
-        AttackId::A1153MarowakExBonemerang => {
-            probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 80, 160])
-        }
+        AttackId::A1153MarowakExBonemerang => fixed_coins_damage_attack(2, 0, 80),
         AttackId::A1154HitmonleeStretchKick => direct_damage(30, true),
         AttackId::A1163GrapploctKnockBack => knock_back_attack(60),
-        AttackId::A1165ArbokCorner => damage_and_turn_effect_attack(index, 1),
+        AttackId::A1165ArbokCorner => damage_and_turn_effect_attack(attack(index), card, 1),
         AttackId::A1171NidokingPoisonHorn => damage_status_attack(90, StatusCondition::Poisoned),
         AttackId::A1174GrimerPoisonGas => damage_status_attack(10, StatusCondition::Poisoned),
         AttackId::A1178MawileCrunch => mawile_crunch(),
         AttackId::A1195WigglytuffSleepySong => damage_status_attack(80, StatusCondition::Asleep),
         AttackId::A1196MeowthPayDay => draw_and_damage_outcome(10),
         AttackId::A1201LickitungContinuousLick => flip_until_tails_attack(60),
-        AttackId::A1203KangaskhanDizzyPunch => {
-            probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 30, 60])
-        }
+        AttackId::A1203KangaskhanDizzyPunch => fixed_coins_damage_attack(2, 0, 30),
         AttackId::A1a003CelebiExPowerfulBloom => celebi_powerful_bloom(acting_player, state),
-        AttackId::A1a010PonytaStomp => probabilistic_damage_attack(vec![0.5, 0.5], vec![10, 40]),
-        AttackId::A1a011RapidashRisingLunge => {
-            probabilistic_damage_attack(vec![0.5, 0.5], vec![40, 100])
-        }
+        AttackId::A1a010PonytaStomp => fixed_coins_damage_attack(1, 10, 30),
+        AttackId::A1a011RapidashRisingLunge => fixed_coins_damage_attack(1, 40, 60),
         AttackId::A1a021LumineonAqua => direct_damage(50, true),
         AttackId::A1a026RaichuGigashock => {
             let opponent = (state.current_player + 1) % 2;
@@ -226,24 +289,160 @@ fn forecast_effect_attack(
         AttackId::A1a030DedenneThunderShock => {
             damage_chance_status_attack(10, 0.5, StatusCondition::Paralyzed)
         }
-        AttackId::A1a041MankeyFocusFist => probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 50]),
         AttackId::A1a061EeveeContinuousSteps => flip_until_tails_attack(20),
         AttackId::A2049PalkiaDimensionalStorm => palkia_dimensional_storm(state),
-        AttackId::A2119DialgaExMetallicTurbo => energy_bench_attack(index, 2, EnergyType::Metal),
+        AttackId::A2119DialgaExMetallicTurbo => {
+            energy_bench_attack(attack(index), 2, EnergyType::Metal)
+        }
         AttackId::A2a071ArceusExUltimateForce => {
             bench_count_attack(acting_player, state, 70, 20, None)
         }
-        AttackId::A2035PiplupHeal | AttackId::PA034PiplupHeal => self_heal_attack(20, index),
+        AttackId::A2035PiplupHeal | AttackId::PA034PiplupHeal => {
+            self_heal_attack(20, attack(index))
+        }
         AttackId::A3a094JynxPsychic => {
             damage_based_on_opponent_energy(acting_player, state, 30, 20)
         }
         AttackId::PA072AlolanGrimerPoison => damage_status_attack(0, StatusCondition::Poisoned),
+        AttackId::A1205DittoCopy => copy_attack(acting_player, state, true),
+        AttackId::A1a032MewExGenomeHacking => copy_attack(acting_player, state, false),
+        AttackId::A3b035MimikyuTryToImitate => gate_attack(
+            &[AttackGate::CoinFlipOrNothing],
+            copy_attack(acting_player, state, false),
+        ),
         AttackId::A1213CinccinoDoTheWave | AttackId::PA031CinccinoDoTheWave => {
             bench_count_attack(acting_player, state, 0, 30, None)
         }
     }
 }
 
+// One possible outcome of an attack interpreted from data.
+#[derive(Clone)]
+struct EffectBranch {
+    probability: f64,
+    flips: CoinFlips,
+    extra_damage: u32,
+    statuses: Vec<StatusCondition>,
+}
+
+/// Interprets attacks described by data (see `attack_effects.rs`). Coin-flipping effects
+/// branch the outcomes; every other effect is applied on all branches. "Does nothing on tails"
+/// flips are left to `gate_attack`.
+fn interpret_attack_effects(
+    acting_player: usize,
+    state: &State,
+    attack: &Attack,
+    index: usize,
+    effects: &[AttackEffect],
+) -> (Probabilities, Mutations) {
+    let mut branches = vec![EffectBranch {
+        probability: 1.0,
+        flips: CoinFlips::default(),
+        extra_damage: 0,
+        statuses: vec![],
+    }];
+    for effect in effects {
+        let outcomes = match effect {
+            AttackEffect::CoinFlipExtraDamage { coins, .. } => CoinFlipper::fixed(*coins),
+            AttackEffect::CoinFlipStatus(_) => CoinFlipper::fixed(1),
+            AttackEffect::Status(status) => {
+                for branch in branches.iter_mut() {
+                    branch.statuses.push(status.clone());
+                }
+                continue;
+            }
+            // Deterministic effects, applied below in every mutation.
+            AttackEffect::CoinFlipOrNothing
+            | AttackEffect::DiscardOwnEnergy(_)
+            | AttackEffect::BenchDamage(_)
+            | AttackEffect::SelfDamage(_)
+            | AttackEffect::SelfHeal(_)
+            | AttackEffect::SelfStatus(_)
+            | AttackEffect::CantAttackNextTurn
+            | AttackEffect::CantUseAgainNextTurn => continue,
+        };
+        branches = branches
+            .into_iter()
+            .flat_map(|branch| {
+                outcomes.iter().map(move |(probability, flips)| {
+                    let mut branch = branch.clone();
+                    branch.probability *= probability;
+                    branch.flips.flips += flips.flips;
+                    branch.flips.heads += flips.heads;
+                    match effect {
+                        AttackEffect::CoinFlipExtraDamage { damage, .. } => {
+                            branch.extra_damage += flips.heads as u32 * damage;
+                        }
+                        AttackEffect::CoinFlipStatus(status) if flips.heads > 0 => {
+                            branch.statuses.push(status.clone());
+                        }
+                        _ => {}
+                    }
+                    branch
+                })
+            })
+            .collect();
+    }
+
+    let base_damage = get_damage_from_attack(state, acting_player, attack, 0);
+    let opponent = (acting_player + 1) % 2;
+    let mut probabilities = vec![];
+    let mut mutations: Mutations = vec![];
+    for branch in branches {
+        probabilities.push(branch.probability);
+
+        let mut targets = vec![(base_damage + branch.extra_damage, 0)];
+        for effect in effects {
+            if let AttackEffect::BenchDamage(damage) = effect {
+                targets.extend(
+                    state
+                        .enumerate_bench_pokemon(opponent)
+                        .map(|(idx, _)| (*damage, idx)),
+                );
+            }
+        }
+        let effects = effects.to_vec();
+        let statuses = branch.statuses;
+        let mutation = damage_effect_mutation(targets, move |rng, state, action| {
+            for effect in effects.iter() {
+                match effect {
+                    AttackEffect::DiscardOwnEnergy(to_discard) => {
+                        discard_own_energy(state, action.actor, 0, to_discard)
+                    }
+                    AttackEffect::SelfDamage(damage) => {
+                        apply_self_damage(state, action.actor, *damage)
+                    }
+                    AttackEffect::SelfHeal(amount) => {
+                        state.get_active_mut(action.actor).heal(*amount)
+                    }
+                    // The attacker might have been Knocked Out by its own damage
+                    AttackEffect::SelfStatus(status) => {
+                        if let Some(active) = &mut state.in_play_pokemon[action.actor][0] {
+                            active.apply_status(status);
+                        }
+                    }
+                    AttackEffect::CantAttackNextTurn | AttackEffect::CantUseAgainNextTurn => {
+                        let until_turn = state.turn_count + 2;
+                        if let Some(active) = &mut state.in_play_pokemon[action.actor][0] {
+                            active.add_attack_effect(index, until_turn);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            for status in statuses.iter() {
+                build_status_effect(status.clone())(rng, state, action);
+            }
+        });
+        if branch.flips.flips > 0 {
+            mutations.push(recording_flips(branch.flips, mutation));
+        } else {
+            mutations.push(mutation);
+        }
+    }
+    (probabilities, mutations)
+}
+
 fn palkia_dimensional_storm(state: &State) -> (Probabilities, Mutations) {
     // This attack does 150 damage to Active, and 20 to every bench pokemon
     // it then also discards 3 energies. This is deterministic
@@ -253,10 +452,7 @@ fn palkia_dimensional_storm(state: &State) -> (Probabilities, Mutations) {
         .chain(std::iter::once((150, 0))) // Add active Pokémon directly
         .collect();
     damage_effect_doutcome(targets, |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.discard_energy(&EnergyType::Water);
-        active.discard_energy(&EnergyType::Water);
-        active.discard_energy(&EnergyType::Water);
+        discard_own_energy(state, action.actor, 0, &[EnergyType::Water; 3]);
     })
 }
 
@@ -359,11 +555,11 @@ fn generate_distributions(
 
 /// Deal damage and attach energy to a pokemon of choice in the bench.
 fn energy_bench_attack(
-    attack_index: usize,
+    attack: Attack,
     amount: u32,
     energy: EnergyType,
 ) -> (Probabilities, Mutations) {
-    index_active_damage_doutcome(attack_index, move |_, state, action| {
+    attack_active_damage_doutcome(attack, move |_, state, action| {
         let mut choices = Vec::new();
         for (in_play_idx, _) in state.enumerate_bench_pokemon(action.actor) {
             choices.push(SimpleAction::Attach {
@@ -388,8 +584,7 @@ fn extra_or_self_damage_attack(
     let mutations: Mutations = vec![
         active_damage_mutation(base_damage + extra_damage),
         active_damage_effect_mutation(base_damage, move |_, state, action| {
-            let active = state.get_active_mut(action.actor);
-            active.apply_damage(self_damage);
+            apply_self_damage(state, action.actor, self_damage);
         }),
     ];
     (probabilities, mutations)
@@ -431,18 +626,13 @@ fn bench_count_attack(
 }
 
 /// Used for attacks that can go directly to one of your own benched Pokémon.
-fn self_benched_damage(damage: u32, attack_index: usize) -> (Probabilities, Mutations) {
-    index_active_damage_doutcome(attack_index, move |_, state, action| {
-        let mut choices = Vec::new();
-        for (in_play_idx, _) in state.enumerate_bench_pokemon(action.actor) {
-            choices.push(SimpleAction::ApplyDamage {
-                targets: vec![(damage, in_play_idx)],
-            });
-        }
-        if choices.is_empty() {
-            return;
-        }
-        state.move_generation_stack.push((action.actor, choices));
+fn self_benched_damage(damage: u32, attack: Attack) -> (Probabilities, Mutations) {
+    attack_active_damage_doutcome(attack, move |_, state, action| {
+        let targets = state
+            .enumerate_bench_pokemon(action.actor)
+            .map(|(in_play_idx, _)| in_play_idx)
+            .collect();
+        push_damage_target_choice(state, action.actor, action.actor, targets, damage);
     })
 }
 
@@ -451,37 +641,51 @@ fn self_benched_damage(damage: u32, attack_index: usize) -> (Probabilities, Muta
 fn direct_damage(damage: u32, bench_only: bool) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(0, move |_, state, action| {
         let opponent = (action.actor + 1) % 2;
-        let mut choices = Vec::new();
-        if bench_only {
-            for (in_play_idx, _) in state.enumerate_bench_pokemon(opponent) {
-                choices.push(SimpleAction::ApplyDamage {
-                    targets: vec![(damage, in_play_idx)],
-                });
-            }
+        let targets = if bench_only {
+            state
+                .enumerate_bench_pokemon(opponent)
+                .map(|(in_play_idx, _)| in_play_idx)
+                .collect()
         } else {
-            for (in_play_idx, _) in state.enumerate_in_play_pokemon(opponent) {
-                choices.push(SimpleAction::ApplyDamage {
-                    targets: vec![(damage, in_play_idx)],
-                });
-            }
-        }
-        if choices.is_empty() {
-            return; // do nothing, since we use common_attack_mutation, turn should end, and no damage applied.
-        }
-        state.move_generation_stack.push((action.actor, choices));
+            state
+                .enumerate_in_play_pokemon(opponent)
+                .map(|(in_play_idx, _)| in_play_idx)
+                .collect()
+        };
+        push_damage_target_choice(state, action.actor, opponent, targets, damage);
     })
 }
 
+/// Queues a ChooseDamageTarget for the attacker among `targets` (in_play_idxs of `player`).
+/// Without targets nothing gets damaged.
+fn push_damage_target_choice(
+    state: &mut State,
+    actor: usize,
+    player: usize,
+    targets: Vec<usize>,
+    damage: u32,
+) {
+    if targets.is_empty() {
+        return;
+    }
+    let choices = targets
+        .into_iter()
+        .map(|in_play_idx| SimpleAction::ChooseDamageTarget {
+            player,
+            in_play_idx,
+            damage,
+        })
+        .collect();
+    state.move_generation_stack.push((actor, choices));
+}
+
 /// Discard energy from the active (attacking) Pokémon.
 fn self_energy_discard_attack(
-    attack_index: usize,
+    attack: Attack,
     to_discard: Vec<EnergyType>,
 ) -> (Probabilities, Mutations) {
-    index_active_damage_doutcome(attack_index, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        for energy in to_discard.iter() {
-            active.discard_energy(energy);
-        }
+    attack_active_damage_doutcome(attack, move |_, state, action| {
+        discard_own_energy(state, action.actor, 0, &to_discard);
     })
 }
 
@@ -489,26 +693,27 @@ fn self_energy_discard_attack(
 fn damage_and_discard_energy(damage: u32, discard_count: usize) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |rng, state, action| {
         let opponent = (action.actor + 1) % 2;
-        let active = state.get_active_mut(opponent);
-
         for _ in 0..discard_count {
-            if active.attached_energy.is_empty() {
-                break; // No more energy to discard
-            }
-
-            // Get a random index to discard
-            let energy_count = active.attached_energy.len();
-            let rand_idx = rng.gen_range(0..energy_count);
-            active.attached_energy.remove(rand_idx);
+            discard_random_energy(rng, state, opponent);
         }
     })
 }
 
+/// Discards a random Energy (if any) from the player's Active Pokémon.
+fn discard_random_energy(rng: &mut StdRng, state: &mut State, player: usize) {
+    let active = state.get_active(player);
+    if active.attached_energy.is_empty() {
+        return;
+    }
+    let rand_idx = rng.gen_range(0..active.attached_energy.len());
+    let energy = active.attached_energy[rand_idx];
+    state.discard_energy(player, 0, energy);
+}
+
 /// For attacks that deal damage to opponent and also damage themselves
 fn self_damage_attack(damage: u32, self_damage: u32) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.apply_damage(self_damage);
+        apply_self_damage(state, action.actor, self_damage);
     })
 }
 
@@ -555,15 +760,15 @@ fn hydro_pump_attack(
 }
 
 /// For attacks that given coin flips, deal different damage.
-fn probabilistic_damage_attack(
-    probabilities: Vec<f64>,
-    damages: Vec<u32>,
+/// For attacks that flip `coins` coins and do `damage_per_heads` more for each heads.
+fn fixed_coins_damage_attack(
+    coins: u8,
+    base_damage: u32,
+    damage_per_heads: u32,
 ) -> (Probabilities, Mutations) {
-    let mutations = damages
-        .into_iter()
-        .map(|damage| active_damage_mutation(damage))
-        .collect();
-    (probabilities, mutations)
+    coin_flip_damage_attack(CoinFlipper::fixed(coins), move |heads| {
+        base_damage + heads * damage_per_heads
+    })
 }
 
 /// For attacks that flip a coin until tails, dealing damage for each heads.
@@ -571,45 +776,104 @@ fn probabilistic_damage_attack(
 fn flip_until_tails_attack(damage_per_heads: u32) -> (Probabilities, Mutations) {
     // Truncate at 8 heads to keep the probability space manageable
     // P(k heads) = (1/2)^(k+1) for k = 0, 1, 2, ...
-    let max_heads = 8;
-    let mut probabilities = Vec::new();
-    let mut damages = Vec::new();
-
-    for heads in 0..=max_heads {
-        let probability = 0.5_f64.powi(heads as i32 + 1);
-        probabilities.push(probability);
-        damages.push(heads * damage_per_heads);
-    }
+    let mut outcomes = CoinFlipper::until_tails(8);
 
     // Ensure probabilities sum to 1 by adjusting the last one for any floating point errors
-    let sum: f64 = probabilities.iter().sum();
-    if let Some(last) = probabilities.last_mut() {
+    let sum: f64 = outcomes.iter().map(|(probability, _)| probability).sum();
+    if let Some((last, _)) = outcomes.last_mut() {
         *last += 1.0 - sum;
     }
 
-    probabilistic_damage_attack(probabilities, damages)
+    coin_flip_damage_attack(outcomes, |heads| heads * damage_per_heads)
 }
 
-fn self_heal_attack(heal: u32, index: usize) -> (Probabilities, Mutations) {
-    index_active_damage_doutcome(index, move |_, state, action| {
+/// For attacks whose damage depends on the number of heads, recording the flips on the state.
+fn coin_flip_damage_attack(
+    outcomes: Vec<(f64, CoinFlips)>,
+    damage: impl Fn(u32) -> u32,
+) -> (Probabilities, Mutations) {
+    outcomes
+        .into_iter()
+        .map(|(probability, flips)| {
+            let damage = damage(flips.heads as u32);
+            (
+                probability,
+                recording_flips(flips, active_damage_mutation(damage)),
+            )
+        })
+        .unzip()
+}
+
+fn self_heal_attack(heal: u32, attack: Attack) -> (Probabilities, Mutations) {
+    attack_active_damage_doutcome(attack, move |_, state, action| {
         let active = state.get_active_mut(action.actor);
         active.heal(heal);
     })
 }
 
-fn damage_and_turn_effect_attack(index: usize, effect_duration: u8) -> (Probabilities, Mutations) {
-    index_active_damage_doutcome(index, move |_, state, action| {
-        let active = state.get_active(action.actor);
+/// The effect is the card the attack is printed on, even when another Pokémon copies it.
+fn damage_and_turn_effect_attack(
+    attack: Attack,
+    card: &Card,
+    effect_duration: u8,
+) -> (Probabilities, Mutations) {
+    let card = card.clone();
+    attack_active_damage_doutcome(attack, move |_, state, _| {
         // TODO: Maybe create an EffectId enum and have a mapping between card,attack_idx to effect?
-        state.add_turn_effect(active.card.clone(), effect_duration);
+        state.add_turn_effect(card.clone(), effect_duration);
     })
 }
 
+/// For attacks that use one of the opponent's Active Pokémon's attacks instead: the player
+/// picks which (see `SimpleAction::CopyAttack`), or the attack does nothing if there is none.
+/// With `needs_energy`, only attacks the attacker has the Energy for can be picked (using
+/// another one would do nothing). Copy attacks can't be copied, so that two copiers facing
+/// each other don't loop.
+fn copy_attack(
+    acting_player: usize,
+    state: &State,
+    needs_energy: bool,
+) -> (Probabilities, Mutations) {
+    let attached = &state.get_active(acting_player).attached_energy;
+    let source = state.get_active((acting_player + 1) % 2);
+    let choices: Vec<SimpleAction> = source
+        .get_attacks()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !is_copy_attack(&source.card, *i))
+        .filter(|(_, attack)| !needs_energy || contains_energy(attached, &attack.energy_required))
+        .map(|(i, _)| SimpleAction::CopyAttack(i))
+        .collect();
+    (
+        vec![1.0],
+        vec![Box::new(move |_, state, action| {
+            apply_common_mutation(state, action);
+            // The copied attack ends the turn once resolved
+            let choices = if choices.is_empty() {
+                vec![SimpleAction::EndTurn]
+            } else {
+                choices.clone()
+            };
+            state.move_generation_stack.push((action.actor, choices));
+        })],
+    )
+}
+
+fn is_copy_attack(card: &Card, index: usize) -> bool {
+    matches!(
+        AttackId::from_pokemon_index(&card.get_id(), index),
+        Some(
+            AttackId::A1205DittoCopy
+                | AttackId::A1a032MewExGenomeHacking
+                | AttackId::A3b035MimikyuTryToImitate
+        )
+    )
+}
+
 /// For Raichu's Thunderbolt attack that deals 140 damage and discards all energy
 fn thunderbolt_attack() -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(140, move |_, state, action| {
-        let active = state.get_active_mut(action.actor);
-        active.attached_energy.clear(); // Discard all energy
+        state.discard_all_energy(action.actor, 0);
     })
 }
 
@@ -632,8 +896,7 @@ fn extra_damage_if_hurt(
     state: &State,
 ) -> (Probabilities, Mutations) {
     let opponent = (acting_player + 1) % 2;
-    let opponent_active = state.get_active(opponent);
-    if opponent_active.remaining_hp < opponent_active.total_hp {
+    if state.get_active(opponent).is_damaged() {
         active_damage_doutcome(base + extra)
     } else {
         active_damage_doutcome(base)
@@ -687,13 +950,7 @@ fn mawile_crunch() -> (Probabilities, Mutations) {
         active_damage_effect_mutation(20, move |rng, state, action| {
             // Heads: damage + discard random energy
             let opponent = (action.actor + 1) % 2;
-            let active = state.get_active_mut(opponent);
-
-            if !active.attached_energy.is_empty() {
-                let energy_count = active.attached_energy.len();
-                let rand_idx = rng.gen_range(0..energy_count);
-                active.attached_energy.remove(rand_idx);
-            }
+            discard_random_energy(rng, state, opponent);
         }),
     ];
     (probabilities, mutations)
@@ -704,7 +961,13 @@ mod test {
     use rand::{rngs::StdRng, SeedableRng};
 
     use crate::{
-        actions::Action, card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card,
+        actions::Action,
+        card_ids::CardId,
+        database::get_card_by_enum,
+        generate_possible_actions,
+        hooks::to_playable_card,
+        test_helpers::{energy, hp, ScenarioBuilder},
+        types::EnergyType::{Grass, Metal},
     };
 
     use super::*;
@@ -732,6 +995,269 @@ mod test {
         assert_eq!(state.get_active(1).remaining_hp, 70);
     }
 
+    #[test]
+    fn test_data_driven_status_attack() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let salazzle = get_card_by_enum(CardId::A1a016Salazzle); // Poison Claws: 30 + Poisoned
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&salazzle, false));
+        let receiver = get_card_by_enum(CardId::A1055Blastoise); // 150 hp
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+
+        let (probabilities, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        assert_eq!(probabilities, vec![1.0]);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+
+        assert_eq!(state.get_active(1).remaining_hp, 120);
+        assert!(state.get_active(1).poisoned);
+    }
+
+    #[test]
+    fn test_data_driven_coin_flip_attacks() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let glameow = get_card_by_enum(CardId::A2139Glameow); // Pose: 40, tails does nothing
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&glameow, false));
+        let receiver = get_card_by_enum(CardId::A1003Venusaur); // 160 hp
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+
+        let (probabilities, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+        // Outcomes are ordered by number of heads
+        let mut tails_state = state.clone();
+        lazy_mutations.remove(0)(&mut rng, &mut tails_state, &action);
+        assert_eq!(tails_state.get_active(1).remaining_hp, 160);
+        assert_eq!(
+            tails_state.coin_flips,
+            vec![CoinFlips { flips: 1, heads: 0 }]
+        );
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 120);
+        assert_eq!(state.coin_flips, vec![CoinFlips { flips: 1, heads: 1 }]);
+
+        // Stacked coin effects multiply the outcomes
+        let (probabilities, _) = interpret_attack_effects(
+            0,
+            &state,
+            &glameow.get_attacks()[0],
+            0,
+            &[
+                AttackEffect::CoinFlipExtraDamage {
+                    coins: 2,
+                    damage: 20,
+                },
+                AttackEffect::CoinFlipStatus(StatusCondition::Paralyzed),
+            ],
+        );
+        assert_eq!(probabilities.len(), 6);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_confusion_and_coin_flip_gates_share_flips() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let glameow = get_card_by_enum(CardId::A2139Glameow); // Pose: 40, tails does nothing
+        let mut attacker = to_playable_card(&glameow, false);
+        attacker.confused = true;
+        state.in_play_pokemon[0][0] = Some(attacker);
+        let receiver = get_card_by_enum(CardId::A1003Venusaur); // 160 hp
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+
+        assert_eq!(
+            attack_gates(0, &state, 0),
+            vec![AttackGate::Confusion, AttackGate::CoinFlipOrNothing]
+        );
+        let (probabilities, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        assert_eq!(probabilities, vec![0.5, 0.25, 0.25]);
+        for (heads, mutation) in lazy_mutations.drain(..2).enumerate() {
+            let mut failed_state = state.clone();
+            mutation(&mut rng, &mut failed_state, &action);
+            assert_eq!(failed_state.get_active(1).remaining_hp, 160);
+            assert_eq!(
+                failed_state.coin_flips,
+                vec![CoinFlips {
+                    flips: heads as u8 + 1,
+                    heads: heads as u8
+                }]
+            );
+            // The turn still ends
+            assert_eq!(
+                failed_state.move_generation_stack,
+                vec![(0, vec![SimpleAction::EndTurn])]
+            );
+        }
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 120);
+
+        // Confused Pokémon without other gates hit half of the time, keeping their outcomes
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut attacker = to_playable_card(&bulbasaur, false);
+        attacker.confused = true;
+        state.in_play_pokemon[0][0] = Some(attacker);
+        let (probabilities, _) = forecast_attack(0, &state, 0);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_data_driven_self_and_bench_effects() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let rotom = get_card_by_enum(CardId::A2048FrostRotom); // Blizzard: 30, 10 to each bench
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&rotom, false));
+        let receiver = get_card_by_enum(CardId::A1003Venusaur); // 160 hp
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+        let bench = get_card_by_enum(CardId::A1001Bulbasaur); // 70 hp
+        state.in_play_pokemon[1][2] = Some(to_playable_card(&bench, false));
+
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 130);
+        assert_eq!(state.get_remaining_hp(1, 2), 60);
+
+        let voltorb = get_card_by_enum(CardId::A2054Voltorb); // Big Explosion: 30, 10 to itself
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&voltorb, false));
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 100);
+        assert!(state.get_active(0).is_damaged());
+    }
+
+    #[test]
+    fn test_data_driven_attacker_effects() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(1),
+            is_stack: false,
+        };
+        // What the attacker can do when its player's turn comes again
+        let attacks_next_turn = |state: &mut State| {
+            state.turn_count += 2;
+            state.reset_turn_states();
+            state.move_generation_stack.clear();
+            let (_, actions) = generate_possible_actions(state);
+            actions
+                .into_iter()
+                .filter(|x| matches!(x.action, SimpleAction::Attack(_)))
+                .map(|x| x.action)
+                .collect::<Vec<_>>()
+        };
+        let grass = energy([Grass, Grass, Grass]);
+        // Big Beat: 120, can't be used again during the next turn
+        let mut state = ScenarioBuilder::new()
+            .active(0, CardId::A3a006BuzzwoleEx, [grass.clone()])
+            .active(1, CardId::A1003Venusaur, [])
+            .turn(3)
+            .build();
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 1);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 40);
+
+        assert_eq!(attacks_next_turn(&mut state), vec![SimpleAction::Attack(0)]);
+        assert_eq!(
+            attacks_next_turn(&mut state),
+            vec![SimpleAction::Attack(0), SimpleAction::Attack(1)]
+        );
+
+        // Giga Impact: 150, no attacks at all during the next turn
+        let mut state = ScenarioBuilder::new()
+            .active(0, CardId::A3a050Aggron, [energy([Metal; 4])])
+            .active(1, CardId::A1003Venusaur, [])
+            .turn(3)
+            .build();
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(attacks_next_turn(&mut state), vec![]);
+
+        // Collapse: 100, and the attacker falls Asleep
+        let mut state = ScenarioBuilder::new()
+            .active(0, CardId::A2a063Snorlax, [grass])
+            .active(1, CardId::A1003Venusaur, [])
+            .build();
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 60);
+        assert!(state.get_active(0).asleep);
+    }
+
+    #[test]
+    fn test_copy_attacks() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let act = |rng: &mut StdRng, state: &mut State, action: SimpleAction| {
+            let is_stack = !state.move_generation_stack.is_empty();
+            let action = Action {
+                actor: 0,
+                action,
+                is_stack,
+            };
+            crate::actions::apply_action(rng, state, &action);
+        };
+        // Genome Hacking copying Mega Drain: 80 and heal 30, as the Psychic attacker
+        let mut state = ScenarioBuilder::new()
+            .active(0, CardId::A1a032MewEx, [hp(50), energy([Grass; 3])])
+            .active(1, CardId::A1003Venusaur, [])
+            .turn(3)
+            .build();
+        act(&mut rng, &mut state, SimpleAction::Attack(1));
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(0, vec![SimpleAction::CopyAttack(0)])]
+        );
+        act(&mut rng, &mut state, SimpleAction::CopyAttack(0));
+        assert_eq!(state.get_active(1).remaining_hp, 80);
+        assert_eq!(state.get_active(0).remaining_hp, 80);
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(0, vec![SimpleAction::EndTurn])]
+        );
+
+        // Copy attacks can't be copied
+        let state = ScenarioBuilder::new()
+            .active(0, CardId::A1a032MewEx, [energy([Grass; 3])])
+            .active(1, CardId::A1a032MewEx, [])
+            .turn(3)
+            .build();
+        let mut copied = state.clone();
+        act(&mut rng, &mut copied, SimpleAction::Attack(1));
+        assert_eq!(
+            copied.move_generation_stack,
+            vec![(0, vec![SimpleAction::CopyAttack(0)])]
+        );
+
+        // Ditto can only copy attacks it has the Energy for, doing nothing otherwise
+        let mut state = ScenarioBuilder::from_state(state)
+            .active(0, CardId::A1205Ditto, [energy([Grass])])
+            .active(1, CardId::A1003Venusaur, [])
+            .build();
+        act(&mut rng, &mut state, SimpleAction::Attack(0));
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(0, vec![SimpleAction::EndTurn])]
+        );
+        assert_eq!(state.get_active(1).remaining_hp, 160);
+    }
+
     #[test]
     fn test_generate_energy_distributions() {
         // 1 pokemon, 1 head
@@ -830,10 +1356,7 @@ mod test {
     #[test]
     fn test_fixed_coin_probabilistic_attack() {
         // Test Jolteon Pin Missile (4 coins, 40 damage each)
-        let (probabilities, _mutations) = probabilistic_damage_attack(
-            vec![0.0625, 0.25, 0.375, 0.25, 0.0625],
-            vec![0, 40, 80, 120, 160],
-        );
+        let (probabilities, mut mutations) = fixed_coins_damage_attack(4, 0, 40);
 
         // Check we have 5 outcomes (0 to 4 heads)
         assert_eq!(probabilities.len(), 5);
@@ -844,6 +1367,21 @@ mod test {
         assert!((probabilities[2] - 0.375).abs() < 0.001); // 2 heads
         assert!((probabilities[3] - 0.25).abs() < 0.001); // 3 heads
         assert!((probabilities[4] - 0.0625).abs() < 0.001); // 4 heads
+
+        // The flips are recorded with the damage
+        let mut state = State::default();
+        let jolteon = get_card_by_enum(CardId::A1102Jolteon);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&jolteon, false));
+        let receiver = get_card_by_enum(CardId::A1003Venusaur); // 160 hp
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        mutations.remove(3)(&mut StdRng::seed_from_u64(0), &mut state, &action);
+        assert_eq!(state.get_active(1).remaining_hp, 40);
+        assert_eq!(state.coin_flips, vec![CoinFlips { flips: 4, heads: 3 }]);
     }
 
     #[test]
@@ -879,37 +1417,80 @@ mod test {
         assert!((probabilities_no_energy[0] - 1.0).abs() < 0.001);
     }
 
-    #[test]
-    fn test_binomial_coefficient() {
-        assert_eq!(binomial_coefficient(0, 0), 1);
-        assert_eq!(binomial_coefficient(1, 0), 1);
-        assert_eq!(binomial_coefficient(1, 1), 1);
-        assert_eq!(binomial_coefficient(2, 0), 1);
-        assert_eq!(binomial_coefficient(2, 1), 2);
-        assert_eq!(binomial_coefficient(2, 2), 1);
-        assert_eq!(binomial_coefficient(4, 2), 6);
-        assert_eq!(binomial_coefficient(5, 3), 10);
-        assert_eq!(binomial_coefficient(6, 2), 15);
-    }
-
     #[test]
     fn test_single_coin_attacks() {
-        // Test Ponyta Stomp (1 coin, 0 or 30 damage)
-        let (probabilities, _mutations) = probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 30]);
+        // Test Ponyta Stomp (1 coin, 10 or 40 damage)
+        let (probabilities, _mutations) = fixed_coins_damage_attack(1, 10, 30);
         assert_eq!(probabilities.len(), 2);
         assert!((probabilities[0] - 0.5).abs() < 0.001);
         assert!((probabilities[1] - 0.5).abs() < 0.001);
 
-        // Test Rapidash Rising Lunge (1 coin, 0 or 60 damage)
-        let (probabilities, _mutations) = probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 60]);
+        // Test Rapidash Rising Lunge (1 coin, 40 or 100 damage)
+        let (probabilities, _mutations) = fixed_coins_damage_attack(1, 40, 60);
         assert_eq!(probabilities.len(), 2);
         assert!((probabilities[0] - 0.5).abs() < 0.001);
         assert!((probabilities[1] - 0.5).abs() < 0.001);
 
         // Test Mankey Focus Fist (1 coin, 0 or 50 damage)
-        let (probabilities, _mutations) = probabilistic_damage_attack(vec![0.5, 0.5], vec![0, 50]);
+        let (probabilities, _mutations) = fixed_coins_damage_attack(1, 0, 50);
         assert_eq!(probabilities.len(), 2);
         assert!((probabilities[0] - 0.5).abs() < 0.001);
         assert!((probabilities[1] - 0.5).abs() < 0.001);
     }
+
+    #[test]
+    fn test_snipe_attacks_let_the_attacker_choose_the_target() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let attack = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false); // 70 hp
+        let zapdos = get_card_by_enum(CardId::A1103Zapdos); // Raging Thunder: 100, 30 to own bench
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&zapdos, false));
+        state.in_play_pokemon[0][1] = Some(bulbasaur.clone());
+        state.in_play_pokemon[0][2] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][1] = Some(bulbasaur);
+        state.in_play_pokemon[1][0].as_mut().unwrap().remaining_hp = 150;
+
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &attack);
+
+        let (actor, choices) = state.move_generation_stack.last().unwrap().clone();
+        assert_eq!(actor, 0);
+        let target = |in_play_idx| SimpleAction::ChooseDamageTarget {
+            player: 0,
+            in_play_idx,
+            damage: 30,
+        };
+        assert_eq!(choices, vec![target(1), target(2)]);
+
+        let choice = Action {
+            actor: 0,
+            action: target(2),
+            is_stack: true,
+        };
+        crate::actions::apply_action(&mut rng, &mut state, &choice);
+        assert_eq!(state.get_active(1).remaining_hp, 50);
+        assert_eq!(
+            state.in_play_pokemon[0][1].as_ref().unwrap().remaining_hp,
+            70
+        );
+        assert_eq!(
+            state.in_play_pokemon[0][2].as_ref().unwrap().remaining_hp,
+            40
+        );
+        assert_eq!(
+            state.in_play_pokemon[1][1].as_ref().unwrap().remaining_hp,
+            70
+        );
+        // Only the attack's EndTurn is left
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(0, vec![SimpleAction::EndTurn])]
+        );
+    }
 }
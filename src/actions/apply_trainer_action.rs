@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 use log::debug;
-use rand::rngs::StdRng;
+use rand::{rngs::StdRng, seq::SliceRandom};
 
 use crate::{
     card_ids::CardId,
@@ -24,7 +26,7 @@ pub fn forecast_trainer_action(
         CardId::from_numeric_id(trainer_card.numeric_id).expect("CardId should be known");
     match trainer_id {
         CardId::PA001Potion => deterministic(potion_effect),
-        CardId::PA002XSpeed => deterministic(turn_effect),
+        CardId::PA002XSpeed => deterministic(active_effect),
         CardId::PA005PokeBall => pokeball_outcomes(acting_player, state),
         CardId::PA006RedCard => deterministic(red_card_effect),
         CardId::PA007ProfessorsResearch => deterministic(professor_oak_effect),
@@ -34,9 +36,19 @@ pub fn forecast_trainer_action(
         CardId::A1223Giovanni | CardId::A1270Giovanni => deterministic(giovanni_effect),
         CardId::A1225Sabrina | CardId::A1272Sabrina => deterministic(sabrina_effect),
         CardId::A1a065MythicalSlab => deterministic(mythical_slab_effect),
-        CardId::A1a068Leaf | CardId::A1a082Leaf => deterministic(turn_effect),
+        CardId::A1a068Leaf | CardId::A1a082Leaf => deterministic(active_effect),
+        CardId::A2146PokemonCommunication => deterministic(pokemon_communication_effect),
         CardId::A2150Cyrus | CardId::A2190Cyrus => deterministic(cyrus_effect),
-        CardId::A2147GiantCape => deterministic(attach_tool),
+        CardId::A2151TeamGalacticGrunt | CardId::A2191TeamGalacticGrunt => {
+            deterministic(team_galactic_grunt_effect)
+        }
+        CardId::A2152Cynthia | CardId::A2192Cynthia => deterministic(turn_effect),
+        CardId::A2153Volkner | CardId::A2193Volkner => deterministic(volkner_effect),
+        CardId::A2154Dawn | CardId::A2194Dawn => deterministic(dawn_effect),
+        CardId::A2155Mars | CardId::A2195Mars => deterministic(mars_effect),
+        CardId::A2147GiantCape | CardId::A2148RockyHelmet | CardId::A2149LumBerry => {
+            deterministic(attach_tool)
+        }
         _ => panic!("Unsupported Trainer Card"),
     }
 }
@@ -124,7 +136,7 @@ fn pokeball_outcomes(acting_player: usize, state: &State) -> (Probabilities, Mut
         deterministic({
             |rng, state, action| {
                 // If there are no basic Pokemon in the deck, just shuffle it
-                state.decks[action.actor].shuffle(false, rng);
+                state.deck_mut(action.actor).shuffle(false, rng);
             }
         })
     } else {
@@ -144,7 +156,7 @@ fn pokeball_outcomes(acting_player: usize, state: &State) -> (Probabilities, Mut
                         .expect("Should be a basic card");
 
                     // Put 1 random Basic Pokemon from your deck into your hand.
-                    let deck = &mut state.decks[action.actor];
+                    let deck = Arc::make_mut(&mut state.decks[action.actor]);
                     // Select a random one
                     debug!("Pokeball selected card: {:?}", card);
                     // Add it to hand and remove one of it from deck
@@ -164,9 +176,19 @@ fn pokeball_outcomes(acting_player: usize, state: &State) -> (Probabilities, Mut
 }
 
 // Remember to implement these in the main controller / hooks.
+// For cards that only affect the Active Pokémon during this turn
+fn active_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    if let SimpleAction::Play { trainer_card } = &action.action {
+        let card = Card::Trainer(trainer_card);
+        state.add_pokemon_effect(action.actor, 0, card, 0);
+    } else {
+        panic!("Something went wrong. An action was played but couldnt get the card");
+    }
+}
+
 fn turn_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let card = Card::Trainer(trainer_card.clone());
+        let card = Card::Trainer(trainer_card);
         state.add_turn_effect(card, 0);
     } else {
         panic!("Something went wrong. An action was played but couldnt get the card");
@@ -198,10 +220,124 @@ fn cyrus_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         .push((opponent_player, possible_moves));
 }
 
+fn team_galactic_grunt_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
+    // Put 1 random Glameow, Stunky, or Croagunk from your deck into your hand.
+    let candidates = state.decks[action.actor]
+        .cards
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| ["Glameow", "Stunky", "Croagunk"].contains(&x.get_name().as_str()))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if let Some(&i) = candidates.choose(rng) {
+        let card = state.deck_mut(action.actor).cards.remove(i);
+        debug!("Team Galactic Grunt selected card: {:?}", card);
+        state.hands[action.actor].push(card);
+    }
+    state.deck_mut(action.actor).shuffle(false, rng);
+}
+
+fn volkner_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Choose 1 of your Electivire or Luxray. Attach 2 [L] Energy from your discard pile to
+    // that Pokémon.
+    let amount = state.take_discarded_energy(action.actor, EnergyType::Lightning, 2);
+    let possible_moves = state
+        .enumerate_in_play_pokemon(action.actor)
+        .filter(|(_, x)| ["Electivire", "Luxray"].contains(&x.get_name().as_str()))
+        .map(|(in_play_idx, _)| SimpleAction::Attach {
+            attachments: vec![(amount, EnergyType::Lightning, in_play_idx)],
+            is_turn_energy: false,
+        })
+        .collect::<Vec<_>>();
+    state
+        .move_generation_stack
+        .push((action.actor, possible_moves));
+}
+
+fn dawn_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Move an Energy from 1 of your Benched Pokémon to your Active Pokémon.
+    let mut possible_moves = vec![];
+    for (from_in_play_idx, pokemon) in state.enumerate_bench_pokemon(action.actor) {
+        let mut energy_types = pokemon.attached_energy.clone();
+        energy_types.sort();
+        energy_types.dedup();
+        possible_moves.extend(
+            energy_types
+                .into_iter()
+                .map(|energy| SimpleAction::MoveEnergy {
+                    from_in_play_idx,
+                    to_in_play_idx: 0,
+                    energy,
+                }),
+        );
+    }
+    state
+        .move_generation_stack
+        .push((action.actor, possible_moves));
+}
+
+fn mars_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
+    // Your opponent shuffles their hand into their deck and draws a card for each of their
+    // remaining points needed to win.
+    let opponent = (action.actor + 1) % 2;
+    let hand = std::mem::take(&mut state.hands[opponent]);
+    let opponent_deck = state.deck_mut(opponent);
+    opponent_deck.cards.extend(hand);
+    opponent_deck.shuffle(false, rng);
+    let remaining_points = state
+        .rules
+        .points_to_win
+        .saturating_sub(state.points[opponent]);
+    for _ in 0..remaining_points {
+        state.queue_draw_action(opponent);
+    }
+}
+
+fn pokemon_communication_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Choose a Pokémon in your hand and switch it with a random Pokémon in your deck.
+    let mut possible_moves = vec![];
+    for card in &state.hands[action.actor] {
+        let choice = SimpleAction::SwapWithDeck(card.clone());
+        if matches!(card, Card::Pokemon(_)) && !possible_moves.contains(&choice) {
+            possible_moves.push(choice);
+        }
+    }
+    state
+        .move_generation_stack
+        .push((action.actor, possible_moves));
+}
+
+/// Pokémon Communication's swap. Which Pokémon comes out of the deck (if there is any) is
+/// only decided once applied, so bots can't see the deck through it.
+pub(crate) fn forecast_swap_with_deck(card: Card) -> (Probabilities, Mutations) {
+    (
+        vec![1.0],
+        vec![Box::new(move |rng, state, action| {
+            apply_common_mutation(state, action);
+            let pokemon_in_deck = state.decks[action.actor]
+                .cards
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| matches!(x, Card::Pokemon(_)))
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            if let Some(&i) = pokemon_in_deck.choose(rng) {
+                let deck = state.deck_mut(action.actor);
+                let drawn = deck.cards.remove(i);
+                deck.cards.push(card.clone());
+                state.remove_card_from_hand(action.actor, &card);
+                state.hands[action.actor].push(drawn);
+            }
+            state.deck_mut(action.actor).shuffle(false, rng);
+        })],
+    )
+}
+
 fn giovanni_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     if let SimpleAction::Play { trainer_card } = &action.action {
         // During this turn, attacks used by your Pokémon do +10 damage to your opponent's Active Pokémon.
-        let card = Card::Trainer(trainer_card.clone());
+        // A turn effect rather than one on each Pokémon, as it also covers those played later.
+        let card = Card::Trainer(trainer_card);
         state.add_turn_effect(card, 0);
     } else {
         panic!("XSpeed should be played");
@@ -252,10 +388,10 @@ fn mythical_slab_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     if let Some(card) = state.decks[action.actor].cards.first() {
         if card.is_basic() {
             state.hands[action.actor].push(card.clone());
-            state.decks[action.actor].cards.remove(0);
+            state.deck_mut(action.actor).cards.remove(0);
         } else {
-            let card = state.decks[action.actor].cards.remove(0);
-            state.decks[action.actor].cards.push(card);
+            let card = state.deck_mut(action.actor).cards.remove(0);
+            state.deck_mut(action.actor).cards.push(card);
         }
     } // else do nothing
 }
@@ -267,7 +403,7 @@ fn red_card_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     let acting_player = action.actor;
     let opponent = (acting_player + 1) % 2;
     let opponent_hand = &mut state.hands[opponent];
-    let opponent_deck = &mut state.decks[opponent];
+    let opponent_deck = Arc::make_mut(&mut state.decks[opponent]);
     opponent_deck.cards.append(opponent_hand);
     opponent_deck.shuffle(false, rng);
     for _ in 0..3 {
@@ -292,3 +428,168 @@ fn attach_tool(_: &mut StdRng, state: &mut State, action: &Action) {
         panic!("Tool should have been played");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        actions::apply_action, database::get_card_by_enum, hooks::to_playable_card,
+        test_helpers::load_test_decks,
+    };
+
+    // Player 0 plays the trainer card from their hand and, if it asks them for a choice,
+    // makes the first one.
+    fn play(state: &mut State, card_id: CardId) {
+        let card = get_card_by_enum(card_id);
+        let Card::Trainer(trainer_card) = card else {
+            panic!("Expected trainer card");
+        };
+        state.hands[0].push(card.clone());
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Play { trainer_card },
+            is_stack: false,
+        };
+        let stack_size = state.move_generation_stack.len();
+        apply_action(&mut rng, state, &action);
+        let (actor, choices) = state
+            .move_generation_stack
+            .last()
+            .cloned()
+            .unwrap_or_default();
+        if state.move_generation_stack.len() > stack_size && actor == 0 {
+            let action = Action {
+                actor,
+                action: choices[0].clone(),
+                is_stack: true,
+            };
+            apply_action(&mut rng, state, &action);
+        }
+    }
+
+    fn state_with_bulbasaurs() -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 3;
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[0][1] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur);
+        state
+    }
+
+    #[test]
+    fn test_dawn_moves_bench_energy_to_active() {
+        let mut state = state_with_bulbasaurs();
+        state.in_play_pokemon[0][1]
+            .as_mut()
+            .unwrap()
+            .attached_energy = vec![EnergyType::Grass];
+        play(&mut state, CardId::A2154Dawn);
+        assert_eq!(state.get_active(0).attached_energy, vec![EnergyType::Grass]);
+        assert!(state.in_play_pokemon[0][1]
+            .as_ref()
+            .unwrap()
+            .attached_energy
+            .is_empty());
+        assert!(state.has_played_support);
+    }
+
+    #[test]
+    fn test_volkner_attaches_discarded_lightning_energy() {
+        let mut state = state_with_bulbasaurs();
+        let electivire = get_card_by_enum(CardId::A2057Electivire);
+        state.in_play_pokemon[0][2] = Some(to_playable_card(&electivire, false));
+        state.discarded_energy[0].insert(EnergyType::Lightning, 3);
+        play(&mut state, CardId::A2153Volkner);
+        assert_eq!(
+            state.in_play_pokemon[0][2]
+                .as_ref()
+                .unwrap()
+                .attached_energy,
+            vec![EnergyType::Lightning; 2]
+        );
+        assert_eq!(state.discarded_energy[0][&EnergyType::Lightning], 1);
+    }
+
+    #[test]
+    fn test_mars_draws_remaining_points() {
+        let mut state = state_with_bulbasaurs();
+        state.hands[1] = state.deck_mut(1).cards.drain(..5).collect();
+        state.points[1] = 1;
+        play(&mut state, CardId::A2155Mars);
+        let draws = state
+            .move_generation_stack
+            .iter()
+            .filter(|(actor, choices)| *actor == 1 && choices == &vec![SimpleAction::DrawCard])
+            .count();
+        assert_eq!(state.hands[1].len(), 0);
+        assert_eq!(state.decks[1].cards.len(), 20);
+        assert_eq!(draws, 2);
+    }
+
+    #[test]
+    fn test_red_card_reduces_a_large_hand_to_three_cards() {
+        let mut state = state_with_bulbasaurs();
+        state.hands[1] = state.deck_mut(1).cards.drain(..9).collect();
+        play(&mut state, CardId::PA006RedCard);
+        assert_eq!(state.hands[1].len(), 0);
+        assert_eq!(state.decks[1].cards.len(), 20);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        while let Some((1, choices)) = state.move_generation_stack.last().cloned() {
+            assert_eq!(choices, vec![SimpleAction::DrawCard]);
+            let action = Action {
+                actor: 1,
+                action: SimpleAction::DrawCard,
+                is_stack: true,
+            };
+            apply_action(&mut rng, &mut state, &action);
+        }
+        assert_eq!(state.hands[1].len(), 3);
+        assert_eq!(state.decks[1].cards.len(), 17);
+    }
+
+    #[test]
+    fn test_team_galactic_grunt_fetches_listed_pokemon() {
+        let mut state = state_with_bulbasaurs();
+        let glameow = get_card_by_enum(CardId::A2139Glameow);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.deck_mut(0).cards = vec![bulbasaur.clone(), glameow.clone(), bulbasaur];
+        play(&mut state, CardId::A2151TeamGalacticGrunt);
+        assert_eq!(state.hands[0], vec![glameow]);
+        assert_eq!(state.decks[0].cards.len(), 2);
+    }
+
+    #[test]
+    fn test_pokemon_communication_switches_with_deck_pokemon() {
+        let mut state = state_with_bulbasaurs();
+        let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let potion = get_card_by_enum(CardId::PA001Potion);
+        state.deck_mut(0).cards = vec![potion.clone(), squirtle.clone()];
+        state.hands[0] = vec![bulbasaur.clone()];
+        play(&mut state, CardId::A2146PokemonCommunication);
+        assert_eq!(state.hands[0], vec![squirtle]);
+        let mut deck = state.decks[0].cards.clone();
+        deck.sort_by_key(|x| x.get_id());
+        assert_eq!(deck, vec![bulbasaur, potion]);
+    }
+
+    #[test]
+    fn test_cynthia_boosts_garchomp() {
+        let mut state = state_with_bulbasaurs();
+        let garchomp = get_card_by_enum(CardId::A2123Garchomp);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&garchomp, false));
+        let damage = |state: &State| {
+            let attack = &state.get_active(0).get_attacks()[0];
+            crate::hooks::get_damage_from_attack(state, 0, attack, 0)
+        };
+        let base_damage = damage(&state);
+        play(&mut state, CardId::A2152Cynthia);
+        assert_eq!(damage(&state), base_damage + 50);
+    }
+}
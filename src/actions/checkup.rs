@@ -0,0 +1,406 @@
+use log::debug;
+use rand::rngs::StdRng;
+use serde::Serialize;
+
+use crate::{tool_ids::ToolId, types::PlayedCard, State};
+
+use super::{
+    apply_action_helpers::{apply_common_mutation, handle_knockouts, Mutations, Probabilities},
+    CoinFlips,
+};
+
+pub(crate) const POISON_DAMAGE: u32 = 10;
+pub(crate) const BURN_DAMAGE: u32 = 20;
+
+/// Something that happened to a Pokémon during the Pokémon Checkup between turns, in the
+/// order it happened (see `State.checkup_events`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum CheckupEvent {
+    /// A Lum Berry cured all Special Conditions at the end of the turn and was discarded.
+    LumBerryUsed { player: usize, in_play_idx: usize },
+    PoisonDamage {
+        player: usize,
+        in_play_idx: usize,
+        damage: u32,
+    },
+    BurnDamage {
+        player: usize,
+        in_play_idx: usize,
+        damage: u32,
+    },
+    /// Burned Pokémon flip a coin after taking damage, recovering on heads.
+    BurnFlip {
+        player: usize,
+        in_play_idx: usize,
+        recovered: bool,
+    },
+    /// Asleep Pokémon flip a coin at every Checkup, waking up on heads.
+    SleepFlip {
+        player: usize,
+        in_play_idx: usize,
+        woke_up: bool,
+    },
+    /// Paralysis wears off at the end of the affected player's turn.
+    ParalysisRecovered { player: usize, in_play_idx: usize },
+}
+
+/// The Pokémon affected by each Special Condition when the Checkup starts, as
+/// (player, in_play_idx). Each step runs over all of them before the next one starts.
+#[derive(Debug, Clone, Default)]
+struct Checkup {
+    lum_berries: Vec<(usize, usize)>,
+    poisoned: Vec<(usize, usize)>,
+    burned: Vec<(usize, usize)>,
+    asleep: Vec<(usize, usize)>,
+    paralyzed: Vec<(usize, usize)>,
+}
+
+impl Checkup {
+    fn new(state: &State) -> Self {
+        let mut checkup = Checkup::default();
+        for player in 0..2 {
+            for (i, pokemon) in state.enumerate_in_play_pokemon(player) {
+                // Lum Berry goes off before the Checkup, so it has nothing left to do
+                if pokemon.attached_tool == Some(ToolId::A2149LumBerry)
+                    && pokemon.has_special_condition()
+                {
+                    checkup.lum_berries.push((player, i));
+                    continue;
+                }
+                if pokemon.poisoned {
+                    checkup.poisoned.push((player, i));
+                }
+                if pokemon.burned {
+                    checkup.burned.push((player, i));
+                }
+                if pokemon.asleep {
+                    checkup.asleep.push((player, i));
+                }
+                // Paralysis lasts until the end of its owner's next turn
+                if pokemon.paralyzed && player == state.current_player {
+                    checkup.paralyzed.push((player, i));
+                }
+            }
+        }
+        checkup
+    }
+
+    // Burn flips come first, then sleep flips
+    fn num_flips(&self) -> usize {
+        self.burned.len() + self.asleep.len()
+    }
+}
+
+/// Pokémon Checkup: Lum Berries cure the Pokémon they are attached to, then poisoned Pokémon
+/// take damage, burned ones take damage and flip to recover, asleep ones flip to wake up and
+/// the paralyzed ones of the player whose turn ends recover, in that order. Knock Outs are
+/// handled once all of it is done, and then the next turn starts.
+pub(crate) fn forecast_pokemon_checkup(state: &State) -> (Probabilities, Mutations) {
+    let checkup = Checkup::new(state);
+
+    // Every combination of coin flips is an outcome (e.g. [true, false] might represent
+    // a burned Pokémon recovering and an asleep one not waking up).
+    let outcome_ids = generate_boolean_vectors(checkup.num_flips());
+    let probabilities = vec![1.0 / outcome_ids.len() as f64; outcome_ids.len()];
+    let mut outcomes: Mutations = vec![];
+    for outcome in outcome_ids {
+        let checkup = checkup.clone();
+        outcomes.push(Box::new({
+            move |rng, state, action| {
+                apply_common_mutation(state, action);
+                apply_pokemon_checkup(rng, state, &checkup, &outcome);
+            }
+        }));
+    }
+    (probabilities, outcomes)
+}
+
+fn apply_pokemon_checkup(rng: &mut StdRng, state: &mut State, checkup: &Checkup, outcome: &[bool]) {
+    let mut events = vec![];
+    for &(player, in_play_idx) in &checkup.lum_berries {
+        let pokemon = get_pokemon(state, player, in_play_idx);
+        pokemon.cure_special_conditions();
+        pokemon.attached_tool = None;
+        debug!("{}'s Pokemon {} used its Lum Berry", player, in_play_idx);
+        events.push(CheckupEvent::LumBerryUsed {
+            player,
+            in_play_idx,
+        });
+    }
+    for &(player, in_play_idx) in &checkup.poisoned {
+        let pokemon = get_pokemon(state, player, in_play_idx);
+        pokemon.apply_damage(POISON_DAMAGE);
+        debug!("{}'s Pokemon {} took poison damage", player, in_play_idx);
+        events.push(CheckupEvent::PoisonDamage {
+            player,
+            in_play_idx,
+            damage: POISON_DAMAGE,
+        });
+    }
+
+    let (burn_flips, sleep_flips) = outcome.split_at(checkup.burned.len());
+    for (&(player, in_play_idx), &recovered) in checkup.burned.iter().zip(burn_flips) {
+        let pokemon = get_pokemon(state, player, in_play_idx);
+        pokemon.apply_damage(BURN_DAMAGE);
+        events.push(CheckupEvent::BurnDamage {
+            player,
+            in_play_idx,
+            damage: BURN_DAMAGE,
+        });
+        if recovered {
+            pokemon.burned = false;
+            debug!("{}'s Pokemon {} is no longer burned", player, in_play_idx);
+        }
+        events.push(CheckupEvent::BurnFlip {
+            player,
+            in_play_idx,
+            recovered,
+        });
+    }
+    for (&(player, in_play_idx), &woke_up) in checkup.asleep.iter().zip(sleep_flips) {
+        if woke_up {
+            get_pokemon(state, player, in_play_idx).asleep = false;
+            debug!("{}'s Pokemon {} woke up", player, in_play_idx);
+        }
+        events.push(CheckupEvent::SleepFlip {
+            player,
+            in_play_idx,
+            woke_up,
+        });
+    }
+    for &(player, in_play_idx) in &checkup.paralyzed {
+        get_pokemon(state, player, in_play_idx).paralyzed = false;
+        debug!("{}'s Pokemon {} is un-paralyzed", player, in_play_idx);
+        events.push(CheckupEvent::ParalysisRecovered {
+            player,
+            in_play_idx,
+        });
+    }
+    if !outcome.is_empty() {
+        state.coin_flips.push(CoinFlips {
+            flips: outcome.len() as u8,
+            heads: outcome.iter().filter(|x| **x).count() as u8,
+        });
+    }
+    state.checkup_events = events;
+
+    let mut knockouts = checkup
+        .poisoned
+        .iter()
+        .chain(&checkup.burned)
+        .copied()
+        .filter(|&(player, in_play_idx)| state.get_remaining_hp(player, in_play_idx) == 0)
+        .collect::<Vec<_>>();
+    knockouts.sort();
+    knockouts.dedup();
+    if !knockouts.is_empty() {
+        handle_knockouts(state, state.current_player, knockouts);
+    }
+
+    state.advance_turn(rng);
+}
+
+fn get_pokemon(state: &mut State, player: usize, in_play_idx: usize) -> &mut PlayedCard {
+    state.in_play_pokemon[player][in_play_idx]
+        .as_mut()
+        .expect("Pokemon should be there...")
+}
+
+fn generate_boolean_vectors(n: usize) -> Vec<Vec<bool>> {
+    // The total number of combinations is 2^n
+    let total_combinations = 1 << n; // 2^n
+
+    // Generate all combinations
+    (0..total_combinations)
+        .map(|i| {
+            // Convert the number `i` to its binary representation as a vector of booleans
+            (0..n).map(|bit| (i & (1 << bit)) != 0).collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        actions::{apply_action::forecast_action, Action, SimpleAction},
+        card_ids::CardId,
+        database::get_card_by_enum,
+        hooks::to_playable_card,
+        test_helpers::load_test_decks,
+    };
+
+    const END_TURN: Action = Action {
+        actor: 0,
+        action: SimpleAction::EndTurn,
+        is_stack: false,
+    };
+
+    // Bulbasaurs (70 HP) as Active Pokémon, and one on player 1's Bench
+    fn checkup_state() -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 3;
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][1] = Some(bulbasaur);
+        state
+    }
+
+    fn apply_outcome(state: &State, outcome: usize) -> State {
+        let (_, mut mutations) = forecast_action(state, &END_TURN);
+        let mut state = state.clone();
+        mutations.remove(outcome)(&mut StdRng::seed_from_u64(0), &mut state, &END_TURN);
+        state
+    }
+
+    #[test]
+    fn test_conditions_are_checked_in_order() {
+        let mut state = checkup_state();
+        let active = state.get_active_mut(1);
+        active.poisoned = true;
+        active.burned = true;
+        active.asleep = true;
+        state.get_active_mut(0).paralyzed = true;
+
+        // Outcome 1 is heads on the burn flip (the first bit) and tails on the sleep flip
+        let state = apply_outcome(&state, 1);
+        let (player, in_play_idx) = (1, 0);
+        assert_eq!(
+            state.checkup_events,
+            vec![
+                CheckupEvent::PoisonDamage {
+                    player,
+                    in_play_idx,
+                    damage: POISON_DAMAGE,
+                },
+                CheckupEvent::BurnDamage {
+                    player,
+                    in_play_idx,
+                    damage: BURN_DAMAGE,
+                },
+                CheckupEvent::BurnFlip {
+                    player,
+                    in_play_idx,
+                    recovered: true,
+                },
+                CheckupEvent::SleepFlip {
+                    player,
+                    in_play_idx,
+                    woke_up: false,
+                },
+                CheckupEvent::ParalysisRecovered {
+                    player: 0,
+                    in_play_idx: 0,
+                },
+            ]
+        );
+        let active = state.get_active(1);
+        assert_eq!(active.remaining_hp, 70 - POISON_DAMAGE - BURN_DAMAGE);
+        assert!(active.poisoned && !active.burned && active.asleep);
+        assert!(!state.get_active(0).paralyzed);
+        assert_eq!(state.coin_flips, vec![CoinFlips { flips: 2, heads: 1 }]);
+        assert_eq!(state.turn_count, 4);
+    }
+
+    #[test]
+    fn test_each_flip_doubles_the_outcomes() {
+        let mut state = checkup_state();
+        let (probabilities, _) = forecast_action(&state, &END_TURN);
+        assert_eq!(probabilities, vec![1.0]);
+
+        state.get_active_mut(1).burned = true;
+        state.in_play_pokemon[1][1].as_mut().unwrap().burned = true;
+        state.get_active_mut(0).asleep = true;
+        let (probabilities, _) = forecast_action(&state, &END_TURN);
+        assert_eq!(probabilities, vec![0.125; 8]);
+    }
+
+    #[test]
+    fn test_burn_knocks_out_bench_pokemon() {
+        let mut state = checkup_state();
+        let benched = state.in_play_pokemon[1][1].as_mut().unwrap();
+        benched.burned = true;
+        benched.remaining_hp = BURN_DAMAGE;
+
+        let state = apply_outcome(&state, 0);
+        assert!(state.in_play_pokemon[1][1].is_none());
+        assert_eq!(state.points, [1, 0]);
+        assert_eq!(state.discard_piles[1].len(), 1);
+    }
+
+    #[test]
+    fn test_paralysis_lasts_through_the_afflicted_players_turn() {
+        // Player 0 paralyzed player 1's Active Pokémon during its turn
+        let mut state = checkup_state();
+        state.get_active_mut(1).paralyzed = true;
+
+        let state = apply_outcome(&state, 0);
+        assert!(state.get_active(1).paralyzed);
+        assert!(state.checkup_events.is_empty());
+        assert_eq!(state.current_player, 1);
+
+        // It wears off at the end of player 1's turn
+        let end_turn = Action {
+            actor: 1,
+            ..END_TURN
+        };
+        let (_, mut mutations) = forecast_action(&state, &end_turn);
+        let mut state = state.clone();
+        mutations.remove(0)(&mut StdRng::seed_from_u64(0), &mut state, &end_turn);
+        assert!(!state.get_active(1).paralyzed);
+        assert_eq!(
+            state.checkup_events,
+            vec![CheckupEvent::ParalysisRecovered {
+                player: 1,
+                in_play_idx: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sleep_is_flipped_at_every_checkup() {
+        // Asleep right after being put to sleep, and again after its owner's turn
+        for current_player in 0..2 {
+            let mut state = checkup_state();
+            state.current_player = current_player;
+            state.get_active_mut(1).asleep = true;
+            let (probabilities, _) = forecast_action(&state, &END_TURN);
+            assert_eq!(probabilities, vec![0.5, 0.5]);
+            assert!(apply_outcome(&state, 0).get_active(1).asleep);
+            assert!(!apply_outcome(&state, 1).get_active(1).asleep);
+        }
+    }
+
+    #[test]
+    fn test_lum_berry_cures_before_the_checkup() {
+        let mut state = checkup_state();
+        let bench = state.in_play_pokemon[1][1].as_mut().unwrap();
+        bench.poisoned = true;
+        bench.asleep = true;
+        bench.attached_tool = Some(ToolId::A2149LumBerry);
+        state.in_play_pokemon[1][0].as_mut().unwrap().attached_tool = Some(ToolId::A2149LumBerry); // Healthy, so it keeps its berry
+
+        let (probabilities, _) = forecast_action(&state, &END_TURN);
+        assert_eq!(probabilities, vec![1.0]);
+        let state = apply_outcome(&state, 0);
+        let bench = state.in_play_pokemon[1][1].as_ref().unwrap();
+        assert!(!bench.has_special_condition());
+        assert_eq!(bench.remaining_hp, 70);
+        assert_eq!(bench.attached_tool, None);
+        assert_eq!(
+            state.get_active(1).attached_tool,
+            Some(ToolId::A2149LumBerry)
+        );
+        assert_eq!(
+            state.checkup_events,
+            vec![CheckupEvent::LumBerryUsed {
+                player: 1,
+                in_play_idx: 1
+            }]
+        );
+    }
+}
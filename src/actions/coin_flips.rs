@@ -0,0 +1,110 @@
+use super::apply_action_helpers::Mutation;
+
+/// Coins flipped as part of resolving an action. Only counts are kept (not the order),
+/// since forecasts collapse outcomes with the same number of heads into one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CoinFlips {
+    pub flips: u8,
+    pub heads: u8,
+}
+
+impl CoinFlips {
+    pub fn tails(&self) -> u8 {
+        self.flips - self.heads
+    }
+}
+
+/// Builds the possible outcomes of flipping coins, indexed by number of heads, so that
+/// forecast functions don't hand-roll coin probabilities.
+pub(crate) struct CoinFlipper;
+
+impl CoinFlipper {
+    /// Flipping a fixed number of coins (binomial distribution).
+    pub(crate) fn fixed(coins: u8) -> Vec<(f64, CoinFlips)> {
+        let num_outcomes = 2_u64.pow(coins as u32) as f64;
+        (0..=coins)
+            .map(|heads| {
+                let probability = binomial_coefficient(coins as u64, heads as u64) as f64;
+                (
+                    probability / num_outcomes,
+                    CoinFlips {
+                        flips: coins,
+                        heads,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Flipping until tails, truncated at `max_heads` (so probabilities add up to a bit less than 1).
+    pub(crate) fn until_tails(max_heads: u8) -> Vec<(f64, CoinFlips)> {
+        (0..=max_heads)
+            .map(|heads| {
+                (
+                    0.5_f64.powi(heads as i32 + 1),
+                    CoinFlips {
+                        flips: heads + 1,
+                        heads,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+/// Wraps a mutation so that it records the flips that led to it in `State.coin_flips`.
+pub(crate) fn recording_flips(flips: CoinFlips, mutation: Mutation) -> Mutation {
+    Box::new(move |rng, state, action| {
+        state.coin_flips.push(flips);
+        mutation(rng, state, action);
+    })
+}
+
+fn binomial_coefficient(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    if k == 0 || k == n {
+        return 1;
+    }
+
+    let mut result = 1;
+    for i in 0..k {
+        result = result * (n - i) / (i + 1);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_flips() {
+        let outcomes = CoinFlipper::fixed(2);
+        let probabilities: Vec<f64> = outcomes.iter().map(|(p, _)| *p).collect();
+        assert_eq!(probabilities, vec![0.25, 0.5, 0.25]);
+        assert_eq!(outcomes[2].1, CoinFlips { flips: 2, heads: 2 });
+        assert_eq!(outcomes[0].1.tails(), 2);
+    }
+
+    #[test]
+    fn test_until_tails_flips() {
+        let outcomes = CoinFlipper::until_tails(2);
+        assert_eq!(outcomes[0], (0.5, CoinFlips { flips: 1, heads: 0 }));
+        assert_eq!(outcomes[2], (0.125, CoinFlips { flips: 3, heads: 2 }));
+    }
+
+    #[test]
+    fn test_binomial_coefficient() {
+        assert_eq!(binomial_coefficient(0, 0), 1);
+        assert_eq!(binomial_coefficient(1, 0), 1);
+        assert_eq!(binomial_coefficient(1, 1), 1);
+        assert_eq!(binomial_coefficient(2, 0), 1);
+        assert_eq!(binomial_coefficient(2, 1), 2);
+        assert_eq!(binomial_coefficient(2, 2), 1);
+        assert_eq!(binomial_coefficient(4, 2), 6);
+        assert_eq!(binomial_coefficient(5, 3), 10);
+        assert_eq!(binomial_coefficient(6, 2), 15);
+    }
+}
@@ -0,0 +1,97 @@
+use rand::rngs::StdRng;
+
+use crate::State;
+
+use super::{apply_action::forecast_action, Action};
+
+/// One way an action can play out, see `forecast`.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub probability: f64,
+    /// The state right after the action, as `apply_action` would leave it.
+    pub state: State,
+}
+
+/// Probabilities of the ways the action can play out (e.g. heads and tails of a coin flip),
+/// adding up to 1. Cheap, since no outcome is built. The action should be one of
+/// `generate_possible_actions(state)`.
+pub fn outcome_probabilities(state: &State, action: &Action) -> Vec<f64> {
+    forecast_action(state, action).0
+}
+
+/// Every way the action can play out, with its probability, leaving `state` untouched, for
+/// players that weigh the risks of their options (e.g. an attack that only hits on heads)
+/// instead of sampling a single result.
+/// Outcomes that can't happen (probability 0) are left out. Randomness that isn't broken
+/// down into outcomes, like the order of a shuffled deck, is drawn from `rng`. The action
+/// should be one of `generate_possible_actions(state)`.
+pub fn forecast(rng: &mut StdRng, state: &State, action: &Action) -> Vec<Outcome> {
+    let (probabilities, mutations) = forecast_action(state, action);
+    probabilities
+        .into_iter()
+        .zip(mutations)
+        .filter(|(probability, _)| *probability > 0.0)
+        .map(|(probability, mutation)| {
+            let mut state = state.clone();
+            mutation(rng, &mut state, action);
+            Outcome { probability, state }
+        })
+        .collect()
+}
+
+/// Average of `value` over the outcomes of the action, weighted by their probabilities.
+pub fn expected_value(
+    rng: &mut StdRng,
+    state: &State,
+    action: &Action,
+    mut value: impl FnMut(&State) -> f64,
+) -> f64 {
+    forecast(rng, state, action)
+        .iter()
+        .map(|outcome| value(&outcome.state) * outcome.probability)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        actions::SimpleAction,
+        card_ids::CardId,
+        test_helpers::{energy, ScenarioBuilder},
+        types::EnergyType::Colorless,
+    };
+
+    #[test]
+    fn test_forecast_coin_flip_attack() {
+        // Pose: 40 damage on heads, nothing on tails
+        let state = ScenarioBuilder::new()
+            .active(0, CardId::A2139Glameow, [energy([Colorless; 2])])
+            .active(1, CardId::A1003Venusaur, [])
+            .turn(3)
+            .build();
+        let action = Action {
+            actor: 0,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        let before = state.clone();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(outcome_probabilities(&state, &action), vec![0.5, 0.5]);
+        let outcomes = forecast(&mut rng, &state, &action);
+        let damage: Vec<u32> = outcomes
+            .iter()
+            .map(|x| x.state.get_active(1).damage_taken())
+            .collect();
+        assert_eq!(damage, vec![0, 40]);
+        assert_eq!(state, before);
+
+        let expected_damage = expected_value(&mut rng, &state, &action, |x| {
+            x.get_active(1).damage_taken() as f64
+        });
+        assert_eq!(expected_damage, 20.0);
+    }
+}
@@ -1,13 +1,21 @@
+mod action_index;
 mod apply_abilities_action;
 mod apply_action;
 mod apply_action_helpers;
 mod apply_attack_action;
 mod apply_trainer_action;
+mod checkup;
+mod coin_flips;
+mod forecast;
 mod safe_trainer_actions;
 mod mutations;
 mod types;
 
 pub(crate) use apply_action::apply_action;
-pub(crate) use apply_action::forecast_action;
+pub(crate) use apply_action::apply_action_with_outcome_rng;
+pub use action_index::{legal_action_mask, ActionIndex};
+pub use checkup::CheckupEvent;
+pub use coin_flips::CoinFlips;
+pub use forecast::{expected_value, forecast, outcome_probabilities, Outcome};
 pub use types::Action;
 pub use types::SimpleAction;
@@ -1,7 +1,12 @@
 use log::trace;
 use rand::rngs::StdRng;
 
-use crate::{card_ids::CardId, hooks::get_damage_from_attack, types::StatusCondition, State};
+use crate::{
+    card_ids::CardId,
+    hooks::get_damage_from_attack,
+    types::{Attack, StatusCondition},
+    State,
+};
 
 use super::{
     apply_action_helpers::{
@@ -34,10 +39,10 @@ pub(crate) fn active_damage_effect_doutcome(
     )
 }
 
-// TODO: Ask for state so that we can get damage via index, before the mutation,
-//  and reuse the common mutation code.
-pub(crate) fn index_active_damage_doutcome<F>(
-    attack_index: usize,
+// TODO: Ask for state so that we can get damage before the mutation, and reuse the common
+//  mutation code.
+pub(crate) fn attack_active_damage_doutcome<F>(
+    attack: Attack,
     additional_effect: F,
 ) -> (Probabilities, Mutations)
 where
@@ -52,7 +57,7 @@ where
                 .push((action.actor, vec![SimpleAction::EndTurn]));
             additional_effect(rng, state, action);
 
-            let damage = get_damage_from_attack(state, action.actor, attack_index, 0);
+            let damage = get_damage_from_attack(state, action.actor, &attack, 0);
             handle_attack_damage(state, action.actor, &vec![(damage, 0)]);
         })],
     )
@@ -127,11 +132,7 @@ pub(crate) fn build_status_effect(status: StatusCondition) -> FnMutation {
                 return;
             }
 
-            match status {
-                StatusCondition::Asleep => opponent_active.asleep = true,
-                StatusCondition::Paralyzed => opponent_active.paralyzed = true,
-                StatusCondition::Poisoned => opponent_active.poisoned = true,
-            }
+            opponent_active.apply_status(&status);
         }
     })
 }
@@ -158,6 +159,21 @@ mod test {
         let effect = build_status_effect(StatusCondition::Asleep);
         effect(&mut rng, &mut state, &action);
         assert!(state.get_active(1).asleep);
+
+        // Paralysis replaces sleep
+        let effect = build_status_effect(StatusCondition::Paralyzed);
+        effect(&mut rng, &mut state, &action);
+        assert!(state.get_active(1).paralyzed);
+        assert!(!state.get_active(1).asleep);
+
+        // So does confusion, but not burns
+        let effect = build_status_effect(StatusCondition::Confused);
+        effect(&mut rng, &mut state, &action);
+        let effect = build_status_effect(StatusCondition::Burned);
+        effect(&mut rng, &mut state, &action);
+        assert!(state.get_active(1).confused);
+        assert!(state.get_active(1).burned);
+        assert!(!state.get_active(1).paralyzed);
     }
 
     #[test]
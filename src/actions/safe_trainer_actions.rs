@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use log::debug;
 use rand::rngs::StdRng;
 
@@ -9,6 +11,8 @@ use crate::{
 
 use super::{
     apply_action_helpers::{apply_common_mutation, Mutations, Probabilities},
+    apply_trainer_action::forecast_trainer_action,
+    coin_flips::{recording_flips, CoinFlipper},
     Action, SimpleAction,
 };
 
@@ -28,15 +32,30 @@ pub fn forecast_trainer_action_safe(
     match trainer_id {
         // Deterministic effects (no information leakage)
         CardId::PA001Potion => deterministic_safe(potion_effect_safe),
-        CardId::PA002XSpeed => deterministic_safe(turn_effect_safe),
+        CardId::PA002XSpeed => deterministic_safe(active_effect_safe),
         CardId::A1219Erika | CardId::A1266Erika => deterministic_safe(erika_effect_safe),
         CardId::A1222Koga | CardId::A1269Koga => deterministic_safe(koga_effect_safe),
         CardId::A1223Giovanni | CardId::A1270Giovanni => deterministic_safe(giovanni_effect_safe),
         CardId::A1225Sabrina | CardId::A1272Sabrina => deterministic_safe(sabrina_effect_safe),
-        CardId::A1a068Leaf | CardId::A1a082Leaf => deterministic_safe(turn_effect_safe),
+        CardId::A1a068Leaf | CardId::A1a082Leaf => deterministic_safe(active_effect_safe),
         CardId::A2150Cyrus | CardId::A2190Cyrus => deterministic_safe(cyrus_effect_safe),
-        CardId::A2147GiantCape => deterministic_safe(attach_tool_safe),
-        
+        CardId::A2147GiantCape | CardId::A2148RockyHelmet | CardId::A2149LumBerry => {
+            deterministic_safe(attach_tool_safe)
+        }
+
+        // Only look at hidden cards once applied (see apply_trainer_action)
+        CardId::A2146PokemonCommunication
+        | CardId::A2151TeamGalacticGrunt
+        | CardId::A2191TeamGalacticGrunt
+        | CardId::A2152Cynthia
+        | CardId::A2192Cynthia
+        | CardId::A2153Volkner
+        | CardId::A2193Volkner
+        | CardId::A2154Dawn
+        | CardId::A2194Dawn
+        | CardId::A2155Mars
+        | CardId::A2195Mars => forecast_trainer_action(acting_player, state, trainer_card),
+
         // Probabilistic effects (fixed to not leak information)
         CardId::PA005PokeBall => pokeball_outcomes_safe(acting_player, state),
         CardId::PA006RedCard => red_card_outcomes_safe(acting_player, state),
@@ -93,7 +112,7 @@ fn pokeball_outcomes_safe(acting_player: usize, state: &State) -> (Probabilities
     if !has_basic {
         // No basics - just shuffle
         deterministic_safe(|rng, state, action| {
-            state.decks[action.actor].shuffle(false, rng);
+            state.deck_mut(action.actor).shuffle(false, rng);
         })
     } else {
         // We know there's at least one basic, but we don't reveal which
@@ -121,10 +140,10 @@ fn pokeball_outcomes_safe(acting_player: usize, state: &State) -> (Probabilities
                     
                     // Add to hand and remove from deck
                     state.hands[action.actor].push(card.clone());
-                    state.decks[action.actor].cards.remove(*idx);
+                    state.deck_mut(action.actor).cards.remove(*idx);
                 }
                 
-                state.decks[action.actor].shuffle(false, rng);
+                state.deck_mut(action.actor).shuffle(false, rng);
             })],
         )
     }
@@ -142,7 +161,7 @@ fn red_card_outcomes_safe(acting_player: usize, state: &State) -> (Probabilities
             
             // Shuffle hand into deck
             let opponent_hand = &mut state.hands[opponent];
-            let opponent_deck = &mut state.decks[opponent];
+            let opponent_deck = Arc::make_mut(&mut state.decks[opponent]);
             opponent_deck.cards.append(opponent_hand);
             opponent_deck.shuffle(false, rng);
             
@@ -167,12 +186,12 @@ fn mythical_slab_outcomes_safe(acting_player: usize, state: &State) -> (Probabil
             if let Some(card) = state.decks[action.actor].cards.first() {
                 if card.is_psychic_type() {
                     // Put in hand
-                    let card = state.decks[action.actor].cards.remove(0);
+                    let card = state.deck_mut(action.actor).cards.remove(0);
                     state.hands[action.actor].push(card);
                 } else {
                     // Put on bottom
-                    let card = state.decks[action.actor].cards.remove(0);
-                    state.decks[action.actor].cards.push(card);
+                    let card = state.deck_mut(action.actor).cards.remove(0);
+                    state.deck_mut(action.actor).cards.push(card);
                 }
             }
         })],
@@ -184,12 +203,14 @@ fn mythical_slab_outcomes_safe(acting_player: usize, state: &State) -> (Probabil
 fn misty_outcomes_safe() -> (Probabilities, Mutations) {
     use crate::types::EnergyType;
     
-    // 50% no energy, 25% 1 energy, 12.5% 2 energy, etc.
-    let probabilities = vec![0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625];
+    // Flip until tails: 50% no energy, 25% 1 energy, 12.5% 2 energy, etc.
+    let mut probabilities = vec![];
     let mut outcomes: Mutations = vec![];
     
-    for j in 0..6 {
-        outcomes.push(Box::new(move |_, state, action| {
+    for (probability, flips) in CoinFlipper::until_tails(5) {
+        probabilities.push(probability);
+        let j = flips.heads as u32;
+        outcomes.push(recording_flips(flips, Box::new(move |_, state, action| {
             apply_common_mutation(state, action);
             
             // Queue energy attachment decisions
@@ -206,7 +227,7 @@ fn misty_outcomes_safe() -> (Probabilities, Mutations) {
                 state.move_generation_stack
                     .push((action.actor, possible_moves));
             }
-        }));
+        })));
     }
     
     (probabilities, outcomes)
@@ -260,7 +281,7 @@ fn koga_effect_safe(_: &mut StdRng, state: &mut State, action: &Action) {
 
 fn giovanni_effect_safe(_: &mut StdRng, state: &mut State, action: &Action) {
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let card = Card::Trainer(trainer_card.clone());
+        let card = Card::Trainer(trainer_card);
         state.add_turn_effect(card, 0);
     }
 }
@@ -288,10 +309,10 @@ fn cyrus_effect_safe(_: &mut StdRng, state: &mut State, action: &Action) {
         .push((opponent_player, possible_moves));
 }
 
-fn turn_effect_safe(_: &mut StdRng, state: &mut State, action: &Action) {
+fn active_effect_safe(_: &mut StdRng, state: &mut State, action: &Action) {
     if let SimpleAction::Play { trainer_card } = &action.action {
-        let card = Card::Trainer(trainer_card.clone());
-        state.add_turn_effect(card, 0);
+        let card = Card::Trainer(trainer_card);
+        state.add_pokemon_effect(action.actor, 0, card, 0);
     }
 }
 
@@ -352,7 +373,7 @@ mod tests {
         };
         
         // Forecast should not reveal what cards will be drawn
-        let (probs, mutations) = forecast_trainer_action_safe(0, &state, &prof_research);
+        let (probs, mutations) = forecast_trainer_action_safe(0, &state, prof_research);
         
         // Should be a single outcome with probability 1.0
         assert_eq!(probs.len(), 1);
@@ -372,10 +393,53 @@ mod tests {
         };
         
         // Should not reveal which basic Pokemon are available
-        let (probs, mutations) = forecast_trainer_action_safe(0, &state, &pokeball);
+        let (probs, mutations) = forecast_trainer_action_safe(0, &state, pokeball);
         
         // Should be single outcome (success/fail hidden)
         assert_eq!(probs.len(), 1);
         assert_eq!(mutations.len(), 1);
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_misty_flips_until_tails() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.in_play_pokemon[0][0] = Some(crate::hooks::to_playable_card(
+            &crate::database::get_card_by_enum(CardId::A1053Squirtle),
+            false,
+        ));
+        let Card::Trainer(misty) = crate::database::get_card_by_enum(CardId::A1220Misty) else {
+            panic!("Expected trainer card");
+        };
+
+        // One outcome per number of heads, each half as likely as the previous one
+        let (probs, mutations) = forecast_trainer_action_safe(0, &state, misty);
+        assert_eq!(probs.len(), mutations.len());
+        for (heads, probability) in probs.iter().enumerate() {
+            assert_eq!(*probability, 0.5f64.powi(heads as i32 + 1));
+        }
+
+        // Applying it records how the coins went
+        for seed in 0..10 {
+            let mut state = state.clone();
+            state.hands[0].push(Card::Trainer(misty));
+            let action = Action {
+                actor: 0,
+                action: SimpleAction::Play {
+                    trainer_card: misty,
+                },
+                is_stack: false,
+            };
+            let mut rng: StdRng = rand::SeedableRng::seed_from_u64(seed);
+            super::super::apply_action(&mut rng, &mut state, &action);
+
+            assert_eq!(state.coin_flips.len(), 1);
+            let flips = state.coin_flips[0];
+            assert_eq!(flips.tails(), 1);
+            let (_, choices) = state.move_generation_stack.last().unwrap();
+            assert!(matches!(
+                &choices[0],
+                SimpleAction::Attach { attachments, .. } if attachments[0].0 == flips.heads as u32
+            ));
+        }
+    }
+}
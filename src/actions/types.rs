@@ -17,7 +17,7 @@ pub struct Action {
 pub enum SimpleAction {
     DrawCard,
     Play {
-        trainer_card: TrainerCard,
+        trainer_card: &'static TrainerCard,
     },
 
     // Card because of the fossil Trainer Cards...
@@ -29,6 +29,9 @@ pub enum SimpleAction {
     // Its given it is with the active pokemon, to the other active.
     // usize is the index of the attack in the pokemon's attacks
     Attack(usize),
+    // Uses the opponent's Active Pokémon's attack at this index as the attack being resolved
+    // (e.g. Mew ex's Genome Hacking).
+    CopyAttack(usize),
     // usize is in_play_pokemon index to retreat to. Can't Retreat(0)
     Retreat(usize),
     EndTurn,
@@ -49,9 +52,41 @@ pub enum SimpleAction {
     ApplyDamage {
         targets: Vec<(u32, usize)>, // Vec of (damage, in_play_idx)
     },
+    // Moves 1 Energy of this type between two of the player's Pokémon (e.g. Dawn).
+    MoveEnergy {
+        from_in_play_idx: usize,
+        to_in_play_idx: usize,
+        energy: EnergyType,
+    },
+    // Shuffles this Pokémon from the hand into the deck and takes a random Pokémon from the
+    // deck instead (i.e. Pokémon Communication).
+    SwapWithDeck(Card),
+    // Discards 1 Energy of this type from one of the player's Pokémon, when they get to pick
+    // which (e.g. paying a retreat cost with different Energy attached).
+    DiscardEnergy {
+        in_play_idx: usize,
+        energy: EnergyType,
+    },
+    // Picks which Pokémon an attack damages (e.g. 1 of the opponent's Benched Pokémon).
+    // The target can be on either side, so it also says whose Pokémon it is.
+    ChooseDamageTarget {
+        player: usize,
+        in_play_idx: usize,
+        damage: u32,
+    },
     Activate {
         in_play_idx: usize,
     },
+    // Replaces a knocked out Active Pokémon. bench_idx is the in_play_pokemon index to promote.
+    Promote {
+        bench_idx: usize,
+    },
+    // The opening board, chosen at once during setup: hand indices of the Active Pokémon and
+    // of the Pokémon to Bench (in Bench order).
+    Setup {
+        active: usize,
+        bench: Vec<usize>,
+    },
 }
 
 impl fmt::Display for SimpleAction {
@@ -63,6 +98,7 @@ impl fmt::Display for SimpleAction {
             SimpleAction::Evolve(card, index) => write!(f, "Evolve({card}, {index})"),
             SimpleAction::UseAbility(index) => write!(f, "UseAbility({index})"),
             SimpleAction::Attack(index) => write!(f, "Attack({index})"),
+            SimpleAction::CopyAttack(index) => write!(f, "CopyAttack({index})"),
             SimpleAction::Retreat(index) => write!(f, "Retreat({index})"),
             SimpleAction::EndTurn => write!(f, "EndTurn"),
             SimpleAction::Attach {
@@ -96,7 +132,27 @@ impl fmt::Display for SimpleAction {
                     .join(", ");
                 write!(f, "ApplyDamage({targets_str})")
             }
+            SimpleAction::MoveEnergy {
+                from_in_play_idx,
+                to_in_play_idx,
+                energy,
+            } => write!(
+                f,
+                "MoveEnergy({from_in_play_idx}, {to_in_play_idx}, {energy:?})"
+            ),
+            SimpleAction::SwapWithDeck(card) => write!(f, "SwapWithDeck({card})"),
+            SimpleAction::DiscardEnergy {
+                in_play_idx,
+                energy,
+            } => write!(f, "DiscardEnergy({in_play_idx}, {energy:?})"),
+            SimpleAction::ChooseDamageTarget {
+                player,
+                in_play_idx,
+                damage,
+            } => write!(f, "ChooseDamageTarget({player}, {in_play_idx}, {damage})"),
             SimpleAction::Activate { in_play_idx } => write!(f, "Activate({in_play_idx})"),
+            SimpleAction::Promote { bench_idx } => write!(f, "Promote({bench_idx})"),
+            SimpleAction::Setup { active, bench } => write!(f, "Setup({active}, {bench:?})"),
         }
     }
 }
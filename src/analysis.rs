@@ -0,0 +1,306 @@
+use std::io::{BufRead, Write};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    actions::{apply_action, Action, ActionIndex},
+    game_log::GameLogEntry,
+    generate_possible_actions,
+    players::{parse_player_code, rollout_policy, PlayerCode},
+    replay::{self, SchemaVersion},
+    simulate::{estimate_win_probability, WinProbability},
+    Deck, State,
+};
+
+pub use crate::replay::Replay;
+
+/// Analysis mode, like a chess engine's: step through a position action by action, try
+/// lines and take them back, and estimate who is winning with rollouts.
+///
+/// Positions are saved as replays (decks, seed and the `ActionIndex` of every action taken),
+/// which rebuild the exact same state, coin flips included.
+pub struct Analysis {
+    deck_a: Deck,
+    deck_b: Deck,
+    seed: u64,
+    state: State,
+    rng: StdRng,
+    /// State and randomness before each action taken, to undo it.
+    undo_stack: Vec<(State, StdRng)>,
+    actions: Vec<ActionIndex>,
+}
+
+impl Analysis {
+    /// Starts analyzing a new game between the decks.
+    pub fn new(deck_a: &Deck, deck_b: &Deck, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let state = State::initialize(deck_a, deck_b, &mut rng);
+        Self {
+            deck_a: deck_a.clone(),
+            deck_b: deck_b.clone(),
+            seed,
+            state,
+            rng,
+            undo_stack: vec![],
+            actions: vec![],
+        }
+    }
+
+    /// Replays a saved game up to where it was left.
+    pub fn from_replay(replay: &Replay) -> Result<Self, String> {
+        let deck_a = Deck::from_string(&replay.deck_a)?;
+        let deck_b = Deck::from_string(&replay.deck_b)?;
+        let mut analysis = Self::new(&deck_a, &deck_b, replay.seed);
+        for index in &replay.actions {
+            analysis
+                .apply_index(*index)
+                .map_err(|error| replay.annotate_error(error))?;
+        }
+        Ok(analysis)
+    }
+
+    /// Plays a saved game back, with a log entry for every action (see `GameLogEntry`).
+    pub fn replay_log(replay: &Replay) -> Result<Vec<GameLogEntry>, String> {
+        let mut analysis = Self::new(
+            &Deck::from_string(&replay.deck_a)?,
+            &Deck::from_string(&replay.deck_b)?,
+            replay.seed,
+        );
+        let mut log = vec![];
+        for index in &replay.actions {
+            let action = analysis
+                .apply_index(*index)
+                .map_err(|error| replay.annotate_error(error))?;
+            let ply = log.len() as u32 + 1;
+            log.push(GameLogEntry::new(
+                replay.seed,
+                ply,
+                &action,
+                &analysis.state,
+            ));
+        }
+        Ok(log)
+    }
+
+    // Takes the legal action with this `ActionIndex`
+    fn apply_index(&mut self, index: usize) -> Result<Action, String> {
+        let position = self
+            .legal_actions()
+            .iter()
+            .position(|x| ActionIndex::of(&x.action).0 == index)
+            .ok_or_else(|| {
+                format!(
+                    "Action {index} of the replay is not legal after {} actions",
+                    self.actions.len()
+                )
+            })?;
+        self.apply(position)
+    }
+
+    pub fn replay(&self) -> Replay {
+        Replay {
+            version: SchemaVersion::current(),
+            deck_a: self.deck_a.to_string(),
+            deck_b: self.deck_b.to_string(),
+            seed: self.seed,
+            actions: self.actions.iter().map(|x| x.0).collect(),
+            final_state_hash: Some(replay::state_hash(&self.state)),
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Actions the player to move can take, empty if the game is over.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.state.is_game_over() {
+            return vec![];
+        }
+        generate_possible_actions(&self.state).1
+    }
+
+    /// Takes the `index`-th of the legal actions.
+    pub fn apply(&mut self, index: usize) -> Result<Action, String> {
+        let actions = self.legal_actions();
+        let action = actions
+            .get(index)
+            .ok_or_else(|| format!("No action {index}, there are {}", actions.len()))?
+            .clone();
+        self.undo_stack.push((self.state.clone(), self.rng.clone()));
+        apply_action(&mut self.rng, &mut self.state, &action);
+        self.actions.push(ActionIndex::of(&action.action));
+        Ok(action)
+    }
+
+    /// Takes back the last action. False if there was none.
+    pub fn undo(&mut self) -> bool {
+        let Some((state, rng)) = self.undo_stack.pop() else {
+            return false;
+        };
+        self.state = state;
+        self.rng = rng;
+        self.actions.pop();
+        true
+    }
+
+    /// Chances of player 0 from here, over `n_rollouts` games played to the end by the
+    /// given strategies (see `estimate_win_probability`).
+    pub fn win_probability(
+        &self,
+        players: [PlayerCode; 2],
+        n_rollouts: u32,
+        seed: u64,
+    ) -> WinProbability {
+        let [player_a, player_b] = players;
+        estimate_win_probability(
+            &self.state,
+            &rollout_policy(player_a),
+            &rollout_policy(player_b),
+            n_rollouts,
+            seed,
+        )
+    }
+
+    /// Reads commands from `input` until it ends or says "quit", writing answers to
+    /// `output`. Type "help" for the commands.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> std::io::Result<()> {
+        writeln!(output, "{}", self.state.debug_string())?;
+        for line in input.lines() {
+            let line = line?;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words[..] {
+                [] => continue,
+                ["quit"] | ["exit"] => break,
+                ["help"] => writeln!(output, "{HELP}")?,
+                ["state"] => writeln!(output, "{}", self.state.debug_string())?,
+                ["actions"] => {
+                    for (i, action) in self.legal_actions().iter().enumerate() {
+                        writeln!(output, "{i}: {action:?}")?;
+                    }
+                }
+                ["play", index] => match index.parse().map_err(|_| "Invalid index".to_string()) {
+                    Ok(index) => match self.apply(index) {
+                        Ok(action) => {
+                            writeln!(output, "Played {action:?}")?;
+                            writeln!(output, "{}", self.state.debug_string())?;
+                        }
+                        Err(error) => writeln!(output, "{error}")?,
+                    },
+                    Err(error) => writeln!(output, "{error}")?,
+                },
+                ["undo"] => {
+                    if self.undo() {
+                        writeln!(output, "{}", self.state.debug_string())?;
+                    } else {
+                        writeln!(output, "Nothing to undo")?;
+                    }
+                }
+                ["rollouts", ref arguments @ ..] => match parse_rollouts(arguments) {
+                    Ok((n, players)) => {
+                        let estimate = self.win_probability(players, n, self.seed);
+                        writeln!(
+                            output,
+                            "Player 0 wins {:.1}% (95% CI {:.1}% to {:.1}%)",
+                            estimate.mean * 100.0,
+                            estimate.ci_low * 100.0,
+                            estimate.ci_high * 100.0
+                        )?;
+                    }
+                    Err(error) => writeln!(output, "{error}")?,
+                },
+                ["save", path] => {
+                    let json = self.replay().to_json();
+                    match std::fs::write(path, json) {
+                        Ok(()) => {
+                            writeln!(output, "Saved {} actions to {path}", self.actions.len())?
+                        }
+                        Err(error) => writeln!(output, "Failed to save {path}: {error}")?,
+                    }
+                }
+                _ => writeln!(output, "Unknown command, try \"help\"")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// "<n> [a,b]", random players by default
+fn parse_rollouts(arguments: &[&str]) -> Result<(u32, [PlayerCode; 2]), String> {
+    let usage = || "Usage: rollouts <n> [a,b]".to_string();
+    let (n, players) = match arguments {
+        [n] => (n, [PlayerCode::R, PlayerCode::R]),
+        [n, players] => {
+            let codes = players
+                .split(',')
+                .map(parse_player_code)
+                .collect::<Result<Vec<_>, _>>()?;
+            (n, codes.try_into().map_err(|_| usage())?)
+        }
+        _ => return Err(usage()),
+    };
+    Ok((n.parse().map_err(|_| usage())?, players))
+}
+
+const HELP: &str = "Commands:
+  state                    show the position
+  actions                  list the legal actions
+  play <i>                 take the i-th legal action
+  undo                     take back the last action
+  rollouts <n> [a,b]       estimate player 0's chances with n games played by strategies a,b
+  save <path>              save the game as a replay
+  quit";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::load_test_decks;
+
+    #[test]
+    fn test_undo_restores_state_and_randomness() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut analysis = Analysis::new(&deck_a, &deck_b, 3);
+        for _ in 0..10 {
+            analysis.apply(0).unwrap();
+        }
+        let state = analysis.state().clone();
+        let replay = analysis.replay();
+
+        let next = analysis.apply(0).unwrap();
+        let after = analysis.state().clone();
+        assert!(analysis.undo());
+        assert_eq!(*analysis.state(), state);
+        assert_eq!(analysis.apply(0).unwrap(), next);
+        assert_eq!(*analysis.state(), after);
+
+        while analysis.undo() {}
+        assert!(analysis.replay().actions.is_empty());
+        assert!(!analysis.undo());
+        assert!(analysis.apply(1000).is_err());
+
+        let restored = Analysis::from_replay(&replay).unwrap();
+        assert_eq!(*restored.state(), state);
+        assert_eq!(restored.replay(), replay);
+        let log = Analysis::replay_log(&replay).unwrap();
+        assert_eq!(log.len(), 10);
+        assert_eq!(log[9].turn, state.turn_count);
+    }
+
+    #[test]
+    fn test_run_commands() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut analysis = Analysis::new(&deck_a, &deck_b, 0);
+        let input = "actions\nplay 0\nplay x\nundo\nundo\nrollouts 4 r,r\nfoo\nquit\nplay 0\n";
+        let mut output = vec![];
+        analysis.run(input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("0: "));
+        assert!(output.contains("Played "));
+        assert!(output.contains("Invalid index"));
+        assert!(output.contains("Nothing to undo"));
+        assert!(output.contains("Player 0 wins"));
+        assert!(output.contains("Unknown command"));
+        // Nothing is read after "quit"
+        assert!(analysis.replay().actions.is_empty());
+    }
+}
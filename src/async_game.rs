@@ -0,0 +1,200 @@
+use crate::{
+    errors::GameResult, game::PendingChoice, game_rules::GameRules, players::AsyncPlayer,
+    state::GameOutcome, Game,
+};
+
+/// A `Game` whose players decide asynchronously. It plays out as a `Game` with the same
+/// seed and players would, awaiting each decision instead of blocking on it, so that a
+/// server can run many games with remote players on a few threads.
+pub struct AsyncGame {
+    game: Game,
+    players: Vec<Box<dyn AsyncPlayer>>,
+}
+
+impl AsyncGame {
+    pub fn new(players: Vec<Box<dyn AsyncPlayer>>, seed: u64) -> Self {
+        Self::with_rules(players, seed, GameRules::default())
+    }
+
+    /// Like `new`, but playing under a variant of the rules (see `GameRules`).
+    pub fn with_rules(players: Vec<Box<dyn AsyncPlayer>>, seed: u64, rules: GameRules) -> Self {
+        let deck_a = players[0].get_deck();
+        let deck_b = players[1].get_deck();
+        Self {
+            game: Game::without_players(&deck_a, &deck_b, seed, rules),
+            players,
+        }
+    }
+
+    /// Plays the game to its end, returning its outcome (None if it ran out of turns
+    /// without a winner). Fails if a player chooses an action that isn't legal, leaving
+    /// the game as it was before that decision.
+    pub async fn play(&mut self) -> GameResult<Option<GameOutcome>> {
+        loop {
+            let (actor, actions) = match self.game.poll_decision() {
+                PendingChoice::Decision { actor, actions } => (actor, actions),
+                PendingChoice::GameOver(outcome) => return Ok(outcome),
+            };
+            let (rng, state) = self.game.decision_context(actor);
+            let action = self.players[actor].decide(rng, state, actions).await;
+            self.game.submit_choice(action)?;
+        }
+    }
+
+    /// The game being played, e.g. to look at its state between decisions.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use rand::rngs::StdRng;
+    use tokio::sync::{mpsc, oneshot};
+
+    use super::*;
+    use crate::{
+        actions::{Action, SimpleAction},
+        players::{ActionFuture, AttachAttackPlayer, Player, RandomPlayer},
+        test_helpers::load_test_decks,
+        Deck, State,
+    };
+
+    // Sends every decision to a "client" task and waits for its answer
+    #[derive(Debug)]
+    struct ChannelPlayer {
+        deck: Deck,
+        requests: mpsc::Sender<(Vec<Action>, oneshot::Sender<Action>)>,
+    }
+
+    impl AsyncPlayer for ChannelPlayer {
+        fn get_deck(&self) -> Deck {
+            self.deck.clone()
+        }
+
+        fn decide<'a>(
+            &'a mut self,
+            _: &'a mut StdRng,
+            _: &'a State,
+            possible_actions: Vec<Action>,
+        ) -> ActionFuture<'a> {
+            Box::pin(async move {
+                let (reply, answer) = oneshot::channel();
+                self.requests.send((possible_actions, reply)).await.unwrap();
+                answer.await.unwrap()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plays_like_a_game() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = || -> Vec<Box<dyn Player>> {
+            vec![
+                Box::new(RandomPlayer {
+                    deck: deck_a.clone(),
+                }),
+                Box::new(AttachAttackPlayer {
+                    deck: deck_b.clone(),
+                }),
+            ]
+        };
+        let mut expected = Game::new(players(), 5);
+        let outcome = expected.play();
+
+        let async_players: Vec<Box<dyn AsyncPlayer>> = vec![
+            Box::new(RandomPlayer {
+                deck: deck_a.clone(),
+            }),
+            Box::new(AttachAttackPlayer {
+                deck: deck_b.clone(),
+            }),
+        ];
+        let mut game = AsyncGame::new(async_players, 5);
+        assert_eq!(game.play().await, Ok(outcome));
+        assert_eq!(game.game().get_state(), expected.get_state());
+    }
+
+    #[tokio::test]
+    async fn test_remote_player() {
+        let (deck_a, deck_b) = load_test_decks();
+        let (requests, mut incoming) = mpsc::channel(1);
+        let players: Vec<Box<dyn AsyncPlayer>> = vec![
+            Box::new(ChannelPlayer {
+                deck: deck_a,
+                requests,
+            }),
+            Box::new(AttachAttackPlayer { deck: deck_b }),
+        ];
+        let client = tokio::spawn(async move {
+            let mut decisions = 0;
+            while let Some((actions, reply)) = incoming.recv().await {
+                decisions += 1;
+                reply.send(actions[0].clone()).unwrap();
+            }
+            decisions
+        });
+
+        let mut game = AsyncGame::new(players, 1);
+        let outcome = game.play().await.unwrap();
+        assert_eq!(outcome, game.game().get_state().winner);
+        drop(game);
+        assert!(client.await.unwrap() > 0);
+    }
+
+    // Remembers whether the opponent's board was in play when choosing its own
+    #[derive(Debug)]
+    struct PeekingPlayer {
+        deck: Deck,
+        saw_opponent_board: Arc<Mutex<Vec<bool>>>,
+    }
+
+    impl AsyncPlayer for PeekingPlayer {
+        fn get_deck(&self) -> Deck {
+            self.deck.clone()
+        }
+
+        fn decide<'a>(
+            &'a mut self,
+            _: &'a mut StdRng,
+            state: &'a State,
+            possible_actions: Vec<Action>,
+        ) -> ActionFuture<'a> {
+            Box::pin(async move {
+                let action = possible_actions.last().unwrap().clone();
+                if let SimpleAction::Setup { .. } = action.action {
+                    let opponent = (action.actor + 1) % 2;
+                    let seen = state.in_play_pokemon[opponent][0].is_some();
+                    self.saw_opponent_board.lock().unwrap().push(seen);
+                }
+                action
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_opening_boards_are_chosen_blind() {
+        let (deck_a, deck_b) = load_test_decks();
+        let saw_opponent_board = Arc::new(Mutex::new(vec![]));
+        let players: Vec<Box<dyn AsyncPlayer>> = vec![
+            Box::new(PeekingPlayer {
+                deck: deck_a,
+                saw_opponent_board: saw_opponent_board.clone(),
+            }),
+            Box::new(PeekingPlayer {
+                deck: deck_b,
+                saw_opponent_board: saw_opponent_board.clone(),
+            }),
+        ];
+        // Both players have several boards to choose from
+        let mut game = AsyncGame::new(players, 3);
+        game.play().await.unwrap();
+        assert_eq!(*saw_opponent_board.lock().unwrap(), [false, false]);
+    }
+}
@@ -0,0 +1,221 @@
+// TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effect_text::parse_attack_effect,
+    types::{Attack, EnergyType, StatusCondition},
+};
+
+/// Data description of the "simple" effects an attack can have. Attacks whose effect text is
+/// fully described by a list of these don't need a bespoke `AttackId` implementation; the
+/// interpreter in `apply_attack_action.rs` resolves them (coin flips included).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AttackEffect {
+    /// Flip `coins` coins. This attack does `damage` more damage for each heads.
+    CoinFlipExtraDamage { coins: u8, damage: u32 },
+    /// Flip a coin. If tails, this attack does nothing. Flipped before the attack is resolved,
+    /// like Confusion (see `forecast_attack`).
+    CoinFlipOrNothing,
+    /// Your opponent's Active Pokémon is now affected by the status condition.
+    Status(StatusCondition),
+    /// Flip a coin. If heads, your opponent's Active Pokémon is now affected by the status condition.
+    CoinFlipStatus(StatusCondition),
+    /// Discard these Energy from this Pokémon (Colorless meaning one of the player's choice).
+    DiscardOwnEnergy(Vec<EnergyType>),
+    /// This attack also does this much damage to each of your opponent's Benched Pokémon.
+    BenchDamage(u32),
+    /// This Pokémon also does this much damage to itself.
+    SelfDamage(u32),
+    /// Heal this much damage from this Pokémon.
+    SelfHeal(u32),
+    /// This Pokémon is now affected by the status condition.
+    SelfStatus(StatusCondition),
+    /// During your next turn, this Pokémon can't attack.
+    CantAttackNextTurn,
+    /// During your next turn, this Pokémon can't use this attack.
+    CantUseAgainNextTurn,
+}
+
+lazy_static::lazy_static! {
+    static ref ATTACK_EFFECT_MAP: HashMap<(&'static str, usize), Vec<AttackEffect>> = {
+        use AttackEffect::*;
+        use EnergyType::*;
+        use StatusCondition::*;
+
+        let mut m = HashMap::new();
+        // Your opponent's Active Pokémon is now Poisoned.
+        m.insert(("A1a 016", 0), vec![Status(Poisoned)]);
+        m.insert(("A1a 054", 0), vec![Status(Poisoned)]);
+        m.insert(("A2 009", 0), vec![Status(Poisoned)]);
+        m.insert(("A2 103", 0), vec![Status(Poisoned)]);
+        m.insert(("A2b 046", 0), vec![Status(Poisoned)]);
+        m.insert(("A2b 047", 0), vec![Status(Poisoned)]);
+        m.insert(("A3 114", 0), vec![Status(Poisoned)]);
+        m.insert(("A3a 042", 0), vec![Status(Poisoned)]);
+        m.insert(("A3a 045", 0), vec![Status(Poisoned)]);
+        m.insert(("A3a 103", 0), vec![Status(Poisoned)]);
+        m.insert(("P-A 056", 0), vec![Status(Poisoned)]);
+        // Your opponent's Active Pokémon is now Asleep.
+        m.insert(("A1a 008", 0), vec![Status(Asleep)]);
+        m.insert(("A1a 036", 0), vec![Status(Asleep)]);
+        m.insert(("A2 109", 0), vec![Status(Asleep)]);
+        m.insert(("A2a 024", 0), vec![Status(Asleep)]);
+        m.insert(("A2a 033", 0), vec![Status(Asleep)]);
+        m.insert(("A3 045", 0), vec![Status(Asleep)]);
+        m.insert(("A3 237", 0), vec![Status(Asleep)]);
+        m.insert(("A3b 021", 0), vec![Status(Asleep)]);
+        m.insert(("P-A 022", 0), vec![Status(Asleep)]);
+        // Your opponent's Active Pokémon is now Burned.
+        m.insert(("A2 024", 0), vec![Status(Burned)]);
+        m.insert(("A2 026", 0), vec![Status(Burned)]);
+        m.insert(("A3 033", 0), vec![Status(Burned)]);
+        m.insert(("A3 182", 0), vec![Status(Burned)]);
+        m.insert(("A3 200", 0), vec![Status(Burned)]);
+        // Your opponent's Active Pokémon is now Poisoned and Burned.
+        m.insert(("A3 036", 0), vec![Status(Poisoned), Status(Burned)]);
+        // Flip a coin. If heads, your opponent's Active Pokémon is now Paralyzed.
+        m.insert(("A2 046", 0), vec![CoinFlipStatus(Paralyzed)]);
+        m.insert(("A2b 002", 0), vec![CoinFlipStatus(Paralyzed)]);
+        m.insert(("A2b 098", 0), vec![CoinFlipStatus(Paralyzed)]);
+        m.insert(("A3a 038", 0), vec![CoinFlipStatus(Paralyzed)]);
+        m.insert(("A3b 026", 0), vec![CoinFlipStatus(Paralyzed)]);
+        m.insert(("A3b 047", 0), vec![CoinFlipStatus(Paralyzed)]);
+        // Flip a coin. If heads, your opponent's Active Pokémon is now Burned.
+        m.insert(("P-A 073", 0), vec![CoinFlipStatus(Burned)]);
+        // Your opponent's Active Pokémon is now Confused.
+        m.insert(("A2 014", 0), vec![Status(Confused)]);
+        m.insert(("A2 067", 0), vec![Status(Confused)]);
+        m.insert(("A2 184", 0), vec![Status(Confused)]);
+        m.insert(("A2 199", 0), vec![Status(Confused)]);
+        m.insert(("A2a 007", 0), vec![Status(Confused)]);
+        m.insert(("A3 076", 0), vec![Status(Confused)]);
+        m.insert(("A3a 020", 0), vec![Status(Confused)]);
+        m.insert(("A3b 060", 0), vec![Status(Confused)]);
+        m.insert(("P-A 038", 0), vec![Status(Confused)]);
+        // Flip a coin. If heads, your opponent's Active Pokémon is now Confused.
+        m.insert(("A2b 049", 0), vec![CoinFlipStatus(Confused)]);
+        // Flip a coin. If tails, this attack does nothing.
+        m.insert(("A1 031", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A1 046", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A1 073", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A1a 041", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A2 139", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A2 178", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A2a 044", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A3 002", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A3 156", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A3a 046", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A3a 062", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A3a 075", 0), vec![CoinFlipOrNothing]);
+        m.insert(("A3b 040", 0), vec![CoinFlipOrNothing]);
+        m.insert(("P-A 020", 0), vec![CoinFlipOrNothing]);
+        m.insert(("P-A 069", 0), vec![CoinFlipOrNothing]);
+        // Flip a coin. If tails, this attack does nothing. If heads, your opponent's Active
+        // Pokémon is now Paralyzed.
+        m.insert(("A3b 054", 0), vec![CoinFlipOrNothing, Status(Paralyzed)]);
+        // This attack also does 10 damage to each of your opponent's Benched Pokémon.
+        m.insert(("A1 258", 1), vec![BenchDamage(10)]);
+        m.insert(("A1 275", 1), vec![BenchDamage(10)]);
+        m.insert(("A2 048", 0), vec![BenchDamage(10)]);
+        m.insert(("A2a 019", 0), vec![BenchDamage(10)]);
+        m.insert(("A2b 020", 0), vec![BenchDamage(10)]);
+        m.insert(("A2b 102", 0), vec![BenchDamage(10)]);
+        m.insert(("A3b 104", 1), vec![BenchDamage(10)]);
+        // This Pokémon also does 10 damage to itself.
+        m.insert(("A2 054", 0), vec![SelfDamage(10)]);
+        m.insert(("A2b 013", 0), vec![SelfDamage(10)]);
+        m.insert(("A3 030", 0), vec![SelfDamage(10)]);
+        m.insert(("A3 032", 0), vec![SelfDamage(10)]);
+        m.insert(("A3 122", 0), vec![SelfDamage(10)]);
+        m.insert(("A3 189", 0), vec![SelfDamage(10)]);
+        m.insert(("A3 207", 0), vec![SelfDamage(10)]);
+        m.insert(("A3 239", 0), vec![SelfDamage(10)]);
+        m.insert(("A3b 095", 0), vec![SelfDamage(10)]);
+        m.insert(("P-A 017", 0), vec![SelfDamage(10)]);
+        // This Pokémon also does 20 damage to itself.
+        m.insert(("A2 120", 0), vec![SelfDamage(20)]);
+        m.insert(("A2 174", 0), vec![SelfDamage(20)]);
+        m.insert(("A2a 068", 0), vec![SelfDamage(20)]);
+        m.insert(("A2b 035", 0), vec![SelfDamage(20)]);
+        m.insert(("A2b 083", 0), vec![SelfDamage(20)]);
+        m.insert(("A2b 096", 0), vec![SelfDamage(20)]);
+        m.insert(("A3a 090", 0), vec![SelfDamage(20)]);
+        m.insert(("A3a 100", 0), vec![SelfDamage(20)]);
+        // Heal 20 damage from this Pokémon.
+        m.insert(("A2 079", 0), vec![SelfHeal(20)]);
+        m.insert(("A2 168", 0), vec![SelfHeal(20)]);
+        m.insert(("A2a 008", 0), vec![SelfHeal(20)]);
+        m.insert(("A3 056", 0), vec![SelfHeal(20)]);
+        m.insert(("A3 164", 0), vec![SelfHeal(20)]);
+        m.insert(("A3b 024", 1), vec![SelfHeal(20)]);
+        m.insert(("A3b 080", 1), vec![SelfHeal(20)]);
+        m.insert(("A3b 088", 1), vec![SelfHeal(20)]);
+        m.insert(("P-A 014", 0), vec![SelfHeal(20)]);
+        m.insert(("P-A 043", 0), vec![SelfHeal(20)]);
+        // Heal 30 damage from this Pokémon.
+        m.insert(("A2 005", 0), vec![SelfHeal(30)]);
+        m.insert(("A2 156", 0), vec![SelfHeal(30)]);
+        m.insert(("A2b 065", 0), vec![SelfHeal(30)]);
+        m.insert(("A2b 087", 0), vec![SelfHeal(30)]);
+        m.insert(("A2b 095", 0), vec![SelfHeal(30)]);
+        m.insert(("A3 212", 0), vec![SelfHeal(30)]);
+        m.insert(("A3 230", 1), vec![SelfHeal(30)]);
+        m.insert(("P-A 018", 0), vec![SelfHeal(30)]);
+        // Discard a [R] Energy from this Pokémon.
+        m.insert(("A3 037", 0), vec![DiscardOwnEnergy(vec![Fire])]);
+        m.insert(("A3 161", 0), vec![DiscardOwnEnergy(vec![Fire])]);
+        m.insert(("A3b 010", 0), vec![DiscardOwnEnergy(vec![Fire])]);
+        m.insert(("A3b 014", 0), vec![DiscardOwnEnergy(vec![Fire])]);
+        m.insert(("P-A 032", 0), vec![DiscardOwnEnergy(vec![Fire])]);
+        // Discard 2 [R] Energy from this Pokémon.
+        m.insert(("A1a 012", 0), vec![DiscardOwnEnergy(vec![Fire, Fire])]);
+        m.insert(("A3b 009", 0), vec![DiscardOwnEnergy(vec![Fire, Fire])]);
+        m.insert(("A3b 079", 0), vec![DiscardOwnEnergy(vec![Fire, Fire])]);
+        m.insert(("A3b 087", 0), vec![DiscardOwnEnergy(vec![Fire, Fire])]);
+        // This Pokémon is now Asleep.
+        m.insert(("A2a 063", 0), vec![SelfStatus(Asleep)]);
+        m.insert(("A3b 057", 0), vec![SelfStatus(Asleep)]);
+        m.insert(("A3b 084", 0), vec![SelfStatus(Asleep)]);
+        m.insert(("A3b 091", 0), vec![SelfStatus(Asleep)]);
+        m.insert(("P-A 049", 0), vec![SelfStatus(Asleep)]);
+        // This Pokémon is now Confused.
+        m.insert(("A3 139", 0), vec![SelfStatus(Confused)]);
+        m.insert(("A3 178", 0), vec![SelfStatus(Confused)]);
+        // During your next turn, this Pokémon can't attack.
+        m.insert(("A2 020", 0), vec![CantAttackNextTurn]);
+        m.insert(("A3 088", 0), vec![CantAttackNextTurn]);
+        m.insert(("A3a 050", 0), vec![CantAttackNextTurn]);
+        m.insert(("A3b 053", 0), vec![CantAttackNextTurn]);
+        m.insert(("A3b 082", 0), vec![CantAttackNextTurn]);
+        m.insert(("A3b 090", 0), vec![CantAttackNextTurn]);
+        // During your next turn, this Pokémon can't use [this attack].
+        m.insert(("A2 012", 0), vec![CantUseAgainNextTurn]);
+        m.insert(("A3a 006", 1), vec![CantUseAgainNextTurn]);
+        m.insert(("A3a 076", 1), vec![CantUseAgainNextTurn]);
+        m.insert(("A3a 088", 1), vec![CantUseAgainNextTurn]);
+
+        m
+    };
+}
+
+impl AttackEffect {
+    // None if the attack is not described by data (it might still have an AttackId)
+    pub fn from_pokemon_index(pokemon_id: &str, index: usize) -> Option<Vec<AttackEffect>> {
+        ATTACK_EFFECT_MAP.get(&(pokemon_id, index)).cloned()
+    }
+
+    /// Like `from_pokemon_index`, falling back to parsing the attack's effect text (see
+    /// `effect_text`) for attacks missing from the table, if all of it is understood.
+    pub fn from_attack(
+        pokemon_id: &str,
+        index: usize,
+        attack: &Attack,
+    ) -> Option<Vec<AttackEffect>> {
+        Self::from_pokemon_index(pokemon_id, index).or_else(|| {
+            let parsed = parse_attack_effect(attack.effect.as_ref()?);
+            parsed.is_complete().then_some(parsed.effects)
+        })
+    }
+}
@@ -13,7 +13,6 @@ pub enum AttackId {
     A1026PinsirDoubleHorn,
     A1029PetililBlot,
     A1030LilligantLeafSupply,
-    A1031Skiddo,
     A1033CharmanderEmber,
     A1035CharizardFireSpin,
     A1036CharizardExCrimsonStorm,
@@ -21,7 +20,6 @@ pub enum AttackId {
     A1040ArcanineHeatTackle,
     A1041ArcanineExInfernoOnrush,
     A1045FlareonFlamethrower,
-    A1046MoltresSkyAttack,
     A1047MoltresExInfernoDance,
     A1052CentiskorchFireBlast,
     A1055BlastoiseHydroPump,
@@ -30,7 +28,6 @@ pub enum AttackId {
     A1063TentacruelPoisonTentacles,
     A1069KinglerKOCrab,
     A1071SeadraWaterArrow,
-    A1073SeakingHornHazard,
     A1078GyaradosHyperBeam,
     A1079LaprasHydroPump,
     A1080VaporeonBubbleDrain,
@@ -67,20 +64,22 @@ pub enum AttackId {
     A1196MeowthPayDay,
     A1201LickitungContinuousLick,
     A1203KangaskhanDizzyPunch,
+    A1205DittoCopy,
     A1213CinccinoDoTheWave,
     A1a003CelebiExPowerfulBloom,
     A1a010PonytaStomp,
     A1a011RapidashRisingLunge,
     A1a026RaichuGigashock,
+    A1a032MewExGenomeHacking,
     A1a021LumineonAqua,
     A1a030DedenneThunderShock,
-    A1a041MankeyFocusFist,
     A1a061EeveeContinuousSteps,
     A2035PiplupHeal,
     A2049PalkiaDimensionalStorm,
     A2119DialgaExMetallicTurbo,
     A2a071ArceusExUltimateForce,
     A3a094JynxPsychic,
+    A3b035MimikyuTryToImitate,
     PA031CinccinoDoTheWave,
     PA034PiplupHeal,
     PA072AlolanGrimerPoison,
@@ -100,7 +99,6 @@ lazy_static::lazy_static! {
         m.insert(("A1 026", 0), AttackId::A1026PinsirDoubleHorn);
         m.insert(("A1 029", 0), AttackId::A1029PetililBlot);
         m.insert(("A1 030", 0), AttackId::A1030LilligantLeafSupply);
-        m.insert(("A1 031", 0), AttackId::A1031Skiddo);
         m.insert(("A1 033", 0), AttackId::A1033CharmanderEmber);
         m.insert(("A1 035", 0), AttackId::A1035CharizardFireSpin);
         m.insert(("A1 036", 1), AttackId::A1036CharizardExCrimsonStorm);
@@ -108,7 +106,6 @@ lazy_static::lazy_static! {
         m.insert(("A1 040", 0), AttackId::A1040ArcanineHeatTackle);
         m.insert(("A1 041", 0), AttackId::A1041ArcanineExInfernoOnrush);
         m.insert(("A1 045", 0), AttackId::A1045FlareonFlamethrower);
-        m.insert(("A1 046", 0), AttackId::A1046MoltresSkyAttack);
         m.insert(("A1 047", 0), AttackId::A1047MoltresExInfernoDance);
         m.insert(("A1 052", 0), AttackId::A1052CentiskorchFireBlast);
         m.insert(("A1 055", 0), AttackId::A1055BlastoiseHydroPump);
@@ -117,7 +114,6 @@ lazy_static::lazy_static! {
         m.insert(("A1 063", 0), AttackId::A1063TentacruelPoisonTentacles);
         m.insert(("A1 069", 0), AttackId::A1069KinglerKOCrab);
         m.insert(("A1 071", 0), AttackId::A1071SeadraWaterArrow);
-        m.insert(("A1 073", 0), AttackId::A1073SeakingHornHazard);
         m.insert(("A1 078", 0), AttackId::A1078GyaradosHyperBeam);
         m.insert(("A1 233", 0), AttackId::A1078GyaradosHyperBeam); // Full art version
         m.insert(("A1 079", 0), AttackId::A1079LaprasHydroPump);
@@ -156,12 +152,14 @@ lazy_static::lazy_static! {
         m.insert(("A1 196", 0), AttackId::A1196MeowthPayDay);
         m.insert(("A1 201", 0), AttackId::A1201LickitungContinuousLick);
         m.insert(("A1 203", 0), AttackId::A1203KangaskhanDizzyPunch);
+        m.insert(("A1 205", 0), AttackId::A1205DittoCopy);
         m.insert(("A1 213", 0), AttackId::A1213CinccinoDoTheWave);
         // Full Arts A1
         m.insert(("A1 229", 0), AttackId::A1026PinsirDoubleHorn);
         m.insert(("A1 230", 0), AttackId::A1033CharmanderEmber);
         m.insert(("A1 241", 0), AttackId::A1171NidokingPoisonHorn);
         m.insert(("A1 246", 0), AttackId::A1196MeowthPayDay);
+        m.insert(("A1 247", 0), AttackId::A1205DittoCopy);
         m.insert(("A1 251", 1), AttackId::A1004VenusaurExGiantBloom);
         m.insert(("A1 252", 0), AttackId::A1023ExeggutorExTropicalSwing);
         m.insert(("A1 253", 1), AttackId::A1036CharizardExCrimsonStorm);
@@ -189,12 +187,15 @@ lazy_static::lazy_static! {
         m.insert(("A1a 021", 0), AttackId::A1a021LumineonAqua);
         m.insert(("A1a 026", 0), AttackId::A1a026RaichuGigashock);
         m.insert(("A1a 030", 0), AttackId::A1a030DedenneThunderShock);
-        m.insert(("A1a 041", 0), AttackId::A1a041MankeyFocusFist);
+        m.insert(("A1a 032", 1), AttackId::A1a032MewExGenomeHacking);
         m.insert(("A1a 061", 0), AttackId::A1a061EeveeContinuousSteps);
         // Full Arts A1a
         m.insert(("A1a 073", 0), AttackId::A1a030DedenneThunderShock);
         m.insert(("A1a 075", 0), AttackId::A1a003CelebiExPowerfulBloom);
+        m.insert(("A1a 077", 1), AttackId::A1a032MewExGenomeHacking);
+        m.insert(("A1a 083", 1), AttackId::A1a032MewExGenomeHacking);
         m.insert(("A1a 085", 0), AttackId::A1a003CelebiExPowerfulBloom);
+        m.insert(("A1a 086", 1), AttackId::A1a032MewExGenomeHacking);
 
         // A2
         m.insert(("A2 035", 0), AttackId::A2035PiplupHeal);
@@ -216,6 +217,9 @@ lazy_static::lazy_static! {
         // A3a
         m.insert(("A3a 094", 0), AttackId::A3a094JynxPsychic);
 
+        // A3b
+        m.insert(("A3b 035", 0), AttackId::A3b035MimikyuTryToImitate);
+
         // Promo
         m.insert(("P-A 012", 0), AttackId::A1196MeowthPayDay);
         m.insert(("P-A 031", 0), AttackId::PA031CinccinoDoTheWave);
@@ -0,0 +1,162 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{actions::Action, State};
+
+/// One decision taken by a player, chained to that player's previous decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub player: usize,
+    pub state_hash: u64,
+    pub actions_hash: u64,
+    pub chosen_hash: u64,
+    /// Hash of the player's previous digest together with this entry's hashes.
+    pub digest: u64,
+}
+
+/// Per-player hash chains of every decision in a game (inputs and output), so that a
+/// disputed game between bots can be verified by replaying it and comparing chain heads.
+///
+/// Hashes rely on the derived `Hash` impls, so replays must use the same engine version.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+    heads: [u64; 2],
+}
+
+impl AuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, state: &State, possible_actions: &[Action], chosen: &Action) {
+        let player = chosen.actor;
+        let state_hash = stable_hash(state);
+        let actions_hash =
+            stable_hash(&possible_actions.iter().map(action_key).collect::<Vec<_>>());
+        let chosen_hash = stable_hash(&action_key(chosen));
+        let digest = chain(self.heads[player], state_hash, actions_hash, chosen_hash);
+        self.heads[player] = digest;
+        self.entries.push(AuditEntry {
+            player,
+            state_hash,
+            actions_hash,
+            chosen_hash,
+            digest,
+        });
+    }
+
+    /// Latest digest of the given player's chain (0 if they haven't decided anything yet).
+    pub fn head(&self, player: usize) -> u64 {
+        self.heads[player]
+    }
+
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Recomputes both chains from the entries, checking nothing was altered after the fact.
+    pub fn verify(&self) -> bool {
+        let mut heads = [0, 0];
+        for entry in &self.entries {
+            let digest = chain(
+                heads[entry.player],
+                entry.state_hash,
+                entry.actions_hash,
+                entry.chosen_hash,
+            );
+            if digest != entry.digest {
+                return false;
+            }
+            heads[entry.player] = digest;
+        }
+        heads == self.heads
+    }
+
+    /// Index of the first entry where two logs of the same game diverge, if any.
+    pub fn first_divergence(&self, other: &AuditLog) -> Option<usize> {
+        let common = self.entries.len().min(other.entries.len());
+        (0..common)
+            .find(|&i| self.entries[i] != other.entries[i])
+            .or((self.entries.len() != other.entries.len()).then_some(common))
+    }
+}
+
+// Action doesn't implement Hash, so hash its fields instead.
+fn action_key(action: &Action) -> (usize, &crate::actions::SimpleAction, bool) {
+    (action.actor, &action.action, action.is_stack)
+}
+
+fn chain(previous: u64, state_hash: u64, actions_hash: u64, chosen_hash: u64) -> u64 {
+    stable_hash(&(previous, state_hash, actions_hash, chosen_hash))
+}
+
+// DefaultHasher's algorithm is unspecified and may change between Rust releases,
+// so use FNV-1a to keep digests comparable across machines and toolchains.
+pub(crate) fn stable_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = FnvHasher(0xcbf29ce484222325);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+struct FnvHasher(u64);
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuditLog;
+    use crate::{
+        players::{Player, RandomPlayer},
+        state::GameOutcome,
+        test_helpers::load_test_decks,
+        Game,
+    };
+
+    fn audited_game(seed: u64) -> (Game, Option<GameOutcome>, AuditLog) {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer { deck: deck_a }),
+            Box::new(RandomPlayer { deck: deck_b }),
+        ];
+        let mut game = Game::new(players, seed);
+        let (outcome, log) = game.play_audited();
+        (game, outcome, log)
+    }
+
+    #[test]
+    fn test_replay_produces_same_chain() {
+        let (game, outcome, log) = audited_game(7);
+        assert_eq!(outcome, game.get_state().winner);
+        assert_eq!(log.entries().len() as u32, game.get_num_plys());
+        assert!(log.verify());
+
+        let (_, _, replay_log) = audited_game(7);
+        assert_eq!(log.head(0), replay_log.head(0));
+        assert_eq!(log.head(1), replay_log.head(1));
+        assert_eq!(log.first_divergence(&replay_log), None);
+    }
+
+    #[test]
+    fn test_detects_tampering_and_divergence() {
+        let (_, _, log) = audited_game(7);
+
+        let mut tampered = log.clone();
+        tampered.entries[3].chosen_hash ^= 1;
+        assert!(!tampered.verify());
+        assert_eq!(log.first_divergence(&tampered), Some(3));
+
+        let (_, _, other) = audited_game(8);
+        assert!(log.first_divergence(&other).is_some());
+    }
+}
@@ -2,7 +2,7 @@ use std::{fs::File, io::Read};
 
 use clap::Parser;
 
-use deckgym::types::{Ability, Attack, Card, EnergyType, TrainerCard};
+use deckgym::types::{Ability, Attack, CardData, EnergyType, TrainerCard};
 use indexmap::IndexMap;
 
 #[derive(Parser, Debug)]
@@ -22,15 +22,15 @@ fn main() {
     let mut json_string = String::new();
     file.read_to_string(&mut json_string)
         .expect("Unable to read string");
-    let deserialized_cards: Vec<Card> = serde_json::from_str(&json_string).unwrap();
+    let deserialized_cards: Vec<CardData> = serde_json::from_str(&json_string).unwrap();
 
     // Generate the Enum Names
-    let mut card_map: IndexMap<String, Card> = IndexMap::new();
+    let mut card_map: IndexMap<String, CardData> = IndexMap::new();
     let mut id_to_enum: IndexMap<String, String> = IndexMap::new();
     let mut numeric_id_to_enum: IndexMap<u16, String> = IndexMap::new();
     for card in deserialized_cards {
         // Remove special characters from the name
-        let mut enum_name = (card.get_id() + &card.get_name())
+        let mut enum_name = (card.get_id().to_string() + card.get_name())
             .replace(" ", "")
             .replace("-", "")
             .replace(".", "")
@@ -44,8 +44,8 @@ fn main() {
             enum_name.push_str("Ex");
         }
         card_map.insert(enum_name.clone(), card.clone());
-        id_to_enum.insert(card.get_id().clone(), enum_name.clone());
-        if let Card::Trainer(TrainerCard { numeric_id, .. }) = &card {
+        id_to_enum.insert(card.get_id().to_string(), enum_name.clone());
+        if let CardData::Trainer(TrainerCard { numeric_id, .. }) = &card {
             numeric_id_to_enum.insert(*numeric_id, enum_name.clone());
         }
     }
@@ -58,7 +58,7 @@ fn main() {
 }
 
 fn print_enums(
-    card_map: &IndexMap<String, Card>,
+    card_map: &IndexMap<String, CardData>,
     id_to_enum: &IndexMap<String, String>,
     numeric_id_to_enum: &IndexMap<u16, String>,
 ) {
@@ -73,6 +73,12 @@ fn print_enums(
     }
     println!("}}\n");
     println!();
+    println!("/// Every card in the database, in database order.");
+    println!("pub const ALL_CARD_IDS: &[CardId] = &[");
+    for (name, _) in card_map.iter() {
+        println!("    CardId::{name},");
+    }
+    println!("];");
     println!();
     println!("impl CardId {{");
     println!("    pub fn from_card_id(id: &str) -> Option<Self> {{");
@@ -95,17 +101,37 @@ fn print_enums(
     println!("}}");
 }
 
-fn print_database(card_map: &IndexMap<String, Card>) {
+fn print_database(card_map: &IndexMap<String, CardData>) {
     println!("// This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.");
     println!();
+    println!("use lazy_static::lazy_static;");
+    println!();
     println!("use crate::{{");
-    println!("    card_ids::CardId,");
+    println!("    card_ids::{{CardId, ALL_CARD_IDS}},");
+    println!("    card_query::CardQuery,");
+    println!("    sets::SetId,");
     println!(
-        "    types::{{Ability, Attack, Card, EnergyType, PokemonCard, TrainerCard, TrainerType}},"
+        "    types::{{Ability, Attack, Card, CardData, EnergyType, PokemonCard, TrainerCard, TrainerType}},"
     );
     println!("}};");
     println!();
+    println!("lazy_static! {{");
+    println!("    // Registry of every card's data, indexed by CardId, that Cards point into.");
+    println!("    static ref CARDS: Vec<CardData> = ALL_CARD_IDS.iter().map(|id| get_card_data(*id)).collect();");
+    println!("}}");
+    println!();
     println!("pub fn get_card_by_enum(id: CardId) -> Card {{");
+    println!("    CARDS[id as usize].as_card()");
+    println!("}}");
+    println!();
+    println!("pub use crate::card_query::find_by_name;");
+    println!();
+    println!("/// The cards matching the query, in `ALL_CARD_IDS` order.");
+    println!("pub fn query(query: CardQuery) -> impl Iterator<Item = Card> {{");
+    println!("    CARDS.iter().map(CardData::as_card).filter(move |card| query.matches(card))");
+    println!("}}");
+    println!();
+    println!("fn get_card_data(id: CardId) -> CardData {{");
     println!("    match id {{");
     for (enum_name, card) in card_map.iter() {
         print_card(enum_name, card);
@@ -114,10 +140,10 @@ fn print_database(card_map: &IndexMap<String, Card>) {
     println!("}}");
 }
 
-fn print_card(enum_name: &str, card: &Card) {
+fn print_card(enum_name: &str, card: &CardData) {
     match card {
-        Card::Pokemon(pokemon_card) => {
-            println!("        CardId::{enum_name} => Card::Pokemon(PokemonCard {{");
+        CardData::Pokemon(pokemon_card) => {
+            println!("        CardId::{enum_name} => CardData::Pokemon(PokemonCard {{");
             println!("            id: \"{}\".to_string(),", pokemon_card.id);
             println!("            name: \"{}\".to_string(),", pokemon_card.name);
             println!("            stage: {},", pokemon_card.stage);
@@ -147,14 +173,11 @@ fn print_card(enum_name: &str, card: &Card) {
                 "            rarity: \"{}\".to_string(),",
                 pokemon_card.rarity
             );
-            println!(
-                "            booster_pack: \"{}\".to_string(),",
-                pokemon_card.booster_pack
-            );
+            println!("            set: SetId::{:?},", pokemon_card.set);
             println!("        }}),");
         }
-        Card::Trainer(trainer_card) => {
-            println!("        CardId::{enum_name} => Card::Trainer(TrainerCard {{");
+        CardData::Trainer(trainer_card) => {
+            println!("        CardId::{enum_name} => CardData::Trainer(TrainerCard {{");
             println!("            id: \"{}\".to_string(),", trainer_card.id);
             println!("            numeric_id: {},", trainer_card.numeric_id);
             println!("            name: \"{}\".to_string(),", trainer_card.name);
@@ -166,10 +189,7 @@ fn print_card(enum_name: &str, card: &Card) {
                 "            rarity: \"{}\".to_string(),",
                 trainer_card.rarity
             );
-            println!(
-                "            booster_pack: \"{}\".to_string(),",
-                trainer_card.booster_pack
-            );
+            println!("            set: SetId::{:?},", trainer_card.set);
             println!(
                 "            trainer_card_type: TrainerType::{:?},",
                 trainer_card.trainer_card_type
@@ -0,0 +1,20 @@
+use std::net::SocketAddr;
+
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(name = "grpc_server")]
+#[command(about = "Serve deckgym simulations over gRPC (see proto/deckgym.proto)")]
+struct Args {
+    /// Address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:50051")]
+    addr: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_logger::init();
+    let args = Args::parse();
+    deckgym::grpc::serve(args.addr).await?;
+    Ok(())
+}
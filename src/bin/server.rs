@@ -0,0 +1,17 @@
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(name = "server")]
+#[command(about = "Serve Pokémon TCG Pocket matches to remote clients over WebSocket")]
+struct Args {
+    /// Address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:9001")]
+    addr: String,
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init();
+    let args = Args::parse();
+    deckgym::server::serve(&args.addr).await
+}
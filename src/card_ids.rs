@@ -1314,6 +1314,1318 @@ pub enum CardId {
     PA073Toucannon,
 }
 
+/// Every card in the database, in database order.
+pub const ALL_CARD_IDS: &[CardId] = &[
+    CardId::A1001Bulbasaur,
+    CardId::A1002Ivysaur,
+    CardId::A1003Venusaur,
+    CardId::A1004VenusaurEx,
+    CardId::A1005Caterpie,
+    CardId::A1006Metapod,
+    CardId::A1007Butterfree,
+    CardId::A1008Weedle,
+    CardId::A1009Kakuna,
+    CardId::A1010Beedrill,
+    CardId::A1011Oddish,
+    CardId::A1012Gloom,
+    CardId::A1013Vileplume,
+    CardId::A1014Paras,
+    CardId::A1015Parasect,
+    CardId::A1016Venonat,
+    CardId::A1017Venomoth,
+    CardId::A1018Bellsprout,
+    CardId::A1019Weepinbell,
+    CardId::A1020Victreebel,
+    CardId::A1021Exeggcute,
+    CardId::A1022Exeggutor,
+    CardId::A1023ExeggutorEx,
+    CardId::A1024Tangela,
+    CardId::A1025Scyther,
+    CardId::A1026Pinsir,
+    CardId::A1027Cottonee,
+    CardId::A1028Whimsicott,
+    CardId::A1029Petilil,
+    CardId::A1030Lilligant,
+    CardId::A1031Skiddo,
+    CardId::A1032Gogoat,
+    CardId::A1033Charmander,
+    CardId::A1034Charmeleon,
+    CardId::A1035Charizard,
+    CardId::A1036CharizardEx,
+    CardId::A1037Vulpix,
+    CardId::A1038Ninetales,
+    CardId::A1039Growlithe,
+    CardId::A1040Arcanine,
+    CardId::A1041ArcanineEx,
+    CardId::A1042Ponyta,
+    CardId::A1043Rapidash,
+    CardId::A1044Magmar,
+    CardId::A1045Flareon,
+    CardId::A1046Moltres,
+    CardId::A1047MoltresEx,
+    CardId::A1048Heatmor,
+    CardId::A1049Salandit,
+    CardId::A1050Salazzle,
+    CardId::A1051Sizzlipede,
+    CardId::A1052Centiskorch,
+    CardId::A1053Squirtle,
+    CardId::A1054Wartortle,
+    CardId::A1055Blastoise,
+    CardId::A1056BlastoiseEx,
+    CardId::A1057Psyduck,
+    CardId::A1058Golduck,
+    CardId::A1059Poliwag,
+    CardId::A1060Poliwhirl,
+    CardId::A1061Poliwrath,
+    CardId::A1062Tentacool,
+    CardId::A1063Tentacruel,
+    CardId::A1064Seel,
+    CardId::A1065Dewgong,
+    CardId::A1066Shellder,
+    CardId::A1067Cloyster,
+    CardId::A1068Krabby,
+    CardId::A1069Kingler,
+    CardId::A1070Horsea,
+    CardId::A1071Seadra,
+    CardId::A1072Goldeen,
+    CardId::A1073Seaking,
+    CardId::A1074Staryu,
+    CardId::A1075Starmie,
+    CardId::A1076StarmieEx,
+    CardId::A1077Magikarp,
+    CardId::A1078Gyarados,
+    CardId::A1079Lapras,
+    CardId::A1080Vaporeon,
+    CardId::A1081Omanyte,
+    CardId::A1082Omastar,
+    CardId::A1083Articuno,
+    CardId::A1084ArticunoEx,
+    CardId::A1085Ducklett,
+    CardId::A1086Swanna,
+    CardId::A1087Froakie,
+    CardId::A1088Frogadier,
+    CardId::A1089Greninja,
+    CardId::A1090Pyukumuku,
+    CardId::A1091Bruxish,
+    CardId::A1092Snom,
+    CardId::A1093Frosmoth,
+    CardId::A1094Pikachu,
+    CardId::A1095Raichu,
+    CardId::A1096PikachuEx,
+    CardId::A1097Magnemite,
+    CardId::A1098Magneton,
+    CardId::A1099Voltorb,
+    CardId::A1100Electrode,
+    CardId::A1101Electabuzz,
+    CardId::A1102Jolteon,
+    CardId::A1103Zapdos,
+    CardId::A1104ZapdosEx,
+    CardId::A1105Blitzle,
+    CardId::A1106Zebstrika,
+    CardId::A1107Tynamo,
+    CardId::A1108Eelektrik,
+    CardId::A1109Eelektross,
+    CardId::A1110Helioptile,
+    CardId::A1111Heliolisk,
+    CardId::A1112Pincurchin,
+    CardId::A1113Clefairy,
+    CardId::A1114Clefable,
+    CardId::A1115Abra,
+    CardId::A1116Kadabra,
+    CardId::A1117Alakazam,
+    CardId::A1118Slowpoke,
+    CardId::A1119Slowbro,
+    CardId::A1120Gastly,
+    CardId::A1121Haunter,
+    CardId::A1122Gengar,
+    CardId::A1123GengarEx,
+    CardId::A1124Drowzee,
+    CardId::A1125Hypno,
+    CardId::A1126MrMime,
+    CardId::A1127Jynx,
+    CardId::A1128Mewtwo,
+    CardId::A1129MewtwoEx,
+    CardId::A1130Ralts,
+    CardId::A1131Kirlia,
+    CardId::A1132Gardevoir,
+    CardId::A1133Woobat,
+    CardId::A1134Swoobat,
+    CardId::A1135Golett,
+    CardId::A1136Golurk,
+    CardId::A1137Sandshrew,
+    CardId::A1138Sandslash,
+    CardId::A1139Diglett,
+    CardId::A1140Dugtrio,
+    CardId::A1141Mankey,
+    CardId::A1142Primeape,
+    CardId::A1143Machop,
+    CardId::A1144Machoke,
+    CardId::A1145Machamp,
+    CardId::A1146MachampEx,
+    CardId::A1147Geodude,
+    CardId::A1148Graveler,
+    CardId::A1149Golem,
+    CardId::A1150Onix,
+    CardId::A1151Cubone,
+    CardId::A1152Marowak,
+    CardId::A1153MarowakEx,
+    CardId::A1154Hitmonlee,
+    CardId::A1155Hitmonchan,
+    CardId::A1156Rhyhorn,
+    CardId::A1157Rhydon,
+    CardId::A1158Kabuto,
+    CardId::A1159Kabutops,
+    CardId::A1160Mienfoo,
+    CardId::A1161Mienshao,
+    CardId::A1162Clobbopus,
+    CardId::A1163Grapploct,
+    CardId::A1164Ekans,
+    CardId::A1165Arbok,
+    CardId::A1166NidoranF,
+    CardId::A1167Nidorina,
+    CardId::A1168Nidoqueen,
+    CardId::A1169NidoranM,
+    CardId::A1170Nidorino,
+    CardId::A1171Nidoking,
+    CardId::A1172Zubat,
+    CardId::A1173Golbat,
+    CardId::A1174Grimer,
+    CardId::A1175Muk,
+    CardId::A1176Koffing,
+    CardId::A1177Weezing,
+    CardId::A1178Mawile,
+    CardId::A1179Pawniard,
+    CardId::A1180Bisharp,
+    CardId::A1181Meltan,
+    CardId::A1182Melmetal,
+    CardId::A1183Dratini,
+    CardId::A1184Dragonair,
+    CardId::A1185Dragonite,
+    CardId::A1186Pidgey,
+    CardId::A1187Pidgeotto,
+    CardId::A1188Pidgeot,
+    CardId::A1189Rattata,
+    CardId::A1190Raticate,
+    CardId::A1191Spearow,
+    CardId::A1192Fearow,
+    CardId::A1193Jigglypuff,
+    CardId::A1194Wigglytuff,
+    CardId::A1195WigglytuffEx,
+    CardId::A1196Meowth,
+    CardId::A1197Persian,
+    CardId::A1198Farfetchd,
+    CardId::A1199Doduo,
+    CardId::A1200Dodrio,
+    CardId::A1201Lickitung,
+    CardId::A1202Chansey,
+    CardId::A1203Kangaskhan,
+    CardId::A1204Tauros,
+    CardId::A1205Ditto,
+    CardId::A1206Eevee,
+    CardId::A1207Eevee,
+    CardId::A1208Eevee,
+    CardId::A1209Porygon,
+    CardId::A1210Aerodactyl,
+    CardId::A1211Snorlax,
+    CardId::A1212Minccino,
+    CardId::A1213Cinccino,
+    CardId::A1214Wooloo,
+    CardId::A1215Dubwool,
+    CardId::A1216HelixFossil,
+    CardId::A1217DomeFossil,
+    CardId::A1218OldAmber,
+    CardId::A1219Erika,
+    CardId::A1220Misty,
+    CardId::A1221Blaine,
+    CardId::A1222Koga,
+    CardId::A1223Giovanni,
+    CardId::A1224Brock,
+    CardId::A1225Sabrina,
+    CardId::A1226LtSurge,
+    CardId::A1227Bulbasaur,
+    CardId::A1228Gloom,
+    CardId::A1229Pinsir,
+    CardId::A1230Charmander,
+    CardId::A1231Rapidash,
+    CardId::A1232Squirtle,
+    CardId::A1233Gyarados,
+    CardId::A1234Lapras,
+    CardId::A1235Electrode,
+    CardId::A1236Alakazam,
+    CardId::A1237Slowpoke,
+    CardId::A1238Diglett,
+    CardId::A1239Cubone,
+    CardId::A1240Nidoqueen,
+    CardId::A1241Nidoking,
+    CardId::A1242Golbat,
+    CardId::A1243Weezing,
+    CardId::A1244Dragonite,
+    CardId::A1245Pidgeot,
+    CardId::A1246Meowth,
+    CardId::A1247Ditto,
+    CardId::A1248Eevee,
+    CardId::A1249Porygon,
+    CardId::A1250Snorlax,
+    CardId::A1251VenusaurEx,
+    CardId::A1252ExeggutorEx,
+    CardId::A1253CharizardEx,
+    CardId::A1254ArcanineEx,
+    CardId::A1255MoltresEx,
+    CardId::A1256BlastoiseEx,
+    CardId::A1257StarmieEx,
+    CardId::A1258ArticunoEx,
+    CardId::A1259PikachuEx,
+    CardId::A1260ZapdosEx,
+    CardId::A1261GengarEx,
+    CardId::A1262MewtwoEx,
+    CardId::A1263MachampEx,
+    CardId::A1264MarowakEx,
+    CardId::A1265WigglytuffEx,
+    CardId::A1266Erika,
+    CardId::A1267Misty,
+    CardId::A1268Blaine,
+    CardId::A1269Koga,
+    CardId::A1270Giovanni,
+    CardId::A1271Brock,
+    CardId::A1272Sabrina,
+    CardId::A1273LtSurge,
+    CardId::A1274MoltresEx,
+    CardId::A1275ArticunoEx,
+    CardId::A1276ZapdosEx,
+    CardId::A1277GengarEx,
+    CardId::A1278MachampEx,
+    CardId::A1279WigglytuffEx,
+    CardId::A1280CharizardEx,
+    CardId::A1281PikachuEx,
+    CardId::A1282MewtwoEx,
+    CardId::A1283Mew,
+    CardId::A1284CharizardEx,
+    CardId::A1285PikachuEx,
+    CardId::A1286MewtwoEx,
+    CardId::A1a001Exeggcute,
+    CardId::A1a002Exeggutor,
+    CardId::A1a003CelebiEx,
+    CardId::A1a004Snivy,
+    CardId::A1a005Servine,
+    CardId::A1a006Serperior,
+    CardId::A1a007Morelull,
+    CardId::A1a008Shiinotic,
+    CardId::A1a009Dhelmise,
+    CardId::A1a010Ponyta,
+    CardId::A1a011Rapidash,
+    CardId::A1a012Magmar,
+    CardId::A1a013Larvesta,
+    CardId::A1a014Volcarona,
+    CardId::A1a015Salandit,
+    CardId::A1a016Salazzle,
+    CardId::A1a017Magikarp,
+    CardId::A1a018GyaradosEx,
+    CardId::A1a019Vaporeon,
+    CardId::A1a020Finneon,
+    CardId::A1a021Lumineon,
+    CardId::A1a022Chewtle,
+    CardId::A1a023Drednaw,
+    CardId::A1a024Cramorant,
+    CardId::A1a025Pikachu,
+    CardId::A1a026Raichu,
+    CardId::A1a027Electabuzz,
+    CardId::A1a028Joltik,
+    CardId::A1a029Galvantula,
+    CardId::A1a030Dedenne,
+    CardId::A1a031Mew,
+    CardId::A1a032MewEx,
+    CardId::A1a033Sigilyph,
+    CardId::A1a034Elgyem,
+    CardId::A1a035Beheeyem,
+    CardId::A1a036Flabebe,
+    CardId::A1a037Floette,
+    CardId::A1a038Florges,
+    CardId::A1a039Swirlix,
+    CardId::A1a040Slurpuff,
+    CardId::A1a041Mankey,
+    CardId::A1a042Primeape,
+    CardId::A1a043Geodude,
+    CardId::A1a044Graveler,
+    CardId::A1a045Golem,
+    CardId::A1a046AerodactylEx,
+    CardId::A1a047Marshadow,
+    CardId::A1a048Stonjourner,
+    CardId::A1a049Koffing,
+    CardId::A1a050Weezing,
+    CardId::A1a051Purrloin,
+    CardId::A1a052Liepard,
+    CardId::A1a053Venipede,
+    CardId::A1a054Whirlipede,
+    CardId::A1a055Scolipede,
+    CardId::A1a056Druddigon,
+    CardId::A1a057Pidgey,
+    CardId::A1a058Pidgeotto,
+    CardId::A1a059PidgeotEx,
+    CardId::A1a060Tauros,
+    CardId::A1a061Eevee,
+    CardId::A1a062Chatot,
+    CardId::A1a063OldAmber,
+    CardId::A1a064PokemonFlute,
+    CardId::A1a065MythicalSlab,
+    CardId::A1a066BuddingExpeditioner,
+    CardId::A1a067Blue,
+    CardId::A1a068Leaf,
+    CardId::A1a069Exeggutor,
+    CardId::A1a070Serperior,
+    CardId::A1a071Salandit,
+    CardId::A1a072Vaporeon,
+    CardId::A1a073Dedenne,
+    CardId::A1a074Marshadow,
+    CardId::A1a075CelebiEx,
+    CardId::A1a076GyaradosEx,
+    CardId::A1a077MewEx,
+    CardId::A1a078AerodactylEx,
+    CardId::A1a079PidgeotEx,
+    CardId::A1a080BuddingExpeditioner,
+    CardId::A1a081Blue,
+    CardId::A1a082Leaf,
+    CardId::A1a083MewEx,
+    CardId::A1a084AerodactylEx,
+    CardId::A1a085CelebiEx,
+    CardId::A1a086MewEx,
+    CardId::A2001Oddish,
+    CardId::A2002Gloom,
+    CardId::A2003Bellossom,
+    CardId::A2004Tangela,
+    CardId::A2005Tangrowth,
+    CardId::A2006Yanma,
+    CardId::A2007YanmegaEx,
+    CardId::A2008Roselia,
+    CardId::A2009Roserade,
+    CardId::A2010Turtwig,
+    CardId::A2011Grotle,
+    CardId::A2012Torterra,
+    CardId::A2013Kricketot,
+    CardId::A2014Kricketune,
+    CardId::A2015Burmy,
+    CardId::A2016Wormadam,
+    CardId::A2017Combee,
+    CardId::A2018Vespiquen,
+    CardId::A2019Carnivine,
+    CardId::A2020Leafeon,
+    CardId::A2021MowRotom,
+    CardId::A2022Shaymin,
+    CardId::A2023Magmar,
+    CardId::A2024Magmortar,
+    CardId::A2025Slugma,
+    CardId::A2026Magcargo,
+    CardId::A2027Chimchar,
+    CardId::A2028Monferno,
+    CardId::A2029InfernapeEx,
+    CardId::A2030HeatRotom,
+    CardId::A2031Swinub,
+    CardId::A2032Piloswine,
+    CardId::A2033Mamoswine,
+    CardId::A2034Regice,
+    CardId::A2035Piplup,
+    CardId::A2036Prinplup,
+    CardId::A2037Empoleon,
+    CardId::A2038Buizel,
+    CardId::A2039Floatzel,
+    CardId::A2040Shellos,
+    CardId::A2041Gastrodon,
+    CardId::A2042Finneon,
+    CardId::A2043Lumineon,
+    CardId::A2044Snover,
+    CardId::A2045Abomasnow,
+    CardId::A2046Glaceon,
+    CardId::A2047WashRotom,
+    CardId::A2048FrostRotom,
+    CardId::A2049PalkiaEx,
+    CardId::A2050Manaphy,
+    CardId::A2051Magnemite,
+    CardId::A2052Magneton,
+    CardId::A2053Magnezone,
+    CardId::A2054Voltorb,
+    CardId::A2055Electrode,
+    CardId::A2056Electabuzz,
+    CardId::A2057Electivire,
+    CardId::A2058Shinx,
+    CardId::A2059Luxio,
+    CardId::A2060Luxray,
+    CardId::A2061PachirisuEx,
+    CardId::A2062Rotom,
+    CardId::A2063Togepi,
+    CardId::A2064Togetic,
+    CardId::A2065Togekiss,
+    CardId::A2066Misdreavus,
+    CardId::A2067MismagiusEx,
+    CardId::A2068Ralts,
+    CardId::A2069Kirlia,
+    CardId::A2070Duskull,
+    CardId::A2071Dusclops,
+    CardId::A2072Dusknoir,
+    CardId::A2073Drifloon,
+    CardId::A2074Drifblim,
+    CardId::A2075Uxie,
+    CardId::A2076Mesprit,
+    CardId::A2077Azelf,
+    CardId::A2078Giratina,
+    CardId::A2079Cresselia,
+    CardId::A2080Rhyhorn,
+    CardId::A2081Rhydon,
+    CardId::A2082Rhyperior,
+    CardId::A2083Gligar,
+    CardId::A2084Gliscor,
+    CardId::A2085Hitmontop,
+    CardId::A2086Nosepass,
+    CardId::A2087Regirock,
+    CardId::A2088Cranidos,
+    CardId::A2089Rampardos,
+    CardId::A2090Wormadam,
+    CardId::A2091Riolu,
+    CardId::A2092Lucario,
+    CardId::A2093Hippopotas,
+    CardId::A2094Hippowdon,
+    CardId::A2095GalladeEx,
+    CardId::A2096Murkrow,
+    CardId::A2097Honchkrow,
+    CardId::A2098Sneasel,
+    CardId::A2099WeavileEx,
+    CardId::A2100Poochyena,
+    CardId::A2101Mightyena,
+    CardId::A2102Stunky,
+    CardId::A2103Skuntank,
+    CardId::A2104Spiritomb,
+    CardId::A2105Skorupi,
+    CardId::A2106Drapion,
+    CardId::A2107Croagunk,
+    CardId::A2108Toxicroak,
+    CardId::A2109Darkrai,
+    CardId::A2110DarkraiEx,
+    CardId::A2111Skarmory,
+    CardId::A2112Registeel,
+    CardId::A2113Shieldon,
+    CardId::A2114Bastiodon,
+    CardId::A2115Wormadam,
+    CardId::A2116Bronzor,
+    CardId::A2117Bronzong,
+    CardId::A2118Probopass,
+    CardId::A2119DialgaEx,
+    CardId::A2120Heatran,
+    CardId::A2121Gible,
+    CardId::A2122Gabite,
+    CardId::A2123Garchomp,
+    CardId::A2124Lickitung,
+    CardId::A2125LickilickyEx,
+    CardId::A2126Eevee,
+    CardId::A2127Porygon,
+    CardId::A2128Porygon2,
+    CardId::A2129PorygonZ,
+    CardId::A2130Aipom,
+    CardId::A2131Ambipom,
+    CardId::A2132Starly,
+    CardId::A2133Staravia,
+    CardId::A2134Staraptor,
+    CardId::A2135Bidoof,
+    CardId::A2136Bibarel,
+    CardId::A2137Buneary,
+    CardId::A2138Lopunny,
+    CardId::A2139Glameow,
+    CardId::A2140Purugly,
+    CardId::A2141Chatot,
+    CardId::A2142FanRotom,
+    CardId::A2143Regigigas,
+    CardId::A2144SkullFossil,
+    CardId::A2145ArmorFossil,
+    CardId::A2146PokemonCommunication,
+    CardId::A2147GiantCape,
+    CardId::A2148RockyHelmet,
+    CardId::A2149LumBerry,
+    CardId::A2150Cyrus,
+    CardId::A2151TeamGalacticGrunt,
+    CardId::A2152Cynthia,
+    CardId::A2153Volkner,
+    CardId::A2154Dawn,
+    CardId::A2155Mars,
+    CardId::A2156Tangrowth,
+    CardId::A2157Combee,
+    CardId::A2158Carnivine,
+    CardId::A2159Shaymin,
+    CardId::A2160Mamoswine,
+    CardId::A2161Gastrodon,
+    CardId::A2162Manaphy,
+    CardId::A2163Shinx,
+    CardId::A2164Rotom,
+    CardId::A2165Drifloon,
+    CardId::A2166Mesprit,
+    CardId::A2167Giratina,
+    CardId::A2168Cresselia,
+    CardId::A2169Rhyperior,
+    CardId::A2170Lucario,
+    CardId::A2171Hippopotas,
+    CardId::A2172Spiritomb,
+    CardId::A2173Croagunk,
+    CardId::A2174Heatran,
+    CardId::A2175Garchomp,
+    CardId::A2176Staraptor,
+    CardId::A2177Bidoof,
+    CardId::A2178Glameow,
+    CardId::A2179Regigigas,
+    CardId::A2180YanmegaEx,
+    CardId::A2181InfernapeEx,
+    CardId::A2182PalkiaEx,
+    CardId::A2183PachirisuEx,
+    CardId::A2184MismagiusEx,
+    CardId::A2185GalladeEx,
+    CardId::A2186WeavileEx,
+    CardId::A2187DarkraiEx,
+    CardId::A2188DialgaEx,
+    CardId::A2189LickilickyEx,
+    CardId::A2190Cyrus,
+    CardId::A2191TeamGalacticGrunt,
+    CardId::A2192Cynthia,
+    CardId::A2193Volkner,
+    CardId::A2194Dawn,
+    CardId::A2195Mars,
+    CardId::A2196YanmegaEx,
+    CardId::A2197InfernapeEx,
+    CardId::A2198PachirisuEx,
+    CardId::A2199MismagiusEx,
+    CardId::A2200GalladeEx,
+    CardId::A2201WeavileEx,
+    CardId::A2202DarkraiEx,
+    CardId::A2203LickilickyEx,
+    CardId::A2204PalkiaEx,
+    CardId::A2205DialgaEx,
+    CardId::A2206PalkiaEx,
+    CardId::A2207DialgaEx,
+    CardId::A2a001Heracross,
+    CardId::A2a002Burmy,
+    CardId::A2a003Mothim,
+    CardId::A2a004Combee,
+    CardId::A2a005Vespiquen,
+    CardId::A2a006Cherubi,
+    CardId::A2a007Cherrim,
+    CardId::A2a008Cherrim,
+    CardId::A2a009Carnivine,
+    CardId::A2a010LeafeonEx,
+    CardId::A2a011Houndour,
+    CardId::A2a012Houndoom,
+    CardId::A2a013Heatran,
+    CardId::A2a014Marill,
+    CardId::A2a015Azumarill,
+    CardId::A2a016Barboach,
+    CardId::A2a017Whiscash,
+    CardId::A2a018Snorunt,
+    CardId::A2a019Froslass,
+    CardId::A2a020Snover,
+    CardId::A2a021Abomasnow,
+    CardId::A2a022GlaceonEx,
+    CardId::A2a023OriginFormePalkia,
+    CardId::A2a024Phione,
+    CardId::A2a025Pikachu,
+    CardId::A2a026Raichu,
+    CardId::A2a027Electrike,
+    CardId::A2a028Manectric,
+    CardId::A2a029Clefairy,
+    CardId::A2a030Clefable,
+    CardId::A2a031Gastly,
+    CardId::A2a032Haunter,
+    CardId::A2a033Gengar,
+    CardId::A2a034Unown,
+    CardId::A2a035Rotom,
+    CardId::A2a036Sudowoodo,
+    CardId::A2a037Phanpy,
+    CardId::A2a038Donphan,
+    CardId::A2a039Larvitar,
+    CardId::A2a040Pupitar,
+    CardId::A2a041Tyranitar,
+    CardId::A2a042Nosepass,
+    CardId::A2a043Meditite,
+    CardId::A2a044Medicham,
+    CardId::A2a045Gible,
+    CardId::A2a046Gabite,
+    CardId::A2a047GarchompEx,
+    CardId::A2a048Zubat,
+    CardId::A2a049Golbat,
+    CardId::A2a050Crobat,
+    CardId::A2a051Croagunk,
+    CardId::A2a052Toxicroak,
+    CardId::A2a053Magnemite,
+    CardId::A2a054Magneton,
+    CardId::A2a055Magnezone,
+    CardId::A2a056Mawile,
+    CardId::A2a057ProbopassEx,
+    CardId::A2a058Bronzor,
+    CardId::A2a059Bronzong,
+    CardId::A2a060OriginFormeDialga,
+    CardId::A2a061Giratina,
+    CardId::A2a062Eevee,
+    CardId::A2a063Snorlax,
+    CardId::A2a064Hoothoot,
+    CardId::A2a065Noctowl,
+    CardId::A2a066Starly,
+    CardId::A2a067Staravia,
+    CardId::A2a068Staraptor,
+    CardId::A2a069Shaymin,
+    CardId::A2a070Arceus,
+    CardId::A2a071ArceusEx,
+    CardId::A2a072Irida,
+    CardId::A2a073CelesticTownElder,
+    CardId::A2a074Barry,
+    CardId::A2a075Adaman,
+    CardId::A2a076Houndoom,
+    CardId::A2a077Marill,
+    CardId::A2a078Unown,
+    CardId::A2a079Sudowoodo,
+    CardId::A2a080Magnemite,
+    CardId::A2a081Shaymin,
+    CardId::A2a082LeafeonEx,
+    CardId::A2a083GlaceonEx,
+    CardId::A2a084GarchompEx,
+    CardId::A2a085ProbopassEx,
+    CardId::A2a086ArceusEx,
+    CardId::A2a087Irida,
+    CardId::A2a088CelesticTownElder,
+    CardId::A2a089Barry,
+    CardId::A2a090Adaman,
+    CardId::A2a091LeafeonEx,
+    CardId::A2a092GlaceonEx,
+    CardId::A2a093GarchompEx,
+    CardId::A2a094ProbopassEx,
+    CardId::A2a095ArceusEx,
+    CardId::A2a096ArceusEx,
+    CardId::A2b001Weedle,
+    CardId::A2b002Kakuna,
+    CardId::A2b003BeedrillEx,
+    CardId::A2b004Pinsir,
+    CardId::A2b005Sprigatito,
+    CardId::A2b006Floragato,
+    CardId::A2b007Meowscarada,
+    CardId::A2b008Charmander,
+    CardId::A2b009Charmeleon,
+    CardId::A2b010CharizardEx,
+    CardId::A2b011Magmar,
+    CardId::A2b012Magmortar,
+    CardId::A2b013PaldeanTauros,
+    CardId::A2b014Tentacool,
+    CardId::A2b015Tentacruel,
+    CardId::A2b016Buizel,
+    CardId::A2b017Floatzel,
+    CardId::A2b018Wiglett,
+    CardId::A2b019WugtrioEx,
+    CardId::A2b020Dondozo,
+    CardId::A2b021Tatsugiri,
+    CardId::A2b022PikachuEx,
+    CardId::A2b023Voltorb,
+    CardId::A2b024Electrode,
+    CardId::A2b025Pachirisu,
+    CardId::A2b026Pawmi,
+    CardId::A2b027Pawmo,
+    CardId::A2b028Pawmot,
+    CardId::A2b029Abra,
+    CardId::A2b030Kadabra,
+    CardId::A2b031Alakazam,
+    CardId::A2b032MrMime,
+    CardId::A2b033Drifloon,
+    CardId::A2b034Drifblim,
+    CardId::A2b035GiratinaEx,
+    CardId::A2b036Gimmighoul,
+    CardId::A2b037Machop,
+    CardId::A2b038Machoke,
+    CardId::A2b039Machamp,
+    CardId::A2b040Hitmonlee,
+    CardId::A2b041Hitmonchan,
+    CardId::A2b042Riolu,
+    CardId::A2b043LucarioEx,
+    CardId::A2b044Flamigo,
+    CardId::A2b045Ekans,
+    CardId::A2b046Arbok,
+    CardId::A2b047PaldeanWooper,
+    CardId::A2b048PaldeanClodsireEx,
+    CardId::A2b049Spiritomb,
+    CardId::A2b050Shroodle,
+    CardId::A2b051Grafaiai,
+    CardId::A2b052Tinkatink,
+    CardId::A2b053Tinkatuff,
+    CardId::A2b054TinkatonEx,
+    CardId::A2b055Varoom,
+    CardId::A2b056Revavroom,
+    CardId::A2b057Gholdengo,
+    CardId::A2b058Rattata,
+    CardId::A2b059Raticate,
+    CardId::A2b060Jigglypuff,
+    CardId::A2b061Wigglytuff,
+    CardId::A2b062Lickitung,
+    CardId::A2b063Lickilicky,
+    CardId::A2b064Bidoof,
+    CardId::A2b065BibarelEx,
+    CardId::A2b066Buneary,
+    CardId::A2b067Lopunny,
+    CardId::A2b068Cyclizar,
+    CardId::A2b069Iono,
+    CardId::A2b070PokemonCenterLady,
+    CardId::A2b071Red,
+    CardId::A2b072TeamRocketGrunt,
+    CardId::A2b073Meowscarada,
+    CardId::A2b074Buizel,
+    CardId::A2b075Tatsugiri,
+    CardId::A2b076Grafaiai,
+    CardId::A2b077Gholdengo,
+    CardId::A2b078Wigglytuff,
+    CardId::A2b079BeedrillEx,
+    CardId::A2b080CharizardEx,
+    CardId::A2b081WugtrioEx,
+    CardId::A2b082PikachuEx,
+    CardId::A2b083GiratinaEx,
+    CardId::A2b084LucarioEx,
+    CardId::A2b085PaldeanClodsireEx,
+    CardId::A2b086TinkatonEx,
+    CardId::A2b087BibarelEx,
+    CardId::A2b088Iono,
+    CardId::A2b089PokemonCenterLady,
+    CardId::A2b090Red,
+    CardId::A2b091TeamRocketGrunt,
+    CardId::A2b092PikachuEx,
+    CardId::A2b093PaldeanClodsireEx,
+    CardId::A2b094TinkatonEx,
+    CardId::A2b095BibarelEx,
+    CardId::A2b096GiratinaEx,
+    CardId::A2b097Weedle,
+    CardId::A2b098Kakuna,
+    CardId::A2b099Charmander,
+    CardId::A2b100Charmeleon,
+    CardId::A2b101Wiglett,
+    CardId::A2b102Dondozo,
+    CardId::A2b103Pachirisu,
+    CardId::A2b104Riolu,
+    CardId::A2b105Varoom,
+    CardId::A2b106Revavroom,
+    CardId::A2b107BeedrillEx,
+    CardId::A2b108CharizardEx,
+    CardId::A2b109WugtrioEx,
+    CardId::A2b110LucarioEx,
+    CardId::A2b111PokeBall,
+    CardId::A3001Exeggcute,
+    CardId::A3002AlolanExeggutor,
+    CardId::A3003Surskit,
+    CardId::A3004Masquerain,
+    CardId::A3005Maractus,
+    CardId::A3006Karrablast,
+    CardId::A3007Phantump,
+    CardId::A3008Trevenant,
+    CardId::A3009Rowlet,
+    CardId::A3010Rowlet,
+    CardId::A3011Dartrix,
+    CardId::A3012DecidueyeEx,
+    CardId::A3013Grubbin,
+    CardId::A3014Fomantis,
+    CardId::A3015Lurantis,
+    CardId::A3016Morelull,
+    CardId::A3017Shiinotic,
+    CardId::A3018Bounsweet,
+    CardId::A3019Steenee,
+    CardId::A3020Tsareena,
+    CardId::A3021Wimpod,
+    CardId::A3022Golisopod,
+    CardId::A3023DhelmiseEx,
+    CardId::A3024TapuBulu,
+    CardId::A3025Growlithe,
+    CardId::A3026Arcanine,
+    CardId::A3027AlolanMarowak,
+    CardId::A3028Fletchinder,
+    CardId::A3029Talonflame,
+    CardId::A3030Litten,
+    CardId::A3031Litten,
+    CardId::A3032Torracat,
+    CardId::A3033IncineroarEx,
+    CardId::A3034Oricorio,
+    CardId::A3035Salandit,
+    CardId::A3036Salazzle,
+    CardId::A3037Turtonator,
+    CardId::A3038AlolanSandshrew,
+    CardId::A3039AlolanSandslash,
+    CardId::A3040AlolanVulpix,
+    CardId::A3041AlolanNinetales,
+    CardId::A3042Shellder,
+    CardId::A3043Cloyster,
+    CardId::A3044Lapras,
+    CardId::A3045Popplio,
+    CardId::A3046Popplio,
+    CardId::A3047Brionne,
+    CardId::A3048Primarina,
+    CardId::A3049CrabominableEx,
+    CardId::A3050Wishiwashi,
+    CardId::A3051WishiwashiEx,
+    CardId::A3052Dewpider,
+    CardId::A3053Araquanid,
+    CardId::A3054Pyukumuku,
+    CardId::A3055Bruxish,
+    CardId::A3056TapuFini,
+    CardId::A3057Pikachu,
+    CardId::A3058AlolanRaichuEx,
+    CardId::A3059AlolanGeodude,
+    CardId::A3060AlolanGraveler,
+    CardId::A3061AlolanGolem,
+    CardId::A3062Helioptile,
+    CardId::A3063Heliolisk,
+    CardId::A3064Charjabug,
+    CardId::A3065Vikavolt,
+    CardId::A3066Oricorio,
+    CardId::A3067Togedemaru,
+    CardId::A3068TapuKoko,
+    CardId::A3069MrMime,
+    CardId::A3070Sableye,
+    CardId::A3071Spoink,
+    CardId::A3072Grumpig,
+    CardId::A3073Lunatone,
+    CardId::A3074Shuppet,
+    CardId::A3075Banette,
+    CardId::A3076Oricorio,
+    CardId::A3077Oricorio,
+    CardId::A3078Cutiefly,
+    CardId::A3079Ribombee,
+    CardId::A3080Comfey,
+    CardId::A3081Sandygast,
+    CardId::A3082Palossand,
+    CardId::A3083Mimikyu,
+    CardId::A3084TapuLele,
+    CardId::A3085Cosmog,
+    CardId::A3086Cosmoem,
+    CardId::A3087LunalaEx,
+    CardId::A3088Necrozma,
+    CardId::A3089Cubone,
+    CardId::A3090Makuhita,
+    CardId::A3091Hariyama,
+    CardId::A3092Solrock,
+    CardId::A3093Drilbur,
+    CardId::A3094Timburr,
+    CardId::A3095Gurdurr,
+    CardId::A3096Conkeldurr,
+    CardId::A3097Crabrawler,
+    CardId::A3098Rockruff,
+    CardId::A3099Rockruff,
+    CardId::A3100Lycanroc,
+    CardId::A3101Lycanroc,
+    CardId::A3102Mudbray,
+    CardId::A3103Mudsdale,
+    CardId::A3104PassimianEx,
+    CardId::A3105Minior,
+    CardId::A3106AlolanRattata,
+    CardId::A3107AlolanRaticate,
+    CardId::A3108AlolanMeowth,
+    CardId::A3109AlolanPersian,
+    CardId::A3110AlolanGrimer,
+    CardId::A3111AlolanMukEx,
+    CardId::A3112Absol,
+    CardId::A3113Trubbish,
+    CardId::A3114Garbodor,
+    CardId::A3115Mareanie,
+    CardId::A3116ToxapEx,
+    CardId::A3117AlolanDiglett,
+    CardId::A3118AlolanDugtrio,
+    CardId::A3119Excadrill,
+    CardId::A3120Escavalier,
+    CardId::A3121Klefki,
+    CardId::A3122SolgaleoEx,
+    CardId::A3123Magearna,
+    CardId::A3124Drampa,
+    CardId::A3125Jangmoo,
+    CardId::A3126Hakamoo,
+    CardId::A3127Kommoo,
+    CardId::A3128Tauros,
+    CardId::A3129Skitty,
+    CardId::A3130Delcatty,
+    CardId::A3131Fletchling,
+    CardId::A3132Hawlucha,
+    CardId::A3133Pikipek,
+    CardId::A3134Trumbeak,
+    CardId::A3135Toucannon,
+    CardId::A3136Yungoos,
+    CardId::A3137Gumshoos,
+    CardId::A3138Stufful,
+    CardId::A3139Bewear,
+    CardId::A3140Oranguru,
+    CardId::A3141Komala,
+    CardId::A3142BigMalasada,
+    CardId::A3143FishingNet,
+    CardId::A3144RareCandy,
+    CardId::A3145RotomDEx,
+    CardId::A3146PoisonBarb,
+    CardId::A3147LeafCape,
+    CardId::A3148Acerola,
+    CardId::A3149Ilima,
+    CardId::A3150Kiawe,
+    CardId::A3151Guzma,
+    CardId::A3152Lana,
+    CardId::A3153Sophocles,
+    CardId::A3154Mallow,
+    CardId::A3155Lillie,
+    CardId::A3156AlolanExeggutor,
+    CardId::A3157Morelull,
+    CardId::A3158Tsareena,
+    CardId::A3159TapuBulu,
+    CardId::A3160AlolanMarowak,
+    CardId::A3161Turtonator,
+    CardId::A3162AlolanVulpix,
+    CardId::A3163Pyukumuku,
+    CardId::A3164TapuFini,
+    CardId::A3165Oricorio,
+    CardId::A3166TapuKoko,
+    CardId::A3167Cutiefly,
+    CardId::A3168Comfey,
+    CardId::A3169Sandygast,
+    CardId::A3170TapuLele,
+    CardId::A3171Cosmog,
+    CardId::A3172Rockruff,
+    CardId::A3173Mudsdale,
+    CardId::A3174Minior,
+    CardId::A3175Magearna,
+    CardId::A3176Drampa,
+    CardId::A3177Pikipek,
+    CardId::A3178Bewear,
+    CardId::A3179Komala,
+    CardId::A3180DecidueyeEx,
+    CardId::A3181DhelmiseEx,
+    CardId::A3182IncineroarEx,
+    CardId::A3183CrabominableEx,
+    CardId::A3184WishiwashiEx,
+    CardId::A3185AlolanRaichuEx,
+    CardId::A3186LunalaEx,
+    CardId::A3187PassimianEx,
+    CardId::A3188AlolanMukEx,
+    CardId::A3189SolgaleoEx,
+    CardId::A3190Acerola,
+    CardId::A3191Ilima,
+    CardId::A3192Kiawe,
+    CardId::A3193Guzma,
+    CardId::A3194Lana,
+    CardId::A3195Sophocles,
+    CardId::A3196Mallow,
+    CardId::A3197Lillie,
+    CardId::A3198DecidueyeEx,
+    CardId::A3199DhelmiseEx,
+    CardId::A3200IncineroarEx,
+    CardId::A3201CrabominableEx,
+    CardId::A3202WishiwashiEx,
+    CardId::A3203AlolanRaichuEx,
+    CardId::A3204LunalaEx,
+    CardId::A3205PassimianEx,
+    CardId::A3206AlolanMukEx,
+    CardId::A3207SolgaleoEx,
+    CardId::A3208Guzma,
+    CardId::A3209Lillie,
+    CardId::A3210Bulbasaur,
+    CardId::A3211Ivysaur,
+    CardId::A3212Venusaur,
+    CardId::A3213Exeggcute,
+    CardId::A3214Exeggutor,
+    CardId::A3215Squirtle,
+    CardId::A3216Wartortle,
+    CardId::A3217Blastoise,
+    CardId::A3218Staryu,
+    CardId::A3219Starmie,
+    CardId::A3220Gastly,
+    CardId::A3221Haunter,
+    CardId::A3222Gengar,
+    CardId::A3223Machop,
+    CardId::A3224Machoke,
+    CardId::A3225Machamp,
+    CardId::A3226Cubone,
+    CardId::A3227Marowak,
+    CardId::A3228Jigglypuff,
+    CardId::A3229Wigglytuff,
+    CardId::A3230VenusaurEx,
+    CardId::A3231ExeggutorEx,
+    CardId::A3232BlastoiseEx,
+    CardId::A3233StarmieEx,
+    CardId::A3234GengarEx,
+    CardId::A3235MachampEx,
+    CardId::A3236MarowakEx,
+    CardId::A3237WigglytuffEx,
+    CardId::A3238LunalaEx,
+    CardId::A3239SolgaleoEx,
+    CardId::A3a001Petilil,
+    CardId::A3a002Lilligant,
+    CardId::A3a003Rowlet,
+    CardId::A3a004Dartrix,
+    CardId::A3a005Decidueye,
+    CardId::A3a006BuzzwoleEx,
+    CardId::A3a007Pheromosa,
+    CardId::A3a008Kartana,
+    CardId::A3a009Blacephalon,
+    CardId::A3a010Mantine,
+    CardId::A3a011Carvanha,
+    CardId::A3a012Sharpedo,
+    CardId::A3a013Shinx,
+    CardId::A3a014Luxio,
+    CardId::A3a015Luxray,
+    CardId::A3a016Blitzle,
+    CardId::A3a017Zebstrika,
+    CardId::A3a018Emolga,
+    CardId::A3a019TapuKokoEx,
+    CardId::A3a020Xurkitree,
+    CardId::A3a021Zeraora,
+    CardId::A3a022Clefairy,
+    CardId::A3a023Clefable,
+    CardId::A3a024Phantump,
+    CardId::A3a025Trevenant,
+    CardId::A3a026Morelull,
+    CardId::A3a027Shiinotic,
+    CardId::A3a028Meditite,
+    CardId::A3a029Medicham,
+    CardId::A3a030Baltoy,
+    CardId::A3a031Claydol,
+    CardId::A3a032Rockruff,
+    CardId::A3a033LycanrocEx,
+    CardId::A3a034Passimian,
+    CardId::A3a035Sandygast,
+    CardId::A3a036Palossand,
+    CardId::A3a037AlolanMeowth,
+    CardId::A3a038AlolanPersian,
+    CardId::A3a039Sandile,
+    CardId::A3a040Krokorok,
+    CardId::A3a041Krookodile,
+    CardId::A3a042Nihilego,
+    CardId::A3a043GuzzlordEx,
+    CardId::A3a044Poipole,
+    CardId::A3a045Naganadel,
+    CardId::A3a046AlolanDiglett,
+    CardId::A3a047AlolanDugtrioEx,
+    CardId::A3a048Aron,
+    CardId::A3a049Lairon,
+    CardId::A3a050Aggron,
+    CardId::A3a051Ferroseed,
+    CardId::A3a052Ferrothorn,
+    CardId::A3a053Stakataka,
+    CardId::A3a054Lillipup,
+    CardId::A3a055Herdier,
+    CardId::A3a056Stoutland,
+    CardId::A3a057Stufful,
+    CardId::A3a058Bewear,
+    CardId::A3a059Oranguru,
+    CardId::A3a060TypeNull,
+    CardId::A3a061Silvally,
+    CardId::A3a062Celesteela,
+    CardId::A3a063BeastWall,
+    CardId::A3a064Repel,
+    CardId::A3a065ElectricalCord,
+    CardId::A3a066Beastite,
+    CardId::A3a067Gladion,
+    CardId::A3a068Looker,
+    CardId::A3a069Lusamine,
+    CardId::A3a070Rowlet,
+    CardId::A3a071Pheromosa,
+    CardId::A3a072Blacephalon,
+    CardId::A3a073AlolanMeowth,
+    CardId::A3a074Silvally,
+    CardId::A3a075Celesteela,
+    CardId::A3a076BuzzwoleEx,
+    CardId::A3a077TapuKokoEx,
+    CardId::A3a078LycanrocEx,
+    CardId::A3a079GuzzlordEx,
+    CardId::A3a080AlolanDugtrioEx,
+    CardId::A3a081Gladion,
+    CardId::A3a082Looker,
+    CardId::A3a083Lusamine,
+    CardId::A3a084TapuKokoEx,
+    CardId::A3a085LycanrocEx,
+    CardId::A3a086GuzzlordEx,
+    CardId::A3a087AlolanDugtrioEx,
+    CardId::A3a088BuzzwoleEx,
+    CardId::A3a089Growlithe,
+    CardId::A3a090Arcanine,
+    CardId::A3a091Froakie,
+    CardId::A3a092Frogadier,
+    CardId::A3a093Greninja,
+    CardId::A3a094Jynx,
+    CardId::A3a095Pidgey,
+    CardId::A3a096Pidgeotto,
+    CardId::A3a097Pidgeot,
+    CardId::A3a098Aerodactyl,
+    CardId::A3a099CelebiEx,
+    CardId::A3a100ArcanineEx,
+    CardId::A3a101AerodactylEx,
+    CardId::A3a102PidgeotEx,
+    CardId::A3a103Nihilego,
+    CardId::A3b001Tropius,
+    CardId::A3b002Leafeon,
+    CardId::A3b003Bounsweet,
+    CardId::A3b004Steenee,
+    CardId::A3b005Tsareena,
+    CardId::A3b006Applin,
+    CardId::A3b007Appletun,
+    CardId::A3b008Flareon,
+    CardId::A3b009FlareonEx,
+    CardId::A3b010Torkoal,
+    CardId::A3b011Litten,
+    CardId::A3b012Torracat,
+    CardId::A3b013Incineroar,
+    CardId::A3b014Salandit,
+    CardId::A3b015Salazzle,
+    CardId::A3b016Vaporeon,
+    CardId::A3b017Glaceon,
+    CardId::A3b018Vanillite,
+    CardId::A3b019Vanillish,
+    CardId::A3b020Vanilluxe,
+    CardId::A3b021Alomomola,
+    CardId::A3b022Popplio,
+    CardId::A3b023Brionne,
+    CardId::A3b024PrimarinaEx,
+    CardId::A3b025Jolteon,
+    CardId::A3b026Joltik,
+    CardId::A3b027Galvantula,
+    CardId::A3b028Espeon,
+    CardId::A3b029Woobat,
+    CardId::A3b030Swoobat,
+    CardId::A3b031Swirlix,
+    CardId::A3b032Slurpuff,
+    CardId::A3b033Sylveon,
+    CardId::A3b034SylveonEx,
+    CardId::A3b035Mimikyu,
+    CardId::A3b036Milcery,
+    CardId::A3b037Alcremie,
+    CardId::A3b038Barboach,
+    CardId::A3b039Whiscash,
+    CardId::A3b040Mienfoo,
+    CardId::A3b041Mienshao,
+    CardId::A3b042Carbink,
+    CardId::A3b043Umbreon,
+    CardId::A3b044Sableye,
+    CardId::A3b045Purrloin,
+    CardId::A3b046Liepard,
+    CardId::A3b047Mawile,
+    CardId::A3b048Togedemaru,
+    CardId::A3b049Meltan,
+    CardId::A3b050Melmetal,
+    CardId::A3b051Dratini,
+    CardId::A3b052Dragonair,
+    CardId::A3b053DragoniteEx,
+    CardId::A3b054Drampa,
+    CardId::A3b055Eevee,
+    CardId::A3b056EeveeEx,
+    CardId::A3b057SnorlaxEx,
+    CardId::A3b058Aipom,
+    CardId::A3b059Ambipom,
+    CardId::A3b060Chatot,
+    CardId::A3b061Audino,
+    CardId::A3b062Minccino,
+    CardId::A3b063Cinccino,
+    CardId::A3b064Skwovet,
+    CardId::A3b065Greedent,
+    CardId::A3b066EeveeBag,
+    CardId::A3b067Leftovers,
+    CardId::A3b068Hau,
+    CardId::A3b069Penny,
+    CardId::A3b070Leafeon,
+    CardId::A3b071Flareon,
+    CardId::A3b072Vaporeon,
+    CardId::A3b073Glaceon,
+    CardId::A3b074Jolteon,
+    CardId::A3b075Espeon,
+    CardId::A3b076Sylveon,
+    CardId::A3b077Umbreon,
+    CardId::A3b078Eevee,
+    CardId::A3b079FlareonEx,
+    CardId::A3b080PrimarinaEx,
+    CardId::A3b081SylveonEx,
+    CardId::A3b082DragoniteEx,
+    CardId::A3b083EeveeEx,
+    CardId::A3b084SnorlaxEx,
+    CardId::A3b085Hau,
+    CardId::A3b086Penny,
+    CardId::A3b087FlareonEx,
+    CardId::A3b088PrimarinaEx,
+    CardId::A3b089SylveonEx,
+    CardId::A3b090DragoniteEx,
+    CardId::A3b091SnorlaxEx,
+    CardId::A3b092EeveeEx,
+    CardId::A3b093Pinsir,
+    CardId::A3b094Lapras,
+    CardId::A3b095Voltorb,
+    CardId::A3b096Electrode,
+    CardId::A3b097Ralts,
+    CardId::A3b098Kirlia,
+    CardId::A3b099Gardevoir,
+    CardId::A3b100Ekans,
+    CardId::A3b101Arbok,
+    CardId::A3b102Farfetchd,
+    CardId::A3b103MoltresEx,
+    CardId::A3b104ArticunoEx,
+    CardId::A3b105ZapdosEx,
+    CardId::A3b106GalladeEx,
+    CardId::A3b107EeveeBag,
+    CardId::PA001Potion,
+    CardId::PA002XSpeed,
+    CardId::PA003HandScope,
+    CardId::PA004PokedEx,
+    CardId::PA005PokeBall,
+    CardId::PA006RedCard,
+    CardId::PA007ProfessorsResearch,
+    CardId::PA008PokedEx,
+    CardId::PA009Pikachu,
+    CardId::PA010Mewtwo,
+    CardId::PA011Chansey,
+    CardId::PA012Meowth,
+    CardId::PA013Butterfree,
+    CardId::PA014LaprasEx,
+    CardId::PA015Pikachu,
+    CardId::PA016Clefairy,
+    CardId::PA017Mankey,
+    CardId::PA018Venusaur,
+    CardId::PA019Greninja,
+    CardId::PA020Haunter,
+    CardId::PA021Onix,
+    CardId::PA022Jigglypuff,
+    CardId::PA023Bulbasaur,
+    CardId::PA024Magnemite,
+    CardId::PA025MoltresEx,
+    CardId::PA026Pikachu,
+    CardId::PA027Snivy,
+    CardId::PA028Volcarona,
+    CardId::PA029Blastoise,
+    CardId::PA030Eevee,
+    CardId::PA031Cinccino,
+    CardId::PA032Charmander,
+    CardId::PA033Squirtle,
+    CardId::PA034Piplup,
+    CardId::PA035Turtwig,
+    CardId::PA036Electivire,
+    CardId::PA037CresseliaEx,
+    CardId::PA038Misdreavus,
+    CardId::PA039Skarmory,
+    CardId::PA040Chimchar,
+    CardId::PA041Togepi,
+    CardId::PA042DarkraiEx,
+    CardId::PA043Cherrim,
+    CardId::PA044Raichu,
+    CardId::PA045Nosepass,
+    CardId::PA046Gible,
+    CardId::PA047Staraptor,
+    CardId::PA048Manaphy,
+    CardId::PA049Snorlax,
+    CardId::PA050MewtwoEx,
+    CardId::PA051Cyclizar,
+    CardId::PA052Sprigatito,
+    CardId::PA053Floatzel,
+    CardId::PA054Pawmot,
+    CardId::PA055Machamp,
+    CardId::PA056Ekans,
+    CardId::PA057Bidoof,
+    CardId::PA058Pachirisu,
+    CardId::PA059Riolu,
+    CardId::PA060Exeggcute,
+    CardId::PA061Froakie,
+    CardId::PA062Farfetchd,
+    CardId::PA063Rayquaza,
+    CardId::PA064RayquazaEx,
+    CardId::PA065RayquazaEx,
+    CardId::PA066Mimikyu,
+    CardId::PA067Cosmog,
+    CardId::PA068Lycanroc,
+    CardId::PA069AlolanExeggutor,
+    CardId::PA070AlolanNinetales,
+    CardId::PA071Crabrawler,
+    CardId::PA072AlolanGrimer,
+    CardId::PA073Toucannon,
+];
+
 impl CardId {
     pub fn from_card_id(id: &str) -> Option<Self> {
         match id {
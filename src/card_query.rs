@@ -0,0 +1,260 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    card_ids::CardId,
+    database::query,
+    sets::{SetId, ALL_SETS},
+    types::{Card, EnergyType, TrainerType},
+};
+
+/// Which cards `database::query` returns. Every filter that is set must match, and
+/// Pokémon-only filters (energy type, stage, HP, ability) leave out trainers, like
+/// `trainer_type` leaves out Pokémon.
+///
+/// ```
+/// use deckgym::card_query::CardQuery;
+/// use deckgym::database::query;
+/// use deckgym::types::EnergyType;
+///
+/// let fire_basics = CardQuery::new().energy_type(EnergyType::Fire).stage(0);
+/// assert!(query(fire_basics.hp(100..=200)).count() > 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CardQuery {
+    pub energy_type: Option<EnergyType>,
+    pub stage: Option<u8>,
+    pub hp: Option<RangeInclusive<u32>>,
+    pub has_ability: Option<bool>,
+    pub trainer_type: Option<TrainerType>,
+    pub sets: Option<Vec<SetId>>,
+}
+
+impl CardQuery {
+    /// A query that matches every card.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn energy_type(mut self, energy_type: EnergyType) -> Self {
+        self.energy_type = Some(energy_type);
+        self
+    }
+
+    /// 0 for Basic, 1 for Stage 1, 2 for Stage 2.
+    pub fn stage(mut self, stage: u8) -> Self {
+        self.stage = Some(stage);
+        self
+    }
+
+    pub fn hp(mut self, hp: RangeInclusive<u32>) -> Self {
+        self.hp = Some(hp);
+        self
+    }
+
+    pub fn has_ability(mut self, has_ability: bool) -> Self {
+        self.has_ability = Some(has_ability);
+        self
+    }
+
+    pub fn trainer_type(mut self, trainer_type: TrainerType) -> Self {
+        self.trainer_type = Some(trainer_type);
+        self
+    }
+
+    /// Only cards from one of these sets.
+    pub fn sets(mut self, sets: &[SetId]) -> Self {
+        self.sets = Some(sets.to_vec());
+        self
+    }
+
+    fn filters_pokemon(&self) -> bool {
+        self.energy_type.is_some()
+            || self.stage.is_some()
+            || self.hp.is_some()
+            || self.has_ability.is_some()
+    }
+
+    pub fn matches(&self, card: &Card) -> bool {
+        if let Some(sets) = &self.sets {
+            if !sets.contains(&card.get_set()) {
+                return false;
+            }
+        }
+        match card {
+            Card::Pokemon(pokemon_card) => {
+                self.trainer_type.is_none()
+                    && self
+                        .energy_type
+                        .is_none_or(|x| pokemon_card.energy_type == x)
+                    && self.stage.is_none_or(|x| pokemon_card.stage == x)
+                    && self
+                        .hp
+                        .as_ref()
+                        .is_none_or(|x| x.contains(&pokemon_card.hp))
+                    && self
+                        .has_ability
+                        .is_none_or(|x| pokemon_card.ability.is_some() == x)
+            }
+            Card::Trainer(trainer_card) => {
+                !self.filters_pokemon()
+                    && self
+                        .trainer_type
+                        .as_ref()
+                        .is_none_or(|x| trainer_card.trainer_card_type == *x)
+            }
+        }
+    }
+}
+
+/// Cards whose name is closest to `name`, ignoring case, accents and punctuation, and
+/// allowing a typo every few letters (e.g. "charizrd"). All printings of the best matches
+/// are returned, in `ALL_CARD_IDS` order, and nothing if no name is close enough.
+///
+/// A set code (e.g. "Pikachu ex A1") only keeps cards of that set, and a full card id
+/// (e.g. "Pikachu ex (A1 096)") picks that card.
+pub fn find_by_name(name: &str) -> Vec<CardId> {
+    let mut words: Vec<&str> = name
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .filter(|x| !x.is_empty())
+        .collect();
+    if let [.., set, number] = words[..] {
+        if let Some(id) = CardId::from_card_id(&format!("{set} {number:0>3}")) {
+            return vec![id];
+        }
+    }
+    let mut cards = CardQuery::new();
+    if let Some(set) = words.last().and_then(|x| set_from_code(x)) {
+        words.pop();
+        cards = cards.sets(&[set]);
+    }
+    let name = normalize(&words.join(" "));
+    if name.is_empty() {
+        return vec![];
+    }
+
+    let max_distance = name.chars().count() / 4;
+    let mut best = max_distance + 1;
+    let mut found = vec![];
+    for card in query(cards) {
+        let distance = edit_distance(&name, &normalize(&card.get_name()));
+        if distance < best {
+            best = distance;
+            found.clear();
+        }
+        if distance == best {
+            found.push(card.get_card_id());
+        }
+    }
+    found
+}
+
+fn set_from_code(code: &str) -> Option<SetId> {
+    ALL_SETS
+        .iter()
+        .find(|x| x.code.eq_ignore_ascii_case(code))
+        .map(|x| x.id)
+}
+
+// Lowercase ASCII words: "Flabébé" is "flabebe", "Mr. Mime" is "mr mime".
+fn normalize(name: &str) -> String {
+    let name: String = name
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            'à' | 'á' | 'â' | 'ä' => Some('a'),
+            'è' | 'é' | 'ê' | 'ë' => Some('e'),
+            'ì' | 'í' | 'î' | 'ï' => Some('i'),
+            'ò' | 'ó' | 'ô' | 'ö' => Some('o'),
+            'ù' | 'ú' | 'û' | 'ü' => Some('u'),
+            '\'' | '’' => None,
+            c if c.is_ascii_alphanumeric() => Some(c),
+            _ => Some(' '),
+        })
+        .collect();
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Levenshtein distance
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != *y);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card_ids::ALL_CARD_IDS, database::get_card_by_enum};
+
+    #[test]
+    fn test_empty_query_returns_every_card_in_order() {
+        let ids: Vec<_> = query(CardQuery::new()).map(|x| x.get_card_id()).collect();
+        assert_eq!(ids, ALL_CARD_IDS);
+    }
+
+    #[test]
+    fn test_filters_combine() {
+        let cards: Vec<Card> = query(
+            CardQuery::new()
+                .energy_type(EnergyType::Grass)
+                .stage(2)
+                .hp(150..=200)
+                .sets(&[SetId::A1]),
+        )
+        .collect();
+        assert!(cards.iter().any(|x| x.get_id() == "A1 004")); // Venusaur ex
+        for card in &cards {
+            let Card::Pokemon(pokemon_card) = card else {
+                panic!("Only Pokémon have HP");
+            };
+            assert_eq!(pokemon_card.energy_type, EnergyType::Grass);
+            assert_eq!(pokemon_card.stage, 2);
+            assert!((150..=200).contains(&pokemon_card.hp));
+            assert_eq!(pokemon_card.set, SetId::A1);
+        }
+
+        let supporters: Vec<Card> =
+            query(CardQuery::new().trainer_type(TrainerType::Supporter)).collect();
+        assert!(!supporters.is_empty());
+        assert!(supporters.iter().all(|x| x.is_support()));
+
+        assert!(query(CardQuery::new().has_ability(true)).all(|x| x.get_ability().is_some()));
+    }
+
+    #[test]
+    fn test_find_by_name() {
+        let charizards = find_by_name("charizrd");
+        assert!(charizards.contains(&CardId::A1035Charizard));
+        assert!(charizards
+            .iter()
+            .all(|x| get_card_by_enum(*x).get_name() == "Charizard"));
+        assert_eq!(find_by_name("CHARIZARD EX")[0], CardId::A1036CharizardEx);
+        assert_eq!(find_by_name("mr. mime"), find_by_name("Mr Mime"));
+        assert!(find_by_name("mr mime").contains(&CardId::A1126MrMime));
+        assert!(find_by_name("Bulbasaurus Rex").is_empty());
+        assert!(find_by_name("").is_empty());
+    }
+
+    #[test]
+    fn test_find_by_name_with_set() {
+        let pikachus = find_by_name("Pikachu ex");
+        assert!(pikachus.len() > 1);
+        let in_a1 = find_by_name("pikachu ex a1");
+        assert!(!in_a1.is_empty() && in_a1.len() < pikachus.len());
+        assert!(in_a1
+            .iter()
+            .all(|x| get_card_by_enum(*x).get_set() == SetId::A1));
+        assert_eq!(
+            find_by_name("Pikachu ex (A1 96)"),
+            vec![CardId::A1096PikachuEx]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::time::Duration;
+
+/// What happens to a player who runs out of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeoutPolicy {
+    /// The player loses the game.
+    #[default]
+    Forfeit,
+    /// A random legal action is played instead of the late one, and every later decision
+    /// is random until increments give the player time again.
+    RandomMove,
+}
+
+/// Time each player gets for their decisions over a game, like a chess clock (see
+/// `Game::enable_clock`), e.g. for fair bot tournaments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeControl {
+    pub budget: Duration,
+    /// Added to a player's remaining time after each of their decisions.
+    pub increment: Duration,
+    pub on_timeout: TimeoutPolicy,
+}
+
+impl TimeControl {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            increment: Duration::ZERO,
+            on_timeout: TimeoutPolicy::default(),
+        }
+    }
+
+    pub fn with_increment(mut self, increment: Duration) -> Self {
+        self.increment = increment;
+        self
+    }
+
+    pub fn with_timeout_policy(mut self, on_timeout: TimeoutPolicy) -> Self {
+        self.on_timeout = on_timeout;
+        self
+    }
+}
+
+/// Both players' clocks. Only decisions with more than one legal action are timed.
+///
+/// Decisions can't be interrupted, so a late one is only detected once it returns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameClock {
+    pub time_control: TimeControl,
+    pub remaining: [Duration; 2],
+    /// Time spent deciding, in total.
+    pub time_used: [Duration; 2],
+    pub decisions: [u32; 2],
+    /// Decisions that ran out of time (with `RandomMove`, including those not even asked).
+    pub timeouts: [u32; 2],
+    /// The player who lost on time, with `Forfeit`.
+    pub forfeited: Option<usize>,
+}
+
+impl GameClock {
+    pub fn new(time_control: TimeControl) -> Self {
+        Self {
+            time_control,
+            remaining: [time_control.budget; 2],
+            time_used: [Duration::ZERO; 2],
+            decisions: [0; 2],
+            timeouts: [0; 2],
+            forfeited: None,
+        }
+    }
+
+    pub(crate) fn has_time(&self, player: usize) -> bool {
+        !self.remaining[player].is_zero()
+    }
+
+    /// Charges a decision to the player's clock, returning whether it was in time.
+    pub(crate) fn record(&mut self, player: usize, elapsed: Duration) -> bool {
+        let in_time = elapsed <= self.remaining[player];
+        self.time_used[player] += elapsed;
+        self.decisions[player] += 1;
+        self.remaining[player] =
+            self.remaining[player].saturating_sub(elapsed) + self.time_control.increment;
+        if !in_time {
+            self.timeouts[player] += 1;
+        }
+        in_time
+    }
+
+    /// Counts a decision made at random because the player had no time left.
+    pub(crate) fn record_skipped(&mut self, player: usize) {
+        self.decisions[player] += 1;
+        self.timeouts[player] += 1;
+        self.remaining[player] += self.time_control.increment;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::{
+        actions::Action,
+        errors::GameError,
+        players::{ExternalPlayer, Player, RandomPlayer},
+        state::GameOutcome,
+        test_helpers::load_test_decks,
+        Deck, Game, State,
+    };
+
+    #[derive(Debug)]
+    struct SlowPlayer {
+        inner: RandomPlayer,
+        delay: Duration,
+    }
+
+    impl Player for SlowPlayer {
+        fn decision_fn(&mut self, rng: &mut StdRng, state: &State, actions: Vec<Action>) -> Action {
+            thread::sleep(self.delay);
+            self.inner.decision_fn(rng, state, actions)
+        }
+
+        fn get_deck(&self) -> Deck {
+            self.inner.get_deck()
+        }
+    }
+
+    fn game_with_slow_player(delay: Duration) -> Game {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(SlowPlayer {
+                inner: RandomPlayer { deck: deck_a },
+                delay,
+            }),
+            Box::new(RandomPlayer { deck: deck_b }),
+        ];
+        Game::new(players, 3)
+    }
+
+    #[test]
+    fn test_running_out_of_time_forfeits() {
+        let mut game = game_with_slow_player(Duration::from_millis(5));
+        game.enable_clock(TimeControl::new(Duration::from_millis(12)));
+        assert_eq!(game.play(), Some(GameOutcome::Win(1)));
+        let clock = game.get_clock().unwrap();
+        assert_eq!(clock.forfeited, Some(0));
+        assert_eq!(clock.timeouts, [1, 0]);
+        assert!(clock.decisions[0] >= 3);
+        assert!(clock.time_used[0] >= Duration::from_millis(12));
+        assert!(clock.remaining[0].is_zero());
+    }
+
+    #[test]
+    fn test_losing_on_time_choosing_a_blind_board_fails_the_tick() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(ExternalPlayer { deck: deck_a }),
+            Box::new(SlowPlayer {
+                inner: RandomPlayer { deck: deck_b },
+                delay: Duration::from_millis(5),
+            }),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_starting_player(0);
+        game.enable_clock(TimeControl::new(Duration::from_millis(1)));
+        // The slow player chooses their board along with the outside one, and loses
+        let (_, actions) = game.legal_actions();
+        let result = game.play_tick_with(&actions[0]);
+        assert_eq!(result, Err(GameError::GameAlreadyOver));
+        assert!(game.get_state().in_play_pokemon[0][0].is_none());
+        assert_eq!(game.get_state().winner, Some(GameOutcome::Win(0)));
+        assert_eq!(game.get_clock().unwrap().forfeited, Some(1));
+    }
+
+    #[test]
+    fn test_running_out_of_time_plays_random_moves() {
+        let mut game = game_with_slow_player(Duration::from_millis(2));
+        let time_control = TimeControl::new(Duration::from_millis(5))
+            .with_timeout_policy(TimeoutPolicy::RandomMove);
+        game.enable_clock(time_control);
+        game.play();
+        let clock = game.get_clock().unwrap();
+        assert_eq!(clock.forfeited, None);
+        assert!(clock.timeouts[0] > 1);
+        assert_eq!(clock.timeouts[1], 0);
+        // Once out of time, the player isn't asked anymore
+        assert!(clock.time_used[0] < Duration::from_millis(5) + Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_clock_does_not_change_games_in_time() {
+        let play = |clock: bool| {
+            let mut game = game_with_slow_player(Duration::ZERO);
+            if clock {
+                game.enable_clock(TimeControl::new(Duration::from_secs(60)));
+            }
+            let outcome = game.play();
+            (outcome, game.get_num_plys(), game.get_clock().cloned())
+        };
+        let (outcome, plys, clock) = play(true);
+        assert_eq!((outcome, plys), (play(false).0, play(false).1));
+        let clock = clock.unwrap();
+        assert_eq!(clock.timeouts, [0, 0]);
+        assert!(clock.decisions.iter().all(|x| *x > 0));
+    }
+
+    #[test]
+    fn test_increment() {
+        let mut clock = GameClock::new(
+            TimeControl::new(Duration::from_millis(10)).with_increment(Duration::from_millis(1)),
+        );
+        assert!(clock.record(0, Duration::from_millis(4)));
+        assert_eq!(clock.remaining[0], Duration::from_millis(7));
+        assert!(!clock.record(0, Duration::from_millis(8)));
+        assert_eq!(clock.remaining[0], Duration::from_millis(1));
+        assert!(clock.has_time(0));
+        clock.record_skipped(1);
+        assert_eq!(clock.timeouts, [1, 1]);
+    }
+}
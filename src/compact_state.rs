@@ -0,0 +1,833 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    actions::{CheckupEvent, CoinFlips, SimpleAction},
+    card_ids::{CardId, ALL_CARD_IDS},
+    database::get_card_by_enum,
+    errors::{GameError, GameResult, OptionExt},
+    game_rules::{GameRules, TieBreak},
+    observation::card_index,
+    state::{GameOutcome, State, TurnPhase, MAX_IN_PLAY},
+    tool_ids::ToolId,
+    types::{Card, EnergyType, PlayedCard, PokemonEffect},
+    Deck,
+};
+
+const VERSION: u8 = 4;
+
+// In the order of their declaration, so that `x as u8` indexes them.
+const ENERGY_TYPES: [EnergyType; 10] = [
+    EnergyType::Grass,
+    EnergyType::Fire,
+    EnergyType::Water,
+    EnergyType::Lightning,
+    EnergyType::Psychic,
+    EnergyType::Fighting,
+    EnergyType::Darkness,
+    EnergyType::Metal,
+    EnergyType::Dragon,
+    EnergyType::Colorless,
+];
+const PHASES: [TurnPhase; 6] = [
+    TurnPhase::Setup,
+    TurnPhase::Draw,
+    TurnPhase::Main,
+    TurnPhase::Attack,
+    TurnPhase::Checkup,
+    TurnPhase::GameOver,
+];
+const TOOLS: [ToolId; 3] = [
+    ToolId::A2147GiantCape,
+    ToolId::A2148RockyHelmet,
+    ToolId::A2149LumBerry,
+];
+
+// Bits of the header flags
+const HAS_PLAYED_SUPPORT: u8 = 1;
+const HAS_RETREATED: u8 = 1 << 1;
+const SECOND_PLAYER_TO_MOVE: u8 = 1 << 2;
+const HAS_CHECKSUMS: u8 = 1 << 3;
+const HAS_CURRENT_ENERGY: u8 = 1 << 4;
+const CUSTOM_RULES: u8 = 1 << 5;
+const HAS_TRANSIENT_DATA: u8 = 1 << 6;
+
+// Bits of the flags of a Pokémon in play
+const PLAYED_THIS_TURN: u8 = 1;
+const ABILITY_USED: u8 = 1 << 1;
+const POISONED: u8 = 1 << 2;
+const PARALYZED: u8 = 1 << 3;
+const ASLEEP: u8 = 1 << 4;
+const BURNED: u8 = 1 << 5;
+const CONFUSED: u8 = 1 << 6;
+const HAS_TOOL: u8 = 1 << 7;
+
+impl State {
+    /// Encodes the whole state compactly, to store many positions (e.g. for training): cards
+    /// are 2-byte database indices, flags are packed into bits and numbers are varints, so a
+    /// typical mid-game state takes 100 to 200 bytes. Panics on cards that are not in the
+    /// database.
+    ///
+    /// The format is versioned, but only meant to be read back by the same engine version,
+    /// since card indices follow `ALL_CARD_IDS`.
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer(Vec::with_capacity(160));
+        writer.u8(VERSION);
+
+        let transient = !self.move_generation_stack.is_empty()
+            || !self.coin_flips.is_empty()
+            || !self.checkup_events.is_empty()
+            || !self.turn_effects.is_empty();
+        let flags = [
+            (self.has_played_support, HAS_PLAYED_SUPPORT),
+            (self.has_retreated, HAS_RETREATED),
+            (self.current_player == 1, SECOND_PLAYER_TO_MOVE),
+            (self.decklist_checksums.is_some(), HAS_CHECKSUMS),
+            (self.current_energy.is_some(), HAS_CURRENT_ENERGY),
+            (self.rules != GameRules::default(), CUSTOM_RULES),
+            (transient, HAS_TRANSIENT_DATA),
+        ];
+        writer.u8(flags
+            .iter()
+            .filter(|(x, _)| *x)
+            .fold(0, |acc, (_, bit)| acc | bit));
+        // Winner in the low 2 bits, phase above
+        let winner = match self.winner {
+            None => 0,
+            Some(GameOutcome::Win(player)) => 1 + player as u8,
+            Some(GameOutcome::Tie) => 3,
+        };
+        writer.u8(winner | (self.phase as u8) << 2);
+        writer.u8(self.points[0]);
+        writer.u8(self.points[1]);
+        writer.u8(self.turn_count);
+        if let Some(energy) = self.current_energy {
+            writer.energy(energy);
+        }
+        if self.rules != GameRules::default() {
+            writer.rules(&self.rules);
+        }
+
+        for player in 0..2 {
+            let deck = &self.decks[player];
+            writer.varint(deck.energy_types.len());
+            for energy in &deck.energy_types {
+                writer.energy(*energy);
+            }
+            writer.cards(&deck.cards);
+            writer.cards(&self.hands[player]);
+            writer.cards(&self.discard_piles[player]);
+            writer.varint(self.discarded_energy[player].len());
+            for (energy, count) in &self.discarded_energy[player] {
+                writer.energy(*energy);
+                writer.varint(*count as usize);
+            }
+            let occupied = self.in_play_pokemon[player]
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| x.is_some())
+                .fold(0, |acc, (i, _)| acc | 1 << i);
+            writer.u8(occupied);
+            for pokemon in self.in_play_pokemon[player].iter().flatten() {
+                writer.played_card(pokemon);
+            }
+        }
+
+        if transient {
+            writer.varint(self.move_generation_stack.len());
+            for (actor, actions) in &self.move_generation_stack {
+                writer.varint(*actor);
+                writer.varint(actions.len());
+                for action in actions {
+                    writer.action(action);
+                }
+            }
+            writer.varint(self.coin_flips.len());
+            for flips in &self.coin_flips {
+                writer.u8(flips.flips);
+                writer.u8(flips.heads);
+            }
+            writer.varint(self.checkup_events.len());
+            for event in &self.checkup_events {
+                writer.checkup_event(event);
+            }
+            writer.varint(self.turn_effects.len());
+            for (turn, cards) in self.turn_effects.iter() {
+                writer.u8(*turn);
+                writer.cards(cards);
+            }
+        }
+        writer.0
+    }
+
+    /// Decodes a state encoded by `to_compact_bytes`. Decklist checksums (see
+    /// `check_invariants`) are recomputed from the cards rather than stored.
+    pub fn from_compact_bytes(bytes: &[u8]) -> GameResult<State> {
+        let mut reader = Reader { bytes, position: 0 };
+        let version = reader.u8()?;
+        if version != VERSION {
+            return Err(GameError::UnsupportedVersion {
+                found: version.into(),
+                supported: VERSION.into(),
+            });
+        }
+        let flags = reader.u8()?;
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        state.has_played_support = flags & HAS_PLAYED_SUPPORT != 0;
+        state.has_retreated = flags & HAS_RETREATED != 0;
+        state.current_player = usize::from(flags & SECOND_PLAYER_TO_MOVE != 0);
+        let outcome = reader.u8()?;
+        state.winner = match outcome & 3 {
+            0 => None,
+            x @ (1 | 2) => Some(GameOutcome::Win(x as usize - 1)),
+            _ => Some(GameOutcome::Tie),
+        };
+        state.phase = *PHASES
+            .get((outcome >> 2) as usize)
+            .ok_or_game_error(|| corrupt(format!("Invalid phase {}", outcome >> 2)))?;
+        state.points = [reader.u8()?, reader.u8()?];
+        state.turn_count = reader.u8()?;
+        if flags & HAS_CURRENT_ENERGY != 0 {
+            state.current_energy = Some(reader.energy()?);
+        }
+        if flags & CUSTOM_RULES != 0 {
+            state.rules = reader.rules()?;
+        }
+
+        for player in 0..2 {
+            let energy_types = (0..reader.varint()?)
+                .map(|_| reader.energy())
+                .collect::<Result<_, _>>()?;
+            let cards = reader.cards()?;
+            state.decks[player] = Arc::new(Deck {
+                cards,
+                energy_types,
+            });
+            state.hands[player] = reader.cards()?;
+            state.discard_piles[player] = reader.cards()?;
+            for _ in 0..reader.varint()? {
+                let energy = reader.energy()?;
+                let count = reader.varint()? as u32;
+                state.discarded_energy[player].insert(energy, count);
+            }
+            let occupied = reader.u8()?;
+            for slot in 0..MAX_IN_PLAY {
+                if occupied & (1 << slot) != 0 {
+                    state.in_play_pokemon[player][slot] = Some(reader.played_card()?);
+                }
+            }
+        }
+
+        if flags & HAS_TRANSIENT_DATA != 0 {
+            for _ in 0..reader.varint()? {
+                let actor = reader.varint()?;
+                let actions = (0..reader.varint()?)
+                    .map(|_| reader.action())
+                    .collect::<Result<_, _>>()?;
+                state.move_generation_stack.push((actor, actions));
+            }
+            for _ in 0..reader.varint()? {
+                state.coin_flips.push(CoinFlips {
+                    flips: reader.u8()?,
+                    heads: reader.u8()?,
+                });
+            }
+            for _ in 0..reader.varint()? {
+                state.checkup_events.push(reader.checkup_event()?);
+            }
+            let mut turn_effects = BTreeMap::new();
+            for _ in 0..reader.varint()? {
+                let turn = reader.u8()?;
+                turn_effects.insert(turn, reader.cards()?);
+            }
+            state.turn_effects = Arc::new(turn_effects);
+        }
+        if reader.position != bytes.len() {
+            return Err(corrupt(format!(
+                "{} trailing bytes after the state",
+                bytes.len() - reader.position
+            )));
+        }
+        if flags & HAS_CHECKSUMS != 0 {
+            state.decklist_checksums = Some([0, 1].map(|x| state.owned_cards_checksum(x)));
+        }
+        Ok(state)
+    }
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, x: u8) {
+        self.0.push(x);
+    }
+
+    // LEB128: 7 bits per byte, the high bit telling whether more bytes follow
+    fn varint(&mut self, mut x: usize) {
+        while x >= 0x80 {
+            self.0.push((x as u8 & 0x7f) | 0x80);
+            x >>= 7;
+        }
+        self.0.push(x as u8);
+    }
+
+    fn energy(&mut self, energy: EnergyType) {
+        self.u8(energy as u8);
+    }
+
+    fn card(&mut self, card: &Card) {
+        let index = card_index(card).expect("Cards should be in the database") as u16;
+        self.0.extend(index.to_le_bytes());
+    }
+
+    fn cards(&mut self, cards: &[Card]) {
+        self.varint(cards.len());
+        for card in cards {
+            self.card(card);
+        }
+    }
+
+    fn rules(&mut self, rules: &GameRules) {
+        self.u8(rules.points_to_win);
+        self.u8(rules.max_turns);
+        self.varint(rules.bench_size);
+        self.u8(u8::from(rules.first_turn_energy) | u8::from(rules.first_turn_attack) << 1);
+        // 0 for no limit
+        self.varint(rules.max_hand_size.map_or(0, |x| x + 1));
+        self.u8(match rules.tie_break {
+            TieBreak::None => 0,
+            TieBreak::Points => 1,
+            TieBreak::RemainingHp => 2,
+        });
+        self.u8(rules.sudden_death_turns);
+    }
+
+    fn played_card(&mut self, pokemon: &PlayedCard) {
+        self.card(&pokemon.card);
+        self.varint(pokemon.remaining_hp as usize);
+        self.varint(pokemon.total_hp as usize);
+        let flags = [
+            (pokemon.played_this_turn, PLAYED_THIS_TURN),
+            (pokemon.ability_used, ABILITY_USED),
+            (pokemon.poisoned, POISONED),
+            (pokemon.paralyzed, PARALYZED),
+            (pokemon.asleep, ASLEEP),
+            (pokemon.burned, BURNED),
+            (pokemon.confused, CONFUSED),
+            (pokemon.attached_tool.is_some(), HAS_TOOL),
+        ];
+        self.u8(flags
+            .iter()
+            .filter(|(x, _)| *x)
+            .fold(0, |acc, (_, bit)| acc | bit));
+        if let Some(tool) = pokemon.attached_tool {
+            self.u8(tool as u8);
+        }
+        // Two energies per byte
+        self.varint(pokemon.attached_energy.len());
+        for pair in pokemon.attached_energy.chunks(2) {
+            let second = pair.get(1).map_or(0, |x| *x as u8);
+            self.u8(pair[0] as u8 | second << 4);
+        }
+        self.cards(&pokemon.cards_behind);
+        self.varint(pokemon.effects.len());
+        for effect in &pokemon.effects {
+            self.card(&effect.card);
+            self.u8(effect.until_turn);
+            self.varint(effect.attack_index.map_or(0, |x| x + 1));
+        }
+    }
+
+    fn action(&mut self, action: &SimpleAction) {
+        match action {
+            SimpleAction::DrawCard => self.u8(0),
+            SimpleAction::Play { trainer_card } => {
+                self.u8(1);
+                self.card(&Card::Trainer(trainer_card));
+            }
+            SimpleAction::Place(card, slot) => {
+                self.u8(2);
+                self.card(card);
+                self.varint(*slot);
+            }
+            SimpleAction::Evolve(card, slot) => {
+                self.u8(3);
+                self.card(card);
+                self.varint(*slot);
+            }
+            SimpleAction::UseAbility(slot) => {
+                self.u8(4);
+                self.varint(*slot);
+            }
+            SimpleAction::Attack(index) => {
+                self.u8(5);
+                self.varint(*index);
+            }
+            SimpleAction::Retreat(slot) => {
+                self.u8(6);
+                self.varint(*slot);
+            }
+            SimpleAction::EndTurn => self.u8(7),
+            SimpleAction::Attach {
+                attachments,
+                is_turn_energy,
+            } => {
+                self.u8(8);
+                self.u8(u8::from(*is_turn_energy));
+                self.varint(attachments.len());
+                for (amount, energy, slot) in attachments {
+                    self.varint(*amount as usize);
+                    self.energy(*energy);
+                    self.varint(*slot);
+                }
+            }
+            SimpleAction::AttachTool {
+                in_play_idx,
+                tool_id,
+            } => {
+                self.u8(9);
+                self.varint(*in_play_idx);
+                self.u8(*tool_id as u8);
+            }
+            SimpleAction::Heal {
+                in_play_idx,
+                amount,
+            } => {
+                self.u8(10);
+                self.varint(*in_play_idx);
+                self.varint(*amount as usize);
+            }
+            SimpleAction::ApplyDamage { targets } => {
+                self.u8(11);
+                self.varint(targets.len());
+                for (damage, slot) in targets {
+                    self.varint(*damage as usize);
+                    self.varint(*slot);
+                }
+            }
+            SimpleAction::MoveEnergy {
+                from_in_play_idx,
+                to_in_play_idx,
+                energy,
+            } => {
+                self.u8(12);
+                self.varint(*from_in_play_idx);
+                self.varint(*to_in_play_idx);
+                self.energy(*energy);
+            }
+            SimpleAction::SwapWithDeck(card) => {
+                self.u8(13);
+                self.card(card);
+            }
+            SimpleAction::DiscardEnergy {
+                in_play_idx,
+                energy,
+            } => {
+                self.u8(14);
+                self.varint(*in_play_idx);
+                self.energy(*energy);
+            }
+            SimpleAction::ChooseDamageTarget {
+                player,
+                in_play_idx,
+                damage,
+            } => {
+                self.u8(15);
+                self.varint(*player);
+                self.varint(*in_play_idx);
+                self.varint(*damage as usize);
+            }
+            SimpleAction::Activate { in_play_idx } => {
+                self.u8(16);
+                self.varint(*in_play_idx);
+            }
+            SimpleAction::Promote { bench_idx } => {
+                self.u8(17);
+                self.varint(*bench_idx);
+            }
+            SimpleAction::CopyAttack(index) => {
+                self.u8(18);
+                self.varint(*index);
+            }
+            SimpleAction::Setup { active, bench } => {
+                self.u8(19);
+                self.varint(*active);
+                self.varint(bench.len());
+                for index in bench {
+                    self.varint(*index);
+                }
+            }
+        }
+    }
+
+    fn checkup_event(&mut self, event: &CheckupEvent) {
+        let (tag, player, in_play_idx, value) = match event {
+            CheckupEvent::LumBerryUsed {
+                player,
+                in_play_idx,
+            } => (0, player, in_play_idx, 0),
+            CheckupEvent::PoisonDamage {
+                player,
+                in_play_idx,
+                damage,
+            } => (1, player, in_play_idx, *damage as usize),
+            CheckupEvent::BurnDamage {
+                player,
+                in_play_idx,
+                damage,
+            } => (2, player, in_play_idx, *damage as usize),
+            CheckupEvent::BurnFlip {
+                player,
+                in_play_idx,
+                recovered,
+            } => (3, player, in_play_idx, usize::from(*recovered)),
+            CheckupEvent::SleepFlip {
+                player,
+                in_play_idx,
+                woke_up,
+            } => (4, player, in_play_idx, usize::from(*woke_up)),
+            CheckupEvent::ParalysisRecovered {
+                player,
+                in_play_idx,
+            } => (5, player, in_play_idx, 0),
+        };
+        self.u8(tag);
+        self.varint(*player);
+        self.varint(*in_play_idx);
+        self.varint(value);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn u8(&mut self) -> GameResult<u8> {
+        let x = *self
+            .bytes
+            .get(self.position)
+            .ok_or_game_error(|| corrupt("Compact state is truncated"))?;
+        self.position += 1;
+        Ok(x)
+    }
+
+    fn varint(&mut self) -> GameResult<usize> {
+        let mut x = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            x |= ((byte & 0x7f) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(x);
+            }
+        }
+        Err(corrupt("Varint is too long"))
+    }
+
+    fn energy(&mut self) -> GameResult<EnergyType> {
+        energy_type(self.u8()?)
+    }
+
+    fn card(&mut self) -> GameResult<Card> {
+        let index = u16::from_le_bytes([self.u8()?, self.u8()?]) as usize;
+        let id: CardId = *ALL_CARD_IDS
+            .get(index)
+            .ok_or_game_error(|| corrupt(format!("Invalid card index {index}")))?;
+        Ok(get_card_by_enum(id))
+    }
+
+    fn cards(&mut self) -> GameResult<Vec<Card>> {
+        (0..self.varint()?).map(|_| self.card()).collect()
+    }
+
+    fn rules(&mut self) -> GameResult<GameRules> {
+        let points_to_win = self.u8()?;
+        let max_turns = self.u8()?;
+        let bench_size = self.varint()?;
+        let first_turn = self.u8()?;
+        let max_hand_size = self.varint()?.checked_sub(1);
+        let tie_break = match self.u8()? {
+            0 => TieBreak::None,
+            1 => TieBreak::Points,
+            2 => TieBreak::RemainingHp,
+            x => return Err(corrupt(format!("Invalid tie break {x}"))),
+        };
+        Ok(GameRules {
+            points_to_win,
+            max_turns,
+            bench_size,
+            first_turn_energy: first_turn & 1 != 0,
+            first_turn_attack: first_turn & 2 != 0,
+            max_hand_size,
+            tie_break,
+            sudden_death_turns: self.u8()?,
+        })
+    }
+
+    fn played_card(&mut self) -> GameResult<PlayedCard> {
+        let card = self.card()?;
+        let remaining_hp = self.varint()? as u32;
+        let total_hp = self.varint()? as u32;
+        let flags = self.u8()?;
+        let attached_tool = if flags & HAS_TOOL != 0 {
+            Some(tool(self.u8()?)?)
+        } else {
+            None
+        };
+        let num_energies = self.varint()?;
+        let mut attached_energy = Vec::with_capacity(num_energies);
+        for i in (0..num_energies).step_by(2) {
+            let pair = self.u8()?;
+            attached_energy.push(energy_type(pair & 0xf)?);
+            if i + 1 < num_energies {
+                attached_energy.push(energy_type(pair >> 4)?);
+            }
+        }
+        let cards_behind = self.cards()?;
+        let effects = (0..self.varint()?)
+            .map(|_| {
+                Ok(PokemonEffect {
+                    card: self.card()?,
+                    until_turn: self.u8()?,
+                    attack_index: self.varint()?.checked_sub(1),
+                })
+            })
+            .collect::<GameResult<_>>()?;
+        Ok(PlayedCard {
+            card,
+            remaining_hp,
+            total_hp,
+            attached_energy,
+            attached_tool,
+            played_this_turn: flags & PLAYED_THIS_TURN != 0,
+            ability_used: flags & ABILITY_USED != 0,
+            poisoned: flags & POISONED != 0,
+            paralyzed: flags & PARALYZED != 0,
+            asleep: flags & ASLEEP != 0,
+            burned: flags & BURNED != 0,
+            confused: flags & CONFUSED != 0,
+            cards_behind,
+            effects,
+        })
+    }
+
+    fn action(&mut self) -> GameResult<SimpleAction> {
+        Ok(match self.u8()? {
+            0 => SimpleAction::DrawCard,
+            1 => match self.card()? {
+                Card::Trainer(trainer_card) => SimpleAction::Play { trainer_card },
+                card => return Err(corrupt(format!("{card} is not a trainer"))),
+            },
+            2 => SimpleAction::Place(self.card()?, self.varint()?),
+            3 => SimpleAction::Evolve(self.card()?, self.varint()?),
+            4 => SimpleAction::UseAbility(self.varint()?),
+            5 => SimpleAction::Attack(self.varint()?),
+            6 => SimpleAction::Retreat(self.varint()?),
+            7 => SimpleAction::EndTurn,
+            8 => {
+                let is_turn_energy = self.u8()? != 0;
+                let attachments = (0..self.varint()?)
+                    .map(|_| Ok((self.varint()? as u32, self.energy()?, self.varint()?)))
+                    .collect::<GameResult<_>>()?;
+                SimpleAction::Attach {
+                    attachments,
+                    is_turn_energy,
+                }
+            }
+            9 => SimpleAction::AttachTool {
+                in_play_idx: self.varint()?,
+                tool_id: tool(self.u8()?)?,
+            },
+            10 => SimpleAction::Heal {
+                in_play_idx: self.varint()?,
+                amount: self.varint()? as u32,
+            },
+            11 => SimpleAction::ApplyDamage {
+                targets: (0..self.varint()?)
+                    .map(|_| Ok((self.varint()? as u32, self.varint()?)))
+                    .collect::<GameResult<_>>()?,
+            },
+            12 => SimpleAction::MoveEnergy {
+                from_in_play_idx: self.varint()?,
+                to_in_play_idx: self.varint()?,
+                energy: self.energy()?,
+            },
+            13 => SimpleAction::SwapWithDeck(self.card()?),
+            14 => SimpleAction::DiscardEnergy {
+                in_play_idx: self.varint()?,
+                energy: self.energy()?,
+            },
+            15 => SimpleAction::ChooseDamageTarget {
+                player: self.varint()?,
+                in_play_idx: self.varint()?,
+                damage: self.varint()? as u32,
+            },
+            16 => SimpleAction::Activate {
+                in_play_idx: self.varint()?,
+            },
+            17 => SimpleAction::Promote {
+                bench_idx: self.varint()?,
+            },
+            18 => SimpleAction::CopyAttack(self.varint()?),
+            19 => SimpleAction::Setup {
+                active: self.varint()?,
+                bench: (0..self.varint()?)
+                    .map(|_| self.varint())
+                    .collect::<GameResult<_>>()?,
+            },
+            x => return Err(corrupt(format!("Invalid action tag {x}"))),
+        })
+    }
+
+    fn checkup_event(&mut self) -> GameResult<CheckupEvent> {
+        let tag = self.u8()?;
+        let player = self.varint()?;
+        let in_play_idx = self.varint()?;
+        let value = self.varint()?;
+        Ok(match tag {
+            0 => CheckupEvent::LumBerryUsed {
+                player,
+                in_play_idx,
+            },
+            1 => CheckupEvent::PoisonDamage {
+                player,
+                in_play_idx,
+                damage: value as u32,
+            },
+            2 => CheckupEvent::BurnDamage {
+                player,
+                in_play_idx,
+                damage: value as u32,
+            },
+            3 => CheckupEvent::BurnFlip {
+                player,
+                in_play_idx,
+                recovered: value != 0,
+            },
+            4 => CheckupEvent::SleepFlip {
+                player,
+                in_play_idx,
+                woke_up: value != 0,
+            },
+            5 => CheckupEvent::ParalysisRecovered {
+                player,
+                in_play_idx,
+            },
+            x => return Err(corrupt(format!("Invalid checkup event tag {x}"))),
+        })
+    }
+}
+
+// Bytes that don't decode to a state
+fn corrupt(reason: impl Into<String>) -> GameError {
+    GameError::invalid_saved_data(reason)
+}
+
+fn energy_type(x: u8) -> GameResult<EnergyType> {
+    ENERGY_TYPES
+        .get(x as usize)
+        .copied()
+        .ok_or_game_error(|| corrupt(format!("Invalid energy type {x}")))
+}
+
+fn tool(x: u8) -> GameResult<ToolId> {
+    TOOLS
+        .get(x as usize)
+        .copied()
+        .ok_or_game_error(|| corrupt(format!("Invalid tool {x}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::play_checked_game;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_round_trip_of_reachable_states() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut sizes = vec![];
+        for _ in 0..200 {
+            let length = rng.gen_range(10..300);
+            let data: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+            let state = play_checked_game(&data);
+            let bytes = state.to_compact_bytes();
+            assert_eq!(State::from_compact_bytes(&bytes).unwrap(), state);
+            sizes.push(bytes.len());
+        }
+        let average = sizes.iter().sum::<usize>() as f64 / sizes.len() as f64;
+        assert!(average < 200.0, "{average} bytes per state on average");
+    }
+
+    #[test]
+    fn test_round_trip_of_every_field() {
+        let (deck_a, deck_b) = crate::test_helpers::load_test_decks();
+        let mut state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(1));
+        state.rules = GameRules {
+            max_hand_size: Some(10),
+            tie_break: TieBreak::RemainingHp,
+            ..GameRules::default()
+        };
+        state.winner = Some(GameOutcome::Tie);
+        state.phase = TurnPhase::GameOver;
+        state.current_energy = Some(EnergyType::Dragon);
+        state.discarded_energy[1].insert(EnergyType::Fire, 300);
+        let mut pokemon =
+            crate::hooks::to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), true);
+        pokemon.attached_energy = vec![EnergyType::Colorless, EnergyType::Grass, EnergyType::Metal];
+        pokemon.attached_tool = Some(ToolId::A2149LumBerry);
+        pokemon.asleep = true;
+        pokemon.add_effect(get_card_by_enum(CardId::PA002XSpeed), 7);
+        pokemon.cards_behind = vec![get_card_by_enum(CardId::A1001Bulbasaur)];
+        state.in_play_pokemon[0][2] = Some(pokemon);
+        state.move_generation_stack.push((
+            1,
+            vec![
+                SimpleAction::Attach {
+                    attachments: vec![(2, EnergyType::Water, 3)],
+                    is_turn_energy: false,
+                },
+                SimpleAction::ApplyDamage {
+                    targets: vec![(130, 0), (20, 1)],
+                },
+                SimpleAction::Promote { bench_idx: 2 },
+            ],
+        ));
+        state.coin_flips.push(CoinFlips { flips: 3, heads: 2 });
+        state.checkup_events.push(CheckupEvent::BurnFlip {
+            player: 1,
+            in_play_idx: 0,
+            recovered: true,
+        });
+        state.add_turn_effect(get_card_by_enum(CardId::A1222Koga), 0);
+
+        let bytes = state.to_compact_bytes();
+        let mut decoded = State::from_compact_bytes(&bytes).unwrap();
+        // Checksums are recomputed from the cards, which changed above
+        assert!(decoded.decklist_checksums.is_some());
+        decoded.decklist_checksums = state.decklist_checksums;
+        assert_eq!(decoded, state);
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        let state = play_checked_game(&[7; 100]);
+        let bytes = state.to_compact_bytes();
+        for length in 0..bytes.len() {
+            assert!(State::from_compact_bytes(&bytes[..length]).is_err());
+        }
+        let mut extra = bytes.clone();
+        extra.push(0);
+        assert_eq!(
+            State::from_compact_bytes(&extra),
+            Err(corrupt("1 trailing bytes after the state"))
+        );
+        assert_eq!(
+            State::from_compact_bytes(&[VERSION + 1]),
+            Err(GameError::UnsupportedVersion {
+                found: VERSION as u32 + 1,
+                supported: VERSION as u32,
+            })
+        );
+    }
+}
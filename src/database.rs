@@ -1,13 +1,36 @@
 // This is code generated from the database.json by card_enum_generator.rs. Do not edit manually.
 
+use lazy_static::lazy_static;
+
 use crate::{
-    card_ids::CardId,
-    types::{Ability, Attack, Card, EnergyType, PokemonCard, TrainerCard, TrainerType},
+    card_ids::{CardId, ALL_CARD_IDS},
+    card_query::CardQuery,
+    sets::SetId,
+    types::{Ability, Attack, Card, CardData, EnergyType, PokemonCard, TrainerCard, TrainerType},
 };
 
+lazy_static! {
+    // Registry of every card's data, indexed by CardId, that Cards point into.
+    static ref CARDS: Vec<CardData> = ALL_CARD_IDS.iter().map(|id| get_card_data(*id)).collect();
+}
+
 pub fn get_card_by_enum(id: CardId) -> Card {
+    CARDS[id as usize].as_card()
+}
+
+pub use crate::card_query::find_by_name;
+
+/// The cards matching the query, in `ALL_CARD_IDS` order.
+pub fn query(query: CardQuery) -> impl Iterator<Item = Card> {
+    CARDS
+        .iter()
+        .map(CardData::as_card)
+        .filter(move |card| query.matches(card))
+}
+
+fn get_card_data(id: CardId) -> CardData {
     match id {
-        CardId::A1001Bulbasaur => Card::Pokemon(PokemonCard {
+        CardId::A1001Bulbasaur => CardData::Pokemon(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -26,9 +49,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1002Ivysaur => Card::Pokemon(PokemonCard {
+        CardId::A1002Ivysaur => CardData::Pokemon(PokemonCard {
             id: "A1 002".to_string(),
             name: "Ivysaur".to_string(),
             stage: 1,
@@ -47,9 +70,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1003Venusaur => Card::Pokemon(PokemonCard {
+        CardId::A1003Venusaur => CardData::Pokemon(PokemonCard {
             id: "A1 003".to_string(),
             name: "Venusaur".to_string(),
             stage: 2,
@@ -68,9 +91,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1004VenusaurEx => Card::Pokemon(PokemonCard {
+        CardId::A1004VenusaurEx => CardData::Pokemon(PokemonCard {
             id: "A1 004".to_string(),
             name: "Venusaur ex".to_string(),
             stage: 2,
@@ -95,9 +118,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1005Caterpie => Card::Pokemon(PokemonCard {
+        CardId::A1005Caterpie => CardData::Pokemon(PokemonCard {
             id: "A1 005".to_string(),
             name: "Caterpie".to_string(),
             stage: 0,
@@ -116,9 +139,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1006Metapod => Card::Pokemon(PokemonCard {
+        CardId::A1006Metapod => CardData::Pokemon(PokemonCard {
             id: "A1 006".to_string(),
             name: "Metapod".to_string(),
             stage: 1,
@@ -137,9 +160,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1007Butterfree => Card::Pokemon(PokemonCard {
+        CardId::A1007Butterfree => CardData::Pokemon(PokemonCard {
             id: "A1 007".to_string(),
             name: "Butterfree".to_string(),
             stage: 2,
@@ -158,9 +181,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1008Weedle => Card::Pokemon(PokemonCard {
+        CardId::A1008Weedle => CardData::Pokemon(PokemonCard {
             id: "A1 008".to_string(),
             name: "Weedle".to_string(),
             stage: 0,
@@ -179,9 +202,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1009Kakuna => Card::Pokemon(PokemonCard {
+        CardId::A1009Kakuna => CardData::Pokemon(PokemonCard {
             id: "A1 009".to_string(),
             name: "Kakuna".to_string(),
             stage: 1,
@@ -200,9 +223,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1010Beedrill => Card::Pokemon(PokemonCard {
+        CardId::A1010Beedrill => CardData::Pokemon(PokemonCard {
             id: "A1 010".to_string(),
             name: "Beedrill".to_string(),
             stage: 2,
@@ -221,9 +244,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1011Oddish => Card::Pokemon(PokemonCard {
+        CardId::A1011Oddish => CardData::Pokemon(PokemonCard {
             id: "A1 011".to_string(),
             name: "Oddish".to_string(),
             stage: 0,
@@ -242,9 +265,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1012Gloom => Card::Pokemon(PokemonCard {
+        CardId::A1012Gloom => CardData::Pokemon(PokemonCard {
             id: "A1 012".to_string(),
             name: "Gloom".to_string(),
             stage: 1,
@@ -263,9 +286,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1013Vileplume => Card::Pokemon(PokemonCard {
+        CardId::A1013Vileplume => CardData::Pokemon(PokemonCard {
             id: "A1 013".to_string(),
             name: "Vileplume".to_string(),
             stage: 2,
@@ -284,9 +307,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1014Paras => Card::Pokemon(PokemonCard {
+        CardId::A1014Paras => CardData::Pokemon(PokemonCard {
             id: "A1 014".to_string(),
             name: "Paras".to_string(),
             stage: 0,
@@ -305,9 +328,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1015Parasect => Card::Pokemon(PokemonCard {
+        CardId::A1015Parasect => CardData::Pokemon(PokemonCard {
             id: "A1 015".to_string(),
             name: "Parasect".to_string(),
             stage: 1,
@@ -326,9 +349,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1016Venonat => Card::Pokemon(PokemonCard {
+        CardId::A1016Venonat => CardData::Pokemon(PokemonCard {
             id: "A1 016".to_string(),
             name: "Venonat".to_string(),
             stage: 0,
@@ -347,9 +370,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1017Venomoth => Card::Pokemon(PokemonCard {
+        CardId::A1017Venomoth => CardData::Pokemon(PokemonCard {
             id: "A1 017".to_string(),
             name: "Venomoth".to_string(),
             stage: 1,
@@ -368,9 +391,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1018Bellsprout => Card::Pokemon(PokemonCard {
+        CardId::A1018Bellsprout => CardData::Pokemon(PokemonCard {
             id: "A1 018".to_string(),
             name: "Bellsprout".to_string(),
             stage: 0,
@@ -389,9 +412,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1019Weepinbell => Card::Pokemon(PokemonCard {
+        CardId::A1019Weepinbell => CardData::Pokemon(PokemonCard {
             id: "A1 019".to_string(),
             name: "Weepinbell".to_string(),
             stage: 1,
@@ -410,9 +433,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1020Victreebel => Card::Pokemon(PokemonCard {
+        CardId::A1020Victreebel => CardData::Pokemon(PokemonCard {
             id: "A1 020".to_string(),
             name: "Victreebel".to_string(),
             stage: 2,
@@ -431,9 +454,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1021Exeggcute => Card::Pokemon(PokemonCard {
+        CardId::A1021Exeggcute => CardData::Pokemon(PokemonCard {
             id: "A1 021".to_string(),
             name: "Exeggcute".to_string(),
             stage: 0,
@@ -452,9 +475,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1022Exeggutor => Card::Pokemon(PokemonCard {
+        CardId::A1022Exeggutor => CardData::Pokemon(PokemonCard {
             id: "A1 022".to_string(),
             name: "Exeggutor".to_string(),
             stage: 1,
@@ -473,9 +496,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1023ExeggutorEx => Card::Pokemon(PokemonCard {
+        CardId::A1023ExeggutorEx => CardData::Pokemon(PokemonCard {
             id: "A1 023".to_string(),
             name: "Exeggutor ex".to_string(),
             stage: 1,
@@ -494,9 +517,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1024Tangela => Card::Pokemon(PokemonCard {
+        CardId::A1024Tangela => CardData::Pokemon(PokemonCard {
             id: "A1 024".to_string(),
             name: "Tangela".to_string(),
             stage: 0,
@@ -515,9 +538,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1025Scyther => Card::Pokemon(PokemonCard {
+        CardId::A1025Scyther => CardData::Pokemon(PokemonCard {
             id: "A1 025".to_string(),
             name: "Scyther".to_string(),
             stage: 0,
@@ -536,9 +559,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1026Pinsir => Card::Pokemon(PokemonCard {
+        CardId::A1026Pinsir => CardData::Pokemon(PokemonCard {
             id: "A1 026".to_string(),
             name: "Pinsir".to_string(),
             stage: 0,
@@ -557,9 +580,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1027Cottonee => Card::Pokemon(PokemonCard {
+        CardId::A1027Cottonee => CardData::Pokemon(PokemonCard {
             id: "A1 027".to_string(),
             name: "Cottonee".to_string(),
             stage: 0,
@@ -578,9 +601,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1028Whimsicott => Card::Pokemon(PokemonCard {
+        CardId::A1028Whimsicott => CardData::Pokemon(PokemonCard {
             id: "A1 028".to_string(),
             name: "Whimsicott".to_string(),
             stage: 1,
@@ -599,9 +622,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1029Petilil => Card::Pokemon(PokemonCard {
+        CardId::A1029Petilil => CardData::Pokemon(PokemonCard {
             id: "A1 029".to_string(),
             name: "Petilil".to_string(),
             stage: 0,
@@ -620,9 +643,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1030Lilligant => Card::Pokemon(PokemonCard {
+        CardId::A1030Lilligant => CardData::Pokemon(PokemonCard {
             id: "A1 030".to_string(),
             name: "Lilligant".to_string(),
             stage: 1,
@@ -641,9 +664,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1031Skiddo => Card::Pokemon(PokemonCard {
+        CardId::A1031Skiddo => CardData::Pokemon(PokemonCard {
             id: "A1 031".to_string(),
             name: "Skiddo".to_string(),
             stage: 0,
@@ -662,9 +685,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1032Gogoat => Card::Pokemon(PokemonCard {
+        CardId::A1032Gogoat => CardData::Pokemon(PokemonCard {
             id: "A1 032".to_string(),
             name: "Gogoat".to_string(),
             stage: 1,
@@ -683,9 +706,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1033Charmander => Card::Pokemon(PokemonCard {
+        CardId::A1033Charmander => CardData::Pokemon(PokemonCard {
             id: "A1 033".to_string(),
             name: "Charmander".to_string(),
             stage: 0,
@@ -704,9 +727,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1034Charmeleon => Card::Pokemon(PokemonCard {
+        CardId::A1034Charmeleon => CardData::Pokemon(PokemonCard {
             id: "A1 034".to_string(),
             name: "Charmeleon".to_string(),
             stage: 1,
@@ -725,9 +748,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1035Charizard => Card::Pokemon(PokemonCard {
+        CardId::A1035Charizard => CardData::Pokemon(PokemonCard {
             id: "A1 035".to_string(),
             name: "Charizard".to_string(),
             stage: 2,
@@ -746,9 +769,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1036CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A1036CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A1 036".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -773,9 +796,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1037Vulpix => Card::Pokemon(PokemonCard {
+        CardId::A1037Vulpix => CardData::Pokemon(PokemonCard {
             id: "A1 037".to_string(),
             name: "Vulpix".to_string(),
             stage: 0,
@@ -794,9 +817,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1038Ninetales => Card::Pokemon(PokemonCard {
+        CardId::A1038Ninetales => CardData::Pokemon(PokemonCard {
             id: "A1 038".to_string(),
             name: "Ninetales".to_string(),
             stage: 1,
@@ -815,9 +838,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1039Growlithe => Card::Pokemon(PokemonCard {
+        CardId::A1039Growlithe => CardData::Pokemon(PokemonCard {
             id: "A1 039".to_string(),
             name: "Growlithe".to_string(),
             stage: 0,
@@ -836,9 +859,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1040Arcanine => Card::Pokemon(PokemonCard {
+        CardId::A1040Arcanine => CardData::Pokemon(PokemonCard {
             id: "A1 040".to_string(),
             name: "Arcanine".to_string(),
             stage: 1,
@@ -857,9 +880,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1041ArcanineEx => Card::Pokemon(PokemonCard {
+        CardId::A1041ArcanineEx => CardData::Pokemon(PokemonCard {
             id: "A1 041".to_string(),
             name: "Arcanine ex".to_string(),
             stage: 1,
@@ -878,9 +901,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1042Ponyta => Card::Pokemon(PokemonCard {
+        CardId::A1042Ponyta => CardData::Pokemon(PokemonCard {
             id: "A1 042".to_string(),
             name: "Ponyta".to_string(),
             stage: 0,
@@ -899,9 +922,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1043Rapidash => Card::Pokemon(PokemonCard {
+        CardId::A1043Rapidash => CardData::Pokemon(PokemonCard {
             id: "A1 043".to_string(),
             name: "Rapidash".to_string(),
             stage: 1,
@@ -920,9 +943,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1044Magmar => Card::Pokemon(PokemonCard {
+        CardId::A1044Magmar => CardData::Pokemon(PokemonCard {
             id: "A1 044".to_string(),
             name: "Magmar".to_string(),
             stage: 0,
@@ -941,9 +964,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1045Flareon => Card::Pokemon(PokemonCard {
+        CardId::A1045Flareon => CardData::Pokemon(PokemonCard {
             id: "A1 045".to_string(),
             name: "Flareon".to_string(),
             stage: 1,
@@ -962,9 +985,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1046Moltres => Card::Pokemon(PokemonCard {
+        CardId::A1046Moltres => CardData::Pokemon(PokemonCard {
             id: "A1 046".to_string(),
             name: "Moltres".to_string(),
             stage: 0,
@@ -983,9 +1006,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1047MoltresEx => Card::Pokemon(PokemonCard {
+        CardId::A1047MoltresEx => CardData::Pokemon(PokemonCard {
             id: "A1 047".to_string(),
             name: "Moltres ex".to_string(),
             stage: 0,
@@ -1010,9 +1033,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1048Heatmor => Card::Pokemon(PokemonCard {
+        CardId::A1048Heatmor => CardData::Pokemon(PokemonCard {
             id: "A1 048".to_string(),
             name: "Heatmor".to_string(),
             stage: 0,
@@ -1031,9 +1054,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1049Salandit => Card::Pokemon(PokemonCard {
+        CardId::A1049Salandit => CardData::Pokemon(PokemonCard {
             id: "A1 049".to_string(),
             name: "Salandit".to_string(),
             stage: 0,
@@ -1052,9 +1075,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1050Salazzle => Card::Pokemon(PokemonCard {
+        CardId::A1050Salazzle => CardData::Pokemon(PokemonCard {
             id: "A1 050".to_string(),
             name: "Salazzle".to_string(),
             stage: 1,
@@ -1073,9 +1096,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1051Sizzlipede => Card::Pokemon(PokemonCard {
+        CardId::A1051Sizzlipede => CardData::Pokemon(PokemonCard {
             id: "A1 051".to_string(),
             name: "Sizzlipede".to_string(),
             stage: 0,
@@ -1094,9 +1117,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1052Centiskorch => Card::Pokemon(PokemonCard {
+        CardId::A1052Centiskorch => CardData::Pokemon(PokemonCard {
             id: "A1 052".to_string(),
             name: "Centiskorch".to_string(),
             stage: 1,
@@ -1115,9 +1138,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1053Squirtle => Card::Pokemon(PokemonCard {
+        CardId::A1053Squirtle => CardData::Pokemon(PokemonCard {
             id: "A1 053".to_string(),
             name: "Squirtle".to_string(),
             stage: 0,
@@ -1136,9 +1159,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1054Wartortle => Card::Pokemon(PokemonCard {
+        CardId::A1054Wartortle => CardData::Pokemon(PokemonCard {
             id: "A1 054".to_string(),
             name: "Wartortle".to_string(),
             stage: 1,
@@ -1157,9 +1180,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1055Blastoise => Card::Pokemon(PokemonCard {
+        CardId::A1055Blastoise => CardData::Pokemon(PokemonCard {
             id: "A1 055".to_string(),
             name: "Blastoise".to_string(),
             stage: 2,
@@ -1178,9 +1201,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1056BlastoiseEx => Card::Pokemon(PokemonCard {
+        CardId::A1056BlastoiseEx => CardData::Pokemon(PokemonCard {
             id: "A1 056".to_string(),
             name: "Blastoise ex".to_string(),
             stage: 2,
@@ -1205,9 +1228,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1057Psyduck => Card::Pokemon(PokemonCard {
+        CardId::A1057Psyduck => CardData::Pokemon(PokemonCard {
             id: "A1 057".to_string(),
             name: "Psyduck".to_string(),
             stage: 0,
@@ -1226,9 +1249,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1058Golduck => Card::Pokemon(PokemonCard {
+        CardId::A1058Golduck => CardData::Pokemon(PokemonCard {
             id: "A1 058".to_string(),
             name: "Golduck".to_string(),
             stage: 1,
@@ -1247,9 +1270,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1059Poliwag => Card::Pokemon(PokemonCard {
+        CardId::A1059Poliwag => CardData::Pokemon(PokemonCard {
             id: "A1 059".to_string(),
             name: "Poliwag".to_string(),
             stage: 0,
@@ -1268,9 +1291,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1060Poliwhirl => Card::Pokemon(PokemonCard {
+        CardId::A1060Poliwhirl => CardData::Pokemon(PokemonCard {
             id: "A1 060".to_string(),
             name: "Poliwhirl".to_string(),
             stage: 1,
@@ -1289,9 +1312,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1061Poliwrath => Card::Pokemon(PokemonCard {
+        CardId::A1061Poliwrath => CardData::Pokemon(PokemonCard {
             id: "A1 061".to_string(),
             name: "Poliwrath".to_string(),
             stage: 2,
@@ -1310,9 +1333,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1062Tentacool => Card::Pokemon(PokemonCard {
+        CardId::A1062Tentacool => CardData::Pokemon(PokemonCard {
             id: "A1 062".to_string(),
             name: "Tentacool".to_string(),
             stage: 0,
@@ -1331,9 +1354,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1063Tentacruel => Card::Pokemon(PokemonCard {
+        CardId::A1063Tentacruel => CardData::Pokemon(PokemonCard {
             id: "A1 063".to_string(),
             name: "Tentacruel".to_string(),
             stage: 1,
@@ -1352,9 +1375,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1064Seel => Card::Pokemon(PokemonCard {
+        CardId::A1064Seel => CardData::Pokemon(PokemonCard {
             id: "A1 064".to_string(),
             name: "Seel".to_string(),
             stage: 0,
@@ -1373,9 +1396,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1065Dewgong => Card::Pokemon(PokemonCard {
+        CardId::A1065Dewgong => CardData::Pokemon(PokemonCard {
             id: "A1 065".to_string(),
             name: "Dewgong".to_string(),
             stage: 1,
@@ -1394,9 +1417,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1066Shellder => Card::Pokemon(PokemonCard {
+        CardId::A1066Shellder => CardData::Pokemon(PokemonCard {
             id: "A1 066".to_string(),
             name: "Shellder".to_string(),
             stage: 0,
@@ -1415,9 +1438,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1067Cloyster => Card::Pokemon(PokemonCard {
+        CardId::A1067Cloyster => CardData::Pokemon(PokemonCard {
             id: "A1 067".to_string(),
             name: "Cloyster".to_string(),
             stage: 1,
@@ -1436,9 +1459,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1068Krabby => Card::Pokemon(PokemonCard {
+        CardId::A1068Krabby => CardData::Pokemon(PokemonCard {
             id: "A1 068".to_string(),
             name: "Krabby".to_string(),
             stage: 0,
@@ -1457,9 +1480,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1069Kingler => Card::Pokemon(PokemonCard {
+        CardId::A1069Kingler => CardData::Pokemon(PokemonCard {
             id: "A1 069".to_string(),
             name: "Kingler".to_string(),
             stage: 1,
@@ -1478,9 +1501,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1070Horsea => Card::Pokemon(PokemonCard {
+        CardId::A1070Horsea => CardData::Pokemon(PokemonCard {
             id: "A1 070".to_string(),
             name: "Horsea".to_string(),
             stage: 0,
@@ -1499,9 +1522,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1071Seadra => Card::Pokemon(PokemonCard {
+        CardId::A1071Seadra => CardData::Pokemon(PokemonCard {
             id: "A1 071".to_string(),
             name: "Seadra".to_string(),
             stage: 1,
@@ -1520,9 +1543,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1072Goldeen => Card::Pokemon(PokemonCard {
+        CardId::A1072Goldeen => CardData::Pokemon(PokemonCard {
             id: "A1 072".to_string(),
             name: "Goldeen".to_string(),
             stage: 0,
@@ -1541,9 +1564,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1073Seaking => Card::Pokemon(PokemonCard {
+        CardId::A1073Seaking => CardData::Pokemon(PokemonCard {
             id: "A1 073".to_string(),
             name: "Seaking".to_string(),
             stage: 1,
@@ -1562,9 +1585,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1074Staryu => Card::Pokemon(PokemonCard {
+        CardId::A1074Staryu => CardData::Pokemon(PokemonCard {
             id: "A1 074".to_string(),
             name: "Staryu".to_string(),
             stage: 0,
@@ -1583,9 +1606,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1075Starmie => Card::Pokemon(PokemonCard {
+        CardId::A1075Starmie => CardData::Pokemon(PokemonCard {
             id: "A1 075".to_string(),
             name: "Starmie".to_string(),
             stage: 1,
@@ -1604,9 +1627,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1076StarmieEx => Card::Pokemon(PokemonCard {
+        CardId::A1076StarmieEx => CardData::Pokemon(PokemonCard {
             id: "A1 076".to_string(),
             name: "Starmie ex".to_string(),
             stage: 1,
@@ -1625,9 +1648,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1077Magikarp => Card::Pokemon(PokemonCard {
+        CardId::A1077Magikarp => CardData::Pokemon(PokemonCard {
             id: "A1 077".to_string(),
             name: "Magikarp".to_string(),
             stage: 0,
@@ -1646,9 +1669,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1078Gyarados => Card::Pokemon(PokemonCard {
+        CardId::A1078Gyarados => CardData::Pokemon(PokemonCard {
             id: "A1 078".to_string(),
             name: "Gyarados".to_string(),
             stage: 1,
@@ -1667,9 +1690,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1079Lapras => Card::Pokemon(PokemonCard {
+        CardId::A1079Lapras => CardData::Pokemon(PokemonCard {
             id: "A1 079".to_string(),
             name: "Lapras".to_string(),
             stage: 0,
@@ -1688,9 +1711,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1080Vaporeon => Card::Pokemon(PokemonCard {
+        CardId::A1080Vaporeon => CardData::Pokemon(PokemonCard {
             id: "A1 080".to_string(),
             name: "Vaporeon".to_string(),
             stage: 1,
@@ -1709,9 +1732,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1081Omanyte => Card::Pokemon(PokemonCard {
+        CardId::A1081Omanyte => CardData::Pokemon(PokemonCard {
             id: "A1 081".to_string(),
             name: "Omanyte".to_string(),
             stage: 1,
@@ -1730,9 +1753,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1082Omastar => Card::Pokemon(PokemonCard {
+        CardId::A1082Omastar => CardData::Pokemon(PokemonCard {
             id: "A1 082".to_string(),
             name: "Omastar".to_string(),
             stage: 2,
@@ -1751,9 +1774,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1083Articuno => Card::Pokemon(PokemonCard {
+        CardId::A1083Articuno => CardData::Pokemon(PokemonCard {
             id: "A1 083".to_string(),
             name: "Articuno".to_string(),
             stage: 0,
@@ -1772,9 +1795,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1084ArticunoEx => Card::Pokemon(PokemonCard {
+        CardId::A1084ArticunoEx => CardData::Pokemon(PokemonCard {
             id: "A1 084".to_string(),
             name: "Articuno ex".to_string(),
             stage: 0,
@@ -1799,9 +1822,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1085Ducklett => Card::Pokemon(PokemonCard {
+        CardId::A1085Ducklett => CardData::Pokemon(PokemonCard {
             id: "A1 085".to_string(),
             name: "Ducklett".to_string(),
             stage: 0,
@@ -1820,9 +1843,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1086Swanna => Card::Pokemon(PokemonCard {
+        CardId::A1086Swanna => CardData::Pokemon(PokemonCard {
             id: "A1 086".to_string(),
             name: "Swanna".to_string(),
             stage: 1,
@@ -1841,9 +1864,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1087Froakie => Card::Pokemon(PokemonCard {
+        CardId::A1087Froakie => CardData::Pokemon(PokemonCard {
             id: "A1 087".to_string(),
             name: "Froakie".to_string(),
             stage: 0,
@@ -1862,9 +1885,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1088Frogadier => Card::Pokemon(PokemonCard {
+        CardId::A1088Frogadier => CardData::Pokemon(PokemonCard {
             id: "A1 088".to_string(),
             name: "Frogadier".to_string(),
             stage: 1,
@@ -1883,9 +1906,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1089Greninja => Card::Pokemon(PokemonCard {
+        CardId::A1089Greninja => CardData::Pokemon(PokemonCard {
             id: "A1 089".to_string(),
             name: "Greninja".to_string(),
             stage: 2,
@@ -1904,9 +1927,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1090Pyukumuku => Card::Pokemon(PokemonCard {
+        CardId::A1090Pyukumuku => CardData::Pokemon(PokemonCard {
             id: "A1 090".to_string(),
             name: "Pyukumuku".to_string(),
             stage: 0,
@@ -1925,9 +1948,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1091Bruxish => Card::Pokemon(PokemonCard {
+        CardId::A1091Bruxish => CardData::Pokemon(PokemonCard {
             id: "A1 091".to_string(),
             name: "Bruxish".to_string(),
             stage: 0,
@@ -1946,9 +1969,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1092Snom => Card::Pokemon(PokemonCard {
+        CardId::A1092Snom => CardData::Pokemon(PokemonCard {
             id: "A1 092".to_string(),
             name: "Snom".to_string(),
             stage: 0,
@@ -1967,9 +1990,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1093Frosmoth => Card::Pokemon(PokemonCard {
+        CardId::A1093Frosmoth => CardData::Pokemon(PokemonCard {
             id: "A1 093".to_string(),
             name: "Frosmoth".to_string(),
             stage: 1,
@@ -1988,9 +2011,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1094Pikachu => Card::Pokemon(PokemonCard {
+        CardId::A1094Pikachu => CardData::Pokemon(PokemonCard {
             id: "A1 094".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -2009,9 +2032,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1095Raichu => Card::Pokemon(PokemonCard {
+        CardId::A1095Raichu => CardData::Pokemon(PokemonCard {
             id: "A1 095".to_string(),
             name: "Raichu".to_string(),
             stage: 1,
@@ -2030,9 +2053,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1096PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A1096PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A1 096".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -2051,9 +2074,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1097Magnemite => Card::Pokemon(PokemonCard {
+        CardId::A1097Magnemite => CardData::Pokemon(PokemonCard {
             id: "A1 097".to_string(),
             name: "Magnemite".to_string(),
             stage: 0,
@@ -2072,9 +2095,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1098Magneton => Card::Pokemon(PokemonCard {
+        CardId::A1098Magneton => CardData::Pokemon(PokemonCard {
             id: "A1 098".to_string(),
             name: "Magneton".to_string(),
             stage: 1,
@@ -2093,9 +2116,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1099Voltorb => Card::Pokemon(PokemonCard {
+        CardId::A1099Voltorb => CardData::Pokemon(PokemonCard {
             id: "A1 099".to_string(),
             name: "Voltorb".to_string(),
             stage: 0,
@@ -2114,9 +2137,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1100Electrode => Card::Pokemon(PokemonCard {
+        CardId::A1100Electrode => CardData::Pokemon(PokemonCard {
             id: "A1 100".to_string(),
             name: "Electrode".to_string(),
             stage: 1,
@@ -2135,9 +2158,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1101Electabuzz => Card::Pokemon(PokemonCard {
+        CardId::A1101Electabuzz => CardData::Pokemon(PokemonCard {
             id: "A1 101".to_string(),
             name: "Electabuzz".to_string(),
             stage: 0,
@@ -2156,9 +2179,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1102Jolteon => Card::Pokemon(PokemonCard {
+        CardId::A1102Jolteon => CardData::Pokemon(PokemonCard {
             id: "A1 102".to_string(),
             name: "Jolteon".to_string(),
             stage: 1,
@@ -2177,9 +2200,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1103Zapdos => Card::Pokemon(PokemonCard {
+        CardId::A1103Zapdos => CardData::Pokemon(PokemonCard {
             id: "A1 103".to_string(),
             name: "Zapdos".to_string(),
             stage: 0,
@@ -2198,9 +2221,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1104ZapdosEx => Card::Pokemon(PokemonCard {
+        CardId::A1104ZapdosEx => CardData::Pokemon(PokemonCard {
             id: "A1 104".to_string(),
             name: "Zapdos ex".to_string(),
             stage: 0,
@@ -2225,9 +2248,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1105Blitzle => Card::Pokemon(PokemonCard {
+        CardId::A1105Blitzle => CardData::Pokemon(PokemonCard {
             id: "A1 105".to_string(),
             name: "Blitzle".to_string(),
             stage: 0,
@@ -2246,9 +2269,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1106Zebstrika => Card::Pokemon(PokemonCard {
+        CardId::A1106Zebstrika => CardData::Pokemon(PokemonCard {
             id: "A1 106".to_string(),
             name: "Zebstrika".to_string(),
             stage: 1,
@@ -2267,9 +2290,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1107Tynamo => Card::Pokemon(PokemonCard {
+        CardId::A1107Tynamo => CardData::Pokemon(PokemonCard {
             id: "A1 107".to_string(),
             name: "Tynamo".to_string(),
             stage: 0,
@@ -2288,9 +2311,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1108Eelektrik => Card::Pokemon(PokemonCard {
+        CardId::A1108Eelektrik => CardData::Pokemon(PokemonCard {
             id: "A1 108".to_string(),
             name: "Eelektrik".to_string(),
             stage: 1,
@@ -2309,9 +2332,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1109Eelektross => Card::Pokemon(PokemonCard {
+        CardId::A1109Eelektross => CardData::Pokemon(PokemonCard {
             id: "A1 109".to_string(),
             name: "Eelektross".to_string(),
             stage: 2,
@@ -2330,9 +2353,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1110Helioptile => Card::Pokemon(PokemonCard {
+        CardId::A1110Helioptile => CardData::Pokemon(PokemonCard {
             id: "A1 110".to_string(),
             name: "Helioptile".to_string(),
             stage: 0,
@@ -2351,9 +2374,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1111Heliolisk => Card::Pokemon(PokemonCard {
+        CardId::A1111Heliolisk => CardData::Pokemon(PokemonCard {
             id: "A1 111".to_string(),
             name: "Heliolisk".to_string(),
             stage: 1,
@@ -2372,9 +2395,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1112Pincurchin => Card::Pokemon(PokemonCard {
+        CardId::A1112Pincurchin => CardData::Pokemon(PokemonCard {
             id: "A1 112".to_string(),
             name: "Pincurchin".to_string(),
             stage: 0,
@@ -2393,9 +2416,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1113Clefairy => Card::Pokemon(PokemonCard {
+        CardId::A1113Clefairy => CardData::Pokemon(PokemonCard {
             id: "A1 113".to_string(),
             name: "Clefairy".to_string(),
             stage: 0,
@@ -2414,9 +2437,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1114Clefable => Card::Pokemon(PokemonCard {
+        CardId::A1114Clefable => CardData::Pokemon(PokemonCard {
             id: "A1 114".to_string(),
             name: "Clefable".to_string(),
             stage: 1,
@@ -2435,9 +2458,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1115Abra => Card::Pokemon(PokemonCard {
+        CardId::A1115Abra => CardData::Pokemon(PokemonCard {
             id: "A1 115".to_string(),
             name: "Abra".to_string(),
             stage: 0,
@@ -2456,9 +2479,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1116Kadabra => Card::Pokemon(PokemonCard {
+        CardId::A1116Kadabra => CardData::Pokemon(PokemonCard {
             id: "A1 116".to_string(),
             name: "Kadabra".to_string(),
             stage: 1,
@@ -2477,9 +2500,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1117Alakazam => Card::Pokemon(PokemonCard {
+        CardId::A1117Alakazam => CardData::Pokemon(PokemonCard {
             id: "A1 117".to_string(),
             name: "Alakazam".to_string(),
             stage: 2,
@@ -2498,9 +2521,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1118Slowpoke => Card::Pokemon(PokemonCard {
+        CardId::A1118Slowpoke => CardData::Pokemon(PokemonCard {
             id: "A1 118".to_string(),
             name: "Slowpoke".to_string(),
             stage: 0,
@@ -2519,9 +2542,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1119Slowbro => Card::Pokemon(PokemonCard {
+        CardId::A1119Slowbro => CardData::Pokemon(PokemonCard {
             id: "A1 119".to_string(),
             name: "Slowbro".to_string(),
             stage: 1,
@@ -2540,9 +2563,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1120Gastly => Card::Pokemon(PokemonCard {
+        CardId::A1120Gastly => CardData::Pokemon(PokemonCard {
             id: "A1 120".to_string(),
             name: "Gastly".to_string(),
             stage: 0,
@@ -2561,9 +2584,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1121Haunter => Card::Pokemon(PokemonCard {
+        CardId::A1121Haunter => CardData::Pokemon(PokemonCard {
             id: "A1 121".to_string(),
             name: "Haunter".to_string(),
             stage: 1,
@@ -2582,9 +2605,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1122Gengar => Card::Pokemon(PokemonCard {
+        CardId::A1122Gengar => CardData::Pokemon(PokemonCard {
             id: "A1 122".to_string(),
             name: "Gengar".to_string(),
             stage: 2,
@@ -2603,9 +2626,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1123GengarEx => Card::Pokemon(PokemonCard {
+        CardId::A1123GengarEx => CardData::Pokemon(PokemonCard {
             id: "A1 123".to_string(),
             name: "Gengar ex".to_string(),
             stage: 2,
@@ -2624,9 +2647,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1124Drowzee => Card::Pokemon(PokemonCard {
+        CardId::A1124Drowzee => CardData::Pokemon(PokemonCard {
             id: "A1 124".to_string(),
             name: "Drowzee".to_string(),
             stage: 0,
@@ -2645,9 +2668,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1125Hypno => Card::Pokemon(PokemonCard {
+        CardId::A1125Hypno => CardData::Pokemon(PokemonCard {
             id: "A1 125".to_string(),
             name: "Hypno".to_string(),
             stage: 1,
@@ -2666,9 +2689,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1126MrMime => Card::Pokemon(PokemonCard {
+        CardId::A1126MrMime => CardData::Pokemon(PokemonCard {
             id: "A1 126".to_string(),
             name: "Mr. Mime".to_string(),
             stage: 0,
@@ -2687,9 +2710,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1127Jynx => Card::Pokemon(PokemonCard {
+        CardId::A1127Jynx => CardData::Pokemon(PokemonCard {
             id: "A1 127".to_string(),
             name: "Jynx".to_string(),
             stage: 0,
@@ -2708,9 +2731,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1128Mewtwo => Card::Pokemon(PokemonCard {
+        CardId::A1128Mewtwo => CardData::Pokemon(PokemonCard {
             id: "A1 128".to_string(),
             name: "Mewtwo".to_string(),
             stage: 0,
@@ -2729,9 +2752,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1129MewtwoEx => Card::Pokemon(PokemonCard {
+        CardId::A1129MewtwoEx => CardData::Pokemon(PokemonCard {
             id: "A1 129".to_string(),
             name: "Mewtwo ex".to_string(),
             stage: 0,
@@ -2756,9 +2779,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1130Ralts => Card::Pokemon(PokemonCard {
+        CardId::A1130Ralts => CardData::Pokemon(PokemonCard {
             id: "A1 130".to_string(),
             name: "Ralts".to_string(),
             stage: 0,
@@ -2777,9 +2800,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1131Kirlia => Card::Pokemon(PokemonCard {
+        CardId::A1131Kirlia => CardData::Pokemon(PokemonCard {
             id: "A1 131".to_string(),
             name: "Kirlia".to_string(),
             stage: 1,
@@ -2798,9 +2821,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1132Gardevoir => Card::Pokemon(PokemonCard {
+        CardId::A1132Gardevoir => CardData::Pokemon(PokemonCard {
             id: "A1 132".to_string(),
             name: "Gardevoir".to_string(),
             stage: 2,
@@ -2819,9 +2842,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1133Woobat => Card::Pokemon(PokemonCard {
+        CardId::A1133Woobat => CardData::Pokemon(PokemonCard {
             id: "A1 133".to_string(),
             name: "Woobat".to_string(),
             stage: 0,
@@ -2840,9 +2863,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1134Swoobat => Card::Pokemon(PokemonCard {
+        CardId::A1134Swoobat => CardData::Pokemon(PokemonCard {
             id: "A1 134".to_string(),
             name: "Swoobat".to_string(),
             stage: 1,
@@ -2861,9 +2884,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1135Golett => Card::Pokemon(PokemonCard {
+        CardId::A1135Golett => CardData::Pokemon(PokemonCard {
             id: "A1 135".to_string(),
             name: "Golett".to_string(),
             stage: 0,
@@ -2882,9 +2905,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1136Golurk => Card::Pokemon(PokemonCard {
+        CardId::A1136Golurk => CardData::Pokemon(PokemonCard {
             id: "A1 136".to_string(),
             name: "Golurk".to_string(),
             stage: 1,
@@ -2903,9 +2926,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1137Sandshrew => Card::Pokemon(PokemonCard {
+        CardId::A1137Sandshrew => CardData::Pokemon(PokemonCard {
             id: "A1 137".to_string(),
             name: "Sandshrew".to_string(),
             stage: 0,
@@ -2924,9 +2947,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1138Sandslash => Card::Pokemon(PokemonCard {
+        CardId::A1138Sandslash => CardData::Pokemon(PokemonCard {
             id: "A1 138".to_string(),
             name: "Sandslash".to_string(),
             stage: 1,
@@ -2945,9 +2968,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1139Diglett => Card::Pokemon(PokemonCard {
+        CardId::A1139Diglett => CardData::Pokemon(PokemonCard {
             id: "A1 139".to_string(),
             name: "Diglett".to_string(),
             stage: 0,
@@ -2966,9 +2989,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1140Dugtrio => Card::Pokemon(PokemonCard {
+        CardId::A1140Dugtrio => CardData::Pokemon(PokemonCard {
             id: "A1 140".to_string(),
             name: "Dugtrio".to_string(),
             stage: 1,
@@ -2987,9 +3010,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1141Mankey => Card::Pokemon(PokemonCard {
+        CardId::A1141Mankey => CardData::Pokemon(PokemonCard {
             id: "A1 141".to_string(),
             name: "Mankey".to_string(),
             stage: 0,
@@ -3008,9 +3031,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1142Primeape => Card::Pokemon(PokemonCard {
+        CardId::A1142Primeape => CardData::Pokemon(PokemonCard {
             id: "A1 142".to_string(),
             name: "Primeape".to_string(),
             stage: 1,
@@ -3029,9 +3052,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1143Machop => Card::Pokemon(PokemonCard {
+        CardId::A1143Machop => CardData::Pokemon(PokemonCard {
             id: "A1 143".to_string(),
             name: "Machop".to_string(),
             stage: 0,
@@ -3050,9 +3073,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1144Machoke => Card::Pokemon(PokemonCard {
+        CardId::A1144Machoke => CardData::Pokemon(PokemonCard {
             id: "A1 144".to_string(),
             name: "Machoke".to_string(),
             stage: 1,
@@ -3071,9 +3094,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1145Machamp => Card::Pokemon(PokemonCard {
+        CardId::A1145Machamp => CardData::Pokemon(PokemonCard {
             id: "A1 145".to_string(),
             name: "Machamp".to_string(),
             stage: 2,
@@ -3092,9 +3115,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1146MachampEx => Card::Pokemon(PokemonCard {
+        CardId::A1146MachampEx => CardData::Pokemon(PokemonCard {
             id: "A1 146".to_string(),
             name: "Machamp ex".to_string(),
             stage: 2,
@@ -3113,9 +3136,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1147Geodude => Card::Pokemon(PokemonCard {
+        CardId::A1147Geodude => CardData::Pokemon(PokemonCard {
             id: "A1 147".to_string(),
             name: "Geodude".to_string(),
             stage: 0,
@@ -3134,9 +3157,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1148Graveler => Card::Pokemon(PokemonCard {
+        CardId::A1148Graveler => CardData::Pokemon(PokemonCard {
             id: "A1 148".to_string(),
             name: "Graveler".to_string(),
             stage: 1,
@@ -3155,9 +3178,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1149Golem => Card::Pokemon(PokemonCard {
+        CardId::A1149Golem => CardData::Pokemon(PokemonCard {
             id: "A1 149".to_string(),
             name: "Golem".to_string(),
             stage: 2,
@@ -3176,9 +3199,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1150Onix => Card::Pokemon(PokemonCard {
+        CardId::A1150Onix => CardData::Pokemon(PokemonCard {
             id: "A1 150".to_string(),
             name: "Onix".to_string(),
             stage: 0,
@@ -3197,9 +3220,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1151Cubone => Card::Pokemon(PokemonCard {
+        CardId::A1151Cubone => CardData::Pokemon(PokemonCard {
             id: "A1 151".to_string(),
             name: "Cubone".to_string(),
             stage: 0,
@@ -3218,9 +3241,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1152Marowak => Card::Pokemon(PokemonCard {
+        CardId::A1152Marowak => CardData::Pokemon(PokemonCard {
             id: "A1 152".to_string(),
             name: "Marowak".to_string(),
             stage: 1,
@@ -3239,9 +3262,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1153MarowakEx => Card::Pokemon(PokemonCard {
+        CardId::A1153MarowakEx => CardData::Pokemon(PokemonCard {
             id: "A1 153".to_string(),
             name: "Marowak ex".to_string(),
             stage: 1,
@@ -3260,9 +3283,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1154Hitmonlee => Card::Pokemon(PokemonCard {
+        CardId::A1154Hitmonlee => CardData::Pokemon(PokemonCard {
             id: "A1 154".to_string(),
             name: "Hitmonlee".to_string(),
             stage: 0,
@@ -3281,9 +3304,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1155Hitmonchan => Card::Pokemon(PokemonCard {
+        CardId::A1155Hitmonchan => CardData::Pokemon(PokemonCard {
             id: "A1 155".to_string(),
             name: "Hitmonchan".to_string(),
             stage: 0,
@@ -3302,9 +3325,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1156Rhyhorn => Card::Pokemon(PokemonCard {
+        CardId::A1156Rhyhorn => CardData::Pokemon(PokemonCard {
             id: "A1 156".to_string(),
             name: "Rhyhorn".to_string(),
             stage: 0,
@@ -3323,9 +3346,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1157Rhydon => Card::Pokemon(PokemonCard {
+        CardId::A1157Rhydon => CardData::Pokemon(PokemonCard {
             id: "A1 157".to_string(),
             name: "Rhydon".to_string(),
             stage: 1,
@@ -3344,9 +3367,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1158Kabuto => Card::Pokemon(PokemonCard {
+        CardId::A1158Kabuto => CardData::Pokemon(PokemonCard {
             id: "A1 158".to_string(),
             name: "Kabuto".to_string(),
             stage: 1,
@@ -3365,9 +3388,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1159Kabutops => Card::Pokemon(PokemonCard {
+        CardId::A1159Kabutops => CardData::Pokemon(PokemonCard {
             id: "A1 159".to_string(),
             name: "Kabutops".to_string(),
             stage: 2,
@@ -3386,9 +3409,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1160Mienfoo => Card::Pokemon(PokemonCard {
+        CardId::A1160Mienfoo => CardData::Pokemon(PokemonCard {
             id: "A1 160".to_string(),
             name: "Mienfoo".to_string(),
             stage: 0,
@@ -3407,9 +3430,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1161Mienshao => Card::Pokemon(PokemonCard {
+        CardId::A1161Mienshao => CardData::Pokemon(PokemonCard {
             id: "A1 161".to_string(),
             name: "Mienshao".to_string(),
             stage: 1,
@@ -3428,9 +3451,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1162Clobbopus => Card::Pokemon(PokemonCard {
+        CardId::A1162Clobbopus => CardData::Pokemon(PokemonCard {
             id: "A1 162".to_string(),
             name: "Clobbopus".to_string(),
             stage: 0,
@@ -3449,9 +3472,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1163Grapploct => Card::Pokemon(PokemonCard {
+        CardId::A1163Grapploct => CardData::Pokemon(PokemonCard {
             id: "A1 163".to_string(),
             name: "Grapploct".to_string(),
             stage: 1,
@@ -3470,9 +3493,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1164Ekans => Card::Pokemon(PokemonCard {
+        CardId::A1164Ekans => CardData::Pokemon(PokemonCard {
             id: "A1 164".to_string(),
             name: "Ekans".to_string(),
             stage: 0,
@@ -3491,9 +3514,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1165Arbok => Card::Pokemon(PokemonCard {
+        CardId::A1165Arbok => CardData::Pokemon(PokemonCard {
             id: "A1 165".to_string(),
             name: "Arbok".to_string(),
             stage: 1,
@@ -3512,9 +3535,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1166NidoranF => Card::Pokemon(PokemonCard {
+        CardId::A1166NidoranF => CardData::Pokemon(PokemonCard {
             id: "A1 166".to_string(),
             name: "Nidoran♀".to_string(),
             stage: 0,
@@ -3533,9 +3556,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1167Nidorina => Card::Pokemon(PokemonCard {
+        CardId::A1167Nidorina => CardData::Pokemon(PokemonCard {
             id: "A1 167".to_string(),
             name: "Nidorina".to_string(),
             stage: 1,
@@ -3554,9 +3577,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1168Nidoqueen => Card::Pokemon(PokemonCard {
+        CardId::A1168Nidoqueen => CardData::Pokemon(PokemonCard {
             id: "A1 168".to_string(),
             name: "Nidoqueen".to_string(),
             stage: 2,
@@ -3575,9 +3598,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1169NidoranM => Card::Pokemon(PokemonCard {
+        CardId::A1169NidoranM => CardData::Pokemon(PokemonCard {
             id: "A1 169".to_string(),
             name: "Nidoran♂".to_string(),
             stage: 0,
@@ -3596,9 +3619,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1170Nidorino => Card::Pokemon(PokemonCard {
+        CardId::A1170Nidorino => CardData::Pokemon(PokemonCard {
             id: "A1 170".to_string(),
             name: "Nidorino".to_string(),
             stage: 1,
@@ -3617,9 +3640,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1171Nidoking => Card::Pokemon(PokemonCard {
+        CardId::A1171Nidoking => CardData::Pokemon(PokemonCard {
             id: "A1 171".to_string(),
             name: "Nidoking".to_string(),
             stage: 2,
@@ -3638,9 +3661,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1172Zubat => Card::Pokemon(PokemonCard {
+        CardId::A1172Zubat => CardData::Pokemon(PokemonCard {
             id: "A1 172".to_string(),
             name: "Zubat".to_string(),
             stage: 0,
@@ -3659,9 +3682,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1173Golbat => Card::Pokemon(PokemonCard {
+        CardId::A1173Golbat => CardData::Pokemon(PokemonCard {
             id: "A1 173".to_string(),
             name: "Golbat".to_string(),
             stage: 1,
@@ -3680,9 +3703,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1174Grimer => Card::Pokemon(PokemonCard {
+        CardId::A1174Grimer => CardData::Pokemon(PokemonCard {
             id: "A1 174".to_string(),
             name: "Grimer".to_string(),
             stage: 0,
@@ -3701,9 +3724,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1175Muk => Card::Pokemon(PokemonCard {
+        CardId::A1175Muk => CardData::Pokemon(PokemonCard {
             id: "A1 175".to_string(),
             name: "Muk".to_string(),
             stage: 1,
@@ -3722,9 +3745,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1176Koffing => Card::Pokemon(PokemonCard {
+        CardId::A1176Koffing => CardData::Pokemon(PokemonCard {
             id: "A1 176".to_string(),
             name: "Koffing".to_string(),
             stage: 0,
@@ -3743,9 +3766,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1177Weezing => Card::Pokemon(PokemonCard {
+        CardId::A1177Weezing => CardData::Pokemon(PokemonCard {
             id: "A1 177".to_string(),
             name: "Weezing".to_string(),
             stage: 1,
@@ -3764,9 +3787,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1178Mawile => Card::Pokemon(PokemonCard {
+        CardId::A1178Mawile => CardData::Pokemon(PokemonCard {
             id: "A1 178".to_string(),
             name: "Mawile".to_string(),
             stage: 0,
@@ -3785,9 +3808,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1179Pawniard => Card::Pokemon(PokemonCard {
+        CardId::A1179Pawniard => CardData::Pokemon(PokemonCard {
             id: "A1 179".to_string(),
             name: "Pawniard".to_string(),
             stage: 0,
@@ -3806,9 +3829,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1180Bisharp => Card::Pokemon(PokemonCard {
+        CardId::A1180Bisharp => CardData::Pokemon(PokemonCard {
             id: "A1 180".to_string(),
             name: "Bisharp".to_string(),
             stage: 1,
@@ -3827,9 +3850,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1181Meltan => Card::Pokemon(PokemonCard {
+        CardId::A1181Meltan => CardData::Pokemon(PokemonCard {
             id: "A1 181".to_string(),
             name: "Meltan".to_string(),
             stage: 0,
@@ -3848,9 +3871,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1182Melmetal => Card::Pokemon(PokemonCard {
+        CardId::A1182Melmetal => CardData::Pokemon(PokemonCard {
             id: "A1 182".to_string(),
             name: "Melmetal".to_string(),
             stage: 1,
@@ -3869,9 +3892,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1183Dratini => Card::Pokemon(PokemonCard {
+        CardId::A1183Dratini => CardData::Pokemon(PokemonCard {
             id: "A1 183".to_string(),
             name: "Dratini".to_string(),
             stage: 0,
@@ -3890,9 +3913,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1184Dragonair => Card::Pokemon(PokemonCard {
+        CardId::A1184Dragonair => CardData::Pokemon(PokemonCard {
             id: "A1 184".to_string(),
             name: "Dragonair".to_string(),
             stage: 1,
@@ -3911,9 +3934,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1185Dragonite => Card::Pokemon(PokemonCard {
+        CardId::A1185Dragonite => CardData::Pokemon(PokemonCard {
             id: "A1 185".to_string(),
             name: "Dragonite".to_string(),
             stage: 2,
@@ -3932,9 +3955,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1186Pidgey => Card::Pokemon(PokemonCard {
+        CardId::A1186Pidgey => CardData::Pokemon(PokemonCard {
             id: "A1 186".to_string(),
             name: "Pidgey".to_string(),
             stage: 0,
@@ -3953,9 +3976,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1187Pidgeotto => Card::Pokemon(PokemonCard {
+        CardId::A1187Pidgeotto => CardData::Pokemon(PokemonCard {
             id: "A1 187".to_string(),
             name: "Pidgeotto".to_string(),
             stage: 1,
@@ -3974,9 +3997,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1188Pidgeot => Card::Pokemon(PokemonCard {
+        CardId::A1188Pidgeot => CardData::Pokemon(PokemonCard {
             id: "A1 188".to_string(),
             name: "Pidgeot".to_string(),
             stage: 2,
@@ -3995,9 +4018,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1189Rattata => Card::Pokemon(PokemonCard {
+        CardId::A1189Rattata => CardData::Pokemon(PokemonCard {
             id: "A1 189".to_string(),
             name: "Rattata".to_string(),
             stage: 0,
@@ -4016,9 +4039,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1190Raticate => Card::Pokemon(PokemonCard {
+        CardId::A1190Raticate => CardData::Pokemon(PokemonCard {
             id: "A1 190".to_string(),
             name: "Raticate".to_string(),
             stage: 1,
@@ -4037,9 +4060,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1191Spearow => Card::Pokemon(PokemonCard {
+        CardId::A1191Spearow => CardData::Pokemon(PokemonCard {
             id: "A1 191".to_string(),
             name: "Spearow".to_string(),
             stage: 0,
@@ -4058,9 +4081,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1192Fearow => Card::Pokemon(PokemonCard {
+        CardId::A1192Fearow => CardData::Pokemon(PokemonCard {
             id: "A1 192".to_string(),
             name: "Fearow".to_string(),
             stage: 1,
@@ -4079,9 +4102,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1193Jigglypuff => Card::Pokemon(PokemonCard {
+        CardId::A1193Jigglypuff => CardData::Pokemon(PokemonCard {
             id: "A1 193".to_string(),
             name: "Jigglypuff".to_string(),
             stage: 0,
@@ -4100,9 +4123,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1194Wigglytuff => Card::Pokemon(PokemonCard {
+        CardId::A1194Wigglytuff => CardData::Pokemon(PokemonCard {
             id: "A1 194".to_string(),
             name: "Wigglytuff".to_string(),
             stage: 1,
@@ -4121,9 +4144,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1195WigglytuffEx => Card::Pokemon(PokemonCard {
+        CardId::A1195WigglytuffEx => CardData::Pokemon(PokemonCard {
             id: "A1 195".to_string(),
             name: "Wigglytuff ex".to_string(),
             stage: 1,
@@ -4142,9 +4165,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1196Meowth => Card::Pokemon(PokemonCard {
+        CardId::A1196Meowth => CardData::Pokemon(PokemonCard {
             id: "A1 196".to_string(),
             name: "Meowth".to_string(),
             stage: 0,
@@ -4163,9 +4186,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1197Persian => Card::Pokemon(PokemonCard {
+        CardId::A1197Persian => CardData::Pokemon(PokemonCard {
             id: "A1 197".to_string(),
             name: "Persian".to_string(),
             stage: 1,
@@ -4184,9 +4207,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1198Farfetchd => Card::Pokemon(PokemonCard {
+        CardId::A1198Farfetchd => CardData::Pokemon(PokemonCard {
             id: "A1 198".to_string(),
             name: "Farfetch'd".to_string(),
             stage: 0,
@@ -4205,9 +4228,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1199Doduo => Card::Pokemon(PokemonCard {
+        CardId::A1199Doduo => CardData::Pokemon(PokemonCard {
             id: "A1 199".to_string(),
             name: "Doduo".to_string(),
             stage: 0,
@@ -4226,9 +4249,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1200Dodrio => Card::Pokemon(PokemonCard {
+        CardId::A1200Dodrio => CardData::Pokemon(PokemonCard {
             id: "A1 200".to_string(),
             name: "Dodrio".to_string(),
             stage: 1,
@@ -4247,9 +4270,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1201Lickitung => Card::Pokemon(PokemonCard {
+        CardId::A1201Lickitung => CardData::Pokemon(PokemonCard {
             id: "A1 201".to_string(),
             name: "Lickitung".to_string(),
             stage: 0,
@@ -4268,9 +4291,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1202Chansey => Card::Pokemon(PokemonCard {
+        CardId::A1202Chansey => CardData::Pokemon(PokemonCard {
             id: "A1 202".to_string(),
             name: "Chansey".to_string(),
             stage: 0,
@@ -4289,9 +4312,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1203Kangaskhan => Card::Pokemon(PokemonCard {
+        CardId::A1203Kangaskhan => CardData::Pokemon(PokemonCard {
             id: "A1 203".to_string(),
             name: "Kangaskhan".to_string(),
             stage: 0,
@@ -4310,9 +4333,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1204Tauros => Card::Pokemon(PokemonCard {
+        CardId::A1204Tauros => CardData::Pokemon(PokemonCard {
             id: "A1 204".to_string(),
             name: "Tauros".to_string(),
             stage: 0,
@@ -4331,9 +4354,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1205Ditto => Card::Pokemon(PokemonCard {
+        CardId::A1205Ditto => CardData::Pokemon(PokemonCard {
             id: "A1 205".to_string(),
             name: "Ditto".to_string(),
             stage: 0,
@@ -4352,9 +4375,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1206Eevee => Card::Pokemon(PokemonCard {
+        CardId::A1206Eevee => CardData::Pokemon(PokemonCard {
             id: "A1 206".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -4373,9 +4396,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1207Eevee => Card::Pokemon(PokemonCard {
+        CardId::A1207Eevee => CardData::Pokemon(PokemonCard {
             id: "A1 207".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -4394,9 +4417,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1208Eevee => Card::Pokemon(PokemonCard {
+        CardId::A1208Eevee => CardData::Pokemon(PokemonCard {
             id: "A1 208".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -4415,9 +4438,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1209Porygon => Card::Pokemon(PokemonCard {
+        CardId::A1209Porygon => CardData::Pokemon(PokemonCard {
             id: "A1 209".to_string(),
             name: "Porygon".to_string(),
             stage: 0,
@@ -4436,9 +4459,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1210Aerodactyl => Card::Pokemon(PokemonCard {
+        CardId::A1210Aerodactyl => CardData::Pokemon(PokemonCard {
             id: "A1 210".to_string(),
             name: "Aerodactyl".to_string(),
             stage: 1,
@@ -4457,9 +4480,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1211Snorlax => Card::Pokemon(PokemonCard {
+        CardId::A1211Snorlax => CardData::Pokemon(PokemonCard {
             id: "A1 211".to_string(),
             name: "Snorlax".to_string(),
             stage: 0,
@@ -4478,9 +4501,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1212Minccino => Card::Pokemon(PokemonCard {
+        CardId::A1212Minccino => CardData::Pokemon(PokemonCard {
             id: "A1 212".to_string(),
             name: "Minccino".to_string(),
             stage: 0,
@@ -4499,9 +4522,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1213Cinccino => Card::Pokemon(PokemonCard {
+        CardId::A1213Cinccino => CardData::Pokemon(PokemonCard {
             id: "A1 213".to_string(),
             name: "Cinccino".to_string(),
             stage: 1,
@@ -4520,9 +4543,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1214Wooloo => Card::Pokemon(PokemonCard {
+        CardId::A1214Wooloo => CardData::Pokemon(PokemonCard {
             id: "A1 214".to_string(),
             name: "Wooloo".to_string(),
             stage: 0,
@@ -4541,9 +4564,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1215Dubwool => Card::Pokemon(PokemonCard {
+        CardId::A1215Dubwool => CardData::Pokemon(PokemonCard {
             id: "A1 215".to_string(),
             name: "Dubwool".to_string(),
             stage: 1,
@@ -4562,108 +4585,108 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1216HelixFossil => Card::Trainer(TrainerCard {
+        CardId::A1216HelixFossil => CardData::Trainer(TrainerCard {
             id: "A1 216".to_string(),
             numeric_id: 216,
             name: "Helix Fossil".to_string(),
             effect: "Play this card as if it were a 40-HP Basic [C] Pokémon.At any time during your turn, you may discard this card from play.This card can't retreat.".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A1217DomeFossil => Card::Trainer(TrainerCard {
+        CardId::A1217DomeFossil => CardData::Trainer(TrainerCard {
             id: "A1 217".to_string(),
             numeric_id: 217,
             name: "Dome Fossil".to_string(),
             effect: "Play this card as if it were a 40-HP Basic [C] Pokémon.At any time during your turn, you may discard this card from play.This card can't retreat.".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A1218OldAmber => Card::Trainer(TrainerCard {
+        CardId::A1218OldAmber => CardData::Trainer(TrainerCard {
             id: "A1 218".to_string(),
             numeric_id: 218,
             name: "Old Amber".to_string(),
             effect: "Play this card as if it were a 40-HP Basic [C] Pokémon.At any time during your turn, you may discard this card from play.This card can't retreat.".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A1219Erika => Card::Trainer(TrainerCard {
+        CardId::A1219Erika => CardData::Trainer(TrainerCard {
             id: "A1 219".to_string(),
             numeric_id: 219,
             name: "Erika".to_string(),
             effect: "Heal 50 damage from 1 of your [G] Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1220Misty => Card::Trainer(TrainerCard {
+        CardId::A1220Misty => CardData::Trainer(TrainerCard {
             id: "A1 220".to_string(),
             numeric_id: 220,
             name: "Misty".to_string(),
             effect: "Choose 1 of your [W] Pokémon, and flip a coin until you get tails. For each heads, take a [W] Energy from your Energy Zone and attach it to that Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1221Blaine => Card::Trainer(TrainerCard {
+        CardId::A1221Blaine => CardData::Trainer(TrainerCard {
             id: "A1 221".to_string(),
             numeric_id: 221,
             name: "Blaine".to_string(),
             effect: "During this turn, attacks used by your Ninetales, Rapidash, or Magmar do +30 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1222Koga => Card::Trainer(TrainerCard {
+        CardId::A1222Koga => CardData::Trainer(TrainerCard {
             id: "A1 222".to_string(),
             numeric_id: 222,
             name: "Koga".to_string(),
             effect: "Put your Muk or Weezing in the Active Spot into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1223Giovanni => Card::Trainer(TrainerCard {
+        CardId::A1223Giovanni => CardData::Trainer(TrainerCard {
             id: "A1 223".to_string(),
             numeric_id: 223,
             name: "Giovanni".to_string(),
             effect: "During this turn, attacks used by your Pokémon do +10 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1224Brock => Card::Trainer(TrainerCard {
+        CardId::A1224Brock => CardData::Trainer(TrainerCard {
             id: "A1 224".to_string(),
             numeric_id: 224,
             name: "Brock".to_string(),
             effect: "Take a [F] Energy from your Energy Zone and attach it to Golem or Onix.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1225Sabrina => Card::Trainer(TrainerCard {
+        CardId::A1225Sabrina => CardData::Trainer(TrainerCard {
             id: "A1 225".to_string(),
             numeric_id: 225,
             name: "Sabrina".to_string(),
             effect: "Switch out your opponent's Active Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1226LtSurge => Card::Trainer(TrainerCard {
+        CardId::A1226LtSurge => CardData::Trainer(TrainerCard {
             id: "A1 226".to_string(),
             numeric_id: 226,
             name: "Lt. Surge".to_string(),
             effect: "Move all [L] Energy from your Benched Pokémon to your Raichu, Electrode, or Electabuzz in the Active Spot.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1227Bulbasaur => Card::Pokemon(PokemonCard {
+        CardId::A1227Bulbasaur => CardData::Pokemon(PokemonCard {
             id: "A1 227".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -4682,9 +4705,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1228Gloom => Card::Pokemon(PokemonCard {
+        CardId::A1228Gloom => CardData::Pokemon(PokemonCard {
             id: "A1 228".to_string(),
             name: "Gloom".to_string(),
             stage: 1,
@@ -4703,9 +4726,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1229Pinsir => Card::Pokemon(PokemonCard {
+        CardId::A1229Pinsir => CardData::Pokemon(PokemonCard {
             id: "A1 229".to_string(),
             name: "Pinsir".to_string(),
             stage: 0,
@@ -4724,9 +4747,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1230Charmander => Card::Pokemon(PokemonCard {
+        CardId::A1230Charmander => CardData::Pokemon(PokemonCard {
             id: "A1 230".to_string(),
             name: "Charmander".to_string(),
             stage: 0,
@@ -4745,9 +4768,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1231Rapidash => Card::Pokemon(PokemonCard {
+        CardId::A1231Rapidash => CardData::Pokemon(PokemonCard {
             id: "A1 231".to_string(),
             name: "Rapidash".to_string(),
             stage: 1,
@@ -4766,9 +4789,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1232Squirtle => Card::Pokemon(PokemonCard {
+        CardId::A1232Squirtle => CardData::Pokemon(PokemonCard {
             id: "A1 232".to_string(),
             name: "Squirtle".to_string(),
             stage: 0,
@@ -4787,9 +4810,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1233Gyarados => Card::Pokemon(PokemonCard {
+        CardId::A1233Gyarados => CardData::Pokemon(PokemonCard {
             id: "A1 233".to_string(),
             name: "Gyarados".to_string(),
             stage: 1,
@@ -4808,9 +4831,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1234Lapras => Card::Pokemon(PokemonCard {
+        CardId::A1234Lapras => CardData::Pokemon(PokemonCard {
             id: "A1 234".to_string(),
             name: "Lapras".to_string(),
             stage: 0,
@@ -4829,9 +4852,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1235Electrode => Card::Pokemon(PokemonCard {
+        CardId::A1235Electrode => CardData::Pokemon(PokemonCard {
             id: "A1 235".to_string(),
             name: "Electrode".to_string(),
             stage: 1,
@@ -4850,9 +4873,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1236Alakazam => Card::Pokemon(PokemonCard {
+        CardId::A1236Alakazam => CardData::Pokemon(PokemonCard {
             id: "A1 236".to_string(),
             name: "Alakazam".to_string(),
             stage: 2,
@@ -4871,9 +4894,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1237Slowpoke => Card::Pokemon(PokemonCard {
+        CardId::A1237Slowpoke => CardData::Pokemon(PokemonCard {
             id: "A1 237".to_string(),
             name: "Slowpoke".to_string(),
             stage: 0,
@@ -4892,9 +4915,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1238Diglett => Card::Pokemon(PokemonCard {
+        CardId::A1238Diglett => CardData::Pokemon(PokemonCard {
             id: "A1 238".to_string(),
             name: "Diglett".to_string(),
             stage: 0,
@@ -4913,9 +4936,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1239Cubone => Card::Pokemon(PokemonCard {
+        CardId::A1239Cubone => CardData::Pokemon(PokemonCard {
             id: "A1 239".to_string(),
             name: "Cubone".to_string(),
             stage: 0,
@@ -4934,9 +4957,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1240Nidoqueen => Card::Pokemon(PokemonCard {
+        CardId::A1240Nidoqueen => CardData::Pokemon(PokemonCard {
             id: "A1 240".to_string(),
             name: "Nidoqueen".to_string(),
             stage: 2,
@@ -4955,9 +4978,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1241Nidoking => Card::Pokemon(PokemonCard {
+        CardId::A1241Nidoking => CardData::Pokemon(PokemonCard {
             id: "A1 241".to_string(),
             name: "Nidoking".to_string(),
             stage: 2,
@@ -4976,9 +4999,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1242Golbat => Card::Pokemon(PokemonCard {
+        CardId::A1242Golbat => CardData::Pokemon(PokemonCard {
             id: "A1 242".to_string(),
             name: "Golbat".to_string(),
             stage: 1,
@@ -4997,9 +5020,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1243Weezing => Card::Pokemon(PokemonCard {
+        CardId::A1243Weezing => CardData::Pokemon(PokemonCard {
             id: "A1 243".to_string(),
             name: "Weezing".to_string(),
             stage: 1,
@@ -5018,9 +5041,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1244Dragonite => Card::Pokemon(PokemonCard {
+        CardId::A1244Dragonite => CardData::Pokemon(PokemonCard {
             id: "A1 244".to_string(),
             name: "Dragonite".to_string(),
             stage: 2,
@@ -5039,9 +5062,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1245Pidgeot => Card::Pokemon(PokemonCard {
+        CardId::A1245Pidgeot => CardData::Pokemon(PokemonCard {
             id: "A1 245".to_string(),
             name: "Pidgeot".to_string(),
             stage: 2,
@@ -5060,9 +5083,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1246Meowth => Card::Pokemon(PokemonCard {
+        CardId::A1246Meowth => CardData::Pokemon(PokemonCard {
             id: "A1 246".to_string(),
             name: "Meowth".to_string(),
             stage: 0,
@@ -5081,9 +5104,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1247Ditto => Card::Pokemon(PokemonCard {
+        CardId::A1247Ditto => CardData::Pokemon(PokemonCard {
             id: "A1 247".to_string(),
             name: "Ditto".to_string(),
             stage: 0,
@@ -5102,9 +5125,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1248Eevee => Card::Pokemon(PokemonCard {
+        CardId::A1248Eevee => CardData::Pokemon(PokemonCard {
             id: "A1 248".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -5123,9 +5146,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1249Porygon => Card::Pokemon(PokemonCard {
+        CardId::A1249Porygon => CardData::Pokemon(PokemonCard {
             id: "A1 249".to_string(),
             name: "Porygon".to_string(),
             stage: 0,
@@ -5144,9 +5167,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1250Snorlax => Card::Pokemon(PokemonCard {
+        CardId::A1250Snorlax => CardData::Pokemon(PokemonCard {
             id: "A1 250".to_string(),
             name: "Snorlax".to_string(),
             stage: 0,
@@ -5165,9 +5188,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1251VenusaurEx => Card::Pokemon(PokemonCard {
+        CardId::A1251VenusaurEx => CardData::Pokemon(PokemonCard {
             id: "A1 251".to_string(),
             name: "Venusaur ex".to_string(),
             stage: 2,
@@ -5192,9 +5215,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1252ExeggutorEx => Card::Pokemon(PokemonCard {
+        CardId::A1252ExeggutorEx => CardData::Pokemon(PokemonCard {
             id: "A1 252".to_string(),
             name: "Exeggutor ex".to_string(),
             stage: 1,
@@ -5213,9 +5236,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1253CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A1253CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A1 253".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -5240,9 +5263,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1254ArcanineEx => Card::Pokemon(PokemonCard {
+        CardId::A1254ArcanineEx => CardData::Pokemon(PokemonCard {
             id: "A1 254".to_string(),
             name: "Arcanine ex".to_string(),
             stage: 1,
@@ -5261,9 +5284,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1255MoltresEx => Card::Pokemon(PokemonCard {
+        CardId::A1255MoltresEx => CardData::Pokemon(PokemonCard {
             id: "A1 255".to_string(),
             name: "Moltres ex".to_string(),
             stage: 0,
@@ -5288,9 +5311,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1256BlastoiseEx => Card::Pokemon(PokemonCard {
+        CardId::A1256BlastoiseEx => CardData::Pokemon(PokemonCard {
             id: "A1 256".to_string(),
             name: "Blastoise ex".to_string(),
             stage: 2,
@@ -5315,9 +5338,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1257StarmieEx => Card::Pokemon(PokemonCard {
+        CardId::A1257StarmieEx => CardData::Pokemon(PokemonCard {
             id: "A1 257".to_string(),
             name: "Starmie ex".to_string(),
             stage: 1,
@@ -5336,9 +5359,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1258ArticunoEx => Card::Pokemon(PokemonCard {
+        CardId::A1258ArticunoEx => CardData::Pokemon(PokemonCard {
             id: "A1 258".to_string(),
             name: "Articuno ex".to_string(),
             stage: 0,
@@ -5363,9 +5386,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1259PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A1259PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A1 259".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -5384,9 +5407,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1260ZapdosEx => Card::Pokemon(PokemonCard {
+        CardId::A1260ZapdosEx => CardData::Pokemon(PokemonCard {
             id: "A1 260".to_string(),
             name: "Zapdos ex".to_string(),
             stage: 0,
@@ -5411,9 +5434,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1261GengarEx => Card::Pokemon(PokemonCard {
+        CardId::A1261GengarEx => CardData::Pokemon(PokemonCard {
             id: "A1 261".to_string(),
             name: "Gengar ex".to_string(),
             stage: 2,
@@ -5432,9 +5455,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1262MewtwoEx => Card::Pokemon(PokemonCard {
+        CardId::A1262MewtwoEx => CardData::Pokemon(PokemonCard {
             id: "A1 262".to_string(),
             name: "Mewtwo ex".to_string(),
             stage: 0,
@@ -5459,9 +5482,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1263MachampEx => Card::Pokemon(PokemonCard {
+        CardId::A1263MachampEx => CardData::Pokemon(PokemonCard {
             id: "A1 263".to_string(),
             name: "Machamp ex".to_string(),
             stage: 2,
@@ -5480,9 +5503,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1264MarowakEx => Card::Pokemon(PokemonCard {
+        CardId::A1264MarowakEx => CardData::Pokemon(PokemonCard {
             id: "A1 264".to_string(),
             name: "Marowak ex".to_string(),
             stage: 1,
@@ -5501,9 +5524,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1265WigglytuffEx => Card::Pokemon(PokemonCard {
+        CardId::A1265WigglytuffEx => CardData::Pokemon(PokemonCard {
             id: "A1 265".to_string(),
             name: "Wigglytuff ex".to_string(),
             stage: 1,
@@ -5522,81 +5545,81 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1266Erika => Card::Trainer(TrainerCard {
+        CardId::A1266Erika => CardData::Trainer(TrainerCard {
             id: "A1 266".to_string(),
             numeric_id: 266,
             name: "Erika".to_string(),
             effect: "Heal 50 damage from 1 of your [G] Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1267Misty => Card::Trainer(TrainerCard {
+        CardId::A1267Misty => CardData::Trainer(TrainerCard {
             id: "A1 267".to_string(),
             numeric_id: 267,
             name: "Misty".to_string(),
             effect: "Choose 1 of your [W] Pokémon, and flip a coin until you get tails. For each heads, take a [W] Energy from your Energy Zone and attach it to that Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1268Blaine => Card::Trainer(TrainerCard {
+        CardId::A1268Blaine => CardData::Trainer(TrainerCard {
             id: "A1 268".to_string(),
             numeric_id: 268,
             name: "Blaine".to_string(),
             effect: "During this turn, attacks used by your Ninetales, Rapidash, or Magmar do +30 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1269Koga => Card::Trainer(TrainerCard {
+        CardId::A1269Koga => CardData::Trainer(TrainerCard {
             id: "A1 269".to_string(),
             numeric_id: 269,
             name: "Koga".to_string(),
             effect: "Put your Muk or Weezing in the Active Spot into your hand.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1270Giovanni => Card::Trainer(TrainerCard {
+        CardId::A1270Giovanni => CardData::Trainer(TrainerCard {
             id: "A1 270".to_string(),
             numeric_id: 270,
             name: "Giovanni".to_string(),
             effect: "During this turn, attacks used by your Pokémon do +10 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1271Brock => Card::Trainer(TrainerCard {
+        CardId::A1271Brock => CardData::Trainer(TrainerCard {
             id: "A1 271".to_string(),
             numeric_id: 271,
             name: "Brock".to_string(),
             effect: "Take a [F] Energy from your Energy Zone and attach it to Golem or Onix.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1272Sabrina => Card::Trainer(TrainerCard {
+        CardId::A1272Sabrina => CardData::Trainer(TrainerCard {
             id: "A1 272".to_string(),
             numeric_id: 272,
             name: "Sabrina".to_string(),
             effect: "Switch out your opponent's Active Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1273LtSurge => Card::Trainer(TrainerCard {
+        CardId::A1273LtSurge => CardData::Trainer(TrainerCard {
             id: "A1 273".to_string(),
             numeric_id: 273,
             name: "Lt. Surge".to_string(),
             effect: "Move all [L] Energy from your Benched Pokémon to your Raichu, Electrode, or Electabuzz in the Active Spot.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1274MoltresEx => Card::Pokemon(PokemonCard {
+        CardId::A1274MoltresEx => CardData::Pokemon(PokemonCard {
             id: "A1 274".to_string(),
             name: "Moltres ex".to_string(),
             stage: 0,
@@ -5621,9 +5644,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1275ArticunoEx => Card::Pokemon(PokemonCard {
+        CardId::A1275ArticunoEx => CardData::Pokemon(PokemonCard {
             id: "A1 275".to_string(),
             name: "Articuno ex".to_string(),
             stage: 0,
@@ -5648,9 +5671,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1276ZapdosEx => Card::Pokemon(PokemonCard {
+        CardId::A1276ZapdosEx => CardData::Pokemon(PokemonCard {
             id: "A1 276".to_string(),
             name: "Zapdos ex".to_string(),
             stage: 0,
@@ -5675,9 +5698,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1277GengarEx => Card::Pokemon(PokemonCard {
+        CardId::A1277GengarEx => CardData::Pokemon(PokemonCard {
             id: "A1 277".to_string(),
             name: "Gengar ex".to_string(),
             stage: 2,
@@ -5696,9 +5719,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1278MachampEx => Card::Pokemon(PokemonCard {
+        CardId::A1278MachampEx => CardData::Pokemon(PokemonCard {
             id: "A1 278".to_string(),
             name: "Machamp ex".to_string(),
             stage: 2,
@@ -5717,9 +5740,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1279WigglytuffEx => Card::Pokemon(PokemonCard {
+        CardId::A1279WigglytuffEx => CardData::Pokemon(PokemonCard {
             id: "A1 279".to_string(),
             name: "Wigglytuff ex".to_string(),
             stage: 1,
@@ -5738,9 +5761,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1280CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A1280CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A1 280".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -5765,9 +5788,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1281PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A1281PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A1 281".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -5786,9 +5809,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1282MewtwoEx => Card::Pokemon(PokemonCard {
+        CardId::A1282MewtwoEx => CardData::Pokemon(PokemonCard {
             id: "A1 282".to_string(),
             name: "Mewtwo ex".to_string(),
             stage: 0,
@@ -5813,9 +5836,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1283Mew => Card::Pokemon(PokemonCard {
+        CardId::A1283Mew => CardData::Pokemon(PokemonCard {
             id: "A1 283".to_string(),
             name: "Mew".to_string(),
             stage: 0,
@@ -5834,9 +5857,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1284CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A1284CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A1 284".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -5861,9 +5884,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1285PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A1285PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A1 285".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -5882,9 +5905,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1286MewtwoEx => Card::Pokemon(PokemonCard {
+        CardId::A1286MewtwoEx => CardData::Pokemon(PokemonCard {
             id: "A1 286".to_string(),
             name: "Mewtwo ex".to_string(),
             stage: 0,
@@ -5909,9 +5932,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         }),
-        CardId::A1a001Exeggcute => Card::Pokemon(PokemonCard {
+        CardId::A1a001Exeggcute => CardData::Pokemon(PokemonCard {
             id: "A1a 001".to_string(),
             name: "Exeggcute".to_string(),
             stage: 0,
@@ -5930,9 +5953,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a002Exeggutor => Card::Pokemon(PokemonCard {
+        CardId::A1a002Exeggutor => CardData::Pokemon(PokemonCard {
             id: "A1a 002".to_string(),
             name: "Exeggutor".to_string(),
             stage: 1,
@@ -5951,9 +5974,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a003CelebiEx => Card::Pokemon(PokemonCard {
+        CardId::A1a003CelebiEx => CardData::Pokemon(PokemonCard {
             id: "A1a 003".to_string(),
             name: "Celebi ex".to_string(),
             stage: 0,
@@ -5972,9 +5995,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a004Snivy => Card::Pokemon(PokemonCard {
+        CardId::A1a004Snivy => CardData::Pokemon(PokemonCard {
             id: "A1a 004".to_string(),
             name: "Snivy".to_string(),
             stage: 0,
@@ -5993,9 +6016,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a005Servine => Card::Pokemon(PokemonCard {
+        CardId::A1a005Servine => CardData::Pokemon(PokemonCard {
             id: "A1a 005".to_string(),
             name: "Servine".to_string(),
             stage: 1,
@@ -6014,9 +6037,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a006Serperior => Card::Pokemon(PokemonCard {
+        CardId::A1a006Serperior => CardData::Pokemon(PokemonCard {
             id: "A1a 006".to_string(),
             name: "Serperior".to_string(),
             stage: 2,
@@ -6035,9 +6058,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a007Morelull => Card::Pokemon(PokemonCard {
+        CardId::A1a007Morelull => CardData::Pokemon(PokemonCard {
             id: "A1a 007".to_string(),
             name: "Morelull".to_string(),
             stage: 0,
@@ -6056,9 +6079,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a008Shiinotic => Card::Pokemon(PokemonCard {
+        CardId::A1a008Shiinotic => CardData::Pokemon(PokemonCard {
             id: "A1a 008".to_string(),
             name: "Shiinotic".to_string(),
             stage: 1,
@@ -6077,9 +6100,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a009Dhelmise => Card::Pokemon(PokemonCard {
+        CardId::A1a009Dhelmise => CardData::Pokemon(PokemonCard {
             id: "A1a 009".to_string(),
             name: "Dhelmise".to_string(),
             stage: 0,
@@ -6098,9 +6121,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a010Ponyta => Card::Pokemon(PokemonCard {
+        CardId::A1a010Ponyta => CardData::Pokemon(PokemonCard {
             id: "A1a 010".to_string(),
             name: "Ponyta".to_string(),
             stage: 0,
@@ -6119,9 +6142,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a011Rapidash => Card::Pokemon(PokemonCard {
+        CardId::A1a011Rapidash => CardData::Pokemon(PokemonCard {
             id: "A1a 011".to_string(),
             name: "Rapidash".to_string(),
             stage: 1,
@@ -6140,9 +6163,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a012Magmar => Card::Pokemon(PokemonCard {
+        CardId::A1a012Magmar => CardData::Pokemon(PokemonCard {
             id: "A1a 012".to_string(),
             name: "Magmar".to_string(),
             stage: 0,
@@ -6161,9 +6184,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a013Larvesta => Card::Pokemon(PokemonCard {
+        CardId::A1a013Larvesta => CardData::Pokemon(PokemonCard {
             id: "A1a 013".to_string(),
             name: "Larvesta".to_string(),
             stage: 0,
@@ -6182,9 +6205,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a014Volcarona => Card::Pokemon(PokemonCard {
+        CardId::A1a014Volcarona => CardData::Pokemon(PokemonCard {
             id: "A1a 014".to_string(),
             name: "Volcarona".to_string(),
             stage: 1,
@@ -6203,9 +6226,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a015Salandit => Card::Pokemon(PokemonCard {
+        CardId::A1a015Salandit => CardData::Pokemon(PokemonCard {
             id: "A1a 015".to_string(),
             name: "Salandit".to_string(),
             stage: 0,
@@ -6224,9 +6247,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a016Salazzle => Card::Pokemon(PokemonCard {
+        CardId::A1a016Salazzle => CardData::Pokemon(PokemonCard {
             id: "A1a 016".to_string(),
             name: "Salazzle".to_string(),
             stage: 1,
@@ -6245,9 +6268,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a017Magikarp => Card::Pokemon(PokemonCard {
+        CardId::A1a017Magikarp => CardData::Pokemon(PokemonCard {
             id: "A1a 017".to_string(),
             name: "Magikarp".to_string(),
             stage: 0,
@@ -6266,9 +6289,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a018GyaradosEx => Card::Pokemon(PokemonCard {
+        CardId::A1a018GyaradosEx => CardData::Pokemon(PokemonCard {
             id: "A1a 018".to_string(),
             name: "Gyarados ex".to_string(),
             stage: 1,
@@ -6287,9 +6310,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a019Vaporeon => Card::Pokemon(PokemonCard {
+        CardId::A1a019Vaporeon => CardData::Pokemon(PokemonCard {
             id: "A1a 019".to_string(),
             name: "Vaporeon".to_string(),
             stage: 1,
@@ -6308,9 +6331,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a020Finneon => Card::Pokemon(PokemonCard {
+        CardId::A1a020Finneon => CardData::Pokemon(PokemonCard {
             id: "A1a 020".to_string(),
             name: "Finneon".to_string(),
             stage: 0,
@@ -6329,9 +6352,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a021Lumineon => Card::Pokemon(PokemonCard {
+        CardId::A1a021Lumineon => CardData::Pokemon(PokemonCard {
             id: "A1a 021".to_string(),
             name: "Lumineon".to_string(),
             stage: 1,
@@ -6350,9 +6373,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a022Chewtle => Card::Pokemon(PokemonCard {
+        CardId::A1a022Chewtle => CardData::Pokemon(PokemonCard {
             id: "A1a 022".to_string(),
             name: "Chewtle".to_string(),
             stage: 0,
@@ -6371,9 +6394,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a023Drednaw => Card::Pokemon(PokemonCard {
+        CardId::A1a023Drednaw => CardData::Pokemon(PokemonCard {
             id: "A1a 023".to_string(),
             name: "Drednaw".to_string(),
             stage: 1,
@@ -6392,9 +6415,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a024Cramorant => Card::Pokemon(PokemonCard {
+        CardId::A1a024Cramorant => CardData::Pokemon(PokemonCard {
             id: "A1a 024".to_string(),
             name: "Cramorant".to_string(),
             stage: 0,
@@ -6413,9 +6436,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a025Pikachu => Card::Pokemon(PokemonCard {
+        CardId::A1a025Pikachu => CardData::Pokemon(PokemonCard {
             id: "A1a 025".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -6434,9 +6457,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a026Raichu => Card::Pokemon(PokemonCard {
+        CardId::A1a026Raichu => CardData::Pokemon(PokemonCard {
             id: "A1a 026".to_string(),
             name: "Raichu".to_string(),
             stage: 1,
@@ -6455,9 +6478,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a027Electabuzz => Card::Pokemon(PokemonCard {
+        CardId::A1a027Electabuzz => CardData::Pokemon(PokemonCard {
             id: "A1a 027".to_string(),
             name: "Electabuzz".to_string(),
             stage: 0,
@@ -6476,9 +6499,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a028Joltik => Card::Pokemon(PokemonCard {
+        CardId::A1a028Joltik => CardData::Pokemon(PokemonCard {
             id: "A1a 028".to_string(),
             name: "Joltik".to_string(),
             stage: 0,
@@ -6497,9 +6520,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a029Galvantula => Card::Pokemon(PokemonCard {
+        CardId::A1a029Galvantula => CardData::Pokemon(PokemonCard {
             id: "A1a 029".to_string(),
             name: "Galvantula".to_string(),
             stage: 1,
@@ -6518,9 +6541,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a030Dedenne => Card::Pokemon(PokemonCard {
+        CardId::A1a030Dedenne => CardData::Pokemon(PokemonCard {
             id: "A1a 030".to_string(),
             name: "Dedenne".to_string(),
             stage: 0,
@@ -6539,9 +6562,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a031Mew => Card::Pokemon(PokemonCard {
+        CardId::A1a031Mew => CardData::Pokemon(PokemonCard {
             id: "A1a 031".to_string(),
             name: "Mew".to_string(),
             stage: 0,
@@ -6560,9 +6583,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a032MewEx => Card::Pokemon(PokemonCard {
+        CardId::A1a032MewEx => CardData::Pokemon(PokemonCard {
             id: "A1a 032".to_string(),
             name: "Mew ex".to_string(),
             stage: 0,
@@ -6587,9 +6610,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a033Sigilyph => Card::Pokemon(PokemonCard {
+        CardId::A1a033Sigilyph => CardData::Pokemon(PokemonCard {
             id: "A1a 033".to_string(),
             name: "Sigilyph".to_string(),
             stage: 0,
@@ -6608,9 +6631,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a034Elgyem => Card::Pokemon(PokemonCard {
+        CardId::A1a034Elgyem => CardData::Pokemon(PokemonCard {
             id: "A1a 034".to_string(),
             name: "Elgyem".to_string(),
             stage: 0,
@@ -6629,9 +6652,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a035Beheeyem => Card::Pokemon(PokemonCard {
+        CardId::A1a035Beheeyem => CardData::Pokemon(PokemonCard {
             id: "A1a 035".to_string(),
             name: "Beheeyem".to_string(),
             stage: 1,
@@ -6650,9 +6673,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a036Flabebe => Card::Pokemon(PokemonCard {
+        CardId::A1a036Flabebe => CardData::Pokemon(PokemonCard {
             id: "A1a 036".to_string(),
             name: "Flabébé".to_string(),
             stage: 0,
@@ -6671,9 +6694,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a037Floette => Card::Pokemon(PokemonCard {
+        CardId::A1a037Floette => CardData::Pokemon(PokemonCard {
             id: "A1a 037".to_string(),
             name: "Floette".to_string(),
             stage: 1,
@@ -6692,9 +6715,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a038Florges => Card::Pokemon(PokemonCard {
+        CardId::A1a038Florges => CardData::Pokemon(PokemonCard {
             id: "A1a 038".to_string(),
             name: "Florges".to_string(),
             stage: 2,
@@ -6713,9 +6736,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a039Swirlix => Card::Pokemon(PokemonCard {
+        CardId::A1a039Swirlix => CardData::Pokemon(PokemonCard {
             id: "A1a 039".to_string(),
             name: "Swirlix".to_string(),
             stage: 0,
@@ -6734,9 +6757,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a040Slurpuff => Card::Pokemon(PokemonCard {
+        CardId::A1a040Slurpuff => CardData::Pokemon(PokemonCard {
             id: "A1a 040".to_string(),
             name: "Slurpuff".to_string(),
             stage: 1,
@@ -6755,9 +6778,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a041Mankey => Card::Pokemon(PokemonCard {
+        CardId::A1a041Mankey => CardData::Pokemon(PokemonCard {
             id: "A1a 041".to_string(),
             name: "Mankey".to_string(),
             stage: 0,
@@ -6776,9 +6799,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a042Primeape => Card::Pokemon(PokemonCard {
+        CardId::A1a042Primeape => CardData::Pokemon(PokemonCard {
             id: "A1a 042".to_string(),
             name: "Primeape".to_string(),
             stage: 1,
@@ -6797,9 +6820,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a043Geodude => Card::Pokemon(PokemonCard {
+        CardId::A1a043Geodude => CardData::Pokemon(PokemonCard {
             id: "A1a 043".to_string(),
             name: "Geodude".to_string(),
             stage: 0,
@@ -6818,9 +6841,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a044Graveler => Card::Pokemon(PokemonCard {
+        CardId::A1a044Graveler => CardData::Pokemon(PokemonCard {
             id: "A1a 044".to_string(),
             name: "Graveler".to_string(),
             stage: 1,
@@ -6839,9 +6862,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a045Golem => Card::Pokemon(PokemonCard {
+        CardId::A1a045Golem => CardData::Pokemon(PokemonCard {
             id: "A1a 045".to_string(),
             name: "Golem".to_string(),
             stage: 2,
@@ -6860,9 +6883,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a046AerodactylEx => Card::Pokemon(PokemonCard {
+        CardId::A1a046AerodactylEx => CardData::Pokemon(PokemonCard {
             id: "A1a 046".to_string(),
             name: "Aerodactyl ex".to_string(),
             stage: 1,
@@ -6881,9 +6904,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a047Marshadow => Card::Pokemon(PokemonCard {
+        CardId::A1a047Marshadow => CardData::Pokemon(PokemonCard {
             id: "A1a 047".to_string(),
             name: "Marshadow".to_string(),
             stage: 0,
@@ -6902,9 +6925,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a048Stonjourner => Card::Pokemon(PokemonCard {
+        CardId::A1a048Stonjourner => CardData::Pokemon(PokemonCard {
             id: "A1a 048".to_string(),
             name: "Stonjourner".to_string(),
             stage: 0,
@@ -6923,9 +6946,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a049Koffing => Card::Pokemon(PokemonCard {
+        CardId::A1a049Koffing => CardData::Pokemon(PokemonCard {
             id: "A1a 049".to_string(),
             name: "Koffing".to_string(),
             stage: 0,
@@ -6944,9 +6967,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a050Weezing => Card::Pokemon(PokemonCard {
+        CardId::A1a050Weezing => CardData::Pokemon(PokemonCard {
             id: "A1a 050".to_string(),
             name: "Weezing".to_string(),
             stage: 1,
@@ -6965,9 +6988,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a051Purrloin => Card::Pokemon(PokemonCard {
+        CardId::A1a051Purrloin => CardData::Pokemon(PokemonCard {
             id: "A1a 051".to_string(),
             name: "Purrloin".to_string(),
             stage: 0,
@@ -6986,9 +7009,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a052Liepard => Card::Pokemon(PokemonCard {
+        CardId::A1a052Liepard => CardData::Pokemon(PokemonCard {
             id: "A1a 052".to_string(),
             name: "Liepard".to_string(),
             stage: 1,
@@ -7007,9 +7030,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a053Venipede => Card::Pokemon(PokemonCard {
+        CardId::A1a053Venipede => CardData::Pokemon(PokemonCard {
             id: "A1a 053".to_string(),
             name: "Venipede".to_string(),
             stage: 0,
@@ -7028,9 +7051,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a054Whirlipede => Card::Pokemon(PokemonCard {
+        CardId::A1a054Whirlipede => CardData::Pokemon(PokemonCard {
             id: "A1a 054".to_string(),
             name: "Whirlipede".to_string(),
             stage: 1,
@@ -7049,9 +7072,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a055Scolipede => Card::Pokemon(PokemonCard {
+        CardId::A1a055Scolipede => CardData::Pokemon(PokemonCard {
             id: "A1a 055".to_string(),
             name: "Scolipede".to_string(),
             stage: 2,
@@ -7070,9 +7093,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a056Druddigon => Card::Pokemon(PokemonCard {
+        CardId::A1a056Druddigon => CardData::Pokemon(PokemonCard {
             id: "A1a 056".to_string(),
             name: "Druddigon".to_string(),
             stage: 0,
@@ -7091,9 +7114,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a057Pidgey => Card::Pokemon(PokemonCard {
+        CardId::A1a057Pidgey => CardData::Pokemon(PokemonCard {
             id: "A1a 057".to_string(),
             name: "Pidgey".to_string(),
             stage: 0,
@@ -7112,9 +7135,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a058Pidgeotto => Card::Pokemon(PokemonCard {
+        CardId::A1a058Pidgeotto => CardData::Pokemon(PokemonCard {
             id: "A1a 058".to_string(),
             name: "Pidgeotto".to_string(),
             stage: 1,
@@ -7133,9 +7156,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a059PidgeotEx => Card::Pokemon(PokemonCard {
+        CardId::A1a059PidgeotEx => CardData::Pokemon(PokemonCard {
             id: "A1a 059".to_string(),
             name: "Pidgeot ex".to_string(),
             stage: 2,
@@ -7154,9 +7177,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a060Tauros => Card::Pokemon(PokemonCard {
+        CardId::A1a060Tauros => CardData::Pokemon(PokemonCard {
             id: "A1a 060".to_string(),
             name: "Tauros".to_string(),
             stage: 0,
@@ -7175,9 +7198,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a061Eevee => Card::Pokemon(PokemonCard {
+        CardId::A1a061Eevee => CardData::Pokemon(PokemonCard {
             id: "A1a 061".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -7196,9 +7219,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a062Chatot => Card::Pokemon(PokemonCard {
+        CardId::A1a062Chatot => CardData::Pokemon(PokemonCard {
             id: "A1a 062".to_string(),
             name: "Chatot".to_string(),
             stage: 0,
@@ -7217,63 +7240,63 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a063OldAmber => Card::Trainer(TrainerCard {
+        CardId::A1a063OldAmber => CardData::Trainer(TrainerCard {
             id: "A1a 063".to_string(),
             numeric_id: 8255,
             name: "Old Amber".to_string(),
             effect: "Play this card as if it were a 40-HP Basic [C] Pokémon.At any time during your turn, you may discard this card from play.This card can't retreat.".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A1a064PokemonFlute => Card::Trainer(TrainerCard {
+        CardId::A1a064PokemonFlute => CardData::Trainer(TrainerCard {
             id: "A1a 064".to_string(),
             numeric_id: 8256,
             name: "Pokémon Flute".to_string(),
             effect: "Put a Basic Pokémon from your opponent's discard pile onto their Bench.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A1a065MythicalSlab => Card::Trainer(TrainerCard {
+        CardId::A1a065MythicalSlab => CardData::Trainer(TrainerCard {
             id: "A1a 065".to_string(),
             numeric_id: 8257,
             name: "Mythical Slab".to_string(),
             effect: "Look at the top card of your deck. If that card is a [P] Pokémon, put it into your hand. If it is not a [P] Pokémon, put it on the bottom of your deck.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A1a066BuddingExpeditioner => Card::Trainer(TrainerCard {
+        CardId::A1a066BuddingExpeditioner => CardData::Trainer(TrainerCard {
             id: "A1a 066".to_string(),
             numeric_id: 8258,
             name: "Budding Expeditioner".to_string(),
             effect: "Put your Mew ex in the Active Spot into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1a067Blue => Card::Trainer(TrainerCard {
+        CardId::A1a067Blue => CardData::Trainer(TrainerCard {
             id: "A1a 067".to_string(),
             numeric_id: 8259,
             name: "Blue".to_string(),
             effect: "During your opponent's next turn, all of your Pokémon take −10 damage from attacks from your opponent's Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1a068Leaf => Card::Trainer(TrainerCard {
+        CardId::A1a068Leaf => CardData::Trainer(TrainerCard {
             id: "A1a 068".to_string(),
             numeric_id: 8260,
             name: "Leaf".to_string(),
             effect: "During this turn, the Retreat Cost of your Active Pokémon is 2 less.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1a069Exeggutor => Card::Pokemon(PokemonCard {
+        CardId::A1a069Exeggutor => CardData::Pokemon(PokemonCard {
             id: "A1a 069".to_string(),
             name: "Exeggutor".to_string(),
             stage: 1,
@@ -7292,9 +7315,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a070Serperior => Card::Pokemon(PokemonCard {
+        CardId::A1a070Serperior => CardData::Pokemon(PokemonCard {
             id: "A1a 070".to_string(),
             name: "Serperior".to_string(),
             stage: 2,
@@ -7313,9 +7336,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a071Salandit => Card::Pokemon(PokemonCard {
+        CardId::A1a071Salandit => CardData::Pokemon(PokemonCard {
             id: "A1a 071".to_string(),
             name: "Salandit".to_string(),
             stage: 0,
@@ -7334,9 +7357,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a072Vaporeon => Card::Pokemon(PokemonCard {
+        CardId::A1a072Vaporeon => CardData::Pokemon(PokemonCard {
             id: "A1a 072".to_string(),
             name: "Vaporeon".to_string(),
             stage: 1,
@@ -7355,9 +7378,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a073Dedenne => Card::Pokemon(PokemonCard {
+        CardId::A1a073Dedenne => CardData::Pokemon(PokemonCard {
             id: "A1a 073".to_string(),
             name: "Dedenne".to_string(),
             stage: 0,
@@ -7376,9 +7399,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a074Marshadow => Card::Pokemon(PokemonCard {
+        CardId::A1a074Marshadow => CardData::Pokemon(PokemonCard {
             id: "A1a 074".to_string(),
             name: "Marshadow".to_string(),
             stage: 0,
@@ -7397,9 +7420,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a075CelebiEx => Card::Pokemon(PokemonCard {
+        CardId::A1a075CelebiEx => CardData::Pokemon(PokemonCard {
             id: "A1a 075".to_string(),
             name: "Celebi ex".to_string(),
             stage: 0,
@@ -7418,9 +7441,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a076GyaradosEx => Card::Pokemon(PokemonCard {
+        CardId::A1a076GyaradosEx => CardData::Pokemon(PokemonCard {
             id: "A1a 076".to_string(),
             name: "Gyarados ex".to_string(),
             stage: 1,
@@ -7439,9 +7462,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a077MewEx => Card::Pokemon(PokemonCard {
+        CardId::A1a077MewEx => CardData::Pokemon(PokemonCard {
             id: "A1a 077".to_string(),
             name: "Mew ex".to_string(),
             stage: 0,
@@ -7466,9 +7489,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a078AerodactylEx => Card::Pokemon(PokemonCard {
+        CardId::A1a078AerodactylEx => CardData::Pokemon(PokemonCard {
             id: "A1a 078".to_string(),
             name: "Aerodactyl ex".to_string(),
             stage: 1,
@@ -7487,9 +7510,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a079PidgeotEx => Card::Pokemon(PokemonCard {
+        CardId::A1a079PidgeotEx => CardData::Pokemon(PokemonCard {
             id: "A1a 079".to_string(),
             name: "Pidgeot ex".to_string(),
             stage: 2,
@@ -7508,36 +7531,36 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a080BuddingExpeditioner => Card::Trainer(TrainerCard {
+        CardId::A1a080BuddingExpeditioner => CardData::Trainer(TrainerCard {
             id: "A1a 080".to_string(),
             numeric_id: 8272,
             name: "Budding Expeditioner".to_string(),
             effect: "Put your Mew ex in the Active Spot into your hand.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1a081Blue => Card::Trainer(TrainerCard {
+        CardId::A1a081Blue => CardData::Trainer(TrainerCard {
             id: "A1a 081".to_string(),
             numeric_id: 8273,
             name: "Blue".to_string(),
             effect: "During your opponent's next turn, all of your Pokémon take −10 damage from attacks from your opponent's Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1a082Leaf => Card::Trainer(TrainerCard {
+        CardId::A1a082Leaf => CardData::Trainer(TrainerCard {
             id: "A1a 082".to_string(),
             numeric_id: 8274,
             name: "Leaf".to_string(),
             effect: "During this turn, the Retreat Cost of your Active Pokémon is 2 less.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A1a083MewEx => Card::Pokemon(PokemonCard {
+        CardId::A1a083MewEx => CardData::Pokemon(PokemonCard {
             id: "A1a 083".to_string(),
             name: "Mew ex".to_string(),
             stage: 0,
@@ -7562,9 +7585,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a084AerodactylEx => Card::Pokemon(PokemonCard {
+        CardId::A1a084AerodactylEx => CardData::Pokemon(PokemonCard {
             id: "A1a 084".to_string(),
             name: "Aerodactyl ex".to_string(),
             stage: 1,
@@ -7583,9 +7606,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a085CelebiEx => Card::Pokemon(PokemonCard {
+        CardId::A1a085CelebiEx => CardData::Pokemon(PokemonCard {
             id: "A1a 085".to_string(),
             name: "Celebi ex".to_string(),
             stage: 0,
@@ -7604,9 +7627,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A1a086MewEx => Card::Pokemon(PokemonCard {
+        CardId::A1a086MewEx => CardData::Pokemon(PokemonCard {
             id: "A1a 086".to_string(),
             name: "Mew ex".to_string(),
             stage: 0,
@@ -7631,9 +7654,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Mythical Island (A1a)".to_string(),
+            set: SetId::A1a,
         }),
-        CardId::A2001Oddish => Card::Pokemon(PokemonCard {
+        CardId::A2001Oddish => CardData::Pokemon(PokemonCard {
             id: "A2 001".to_string(),
             name: "Oddish".to_string(),
             stage: 0,
@@ -7652,9 +7675,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2002Gloom => Card::Pokemon(PokemonCard {
+        CardId::A2002Gloom => CardData::Pokemon(PokemonCard {
             id: "A2 002".to_string(),
             name: "Gloom".to_string(),
             stage: 1,
@@ -7673,9 +7696,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2003Bellossom => Card::Pokemon(PokemonCard {
+        CardId::A2003Bellossom => CardData::Pokemon(PokemonCard {
             id: "A2 003".to_string(),
             name: "Bellossom".to_string(),
             stage: 2,
@@ -7694,9 +7717,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2004Tangela => Card::Pokemon(PokemonCard {
+        CardId::A2004Tangela => CardData::Pokemon(PokemonCard {
             id: "A2 004".to_string(),
             name: "Tangela".to_string(),
             stage: 0,
@@ -7715,9 +7738,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2005Tangrowth => Card::Pokemon(PokemonCard {
+        CardId::A2005Tangrowth => CardData::Pokemon(PokemonCard {
             id: "A2 005".to_string(),
             name: "Tangrowth".to_string(),
             stage: 1,
@@ -7736,9 +7759,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2006Yanma => Card::Pokemon(PokemonCard {
+        CardId::A2006Yanma => CardData::Pokemon(PokemonCard {
             id: "A2 006".to_string(),
             name: "Yanma".to_string(),
             stage: 0,
@@ -7757,9 +7780,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2007YanmegaEx => Card::Pokemon(PokemonCard {
+        CardId::A2007YanmegaEx => CardData::Pokemon(PokemonCard {
             id: "A2 007".to_string(),
             name: "Yanmega ex".to_string(),
             stage: 1,
@@ -7778,9 +7801,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2008Roselia => Card::Pokemon(PokemonCard {
+        CardId::A2008Roselia => CardData::Pokemon(PokemonCard {
             id: "A2 008".to_string(),
             name: "Roselia".to_string(),
             stage: 0,
@@ -7799,9 +7822,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2009Roserade => Card::Pokemon(PokemonCard {
+        CardId::A2009Roserade => CardData::Pokemon(PokemonCard {
             id: "A2 009".to_string(),
             name: "Roserade".to_string(),
             stage: 1,
@@ -7820,9 +7843,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2010Turtwig => Card::Pokemon(PokemonCard {
+        CardId::A2010Turtwig => CardData::Pokemon(PokemonCard {
             id: "A2 010".to_string(),
             name: "Turtwig".to_string(),
             stage: 0,
@@ -7841,9 +7864,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2011Grotle => Card::Pokemon(PokemonCard {
+        CardId::A2011Grotle => CardData::Pokemon(PokemonCard {
             id: "A2 011".to_string(),
             name: "Grotle".to_string(),
             stage: 1,
@@ -7862,9 +7885,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2012Torterra => Card::Pokemon(PokemonCard {
+        CardId::A2012Torterra => CardData::Pokemon(PokemonCard {
             id: "A2 012".to_string(),
             name: "Torterra".to_string(),
             stage: 2,
@@ -7883,9 +7906,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2013Kricketot => Card::Pokemon(PokemonCard {
+        CardId::A2013Kricketot => CardData::Pokemon(PokemonCard {
             id: "A2 013".to_string(),
             name: "Kricketot".to_string(),
             stage: 0,
@@ -7904,9 +7927,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2014Kricketune => Card::Pokemon(PokemonCard {
+        CardId::A2014Kricketune => CardData::Pokemon(PokemonCard {
             id: "A2 014".to_string(),
             name: "Kricketune".to_string(),
             stage: 1,
@@ -7925,9 +7948,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2015Burmy => Card::Pokemon(PokemonCard {
+        CardId::A2015Burmy => CardData::Pokemon(PokemonCard {
             id: "A2 015".to_string(),
             name: "Burmy".to_string(),
             stage: 0,
@@ -7946,9 +7969,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2016Wormadam => Card::Pokemon(PokemonCard {
+        CardId::A2016Wormadam => CardData::Pokemon(PokemonCard {
             id: "A2 016".to_string(),
             name: "Wormadam".to_string(),
             stage: 1,
@@ -7967,9 +7990,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2017Combee => Card::Pokemon(PokemonCard {
+        CardId::A2017Combee => CardData::Pokemon(PokemonCard {
             id: "A2 017".to_string(),
             name: "Combee".to_string(),
             stage: 0,
@@ -7988,9 +8011,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2018Vespiquen => Card::Pokemon(PokemonCard {
+        CardId::A2018Vespiquen => CardData::Pokemon(PokemonCard {
             id: "A2 018".to_string(),
             name: "Vespiquen".to_string(),
             stage: 1,
@@ -8009,9 +8032,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2019Carnivine => Card::Pokemon(PokemonCard {
+        CardId::A2019Carnivine => CardData::Pokemon(PokemonCard {
             id: "A2 019".to_string(),
             name: "Carnivine".to_string(),
             stage: 0,
@@ -8030,9 +8053,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2020Leafeon => Card::Pokemon(PokemonCard {
+        CardId::A2020Leafeon => CardData::Pokemon(PokemonCard {
             id: "A2 020".to_string(),
             name: "Leafeon".to_string(),
             stage: 1,
@@ -8051,9 +8074,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2021MowRotom => Card::Pokemon(PokemonCard {
+        CardId::A2021MowRotom => CardData::Pokemon(PokemonCard {
             id: "A2 021".to_string(),
             name: "Mow Rotom".to_string(),
             stage: 0,
@@ -8072,9 +8095,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2022Shaymin => Card::Pokemon(PokemonCard {
+        CardId::A2022Shaymin => CardData::Pokemon(PokemonCard {
             id: "A2 022".to_string(),
             name: "Shaymin".to_string(),
             stage: 0,
@@ -8093,9 +8116,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2023Magmar => Card::Pokemon(PokemonCard {
+        CardId::A2023Magmar => CardData::Pokemon(PokemonCard {
             id: "A2 023".to_string(),
             name: "Magmar".to_string(),
             stage: 0,
@@ -8114,9 +8137,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2024Magmortar => Card::Pokemon(PokemonCard {
+        CardId::A2024Magmortar => CardData::Pokemon(PokemonCard {
             id: "A2 024".to_string(),
             name: "Magmortar".to_string(),
             stage: 1,
@@ -8135,9 +8158,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2025Slugma => Card::Pokemon(PokemonCard {
+        CardId::A2025Slugma => CardData::Pokemon(PokemonCard {
             id: "A2 025".to_string(),
             name: "Slugma".to_string(),
             stage: 0,
@@ -8156,9 +8179,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2026Magcargo => Card::Pokemon(PokemonCard {
+        CardId::A2026Magcargo => CardData::Pokemon(PokemonCard {
             id: "A2 026".to_string(),
             name: "Magcargo".to_string(),
             stage: 1,
@@ -8177,9 +8200,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2027Chimchar => Card::Pokemon(PokemonCard {
+        CardId::A2027Chimchar => CardData::Pokemon(PokemonCard {
             id: "A2 027".to_string(),
             name: "Chimchar".to_string(),
             stage: 0,
@@ -8198,9 +8221,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2028Monferno => Card::Pokemon(PokemonCard {
+        CardId::A2028Monferno => CardData::Pokemon(PokemonCard {
             id: "A2 028".to_string(),
             name: "Monferno".to_string(),
             stage: 1,
@@ -8219,9 +8242,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2029InfernapeEx => Card::Pokemon(PokemonCard {
+        CardId::A2029InfernapeEx => CardData::Pokemon(PokemonCard {
             id: "A2 029".to_string(),
             name: "Infernape ex".to_string(),
             stage: 2,
@@ -8240,9 +8263,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2030HeatRotom => Card::Pokemon(PokemonCard {
+        CardId::A2030HeatRotom => CardData::Pokemon(PokemonCard {
             id: "A2 030".to_string(),
             name: "Heat Rotom".to_string(),
             stage: 0,
@@ -8261,9 +8284,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2031Swinub => Card::Pokemon(PokemonCard {
+        CardId::A2031Swinub => CardData::Pokemon(PokemonCard {
             id: "A2 031".to_string(),
             name: "Swinub".to_string(),
             stage: 0,
@@ -8282,9 +8305,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2032Piloswine => Card::Pokemon(PokemonCard {
+        CardId::A2032Piloswine => CardData::Pokemon(PokemonCard {
             id: "A2 032".to_string(),
             name: "Piloswine".to_string(),
             stage: 1,
@@ -8303,9 +8326,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2033Mamoswine => Card::Pokemon(PokemonCard {
+        CardId::A2033Mamoswine => CardData::Pokemon(PokemonCard {
             id: "A2 033".to_string(),
             name: "Mamoswine".to_string(),
             stage: 2,
@@ -8324,9 +8347,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2034Regice => Card::Pokemon(PokemonCard {
+        CardId::A2034Regice => CardData::Pokemon(PokemonCard {
             id: "A2 034".to_string(),
             name: "Regice".to_string(),
             stage: 0,
@@ -8345,9 +8368,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2035Piplup => Card::Pokemon(PokemonCard {
+        CardId::A2035Piplup => CardData::Pokemon(PokemonCard {
             id: "A2 035".to_string(),
             name: "Piplup".to_string(),
             stage: 0,
@@ -8366,9 +8389,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2036Prinplup => Card::Pokemon(PokemonCard {
+        CardId::A2036Prinplup => CardData::Pokemon(PokemonCard {
             id: "A2 036".to_string(),
             name: "Prinplup".to_string(),
             stage: 1,
@@ -8387,9 +8410,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2037Empoleon => Card::Pokemon(PokemonCard {
+        CardId::A2037Empoleon => CardData::Pokemon(PokemonCard {
             id: "A2 037".to_string(),
             name: "Empoleon".to_string(),
             stage: 2,
@@ -8408,9 +8431,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2038Buizel => Card::Pokemon(PokemonCard {
+        CardId::A2038Buizel => CardData::Pokemon(PokemonCard {
             id: "A2 038".to_string(),
             name: "Buizel".to_string(),
             stage: 0,
@@ -8429,9 +8452,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2039Floatzel => Card::Pokemon(PokemonCard {
+        CardId::A2039Floatzel => CardData::Pokemon(PokemonCard {
             id: "A2 039".to_string(),
             name: "Floatzel".to_string(),
             stage: 1,
@@ -8450,9 +8473,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2040Shellos => Card::Pokemon(PokemonCard {
+        CardId::A2040Shellos => CardData::Pokemon(PokemonCard {
             id: "A2 040".to_string(),
             name: "Shellos".to_string(),
             stage: 0,
@@ -8471,9 +8494,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2041Gastrodon => Card::Pokemon(PokemonCard {
+        CardId::A2041Gastrodon => CardData::Pokemon(PokemonCard {
             id: "A2 041".to_string(),
             name: "Gastrodon".to_string(),
             stage: 1,
@@ -8492,9 +8515,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2042Finneon => Card::Pokemon(PokemonCard {
+        CardId::A2042Finneon => CardData::Pokemon(PokemonCard {
             id: "A2 042".to_string(),
             name: "Finneon".to_string(),
             stage: 0,
@@ -8513,9 +8536,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2043Lumineon => Card::Pokemon(PokemonCard {
+        CardId::A2043Lumineon => CardData::Pokemon(PokemonCard {
             id: "A2 043".to_string(),
             name: "Lumineon".to_string(),
             stage: 1,
@@ -8534,9 +8557,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2044Snover => Card::Pokemon(PokemonCard {
+        CardId::A2044Snover => CardData::Pokemon(PokemonCard {
             id: "A2 044".to_string(),
             name: "Snover".to_string(),
             stage: 0,
@@ -8555,9 +8578,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2045Abomasnow => Card::Pokemon(PokemonCard {
+        CardId::A2045Abomasnow => CardData::Pokemon(PokemonCard {
             id: "A2 045".to_string(),
             name: "Abomasnow".to_string(),
             stage: 1,
@@ -8576,9 +8599,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2046Glaceon => Card::Pokemon(PokemonCard {
+        CardId::A2046Glaceon => CardData::Pokemon(PokemonCard {
             id: "A2 046".to_string(),
             name: "Glaceon".to_string(),
             stage: 1,
@@ -8597,9 +8620,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2047WashRotom => Card::Pokemon(PokemonCard {
+        CardId::A2047WashRotom => CardData::Pokemon(PokemonCard {
             id: "A2 047".to_string(),
             name: "Wash Rotom".to_string(),
             stage: 0,
@@ -8618,9 +8641,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2048FrostRotom => Card::Pokemon(PokemonCard {
+        CardId::A2048FrostRotom => CardData::Pokemon(PokemonCard {
             id: "A2 048".to_string(),
             name: "Frost Rotom".to_string(),
             stage: 0,
@@ -8639,9 +8662,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2049PalkiaEx => Card::Pokemon(PokemonCard {
+        CardId::A2049PalkiaEx => CardData::Pokemon(PokemonCard {
             id: "A2 049".to_string(),
             name: "Palkia ex".to_string(),
             stage: 0,
@@ -8666,9 +8689,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2050Manaphy => Card::Pokemon(PokemonCard {
+        CardId::A2050Manaphy => CardData::Pokemon(PokemonCard {
             id: "A2 050".to_string(),
             name: "Manaphy".to_string(),
             stage: 0,
@@ -8687,9 +8710,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2051Magnemite => Card::Pokemon(PokemonCard {
+        CardId::A2051Magnemite => CardData::Pokemon(PokemonCard {
             id: "A2 051".to_string(),
             name: "Magnemite".to_string(),
             stage: 0,
@@ -8708,9 +8731,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2052Magneton => Card::Pokemon(PokemonCard {
+        CardId::A2052Magneton => CardData::Pokemon(PokemonCard {
             id: "A2 052".to_string(),
             name: "Magneton".to_string(),
             stage: 1,
@@ -8729,9 +8752,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2053Magnezone => Card::Pokemon(PokemonCard {
+        CardId::A2053Magnezone => CardData::Pokemon(PokemonCard {
             id: "A2 053".to_string(),
             name: "Magnezone".to_string(),
             stage: 2,
@@ -8750,9 +8773,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2054Voltorb => Card::Pokemon(PokemonCard {
+        CardId::A2054Voltorb => CardData::Pokemon(PokemonCard {
             id: "A2 054".to_string(),
             name: "Voltorb".to_string(),
             stage: 0,
@@ -8771,9 +8794,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2055Electrode => Card::Pokemon(PokemonCard {
+        CardId::A2055Electrode => CardData::Pokemon(PokemonCard {
             id: "A2 055".to_string(),
             name: "Electrode".to_string(),
             stage: 1,
@@ -8792,9 +8815,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2056Electabuzz => Card::Pokemon(PokemonCard {
+        CardId::A2056Electabuzz => CardData::Pokemon(PokemonCard {
             id: "A2 056".to_string(),
             name: "Electabuzz".to_string(),
             stage: 0,
@@ -8813,9 +8836,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2057Electivire => Card::Pokemon(PokemonCard {
+        CardId::A2057Electivire => CardData::Pokemon(PokemonCard {
             id: "A2 057".to_string(),
             name: "Electivire".to_string(),
             stage: 1,
@@ -8834,9 +8857,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2058Shinx => Card::Pokemon(PokemonCard {
+        CardId::A2058Shinx => CardData::Pokemon(PokemonCard {
             id: "A2 058".to_string(),
             name: "Shinx".to_string(),
             stage: 0,
@@ -8855,9 +8878,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2059Luxio => Card::Pokemon(PokemonCard {
+        CardId::A2059Luxio => CardData::Pokemon(PokemonCard {
             id: "A2 059".to_string(),
             name: "Luxio".to_string(),
             stage: 1,
@@ -8876,9 +8899,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2060Luxray => Card::Pokemon(PokemonCard {
+        CardId::A2060Luxray => CardData::Pokemon(PokemonCard {
             id: "A2 060".to_string(),
             name: "Luxray".to_string(),
             stage: 2,
@@ -8897,9 +8920,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2061PachirisuEx => Card::Pokemon(PokemonCard {
+        CardId::A2061PachirisuEx => CardData::Pokemon(PokemonCard {
             id: "A2 061".to_string(),
             name: "Pachirisu ex".to_string(),
             stage: 0,
@@ -8918,9 +8941,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2062Rotom => Card::Pokemon(PokemonCard {
+        CardId::A2062Rotom => CardData::Pokemon(PokemonCard {
             id: "A2 062".to_string(),
             name: "Rotom".to_string(),
             stage: 0,
@@ -8939,9 +8962,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2063Togepi => Card::Pokemon(PokemonCard {
+        CardId::A2063Togepi => CardData::Pokemon(PokemonCard {
             id: "A2 063".to_string(),
             name: "Togepi".to_string(),
             stage: 0,
@@ -8960,9 +8983,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2064Togetic => Card::Pokemon(PokemonCard {
+        CardId::A2064Togetic => CardData::Pokemon(PokemonCard {
             id: "A2 064".to_string(),
             name: "Togetic".to_string(),
             stage: 1,
@@ -8981,9 +9004,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2065Togekiss => Card::Pokemon(PokemonCard {
+        CardId::A2065Togekiss => CardData::Pokemon(PokemonCard {
             id: "A2 065".to_string(),
             name: "Togekiss".to_string(),
             stage: 2,
@@ -9002,9 +9025,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2066Misdreavus => Card::Pokemon(PokemonCard {
+        CardId::A2066Misdreavus => CardData::Pokemon(PokemonCard {
             id: "A2 066".to_string(),
             name: "Misdreavus".to_string(),
             stage: 0,
@@ -9023,9 +9046,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2067MismagiusEx => Card::Pokemon(PokemonCard {
+        CardId::A2067MismagiusEx => CardData::Pokemon(PokemonCard {
             id: "A2 067".to_string(),
             name: "Mismagius ex".to_string(),
             stage: 1,
@@ -9044,9 +9067,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2068Ralts => Card::Pokemon(PokemonCard {
+        CardId::A2068Ralts => CardData::Pokemon(PokemonCard {
             id: "A2 068".to_string(),
             name: "Ralts".to_string(),
             stage: 0,
@@ -9065,9 +9088,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2069Kirlia => Card::Pokemon(PokemonCard {
+        CardId::A2069Kirlia => CardData::Pokemon(PokemonCard {
             id: "A2 069".to_string(),
             name: "Kirlia".to_string(),
             stage: 1,
@@ -9086,9 +9109,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2070Duskull => Card::Pokemon(PokemonCard {
+        CardId::A2070Duskull => CardData::Pokemon(PokemonCard {
             id: "A2 070".to_string(),
             name: "Duskull".to_string(),
             stage: 0,
@@ -9107,9 +9130,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2071Dusclops => Card::Pokemon(PokemonCard {
+        CardId::A2071Dusclops => CardData::Pokemon(PokemonCard {
             id: "A2 071".to_string(),
             name: "Dusclops".to_string(),
             stage: 1,
@@ -9128,9 +9151,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2072Dusknoir => Card::Pokemon(PokemonCard {
+        CardId::A2072Dusknoir => CardData::Pokemon(PokemonCard {
             id: "A2 072".to_string(),
             name: "Dusknoir".to_string(),
             stage: 2,
@@ -9149,9 +9172,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2073Drifloon => Card::Pokemon(PokemonCard {
+        CardId::A2073Drifloon => CardData::Pokemon(PokemonCard {
             id: "A2 073".to_string(),
             name: "Drifloon".to_string(),
             stage: 0,
@@ -9170,9 +9193,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2074Drifblim => Card::Pokemon(PokemonCard {
+        CardId::A2074Drifblim => CardData::Pokemon(PokemonCard {
             id: "A2 074".to_string(),
             name: "Drifblim".to_string(),
             stage: 1,
@@ -9191,9 +9214,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2075Uxie => Card::Pokemon(PokemonCard {
+        CardId::A2075Uxie => CardData::Pokemon(PokemonCard {
             id: "A2 075".to_string(),
             name: "Uxie".to_string(),
             stage: 0,
@@ -9212,9 +9235,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2076Mesprit => Card::Pokemon(PokemonCard {
+        CardId::A2076Mesprit => CardData::Pokemon(PokemonCard {
             id: "A2 076".to_string(),
             name: "Mesprit".to_string(),
             stage: 0,
@@ -9233,9 +9256,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2077Azelf => Card::Pokemon(PokemonCard {
+        CardId::A2077Azelf => CardData::Pokemon(PokemonCard {
             id: "A2 077".to_string(),
             name: "Azelf".to_string(),
             stage: 0,
@@ -9254,9 +9277,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2078Giratina => Card::Pokemon(PokemonCard {
+        CardId::A2078Giratina => CardData::Pokemon(PokemonCard {
             id: "A2 078".to_string(),
             name: "Giratina".to_string(),
             stage: 0,
@@ -9275,9 +9298,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2079Cresselia => Card::Pokemon(PokemonCard {
+        CardId::A2079Cresselia => CardData::Pokemon(PokemonCard {
             id: "A2 079".to_string(),
             name: "Cresselia".to_string(),
             stage: 0,
@@ -9296,9 +9319,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2080Rhyhorn => Card::Pokemon(PokemonCard {
+        CardId::A2080Rhyhorn => CardData::Pokemon(PokemonCard {
             id: "A2 080".to_string(),
             name: "Rhyhorn".to_string(),
             stage: 0,
@@ -9317,9 +9340,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2081Rhydon => Card::Pokemon(PokemonCard {
+        CardId::A2081Rhydon => CardData::Pokemon(PokemonCard {
             id: "A2 081".to_string(),
             name: "Rhydon".to_string(),
             stage: 1,
@@ -9338,9 +9361,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2082Rhyperior => Card::Pokemon(PokemonCard {
+        CardId::A2082Rhyperior => CardData::Pokemon(PokemonCard {
             id: "A2 082".to_string(),
             name: "Rhyperior".to_string(),
             stage: 2,
@@ -9359,9 +9382,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2083Gligar => Card::Pokemon(PokemonCard {
+        CardId::A2083Gligar => CardData::Pokemon(PokemonCard {
             id: "A2 083".to_string(),
             name: "Gligar".to_string(),
             stage: 0,
@@ -9380,9 +9403,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2084Gliscor => Card::Pokemon(PokemonCard {
+        CardId::A2084Gliscor => CardData::Pokemon(PokemonCard {
             id: "A2 084".to_string(),
             name: "Gliscor".to_string(),
             stage: 1,
@@ -9401,9 +9424,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2085Hitmontop => Card::Pokemon(PokemonCard {
+        CardId::A2085Hitmontop => CardData::Pokemon(PokemonCard {
             id: "A2 085".to_string(),
             name: "Hitmontop".to_string(),
             stage: 0,
@@ -9422,9 +9445,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2086Nosepass => Card::Pokemon(PokemonCard {
+        CardId::A2086Nosepass => CardData::Pokemon(PokemonCard {
             id: "A2 086".to_string(),
             name: "Nosepass".to_string(),
             stage: 0,
@@ -9443,9 +9466,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2087Regirock => Card::Pokemon(PokemonCard {
+        CardId::A2087Regirock => CardData::Pokemon(PokemonCard {
             id: "A2 087".to_string(),
             name: "Regirock".to_string(),
             stage: 0,
@@ -9464,9 +9487,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2088Cranidos => Card::Pokemon(PokemonCard {
+        CardId::A2088Cranidos => CardData::Pokemon(PokemonCard {
             id: "A2 088".to_string(),
             name: "Cranidos".to_string(),
             stage: 1,
@@ -9485,9 +9508,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2089Rampardos => Card::Pokemon(PokemonCard {
+        CardId::A2089Rampardos => CardData::Pokemon(PokemonCard {
             id: "A2 089".to_string(),
             name: "Rampardos".to_string(),
             stage: 2,
@@ -9506,9 +9529,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2090Wormadam => Card::Pokemon(PokemonCard {
+        CardId::A2090Wormadam => CardData::Pokemon(PokemonCard {
             id: "A2 090".to_string(),
             name: "Wormadam".to_string(),
             stage: 1,
@@ -9527,9 +9550,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2091Riolu => Card::Pokemon(PokemonCard {
+        CardId::A2091Riolu => CardData::Pokemon(PokemonCard {
             id: "A2 091".to_string(),
             name: "Riolu".to_string(),
             stage: 0,
@@ -9548,9 +9571,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2092Lucario => Card::Pokemon(PokemonCard {
+        CardId::A2092Lucario => CardData::Pokemon(PokemonCard {
             id: "A2 092".to_string(),
             name: "Lucario".to_string(),
             stage: 1,
@@ -9569,9 +9592,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2093Hippopotas => Card::Pokemon(PokemonCard {
+        CardId::A2093Hippopotas => CardData::Pokemon(PokemonCard {
             id: "A2 093".to_string(),
             name: "Hippopotas".to_string(),
             stage: 0,
@@ -9590,9 +9613,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2094Hippowdon => Card::Pokemon(PokemonCard {
+        CardId::A2094Hippowdon => CardData::Pokemon(PokemonCard {
             id: "A2 094".to_string(),
             name: "Hippowdon".to_string(),
             stage: 1,
@@ -9611,9 +9634,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2095GalladeEx => Card::Pokemon(PokemonCard {
+        CardId::A2095GalladeEx => CardData::Pokemon(PokemonCard {
             id: "A2 095".to_string(),
             name: "Gallade ex".to_string(),
             stage: 2,
@@ -9632,9 +9655,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2096Murkrow => Card::Pokemon(PokemonCard {
+        CardId::A2096Murkrow => CardData::Pokemon(PokemonCard {
             id: "A2 096".to_string(),
             name: "Murkrow".to_string(),
             stage: 0,
@@ -9653,9 +9676,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2097Honchkrow => Card::Pokemon(PokemonCard {
+        CardId::A2097Honchkrow => CardData::Pokemon(PokemonCard {
             id: "A2 097".to_string(),
             name: "Honchkrow".to_string(),
             stage: 1,
@@ -9674,9 +9697,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2098Sneasel => Card::Pokemon(PokemonCard {
+        CardId::A2098Sneasel => CardData::Pokemon(PokemonCard {
             id: "A2 098".to_string(),
             name: "Sneasel".to_string(),
             stage: 0,
@@ -9695,9 +9718,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2099WeavileEx => Card::Pokemon(PokemonCard {
+        CardId::A2099WeavileEx => CardData::Pokemon(PokemonCard {
             id: "A2 099".to_string(),
             name: "Weavile ex".to_string(),
             stage: 1,
@@ -9716,9 +9739,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2100Poochyena => Card::Pokemon(PokemonCard {
+        CardId::A2100Poochyena => CardData::Pokemon(PokemonCard {
             id: "A2 100".to_string(),
             name: "Poochyena".to_string(),
             stage: 0,
@@ -9737,9 +9760,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2101Mightyena => Card::Pokemon(PokemonCard {
+        CardId::A2101Mightyena => CardData::Pokemon(PokemonCard {
             id: "A2 101".to_string(),
             name: "Mightyena".to_string(),
             stage: 1,
@@ -9758,9 +9781,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2102Stunky => Card::Pokemon(PokemonCard {
+        CardId::A2102Stunky => CardData::Pokemon(PokemonCard {
             id: "A2 102".to_string(),
             name: "Stunky".to_string(),
             stage: 0,
@@ -9779,9 +9802,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2103Skuntank => Card::Pokemon(PokemonCard {
+        CardId::A2103Skuntank => CardData::Pokemon(PokemonCard {
             id: "A2 103".to_string(),
             name: "Skuntank".to_string(),
             stage: 1,
@@ -9800,9 +9823,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2104Spiritomb => Card::Pokemon(PokemonCard {
+        CardId::A2104Spiritomb => CardData::Pokemon(PokemonCard {
             id: "A2 104".to_string(),
             name: "Spiritomb".to_string(),
             stage: 0,
@@ -9821,9 +9844,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2105Skorupi => Card::Pokemon(PokemonCard {
+        CardId::A2105Skorupi => CardData::Pokemon(PokemonCard {
             id: "A2 105".to_string(),
             name: "Skorupi".to_string(),
             stage: 0,
@@ -9842,9 +9865,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2106Drapion => Card::Pokemon(PokemonCard {
+        CardId::A2106Drapion => CardData::Pokemon(PokemonCard {
             id: "A2 106".to_string(),
             name: "Drapion".to_string(),
             stage: 1,
@@ -9863,9 +9886,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2107Croagunk => Card::Pokemon(PokemonCard {
+        CardId::A2107Croagunk => CardData::Pokemon(PokemonCard {
             id: "A2 107".to_string(),
             name: "Croagunk".to_string(),
             stage: 0,
@@ -9884,9 +9907,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2108Toxicroak => Card::Pokemon(PokemonCard {
+        CardId::A2108Toxicroak => CardData::Pokemon(PokemonCard {
             id: "A2 108".to_string(),
             name: "Toxicroak".to_string(),
             stage: 1,
@@ -9905,9 +9928,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2109Darkrai => Card::Pokemon(PokemonCard {
+        CardId::A2109Darkrai => CardData::Pokemon(PokemonCard {
             id: "A2 109".to_string(),
             name: "Darkrai".to_string(),
             stage: 0,
@@ -9926,9 +9949,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2110DarkraiEx => Card::Pokemon(PokemonCard {
+        CardId::A2110DarkraiEx => CardData::Pokemon(PokemonCard {
             id: "A2 110".to_string(),
             name: "Darkrai ex".to_string(),
             stage: 0,
@@ -9947,9 +9970,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2111Skarmory => Card::Pokemon(PokemonCard {
+        CardId::A2111Skarmory => CardData::Pokemon(PokemonCard {
             id: "A2 111".to_string(),
             name: "Skarmory".to_string(),
             stage: 0,
@@ -9968,9 +9991,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2112Registeel => Card::Pokemon(PokemonCard {
+        CardId::A2112Registeel => CardData::Pokemon(PokemonCard {
             id: "A2 112".to_string(),
             name: "Registeel".to_string(),
             stage: 0,
@@ -9989,9 +10012,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2113Shieldon => Card::Pokemon(PokemonCard {
+        CardId::A2113Shieldon => CardData::Pokemon(PokemonCard {
             id: "A2 113".to_string(),
             name: "Shieldon".to_string(),
             stage: 1,
@@ -10010,9 +10033,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2114Bastiodon => Card::Pokemon(PokemonCard {
+        CardId::A2114Bastiodon => CardData::Pokemon(PokemonCard {
             id: "A2 114".to_string(),
             name: "Bastiodon".to_string(),
             stage: 2,
@@ -10031,9 +10054,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2115Wormadam => Card::Pokemon(PokemonCard {
+        CardId::A2115Wormadam => CardData::Pokemon(PokemonCard {
             id: "A2 115".to_string(),
             name: "Wormadam".to_string(),
             stage: 1,
@@ -10052,9 +10075,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2116Bronzor => Card::Pokemon(PokemonCard {
+        CardId::A2116Bronzor => CardData::Pokemon(PokemonCard {
             id: "A2 116".to_string(),
             name: "Bronzor".to_string(),
             stage: 0,
@@ -10073,9 +10096,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2117Bronzong => Card::Pokemon(PokemonCard {
+        CardId::A2117Bronzong => CardData::Pokemon(PokemonCard {
             id: "A2 117".to_string(),
             name: "Bronzong".to_string(),
             stage: 1,
@@ -10094,9 +10117,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2118Probopass => Card::Pokemon(PokemonCard {
+        CardId::A2118Probopass => CardData::Pokemon(PokemonCard {
             id: "A2 118".to_string(),
             name: "Probopass".to_string(),
             stage: 1,
@@ -10115,9 +10138,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2119DialgaEx => Card::Pokemon(PokemonCard {
+        CardId::A2119DialgaEx => CardData::Pokemon(PokemonCard {
             id: "A2 119".to_string(),
             name: "Dialga ex".to_string(),
             stage: 0,
@@ -10142,9 +10165,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2120Heatran => Card::Pokemon(PokemonCard {
+        CardId::A2120Heatran => CardData::Pokemon(PokemonCard {
             id: "A2 120".to_string(),
             name: "Heatran".to_string(),
             stage: 0,
@@ -10163,9 +10186,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2121Gible => Card::Pokemon(PokemonCard {
+        CardId::A2121Gible => CardData::Pokemon(PokemonCard {
             id: "A2 121".to_string(),
             name: "Gible".to_string(),
             stage: 0,
@@ -10184,9 +10207,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2122Gabite => Card::Pokemon(PokemonCard {
+        CardId::A2122Gabite => CardData::Pokemon(PokemonCard {
             id: "A2 122".to_string(),
             name: "Gabite".to_string(),
             stage: 1,
@@ -10205,9 +10228,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2123Garchomp => Card::Pokemon(PokemonCard {
+        CardId::A2123Garchomp => CardData::Pokemon(PokemonCard {
             id: "A2 123".to_string(),
             name: "Garchomp".to_string(),
             stage: 2,
@@ -10226,9 +10249,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2124Lickitung => Card::Pokemon(PokemonCard {
+        CardId::A2124Lickitung => CardData::Pokemon(PokemonCard {
             id: "A2 124".to_string(),
             name: "Lickitung".to_string(),
             stage: 0,
@@ -10247,9 +10270,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2125LickilickyEx => Card::Pokemon(PokemonCard {
+        CardId::A2125LickilickyEx => CardData::Pokemon(PokemonCard {
             id: "A2 125".to_string(),
             name: "Lickilicky ex".to_string(),
             stage: 1,
@@ -10268,9 +10291,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2126Eevee => Card::Pokemon(PokemonCard {
+        CardId::A2126Eevee => CardData::Pokemon(PokemonCard {
             id: "A2 126".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -10289,9 +10312,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2127Porygon => Card::Pokemon(PokemonCard {
+        CardId::A2127Porygon => CardData::Pokemon(PokemonCard {
             id: "A2 127".to_string(),
             name: "Porygon".to_string(),
             stage: 0,
@@ -10310,9 +10333,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2128Porygon2 => Card::Pokemon(PokemonCard {
+        CardId::A2128Porygon2 => CardData::Pokemon(PokemonCard {
             id: "A2 128".to_string(),
             name: "Porygon2".to_string(),
             stage: 1,
@@ -10331,9 +10354,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2129PorygonZ => Card::Pokemon(PokemonCard {
+        CardId::A2129PorygonZ => CardData::Pokemon(PokemonCard {
             id: "A2 129".to_string(),
             name: "Porygon-Z".to_string(),
             stage: 2,
@@ -10352,9 +10375,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2130Aipom => Card::Pokemon(PokemonCard {
+        CardId::A2130Aipom => CardData::Pokemon(PokemonCard {
             id: "A2 130".to_string(),
             name: "Aipom".to_string(),
             stage: 0,
@@ -10373,9 +10396,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2131Ambipom => Card::Pokemon(PokemonCard {
+        CardId::A2131Ambipom => CardData::Pokemon(PokemonCard {
             id: "A2 131".to_string(),
             name: "Ambipom".to_string(),
             stage: 1,
@@ -10394,9 +10417,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2132Starly => Card::Pokemon(PokemonCard {
+        CardId::A2132Starly => CardData::Pokemon(PokemonCard {
             id: "A2 132".to_string(),
             name: "Starly".to_string(),
             stage: 0,
@@ -10415,9 +10438,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2133Staravia => Card::Pokemon(PokemonCard {
+        CardId::A2133Staravia => CardData::Pokemon(PokemonCard {
             id: "A2 133".to_string(),
             name: "Staravia".to_string(),
             stage: 1,
@@ -10436,9 +10459,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2134Staraptor => Card::Pokemon(PokemonCard {
+        CardId::A2134Staraptor => CardData::Pokemon(PokemonCard {
             id: "A2 134".to_string(),
             name: "Staraptor".to_string(),
             stage: 2,
@@ -10457,9 +10480,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2135Bidoof => Card::Pokemon(PokemonCard {
+        CardId::A2135Bidoof => CardData::Pokemon(PokemonCard {
             id: "A2 135".to_string(),
             name: "Bidoof".to_string(),
             stage: 0,
@@ -10478,9 +10501,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2136Bibarel => Card::Pokemon(PokemonCard {
+        CardId::A2136Bibarel => CardData::Pokemon(PokemonCard {
             id: "A2 136".to_string(),
             name: "Bibarel".to_string(),
             stage: 1,
@@ -10499,9 +10522,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2137Buneary => Card::Pokemon(PokemonCard {
+        CardId::A2137Buneary => CardData::Pokemon(PokemonCard {
             id: "A2 137".to_string(),
             name: "Buneary".to_string(),
             stage: 0,
@@ -10520,9 +10543,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2138Lopunny => Card::Pokemon(PokemonCard {
+        CardId::A2138Lopunny => CardData::Pokemon(PokemonCard {
             id: "A2 138".to_string(),
             name: "Lopunny".to_string(),
             stage: 1,
@@ -10541,9 +10564,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2139Glameow => Card::Pokemon(PokemonCard {
+        CardId::A2139Glameow => CardData::Pokemon(PokemonCard {
             id: "A2 139".to_string(),
             name: "Glameow".to_string(),
             stage: 0,
@@ -10562,9 +10585,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2140Purugly => Card::Pokemon(PokemonCard {
+        CardId::A2140Purugly => CardData::Pokemon(PokemonCard {
             id: "A2 140".to_string(),
             name: "Purugly".to_string(),
             stage: 1,
@@ -10583,9 +10606,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2141Chatot => Card::Pokemon(PokemonCard {
+        CardId::A2141Chatot => CardData::Pokemon(PokemonCard {
             id: "A2 141".to_string(),
             name: "Chatot".to_string(),
             stage: 0,
@@ -10604,9 +10627,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2142FanRotom => Card::Pokemon(PokemonCard {
+        CardId::A2142FanRotom => CardData::Pokemon(PokemonCard {
             id: "A2 142".to_string(),
             name: "Fan Rotom".to_string(),
             stage: 0,
@@ -10625,9 +10648,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2143Regigigas => Card::Pokemon(PokemonCard {
+        CardId::A2143Regigigas => CardData::Pokemon(PokemonCard {
             id: "A2 143".to_string(),
             name: "Regigigas".to_string(),
             stage: 0,
@@ -10646,117 +10669,117 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2144SkullFossil => Card::Trainer(TrainerCard {
+        CardId::A2144SkullFossil => CardData::Trainer(TrainerCard {
             id: "A2 144".to_string(),
             numeric_id: 12432,
             name: "Skull Fossil".to_string(),
             effect: "Play this card as if it were a 40-HP Basic [C] Pokémon.At any time during your turn, you may discard this card from play.This card can't retreat.".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A2145ArmorFossil => Card::Trainer(TrainerCard {
+        CardId::A2145ArmorFossil => CardData::Trainer(TrainerCard {
             id: "A2 145".to_string(),
             numeric_id: 12433,
             name: "Armor Fossil".to_string(),
             effect: "Play this card as if it were a 40-HP Basic [C] Pokémon.At any time during your turn, you may discard this card from play.This card can't retreat.".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A2146PokemonCommunication => Card::Trainer(TrainerCard {
+        CardId::A2146PokemonCommunication => CardData::Trainer(TrainerCard {
             id: "A2 146".to_string(),
             numeric_id: 12434,
             name: "Pokémon Communication".to_string(),
             effect: "Choose a Pokémon in your hand and switch it with a random Pokémon in your deck.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A2147GiantCape => Card::Trainer(TrainerCard {
+        CardId::A2147GiantCape => CardData::Trainer(TrainerCard {
             id: "A2 147".to_string(),
             numeric_id: 12435,
             name: "Giant Cape".to_string(),
             effect: "The Pokémon this card is attached to gets +20 HP.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A2148RockyHelmet => Card::Trainer(TrainerCard {
+        CardId::A2148RockyHelmet => CardData::Trainer(TrainerCard {
             id: "A2 148".to_string(),
             numeric_id: 12436,
             name: "Rocky Helmet".to_string(),
             effect: "If the Pokémon this card is attached to is in the Active Spot and is damaged by an attack from your opponent's Pokémon, do 20 damage to the Attacking Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A2149LumBerry => Card::Trainer(TrainerCard {
+        CardId::A2149LumBerry => CardData::Trainer(TrainerCard {
             id: "A2 149".to_string(),
             numeric_id: 12437,
             name: "Lum Berry".to_string(),
             effect: "At the end of each turn, if the Pokémon this card is attached to is affected by any Special Conditions, it recovers from all of them, and discard this card.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A2150Cyrus => Card::Trainer(TrainerCard {
+        CardId::A2150Cyrus => CardData::Trainer(TrainerCard {
             id: "A2 150".to_string(),
             numeric_id: 12438,
             name: "Cyrus".to_string(),
             effect: "Switch in 1 of your opponent's Benched Pokémon that has damage on it to the Active Spot.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2151TeamGalacticGrunt => Card::Trainer(TrainerCard {
+        CardId::A2151TeamGalacticGrunt => CardData::Trainer(TrainerCard {
             id: "A2 151".to_string(),
             numeric_id: 12439,
             name: "Team Galactic Grunt".to_string(),
             effect: "Put 1 random Glameow, Stunky, or Croagunk from your deck into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2152Cynthia => Card::Trainer(TrainerCard {
+        CardId::A2152Cynthia => CardData::Trainer(TrainerCard {
             id: "A2 152".to_string(),
             numeric_id: 12440,
             name: "Cynthia".to_string(),
             effect: "During this turn, attacks used by your Garchomp or Togekiss do +50 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2153Volkner => Card::Trainer(TrainerCard {
+        CardId::A2153Volkner => CardData::Trainer(TrainerCard {
             id: "A2 153".to_string(),
             numeric_id: 12441,
             name: "Volkner".to_string(),
             effect: "Choose 1 of your Electivire or Luxray. Attach 2 [L] Energy from your discard pile to that Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2154Dawn => Card::Trainer(TrainerCard {
+        CardId::A2154Dawn => CardData::Trainer(TrainerCard {
             id: "A2 154".to_string(),
             numeric_id: 12442,
             name: "Dawn".to_string(),
             effect: "Move an Energy from 1 of your Benched Pokémon to your Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2155Mars => Card::Trainer(TrainerCard {
+        CardId::A2155Mars => CardData::Trainer(TrainerCard {
             id: "A2 155".to_string(),
             numeric_id: 12443,
             name: "Mars".to_string(),
             effect: "Your opponent shuffles their hand into their deck and draws a card for each of their remaining points needed to win.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2156Tangrowth => Card::Pokemon(PokemonCard {
+        CardId::A2156Tangrowth => CardData::Pokemon(PokemonCard {
             id: "A2 156".to_string(),
             name: "Tangrowth".to_string(),
             stage: 1,
@@ -10775,9 +10798,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2157Combee => Card::Pokemon(PokemonCard {
+        CardId::A2157Combee => CardData::Pokemon(PokemonCard {
             id: "A2 157".to_string(),
             name: "Combee".to_string(),
             stage: 0,
@@ -10796,9 +10819,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2158Carnivine => Card::Pokemon(PokemonCard {
+        CardId::A2158Carnivine => CardData::Pokemon(PokemonCard {
             id: "A2 158".to_string(),
             name: "Carnivine".to_string(),
             stage: 0,
@@ -10817,9 +10840,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2159Shaymin => Card::Pokemon(PokemonCard {
+        CardId::A2159Shaymin => CardData::Pokemon(PokemonCard {
             id: "A2 159".to_string(),
             name: "Shaymin".to_string(),
             stage: 0,
@@ -10838,9 +10861,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2160Mamoswine => Card::Pokemon(PokemonCard {
+        CardId::A2160Mamoswine => CardData::Pokemon(PokemonCard {
             id: "A2 160".to_string(),
             name: "Mamoswine".to_string(),
             stage: 2,
@@ -10859,9 +10882,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2161Gastrodon => Card::Pokemon(PokemonCard {
+        CardId::A2161Gastrodon => CardData::Pokemon(PokemonCard {
             id: "A2 161".to_string(),
             name: "Gastrodon".to_string(),
             stage: 1,
@@ -10880,9 +10903,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2162Manaphy => Card::Pokemon(PokemonCard {
+        CardId::A2162Manaphy => CardData::Pokemon(PokemonCard {
             id: "A2 162".to_string(),
             name: "Manaphy".to_string(),
             stage: 0,
@@ -10901,9 +10924,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2163Shinx => Card::Pokemon(PokemonCard {
+        CardId::A2163Shinx => CardData::Pokemon(PokemonCard {
             id: "A2 163".to_string(),
             name: "Shinx".to_string(),
             stage: 0,
@@ -10922,9 +10945,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2164Rotom => Card::Pokemon(PokemonCard {
+        CardId::A2164Rotom => CardData::Pokemon(PokemonCard {
             id: "A2 164".to_string(),
             name: "Rotom".to_string(),
             stage: 0,
@@ -10943,9 +10966,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2165Drifloon => Card::Pokemon(PokemonCard {
+        CardId::A2165Drifloon => CardData::Pokemon(PokemonCard {
             id: "A2 165".to_string(),
             name: "Drifloon".to_string(),
             stage: 0,
@@ -10964,9 +10987,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2166Mesprit => Card::Pokemon(PokemonCard {
+        CardId::A2166Mesprit => CardData::Pokemon(PokemonCard {
             id: "A2 166".to_string(),
             name: "Mesprit".to_string(),
             stage: 0,
@@ -10985,9 +11008,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2167Giratina => Card::Pokemon(PokemonCard {
+        CardId::A2167Giratina => CardData::Pokemon(PokemonCard {
             id: "A2 167".to_string(),
             name: "Giratina".to_string(),
             stage: 0,
@@ -11006,9 +11029,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2168Cresselia => Card::Pokemon(PokemonCard {
+        CardId::A2168Cresselia => CardData::Pokemon(PokemonCard {
             id: "A2 168".to_string(),
             name: "Cresselia".to_string(),
             stage: 0,
@@ -11027,9 +11050,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2169Rhyperior => Card::Pokemon(PokemonCard {
+        CardId::A2169Rhyperior => CardData::Pokemon(PokemonCard {
             id: "A2 169".to_string(),
             name: "Rhyperior".to_string(),
             stage: 2,
@@ -11048,9 +11071,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2170Lucario => Card::Pokemon(PokemonCard {
+        CardId::A2170Lucario => CardData::Pokemon(PokemonCard {
             id: "A2 170".to_string(),
             name: "Lucario".to_string(),
             stage: 1,
@@ -11069,9 +11092,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2171Hippopotas => Card::Pokemon(PokemonCard {
+        CardId::A2171Hippopotas => CardData::Pokemon(PokemonCard {
             id: "A2 171".to_string(),
             name: "Hippopotas".to_string(),
             stage: 0,
@@ -11090,9 +11113,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2172Spiritomb => Card::Pokemon(PokemonCard {
+        CardId::A2172Spiritomb => CardData::Pokemon(PokemonCard {
             id: "A2 172".to_string(),
             name: "Spiritomb".to_string(),
             stage: 0,
@@ -11111,9 +11134,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2173Croagunk => Card::Pokemon(PokemonCard {
+        CardId::A2173Croagunk => CardData::Pokemon(PokemonCard {
             id: "A2 173".to_string(),
             name: "Croagunk".to_string(),
             stage: 0,
@@ -11132,9 +11155,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2174Heatran => Card::Pokemon(PokemonCard {
+        CardId::A2174Heatran => CardData::Pokemon(PokemonCard {
             id: "A2 174".to_string(),
             name: "Heatran".to_string(),
             stage: 0,
@@ -11153,9 +11176,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2175Garchomp => Card::Pokemon(PokemonCard {
+        CardId::A2175Garchomp => CardData::Pokemon(PokemonCard {
             id: "A2 175".to_string(),
             name: "Garchomp".to_string(),
             stage: 2,
@@ -11174,9 +11197,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2176Staraptor => Card::Pokemon(PokemonCard {
+        CardId::A2176Staraptor => CardData::Pokemon(PokemonCard {
             id: "A2 176".to_string(),
             name: "Staraptor".to_string(),
             stage: 2,
@@ -11195,9 +11218,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2177Bidoof => Card::Pokemon(PokemonCard {
+        CardId::A2177Bidoof => CardData::Pokemon(PokemonCard {
             id: "A2 177".to_string(),
             name: "Bidoof".to_string(),
             stage: 0,
@@ -11216,9 +11239,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2178Glameow => Card::Pokemon(PokemonCard {
+        CardId::A2178Glameow => CardData::Pokemon(PokemonCard {
             id: "A2 178".to_string(),
             name: "Glameow".to_string(),
             stage: 0,
@@ -11237,9 +11260,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2179Regigigas => Card::Pokemon(PokemonCard {
+        CardId::A2179Regigigas => CardData::Pokemon(PokemonCard {
             id: "A2 179".to_string(),
             name: "Regigigas".to_string(),
             stage: 0,
@@ -11258,9 +11281,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2180YanmegaEx => Card::Pokemon(PokemonCard {
+        CardId::A2180YanmegaEx => CardData::Pokemon(PokemonCard {
             id: "A2 180".to_string(),
             name: "Yanmega ex".to_string(),
             stage: 1,
@@ -11279,9 +11302,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2181InfernapeEx => Card::Pokemon(PokemonCard {
+        CardId::A2181InfernapeEx => CardData::Pokemon(PokemonCard {
             id: "A2 181".to_string(),
             name: "Infernape ex".to_string(),
             stage: 2,
@@ -11300,9 +11323,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2182PalkiaEx => Card::Pokemon(PokemonCard {
+        CardId::A2182PalkiaEx => CardData::Pokemon(PokemonCard {
             id: "A2 182".to_string(),
             name: "Palkia ex".to_string(),
             stage: 0,
@@ -11327,9 +11350,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2183PachirisuEx => Card::Pokemon(PokemonCard {
+        CardId::A2183PachirisuEx => CardData::Pokemon(PokemonCard {
             id: "A2 183".to_string(),
             name: "Pachirisu ex".to_string(),
             stage: 0,
@@ -11348,9 +11371,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2184MismagiusEx => Card::Pokemon(PokemonCard {
+        CardId::A2184MismagiusEx => CardData::Pokemon(PokemonCard {
             id: "A2 184".to_string(),
             name: "Mismagius ex".to_string(),
             stage: 1,
@@ -11369,9 +11392,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2185GalladeEx => Card::Pokemon(PokemonCard {
+        CardId::A2185GalladeEx => CardData::Pokemon(PokemonCard {
             id: "A2 185".to_string(),
             name: "Gallade ex".to_string(),
             stage: 2,
@@ -11390,9 +11413,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2186WeavileEx => Card::Pokemon(PokemonCard {
+        CardId::A2186WeavileEx => CardData::Pokemon(PokemonCard {
             id: "A2 186".to_string(),
             name: "Weavile ex".to_string(),
             stage: 1,
@@ -11411,9 +11434,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2187DarkraiEx => Card::Pokemon(PokemonCard {
+        CardId::A2187DarkraiEx => CardData::Pokemon(PokemonCard {
             id: "A2 187".to_string(),
             name: "Darkrai ex".to_string(),
             stage: 0,
@@ -11432,9 +11455,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2188DialgaEx => Card::Pokemon(PokemonCard {
+        CardId::A2188DialgaEx => CardData::Pokemon(PokemonCard {
             id: "A2 188".to_string(),
             name: "Dialga ex".to_string(),
             stage: 0,
@@ -11459,9 +11482,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2189LickilickyEx => Card::Pokemon(PokemonCard {
+        CardId::A2189LickilickyEx => CardData::Pokemon(PokemonCard {
             id: "A2 189".to_string(),
             name: "Lickilicky ex".to_string(),
             stage: 1,
@@ -11480,63 +11503,63 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2190Cyrus => Card::Trainer(TrainerCard {
+        CardId::A2190Cyrus => CardData::Trainer(TrainerCard {
             id: "A2 190".to_string(),
             numeric_id: 12478,
             name: "Cyrus".to_string(),
             effect: "Switch in 1 of your opponent's Benched Pokémon that has damage on it to the Active Spot.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2191TeamGalacticGrunt => Card::Trainer(TrainerCard {
+        CardId::A2191TeamGalacticGrunt => CardData::Trainer(TrainerCard {
             id: "A2 191".to_string(),
             numeric_id: 12479,
             name: "Team Galactic Grunt".to_string(),
             effect: "Put 1 random Glameow, Stunky, or Croagunk from your deck into your hand.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2192Cynthia => Card::Trainer(TrainerCard {
+        CardId::A2192Cynthia => CardData::Trainer(TrainerCard {
             id: "A2 192".to_string(),
             numeric_id: 12480,
             name: "Cynthia".to_string(),
             effect: "During this turn, attacks used by your Garchomp or Togekiss do +50 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2193Volkner => Card::Trainer(TrainerCard {
+        CardId::A2193Volkner => CardData::Trainer(TrainerCard {
             id: "A2 193".to_string(),
             numeric_id: 12481,
             name: "Volkner".to_string(),
             effect: "Choose 1 of your Electivire or Luxray. Attach 2 [L] Energy from your discard pile to that Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2194Dawn => Card::Trainer(TrainerCard {
+        CardId::A2194Dawn => CardData::Trainer(TrainerCard {
             id: "A2 194".to_string(),
             numeric_id: 12482,
             name: "Dawn".to_string(),
             effect: "Move an Energy from 1 of your Benched Pokémon to your Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2195Mars => Card::Trainer(TrainerCard {
+        CardId::A2195Mars => CardData::Trainer(TrainerCard {
             id: "A2 195".to_string(),
             numeric_id: 12483,
             name: "Mars".to_string(),
             effect: "Your opponent shuffles their hand into their deck and draws a card for each of their remaining points needed to win.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2196YanmegaEx => Card::Pokemon(PokemonCard {
+        CardId::A2196YanmegaEx => CardData::Pokemon(PokemonCard {
             id: "A2 196".to_string(),
             name: "Yanmega ex".to_string(),
             stage: 1,
@@ -11555,9 +11578,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2197InfernapeEx => Card::Pokemon(PokemonCard {
+        CardId::A2197InfernapeEx => CardData::Pokemon(PokemonCard {
             id: "A2 197".to_string(),
             name: "Infernape ex".to_string(),
             stage: 2,
@@ -11576,9 +11599,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2198PachirisuEx => Card::Pokemon(PokemonCard {
+        CardId::A2198PachirisuEx => CardData::Pokemon(PokemonCard {
             id: "A2 198".to_string(),
             name: "Pachirisu ex".to_string(),
             stage: 0,
@@ -11597,9 +11620,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2199MismagiusEx => Card::Pokemon(PokemonCard {
+        CardId::A2199MismagiusEx => CardData::Pokemon(PokemonCard {
             id: "A2 199".to_string(),
             name: "Mismagius ex".to_string(),
             stage: 1,
@@ -11618,9 +11641,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2200GalladeEx => Card::Pokemon(PokemonCard {
+        CardId::A2200GalladeEx => CardData::Pokemon(PokemonCard {
             id: "A2 200".to_string(),
             name: "Gallade ex".to_string(),
             stage: 2,
@@ -11639,9 +11662,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2201WeavileEx => Card::Pokemon(PokemonCard {
+        CardId::A2201WeavileEx => CardData::Pokemon(PokemonCard {
             id: "A2 201".to_string(),
             name: "Weavile ex".to_string(),
             stage: 1,
@@ -11660,9 +11683,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2202DarkraiEx => Card::Pokemon(PokemonCard {
+        CardId::A2202DarkraiEx => CardData::Pokemon(PokemonCard {
             id: "A2 202".to_string(),
             name: "Darkrai ex".to_string(),
             stage: 0,
@@ -11681,9 +11704,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2203LickilickyEx => Card::Pokemon(PokemonCard {
+        CardId::A2203LickilickyEx => CardData::Pokemon(PokemonCard {
             id: "A2 203".to_string(),
             name: "Lickilicky ex".to_string(),
             stage: 1,
@@ -11702,9 +11725,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2204PalkiaEx => Card::Pokemon(PokemonCard {
+        CardId::A2204PalkiaEx => CardData::Pokemon(PokemonCard {
             id: "A2 204".to_string(),
             name: "Palkia ex".to_string(),
             stage: 0,
@@ -11729,9 +11752,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2205DialgaEx => Card::Pokemon(PokemonCard {
+        CardId::A2205DialgaEx => CardData::Pokemon(PokemonCard {
             id: "A2 205".to_string(),
             name: "Dialga ex".to_string(),
             stage: 0,
@@ -11756,9 +11779,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2206PalkiaEx => Card::Pokemon(PokemonCard {
+        CardId::A2206PalkiaEx => CardData::Pokemon(PokemonCard {
             id: "A2 206".to_string(),
             name: "Palkia ex".to_string(),
             stage: 0,
@@ -11783,9 +11806,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2207DialgaEx => Card::Pokemon(PokemonCard {
+        CardId::A2207DialgaEx => CardData::Pokemon(PokemonCard {
             id: "A2 207".to_string(),
             name: "Dialga ex".to_string(),
             stage: 0,
@@ -11810,9 +11833,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Space-Time Smackdown (A2)".to_string(),
+            set: SetId::A2,
         }),
-        CardId::A2a001Heracross => Card::Pokemon(PokemonCard {
+        CardId::A2a001Heracross => CardData::Pokemon(PokemonCard {
             id: "A2a 001".to_string(),
             name: "Heracross".to_string(),
             stage: 0,
@@ -11831,9 +11854,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a002Burmy => Card::Pokemon(PokemonCard {
+        CardId::A2a002Burmy => CardData::Pokemon(PokemonCard {
             id: "A2a 002".to_string(),
             name: "Burmy".to_string(),
             stage: 0,
@@ -11852,9 +11875,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a003Mothim => Card::Pokemon(PokemonCard {
+        CardId::A2a003Mothim => CardData::Pokemon(PokemonCard {
             id: "A2a 003".to_string(),
             name: "Mothim".to_string(),
             stage: 1,
@@ -11873,9 +11896,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a004Combee => Card::Pokemon(PokemonCard {
+        CardId::A2a004Combee => CardData::Pokemon(PokemonCard {
             id: "A2a 004".to_string(),
             name: "Combee".to_string(),
             stage: 0,
@@ -11894,9 +11917,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a005Vespiquen => Card::Pokemon(PokemonCard {
+        CardId::A2a005Vespiquen => CardData::Pokemon(PokemonCard {
             id: "A2a 005".to_string(),
             name: "Vespiquen".to_string(),
             stage: 1,
@@ -11915,9 +11938,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a006Cherubi => Card::Pokemon(PokemonCard {
+        CardId::A2a006Cherubi => CardData::Pokemon(PokemonCard {
             id: "A2a 006".to_string(),
             name: "Cherubi".to_string(),
             stage: 0,
@@ -11936,9 +11959,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a007Cherrim => Card::Pokemon(PokemonCard {
+        CardId::A2a007Cherrim => CardData::Pokemon(PokemonCard {
             id: "A2a 007".to_string(),
             name: "Cherrim".to_string(),
             stage: 1,
@@ -11957,9 +11980,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a008Cherrim => Card::Pokemon(PokemonCard {
+        CardId::A2a008Cherrim => CardData::Pokemon(PokemonCard {
             id: "A2a 008".to_string(),
             name: "Cherrim".to_string(),
             stage: 1,
@@ -11978,9 +12001,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a009Carnivine => Card::Pokemon(PokemonCard {
+        CardId::A2a009Carnivine => CardData::Pokemon(PokemonCard {
             id: "A2a 009".to_string(),
             name: "Carnivine".to_string(),
             stage: 0,
@@ -11999,9 +12022,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a010LeafeonEx => Card::Pokemon(PokemonCard {
+        CardId::A2a010LeafeonEx => CardData::Pokemon(PokemonCard {
             id: "A2a 010".to_string(),
             name: "Leafeon ex".to_string(),
             stage: 1,
@@ -12020,9 +12043,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a011Houndour => Card::Pokemon(PokemonCard {
+        CardId::A2a011Houndour => CardData::Pokemon(PokemonCard {
             id: "A2a 011".to_string(),
             name: "Houndour".to_string(),
             stage: 0,
@@ -12041,9 +12064,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a012Houndoom => Card::Pokemon(PokemonCard {
+        CardId::A2a012Houndoom => CardData::Pokemon(PokemonCard {
             id: "A2a 012".to_string(),
             name: "Houndoom".to_string(),
             stage: 1,
@@ -12062,9 +12085,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a013Heatran => Card::Pokemon(PokemonCard {
+        CardId::A2a013Heatran => CardData::Pokemon(PokemonCard {
             id: "A2a 013".to_string(),
             name: "Heatran".to_string(),
             stage: 0,
@@ -12083,9 +12106,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a014Marill => Card::Pokemon(PokemonCard {
+        CardId::A2a014Marill => CardData::Pokemon(PokemonCard {
             id: "A2a 014".to_string(),
             name: "Marill".to_string(),
             stage: 0,
@@ -12104,9 +12127,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a015Azumarill => Card::Pokemon(PokemonCard {
+        CardId::A2a015Azumarill => CardData::Pokemon(PokemonCard {
             id: "A2a 015".to_string(),
             name: "Azumarill".to_string(),
             stage: 1,
@@ -12125,9 +12148,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a016Barboach => Card::Pokemon(PokemonCard {
+        CardId::A2a016Barboach => CardData::Pokemon(PokemonCard {
             id: "A2a 016".to_string(),
             name: "Barboach".to_string(),
             stage: 0,
@@ -12146,9 +12169,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a017Whiscash => Card::Pokemon(PokemonCard {
+        CardId::A2a017Whiscash => CardData::Pokemon(PokemonCard {
             id: "A2a 017".to_string(),
             name: "Whiscash".to_string(),
             stage: 1,
@@ -12167,9 +12190,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a018Snorunt => Card::Pokemon(PokemonCard {
+        CardId::A2a018Snorunt => CardData::Pokemon(PokemonCard {
             id: "A2a 018".to_string(),
             name: "Snorunt".to_string(),
             stage: 0,
@@ -12188,9 +12211,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a019Froslass => Card::Pokemon(PokemonCard {
+        CardId::A2a019Froslass => CardData::Pokemon(PokemonCard {
             id: "A2a 019".to_string(),
             name: "Froslass".to_string(),
             stage: 1,
@@ -12209,9 +12232,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a020Snover => Card::Pokemon(PokemonCard {
+        CardId::A2a020Snover => CardData::Pokemon(PokemonCard {
             id: "A2a 020".to_string(),
             name: "Snover".to_string(),
             stage: 0,
@@ -12230,9 +12253,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a021Abomasnow => Card::Pokemon(PokemonCard {
+        CardId::A2a021Abomasnow => CardData::Pokemon(PokemonCard {
             id: "A2a 021".to_string(),
             name: "Abomasnow".to_string(),
             stage: 1,
@@ -12251,9 +12274,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a022GlaceonEx => Card::Pokemon(PokemonCard {
+        CardId::A2a022GlaceonEx => CardData::Pokemon(PokemonCard {
             id: "A2a 022".to_string(),
             name: "Glaceon ex".to_string(),
             stage: 1,
@@ -12272,9 +12295,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a023OriginFormePalkia => Card::Pokemon(PokemonCard {
+        CardId::A2a023OriginFormePalkia => CardData::Pokemon(PokemonCard {
             id: "A2a 023".to_string(),
             name: "Origin Forme Palkia".to_string(),
             stage: 0,
@@ -12293,9 +12316,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a024Phione => Card::Pokemon(PokemonCard {
+        CardId::A2a024Phione => CardData::Pokemon(PokemonCard {
             id: "A2a 024".to_string(),
             name: "Phione".to_string(),
             stage: 0,
@@ -12314,9 +12337,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a025Pikachu => Card::Pokemon(PokemonCard {
+        CardId::A2a025Pikachu => CardData::Pokemon(PokemonCard {
             id: "A2a 025".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -12335,9 +12358,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a026Raichu => Card::Pokemon(PokemonCard {
+        CardId::A2a026Raichu => CardData::Pokemon(PokemonCard {
             id: "A2a 026".to_string(),
             name: "Raichu".to_string(),
             stage: 1,
@@ -12356,9 +12379,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a027Electrike => Card::Pokemon(PokemonCard {
+        CardId::A2a027Electrike => CardData::Pokemon(PokemonCard {
             id: "A2a 027".to_string(),
             name: "Electrike".to_string(),
             stage: 0,
@@ -12377,9 +12400,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a028Manectric => Card::Pokemon(PokemonCard {
+        CardId::A2a028Manectric => CardData::Pokemon(PokemonCard {
             id: "A2a 028".to_string(),
             name: "Manectric".to_string(),
             stage: 1,
@@ -12398,9 +12421,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a029Clefairy => Card::Pokemon(PokemonCard {
+        CardId::A2a029Clefairy => CardData::Pokemon(PokemonCard {
             id: "A2a 029".to_string(),
             name: "Clefairy".to_string(),
             stage: 0,
@@ -12419,9 +12442,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a030Clefable => Card::Pokemon(PokemonCard {
+        CardId::A2a030Clefable => CardData::Pokemon(PokemonCard {
             id: "A2a 030".to_string(),
             name: "Clefable".to_string(),
             stage: 1,
@@ -12440,9 +12463,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a031Gastly => Card::Pokemon(PokemonCard {
+        CardId::A2a031Gastly => CardData::Pokemon(PokemonCard {
             id: "A2a 031".to_string(),
             name: "Gastly".to_string(),
             stage: 0,
@@ -12461,9 +12484,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a032Haunter => Card::Pokemon(PokemonCard {
+        CardId::A2a032Haunter => CardData::Pokemon(PokemonCard {
             id: "A2a 032".to_string(),
             name: "Haunter".to_string(),
             stage: 1,
@@ -12482,9 +12505,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a033Gengar => Card::Pokemon(PokemonCard {
+        CardId::A2a033Gengar => CardData::Pokemon(PokemonCard {
             id: "A2a 033".to_string(),
             name: "Gengar".to_string(),
             stage: 2,
@@ -12503,9 +12526,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a034Unown => Card::Pokemon(PokemonCard {
+        CardId::A2a034Unown => CardData::Pokemon(PokemonCard {
             id: "A2a 034".to_string(),
             name: "Unown".to_string(),
             stage: 0,
@@ -12524,9 +12547,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a035Rotom => Card::Pokemon(PokemonCard {
+        CardId::A2a035Rotom => CardData::Pokemon(PokemonCard {
             id: "A2a 035".to_string(),
             name: "Rotom".to_string(),
             stage: 0,
@@ -12545,9 +12568,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a036Sudowoodo => Card::Pokemon(PokemonCard {
+        CardId::A2a036Sudowoodo => CardData::Pokemon(PokemonCard {
             id: "A2a 036".to_string(),
             name: "Sudowoodo".to_string(),
             stage: 0,
@@ -12566,9 +12589,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a037Phanpy => Card::Pokemon(PokemonCard {
+        CardId::A2a037Phanpy => CardData::Pokemon(PokemonCard {
             id: "A2a 037".to_string(),
             name: "Phanpy".to_string(),
             stage: 0,
@@ -12587,9 +12610,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a038Donphan => Card::Pokemon(PokemonCard {
+        CardId::A2a038Donphan => CardData::Pokemon(PokemonCard {
             id: "A2a 038".to_string(),
             name: "Donphan".to_string(),
             stage: 1,
@@ -12608,9 +12631,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a039Larvitar => Card::Pokemon(PokemonCard {
+        CardId::A2a039Larvitar => CardData::Pokemon(PokemonCard {
             id: "A2a 039".to_string(),
             name: "Larvitar".to_string(),
             stage: 0,
@@ -12629,9 +12652,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a040Pupitar => Card::Pokemon(PokemonCard {
+        CardId::A2a040Pupitar => CardData::Pokemon(PokemonCard {
             id: "A2a 040".to_string(),
             name: "Pupitar".to_string(),
             stage: 1,
@@ -12650,9 +12673,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a041Tyranitar => Card::Pokemon(PokemonCard {
+        CardId::A2a041Tyranitar => CardData::Pokemon(PokemonCard {
             id: "A2a 041".to_string(),
             name: "Tyranitar".to_string(),
             stage: 2,
@@ -12671,9 +12694,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a042Nosepass => Card::Pokemon(PokemonCard {
+        CardId::A2a042Nosepass => CardData::Pokemon(PokemonCard {
             id: "A2a 042".to_string(),
             name: "Nosepass".to_string(),
             stage: 0,
@@ -12692,9 +12715,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a043Meditite => Card::Pokemon(PokemonCard {
+        CardId::A2a043Meditite => CardData::Pokemon(PokemonCard {
             id: "A2a 043".to_string(),
             name: "Meditite".to_string(),
             stage: 0,
@@ -12713,9 +12736,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a044Medicham => Card::Pokemon(PokemonCard {
+        CardId::A2a044Medicham => CardData::Pokemon(PokemonCard {
             id: "A2a 044".to_string(),
             name: "Medicham".to_string(),
             stage: 1,
@@ -12734,9 +12757,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a045Gible => Card::Pokemon(PokemonCard {
+        CardId::A2a045Gible => CardData::Pokemon(PokemonCard {
             id: "A2a 045".to_string(),
             name: "Gible".to_string(),
             stage: 0,
@@ -12755,9 +12778,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a046Gabite => Card::Pokemon(PokemonCard {
+        CardId::A2a046Gabite => CardData::Pokemon(PokemonCard {
             id: "A2a 046".to_string(),
             name: "Gabite".to_string(),
             stage: 1,
@@ -12776,9 +12799,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a047GarchompEx => Card::Pokemon(PokemonCard {
+        CardId::A2a047GarchompEx => CardData::Pokemon(PokemonCard {
             id: "A2a 047".to_string(),
             name: "Garchomp ex".to_string(),
             stage: 2,
@@ -12803,9 +12826,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a048Zubat => Card::Pokemon(PokemonCard {
+        CardId::A2a048Zubat => CardData::Pokemon(PokemonCard {
             id: "A2a 048".to_string(),
             name: "Zubat".to_string(),
             stage: 0,
@@ -12824,9 +12847,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a049Golbat => Card::Pokemon(PokemonCard {
+        CardId::A2a049Golbat => CardData::Pokemon(PokemonCard {
             id: "A2a 049".to_string(),
             name: "Golbat".to_string(),
             stage: 1,
@@ -12845,9 +12868,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a050Crobat => Card::Pokemon(PokemonCard {
+        CardId::A2a050Crobat => CardData::Pokemon(PokemonCard {
             id: "A2a 050".to_string(),
             name: "Crobat".to_string(),
             stage: 2,
@@ -12866,9 +12889,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a051Croagunk => Card::Pokemon(PokemonCard {
+        CardId::A2a051Croagunk => CardData::Pokemon(PokemonCard {
             id: "A2a 051".to_string(),
             name: "Croagunk".to_string(),
             stage: 0,
@@ -12887,9 +12910,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a052Toxicroak => Card::Pokemon(PokemonCard {
+        CardId::A2a052Toxicroak => CardData::Pokemon(PokemonCard {
             id: "A2a 052".to_string(),
             name: "Toxicroak".to_string(),
             stage: 1,
@@ -12908,9 +12931,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a053Magnemite => Card::Pokemon(PokemonCard {
+        CardId::A2a053Magnemite => CardData::Pokemon(PokemonCard {
             id: "A2a 053".to_string(),
             name: "Magnemite".to_string(),
             stage: 0,
@@ -12929,9 +12952,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a054Magneton => Card::Pokemon(PokemonCard {
+        CardId::A2a054Magneton => CardData::Pokemon(PokemonCard {
             id: "A2a 054".to_string(),
             name: "Magneton".to_string(),
             stage: 1,
@@ -12950,9 +12973,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a055Magnezone => Card::Pokemon(PokemonCard {
+        CardId::A2a055Magnezone => CardData::Pokemon(PokemonCard {
             id: "A2a 055".to_string(),
             name: "Magnezone".to_string(),
             stage: 2,
@@ -12971,9 +12994,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a056Mawile => Card::Pokemon(PokemonCard {
+        CardId::A2a056Mawile => CardData::Pokemon(PokemonCard {
             id: "A2a 056".to_string(),
             name: "Mawile".to_string(),
             stage: 0,
@@ -12992,9 +13015,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a057ProbopassEx => Card::Pokemon(PokemonCard {
+        CardId::A2a057ProbopassEx => CardData::Pokemon(PokemonCard {
             id: "A2a 057".to_string(),
             name: "Probopass ex".to_string(),
             stage: 1,
@@ -13013,9 +13036,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a058Bronzor => Card::Pokemon(PokemonCard {
+        CardId::A2a058Bronzor => CardData::Pokemon(PokemonCard {
             id: "A2a 058".to_string(),
             name: "Bronzor".to_string(),
             stage: 0,
@@ -13034,9 +13057,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a059Bronzong => Card::Pokemon(PokemonCard {
+        CardId::A2a059Bronzong => CardData::Pokemon(PokemonCard {
             id: "A2a 059".to_string(),
             name: "Bronzong".to_string(),
             stage: 1,
@@ -13055,9 +13078,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a060OriginFormeDialga => Card::Pokemon(PokemonCard {
+        CardId::A2a060OriginFormeDialga => CardData::Pokemon(PokemonCard {
             id: "A2a 060".to_string(),
             name: "Origin Forme Dialga".to_string(),
             stage: 0,
@@ -13076,9 +13099,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a061Giratina => Card::Pokemon(PokemonCard {
+        CardId::A2a061Giratina => CardData::Pokemon(PokemonCard {
             id: "A2a 061".to_string(),
             name: "Giratina".to_string(),
             stage: 0,
@@ -13097,9 +13120,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a062Eevee => Card::Pokemon(PokemonCard {
+        CardId::A2a062Eevee => CardData::Pokemon(PokemonCard {
             id: "A2a 062".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -13118,9 +13141,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a063Snorlax => Card::Pokemon(PokemonCard {
+        CardId::A2a063Snorlax => CardData::Pokemon(PokemonCard {
             id: "A2a 063".to_string(),
             name: "Snorlax".to_string(),
             stage: 0,
@@ -13139,9 +13162,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a064Hoothoot => Card::Pokemon(PokemonCard {
+        CardId::A2a064Hoothoot => CardData::Pokemon(PokemonCard {
             id: "A2a 064".to_string(),
             name: "Hoothoot".to_string(),
             stage: 0,
@@ -13160,9 +13183,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a065Noctowl => Card::Pokemon(PokemonCard {
+        CardId::A2a065Noctowl => CardData::Pokemon(PokemonCard {
             id: "A2a 065".to_string(),
             name: "Noctowl".to_string(),
             stage: 1,
@@ -13181,9 +13204,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a066Starly => Card::Pokemon(PokemonCard {
+        CardId::A2a066Starly => CardData::Pokemon(PokemonCard {
             id: "A2a 066".to_string(),
             name: "Starly".to_string(),
             stage: 0,
@@ -13202,9 +13225,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a067Staravia => Card::Pokemon(PokemonCard {
+        CardId::A2a067Staravia => CardData::Pokemon(PokemonCard {
             id: "A2a 067".to_string(),
             name: "Staravia".to_string(),
             stage: 1,
@@ -13223,9 +13246,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a068Staraptor => Card::Pokemon(PokemonCard {
+        CardId::A2a068Staraptor => CardData::Pokemon(PokemonCard {
             id: "A2a 068".to_string(),
             name: "Staraptor".to_string(),
             stage: 2,
@@ -13244,9 +13267,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a069Shaymin => Card::Pokemon(PokemonCard {
+        CardId::A2a069Shaymin => CardData::Pokemon(PokemonCard {
             id: "A2a 069".to_string(),
             name: "Shaymin".to_string(),
             stage: 0,
@@ -13265,9 +13288,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a070Arceus => Card::Pokemon(PokemonCard {
+        CardId::A2a070Arceus => CardData::Pokemon(PokemonCard {
             id: "A2a 070".to_string(),
             name: "Arceus".to_string(),
             stage: 0,
@@ -13286,9 +13309,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a071ArceusEx => Card::Pokemon(PokemonCard {
+        CardId::A2a071ArceusEx => CardData::Pokemon(PokemonCard {
             id: "A2a 071".to_string(),
             name: "Arceus ex".to_string(),
             stage: 0,
@@ -13307,45 +13330,45 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a072Irida => Card::Trainer(TrainerCard {
+        CardId::A2a072Irida => CardData::Trainer(TrainerCard {
             id: "A2a 072".to_string(),
             numeric_id: 16456,
             name: "Irida".to_string(),
             effect: "Heal 40 damage from each of your Pokémon that has any [W] Energy attached.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a073CelesticTownElder => Card::Trainer(TrainerCard {
+        CardId::A2a073CelesticTownElder => CardData::Trainer(TrainerCard {
             id: "A2a 073".to_string(),
             numeric_id: 16457,
             name: "Celestic Town Elder".to_string(),
             effect: "Put 1 random Basic Pokémon from your discard pile into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a074Barry => Card::Trainer(TrainerCard {
+        CardId::A2a074Barry => CardData::Trainer(TrainerCard {
             id: "A2a 074".to_string(),
             numeric_id: 16458,
             name: "Barry".to_string(),
             effect: "During this turn, attacks used by your Snorlax, Heracross, and Staraptor cost 2 less [C] Energy.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a075Adaman => Card::Trainer(TrainerCard {
+        CardId::A2a075Adaman => CardData::Trainer(TrainerCard {
             id: "A2a 075".to_string(),
             numeric_id: 16459,
             name: "Adaman".to_string(),
             effect: "During your opponent's next turn, all of your [M] Pokémon take −20 damage from attacks from your opponent's Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a076Houndoom => Card::Pokemon(PokemonCard {
+        CardId::A2a076Houndoom => CardData::Pokemon(PokemonCard {
             id: "A2a 076".to_string(),
             name: "Houndoom".to_string(),
             stage: 1,
@@ -13364,9 +13387,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a077Marill => Card::Pokemon(PokemonCard {
+        CardId::A2a077Marill => CardData::Pokemon(PokemonCard {
             id: "A2a 077".to_string(),
             name: "Marill".to_string(),
             stage: 0,
@@ -13385,9 +13408,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a078Unown => Card::Pokemon(PokemonCard {
+        CardId::A2a078Unown => CardData::Pokemon(PokemonCard {
             id: "A2a 078".to_string(),
             name: "Unown".to_string(),
             stage: 0,
@@ -13406,9 +13429,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a079Sudowoodo => Card::Pokemon(PokemonCard {
+        CardId::A2a079Sudowoodo => CardData::Pokemon(PokemonCard {
             id: "A2a 079".to_string(),
             name: "Sudowoodo".to_string(),
             stage: 0,
@@ -13427,9 +13450,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a080Magnemite => Card::Pokemon(PokemonCard {
+        CardId::A2a080Magnemite => CardData::Pokemon(PokemonCard {
             id: "A2a 080".to_string(),
             name: "Magnemite".to_string(),
             stage: 0,
@@ -13448,9 +13471,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a081Shaymin => Card::Pokemon(PokemonCard {
+        CardId::A2a081Shaymin => CardData::Pokemon(PokemonCard {
             id: "A2a 081".to_string(),
             name: "Shaymin".to_string(),
             stage: 0,
@@ -13469,9 +13492,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a082LeafeonEx => Card::Pokemon(PokemonCard {
+        CardId::A2a082LeafeonEx => CardData::Pokemon(PokemonCard {
             id: "A2a 082".to_string(),
             name: "Leafeon ex".to_string(),
             stage: 1,
@@ -13490,9 +13513,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a083GlaceonEx => Card::Pokemon(PokemonCard {
+        CardId::A2a083GlaceonEx => CardData::Pokemon(PokemonCard {
             id: "A2a 083".to_string(),
             name: "Glaceon ex".to_string(),
             stage: 1,
@@ -13511,9 +13534,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a084GarchompEx => Card::Pokemon(PokemonCard {
+        CardId::A2a084GarchompEx => CardData::Pokemon(PokemonCard {
             id: "A2a 084".to_string(),
             name: "Garchomp ex".to_string(),
             stage: 2,
@@ -13538,9 +13561,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a085ProbopassEx => Card::Pokemon(PokemonCard {
+        CardId::A2a085ProbopassEx => CardData::Pokemon(PokemonCard {
             id: "A2a 085".to_string(),
             name: "Probopass ex".to_string(),
             stage: 1,
@@ -13559,9 +13582,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a086ArceusEx => Card::Pokemon(PokemonCard {
+        CardId::A2a086ArceusEx => CardData::Pokemon(PokemonCard {
             id: "A2a 086".to_string(),
             name: "Arceus ex".to_string(),
             stage: 0,
@@ -13580,45 +13603,45 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a087Irida => Card::Trainer(TrainerCard {
+        CardId::A2a087Irida => CardData::Trainer(TrainerCard {
             id: "A2a 087".to_string(),
             numeric_id: 16471,
             name: "Irida".to_string(),
             effect: "Heal 40 damage from each of your Pokémon that has any [W] Energy attached.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a088CelesticTownElder => Card::Trainer(TrainerCard {
+        CardId::A2a088CelesticTownElder => CardData::Trainer(TrainerCard {
             id: "A2a 088".to_string(),
             numeric_id: 16472,
             name: "Celestic Town Elder".to_string(),
             effect: "Put 1 random Basic Pokémon from your discard pile into your hand.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a089Barry => Card::Trainer(TrainerCard {
+        CardId::A2a089Barry => CardData::Trainer(TrainerCard {
             id: "A2a 089".to_string(),
             numeric_id: 16473,
             name: "Barry".to_string(),
             effect: "During this turn, attacks used by your Snorlax, Heracross, and Staraptor cost 2 less [C] Energy.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a090Adaman => Card::Trainer(TrainerCard {
+        CardId::A2a090Adaman => CardData::Trainer(TrainerCard {
             id: "A2a 090".to_string(),
             numeric_id: 16474,
             name: "Adaman".to_string(),
             effect: "During your opponent's next turn, all of your [M] Pokémon take −20 damage from attacks from your opponent's Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2a091LeafeonEx => Card::Pokemon(PokemonCard {
+        CardId::A2a091LeafeonEx => CardData::Pokemon(PokemonCard {
             id: "A2a 091".to_string(),
             name: "Leafeon ex".to_string(),
             stage: 1,
@@ -13637,9 +13660,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a092GlaceonEx => Card::Pokemon(PokemonCard {
+        CardId::A2a092GlaceonEx => CardData::Pokemon(PokemonCard {
             id: "A2a 092".to_string(),
             name: "Glaceon ex".to_string(),
             stage: 1,
@@ -13658,9 +13681,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a093GarchompEx => Card::Pokemon(PokemonCard {
+        CardId::A2a093GarchompEx => CardData::Pokemon(PokemonCard {
             id: "A2a 093".to_string(),
             name: "Garchomp ex".to_string(),
             stage: 2,
@@ -13685,9 +13708,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a094ProbopassEx => Card::Pokemon(PokemonCard {
+        CardId::A2a094ProbopassEx => CardData::Pokemon(PokemonCard {
             id: "A2a 094".to_string(),
             name: "Probopass ex".to_string(),
             stage: 1,
@@ -13706,9 +13729,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a095ArceusEx => Card::Pokemon(PokemonCard {
+        CardId::A2a095ArceusEx => CardData::Pokemon(PokemonCard {
             id: "A2a 095".to_string(),
             name: "Arceus ex".to_string(),
             stage: 0,
@@ -13727,9 +13750,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2a096ArceusEx => Card::Pokemon(PokemonCard {
+        CardId::A2a096ArceusEx => CardData::Pokemon(PokemonCard {
             id: "A2a 096".to_string(),
             name: "Arceus ex".to_string(),
             stage: 0,
@@ -13748,9 +13771,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Triumphant Light (A2a)".to_string(),
+            set: SetId::A2a,
         }),
-        CardId::A2b001Weedle => Card::Pokemon(PokemonCard {
+        CardId::A2b001Weedle => CardData::Pokemon(PokemonCard {
             id: "A2b 001".to_string(),
             name: "Weedle".to_string(),
             stage: 0,
@@ -13769,9 +13792,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b002Kakuna => Card::Pokemon(PokemonCard {
+        CardId::A2b002Kakuna => CardData::Pokemon(PokemonCard {
             id: "A2b 002".to_string(),
             name: "Kakuna".to_string(),
             stage: 1,
@@ -13790,9 +13813,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b003BeedrillEx => Card::Pokemon(PokemonCard {
+        CardId::A2b003BeedrillEx => CardData::Pokemon(PokemonCard {
             id: "A2b 003".to_string(),
             name: "Beedrill ex".to_string(),
             stage: 2,
@@ -13811,9 +13834,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b004Pinsir => Card::Pokemon(PokemonCard {
+        CardId::A2b004Pinsir => CardData::Pokemon(PokemonCard {
             id: "A2b 004".to_string(),
             name: "Pinsir".to_string(),
             stage: 0,
@@ -13832,9 +13855,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b005Sprigatito => Card::Pokemon(PokemonCard {
+        CardId::A2b005Sprigatito => CardData::Pokemon(PokemonCard {
             id: "A2b 005".to_string(),
             name: "Sprigatito".to_string(),
             stage: 0,
@@ -13853,9 +13876,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b006Floragato => Card::Pokemon(PokemonCard {
+        CardId::A2b006Floragato => CardData::Pokemon(PokemonCard {
             id: "A2b 006".to_string(),
             name: "Floragato".to_string(),
             stage: 1,
@@ -13874,9 +13897,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b007Meowscarada => Card::Pokemon(PokemonCard {
+        CardId::A2b007Meowscarada => CardData::Pokemon(PokemonCard {
             id: "A2b 007".to_string(),
             name: "Meowscarada".to_string(),
             stage: 2,
@@ -13895,9 +13918,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b008Charmander => Card::Pokemon(PokemonCard {
+        CardId::A2b008Charmander => CardData::Pokemon(PokemonCard {
             id: "A2b 008".to_string(),
             name: "Charmander".to_string(),
             stage: 0,
@@ -13916,9 +13939,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b009Charmeleon => Card::Pokemon(PokemonCard {
+        CardId::A2b009Charmeleon => CardData::Pokemon(PokemonCard {
             id: "A2b 009".to_string(),
             name: "Charmeleon".to_string(),
             stage: 1,
@@ -13937,9 +13960,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b010CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A2b010CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A2b 010".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -13964,9 +13987,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b011Magmar => Card::Pokemon(PokemonCard {
+        CardId::A2b011Magmar => CardData::Pokemon(PokemonCard {
             id: "A2b 011".to_string(),
             name: "Magmar".to_string(),
             stage: 0,
@@ -13985,9 +14008,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b012Magmortar => Card::Pokemon(PokemonCard {
+        CardId::A2b012Magmortar => CardData::Pokemon(PokemonCard {
             id: "A2b 012".to_string(),
             name: "Magmortar".to_string(),
             stage: 1,
@@ -14006,9 +14029,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b013PaldeanTauros => Card::Pokemon(PokemonCard {
+        CardId::A2b013PaldeanTauros => CardData::Pokemon(PokemonCard {
             id: "A2b 013".to_string(),
             name: "Paldean Tauros".to_string(),
             stage: 0,
@@ -14027,9 +14050,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b014Tentacool => Card::Pokemon(PokemonCard {
+        CardId::A2b014Tentacool => CardData::Pokemon(PokemonCard {
             id: "A2b 014".to_string(),
             name: "Tentacool".to_string(),
             stage: 0,
@@ -14048,9 +14071,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b015Tentacruel => Card::Pokemon(PokemonCard {
+        CardId::A2b015Tentacruel => CardData::Pokemon(PokemonCard {
             id: "A2b 015".to_string(),
             name: "Tentacruel".to_string(),
             stage: 1,
@@ -14069,9 +14092,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b016Buizel => Card::Pokemon(PokemonCard {
+        CardId::A2b016Buizel => CardData::Pokemon(PokemonCard {
             id: "A2b 016".to_string(),
             name: "Buizel".to_string(),
             stage: 0,
@@ -14090,9 +14113,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b017Floatzel => Card::Pokemon(PokemonCard {
+        CardId::A2b017Floatzel => CardData::Pokemon(PokemonCard {
             id: "A2b 017".to_string(),
             name: "Floatzel".to_string(),
             stage: 1,
@@ -14111,9 +14134,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b018Wiglett => Card::Pokemon(PokemonCard {
+        CardId::A2b018Wiglett => CardData::Pokemon(PokemonCard {
             id: "A2b 018".to_string(),
             name: "Wiglett".to_string(),
             stage: 0,
@@ -14132,9 +14155,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b019WugtrioEx => Card::Pokemon(PokemonCard {
+        CardId::A2b019WugtrioEx => CardData::Pokemon(PokemonCard {
             id: "A2b 019".to_string(),
             name: "Wugtrio ex".to_string(),
             stage: 1,
@@ -14153,9 +14176,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b020Dondozo => Card::Pokemon(PokemonCard {
+        CardId::A2b020Dondozo => CardData::Pokemon(PokemonCard {
             id: "A2b 020".to_string(),
             name: "Dondozo".to_string(),
             stage: 0,
@@ -14174,9 +14197,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b021Tatsugiri => Card::Pokemon(PokemonCard {
+        CardId::A2b021Tatsugiri => CardData::Pokemon(PokemonCard {
             id: "A2b 021".to_string(),
             name: "Tatsugiri".to_string(),
             stage: 0,
@@ -14195,9 +14218,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b022PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A2b022PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A2b 022".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -14216,9 +14239,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b023Voltorb => Card::Pokemon(PokemonCard {
+        CardId::A2b023Voltorb => CardData::Pokemon(PokemonCard {
             id: "A2b 023".to_string(),
             name: "Voltorb".to_string(),
             stage: 0,
@@ -14237,9 +14260,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b024Electrode => Card::Pokemon(PokemonCard {
+        CardId::A2b024Electrode => CardData::Pokemon(PokemonCard {
             id: "A2b 024".to_string(),
             name: "Electrode".to_string(),
             stage: 1,
@@ -14258,9 +14281,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b025Pachirisu => Card::Pokemon(PokemonCard {
+        CardId::A2b025Pachirisu => CardData::Pokemon(PokemonCard {
             id: "A2b 025".to_string(),
             name: "Pachirisu".to_string(),
             stage: 0,
@@ -14279,9 +14302,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b026Pawmi => Card::Pokemon(PokemonCard {
+        CardId::A2b026Pawmi => CardData::Pokemon(PokemonCard {
             id: "A2b 026".to_string(),
             name: "Pawmi".to_string(),
             stage: 0,
@@ -14300,9 +14323,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b027Pawmo => Card::Pokemon(PokemonCard {
+        CardId::A2b027Pawmo => CardData::Pokemon(PokemonCard {
             id: "A2b 027".to_string(),
             name: "Pawmo".to_string(),
             stage: 1,
@@ -14321,9 +14344,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b028Pawmot => Card::Pokemon(PokemonCard {
+        CardId::A2b028Pawmot => CardData::Pokemon(PokemonCard {
             id: "A2b 028".to_string(),
             name: "Pawmot".to_string(),
             stage: 2,
@@ -14342,9 +14365,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b029Abra => Card::Pokemon(PokemonCard {
+        CardId::A2b029Abra => CardData::Pokemon(PokemonCard {
             id: "A2b 029".to_string(),
             name: "Abra".to_string(),
             stage: 0,
@@ -14363,9 +14386,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b030Kadabra => Card::Pokemon(PokemonCard {
+        CardId::A2b030Kadabra => CardData::Pokemon(PokemonCard {
             id: "A2b 030".to_string(),
             name: "Kadabra".to_string(),
             stage: 1,
@@ -14384,9 +14407,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b031Alakazam => Card::Pokemon(PokemonCard {
+        CardId::A2b031Alakazam => CardData::Pokemon(PokemonCard {
             id: "A2b 031".to_string(),
             name: "Alakazam".to_string(),
             stage: 2,
@@ -14405,9 +14428,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b032MrMime => Card::Pokemon(PokemonCard {
+        CardId::A2b032MrMime => CardData::Pokemon(PokemonCard {
             id: "A2b 032".to_string(),
             name: "Mr. Mime".to_string(),
             stage: 0,
@@ -14426,9 +14449,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b033Drifloon => Card::Pokemon(PokemonCard {
+        CardId::A2b033Drifloon => CardData::Pokemon(PokemonCard {
             id: "A2b 033".to_string(),
             name: "Drifloon".to_string(),
             stage: 0,
@@ -14447,9 +14470,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b034Drifblim => Card::Pokemon(PokemonCard {
+        CardId::A2b034Drifblim => CardData::Pokemon(PokemonCard {
             id: "A2b 034".to_string(),
             name: "Drifblim".to_string(),
             stage: 1,
@@ -14468,9 +14491,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b035GiratinaEx => Card::Pokemon(PokemonCard {
+        CardId::A2b035GiratinaEx => CardData::Pokemon(PokemonCard {
             id: "A2b 035".to_string(),
             name: "Giratina ex".to_string(),
             stage: 0,
@@ -14489,9 +14512,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b036Gimmighoul => Card::Pokemon(PokemonCard {
+        CardId::A2b036Gimmighoul => CardData::Pokemon(PokemonCard {
             id: "A2b 036".to_string(),
             name: "Gimmighoul".to_string(),
             stage: 0,
@@ -14510,9 +14533,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b037Machop => Card::Pokemon(PokemonCard {
+        CardId::A2b037Machop => CardData::Pokemon(PokemonCard {
             id: "A2b 037".to_string(),
             name: "Machop".to_string(),
             stage: 0,
@@ -14531,9 +14554,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b038Machoke => Card::Pokemon(PokemonCard {
+        CardId::A2b038Machoke => CardData::Pokemon(PokemonCard {
             id: "A2b 038".to_string(),
             name: "Machoke".to_string(),
             stage: 1,
@@ -14552,9 +14575,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b039Machamp => Card::Pokemon(PokemonCard {
+        CardId::A2b039Machamp => CardData::Pokemon(PokemonCard {
             id: "A2b 039".to_string(),
             name: "Machamp".to_string(),
             stage: 2,
@@ -14573,9 +14596,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b040Hitmonlee => Card::Pokemon(PokemonCard {
+        CardId::A2b040Hitmonlee => CardData::Pokemon(PokemonCard {
             id: "A2b 040".to_string(),
             name: "Hitmonlee".to_string(),
             stage: 0,
@@ -14594,9 +14617,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b041Hitmonchan => Card::Pokemon(PokemonCard {
+        CardId::A2b041Hitmonchan => CardData::Pokemon(PokemonCard {
             id: "A2b 041".to_string(),
             name: "Hitmonchan".to_string(),
             stage: 0,
@@ -14615,9 +14638,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b042Riolu => Card::Pokemon(PokemonCard {
+        CardId::A2b042Riolu => CardData::Pokemon(PokemonCard {
             id: "A2b 042".to_string(),
             name: "Riolu".to_string(),
             stage: 0,
@@ -14636,9 +14659,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b043LucarioEx => Card::Pokemon(PokemonCard {
+        CardId::A2b043LucarioEx => CardData::Pokemon(PokemonCard {
             id: "A2b 043".to_string(),
             name: "Lucario ex".to_string(),
             stage: 1,
@@ -14657,9 +14680,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b044Flamigo => Card::Pokemon(PokemonCard {
+        CardId::A2b044Flamigo => CardData::Pokemon(PokemonCard {
             id: "A2b 044".to_string(),
             name: "Flamigo".to_string(),
             stage: 0,
@@ -14678,9 +14701,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b045Ekans => Card::Pokemon(PokemonCard {
+        CardId::A2b045Ekans => CardData::Pokemon(PokemonCard {
             id: "A2b 045".to_string(),
             name: "Ekans".to_string(),
             stage: 0,
@@ -14699,9 +14722,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b046Arbok => Card::Pokemon(PokemonCard {
+        CardId::A2b046Arbok => CardData::Pokemon(PokemonCard {
             id: "A2b 046".to_string(),
             name: "Arbok".to_string(),
             stage: 1,
@@ -14720,9 +14743,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b047PaldeanWooper => Card::Pokemon(PokemonCard {
+        CardId::A2b047PaldeanWooper => CardData::Pokemon(PokemonCard {
             id: "A2b 047".to_string(),
             name: "Paldean Wooper".to_string(),
             stage: 0,
@@ -14741,9 +14764,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b048PaldeanClodsireEx => Card::Pokemon(PokemonCard {
+        CardId::A2b048PaldeanClodsireEx => CardData::Pokemon(PokemonCard {
             id: "A2b 048".to_string(),
             name: "Paldean Clodsire ex".to_string(),
             stage: 1,
@@ -14762,9 +14785,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b049Spiritomb => Card::Pokemon(PokemonCard {
+        CardId::A2b049Spiritomb => CardData::Pokemon(PokemonCard {
             id: "A2b 049".to_string(),
             name: "Spiritomb".to_string(),
             stage: 0,
@@ -14783,9 +14806,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b050Shroodle => Card::Pokemon(PokemonCard {
+        CardId::A2b050Shroodle => CardData::Pokemon(PokemonCard {
             id: "A2b 050".to_string(),
             name: "Shroodle".to_string(),
             stage: 0,
@@ -14804,9 +14827,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b051Grafaiai => Card::Pokemon(PokemonCard {
+        CardId::A2b051Grafaiai => CardData::Pokemon(PokemonCard {
             id: "A2b 051".to_string(),
             name: "Grafaiai".to_string(),
             stage: 1,
@@ -14825,9 +14848,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b052Tinkatink => Card::Pokemon(PokemonCard {
+        CardId::A2b052Tinkatink => CardData::Pokemon(PokemonCard {
             id: "A2b 052".to_string(),
             name: "Tinkatink".to_string(),
             stage: 0,
@@ -14846,9 +14869,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b053Tinkatuff => Card::Pokemon(PokemonCard {
+        CardId::A2b053Tinkatuff => CardData::Pokemon(PokemonCard {
             id: "A2b 053".to_string(),
             name: "Tinkatuff".to_string(),
             stage: 1,
@@ -14867,9 +14890,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b054TinkatonEx => Card::Pokemon(PokemonCard {
+        CardId::A2b054TinkatonEx => CardData::Pokemon(PokemonCard {
             id: "A2b 054".to_string(),
             name: "Tinkaton ex".to_string(),
             stage: 2,
@@ -14888,9 +14911,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b055Varoom => Card::Pokemon(PokemonCard {
+        CardId::A2b055Varoom => CardData::Pokemon(PokemonCard {
             id: "A2b 055".to_string(),
             name: "Varoom".to_string(),
             stage: 0,
@@ -14909,9 +14932,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b056Revavroom => Card::Pokemon(PokemonCard {
+        CardId::A2b056Revavroom => CardData::Pokemon(PokemonCard {
             id: "A2b 056".to_string(),
             name: "Revavroom".to_string(),
             stage: 1,
@@ -14930,9 +14953,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b057Gholdengo => Card::Pokemon(PokemonCard {
+        CardId::A2b057Gholdengo => CardData::Pokemon(PokemonCard {
             id: "A2b 057".to_string(),
             name: "Gholdengo".to_string(),
             stage: 1,
@@ -14951,9 +14974,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b058Rattata => Card::Pokemon(PokemonCard {
+        CardId::A2b058Rattata => CardData::Pokemon(PokemonCard {
             id: "A2b 058".to_string(),
             name: "Rattata".to_string(),
             stage: 0,
@@ -14972,9 +14995,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b059Raticate => Card::Pokemon(PokemonCard {
+        CardId::A2b059Raticate => CardData::Pokemon(PokemonCard {
             id: "A2b 059".to_string(),
             name: "Raticate".to_string(),
             stage: 1,
@@ -14993,9 +15016,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b060Jigglypuff => Card::Pokemon(PokemonCard {
+        CardId::A2b060Jigglypuff => CardData::Pokemon(PokemonCard {
             id: "A2b 060".to_string(),
             name: "Jigglypuff".to_string(),
             stage: 0,
@@ -15014,9 +15037,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b061Wigglytuff => Card::Pokemon(PokemonCard {
+        CardId::A2b061Wigglytuff => CardData::Pokemon(PokemonCard {
             id: "A2b 061".to_string(),
             name: "Wigglytuff".to_string(),
             stage: 1,
@@ -15035,9 +15058,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b062Lickitung => Card::Pokemon(PokemonCard {
+        CardId::A2b062Lickitung => CardData::Pokemon(PokemonCard {
             id: "A2b 062".to_string(),
             name: "Lickitung".to_string(),
             stage: 0,
@@ -15056,9 +15079,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b063Lickilicky => Card::Pokemon(PokemonCard {
+        CardId::A2b063Lickilicky => CardData::Pokemon(PokemonCard {
             id: "A2b 063".to_string(),
             name: "Lickilicky".to_string(),
             stage: 1,
@@ -15077,9 +15100,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b064Bidoof => Card::Pokemon(PokemonCard {
+        CardId::A2b064Bidoof => CardData::Pokemon(PokemonCard {
             id: "A2b 064".to_string(),
             name: "Bidoof".to_string(),
             stage: 0,
@@ -15098,9 +15121,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b065BibarelEx => Card::Pokemon(PokemonCard {
+        CardId::A2b065BibarelEx => CardData::Pokemon(PokemonCard {
             id: "A2b 065".to_string(),
             name: "Bibarel ex".to_string(),
             stage: 1,
@@ -15119,9 +15142,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b066Buneary => Card::Pokemon(PokemonCard {
+        CardId::A2b066Buneary => CardData::Pokemon(PokemonCard {
             id: "A2b 066".to_string(),
             name: "Buneary".to_string(),
             stage: 0,
@@ -15140,9 +15163,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b067Lopunny => Card::Pokemon(PokemonCard {
+        CardId::A2b067Lopunny => CardData::Pokemon(PokemonCard {
             id: "A2b 067".to_string(),
             name: "Lopunny".to_string(),
             stage: 1,
@@ -15161,9 +15184,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b068Cyclizar => Card::Pokemon(PokemonCard {
+        CardId::A2b068Cyclizar => CardData::Pokemon(PokemonCard {
             id: "A2b 068".to_string(),
             name: "Cyclizar".to_string(),
             stage: 0,
@@ -15182,45 +15205,45 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b069Iono => Card::Trainer(TrainerCard {
+        CardId::A2b069Iono => CardData::Trainer(TrainerCard {
             id: "A2b 069".to_string(),
             numeric_id: 20549,
             name: "Iono".to_string(),
             effect: "Each player shuffles the cards in their hand into their deck, then draws that many cards.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b070PokemonCenterLady => Card::Trainer(TrainerCard {
+        CardId::A2b070PokemonCenterLady => CardData::Trainer(TrainerCard {
             id: "A2b 070".to_string(),
             numeric_id: 20550,
             name: "Pokémon Center Lady".to_string(),
             effect: "Heal 30 damage from 1 of your Pokémon, and it recovers from all Special Conditions.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b071Red => Card::Trainer(TrainerCard {
+        CardId::A2b071Red => CardData::Trainer(TrainerCard {
             id: "A2b 071".to_string(),
             numeric_id: 20551,
             name: "Red".to_string(),
             effect: "During this turn, attacks used by your Pokémon do +20 damage to your opponent's Active Pokémon ex.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b072TeamRocketGrunt => Card::Trainer(TrainerCard {
+        CardId::A2b072TeamRocketGrunt => CardData::Trainer(TrainerCard {
             id: "A2b 072".to_string(),
             numeric_id: 20552,
             name: "Team Rocket Grunt".to_string(),
             effect: "Flip a coin until you get tails. For each heads, discard a random Energy from your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b073Meowscarada => Card::Pokemon(PokemonCard {
+        CardId::A2b073Meowscarada => CardData::Pokemon(PokemonCard {
             id: "A2b 073".to_string(),
             name: "Meowscarada".to_string(),
             stage: 2,
@@ -15239,9 +15262,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b074Buizel => Card::Pokemon(PokemonCard {
+        CardId::A2b074Buizel => CardData::Pokemon(PokemonCard {
             id: "A2b 074".to_string(),
             name: "Buizel".to_string(),
             stage: 0,
@@ -15260,9 +15283,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b075Tatsugiri => Card::Pokemon(PokemonCard {
+        CardId::A2b075Tatsugiri => CardData::Pokemon(PokemonCard {
             id: "A2b 075".to_string(),
             name: "Tatsugiri".to_string(),
             stage: 0,
@@ -15281,9 +15304,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b076Grafaiai => Card::Pokemon(PokemonCard {
+        CardId::A2b076Grafaiai => CardData::Pokemon(PokemonCard {
             id: "A2b 076".to_string(),
             name: "Grafaiai".to_string(),
             stage: 1,
@@ -15302,9 +15325,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b077Gholdengo => Card::Pokemon(PokemonCard {
+        CardId::A2b077Gholdengo => CardData::Pokemon(PokemonCard {
             id: "A2b 077".to_string(),
             name: "Gholdengo".to_string(),
             stage: 1,
@@ -15323,9 +15346,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b078Wigglytuff => Card::Pokemon(PokemonCard {
+        CardId::A2b078Wigglytuff => CardData::Pokemon(PokemonCard {
             id: "A2b 078".to_string(),
             name: "Wigglytuff".to_string(),
             stage: 1,
@@ -15344,9 +15367,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b079BeedrillEx => Card::Pokemon(PokemonCard {
+        CardId::A2b079BeedrillEx => CardData::Pokemon(PokemonCard {
             id: "A2b 079".to_string(),
             name: "Beedrill ex".to_string(),
             stage: 2,
@@ -15365,9 +15388,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b080CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A2b080CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A2b 080".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -15392,9 +15415,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b081WugtrioEx => Card::Pokemon(PokemonCard {
+        CardId::A2b081WugtrioEx => CardData::Pokemon(PokemonCard {
             id: "A2b 081".to_string(),
             name: "Wugtrio ex".to_string(),
             stage: 1,
@@ -15413,9 +15436,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b082PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A2b082PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A2b 082".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -15434,9 +15457,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b083GiratinaEx => Card::Pokemon(PokemonCard {
+        CardId::A2b083GiratinaEx => CardData::Pokemon(PokemonCard {
             id: "A2b 083".to_string(),
             name: "Giratina ex".to_string(),
             stage: 0,
@@ -15455,9 +15478,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b084LucarioEx => Card::Pokemon(PokemonCard {
+        CardId::A2b084LucarioEx => CardData::Pokemon(PokemonCard {
             id: "A2b 084".to_string(),
             name: "Lucario ex".to_string(),
             stage: 1,
@@ -15476,9 +15499,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b085PaldeanClodsireEx => Card::Pokemon(PokemonCard {
+        CardId::A2b085PaldeanClodsireEx => CardData::Pokemon(PokemonCard {
             id: "A2b 085".to_string(),
             name: "Paldean Clodsire ex".to_string(),
             stage: 1,
@@ -15497,9 +15520,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b086TinkatonEx => Card::Pokemon(PokemonCard {
+        CardId::A2b086TinkatonEx => CardData::Pokemon(PokemonCard {
             id: "A2b 086".to_string(),
             name: "Tinkaton ex".to_string(),
             stage: 2,
@@ -15518,9 +15541,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b087BibarelEx => Card::Pokemon(PokemonCard {
+        CardId::A2b087BibarelEx => CardData::Pokemon(PokemonCard {
             id: "A2b 087".to_string(),
             name: "Bibarel ex".to_string(),
             stage: 1,
@@ -15539,45 +15562,45 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b088Iono => Card::Trainer(TrainerCard {
+        CardId::A2b088Iono => CardData::Trainer(TrainerCard {
             id: "A2b 088".to_string(),
             numeric_id: 20568,
             name: "Iono".to_string(),
             effect: "Each player shuffles the cards in their hand into their deck, then draws that many cards.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b089PokemonCenterLady => Card::Trainer(TrainerCard {
+        CardId::A2b089PokemonCenterLady => CardData::Trainer(TrainerCard {
             id: "A2b 089".to_string(),
             numeric_id: 20569,
             name: "Pokémon Center Lady".to_string(),
             effect: "Heal 30 damage from 1 of your Pokémon, and it recovers from all Special Conditions.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b090Red => Card::Trainer(TrainerCard {
+        CardId::A2b090Red => CardData::Trainer(TrainerCard {
             id: "A2b 090".to_string(),
             numeric_id: 20570,
             name: "Red".to_string(),
             effect: "During this turn, attacks used by your Pokémon do +20 damage to your opponent's Active Pokémon ex.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b091TeamRocketGrunt => Card::Trainer(TrainerCard {
+        CardId::A2b091TeamRocketGrunt => CardData::Trainer(TrainerCard {
             id: "A2b 091".to_string(),
             numeric_id: 20571,
             name: "Team Rocket Grunt".to_string(),
             effect: "Flip a coin until you get tails. For each heads, discard a random Energy from your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A2b092PikachuEx => Card::Pokemon(PokemonCard {
+        CardId::A2b092PikachuEx => CardData::Pokemon(PokemonCard {
             id: "A2b 092".to_string(),
             name: "Pikachu ex".to_string(),
             stage: 0,
@@ -15596,9 +15619,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b093PaldeanClodsireEx => Card::Pokemon(PokemonCard {
+        CardId::A2b093PaldeanClodsireEx => CardData::Pokemon(PokemonCard {
             id: "A2b 093".to_string(),
             name: "Paldean Clodsire ex".to_string(),
             stage: 1,
@@ -15617,9 +15640,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b094TinkatonEx => Card::Pokemon(PokemonCard {
+        CardId::A2b094TinkatonEx => CardData::Pokemon(PokemonCard {
             id: "A2b 094".to_string(),
             name: "Tinkaton ex".to_string(),
             stage: 2,
@@ -15638,9 +15661,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b095BibarelEx => Card::Pokemon(PokemonCard {
+        CardId::A2b095BibarelEx => CardData::Pokemon(PokemonCard {
             id: "A2b 095".to_string(),
             name: "Bibarel ex".to_string(),
             stage: 1,
@@ -15659,9 +15682,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b096GiratinaEx => Card::Pokemon(PokemonCard {
+        CardId::A2b096GiratinaEx => CardData::Pokemon(PokemonCard {
             id: "A2b 096".to_string(),
             name: "Giratina ex".to_string(),
             stage: 0,
@@ -15680,9 +15703,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b097Weedle => Card::Pokemon(PokemonCard {
+        CardId::A2b097Weedle => CardData::Pokemon(PokemonCard {
             id: "A2b 097".to_string(),
             name: "Weedle".to_string(),
             stage: 0,
@@ -15701,9 +15724,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b098Kakuna => Card::Pokemon(PokemonCard {
+        CardId::A2b098Kakuna => CardData::Pokemon(PokemonCard {
             id: "A2b 098".to_string(),
             name: "Kakuna".to_string(),
             stage: 1,
@@ -15722,9 +15745,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b099Charmander => Card::Pokemon(PokemonCard {
+        CardId::A2b099Charmander => CardData::Pokemon(PokemonCard {
             id: "A2b 099".to_string(),
             name: "Charmander".to_string(),
             stage: 0,
@@ -15743,9 +15766,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b100Charmeleon => Card::Pokemon(PokemonCard {
+        CardId::A2b100Charmeleon => CardData::Pokemon(PokemonCard {
             id: "A2b 100".to_string(),
             name: "Charmeleon".to_string(),
             stage: 1,
@@ -15764,9 +15787,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b101Wiglett => Card::Pokemon(PokemonCard {
+        CardId::A2b101Wiglett => CardData::Pokemon(PokemonCard {
             id: "A2b 101".to_string(),
             name: "Wiglett".to_string(),
             stage: 0,
@@ -15785,9 +15808,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b102Dondozo => Card::Pokemon(PokemonCard {
+        CardId::A2b102Dondozo => CardData::Pokemon(PokemonCard {
             id: "A2b 102".to_string(),
             name: "Dondozo".to_string(),
             stage: 0,
@@ -15806,9 +15829,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b103Pachirisu => Card::Pokemon(PokemonCard {
+        CardId::A2b103Pachirisu => CardData::Pokemon(PokemonCard {
             id: "A2b 103".to_string(),
             name: "Pachirisu".to_string(),
             stage: 0,
@@ -15827,9 +15850,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b104Riolu => Card::Pokemon(PokemonCard {
+        CardId::A2b104Riolu => CardData::Pokemon(PokemonCard {
             id: "A2b 104".to_string(),
             name: "Riolu".to_string(),
             stage: 0,
@@ -15848,9 +15871,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b105Varoom => Card::Pokemon(PokemonCard {
+        CardId::A2b105Varoom => CardData::Pokemon(PokemonCard {
             id: "A2b 105".to_string(),
             name: "Varoom".to_string(),
             stage: 0,
@@ -15869,9 +15892,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b106Revavroom => Card::Pokemon(PokemonCard {
+        CardId::A2b106Revavroom => CardData::Pokemon(PokemonCard {
             id: "A2b 106".to_string(),
             name: "Revavroom".to_string(),
             stage: 1,
@@ -15890,9 +15913,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b107BeedrillEx => Card::Pokemon(PokemonCard {
+        CardId::A2b107BeedrillEx => CardData::Pokemon(PokemonCard {
             id: "A2b 107".to_string(),
             name: "Beedrill ex".to_string(),
             stage: 2,
@@ -15911,9 +15934,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b108CharizardEx => Card::Pokemon(PokemonCard {
+        CardId::A2b108CharizardEx => CardData::Pokemon(PokemonCard {
             id: "A2b 108".to_string(),
             name: "Charizard ex".to_string(),
             stage: 2,
@@ -15938,9 +15961,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b109WugtrioEx => Card::Pokemon(PokemonCard {
+        CardId::A2b109WugtrioEx => CardData::Pokemon(PokemonCard {
             id: "A2b 109".to_string(),
             name: "Wugtrio ex".to_string(),
             stage: 1,
@@ -15959,9 +15982,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b110LucarioEx => Card::Pokemon(PokemonCard {
+        CardId::A2b110LucarioEx => CardData::Pokemon(PokemonCard {
             id: "A2b 110".to_string(),
             name: "Lucario ex".to_string(),
             stage: 1,
@@ -15980,18 +16003,18 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
         }),
-        CardId::A2b111PokeBall => Card::Trainer(TrainerCard {
+        CardId::A2b111PokeBall => CardData::Trainer(TrainerCard {
             id: "A2b 111".to_string(),
             numeric_id: 20591,
             name: "Poké Ball".to_string(),
             effect: "Put 1 random Basic Pokemon from your deck into your hand.".to_string(),
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Shining Revelry (A2b)".to_string(),
+            set: SetId::A2b,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3001Exeggcute => Card::Pokemon(PokemonCard {
+        CardId::A3001Exeggcute => CardData::Pokemon(PokemonCard {
             id: "A3 001".to_string(),
             name: "Exeggcute".to_string(),
             stage: 0,
@@ -16010,9 +16033,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3002AlolanExeggutor => Card::Pokemon(PokemonCard {
+        CardId::A3002AlolanExeggutor => CardData::Pokemon(PokemonCard {
             id: "A3 002".to_string(),
             name: "Alolan Exeggutor".to_string(),
             stage: 1,
@@ -16031,9 +16054,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3003Surskit => Card::Pokemon(PokemonCard {
+        CardId::A3003Surskit => CardData::Pokemon(PokemonCard {
             id: "A3 003".to_string(),
             name: "Surskit".to_string(),
             stage: 0,
@@ -16052,9 +16075,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3004Masquerain => Card::Pokemon(PokemonCard {
+        CardId::A3004Masquerain => CardData::Pokemon(PokemonCard {
             id: "A3 004".to_string(),
             name: "Masquerain".to_string(),
             stage: 1,
@@ -16073,9 +16096,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3005Maractus => Card::Pokemon(PokemonCard {
+        CardId::A3005Maractus => CardData::Pokemon(PokemonCard {
             id: "A3 005".to_string(),
             name: "Maractus".to_string(),
             stage: 0,
@@ -16094,9 +16117,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3006Karrablast => Card::Pokemon(PokemonCard {
+        CardId::A3006Karrablast => CardData::Pokemon(PokemonCard {
             id: "A3 006".to_string(),
             name: "Karrablast".to_string(),
             stage: 0,
@@ -16115,9 +16138,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3007Phantump => Card::Pokemon(PokemonCard {
+        CardId::A3007Phantump => CardData::Pokemon(PokemonCard {
             id: "A3 007".to_string(),
             name: "Phantump".to_string(),
             stage: 0,
@@ -16136,9 +16159,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3008Trevenant => Card::Pokemon(PokemonCard {
+        CardId::A3008Trevenant => CardData::Pokemon(PokemonCard {
             id: "A3 008".to_string(),
             name: "Trevenant".to_string(),
             stage: 1,
@@ -16157,9 +16180,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3009Rowlet => Card::Pokemon(PokemonCard {
+        CardId::A3009Rowlet => CardData::Pokemon(PokemonCard {
             id: "A3 009".to_string(),
             name: "Rowlet".to_string(),
             stage: 0,
@@ -16178,9 +16201,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3010Rowlet => Card::Pokemon(PokemonCard {
+        CardId::A3010Rowlet => CardData::Pokemon(PokemonCard {
             id: "A3 010".to_string(),
             name: "Rowlet".to_string(),
             stage: 0,
@@ -16199,9 +16222,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3011Dartrix => Card::Pokemon(PokemonCard {
+        CardId::A3011Dartrix => CardData::Pokemon(PokemonCard {
             id: "A3 011".to_string(),
             name: "Dartrix".to_string(),
             stage: 1,
@@ -16220,9 +16243,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3012DecidueyeEx => Card::Pokemon(PokemonCard {
+        CardId::A3012DecidueyeEx => CardData::Pokemon(PokemonCard {
             id: "A3 012".to_string(),
             name: "Decidueye ex".to_string(),
             stage: 2,
@@ -16247,9 +16270,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3013Grubbin => Card::Pokemon(PokemonCard {
+        CardId::A3013Grubbin => CardData::Pokemon(PokemonCard {
             id: "A3 013".to_string(),
             name: "Grubbin".to_string(),
             stage: 0,
@@ -16268,9 +16291,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3014Fomantis => Card::Pokemon(PokemonCard {
+        CardId::A3014Fomantis => CardData::Pokemon(PokemonCard {
             id: "A3 014".to_string(),
             name: "Fomantis".to_string(),
             stage: 0,
@@ -16289,9 +16312,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3015Lurantis => Card::Pokemon(PokemonCard {
+        CardId::A3015Lurantis => CardData::Pokemon(PokemonCard {
             id: "A3 015".to_string(),
             name: "Lurantis".to_string(),
             stage: 1,
@@ -16310,9 +16333,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3016Morelull => Card::Pokemon(PokemonCard {
+        CardId::A3016Morelull => CardData::Pokemon(PokemonCard {
             id: "A3 016".to_string(),
             name: "Morelull".to_string(),
             stage: 0,
@@ -16331,9 +16354,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3017Shiinotic => Card::Pokemon(PokemonCard {
+        CardId::A3017Shiinotic => CardData::Pokemon(PokemonCard {
             id: "A3 017".to_string(),
             name: "Shiinotic".to_string(),
             stage: 1,
@@ -16352,9 +16375,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3018Bounsweet => Card::Pokemon(PokemonCard {
+        CardId::A3018Bounsweet => CardData::Pokemon(PokemonCard {
             id: "A3 018".to_string(),
             name: "Bounsweet".to_string(),
             stage: 0,
@@ -16373,9 +16396,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3019Steenee => Card::Pokemon(PokemonCard {
+        CardId::A3019Steenee => CardData::Pokemon(PokemonCard {
             id: "A3 019".to_string(),
             name: "Steenee".to_string(),
             stage: 1,
@@ -16394,9 +16417,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3020Tsareena => Card::Pokemon(PokemonCard {
+        CardId::A3020Tsareena => CardData::Pokemon(PokemonCard {
             id: "A3 020".to_string(),
             name: "Tsareena".to_string(),
             stage: 2,
@@ -16415,9 +16438,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3021Wimpod => Card::Pokemon(PokemonCard {
+        CardId::A3021Wimpod => CardData::Pokemon(PokemonCard {
             id: "A3 021".to_string(),
             name: "Wimpod".to_string(),
             stage: 0,
@@ -16436,9 +16459,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3022Golisopod => Card::Pokemon(PokemonCard {
+        CardId::A3022Golisopod => CardData::Pokemon(PokemonCard {
             id: "A3 022".to_string(),
             name: "Golisopod".to_string(),
             stage: 1,
@@ -16457,9 +16480,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3023DhelmiseEx => Card::Pokemon(PokemonCard {
+        CardId::A3023DhelmiseEx => CardData::Pokemon(PokemonCard {
             id: "A3 023".to_string(),
             name: "Dhelmise ex".to_string(),
             stage: 0,
@@ -16478,9 +16501,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3024TapuBulu => Card::Pokemon(PokemonCard {
+        CardId::A3024TapuBulu => CardData::Pokemon(PokemonCard {
             id: "A3 024".to_string(),
             name: "Tapu Bulu".to_string(),
             stage: 0,
@@ -16499,9 +16522,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3025Growlithe => Card::Pokemon(PokemonCard {
+        CardId::A3025Growlithe => CardData::Pokemon(PokemonCard {
             id: "A3 025".to_string(),
             name: "Growlithe".to_string(),
             stage: 0,
@@ -16520,9 +16543,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3026Arcanine => Card::Pokemon(PokemonCard {
+        CardId::A3026Arcanine => CardData::Pokemon(PokemonCard {
             id: "A3 026".to_string(),
             name: "Arcanine".to_string(),
             stage: 1,
@@ -16541,9 +16564,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3027AlolanMarowak => Card::Pokemon(PokemonCard {
+        CardId::A3027AlolanMarowak => CardData::Pokemon(PokemonCard {
             id: "A3 027".to_string(),
             name: "Alolan Marowak".to_string(),
             stage: 1,
@@ -16562,9 +16585,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3028Fletchinder => Card::Pokemon(PokemonCard {
+        CardId::A3028Fletchinder => CardData::Pokemon(PokemonCard {
             id: "A3 028".to_string(),
             name: "Fletchinder".to_string(),
             stage: 1,
@@ -16583,9 +16606,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3029Talonflame => Card::Pokemon(PokemonCard {
+        CardId::A3029Talonflame => CardData::Pokemon(PokemonCard {
             id: "A3 029".to_string(),
             name: "Talonflame".to_string(),
             stage: 2,
@@ -16604,9 +16627,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3030Litten => Card::Pokemon(PokemonCard {
+        CardId::A3030Litten => CardData::Pokemon(PokemonCard {
             id: "A3 030".to_string(),
             name: "Litten".to_string(),
             stage: 0,
@@ -16625,9 +16648,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3031Litten => Card::Pokemon(PokemonCard {
+        CardId::A3031Litten => CardData::Pokemon(PokemonCard {
             id: "A3 031".to_string(),
             name: "Litten".to_string(),
             stage: 0,
@@ -16646,9 +16669,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3032Torracat => Card::Pokemon(PokemonCard {
+        CardId::A3032Torracat => CardData::Pokemon(PokemonCard {
             id: "A3 032".to_string(),
             name: "Torracat".to_string(),
             stage: 1,
@@ -16667,9 +16690,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3033IncineroarEx => Card::Pokemon(PokemonCard {
+        CardId::A3033IncineroarEx => CardData::Pokemon(PokemonCard {
             id: "A3 033".to_string(),
             name: "Incineroar ex".to_string(),
             stage: 2,
@@ -16694,9 +16717,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3034Oricorio => Card::Pokemon(PokemonCard {
+        CardId::A3034Oricorio => CardData::Pokemon(PokemonCard {
             id: "A3 034".to_string(),
             name: "Oricorio".to_string(),
             stage: 0,
@@ -16715,9 +16738,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3035Salandit => Card::Pokemon(PokemonCard {
+        CardId::A3035Salandit => CardData::Pokemon(PokemonCard {
             id: "A3 035".to_string(),
             name: "Salandit".to_string(),
             stage: 0,
@@ -16736,9 +16759,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3036Salazzle => Card::Pokemon(PokemonCard {
+        CardId::A3036Salazzle => CardData::Pokemon(PokemonCard {
             id: "A3 036".to_string(),
             name: "Salazzle".to_string(),
             stage: 1,
@@ -16757,9 +16780,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3037Turtonator => Card::Pokemon(PokemonCard {
+        CardId::A3037Turtonator => CardData::Pokemon(PokemonCard {
             id: "A3 037".to_string(),
             name: "Turtonator".to_string(),
             stage: 0,
@@ -16778,9 +16801,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3038AlolanSandshrew => Card::Pokemon(PokemonCard {
+        CardId::A3038AlolanSandshrew => CardData::Pokemon(PokemonCard {
             id: "A3 038".to_string(),
             name: "Alolan Sandshrew".to_string(),
             stage: 0,
@@ -16799,9 +16822,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3039AlolanSandslash => Card::Pokemon(PokemonCard {
+        CardId::A3039AlolanSandslash => CardData::Pokemon(PokemonCard {
             id: "A3 039".to_string(),
             name: "Alolan Sandslash".to_string(),
             stage: 1,
@@ -16820,9 +16843,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3040AlolanVulpix => Card::Pokemon(PokemonCard {
+        CardId::A3040AlolanVulpix => CardData::Pokemon(PokemonCard {
             id: "A3 040".to_string(),
             name: "Alolan Vulpix".to_string(),
             stage: 0,
@@ -16841,9 +16864,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3041AlolanNinetales => Card::Pokemon(PokemonCard {
+        CardId::A3041AlolanNinetales => CardData::Pokemon(PokemonCard {
             id: "A3 041".to_string(),
             name: "Alolan Ninetales".to_string(),
             stage: 1,
@@ -16862,9 +16885,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3042Shellder => Card::Pokemon(PokemonCard {
+        CardId::A3042Shellder => CardData::Pokemon(PokemonCard {
             id: "A3 042".to_string(),
             name: "Shellder".to_string(),
             stage: 0,
@@ -16883,9 +16906,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3043Cloyster => Card::Pokemon(PokemonCard {
+        CardId::A3043Cloyster => CardData::Pokemon(PokemonCard {
             id: "A3 043".to_string(),
             name: "Cloyster".to_string(),
             stage: 1,
@@ -16904,9 +16927,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3044Lapras => Card::Pokemon(PokemonCard {
+        CardId::A3044Lapras => CardData::Pokemon(PokemonCard {
             id: "A3 044".to_string(),
             name: "Lapras".to_string(),
             stage: 0,
@@ -16925,9 +16948,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3045Popplio => Card::Pokemon(PokemonCard {
+        CardId::A3045Popplio => CardData::Pokemon(PokemonCard {
             id: "A3 045".to_string(),
             name: "Popplio".to_string(),
             stage: 0,
@@ -16946,9 +16969,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3046Popplio => Card::Pokemon(PokemonCard {
+        CardId::A3046Popplio => CardData::Pokemon(PokemonCard {
             id: "A3 046".to_string(),
             name: "Popplio".to_string(),
             stage: 0,
@@ -16967,9 +16990,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3047Brionne => Card::Pokemon(PokemonCard {
+        CardId::A3047Brionne => CardData::Pokemon(PokemonCard {
             id: "A3 047".to_string(),
             name: "Brionne".to_string(),
             stage: 1,
@@ -16988,9 +17011,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3048Primarina => Card::Pokemon(PokemonCard {
+        CardId::A3048Primarina => CardData::Pokemon(PokemonCard {
             id: "A3 048".to_string(),
             name: "Primarina".to_string(),
             stage: 2,
@@ -17009,9 +17032,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3049CrabominableEx => Card::Pokemon(PokemonCard {
+        CardId::A3049CrabominableEx => CardData::Pokemon(PokemonCard {
             id: "A3 049".to_string(),
             name: "Crabominable ex".to_string(),
             stage: 1,
@@ -17030,9 +17053,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3050Wishiwashi => Card::Pokemon(PokemonCard {
+        CardId::A3050Wishiwashi => CardData::Pokemon(PokemonCard {
             id: "A3 050".to_string(),
             name: "Wishiwashi".to_string(),
             stage: 0,
@@ -17051,9 +17074,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3051WishiwashiEx => Card::Pokemon(PokemonCard {
+        CardId::A3051WishiwashiEx => CardData::Pokemon(PokemonCard {
             id: "A3 051".to_string(),
             name: "Wishiwashi ex".to_string(),
             stage: 0,
@@ -17072,9 +17095,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3052Dewpider => Card::Pokemon(PokemonCard {
+        CardId::A3052Dewpider => CardData::Pokemon(PokemonCard {
             id: "A3 052".to_string(),
             name: "Dewpider".to_string(),
             stage: 0,
@@ -17093,9 +17116,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3053Araquanid => Card::Pokemon(PokemonCard {
+        CardId::A3053Araquanid => CardData::Pokemon(PokemonCard {
             id: "A3 053".to_string(),
             name: "Araquanid".to_string(),
             stage: 1,
@@ -17114,9 +17137,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3054Pyukumuku => Card::Pokemon(PokemonCard {
+        CardId::A3054Pyukumuku => CardData::Pokemon(PokemonCard {
             id: "A3 054".to_string(),
             name: "Pyukumuku".to_string(),
             stage: 0,
@@ -17135,9 +17158,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3055Bruxish => Card::Pokemon(PokemonCard {
+        CardId::A3055Bruxish => CardData::Pokemon(PokemonCard {
             id: "A3 055".to_string(),
             name: "Bruxish".to_string(),
             stage: 0,
@@ -17156,9 +17179,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3056TapuFini => Card::Pokemon(PokemonCard {
+        CardId::A3056TapuFini => CardData::Pokemon(PokemonCard {
             id: "A3 056".to_string(),
             name: "Tapu Fini".to_string(),
             stage: 0,
@@ -17177,9 +17200,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3057Pikachu => Card::Pokemon(PokemonCard {
+        CardId::A3057Pikachu => CardData::Pokemon(PokemonCard {
             id: "A3 057".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -17198,9 +17221,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3058AlolanRaichuEx => Card::Pokemon(PokemonCard {
+        CardId::A3058AlolanRaichuEx => CardData::Pokemon(PokemonCard {
             id: "A3 058".to_string(),
             name: "Alolan Raichu ex".to_string(),
             stage: 1,
@@ -17219,9 +17242,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3059AlolanGeodude => Card::Pokemon(PokemonCard {
+        CardId::A3059AlolanGeodude => CardData::Pokemon(PokemonCard {
             id: "A3 059".to_string(),
             name: "Alolan Geodude".to_string(),
             stage: 0,
@@ -17240,9 +17263,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3060AlolanGraveler => Card::Pokemon(PokemonCard {
+        CardId::A3060AlolanGraveler => CardData::Pokemon(PokemonCard {
             id: "A3 060".to_string(),
             name: "Alolan Graveler".to_string(),
             stage: 1,
@@ -17261,9 +17284,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3061AlolanGolem => Card::Pokemon(PokemonCard {
+        CardId::A3061AlolanGolem => CardData::Pokemon(PokemonCard {
             id: "A3 061".to_string(),
             name: "Alolan Golem".to_string(),
             stage: 2,
@@ -17282,9 +17305,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3062Helioptile => Card::Pokemon(PokemonCard {
+        CardId::A3062Helioptile => CardData::Pokemon(PokemonCard {
             id: "A3 062".to_string(),
             name: "Helioptile".to_string(),
             stage: 0,
@@ -17303,9 +17326,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3063Heliolisk => Card::Pokemon(PokemonCard {
+        CardId::A3063Heliolisk => CardData::Pokemon(PokemonCard {
             id: "A3 063".to_string(),
             name: "Heliolisk".to_string(),
             stage: 1,
@@ -17324,9 +17347,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3064Charjabug => Card::Pokemon(PokemonCard {
+        CardId::A3064Charjabug => CardData::Pokemon(PokemonCard {
             id: "A3 064".to_string(),
             name: "Charjabug".to_string(),
             stage: 1,
@@ -17345,9 +17368,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3065Vikavolt => Card::Pokemon(PokemonCard {
+        CardId::A3065Vikavolt => CardData::Pokemon(PokemonCard {
             id: "A3 065".to_string(),
             name: "Vikavolt".to_string(),
             stage: 2,
@@ -17366,9 +17389,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3066Oricorio => Card::Pokemon(PokemonCard {
+        CardId::A3066Oricorio => CardData::Pokemon(PokemonCard {
             id: "A3 066".to_string(),
             name: "Oricorio".to_string(),
             stage: 0,
@@ -17387,9 +17410,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3067Togedemaru => Card::Pokemon(PokemonCard {
+        CardId::A3067Togedemaru => CardData::Pokemon(PokemonCard {
             id: "A3 067".to_string(),
             name: "Togedemaru".to_string(),
             stage: 0,
@@ -17408,9 +17431,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3068TapuKoko => Card::Pokemon(PokemonCard {
+        CardId::A3068TapuKoko => CardData::Pokemon(PokemonCard {
             id: "A3 068".to_string(),
             name: "Tapu Koko".to_string(),
             stage: 0,
@@ -17429,9 +17452,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3069MrMime => Card::Pokemon(PokemonCard {
+        CardId::A3069MrMime => CardData::Pokemon(PokemonCard {
             id: "A3 069".to_string(),
             name: "Mr. Mime".to_string(),
             stage: 0,
@@ -17450,9 +17473,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3070Sableye => Card::Pokemon(PokemonCard {
+        CardId::A3070Sableye => CardData::Pokemon(PokemonCard {
             id: "A3 070".to_string(),
             name: "Sableye".to_string(),
             stage: 0,
@@ -17471,9 +17494,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3071Spoink => Card::Pokemon(PokemonCard {
+        CardId::A3071Spoink => CardData::Pokemon(PokemonCard {
             id: "A3 071".to_string(),
             name: "Spoink".to_string(),
             stage: 0,
@@ -17492,9 +17515,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3072Grumpig => Card::Pokemon(PokemonCard {
+        CardId::A3072Grumpig => CardData::Pokemon(PokemonCard {
             id: "A3 072".to_string(),
             name: "Grumpig".to_string(),
             stage: 1,
@@ -17513,9 +17536,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3073Lunatone => Card::Pokemon(PokemonCard {
+        CardId::A3073Lunatone => CardData::Pokemon(PokemonCard {
             id: "A3 073".to_string(),
             name: "Lunatone".to_string(),
             stage: 0,
@@ -17534,9 +17557,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3074Shuppet => Card::Pokemon(PokemonCard {
+        CardId::A3074Shuppet => CardData::Pokemon(PokemonCard {
             id: "A3 074".to_string(),
             name: "Shuppet".to_string(),
             stage: 0,
@@ -17555,9 +17578,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3075Banette => Card::Pokemon(PokemonCard {
+        CardId::A3075Banette => CardData::Pokemon(PokemonCard {
             id: "A3 075".to_string(),
             name: "Banette".to_string(),
             stage: 1,
@@ -17576,9 +17599,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3076Oricorio => Card::Pokemon(PokemonCard {
+        CardId::A3076Oricorio => CardData::Pokemon(PokemonCard {
             id: "A3 076".to_string(),
             name: "Oricorio".to_string(),
             stage: 0,
@@ -17597,9 +17620,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3077Oricorio => Card::Pokemon(PokemonCard {
+        CardId::A3077Oricorio => CardData::Pokemon(PokemonCard {
             id: "A3 077".to_string(),
             name: "Oricorio".to_string(),
             stage: 0,
@@ -17618,9 +17641,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3078Cutiefly => Card::Pokemon(PokemonCard {
+        CardId::A3078Cutiefly => CardData::Pokemon(PokemonCard {
             id: "A3 078".to_string(),
             name: "Cutiefly".to_string(),
             stage: 0,
@@ -17639,9 +17662,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3079Ribombee => Card::Pokemon(PokemonCard {
+        CardId::A3079Ribombee => CardData::Pokemon(PokemonCard {
             id: "A3 079".to_string(),
             name: "Ribombee".to_string(),
             stage: 1,
@@ -17660,9 +17683,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3080Comfey => Card::Pokemon(PokemonCard {
+        CardId::A3080Comfey => CardData::Pokemon(PokemonCard {
             id: "A3 080".to_string(),
             name: "Comfey".to_string(),
             stage: 0,
@@ -17681,9 +17704,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3081Sandygast => Card::Pokemon(PokemonCard {
+        CardId::A3081Sandygast => CardData::Pokemon(PokemonCard {
             id: "A3 081".to_string(),
             name: "Sandygast".to_string(),
             stage: 0,
@@ -17702,9 +17725,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3082Palossand => Card::Pokemon(PokemonCard {
+        CardId::A3082Palossand => CardData::Pokemon(PokemonCard {
             id: "A3 082".to_string(),
             name: "Palossand".to_string(),
             stage: 1,
@@ -17723,9 +17746,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3083Mimikyu => Card::Pokemon(PokemonCard {
+        CardId::A3083Mimikyu => CardData::Pokemon(PokemonCard {
             id: "A3 083".to_string(),
             name: "Mimikyu".to_string(),
             stage: 0,
@@ -17744,9 +17767,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3084TapuLele => Card::Pokemon(PokemonCard {
+        CardId::A3084TapuLele => CardData::Pokemon(PokemonCard {
             id: "A3 084".to_string(),
             name: "Tapu Lele".to_string(),
             stage: 0,
@@ -17765,9 +17788,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3085Cosmog => Card::Pokemon(PokemonCard {
+        CardId::A3085Cosmog => CardData::Pokemon(PokemonCard {
             id: "A3 085".to_string(),
             name: "Cosmog".to_string(),
             stage: 0,
@@ -17786,9 +17809,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3086Cosmoem => Card::Pokemon(PokemonCard {
+        CardId::A3086Cosmoem => CardData::Pokemon(PokemonCard {
             id: "A3 086".to_string(),
             name: "Cosmoem".to_string(),
             stage: 1,
@@ -17807,9 +17830,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3087LunalaEx => Card::Pokemon(PokemonCard {
+        CardId::A3087LunalaEx => CardData::Pokemon(PokemonCard {
             id: "A3 087".to_string(),
             name: "Lunala ex".to_string(),
             stage: 2,
@@ -17828,9 +17851,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3088Necrozma => Card::Pokemon(PokemonCard {
+        CardId::A3088Necrozma => CardData::Pokemon(PokemonCard {
             id: "A3 088".to_string(),
             name: "Necrozma".to_string(),
             stage: 0,
@@ -17849,9 +17872,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3089Cubone => Card::Pokemon(PokemonCard {
+        CardId::A3089Cubone => CardData::Pokemon(PokemonCard {
             id: "A3 089".to_string(),
             name: "Cubone".to_string(),
             stage: 0,
@@ -17870,9 +17893,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3090Makuhita => Card::Pokemon(PokemonCard {
+        CardId::A3090Makuhita => CardData::Pokemon(PokemonCard {
             id: "A3 090".to_string(),
             name: "Makuhita".to_string(),
             stage: 0,
@@ -17891,9 +17914,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3091Hariyama => Card::Pokemon(PokemonCard {
+        CardId::A3091Hariyama => CardData::Pokemon(PokemonCard {
             id: "A3 091".to_string(),
             name: "Hariyama".to_string(),
             stage: 1,
@@ -17912,9 +17935,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3092Solrock => Card::Pokemon(PokemonCard {
+        CardId::A3092Solrock => CardData::Pokemon(PokemonCard {
             id: "A3 092".to_string(),
             name: "Solrock".to_string(),
             stage: 0,
@@ -17933,9 +17956,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3093Drilbur => Card::Pokemon(PokemonCard {
+        CardId::A3093Drilbur => CardData::Pokemon(PokemonCard {
             id: "A3 093".to_string(),
             name: "Drilbur".to_string(),
             stage: 0,
@@ -17954,9 +17977,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3094Timburr => Card::Pokemon(PokemonCard {
+        CardId::A3094Timburr => CardData::Pokemon(PokemonCard {
             id: "A3 094".to_string(),
             name: "Timburr".to_string(),
             stage: 0,
@@ -17975,9 +17998,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3095Gurdurr => Card::Pokemon(PokemonCard {
+        CardId::A3095Gurdurr => CardData::Pokemon(PokemonCard {
             id: "A3 095".to_string(),
             name: "Gurdurr".to_string(),
             stage: 1,
@@ -17996,9 +18019,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3096Conkeldurr => Card::Pokemon(PokemonCard {
+        CardId::A3096Conkeldurr => CardData::Pokemon(PokemonCard {
             id: "A3 096".to_string(),
             name: "Conkeldurr".to_string(),
             stage: 2,
@@ -18017,9 +18040,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3097Crabrawler => Card::Pokemon(PokemonCard {
+        CardId::A3097Crabrawler => CardData::Pokemon(PokemonCard {
             id: "A3 097".to_string(),
             name: "Crabrawler".to_string(),
             stage: 0,
@@ -18038,9 +18061,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3098Rockruff => Card::Pokemon(PokemonCard {
+        CardId::A3098Rockruff => CardData::Pokemon(PokemonCard {
             id: "A3 098".to_string(),
             name: "Rockruff".to_string(),
             stage: 0,
@@ -18059,9 +18082,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3099Rockruff => Card::Pokemon(PokemonCard {
+        CardId::A3099Rockruff => CardData::Pokemon(PokemonCard {
             id: "A3 099".to_string(),
             name: "Rockruff".to_string(),
             stage: 0,
@@ -18080,9 +18103,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3100Lycanroc => Card::Pokemon(PokemonCard {
+        CardId::A3100Lycanroc => CardData::Pokemon(PokemonCard {
             id: "A3 100".to_string(),
             name: "Lycanroc".to_string(),
             stage: 1,
@@ -18101,9 +18124,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3101Lycanroc => Card::Pokemon(PokemonCard {
+        CardId::A3101Lycanroc => CardData::Pokemon(PokemonCard {
             id: "A3 101".to_string(),
             name: "Lycanroc".to_string(),
             stage: 1,
@@ -18122,9 +18145,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3102Mudbray => Card::Pokemon(PokemonCard {
+        CardId::A3102Mudbray => CardData::Pokemon(PokemonCard {
             id: "A3 102".to_string(),
             name: "Mudbray".to_string(),
             stage: 0,
@@ -18143,9 +18166,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3103Mudsdale => Card::Pokemon(PokemonCard {
+        CardId::A3103Mudsdale => CardData::Pokemon(PokemonCard {
             id: "A3 103".to_string(),
             name: "Mudsdale".to_string(),
             stage: 1,
@@ -18164,9 +18187,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3104PassimianEx => Card::Pokemon(PokemonCard {
+        CardId::A3104PassimianEx => CardData::Pokemon(PokemonCard {
             id: "A3 104".to_string(),
             name: "Passimian ex".to_string(),
             stage: 0,
@@ -18185,9 +18208,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3105Minior => Card::Pokemon(PokemonCard {
+        CardId::A3105Minior => CardData::Pokemon(PokemonCard {
             id: "A3 105".to_string(),
             name: "Minior".to_string(),
             stage: 0,
@@ -18206,9 +18229,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3106AlolanRattata => Card::Pokemon(PokemonCard {
+        CardId::A3106AlolanRattata => CardData::Pokemon(PokemonCard {
             id: "A3 106".to_string(),
             name: "Alolan Rattata".to_string(),
             stage: 0,
@@ -18227,9 +18250,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3107AlolanRaticate => Card::Pokemon(PokemonCard {
+        CardId::A3107AlolanRaticate => CardData::Pokemon(PokemonCard {
             id: "A3 107".to_string(),
             name: "Alolan Raticate".to_string(),
             stage: 1,
@@ -18248,9 +18271,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3108AlolanMeowth => Card::Pokemon(PokemonCard {
+        CardId::A3108AlolanMeowth => CardData::Pokemon(PokemonCard {
             id: "A3 108".to_string(),
             name: "Alolan Meowth".to_string(),
             stage: 0,
@@ -18269,9 +18292,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3109AlolanPersian => Card::Pokemon(PokemonCard {
+        CardId::A3109AlolanPersian => CardData::Pokemon(PokemonCard {
             id: "A3 109".to_string(),
             name: "Alolan Persian".to_string(),
             stage: 1,
@@ -18290,9 +18313,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3110AlolanGrimer => Card::Pokemon(PokemonCard {
+        CardId::A3110AlolanGrimer => CardData::Pokemon(PokemonCard {
             id: "A3 110".to_string(),
             name: "Alolan Grimer".to_string(),
             stage: 0,
@@ -18311,9 +18334,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3111AlolanMukEx => Card::Pokemon(PokemonCard {
+        CardId::A3111AlolanMukEx => CardData::Pokemon(PokemonCard {
             id: "A3 111".to_string(),
             name: "Alolan Muk ex".to_string(),
             stage: 1,
@@ -18332,9 +18355,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3112Absol => Card::Pokemon(PokemonCard {
+        CardId::A3112Absol => CardData::Pokemon(PokemonCard {
             id: "A3 112".to_string(),
             name: "Absol".to_string(),
             stage: 0,
@@ -18353,9 +18376,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3113Trubbish => Card::Pokemon(PokemonCard {
+        CardId::A3113Trubbish => CardData::Pokemon(PokemonCard {
             id: "A3 113".to_string(),
             name: "Trubbish".to_string(),
             stage: 0,
@@ -18374,9 +18397,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3114Garbodor => Card::Pokemon(PokemonCard {
+        CardId::A3114Garbodor => CardData::Pokemon(PokemonCard {
             id: "A3 114".to_string(),
             name: "Garbodor".to_string(),
             stage: 1,
@@ -18395,9 +18418,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3115Mareanie => Card::Pokemon(PokemonCard {
+        CardId::A3115Mareanie => CardData::Pokemon(PokemonCard {
             id: "A3 115".to_string(),
             name: "Mareanie".to_string(),
             stage: 0,
@@ -18416,9 +18439,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3116ToxapEx => Card::Pokemon(PokemonCard {
+        CardId::A3116ToxapEx => CardData::Pokemon(PokemonCard {
             id: "A3 116".to_string(),
             name: "Toxapex".to_string(),
             stage: 1,
@@ -18437,9 +18460,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3117AlolanDiglett => Card::Pokemon(PokemonCard {
+        CardId::A3117AlolanDiglett => CardData::Pokemon(PokemonCard {
             id: "A3 117".to_string(),
             name: "Alolan Diglett".to_string(),
             stage: 0,
@@ -18458,9 +18481,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3118AlolanDugtrio => Card::Pokemon(PokemonCard {
+        CardId::A3118AlolanDugtrio => CardData::Pokemon(PokemonCard {
             id: "A3 118".to_string(),
             name: "Alolan Dugtrio".to_string(),
             stage: 1,
@@ -18479,9 +18502,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3119Excadrill => Card::Pokemon(PokemonCard {
+        CardId::A3119Excadrill => CardData::Pokemon(PokemonCard {
             id: "A3 119".to_string(),
             name: "Excadrill".to_string(),
             stage: 1,
@@ -18500,9 +18523,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3120Escavalier => Card::Pokemon(PokemonCard {
+        CardId::A3120Escavalier => CardData::Pokemon(PokemonCard {
             id: "A3 120".to_string(),
             name: "Escavalier".to_string(),
             stage: 1,
@@ -18521,9 +18544,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3121Klefki => Card::Pokemon(PokemonCard {
+        CardId::A3121Klefki => CardData::Pokemon(PokemonCard {
             id: "A3 121".to_string(),
             name: "Klefki".to_string(),
             stage: 0,
@@ -18542,9 +18565,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3122SolgaleoEx => Card::Pokemon(PokemonCard {
+        CardId::A3122SolgaleoEx => CardData::Pokemon(PokemonCard {
             id: "A3 122".to_string(),
             name: "Solgaleo ex".to_string(),
             stage: 2,
@@ -18563,9 +18586,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3123Magearna => Card::Pokemon(PokemonCard {
+        CardId::A3123Magearna => CardData::Pokemon(PokemonCard {
             id: "A3 123".to_string(),
             name: "Magearna".to_string(),
             stage: 0,
@@ -18584,9 +18607,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3124Drampa => Card::Pokemon(PokemonCard {
+        CardId::A3124Drampa => CardData::Pokemon(PokemonCard {
             id: "A3 124".to_string(),
             name: "Drampa".to_string(),
             stage: 0,
@@ -18605,9 +18628,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3125Jangmoo => Card::Pokemon(PokemonCard {
+        CardId::A3125Jangmoo => CardData::Pokemon(PokemonCard {
             id: "A3 125".to_string(),
             name: "Jangmo-o".to_string(),
             stage: 0,
@@ -18626,9 +18649,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3126Hakamoo => Card::Pokemon(PokemonCard {
+        CardId::A3126Hakamoo => CardData::Pokemon(PokemonCard {
             id: "A3 126".to_string(),
             name: "Hakamo-o".to_string(),
             stage: 1,
@@ -18647,9 +18670,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3127Kommoo => Card::Pokemon(PokemonCard {
+        CardId::A3127Kommoo => CardData::Pokemon(PokemonCard {
             id: "A3 127".to_string(),
             name: "Kommo-o".to_string(),
             stage: 2,
@@ -18668,9 +18691,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3128Tauros => Card::Pokemon(PokemonCard {
+        CardId::A3128Tauros => CardData::Pokemon(PokemonCard {
             id: "A3 128".to_string(),
             name: "Tauros".to_string(),
             stage: 0,
@@ -18689,9 +18712,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3129Skitty => Card::Pokemon(PokemonCard {
+        CardId::A3129Skitty => CardData::Pokemon(PokemonCard {
             id: "A3 129".to_string(),
             name: "Skitty".to_string(),
             stage: 0,
@@ -18710,9 +18733,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3130Delcatty => Card::Pokemon(PokemonCard {
+        CardId::A3130Delcatty => CardData::Pokemon(PokemonCard {
             id: "A3 130".to_string(),
             name: "Delcatty".to_string(),
             stage: 1,
@@ -18731,9 +18754,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3131Fletchling => Card::Pokemon(PokemonCard {
+        CardId::A3131Fletchling => CardData::Pokemon(PokemonCard {
             id: "A3 131".to_string(),
             name: "Fletchling".to_string(),
             stage: 0,
@@ -18752,9 +18775,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3132Hawlucha => Card::Pokemon(PokemonCard {
+        CardId::A3132Hawlucha => CardData::Pokemon(PokemonCard {
             id: "A3 132".to_string(),
             name: "Hawlucha".to_string(),
             stage: 0,
@@ -18773,9 +18796,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3133Pikipek => Card::Pokemon(PokemonCard {
+        CardId::A3133Pikipek => CardData::Pokemon(PokemonCard {
             id: "A3 133".to_string(),
             name: "Pikipek".to_string(),
             stage: 0,
@@ -18794,9 +18817,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3134Trumbeak => Card::Pokemon(PokemonCard {
+        CardId::A3134Trumbeak => CardData::Pokemon(PokemonCard {
             id: "A3 134".to_string(),
             name: "Trumbeak".to_string(),
             stage: 1,
@@ -18815,9 +18838,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3135Toucannon => Card::Pokemon(PokemonCard {
+        CardId::A3135Toucannon => CardData::Pokemon(PokemonCard {
             id: "A3 135".to_string(),
             name: "Toucannon".to_string(),
             stage: 2,
@@ -18836,9 +18859,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3136Yungoos => Card::Pokemon(PokemonCard {
+        CardId::A3136Yungoos => CardData::Pokemon(PokemonCard {
             id: "A3 136".to_string(),
             name: "Yungoos".to_string(),
             stage: 0,
@@ -18857,9 +18880,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3137Gumshoos => Card::Pokemon(PokemonCard {
+        CardId::A3137Gumshoos => CardData::Pokemon(PokemonCard {
             id: "A3 137".to_string(),
             name: "Gumshoos".to_string(),
             stage: 1,
@@ -18878,9 +18901,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3138Stufful => Card::Pokemon(PokemonCard {
+        CardId::A3138Stufful => CardData::Pokemon(PokemonCard {
             id: "A3 138".to_string(),
             name: "Stufful".to_string(),
             stage: 0,
@@ -18899,9 +18922,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3139Bewear => Card::Pokemon(PokemonCard {
+        CardId::A3139Bewear => CardData::Pokemon(PokemonCard {
             id: "A3 139".to_string(),
             name: "Bewear".to_string(),
             stage: 1,
@@ -18920,9 +18943,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3140Oranguru => Card::Pokemon(PokemonCard {
+        CardId::A3140Oranguru => CardData::Pokemon(PokemonCard {
             id: "A3 140".to_string(),
             name: "Oranguru".to_string(),
             stage: 0,
@@ -18941,9 +18964,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3141Komala => Card::Pokemon(PokemonCard {
+        CardId::A3141Komala => CardData::Pokemon(PokemonCard {
             id: "A3 141".to_string(),
             name: "Komala".to_string(),
             stage: 0,
@@ -18962,135 +18985,135 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3142BigMalasada => Card::Trainer(TrainerCard {
+        CardId::A3142BigMalasada => CardData::Trainer(TrainerCard {
             id: "A3 142".to_string(),
             numeric_id: 24718,
             name: "Big Malasada".to_string(),
             effect: "Heal 10 damage and remove a random Special Condition from your Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3143FishingNet => Card::Trainer(TrainerCard {
+        CardId::A3143FishingNet => CardData::Trainer(TrainerCard {
             id: "A3 143".to_string(),
             numeric_id: 24719,
             name: "Fishing Net".to_string(),
             effect: "Put a random Basic [W] Pokémon from your discard pile into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3144RareCandy => Card::Trainer(TrainerCard {
+        CardId::A3144RareCandy => CardData::Trainer(TrainerCard {
             id: "A3 144".to_string(),
             numeric_id: 24720,
             name: "Rare Candy".to_string(),
             effect: "Choose 1 of your Basic Pokémon in play. If you have a Stage 2 card in your hand that evolves from that Pokémon, put that card onto the Basic Pokémon to evolve it, skipping the Stage 1. You can't use this card during your first turn or on a Basic Pokémon that was put into play this turn.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3145RotomDEx => Card::Trainer(TrainerCard {
+        CardId::A3145RotomDEx => CardData::Trainer(TrainerCard {
             id: "A3 145".to_string(),
             numeric_id: 24721,
             name: "Rotom Dex".to_string(),
             effect: "Look at the top card of your deck. Then, you may shuffle your deck.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3146PoisonBarb => Card::Trainer(TrainerCard {
+        CardId::A3146PoisonBarb => CardData::Trainer(TrainerCard {
             id: "A3 146".to_string(),
             numeric_id: 24722,
             name: "Poison Barb".to_string(),
             effect: "If the Pokémon this card is attached to is your Active Pokémon and is damaged by an attack from your opponent's Pokémon, the Attacking Pokémon is now Poisoned.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A3147LeafCape => Card::Trainer(TrainerCard {
+        CardId::A3147LeafCape => CardData::Trainer(TrainerCard {
             id: "A3 147".to_string(),
             numeric_id: 24723,
             name: "Leaf Cape".to_string(),
             effect: "The [G] Pokémon this card is attached to gets +30 HP.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A3148Acerola => Card::Trainer(TrainerCard {
+        CardId::A3148Acerola => CardData::Trainer(TrainerCard {
             id: "A3 148".to_string(),
             numeric_id: 24724,
             name: "Acerola".to_string(),
             effect: "Choose 1 of your Palossand or Mimikyu that has damage on it, and move 40 of its damage to your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3149Ilima => Card::Trainer(TrainerCard {
+        CardId::A3149Ilima => CardData::Trainer(TrainerCard {
             id: "A3 149".to_string(),
             numeric_id: 24725,
             name: "Ilima".to_string(),
             effect: "Put 1 of your [C] Pokémon that has damage on it into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3150Kiawe => Card::Trainer(TrainerCard {
+        CardId::A3150Kiawe => CardData::Trainer(TrainerCard {
             id: "A3 150".to_string(),
             numeric_id: 24726,
             name: "Kiawe".to_string(),
             effect: "Choose 1 of your Alolan Marowak or Turtonator. Take 2 [R] Energy from your Energy Zone and attach it to that Pokémon. Your turn ends.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3151Guzma => Card::Trainer(TrainerCard {
+        CardId::A3151Guzma => CardData::Trainer(TrainerCard {
             id: "A3 151".to_string(),
             numeric_id: 24727,
             name: "Guzma".to_string(),
             effect: "Discard all Pokémon Tool cards attached to each of your opponent's Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3152Lana => Card::Trainer(TrainerCard {
+        CardId::A3152Lana => CardData::Trainer(TrainerCard {
             id: "A3 152".to_string(),
             numeric_id: 24728,
             name: "Lana".to_string(),
             effect: "You can use this card only if you have Araquanid in play.Switch in 1 of your opponent's Benched Pokémon to the Active Spot.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3153Sophocles => Card::Trainer(TrainerCard {
+        CardId::A3153Sophocles => CardData::Trainer(TrainerCard {
             id: "A3 153".to_string(),
             numeric_id: 24729,
             name: "Sophocles".to_string(),
             effect: "During this turn, attacks used by your Alolan Golem, Vikavolt, or Togedemaru do +30 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3154Mallow => Card::Trainer(TrainerCard {
+        CardId::A3154Mallow => CardData::Trainer(TrainerCard {
             id: "A3 154".to_string(),
             numeric_id: 24730,
             name: "Mallow".to_string(),
             effect: "Heal all damage from 1 of your Shiinotic or Tsareena. If you do, discard all Energy from that Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3155Lillie => Card::Trainer(TrainerCard {
+        CardId::A3155Lillie => CardData::Trainer(TrainerCard {
             id: "A3 155".to_string(),
             numeric_id: 24731,
             name: "Lillie".to_string(),
             effect: "Heal 60 damage from 1 of your Stage 2 Pokemon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3156AlolanExeggutor => Card::Pokemon(PokemonCard {
+        CardId::A3156AlolanExeggutor => CardData::Pokemon(PokemonCard {
             id: "A3 156".to_string(),
             name: "Alolan Exeggutor".to_string(),
             stage: 1,
@@ -19109,9 +19132,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3157Morelull => Card::Pokemon(PokemonCard {
+        CardId::A3157Morelull => CardData::Pokemon(PokemonCard {
             id: "A3 157".to_string(),
             name: "Morelull".to_string(),
             stage: 0,
@@ -19130,9 +19153,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3158Tsareena => Card::Pokemon(PokemonCard {
+        CardId::A3158Tsareena => CardData::Pokemon(PokemonCard {
             id: "A3 158".to_string(),
             name: "Tsareena".to_string(),
             stage: 2,
@@ -19151,9 +19174,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3159TapuBulu => Card::Pokemon(PokemonCard {
+        CardId::A3159TapuBulu => CardData::Pokemon(PokemonCard {
             id: "A3 159".to_string(),
             name: "Tapu Bulu".to_string(),
             stage: 0,
@@ -19172,9 +19195,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3160AlolanMarowak => Card::Pokemon(PokemonCard {
+        CardId::A3160AlolanMarowak => CardData::Pokemon(PokemonCard {
             id: "A3 160".to_string(),
             name: "Alolan Marowak".to_string(),
             stage: 1,
@@ -19193,9 +19216,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3161Turtonator => Card::Pokemon(PokemonCard {
+        CardId::A3161Turtonator => CardData::Pokemon(PokemonCard {
             id: "A3 161".to_string(),
             name: "Turtonator".to_string(),
             stage: 0,
@@ -19214,9 +19237,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3162AlolanVulpix => Card::Pokemon(PokemonCard {
+        CardId::A3162AlolanVulpix => CardData::Pokemon(PokemonCard {
             id: "A3 162".to_string(),
             name: "Alolan Vulpix".to_string(),
             stage: 0,
@@ -19235,9 +19258,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3163Pyukumuku => Card::Pokemon(PokemonCard {
+        CardId::A3163Pyukumuku => CardData::Pokemon(PokemonCard {
             id: "A3 163".to_string(),
             name: "Pyukumuku".to_string(),
             stage: 0,
@@ -19256,9 +19279,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3164TapuFini => Card::Pokemon(PokemonCard {
+        CardId::A3164TapuFini => CardData::Pokemon(PokemonCard {
             id: "A3 164".to_string(),
             name: "Tapu Fini".to_string(),
             stage: 0,
@@ -19277,9 +19300,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3165Oricorio => Card::Pokemon(PokemonCard {
+        CardId::A3165Oricorio => CardData::Pokemon(PokemonCard {
             id: "A3 165".to_string(),
             name: "Oricorio".to_string(),
             stage: 0,
@@ -19298,9 +19321,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3166TapuKoko => Card::Pokemon(PokemonCard {
+        CardId::A3166TapuKoko => CardData::Pokemon(PokemonCard {
             id: "A3 166".to_string(),
             name: "Tapu Koko".to_string(),
             stage: 0,
@@ -19319,9 +19342,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3167Cutiefly => Card::Pokemon(PokemonCard {
+        CardId::A3167Cutiefly => CardData::Pokemon(PokemonCard {
             id: "A3 167".to_string(),
             name: "Cutiefly".to_string(),
             stage: 0,
@@ -19340,9 +19363,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3168Comfey => Card::Pokemon(PokemonCard {
+        CardId::A3168Comfey => CardData::Pokemon(PokemonCard {
             id: "A3 168".to_string(),
             name: "Comfey".to_string(),
             stage: 0,
@@ -19361,9 +19384,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3169Sandygast => Card::Pokemon(PokemonCard {
+        CardId::A3169Sandygast => CardData::Pokemon(PokemonCard {
             id: "A3 169".to_string(),
             name: "Sandygast".to_string(),
             stage: 0,
@@ -19382,9 +19405,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3170TapuLele => Card::Pokemon(PokemonCard {
+        CardId::A3170TapuLele => CardData::Pokemon(PokemonCard {
             id: "A3 170".to_string(),
             name: "Tapu Lele".to_string(),
             stage: 0,
@@ -19403,9 +19426,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3171Cosmog => Card::Pokemon(PokemonCard {
+        CardId::A3171Cosmog => CardData::Pokemon(PokemonCard {
             id: "A3 171".to_string(),
             name: "Cosmog".to_string(),
             stage: 0,
@@ -19424,9 +19447,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3172Rockruff => Card::Pokemon(PokemonCard {
+        CardId::A3172Rockruff => CardData::Pokemon(PokemonCard {
             id: "A3 172".to_string(),
             name: "Rockruff".to_string(),
             stage: 0,
@@ -19445,9 +19468,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3173Mudsdale => Card::Pokemon(PokemonCard {
+        CardId::A3173Mudsdale => CardData::Pokemon(PokemonCard {
             id: "A3 173".to_string(),
             name: "Mudsdale".to_string(),
             stage: 1,
@@ -19466,9 +19489,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3174Minior => Card::Pokemon(PokemonCard {
+        CardId::A3174Minior => CardData::Pokemon(PokemonCard {
             id: "A3 174".to_string(),
             name: "Minior".to_string(),
             stage: 0,
@@ -19487,9 +19510,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3175Magearna => Card::Pokemon(PokemonCard {
+        CardId::A3175Magearna => CardData::Pokemon(PokemonCard {
             id: "A3 175".to_string(),
             name: "Magearna".to_string(),
             stage: 0,
@@ -19508,9 +19531,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3176Drampa => Card::Pokemon(PokemonCard {
+        CardId::A3176Drampa => CardData::Pokemon(PokemonCard {
             id: "A3 176".to_string(),
             name: "Drampa".to_string(),
             stage: 0,
@@ -19529,9 +19552,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3177Pikipek => Card::Pokemon(PokemonCard {
+        CardId::A3177Pikipek => CardData::Pokemon(PokemonCard {
             id: "A3 177".to_string(),
             name: "Pikipek".to_string(),
             stage: 0,
@@ -19550,9 +19573,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3178Bewear => Card::Pokemon(PokemonCard {
+        CardId::A3178Bewear => CardData::Pokemon(PokemonCard {
             id: "A3 178".to_string(),
             name: "Bewear".to_string(),
             stage: 1,
@@ -19571,9 +19594,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3179Komala => Card::Pokemon(PokemonCard {
+        CardId::A3179Komala => CardData::Pokemon(PokemonCard {
             id: "A3 179".to_string(),
             name: "Komala".to_string(),
             stage: 0,
@@ -19592,9 +19615,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3180DecidueyeEx => Card::Pokemon(PokemonCard {
+        CardId::A3180DecidueyeEx => CardData::Pokemon(PokemonCard {
             id: "A3 180".to_string(),
             name: "Decidueye ex".to_string(),
             stage: 2,
@@ -19619,9 +19642,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3181DhelmiseEx => Card::Pokemon(PokemonCard {
+        CardId::A3181DhelmiseEx => CardData::Pokemon(PokemonCard {
             id: "A3 181".to_string(),
             name: "Dhelmise ex".to_string(),
             stage: 0,
@@ -19640,9 +19663,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3182IncineroarEx => Card::Pokemon(PokemonCard {
+        CardId::A3182IncineroarEx => CardData::Pokemon(PokemonCard {
             id: "A3 182".to_string(),
             name: "Incineroar ex".to_string(),
             stage: 2,
@@ -19667,9 +19690,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3183CrabominableEx => Card::Pokemon(PokemonCard {
+        CardId::A3183CrabominableEx => CardData::Pokemon(PokemonCard {
             id: "A3 183".to_string(),
             name: "Crabominable ex".to_string(),
             stage: 1,
@@ -19688,9 +19711,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3184WishiwashiEx => Card::Pokemon(PokemonCard {
+        CardId::A3184WishiwashiEx => CardData::Pokemon(PokemonCard {
             id: "A3 184".to_string(),
             name: "Wishiwashi ex".to_string(),
             stage: 0,
@@ -19709,9 +19732,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3185AlolanRaichuEx => Card::Pokemon(PokemonCard {
+        CardId::A3185AlolanRaichuEx => CardData::Pokemon(PokemonCard {
             id: "A3 185".to_string(),
             name: "Alolan Raichu ex".to_string(),
             stage: 1,
@@ -19730,9 +19753,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3186LunalaEx => Card::Pokemon(PokemonCard {
+        CardId::A3186LunalaEx => CardData::Pokemon(PokemonCard {
             id: "A3 186".to_string(),
             name: "Lunala ex".to_string(),
             stage: 2,
@@ -19751,9 +19774,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3187PassimianEx => Card::Pokemon(PokemonCard {
+        CardId::A3187PassimianEx => CardData::Pokemon(PokemonCard {
             id: "A3 187".to_string(),
             name: "Passimian ex".to_string(),
             stage: 0,
@@ -19772,9 +19795,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3188AlolanMukEx => Card::Pokemon(PokemonCard {
+        CardId::A3188AlolanMukEx => CardData::Pokemon(PokemonCard {
             id: "A3 188".to_string(),
             name: "Alolan Muk ex".to_string(),
             stage: 1,
@@ -19793,9 +19816,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3189SolgaleoEx => Card::Pokemon(PokemonCard {
+        CardId::A3189SolgaleoEx => CardData::Pokemon(PokemonCard {
             id: "A3 189".to_string(),
             name: "Solgaleo ex".to_string(),
             stage: 2,
@@ -19814,81 +19837,81 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3190Acerola => Card::Trainer(TrainerCard {
+        CardId::A3190Acerola => CardData::Trainer(TrainerCard {
             id: "A3 190".to_string(),
             numeric_id: 24766,
             name: "Acerola".to_string(),
             effect: "Choose 1 of your Palossand or Mimikyu that has damage on it, and move 40 of its damage to your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3191Ilima => Card::Trainer(TrainerCard {
+        CardId::A3191Ilima => CardData::Trainer(TrainerCard {
             id: "A3 191".to_string(),
             numeric_id: 24767,
             name: "Ilima".to_string(),
             effect: "Put 1 of your [C] Pokémon that has damage on it into your hand.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3192Kiawe => Card::Trainer(TrainerCard {
+        CardId::A3192Kiawe => CardData::Trainer(TrainerCard {
             id: "A3 192".to_string(),
             numeric_id: 24768,
             name: "Kiawe".to_string(),
             effect: "Choose 1 of your Alolan Marowak or Turtonator. Take 2 [R] Energy from your Energy Zone and attach it to that Pokémon. Your turn ends.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3193Guzma => Card::Trainer(TrainerCard {
+        CardId::A3193Guzma => CardData::Trainer(TrainerCard {
             id: "A3 193".to_string(),
             numeric_id: 24769,
             name: "Guzma".to_string(),
             effect: "Discard all Pokémon Tool cards attached to each of your opponent's Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3194Lana => Card::Trainer(TrainerCard {
+        CardId::A3194Lana => CardData::Trainer(TrainerCard {
             id: "A3 194".to_string(),
             numeric_id: 24770,
             name: "Lana".to_string(),
             effect: "You can use this card only if you have Araquanid in play.Switch in 1 of your opponent's Benched Pokémon to the Active Spot.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3195Sophocles => Card::Trainer(TrainerCard {
+        CardId::A3195Sophocles => CardData::Trainer(TrainerCard {
             id: "A3 195".to_string(),
             numeric_id: 24771,
             name: "Sophocles".to_string(),
             effect: "During this turn, attacks used by your Alolan Golem, Vikavolt, or Togedemaru do +30 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3196Mallow => Card::Trainer(TrainerCard {
+        CardId::A3196Mallow => CardData::Trainer(TrainerCard {
             id: "A3 196".to_string(),
             numeric_id: 24772,
             name: "Mallow".to_string(),
             effect: "Heal all damage from 1 of your Shiinotic or Tsareena. If you do, discard all Energy from that Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3197Lillie => Card::Trainer(TrainerCard {
+        CardId::A3197Lillie => CardData::Trainer(TrainerCard {
             id: "A3 197".to_string(),
             numeric_id: 24773,
             name: "Lillie".to_string(),
             effect: "Heal 60 damage from 1 of your Stage 2 Pokemon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3198DecidueyeEx => Card::Pokemon(PokemonCard {
+        CardId::A3198DecidueyeEx => CardData::Pokemon(PokemonCard {
             id: "A3 198".to_string(),
             name: "Decidueye ex".to_string(),
             stage: 2,
@@ -19913,9 +19936,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3199DhelmiseEx => Card::Pokemon(PokemonCard {
+        CardId::A3199DhelmiseEx => CardData::Pokemon(PokemonCard {
             id: "A3 199".to_string(),
             name: "Dhelmise ex".to_string(),
             stage: 0,
@@ -19934,9 +19957,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3200IncineroarEx => Card::Pokemon(PokemonCard {
+        CardId::A3200IncineroarEx => CardData::Pokemon(PokemonCard {
             id: "A3 200".to_string(),
             name: "Incineroar ex".to_string(),
             stage: 2,
@@ -19961,9 +19984,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3201CrabominableEx => Card::Pokemon(PokemonCard {
+        CardId::A3201CrabominableEx => CardData::Pokemon(PokemonCard {
             id: "A3 201".to_string(),
             name: "Crabominable ex".to_string(),
             stage: 1,
@@ -19982,9 +20005,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3202WishiwashiEx => Card::Pokemon(PokemonCard {
+        CardId::A3202WishiwashiEx => CardData::Pokemon(PokemonCard {
             id: "A3 202".to_string(),
             name: "Wishiwashi ex".to_string(),
             stage: 0,
@@ -20003,9 +20026,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3203AlolanRaichuEx => Card::Pokemon(PokemonCard {
+        CardId::A3203AlolanRaichuEx => CardData::Pokemon(PokemonCard {
             id: "A3 203".to_string(),
             name: "Alolan Raichu ex".to_string(),
             stage: 1,
@@ -20024,9 +20047,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3204LunalaEx => Card::Pokemon(PokemonCard {
+        CardId::A3204LunalaEx => CardData::Pokemon(PokemonCard {
             id: "A3 204".to_string(),
             name: "Lunala ex".to_string(),
             stage: 2,
@@ -20045,9 +20068,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3205PassimianEx => Card::Pokemon(PokemonCard {
+        CardId::A3205PassimianEx => CardData::Pokemon(PokemonCard {
             id: "A3 205".to_string(),
             name: "Passimian ex".to_string(),
             stage: 0,
@@ -20066,9 +20089,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3206AlolanMukEx => Card::Pokemon(PokemonCard {
+        CardId::A3206AlolanMukEx => CardData::Pokemon(PokemonCard {
             id: "A3 206".to_string(),
             name: "Alolan Muk ex".to_string(),
             stage: 1,
@@ -20087,9 +20110,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3207SolgaleoEx => Card::Pokemon(PokemonCard {
+        CardId::A3207SolgaleoEx => CardData::Pokemon(PokemonCard {
             id: "A3 207".to_string(),
             name: "Solgaleo ex".to_string(),
             stage: 2,
@@ -20108,27 +20131,27 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3208Guzma => Card::Trainer(TrainerCard {
+        CardId::A3208Guzma => CardData::Trainer(TrainerCard {
             id: "A3 208".to_string(),
             numeric_id: 24784,
             name: "Guzma".to_string(),
             effect: "Discard all Pokémon Tool cards attached to each of your opponent's Pokémon.".to_string(),
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3209Lillie => Card::Trainer(TrainerCard {
+        CardId::A3209Lillie => CardData::Trainer(TrainerCard {
             id: "A3 209".to_string(),
             numeric_id: 24785,
             name: "Lillie".to_string(),
             effect: "Heal 60 damage from 1 of your Stage 2 Pokemon.".to_string(),
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3210Bulbasaur => Card::Pokemon(PokemonCard {
+        CardId::A3210Bulbasaur => CardData::Pokemon(PokemonCard {
             id: "A3 210".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -20147,9 +20170,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3211Ivysaur => Card::Pokemon(PokemonCard {
+        CardId::A3211Ivysaur => CardData::Pokemon(PokemonCard {
             id: "A3 211".to_string(),
             name: "Ivysaur".to_string(),
             stage: 1,
@@ -20168,9 +20191,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3212Venusaur => Card::Pokemon(PokemonCard {
+        CardId::A3212Venusaur => CardData::Pokemon(PokemonCard {
             id: "A3 212".to_string(),
             name: "Venusaur".to_string(),
             stage: 2,
@@ -20189,9 +20212,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3213Exeggcute => Card::Pokemon(PokemonCard {
+        CardId::A3213Exeggcute => CardData::Pokemon(PokemonCard {
             id: "A3 213".to_string(),
             name: "Exeggcute".to_string(),
             stage: 0,
@@ -20210,9 +20233,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3214Exeggutor => Card::Pokemon(PokemonCard {
+        CardId::A3214Exeggutor => CardData::Pokemon(PokemonCard {
             id: "A3 214".to_string(),
             name: "Exeggutor".to_string(),
             stage: 1,
@@ -20231,9 +20254,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3215Squirtle => Card::Pokemon(PokemonCard {
+        CardId::A3215Squirtle => CardData::Pokemon(PokemonCard {
             id: "A3 215".to_string(),
             name: "Squirtle".to_string(),
             stage: 0,
@@ -20252,9 +20275,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3216Wartortle => Card::Pokemon(PokemonCard {
+        CardId::A3216Wartortle => CardData::Pokemon(PokemonCard {
             id: "A3 216".to_string(),
             name: "Wartortle".to_string(),
             stage: 1,
@@ -20273,9 +20296,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3217Blastoise => Card::Pokemon(PokemonCard {
+        CardId::A3217Blastoise => CardData::Pokemon(PokemonCard {
             id: "A3 217".to_string(),
             name: "Blastoise".to_string(),
             stage: 2,
@@ -20294,9 +20317,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3218Staryu => Card::Pokemon(PokemonCard {
+        CardId::A3218Staryu => CardData::Pokemon(PokemonCard {
             id: "A3 218".to_string(),
             name: "Staryu".to_string(),
             stage: 0,
@@ -20315,9 +20338,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3219Starmie => Card::Pokemon(PokemonCard {
+        CardId::A3219Starmie => CardData::Pokemon(PokemonCard {
             id: "A3 219".to_string(),
             name: "Starmie".to_string(),
             stage: 1,
@@ -20336,9 +20359,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3220Gastly => Card::Pokemon(PokemonCard {
+        CardId::A3220Gastly => CardData::Pokemon(PokemonCard {
             id: "A3 220".to_string(),
             name: "Gastly".to_string(),
             stage: 0,
@@ -20357,9 +20380,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3221Haunter => Card::Pokemon(PokemonCard {
+        CardId::A3221Haunter => CardData::Pokemon(PokemonCard {
             id: "A3 221".to_string(),
             name: "Haunter".to_string(),
             stage: 1,
@@ -20378,9 +20401,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3222Gengar => Card::Pokemon(PokemonCard {
+        CardId::A3222Gengar => CardData::Pokemon(PokemonCard {
             id: "A3 222".to_string(),
             name: "Gengar".to_string(),
             stage: 2,
@@ -20399,9 +20422,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3223Machop => Card::Pokemon(PokemonCard {
+        CardId::A3223Machop => CardData::Pokemon(PokemonCard {
             id: "A3 223".to_string(),
             name: "Machop".to_string(),
             stage: 0,
@@ -20420,9 +20443,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3224Machoke => Card::Pokemon(PokemonCard {
+        CardId::A3224Machoke => CardData::Pokemon(PokemonCard {
             id: "A3 224".to_string(),
             name: "Machoke".to_string(),
             stage: 1,
@@ -20441,9 +20464,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3225Machamp => Card::Pokemon(PokemonCard {
+        CardId::A3225Machamp => CardData::Pokemon(PokemonCard {
             id: "A3 225".to_string(),
             name: "Machamp".to_string(),
             stage: 2,
@@ -20462,9 +20485,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3226Cubone => Card::Pokemon(PokemonCard {
+        CardId::A3226Cubone => CardData::Pokemon(PokemonCard {
             id: "A3 226".to_string(),
             name: "Cubone".to_string(),
             stage: 0,
@@ -20483,9 +20506,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3227Marowak => Card::Pokemon(PokemonCard {
+        CardId::A3227Marowak => CardData::Pokemon(PokemonCard {
             id: "A3 227".to_string(),
             name: "Marowak".to_string(),
             stage: 1,
@@ -20504,9 +20527,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3228Jigglypuff => Card::Pokemon(PokemonCard {
+        CardId::A3228Jigglypuff => CardData::Pokemon(PokemonCard {
             id: "A3 228".to_string(),
             name: "Jigglypuff".to_string(),
             stage: 0,
@@ -20525,9 +20548,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3229Wigglytuff => Card::Pokemon(PokemonCard {
+        CardId::A3229Wigglytuff => CardData::Pokemon(PokemonCard {
             id: "A3 229".to_string(),
             name: "Wigglytuff".to_string(),
             stage: 1,
@@ -20546,9 +20569,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3230VenusaurEx => Card::Pokemon(PokemonCard {
+        CardId::A3230VenusaurEx => CardData::Pokemon(PokemonCard {
             id: "A3 230".to_string(),
             name: "Venusaur ex".to_string(),
             stage: 2,
@@ -20573,9 +20596,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3231ExeggutorEx => Card::Pokemon(PokemonCard {
+        CardId::A3231ExeggutorEx => CardData::Pokemon(PokemonCard {
             id: "A3 231".to_string(),
             name: "Exeggutor ex".to_string(),
             stage: 1,
@@ -20594,9 +20617,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3232BlastoiseEx => Card::Pokemon(PokemonCard {
+        CardId::A3232BlastoiseEx => CardData::Pokemon(PokemonCard {
             id: "A3 232".to_string(),
             name: "Blastoise ex".to_string(),
             stage: 2,
@@ -20621,9 +20644,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3233StarmieEx => Card::Pokemon(PokemonCard {
+        CardId::A3233StarmieEx => CardData::Pokemon(PokemonCard {
             id: "A3 233".to_string(),
             name: "Starmie ex".to_string(),
             stage: 1,
@@ -20642,9 +20665,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3234GengarEx => Card::Pokemon(PokemonCard {
+        CardId::A3234GengarEx => CardData::Pokemon(PokemonCard {
             id: "A3 234".to_string(),
             name: "Gengar ex".to_string(),
             stage: 2,
@@ -20663,9 +20686,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3235MachampEx => Card::Pokemon(PokemonCard {
+        CardId::A3235MachampEx => CardData::Pokemon(PokemonCard {
             id: "A3 235".to_string(),
             name: "Machamp ex".to_string(),
             stage: 2,
@@ -20684,9 +20707,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3236MarowakEx => Card::Pokemon(PokemonCard {
+        CardId::A3236MarowakEx => CardData::Pokemon(PokemonCard {
             id: "A3 236".to_string(),
             name: "Marowak ex".to_string(),
             stage: 1,
@@ -20705,9 +20728,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3237WigglytuffEx => Card::Pokemon(PokemonCard {
+        CardId::A3237WigglytuffEx => CardData::Pokemon(PokemonCard {
             id: "A3 237".to_string(),
             name: "Wigglytuff ex".to_string(),
             stage: 1,
@@ -20726,9 +20749,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3238LunalaEx => Card::Pokemon(PokemonCard {
+        CardId::A3238LunalaEx => CardData::Pokemon(PokemonCard {
             id: "A3 238".to_string(),
             name: "Lunala ex".to_string(),
             stage: 2,
@@ -20747,9 +20770,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3239SolgaleoEx => Card::Pokemon(PokemonCard {
+        CardId::A3239SolgaleoEx => CardData::Pokemon(PokemonCard {
             id: "A3 239".to_string(),
             name: "Solgaleo ex".to_string(),
             stage: 2,
@@ -20768,9 +20791,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Celestial Guardians (A3)".to_string(),
+            set: SetId::A3,
         }),
-        CardId::A3a001Petilil => Card::Pokemon(PokemonCard {
+        CardId::A3a001Petilil => CardData::Pokemon(PokemonCard {
             id: "A3a 001".to_string(),
             name: "Petilil".to_string(),
             stage: 0,
@@ -20789,9 +20812,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a002Lilligant => Card::Pokemon(PokemonCard {
+        CardId::A3a002Lilligant => CardData::Pokemon(PokemonCard {
             id: "A3a 002".to_string(),
             name: "Lilligant".to_string(),
             stage: 1,
@@ -20810,9 +20833,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a003Rowlet => Card::Pokemon(PokemonCard {
+        CardId::A3a003Rowlet => CardData::Pokemon(PokemonCard {
             id: "A3a 003".to_string(),
             name: "Rowlet".to_string(),
             stage: 0,
@@ -20831,9 +20854,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a004Dartrix => Card::Pokemon(PokemonCard {
+        CardId::A3a004Dartrix => CardData::Pokemon(PokemonCard {
             id: "A3a 004".to_string(),
             name: "Dartrix".to_string(),
             stage: 1,
@@ -20852,9 +20875,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a005Decidueye => Card::Pokemon(PokemonCard {
+        CardId::A3a005Decidueye => CardData::Pokemon(PokemonCard {
             id: "A3a 005".to_string(),
             name: "Decidueye".to_string(),
             stage: 2,
@@ -20873,9 +20896,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a006BuzzwoleEx => Card::Pokemon(PokemonCard {
+        CardId::A3a006BuzzwoleEx => CardData::Pokemon(PokemonCard {
             id: "A3a 006".to_string(),
             name: "Buzzwole ex".to_string(),
             stage: 0,
@@ -20900,9 +20923,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a007Pheromosa => Card::Pokemon(PokemonCard {
+        CardId::A3a007Pheromosa => CardData::Pokemon(PokemonCard {
             id: "A3a 007".to_string(),
             name: "Pheromosa".to_string(),
             stage: 0,
@@ -20921,9 +20944,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a008Kartana => Card::Pokemon(PokemonCard {
+        CardId::A3a008Kartana => CardData::Pokemon(PokemonCard {
             id: "A3a 008".to_string(),
             name: "Kartana".to_string(),
             stage: 0,
@@ -20942,9 +20965,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a009Blacephalon => Card::Pokemon(PokemonCard {
+        CardId::A3a009Blacephalon => CardData::Pokemon(PokemonCard {
             id: "A3a 009".to_string(),
             name: "Blacephalon".to_string(),
             stage: 0,
@@ -20963,9 +20986,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a010Mantine => Card::Pokemon(PokemonCard {
+        CardId::A3a010Mantine => CardData::Pokemon(PokemonCard {
             id: "A3a 010".to_string(),
             name: "Mantine".to_string(),
             stage: 0,
@@ -20984,9 +21007,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a011Carvanha => Card::Pokemon(PokemonCard {
+        CardId::A3a011Carvanha => CardData::Pokemon(PokemonCard {
             id: "A3a 011".to_string(),
             name: "Carvanha".to_string(),
             stage: 0,
@@ -21005,9 +21028,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a012Sharpedo => Card::Pokemon(PokemonCard {
+        CardId::A3a012Sharpedo => CardData::Pokemon(PokemonCard {
             id: "A3a 012".to_string(),
             name: "Sharpedo".to_string(),
             stage: 1,
@@ -21026,9 +21049,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a013Shinx => Card::Pokemon(PokemonCard {
+        CardId::A3a013Shinx => CardData::Pokemon(PokemonCard {
             id: "A3a 013".to_string(),
             name: "Shinx".to_string(),
             stage: 0,
@@ -21047,9 +21070,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a014Luxio => Card::Pokemon(PokemonCard {
+        CardId::A3a014Luxio => CardData::Pokemon(PokemonCard {
             id: "A3a 014".to_string(),
             name: "Luxio".to_string(),
             stage: 1,
@@ -21068,9 +21091,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a015Luxray => Card::Pokemon(PokemonCard {
+        CardId::A3a015Luxray => CardData::Pokemon(PokemonCard {
             id: "A3a 015".to_string(),
             name: "Luxray".to_string(),
             stage: 2,
@@ -21089,9 +21112,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a016Blitzle => Card::Pokemon(PokemonCard {
+        CardId::A3a016Blitzle => CardData::Pokemon(PokemonCard {
             id: "A3a 016".to_string(),
             name: "Blitzle".to_string(),
             stage: 0,
@@ -21110,9 +21133,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a017Zebstrika => Card::Pokemon(PokemonCard {
+        CardId::A3a017Zebstrika => CardData::Pokemon(PokemonCard {
             id: "A3a 017".to_string(),
             name: "Zebstrika".to_string(),
             stage: 1,
@@ -21131,9 +21154,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a018Emolga => Card::Pokemon(PokemonCard {
+        CardId::A3a018Emolga => CardData::Pokemon(PokemonCard {
             id: "A3a 018".to_string(),
             name: "Emolga".to_string(),
             stage: 0,
@@ -21152,9 +21175,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a019TapuKokoEx => Card::Pokemon(PokemonCard {
+        CardId::A3a019TapuKokoEx => CardData::Pokemon(PokemonCard {
             id: "A3a 019".to_string(),
             name: "Tapu Koko ex".to_string(),
             stage: 0,
@@ -21179,9 +21202,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a020Xurkitree => Card::Pokemon(PokemonCard {
+        CardId::A3a020Xurkitree => CardData::Pokemon(PokemonCard {
             id: "A3a 020".to_string(),
             name: "Xurkitree".to_string(),
             stage: 0,
@@ -21200,9 +21223,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a021Zeraora => Card::Pokemon(PokemonCard {
+        CardId::A3a021Zeraora => CardData::Pokemon(PokemonCard {
             id: "A3a 021".to_string(),
             name: "Zeraora".to_string(),
             stage: 0,
@@ -21221,9 +21244,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a022Clefairy => Card::Pokemon(PokemonCard {
+        CardId::A3a022Clefairy => CardData::Pokemon(PokemonCard {
             id: "A3a 022".to_string(),
             name: "Clefairy".to_string(),
             stage: 0,
@@ -21242,9 +21265,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a023Clefable => Card::Pokemon(PokemonCard {
+        CardId::A3a023Clefable => CardData::Pokemon(PokemonCard {
             id: "A3a 023".to_string(),
             name: "Clefable".to_string(),
             stage: 1,
@@ -21263,9 +21286,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a024Phantump => Card::Pokemon(PokemonCard {
+        CardId::A3a024Phantump => CardData::Pokemon(PokemonCard {
             id: "A3a 024".to_string(),
             name: "Phantump".to_string(),
             stage: 0,
@@ -21284,9 +21307,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a025Trevenant => Card::Pokemon(PokemonCard {
+        CardId::A3a025Trevenant => CardData::Pokemon(PokemonCard {
             id: "A3a 025".to_string(),
             name: "Trevenant".to_string(),
             stage: 1,
@@ -21305,9 +21328,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a026Morelull => Card::Pokemon(PokemonCard {
+        CardId::A3a026Morelull => CardData::Pokemon(PokemonCard {
             id: "A3a 026".to_string(),
             name: "Morelull".to_string(),
             stage: 0,
@@ -21326,9 +21349,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a027Shiinotic => Card::Pokemon(PokemonCard {
+        CardId::A3a027Shiinotic => CardData::Pokemon(PokemonCard {
             id: "A3a 027".to_string(),
             name: "Shiinotic".to_string(),
             stage: 1,
@@ -21347,9 +21370,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a028Meditite => Card::Pokemon(PokemonCard {
+        CardId::A3a028Meditite => CardData::Pokemon(PokemonCard {
             id: "A3a 028".to_string(),
             name: "Meditite".to_string(),
             stage: 0,
@@ -21368,9 +21391,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a029Medicham => Card::Pokemon(PokemonCard {
+        CardId::A3a029Medicham => CardData::Pokemon(PokemonCard {
             id: "A3a 029".to_string(),
             name: "Medicham".to_string(),
             stage: 1,
@@ -21389,9 +21412,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a030Baltoy => Card::Pokemon(PokemonCard {
+        CardId::A3a030Baltoy => CardData::Pokemon(PokemonCard {
             id: "A3a 030".to_string(),
             name: "Baltoy".to_string(),
             stage: 0,
@@ -21410,9 +21433,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a031Claydol => Card::Pokemon(PokemonCard {
+        CardId::A3a031Claydol => CardData::Pokemon(PokemonCard {
             id: "A3a 031".to_string(),
             name: "Claydol".to_string(),
             stage: 1,
@@ -21431,9 +21454,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a032Rockruff => Card::Pokemon(PokemonCard {
+        CardId::A3a032Rockruff => CardData::Pokemon(PokemonCard {
             id: "A3a 032".to_string(),
             name: "Rockruff".to_string(),
             stage: 0,
@@ -21452,9 +21475,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a033LycanrocEx => Card::Pokemon(PokemonCard {
+        CardId::A3a033LycanrocEx => CardData::Pokemon(PokemonCard {
             id: "A3a 033".to_string(),
             name: "Lycanroc ex".to_string(),
             stage: 1,
@@ -21473,9 +21496,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a034Passimian => Card::Pokemon(PokemonCard {
+        CardId::A3a034Passimian => CardData::Pokemon(PokemonCard {
             id: "A3a 034".to_string(),
             name: "Passimian".to_string(),
             stage: 0,
@@ -21494,9 +21517,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a035Sandygast => Card::Pokemon(PokemonCard {
+        CardId::A3a035Sandygast => CardData::Pokemon(PokemonCard {
             id: "A3a 035".to_string(),
             name: "Sandygast".to_string(),
             stage: 0,
@@ -21515,9 +21538,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a036Palossand => Card::Pokemon(PokemonCard {
+        CardId::A3a036Palossand => CardData::Pokemon(PokemonCard {
             id: "A3a 036".to_string(),
             name: "Palossand".to_string(),
             stage: 1,
@@ -21536,9 +21559,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a037AlolanMeowth => Card::Pokemon(PokemonCard {
+        CardId::A3a037AlolanMeowth => CardData::Pokemon(PokemonCard {
             id: "A3a 037".to_string(),
             name: "Alolan Meowth".to_string(),
             stage: 0,
@@ -21557,9 +21580,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a038AlolanPersian => Card::Pokemon(PokemonCard {
+        CardId::A3a038AlolanPersian => CardData::Pokemon(PokemonCard {
             id: "A3a 038".to_string(),
             name: "Alolan Persian".to_string(),
             stage: 1,
@@ -21578,9 +21601,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a039Sandile => Card::Pokemon(PokemonCard {
+        CardId::A3a039Sandile => CardData::Pokemon(PokemonCard {
             id: "A3a 039".to_string(),
             name: "Sandile".to_string(),
             stage: 0,
@@ -21599,9 +21622,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a040Krokorok => Card::Pokemon(PokemonCard {
+        CardId::A3a040Krokorok => CardData::Pokemon(PokemonCard {
             id: "A3a 040".to_string(),
             name: "Krokorok".to_string(),
             stage: 1,
@@ -21620,9 +21643,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a041Krookodile => Card::Pokemon(PokemonCard {
+        CardId::A3a041Krookodile => CardData::Pokemon(PokemonCard {
             id: "A3a 041".to_string(),
             name: "Krookodile".to_string(),
             stage: 2,
@@ -21641,9 +21664,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a042Nihilego => Card::Pokemon(PokemonCard {
+        CardId::A3a042Nihilego => CardData::Pokemon(PokemonCard {
             id: "A3a 042".to_string(),
             name: "Nihilego".to_string(),
             stage: 0,
@@ -21662,9 +21685,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a043GuzzlordEx => Card::Pokemon(PokemonCard {
+        CardId::A3a043GuzzlordEx => CardData::Pokemon(PokemonCard {
             id: "A3a 043".to_string(),
             name: "Guzzlord ex".to_string(),
             stage: 0,
@@ -21689,9 +21712,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a044Poipole => Card::Pokemon(PokemonCard {
+        CardId::A3a044Poipole => CardData::Pokemon(PokemonCard {
             id: "A3a 044".to_string(),
             name: "Poipole".to_string(),
             stage: 0,
@@ -21710,9 +21733,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a045Naganadel => Card::Pokemon(PokemonCard {
+        CardId::A3a045Naganadel => CardData::Pokemon(PokemonCard {
             id: "A3a 045".to_string(),
             name: "Naganadel".to_string(),
             stage: 1,
@@ -21731,9 +21754,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a046AlolanDiglett => Card::Pokemon(PokemonCard {
+        CardId::A3a046AlolanDiglett => CardData::Pokemon(PokemonCard {
             id: "A3a 046".to_string(),
             name: "Alolan Diglett".to_string(),
             stage: 0,
@@ -21752,9 +21775,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a047AlolanDugtrioEx => Card::Pokemon(PokemonCard {
+        CardId::A3a047AlolanDugtrioEx => CardData::Pokemon(PokemonCard {
             id: "A3a 047".to_string(),
             name: "Alolan Dugtrio ex".to_string(),
             stage: 1,
@@ -21773,9 +21796,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a048Aron => Card::Pokemon(PokemonCard {
+        CardId::A3a048Aron => CardData::Pokemon(PokemonCard {
             id: "A3a 048".to_string(),
             name: "Aron".to_string(),
             stage: 0,
@@ -21794,9 +21817,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a049Lairon => Card::Pokemon(PokemonCard {
+        CardId::A3a049Lairon => CardData::Pokemon(PokemonCard {
             id: "A3a 049".to_string(),
             name: "Lairon".to_string(),
             stage: 1,
@@ -21815,9 +21838,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a050Aggron => Card::Pokemon(PokemonCard {
+        CardId::A3a050Aggron => CardData::Pokemon(PokemonCard {
             id: "A3a 050".to_string(),
             name: "Aggron".to_string(),
             stage: 2,
@@ -21836,9 +21859,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a051Ferroseed => Card::Pokemon(PokemonCard {
+        CardId::A3a051Ferroseed => CardData::Pokemon(PokemonCard {
             id: "A3a 051".to_string(),
             name: "Ferroseed".to_string(),
             stage: 0,
@@ -21857,9 +21880,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a052Ferrothorn => Card::Pokemon(PokemonCard {
+        CardId::A3a052Ferrothorn => CardData::Pokemon(PokemonCard {
             id: "A3a 052".to_string(),
             name: "Ferrothorn".to_string(),
             stage: 1,
@@ -21878,9 +21901,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a053Stakataka => Card::Pokemon(PokemonCard {
+        CardId::A3a053Stakataka => CardData::Pokemon(PokemonCard {
             id: "A3a 053".to_string(),
             name: "Stakataka".to_string(),
             stage: 0,
@@ -21899,9 +21922,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a054Lillipup => Card::Pokemon(PokemonCard {
+        CardId::A3a054Lillipup => CardData::Pokemon(PokemonCard {
             id: "A3a 054".to_string(),
             name: "Lillipup".to_string(),
             stage: 0,
@@ -21920,9 +21943,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a055Herdier => Card::Pokemon(PokemonCard {
+        CardId::A3a055Herdier => CardData::Pokemon(PokemonCard {
             id: "A3a 055".to_string(),
             name: "Herdier".to_string(),
             stage: 1,
@@ -21941,9 +21964,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a056Stoutland => Card::Pokemon(PokemonCard {
+        CardId::A3a056Stoutland => CardData::Pokemon(PokemonCard {
             id: "A3a 056".to_string(),
             name: "Stoutland".to_string(),
             stage: 2,
@@ -21962,9 +21985,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a057Stufful => Card::Pokemon(PokemonCard {
+        CardId::A3a057Stufful => CardData::Pokemon(PokemonCard {
             id: "A3a 057".to_string(),
             name: "Stufful".to_string(),
             stage: 0,
@@ -21983,9 +22006,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a058Bewear => Card::Pokemon(PokemonCard {
+        CardId::A3a058Bewear => CardData::Pokemon(PokemonCard {
             id: "A3a 058".to_string(),
             name: "Bewear".to_string(),
             stage: 1,
@@ -22004,9 +22027,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a059Oranguru => Card::Pokemon(PokemonCard {
+        CardId::A3a059Oranguru => CardData::Pokemon(PokemonCard {
             id: "A3a 059".to_string(),
             name: "Oranguru".to_string(),
             stage: 0,
@@ -22025,9 +22048,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a060TypeNull => Card::Pokemon(PokemonCard {
+        CardId::A3a060TypeNull => CardData::Pokemon(PokemonCard {
             id: "A3a 060".to_string(),
             name: "Type: Null".to_string(),
             stage: 0,
@@ -22046,9 +22069,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a061Silvally => Card::Pokemon(PokemonCard {
+        CardId::A3a061Silvally => CardData::Pokemon(PokemonCard {
             id: "A3a 061".to_string(),
             name: "Silvally".to_string(),
             stage: 1,
@@ -22067,9 +22090,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a062Celesteela => Card::Pokemon(PokemonCard {
+        CardId::A3a062Celesteela => CardData::Pokemon(PokemonCard {
             id: "A3a 062".to_string(),
             name: "Celesteela".to_string(),
             stage: 0,
@@ -22088,72 +22111,72 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a063BeastWall => Card::Trainer(TrainerCard {
+        CardId::A3a063BeastWall => CardData::Trainer(TrainerCard {
             id: "A3a 063".to_string(),
             numeric_id: 28735,
             name: "Beast Wall".to_string(),
             effect: "You can use this card only if your opponent hasn't gotten any points.During your opponent's next turn, all of your Ultra Beasts take −20 damage from attacks from your opponent's Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3a064Repel => Card::Trainer(TrainerCard {
+        CardId::A3a064Repel => CardData::Trainer(TrainerCard {
             id: "A3a 064".to_string(),
             numeric_id: 28736,
             name: "Repel".to_string(),
             effect: "Switch out your opponent's Active Basic Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3a065ElectricalCord => Card::Trainer(TrainerCard {
+        CardId::A3a065ElectricalCord => CardData::Trainer(TrainerCard {
             id: "A3a 065".to_string(),
             numeric_id: 28737,
             name: "Electrical Cord".to_string(),
             effect: "If the [L] Pokémon this card is attached to is in the Active Spot and is Knocked Out by damage from an attack from your opponent's Pokémon, move 2 [L] Energy from that Pokémon and attach 1 Energy each to 2 of your Benched Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A3a066Beastite => Card::Trainer(TrainerCard {
+        CardId::A3a066Beastite => CardData::Trainer(TrainerCard {
             id: "A3a 066".to_string(),
             numeric_id: 28738,
             name: "Beastite".to_string(),
             effect: "Attacks used by the Ultra Beast this card is attached to do +10 damage to your opponent's Active Pokémon for each point you have gotten.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A3a067Gladion => Card::Trainer(TrainerCard {
+        CardId::A3a067Gladion => CardData::Trainer(TrainerCard {
             id: "A3a 067".to_string(),
             numeric_id: 28739,
             name: "Gladion".to_string(),
             effect: "Put 1 random Type: Null or Silvally from your deck into your hand.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3a068Looker => Card::Trainer(TrainerCard {
+        CardId::A3a068Looker => CardData::Trainer(TrainerCard {
             id: "A3a 068".to_string(),
             numeric_id: 28740,
             name: "Looker".to_string(),
             effect: "Your opponent reveals all of the Supporter cards in their deck.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3a069Lusamine => Card::Trainer(TrainerCard {
+        CardId::A3a069Lusamine => CardData::Trainer(TrainerCard {
             id: "A3a 069".to_string(),
             numeric_id: 28741,
             name: "Lusamine".to_string(),
             effect: "You can use this card only if your opponent has gotten at least 1 point.Choose 1 of your Ultra Beasts. Attach 2 random Energy from your discard pile to that Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3a070Rowlet => Card::Pokemon(PokemonCard {
+        CardId::A3a070Rowlet => CardData::Pokemon(PokemonCard {
             id: "A3a 070".to_string(),
             name: "Rowlet".to_string(),
             stage: 0,
@@ -22172,9 +22195,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a071Pheromosa => Card::Pokemon(PokemonCard {
+        CardId::A3a071Pheromosa => CardData::Pokemon(PokemonCard {
             id: "A3a 071".to_string(),
             name: "Pheromosa".to_string(),
             stage: 0,
@@ -22193,9 +22216,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a072Blacephalon => Card::Pokemon(PokemonCard {
+        CardId::A3a072Blacephalon => CardData::Pokemon(PokemonCard {
             id: "A3a 072".to_string(),
             name: "Blacephalon".to_string(),
             stage: 0,
@@ -22214,9 +22237,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a073AlolanMeowth => Card::Pokemon(PokemonCard {
+        CardId::A3a073AlolanMeowth => CardData::Pokemon(PokemonCard {
             id: "A3a 073".to_string(),
             name: "Alolan Meowth".to_string(),
             stage: 0,
@@ -22235,9 +22258,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a074Silvally => Card::Pokemon(PokemonCard {
+        CardId::A3a074Silvally => CardData::Pokemon(PokemonCard {
             id: "A3a 074".to_string(),
             name: "Silvally".to_string(),
             stage: 1,
@@ -22256,9 +22279,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a075Celesteela => Card::Pokemon(PokemonCard {
+        CardId::A3a075Celesteela => CardData::Pokemon(PokemonCard {
             id: "A3a 075".to_string(),
             name: "Celesteela".to_string(),
             stage: 0,
@@ -22277,9 +22300,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a076BuzzwoleEx => Card::Pokemon(PokemonCard {
+        CardId::A3a076BuzzwoleEx => CardData::Pokemon(PokemonCard {
             id: "A3a 076".to_string(),
             name: "Buzzwole ex".to_string(),
             stage: 0,
@@ -22304,9 +22327,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a077TapuKokoEx => Card::Pokemon(PokemonCard {
+        CardId::A3a077TapuKokoEx => CardData::Pokemon(PokemonCard {
             id: "A3a 077".to_string(),
             name: "Tapu Koko ex".to_string(),
             stage: 0,
@@ -22331,9 +22354,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a078LycanrocEx => Card::Pokemon(PokemonCard {
+        CardId::A3a078LycanrocEx => CardData::Pokemon(PokemonCard {
             id: "A3a 078".to_string(),
             name: "Lycanroc ex".to_string(),
             stage: 1,
@@ -22352,9 +22375,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a079GuzzlordEx => Card::Pokemon(PokemonCard {
+        CardId::A3a079GuzzlordEx => CardData::Pokemon(PokemonCard {
             id: "A3a 079".to_string(),
             name: "Guzzlord ex".to_string(),
             stage: 0,
@@ -22379,9 +22402,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a080AlolanDugtrioEx => Card::Pokemon(PokemonCard {
+        CardId::A3a080AlolanDugtrioEx => CardData::Pokemon(PokemonCard {
             id: "A3a 080".to_string(),
             name: "Alolan Dugtrio ex".to_string(),
             stage: 1,
@@ -22400,36 +22423,36 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a081Gladion => Card::Trainer(TrainerCard {
+        CardId::A3a081Gladion => CardData::Trainer(TrainerCard {
             id: "A3a 081".to_string(),
             numeric_id: 28753,
             name: "Gladion".to_string(),
             effect: "Put 1 random Type: Null or Silvally from your deck into your hand.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3a082Looker => Card::Trainer(TrainerCard {
+        CardId::A3a082Looker => CardData::Trainer(TrainerCard {
             id: "A3a 082".to_string(),
             numeric_id: 28754,
             name: "Looker".to_string(),
             effect: "Your opponent reveals all of the Supporter cards in their deck.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3a083Lusamine => Card::Trainer(TrainerCard {
+        CardId::A3a083Lusamine => CardData::Trainer(TrainerCard {
             id: "A3a 083".to_string(),
             numeric_id: 28755,
             name: "Lusamine".to_string(),
             effect: "You can use this card only if your opponent has gotten at least 1 point.Choose 1 of your Ultra Beasts. Attach 2 random Energy from your discard pile to that Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3a084TapuKokoEx => Card::Pokemon(PokemonCard {
+        CardId::A3a084TapuKokoEx => CardData::Pokemon(PokemonCard {
             id: "A3a 084".to_string(),
             name: "Tapu Koko ex".to_string(),
             stage: 0,
@@ -22454,9 +22477,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a085LycanrocEx => Card::Pokemon(PokemonCard {
+        CardId::A3a085LycanrocEx => CardData::Pokemon(PokemonCard {
             id: "A3a 085".to_string(),
             name: "Lycanroc ex".to_string(),
             stage: 1,
@@ -22475,9 +22498,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a086GuzzlordEx => Card::Pokemon(PokemonCard {
+        CardId::A3a086GuzzlordEx => CardData::Pokemon(PokemonCard {
             id: "A3a 086".to_string(),
             name: "Guzzlord ex".to_string(),
             stage: 0,
@@ -22502,9 +22525,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a087AlolanDugtrioEx => Card::Pokemon(PokemonCard {
+        CardId::A3a087AlolanDugtrioEx => CardData::Pokemon(PokemonCard {
             id: "A3a 087".to_string(),
             name: "Alolan Dugtrio ex".to_string(),
             stage: 1,
@@ -22523,9 +22546,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a088BuzzwoleEx => Card::Pokemon(PokemonCard {
+        CardId::A3a088BuzzwoleEx => CardData::Pokemon(PokemonCard {
             id: "A3a 088".to_string(),
             name: "Buzzwole ex".to_string(),
             stage: 0,
@@ -22550,9 +22573,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a089Growlithe => Card::Pokemon(PokemonCard {
+        CardId::A3a089Growlithe => CardData::Pokemon(PokemonCard {
             id: "A3a 089".to_string(),
             name: "Growlithe".to_string(),
             stage: 0,
@@ -22571,9 +22594,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a090Arcanine => Card::Pokemon(PokemonCard {
+        CardId::A3a090Arcanine => CardData::Pokemon(PokemonCard {
             id: "A3a 090".to_string(),
             name: "Arcanine".to_string(),
             stage: 1,
@@ -22592,9 +22615,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a091Froakie => Card::Pokemon(PokemonCard {
+        CardId::A3a091Froakie => CardData::Pokemon(PokemonCard {
             id: "A3a 091".to_string(),
             name: "Froakie".to_string(),
             stage: 0,
@@ -22613,9 +22636,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a092Frogadier => Card::Pokemon(PokemonCard {
+        CardId::A3a092Frogadier => CardData::Pokemon(PokemonCard {
             id: "A3a 092".to_string(),
             name: "Frogadier".to_string(),
             stage: 1,
@@ -22634,9 +22657,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a093Greninja => Card::Pokemon(PokemonCard {
+        CardId::A3a093Greninja => CardData::Pokemon(PokemonCard {
             id: "A3a 093".to_string(),
             name: "Greninja".to_string(),
             stage: 2,
@@ -22655,9 +22678,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a094Jynx => Card::Pokemon(PokemonCard {
+        CardId::A3a094Jynx => CardData::Pokemon(PokemonCard {
             id: "A3a 094".to_string(),
             name: "Jynx".to_string(),
             stage: 0,
@@ -22676,9 +22699,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a095Pidgey => Card::Pokemon(PokemonCard {
+        CardId::A3a095Pidgey => CardData::Pokemon(PokemonCard {
             id: "A3a 095".to_string(),
             name: "Pidgey".to_string(),
             stage: 0,
@@ -22697,9 +22720,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a096Pidgeotto => Card::Pokemon(PokemonCard {
+        CardId::A3a096Pidgeotto => CardData::Pokemon(PokemonCard {
             id: "A3a 096".to_string(),
             name: "Pidgeotto".to_string(),
             stage: 1,
@@ -22718,9 +22741,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a097Pidgeot => Card::Pokemon(PokemonCard {
+        CardId::A3a097Pidgeot => CardData::Pokemon(PokemonCard {
             id: "A3a 097".to_string(),
             name: "Pidgeot".to_string(),
             stage: 2,
@@ -22739,9 +22762,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a098Aerodactyl => Card::Pokemon(PokemonCard {
+        CardId::A3a098Aerodactyl => CardData::Pokemon(PokemonCard {
             id: "A3a 098".to_string(),
             name: "Aerodactyl".to_string(),
             stage: 1,
@@ -22760,9 +22783,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a099CelebiEx => Card::Pokemon(PokemonCard {
+        CardId::A3a099CelebiEx => CardData::Pokemon(PokemonCard {
             id: "A3a 099".to_string(),
             name: "Celebi ex".to_string(),
             stage: 0,
@@ -22781,9 +22804,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a100ArcanineEx => Card::Pokemon(PokemonCard {
+        CardId::A3a100ArcanineEx => CardData::Pokemon(PokemonCard {
             id: "A3a 100".to_string(),
             name: "Arcanine ex".to_string(),
             stage: 1,
@@ -22802,9 +22825,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a101AerodactylEx => Card::Pokemon(PokemonCard {
+        CardId::A3a101AerodactylEx => CardData::Pokemon(PokemonCard {
             id: "A3a 101".to_string(),
             name: "Aerodactyl ex".to_string(),
             stage: 1,
@@ -22823,9 +22846,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a102PidgeotEx => Card::Pokemon(PokemonCard {
+        CardId::A3a102PidgeotEx => CardData::Pokemon(PokemonCard {
             id: "A3a 102".to_string(),
             name: "Pidgeot ex".to_string(),
             stage: 2,
@@ -22844,9 +22867,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3a103Nihilego => Card::Pokemon(PokemonCard {
+        CardId::A3a103Nihilego => CardData::Pokemon(PokemonCard {
             id: "A3a 103".to_string(),
             name: "Nihilego".to_string(),
             stage: 0,
@@ -22865,9 +22888,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Extradimensional Crisis (A3a)".to_string(),
+            set: SetId::A3a,
         }),
-        CardId::A3b001Tropius => Card::Pokemon(PokemonCard {
+        CardId::A3b001Tropius => CardData::Pokemon(PokemonCard {
             id: "A3b 001".to_string(),
             name: "Tropius".to_string(),
             stage: 0,
@@ -22886,9 +22909,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b002Leafeon => Card::Pokemon(PokemonCard {
+        CardId::A3b002Leafeon => CardData::Pokemon(PokemonCard {
             id: "A3b 002".to_string(),
             name: "Leafeon".to_string(),
             stage: 1,
@@ -22907,9 +22930,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b003Bounsweet => Card::Pokemon(PokemonCard {
+        CardId::A3b003Bounsweet => CardData::Pokemon(PokemonCard {
             id: "A3b 003".to_string(),
             name: "Bounsweet".to_string(),
             stage: 0,
@@ -22928,9 +22951,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b004Steenee => Card::Pokemon(PokemonCard {
+        CardId::A3b004Steenee => CardData::Pokemon(PokemonCard {
             id: "A3b 004".to_string(),
             name: "Steenee".to_string(),
             stage: 1,
@@ -22949,9 +22972,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b005Tsareena => Card::Pokemon(PokemonCard {
+        CardId::A3b005Tsareena => CardData::Pokemon(PokemonCard {
             id: "A3b 005".to_string(),
             name: "Tsareena".to_string(),
             stage: 2,
@@ -22970,9 +22993,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b006Applin => Card::Pokemon(PokemonCard {
+        CardId::A3b006Applin => CardData::Pokemon(PokemonCard {
             id: "A3b 006".to_string(),
             name: "Applin".to_string(),
             stage: 0,
@@ -22991,9 +23014,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b007Appletun => Card::Pokemon(PokemonCard {
+        CardId::A3b007Appletun => CardData::Pokemon(PokemonCard {
             id: "A3b 007".to_string(),
             name: "Appletun".to_string(),
             stage: 1,
@@ -23012,9 +23035,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b008Flareon => Card::Pokemon(PokemonCard {
+        CardId::A3b008Flareon => CardData::Pokemon(PokemonCard {
             id: "A3b 008".to_string(),
             name: "Flareon".to_string(),
             stage: 1,
@@ -23033,9 +23056,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b009FlareonEx => Card::Pokemon(PokemonCard {
+        CardId::A3b009FlareonEx => CardData::Pokemon(PokemonCard {
             id: "A3b 009".to_string(),
             name: "Flareon ex".to_string(),
             stage: 1,
@@ -23054,9 +23077,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b010Torkoal => Card::Pokemon(PokemonCard {
+        CardId::A3b010Torkoal => CardData::Pokemon(PokemonCard {
             id: "A3b 010".to_string(),
             name: "Torkoal".to_string(),
             stage: 0,
@@ -23075,9 +23098,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b011Litten => Card::Pokemon(PokemonCard {
+        CardId::A3b011Litten => CardData::Pokemon(PokemonCard {
             id: "A3b 011".to_string(),
             name: "Litten".to_string(),
             stage: 0,
@@ -23096,9 +23119,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b012Torracat => Card::Pokemon(PokemonCard {
+        CardId::A3b012Torracat => CardData::Pokemon(PokemonCard {
             id: "A3b 012".to_string(),
             name: "Torracat".to_string(),
             stage: 1,
@@ -23117,9 +23140,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b013Incineroar => Card::Pokemon(PokemonCard {
+        CardId::A3b013Incineroar => CardData::Pokemon(PokemonCard {
             id: "A3b 013".to_string(),
             name: "Incineroar".to_string(),
             stage: 2,
@@ -23138,9 +23161,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b014Salandit => Card::Pokemon(PokemonCard {
+        CardId::A3b014Salandit => CardData::Pokemon(PokemonCard {
             id: "A3b 014".to_string(),
             name: "Salandit".to_string(),
             stage: 0,
@@ -23159,9 +23182,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b015Salazzle => Card::Pokemon(PokemonCard {
+        CardId::A3b015Salazzle => CardData::Pokemon(PokemonCard {
             id: "A3b 015".to_string(),
             name: "Salazzle".to_string(),
             stage: 1,
@@ -23180,9 +23203,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b016Vaporeon => Card::Pokemon(PokemonCard {
+        CardId::A3b016Vaporeon => CardData::Pokemon(PokemonCard {
             id: "A3b 016".to_string(),
             name: "Vaporeon".to_string(),
             stage: 1,
@@ -23201,9 +23224,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b017Glaceon => Card::Pokemon(PokemonCard {
+        CardId::A3b017Glaceon => CardData::Pokemon(PokemonCard {
             id: "A3b 017".to_string(),
             name: "Glaceon".to_string(),
             stage: 1,
@@ -23222,9 +23245,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b018Vanillite => Card::Pokemon(PokemonCard {
+        CardId::A3b018Vanillite => CardData::Pokemon(PokemonCard {
             id: "A3b 018".to_string(),
             name: "Vanillite".to_string(),
             stage: 0,
@@ -23243,9 +23266,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b019Vanillish => Card::Pokemon(PokemonCard {
+        CardId::A3b019Vanillish => CardData::Pokemon(PokemonCard {
             id: "A3b 019".to_string(),
             name: "Vanillish".to_string(),
             stage: 1,
@@ -23264,9 +23287,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b020Vanilluxe => Card::Pokemon(PokemonCard {
+        CardId::A3b020Vanilluxe => CardData::Pokemon(PokemonCard {
             id: "A3b 020".to_string(),
             name: "Vanilluxe".to_string(),
             stage: 2,
@@ -23285,9 +23308,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b021Alomomola => Card::Pokemon(PokemonCard {
+        CardId::A3b021Alomomola => CardData::Pokemon(PokemonCard {
             id: "A3b 021".to_string(),
             name: "Alomomola".to_string(),
             stage: 0,
@@ -23306,9 +23329,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b022Popplio => Card::Pokemon(PokemonCard {
+        CardId::A3b022Popplio => CardData::Pokemon(PokemonCard {
             id: "A3b 022".to_string(),
             name: "Popplio".to_string(),
             stage: 0,
@@ -23327,9 +23350,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b023Brionne => Card::Pokemon(PokemonCard {
+        CardId::A3b023Brionne => CardData::Pokemon(PokemonCard {
             id: "A3b 023".to_string(),
             name: "Brionne".to_string(),
             stage: 1,
@@ -23348,9 +23371,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b024PrimarinaEx => Card::Pokemon(PokemonCard {
+        CardId::A3b024PrimarinaEx => CardData::Pokemon(PokemonCard {
             id: "A3b 024".to_string(),
             name: "Primarina ex".to_string(),
             stage: 2,
@@ -23375,9 +23398,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b025Jolteon => Card::Pokemon(PokemonCard {
+        CardId::A3b025Jolteon => CardData::Pokemon(PokemonCard {
             id: "A3b 025".to_string(),
             name: "Jolteon".to_string(),
             stage: 1,
@@ -23396,9 +23419,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b026Joltik => Card::Pokemon(PokemonCard {
+        CardId::A3b026Joltik => CardData::Pokemon(PokemonCard {
             id: "A3b 026".to_string(),
             name: "Joltik".to_string(),
             stage: 0,
@@ -23417,9 +23440,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b027Galvantula => Card::Pokemon(PokemonCard {
+        CardId::A3b027Galvantula => CardData::Pokemon(PokemonCard {
             id: "A3b 027".to_string(),
             name: "Galvantula".to_string(),
             stage: 1,
@@ -23438,9 +23461,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b028Espeon => Card::Pokemon(PokemonCard {
+        CardId::A3b028Espeon => CardData::Pokemon(PokemonCard {
             id: "A3b 028".to_string(),
             name: "Espeon".to_string(),
             stage: 1,
@@ -23459,9 +23482,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b029Woobat => Card::Pokemon(PokemonCard {
+        CardId::A3b029Woobat => CardData::Pokemon(PokemonCard {
             id: "A3b 029".to_string(),
             name: "Woobat".to_string(),
             stage: 0,
@@ -23480,9 +23503,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b030Swoobat => Card::Pokemon(PokemonCard {
+        CardId::A3b030Swoobat => CardData::Pokemon(PokemonCard {
             id: "A3b 030".to_string(),
             name: "Swoobat".to_string(),
             stage: 1,
@@ -23501,9 +23524,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b031Swirlix => Card::Pokemon(PokemonCard {
+        CardId::A3b031Swirlix => CardData::Pokemon(PokemonCard {
             id: "A3b 031".to_string(),
             name: "Swirlix".to_string(),
             stage: 0,
@@ -23522,9 +23545,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b032Slurpuff => Card::Pokemon(PokemonCard {
+        CardId::A3b032Slurpuff => CardData::Pokemon(PokemonCard {
             id: "A3b 032".to_string(),
             name: "Slurpuff".to_string(),
             stage: 1,
@@ -23543,9 +23566,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b033Sylveon => Card::Pokemon(PokemonCard {
+        CardId::A3b033Sylveon => CardData::Pokemon(PokemonCard {
             id: "A3b 033".to_string(),
             name: "Sylveon".to_string(),
             stage: 1,
@@ -23564,9 +23587,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b034SylveonEx => Card::Pokemon(PokemonCard {
+        CardId::A3b034SylveonEx => CardData::Pokemon(PokemonCard {
             id: "A3b 034".to_string(),
             name: "Sylveon ex".to_string(),
             stage: 1,
@@ -23585,9 +23608,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b035Mimikyu => Card::Pokemon(PokemonCard {
+        CardId::A3b035Mimikyu => CardData::Pokemon(PokemonCard {
             id: "A3b 035".to_string(),
             name: "Mimikyu".to_string(),
             stage: 0,
@@ -23606,9 +23629,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b036Milcery => Card::Pokemon(PokemonCard {
+        CardId::A3b036Milcery => CardData::Pokemon(PokemonCard {
             id: "A3b 036".to_string(),
             name: "Milcery".to_string(),
             stage: 0,
@@ -23627,9 +23650,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b037Alcremie => Card::Pokemon(PokemonCard {
+        CardId::A3b037Alcremie => CardData::Pokemon(PokemonCard {
             id: "A3b 037".to_string(),
             name: "Alcremie".to_string(),
             stage: 1,
@@ -23648,9 +23671,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b038Barboach => Card::Pokemon(PokemonCard {
+        CardId::A3b038Barboach => CardData::Pokemon(PokemonCard {
             id: "A3b 038".to_string(),
             name: "Barboach".to_string(),
             stage: 0,
@@ -23669,9 +23692,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b039Whiscash => Card::Pokemon(PokemonCard {
+        CardId::A3b039Whiscash => CardData::Pokemon(PokemonCard {
             id: "A3b 039".to_string(),
             name: "Whiscash".to_string(),
             stage: 1,
@@ -23690,9 +23713,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b040Mienfoo => Card::Pokemon(PokemonCard {
+        CardId::A3b040Mienfoo => CardData::Pokemon(PokemonCard {
             id: "A3b 040".to_string(),
             name: "Mienfoo".to_string(),
             stage: 0,
@@ -23711,9 +23734,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b041Mienshao => Card::Pokemon(PokemonCard {
+        CardId::A3b041Mienshao => CardData::Pokemon(PokemonCard {
             id: "A3b 041".to_string(),
             name: "Mienshao".to_string(),
             stage: 1,
@@ -23732,9 +23755,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b042Carbink => Card::Pokemon(PokemonCard {
+        CardId::A3b042Carbink => CardData::Pokemon(PokemonCard {
             id: "A3b 042".to_string(),
             name: "Carbink".to_string(),
             stage: 0,
@@ -23753,9 +23776,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b043Umbreon => Card::Pokemon(PokemonCard {
+        CardId::A3b043Umbreon => CardData::Pokemon(PokemonCard {
             id: "A3b 043".to_string(),
             name: "Umbreon".to_string(),
             stage: 1,
@@ -23774,9 +23797,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b044Sableye => Card::Pokemon(PokemonCard {
+        CardId::A3b044Sableye => CardData::Pokemon(PokemonCard {
             id: "A3b 044".to_string(),
             name: "Sableye".to_string(),
             stage: 0,
@@ -23795,9 +23818,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b045Purrloin => Card::Pokemon(PokemonCard {
+        CardId::A3b045Purrloin => CardData::Pokemon(PokemonCard {
             id: "A3b 045".to_string(),
             name: "Purrloin".to_string(),
             stage: 0,
@@ -23816,9 +23839,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b046Liepard => Card::Pokemon(PokemonCard {
+        CardId::A3b046Liepard => CardData::Pokemon(PokemonCard {
             id: "A3b 046".to_string(),
             name: "Liepard".to_string(),
             stage: 1,
@@ -23837,9 +23860,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b047Mawile => Card::Pokemon(PokemonCard {
+        CardId::A3b047Mawile => CardData::Pokemon(PokemonCard {
             id: "A3b 047".to_string(),
             name: "Mawile".to_string(),
             stage: 0,
@@ -23858,9 +23881,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b048Togedemaru => Card::Pokemon(PokemonCard {
+        CardId::A3b048Togedemaru => CardData::Pokemon(PokemonCard {
             id: "A3b 048".to_string(),
             name: "Togedemaru".to_string(),
             stage: 0,
@@ -23879,9 +23902,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b049Meltan => Card::Pokemon(PokemonCard {
+        CardId::A3b049Meltan => CardData::Pokemon(PokemonCard {
             id: "A3b 049".to_string(),
             name: "Meltan".to_string(),
             stage: 0,
@@ -23900,9 +23923,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b050Melmetal => Card::Pokemon(PokemonCard {
+        CardId::A3b050Melmetal => CardData::Pokemon(PokemonCard {
             id: "A3b 050".to_string(),
             name: "Melmetal".to_string(),
             stage: 1,
@@ -23921,9 +23944,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b051Dratini => Card::Pokemon(PokemonCard {
+        CardId::A3b051Dratini => CardData::Pokemon(PokemonCard {
             id: "A3b 051".to_string(),
             name: "Dratini".to_string(),
             stage: 0,
@@ -23942,9 +23965,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b052Dragonair => Card::Pokemon(PokemonCard {
+        CardId::A3b052Dragonair => CardData::Pokemon(PokemonCard {
             id: "A3b 052".to_string(),
             name: "Dragonair".to_string(),
             stage: 1,
@@ -23963,9 +23986,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b053DragoniteEx => Card::Pokemon(PokemonCard {
+        CardId::A3b053DragoniteEx => CardData::Pokemon(PokemonCard {
             id: "A3b 053".to_string(),
             name: "Dragonite ex".to_string(),
             stage: 2,
@@ -23984,9 +24007,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b054Drampa => Card::Pokemon(PokemonCard {
+        CardId::A3b054Drampa => CardData::Pokemon(PokemonCard {
             id: "A3b 054".to_string(),
             name: "Drampa".to_string(),
             stage: 0,
@@ -24005,9 +24028,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b055Eevee => Card::Pokemon(PokemonCard {
+        CardId::A3b055Eevee => CardData::Pokemon(PokemonCard {
             id: "A3b 055".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -24026,9 +24049,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b056EeveeEx => Card::Pokemon(PokemonCard {
+        CardId::A3b056EeveeEx => CardData::Pokemon(PokemonCard {
             id: "A3b 056".to_string(),
             name: "Eevee ex".to_string(),
             stage: 0,
@@ -24047,9 +24070,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b057SnorlaxEx => Card::Pokemon(PokemonCard {
+        CardId::A3b057SnorlaxEx => CardData::Pokemon(PokemonCard {
             id: "A3b 057".to_string(),
             name: "Snorlax ex".to_string(),
             stage: 0,
@@ -24068,9 +24091,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b058Aipom => Card::Pokemon(PokemonCard {
+        CardId::A3b058Aipom => CardData::Pokemon(PokemonCard {
             id: "A3b 058".to_string(),
             name: "Aipom".to_string(),
             stage: 0,
@@ -24089,9 +24112,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b059Ambipom => Card::Pokemon(PokemonCard {
+        CardId::A3b059Ambipom => CardData::Pokemon(PokemonCard {
             id: "A3b 059".to_string(),
             name: "Ambipom".to_string(),
             stage: 1,
@@ -24110,9 +24133,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b060Chatot => Card::Pokemon(PokemonCard {
+        CardId::A3b060Chatot => CardData::Pokemon(PokemonCard {
             id: "A3b 060".to_string(),
             name: "Chatot".to_string(),
             stage: 0,
@@ -24131,9 +24154,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b061Audino => Card::Pokemon(PokemonCard {
+        CardId::A3b061Audino => CardData::Pokemon(PokemonCard {
             id: "A3b 061".to_string(),
             name: "Audino".to_string(),
             stage: 0,
@@ -24152,9 +24175,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b062Minccino => Card::Pokemon(PokemonCard {
+        CardId::A3b062Minccino => CardData::Pokemon(PokemonCard {
             id: "A3b 062".to_string(),
             name: "Minccino".to_string(),
             stage: 0,
@@ -24173,9 +24196,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b063Cinccino => Card::Pokemon(PokemonCard {
+        CardId::A3b063Cinccino => CardData::Pokemon(PokemonCard {
             id: "A3b 063".to_string(),
             name: "Cinccino".to_string(),
             stage: 1,
@@ -24194,9 +24217,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b064Skwovet => Card::Pokemon(PokemonCard {
+        CardId::A3b064Skwovet => CardData::Pokemon(PokemonCard {
             id: "A3b 064".to_string(),
             name: "Skwovet".to_string(),
             stage: 0,
@@ -24215,9 +24238,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b065Greedent => Card::Pokemon(PokemonCard {
+        CardId::A3b065Greedent => CardData::Pokemon(PokemonCard {
             id: "A3b 065".to_string(),
             name: "Greedent".to_string(),
             stage: 1,
@@ -24236,45 +24259,45 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b066EeveeBag => Card::Trainer(TrainerCard {
+        CardId::A3b066EeveeBag => CardData::Trainer(TrainerCard {
             id: "A3b 066".to_string(),
             numeric_id: 32834,
             name: "Eevee Bag".to_string(),
             effect: "Choose 1:① During this turn, attacks used by your Pokémon that evolve from Eevee do +10 damage to your opponent's Active Pokémon.② Heal 20 damage from each of your Pokémon that evolves from Eevee.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::A3b067Leftovers => Card::Trainer(TrainerCard {
+        CardId::A3b067Leftovers => CardData::Trainer(TrainerCard {
             id: "A3b 067".to_string(),
             numeric_id: 32835,
             name: "Leftovers".to_string(),
             effect: "At the end of your turn, if the Pokémon this card is attached to is in the Active Spot, heal 10 damage from that Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Tool,
         }),
-        CardId::A3b068Hau => Card::Trainer(TrainerCard {
+        CardId::A3b068Hau => CardData::Trainer(TrainerCard {
             id: "A3b 068".to_string(),
             numeric_id: 32836,
             name: "Hau".to_string(),
             effect: "During this turn, attacks used by your Decidueye ex, Incineroar ex, or Primarina ex do +30 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3b069Penny => Card::Trainer(TrainerCard {
+        CardId::A3b069Penny => CardData::Trainer(TrainerCard {
             id: "A3b 069".to_string(),
             numeric_id: 32837,
             name: "Penny".to_string(),
             effect: "Look at a random Supporter card that's not Penny from your opponent's deck and shuffle it back into their deck. Use the effect of that card as the effect of this card.".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3b070Leafeon => Card::Pokemon(PokemonCard {
+        CardId::A3b070Leafeon => CardData::Pokemon(PokemonCard {
             id: "A3b 070".to_string(),
             name: "Leafeon".to_string(),
             stage: 1,
@@ -24293,9 +24316,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b071Flareon => Card::Pokemon(PokemonCard {
+        CardId::A3b071Flareon => CardData::Pokemon(PokemonCard {
             id: "A3b 071".to_string(),
             name: "Flareon".to_string(),
             stage: 1,
@@ -24314,9 +24337,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b072Vaporeon => Card::Pokemon(PokemonCard {
+        CardId::A3b072Vaporeon => CardData::Pokemon(PokemonCard {
             id: "A3b 072".to_string(),
             name: "Vaporeon".to_string(),
             stage: 1,
@@ -24335,9 +24358,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b073Glaceon => Card::Pokemon(PokemonCard {
+        CardId::A3b073Glaceon => CardData::Pokemon(PokemonCard {
             id: "A3b 073".to_string(),
             name: "Glaceon".to_string(),
             stage: 1,
@@ -24356,9 +24379,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b074Jolteon => Card::Pokemon(PokemonCard {
+        CardId::A3b074Jolteon => CardData::Pokemon(PokemonCard {
             id: "A3b 074".to_string(),
             name: "Jolteon".to_string(),
             stage: 1,
@@ -24377,9 +24400,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b075Espeon => Card::Pokemon(PokemonCard {
+        CardId::A3b075Espeon => CardData::Pokemon(PokemonCard {
             id: "A3b 075".to_string(),
             name: "Espeon".to_string(),
             stage: 1,
@@ -24398,9 +24421,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b076Sylveon => Card::Pokemon(PokemonCard {
+        CardId::A3b076Sylveon => CardData::Pokemon(PokemonCard {
             id: "A3b 076".to_string(),
             name: "Sylveon".to_string(),
             stage: 1,
@@ -24419,9 +24442,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b077Umbreon => Card::Pokemon(PokemonCard {
+        CardId::A3b077Umbreon => CardData::Pokemon(PokemonCard {
             id: "A3b 077".to_string(),
             name: "Umbreon".to_string(),
             stage: 1,
@@ -24440,9 +24463,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b078Eevee => Card::Pokemon(PokemonCard {
+        CardId::A3b078Eevee => CardData::Pokemon(PokemonCard {
             id: "A3b 078".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -24461,9 +24484,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b079FlareonEx => Card::Pokemon(PokemonCard {
+        CardId::A3b079FlareonEx => CardData::Pokemon(PokemonCard {
             id: "A3b 079".to_string(),
             name: "Flareon ex".to_string(),
             stage: 1,
@@ -24482,9 +24505,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b080PrimarinaEx => Card::Pokemon(PokemonCard {
+        CardId::A3b080PrimarinaEx => CardData::Pokemon(PokemonCard {
             id: "A3b 080".to_string(),
             name: "Primarina ex".to_string(),
             stage: 2,
@@ -24509,9 +24532,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b081SylveonEx => Card::Pokemon(PokemonCard {
+        CardId::A3b081SylveonEx => CardData::Pokemon(PokemonCard {
             id: "A3b 081".to_string(),
             name: "Sylveon ex".to_string(),
             stage: 1,
@@ -24530,9 +24553,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b082DragoniteEx => Card::Pokemon(PokemonCard {
+        CardId::A3b082DragoniteEx => CardData::Pokemon(PokemonCard {
             id: "A3b 082".to_string(),
             name: "Dragonite ex".to_string(),
             stage: 2,
@@ -24551,9 +24574,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b083EeveeEx => Card::Pokemon(PokemonCard {
+        CardId::A3b083EeveeEx => CardData::Pokemon(PokemonCard {
             id: "A3b 083".to_string(),
             name: "Eevee ex".to_string(),
             stage: 0,
@@ -24572,9 +24595,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b084SnorlaxEx => Card::Pokemon(PokemonCard {
+        CardId::A3b084SnorlaxEx => CardData::Pokemon(PokemonCard {
             id: "A3b 084".to_string(),
             name: "Snorlax ex".to_string(),
             stage: 0,
@@ -24593,27 +24616,27 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b085Hau => Card::Trainer(TrainerCard {
+        CardId::A3b085Hau => CardData::Trainer(TrainerCard {
             id: "A3b 085".to_string(),
             numeric_id: 32853,
             name: "Hau".to_string(),
             effect: "During this turn, attacks used by your Decidueye ex, Incineroar ex, or Primarina ex do +30 damage to your opponent's Active Pokémon.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3b086Penny => Card::Trainer(TrainerCard {
+        CardId::A3b086Penny => CardData::Trainer(TrainerCard {
             id: "A3b 086".to_string(),
             numeric_id: 32854,
             name: "Penny".to_string(),
             effect: "Look at a random Supporter card that's not Penny from your opponent's deck and shuffle it back into their deck. Use the effect of that card as the effect of this card.".to_string(),
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::A3b087FlareonEx => Card::Pokemon(PokemonCard {
+        CardId::A3b087FlareonEx => CardData::Pokemon(PokemonCard {
             id: "A3b 087".to_string(),
             name: "Flareon ex".to_string(),
             stage: 1,
@@ -24632,9 +24655,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b088PrimarinaEx => Card::Pokemon(PokemonCard {
+        CardId::A3b088PrimarinaEx => CardData::Pokemon(PokemonCard {
             id: "A3b 088".to_string(),
             name: "Primarina ex".to_string(),
             stage: 2,
@@ -24659,9 +24682,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b089SylveonEx => Card::Pokemon(PokemonCard {
+        CardId::A3b089SylveonEx => CardData::Pokemon(PokemonCard {
             id: "A3b 089".to_string(),
             name: "Sylveon ex".to_string(),
             stage: 1,
@@ -24680,9 +24703,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b090DragoniteEx => Card::Pokemon(PokemonCard {
+        CardId::A3b090DragoniteEx => CardData::Pokemon(PokemonCard {
             id: "A3b 090".to_string(),
             name: "Dragonite ex".to_string(),
             stage: 2,
@@ -24701,9 +24724,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Colorless),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b091SnorlaxEx => Card::Pokemon(PokemonCard {
+        CardId::A3b091SnorlaxEx => CardData::Pokemon(PokemonCard {
             id: "A3b 091".to_string(),
             name: "Snorlax ex".to_string(),
             stage: 0,
@@ -24722,9 +24745,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b092EeveeEx => Card::Pokemon(PokemonCard {
+        CardId::A3b092EeveeEx => CardData::Pokemon(PokemonCard {
             id: "A3b 092".to_string(),
             name: "Eevee ex".to_string(),
             stage: 0,
@@ -24743,9 +24766,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b093Pinsir => Card::Pokemon(PokemonCard {
+        CardId::A3b093Pinsir => CardData::Pokemon(PokemonCard {
             id: "A3b 093".to_string(),
             name: "Pinsir".to_string(),
             stage: 0,
@@ -24764,9 +24787,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b094Lapras => Card::Pokemon(PokemonCard {
+        CardId::A3b094Lapras => CardData::Pokemon(PokemonCard {
             id: "A3b 094".to_string(),
             name: "Lapras".to_string(),
             stage: 0,
@@ -24785,9 +24808,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b095Voltorb => Card::Pokemon(PokemonCard {
+        CardId::A3b095Voltorb => CardData::Pokemon(PokemonCard {
             id: "A3b 095".to_string(),
             name: "Voltorb".to_string(),
             stage: 0,
@@ -24806,9 +24829,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b096Electrode => Card::Pokemon(PokemonCard {
+        CardId::A3b096Electrode => CardData::Pokemon(PokemonCard {
             id: "A3b 096".to_string(),
             name: "Electrode".to_string(),
             stage: 1,
@@ -24827,9 +24850,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b097Ralts => Card::Pokemon(PokemonCard {
+        CardId::A3b097Ralts => CardData::Pokemon(PokemonCard {
             id: "A3b 097".to_string(),
             name: "Ralts".to_string(),
             stage: 0,
@@ -24848,9 +24871,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b098Kirlia => Card::Pokemon(PokemonCard {
+        CardId::A3b098Kirlia => CardData::Pokemon(PokemonCard {
             id: "A3b 098".to_string(),
             name: "Kirlia".to_string(),
             stage: 1,
@@ -24869,9 +24892,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b099Gardevoir => Card::Pokemon(PokemonCard {
+        CardId::A3b099Gardevoir => CardData::Pokemon(PokemonCard {
             id: "A3b 099".to_string(),
             name: "Gardevoir".to_string(),
             stage: 2,
@@ -24890,9 +24913,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b100Ekans => Card::Pokemon(PokemonCard {
+        CardId::A3b100Ekans => CardData::Pokemon(PokemonCard {
             id: "A3b 100".to_string(),
             name: "Ekans".to_string(),
             stage: 0,
@@ -24911,9 +24934,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b101Arbok => Card::Pokemon(PokemonCard {
+        CardId::A3b101Arbok => CardData::Pokemon(PokemonCard {
             id: "A3b 101".to_string(),
             name: "Arbok".to_string(),
             stage: 1,
@@ -24932,9 +24955,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b102Farfetchd => Card::Pokemon(PokemonCard {
+        CardId::A3b102Farfetchd => CardData::Pokemon(PokemonCard {
             id: "A3b 102".to_string(),
             name: "Farfetch'd".to_string(),
             stage: 0,
@@ -24953,9 +24976,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b103MoltresEx => Card::Pokemon(PokemonCard {
+        CardId::A3b103MoltresEx => CardData::Pokemon(PokemonCard {
             id: "A3b 103".to_string(),
             name: "Moltres ex".to_string(),
             stage: 0,
@@ -24980,9 +25003,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b104ArticunoEx => Card::Pokemon(PokemonCard {
+        CardId::A3b104ArticunoEx => CardData::Pokemon(PokemonCard {
             id: "A3b 104".to_string(),
             name: "Articuno ex".to_string(),
             stage: 0,
@@ -25007,9 +25030,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b105ZapdosEx => Card::Pokemon(PokemonCard {
+        CardId::A3b105ZapdosEx => CardData::Pokemon(PokemonCard {
             id: "A3b 105".to_string(),
             name: "Zapdos ex".to_string(),
             stage: 0,
@@ -25034,9 +25057,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b106GalladeEx => Card::Pokemon(PokemonCard {
+        CardId::A3b106GalladeEx => CardData::Pokemon(PokemonCard {
             id: "A3b 106".to_string(),
             name: "Gallade ex".to_string(),
             stage: 2,
@@ -25055,90 +25078,90 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "☆☆".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
         }),
-        CardId::A3b107EeveeBag => Card::Trainer(TrainerCard {
+        CardId::A3b107EeveeBag => CardData::Trainer(TrainerCard {
             id: "A3b 107".to_string(),
             numeric_id: 32875,
             name: "Eevee Bag".to_string(),
             effect: "Choose 1:① During this turn, attacks used by your Pokémon that evolve from Eevee do +10 damage to your opponent's Active Pokémon.② Heal 20 damage from each of your Pokémon that evolves from Eevee.".to_string(),
             rarity: "Crown Rare".to_string(),
-            booster_pack: "Eevee Grove (A3b)".to_string(),
+            set: SetId::A3b,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA001Potion => Card::Trainer(TrainerCard {
+        CardId::PA001Potion => CardData::Trainer(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 4097,
             name: "Potion".to_string(),
             effect: "Heal 20 damage from 1 of your Pokémon.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA002XSpeed => Card::Trainer(TrainerCard {
+        CardId::PA002XSpeed => CardData::Trainer(TrainerCard {
             id: "P-A 002".to_string(),
             numeric_id: 4098,
             name: "X Speed".to_string(),
             effect: "During this turn, the Retreat Cost of your Active Pokémon is 1 less.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA003HandScope => Card::Trainer(TrainerCard {
+        CardId::PA003HandScope => CardData::Trainer(TrainerCard {
             id: "P-A 003".to_string(),
             numeric_id: 4099,
             name: "Hand Scope".to_string(),
             effect: "Your opponent reveals their hand.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA004PokedEx => Card::Trainer(TrainerCard {
+        CardId::PA004PokedEx => CardData::Trainer(TrainerCard {
             id: "P-A 004".to_string(),
             numeric_id: 4100,
             name: "Pokédex".to_string(),
             effect: "Look at the top 3 cards of your deck.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA005PokeBall => Card::Trainer(TrainerCard {
+        CardId::PA005PokeBall => CardData::Trainer(TrainerCard {
             id: "P-A 005".to_string(),
             numeric_id: 4101,
             name: "Poké Ball".to_string(),
             effect: "Put 1 random Basic Pokemon from your deck into your hand.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA006RedCard => Card::Trainer(TrainerCard {
+        CardId::PA006RedCard => CardData::Trainer(TrainerCard {
             id: "P-A 006".to_string(),
             numeric_id: 4102,
             name: "Red Card".to_string(),
             effect: "Your opponent shuffles their hand into their deck and draws 3 cards.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA007ProfessorsResearch => Card::Trainer(TrainerCard {
+        CardId::PA007ProfessorsResearch => CardData::Trainer(TrainerCard {
             id: "P-A 007".to_string(),
             numeric_id: 4103,
             name: "Professor's Research".to_string(),
             effect: "Draw 2 cards.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Supporter,
         }),
-        CardId::PA008PokedEx => Card::Trainer(TrainerCard {
+        CardId::PA008PokedEx => CardData::Trainer(TrainerCard {
             id: "P-A 008".to_string(),
             numeric_id: 4104,
             name: "Pokédex".to_string(),
             effect: "Look at the top 3 cards of your deck.".to_string(),
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
             trainer_card_type: TrainerType::Item,
         }),
-        CardId::PA009Pikachu => Card::Pokemon(PokemonCard {
+        CardId::PA009Pikachu => CardData::Pokemon(PokemonCard {
             id: "P-A 009".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -25157,9 +25180,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA010Mewtwo => Card::Pokemon(PokemonCard {
+        CardId::PA010Mewtwo => CardData::Pokemon(PokemonCard {
             id: "P-A 010".to_string(),
             name: "Mewtwo".to_string(),
             stage: 0,
@@ -25178,9 +25201,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA011Chansey => Card::Pokemon(PokemonCard {
+        CardId::PA011Chansey => CardData::Pokemon(PokemonCard {
             id: "P-A 011".to_string(),
             name: "Chansey".to_string(),
             stage: 0,
@@ -25199,9 +25222,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA012Meowth => Card::Pokemon(PokemonCard {
+        CardId::PA012Meowth => CardData::Pokemon(PokemonCard {
             id: "P-A 012".to_string(),
             name: "Meowth".to_string(),
             stage: 0,
@@ -25220,9 +25243,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA013Butterfree => Card::Pokemon(PokemonCard {
+        CardId::PA013Butterfree => CardData::Pokemon(PokemonCard {
             id: "P-A 013".to_string(),
             name: "Butterfree".to_string(),
             stage: 2,
@@ -25241,9 +25264,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA014LaprasEx => Card::Pokemon(PokemonCard {
+        CardId::PA014LaprasEx => CardData::Pokemon(PokemonCard {
             id: "P-A 014".to_string(),
             name: "Lapras ex".to_string(),
             stage: 0,
@@ -25262,9 +25285,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA015Pikachu => Card::Pokemon(PokemonCard {
+        CardId::PA015Pikachu => CardData::Pokemon(PokemonCard {
             id: "P-A 015".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -25283,9 +25306,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA016Clefairy => Card::Pokemon(PokemonCard {
+        CardId::PA016Clefairy => CardData::Pokemon(PokemonCard {
             id: "P-A 016".to_string(),
             name: "Clefairy".to_string(),
             stage: 0,
@@ -25304,9 +25327,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA017Mankey => Card::Pokemon(PokemonCard {
+        CardId::PA017Mankey => CardData::Pokemon(PokemonCard {
             id: "P-A 017".to_string(),
             name: "Mankey".to_string(),
             stage: 0,
@@ -25325,9 +25348,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA018Venusaur => Card::Pokemon(PokemonCard {
+        CardId::PA018Venusaur => CardData::Pokemon(PokemonCard {
             id: "P-A 018".to_string(),
             name: "Venusaur".to_string(),
             stage: 2,
@@ -25346,9 +25369,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA019Greninja => Card::Pokemon(PokemonCard {
+        CardId::PA019Greninja => CardData::Pokemon(PokemonCard {
             id: "P-A 019".to_string(),
             name: "Greninja".to_string(),
             stage: 2,
@@ -25367,9 +25390,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA020Haunter => Card::Pokemon(PokemonCard {
+        CardId::PA020Haunter => CardData::Pokemon(PokemonCard {
             id: "P-A 020".to_string(),
             name: "Haunter".to_string(),
             stage: 1,
@@ -25388,9 +25411,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA021Onix => Card::Pokemon(PokemonCard {
+        CardId::PA021Onix => CardData::Pokemon(PokemonCard {
             id: "P-A 021".to_string(),
             name: "Onix".to_string(),
             stage: 0,
@@ -25409,9 +25432,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA022Jigglypuff => Card::Pokemon(PokemonCard {
+        CardId::PA022Jigglypuff => CardData::Pokemon(PokemonCard {
             id: "P-A 022".to_string(),
             name: "Jigglypuff".to_string(),
             stage: 0,
@@ -25430,9 +25453,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA023Bulbasaur => Card::Pokemon(PokemonCard {
+        CardId::PA023Bulbasaur => CardData::Pokemon(PokemonCard {
             id: "P-A 023".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -25451,9 +25474,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA024Magnemite => Card::Pokemon(PokemonCard {
+        CardId::PA024Magnemite => CardData::Pokemon(PokemonCard {
             id: "P-A 024".to_string(),
             name: "Magnemite".to_string(),
             stage: 0,
@@ -25472,9 +25495,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA025MoltresEx => Card::Pokemon(PokemonCard {
+        CardId::PA025MoltresEx => CardData::Pokemon(PokemonCard {
             id: "P-A 025".to_string(),
             name: "Moltres ex".to_string(),
             stage: 0,
@@ -25499,9 +25522,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA026Pikachu => Card::Pokemon(PokemonCard {
+        CardId::PA026Pikachu => CardData::Pokemon(PokemonCard {
             id: "P-A 026".to_string(),
             name: "Pikachu".to_string(),
             stage: 0,
@@ -25520,9 +25543,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA027Snivy => Card::Pokemon(PokemonCard {
+        CardId::PA027Snivy => CardData::Pokemon(PokemonCard {
             id: "P-A 027".to_string(),
             name: "Snivy".to_string(),
             stage: 0,
@@ -25541,9 +25564,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA028Volcarona => Card::Pokemon(PokemonCard {
+        CardId::PA028Volcarona => CardData::Pokemon(PokemonCard {
             id: "P-A 028".to_string(),
             name: "Volcarona".to_string(),
             stage: 1,
@@ -25562,9 +25585,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA029Blastoise => Card::Pokemon(PokemonCard {
+        CardId::PA029Blastoise => CardData::Pokemon(PokemonCard {
             id: "P-A 029".to_string(),
             name: "Blastoise".to_string(),
             stage: 2,
@@ -25583,9 +25606,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA030Eevee => Card::Pokemon(PokemonCard {
+        CardId::PA030Eevee => CardData::Pokemon(PokemonCard {
             id: "P-A 030".to_string(),
             name: "Eevee".to_string(),
             stage: 0,
@@ -25604,9 +25627,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA031Cinccino => Card::Pokemon(PokemonCard {
+        CardId::PA031Cinccino => CardData::Pokemon(PokemonCard {
             id: "P-A 031".to_string(),
             name: "Cinccino".to_string(),
             stage: 1,
@@ -25625,9 +25648,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA032Charmander => Card::Pokemon(PokemonCard {
+        CardId::PA032Charmander => CardData::Pokemon(PokemonCard {
             id: "P-A 032".to_string(),
             name: "Charmander".to_string(),
             stage: 0,
@@ -25646,9 +25669,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA033Squirtle => Card::Pokemon(PokemonCard {
+        CardId::PA033Squirtle => CardData::Pokemon(PokemonCard {
             id: "P-A 033".to_string(),
             name: "Squirtle".to_string(),
             stage: 0,
@@ -25667,9 +25690,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA034Piplup => Card::Pokemon(PokemonCard {
+        CardId::PA034Piplup => CardData::Pokemon(PokemonCard {
             id: "P-A 034".to_string(),
             name: "Piplup".to_string(),
             stage: 0,
@@ -25688,9 +25711,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA035Turtwig => Card::Pokemon(PokemonCard {
+        CardId::PA035Turtwig => CardData::Pokemon(PokemonCard {
             id: "P-A 035".to_string(),
             name: "Turtwig".to_string(),
             stage: 0,
@@ -25709,9 +25732,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA036Electivire => Card::Pokemon(PokemonCard {
+        CardId::PA036Electivire => CardData::Pokemon(PokemonCard {
             id: "P-A 036".to_string(),
             name: "Electivire".to_string(),
             stage: 1,
@@ -25730,9 +25753,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA037CresseliaEx => Card::Pokemon(PokemonCard {
+        CardId::PA037CresseliaEx => CardData::Pokemon(PokemonCard {
             id: "P-A 037".to_string(),
             name: "Cresselia ex".to_string(),
             stage: 0,
@@ -25751,9 +25774,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA038Misdreavus => Card::Pokemon(PokemonCard {
+        CardId::PA038Misdreavus => CardData::Pokemon(PokemonCard {
             id: "P-A 038".to_string(),
             name: "Misdreavus".to_string(),
             stage: 0,
@@ -25772,9 +25795,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA039Skarmory => Card::Pokemon(PokemonCard {
+        CardId::PA039Skarmory => CardData::Pokemon(PokemonCard {
             id: "P-A 039".to_string(),
             name: "Skarmory".to_string(),
             stage: 0,
@@ -25793,9 +25816,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA040Chimchar => Card::Pokemon(PokemonCard {
+        CardId::PA040Chimchar => CardData::Pokemon(PokemonCard {
             id: "P-A 040".to_string(),
             name: "Chimchar".to_string(),
             stage: 0,
@@ -25814,9 +25837,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Water),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA041Togepi => Card::Pokemon(PokemonCard {
+        CardId::PA041Togepi => CardData::Pokemon(PokemonCard {
             id: "P-A 041".to_string(),
             name: "Togepi".to_string(),
             stage: 0,
@@ -25835,9 +25858,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA042DarkraiEx => Card::Pokemon(PokemonCard {
+        CardId::PA042DarkraiEx => CardData::Pokemon(PokemonCard {
             id: "P-A 042".to_string(),
             name: "Darkrai ex".to_string(),
             stage: 0,
@@ -25856,9 +25879,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA043Cherrim => Card::Pokemon(PokemonCard {
+        CardId::PA043Cherrim => CardData::Pokemon(PokemonCard {
             id: "P-A 043".to_string(),
             name: "Cherrim".to_string(),
             stage: 1,
@@ -25877,9 +25900,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA044Raichu => Card::Pokemon(PokemonCard {
+        CardId::PA044Raichu => CardData::Pokemon(PokemonCard {
             id: "P-A 044".to_string(),
             name: "Raichu".to_string(),
             stage: 1,
@@ -25898,9 +25921,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA045Nosepass => Card::Pokemon(PokemonCard {
+        CardId::PA045Nosepass => CardData::Pokemon(PokemonCard {
             id: "P-A 045".to_string(),
             name: "Nosepass".to_string(),
             stage: 0,
@@ -25919,9 +25942,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA046Gible => Card::Pokemon(PokemonCard {
+        CardId::PA046Gible => CardData::Pokemon(PokemonCard {
             id: "P-A 046".to_string(),
             name: "Gible".to_string(),
             stage: 0,
@@ -25940,9 +25963,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA047Staraptor => Card::Pokemon(PokemonCard {
+        CardId::PA047Staraptor => CardData::Pokemon(PokemonCard {
             id: "P-A 047".to_string(),
             name: "Staraptor".to_string(),
             stage: 2,
@@ -25961,9 +25984,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA048Manaphy => Card::Pokemon(PokemonCard {
+        CardId::PA048Manaphy => CardData::Pokemon(PokemonCard {
             id: "P-A 048".to_string(),
             name: "Manaphy".to_string(),
             stage: 0,
@@ -25982,9 +26005,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA049Snorlax => Card::Pokemon(PokemonCard {
+        CardId::PA049Snorlax => CardData::Pokemon(PokemonCard {
             id: "P-A 049".to_string(),
             name: "Snorlax".to_string(),
             stage: 0,
@@ -26003,9 +26026,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA050MewtwoEx => Card::Pokemon(PokemonCard {
+        CardId::PA050MewtwoEx => CardData::Pokemon(PokemonCard {
             id: "P-A 050".to_string(),
             name: "Mewtwo ex".to_string(),
             stage: 0,
@@ -26030,9 +26053,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA051Cyclizar => Card::Pokemon(PokemonCard {
+        CardId::PA051Cyclizar => CardData::Pokemon(PokemonCard {
             id: "P-A 051".to_string(),
             name: "Cyclizar".to_string(),
             stage: 0,
@@ -26051,9 +26074,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA052Sprigatito => Card::Pokemon(PokemonCard {
+        CardId::PA052Sprigatito => CardData::Pokemon(PokemonCard {
             id: "P-A 052".to_string(),
             name: "Sprigatito".to_string(),
             stage: 0,
@@ -26072,9 +26095,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA053Floatzel => Card::Pokemon(PokemonCard {
+        CardId::PA053Floatzel => CardData::Pokemon(PokemonCard {
             id: "P-A 053".to_string(),
             name: "Floatzel".to_string(),
             stage: 1,
@@ -26093,9 +26116,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA054Pawmot => Card::Pokemon(PokemonCard {
+        CardId::PA054Pawmot => CardData::Pokemon(PokemonCard {
             id: "P-A 054".to_string(),
             name: "Pawmot".to_string(),
             stage: 2,
@@ -26114,9 +26137,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA055Machamp => Card::Pokemon(PokemonCard {
+        CardId::PA055Machamp => CardData::Pokemon(PokemonCard {
             id: "P-A 055".to_string(),
             name: "Machamp".to_string(),
             stage: 2,
@@ -26135,9 +26158,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA056Ekans => Card::Pokemon(PokemonCard {
+        CardId::PA056Ekans => CardData::Pokemon(PokemonCard {
             id: "P-A 056".to_string(),
             name: "Ekans".to_string(),
             stage: 0,
@@ -26156,9 +26179,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA057Bidoof => Card::Pokemon(PokemonCard {
+        CardId::PA057Bidoof => CardData::Pokemon(PokemonCard {
             id: "P-A 057".to_string(),
             name: "Bidoof".to_string(),
             stage: 0,
@@ -26177,9 +26200,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA058Pachirisu => Card::Pokemon(PokemonCard {
+        CardId::PA058Pachirisu => CardData::Pokemon(PokemonCard {
             id: "P-A 058".to_string(),
             name: "Pachirisu".to_string(),
             stage: 0,
@@ -26198,9 +26221,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA059Riolu => Card::Pokemon(PokemonCard {
+        CardId::PA059Riolu => CardData::Pokemon(PokemonCard {
             id: "P-A 059".to_string(),
             name: "Riolu".to_string(),
             stage: 0,
@@ -26219,9 +26242,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA060Exeggcute => Card::Pokemon(PokemonCard {
+        CardId::PA060Exeggcute => CardData::Pokemon(PokemonCard {
             id: "P-A 060".to_string(),
             name: "Exeggcute".to_string(),
             stage: 0,
@@ -26240,9 +26263,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA061Froakie => Card::Pokemon(PokemonCard {
+        CardId::PA061Froakie => CardData::Pokemon(PokemonCard {
             id: "P-A 061".to_string(),
             name: "Froakie".to_string(),
             stage: 0,
@@ -26261,9 +26284,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA062Farfetchd => Card::Pokemon(PokemonCard {
+        CardId::PA062Farfetchd => CardData::Pokemon(PokemonCard {
             id: "P-A 062".to_string(),
             name: "Farfetch'd".to_string(),
             stage: 0,
@@ -26282,9 +26305,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA063Rayquaza => Card::Pokemon(PokemonCard {
+        CardId::PA063Rayquaza => CardData::Pokemon(PokemonCard {
             id: "P-A 063".to_string(),
             name: "Rayquaza".to_string(),
             stage: 0,
@@ -26303,9 +26326,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA064RayquazaEx => Card::Pokemon(PokemonCard {
+        CardId::PA064RayquazaEx => CardData::Pokemon(PokemonCard {
             id: "P-A 064".to_string(),
             name: "Rayquaza ex".to_string(),
             stage: 0,
@@ -26324,9 +26347,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA065RayquazaEx => Card::Pokemon(PokemonCard {
+        CardId::PA065RayquazaEx => CardData::Pokemon(PokemonCard {
             id: "P-A 065".to_string(),
             name: "Rayquaza ex".to_string(),
             stage: 0,
@@ -26345,9 +26368,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA066Mimikyu => Card::Pokemon(PokemonCard {
+        CardId::PA066Mimikyu => CardData::Pokemon(PokemonCard {
             id: "P-A 066".to_string(),
             name: "Mimikyu".to_string(),
             stage: 0,
@@ -26366,9 +26389,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA067Cosmog => Card::Pokemon(PokemonCard {
+        CardId::PA067Cosmog => CardData::Pokemon(PokemonCard {
             id: "P-A 067".to_string(),
             name: "Cosmog".to_string(),
             stage: 0,
@@ -26387,9 +26410,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Darkness),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA068Lycanroc => Card::Pokemon(PokemonCard {
+        CardId::PA068Lycanroc => CardData::Pokemon(PokemonCard {
             id: "P-A 068".to_string(),
             name: "Lycanroc".to_string(),
             stage: 1,
@@ -26408,9 +26431,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Grass),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA069AlolanExeggutor => Card::Pokemon(PokemonCard {
+        CardId::PA069AlolanExeggutor => CardData::Pokemon(PokemonCard {
             id: "P-A 069".to_string(),
             name: "Alolan Exeggutor".to_string(),
             stage: 1,
@@ -26429,9 +26452,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA070AlolanNinetales => Card::Pokemon(PokemonCard {
+        CardId::PA070AlolanNinetales => CardData::Pokemon(PokemonCard {
             id: "P-A 070".to_string(),
             name: "Alolan Ninetales".to_string(),
             stage: 1,
@@ -26450,9 +26473,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Metal),
             retreat_cost: vec![EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA071Crabrawler => Card::Pokemon(PokemonCard {
+        CardId::PA071Crabrawler => CardData::Pokemon(PokemonCard {
             id: "P-A 071".to_string(),
             name: "Crabrawler".to_string(),
             stage: 0,
@@ -26471,9 +26494,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Psychic),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA072AlolanGrimer => Card::Pokemon(PokemonCard {
+        CardId::PA072AlolanGrimer => CardData::Pokemon(PokemonCard {
             id: "P-A 072".to_string(),
             name: "Alolan Grimer".to_string(),
             stage: 0,
@@ -26492,9 +26515,9 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Fighting),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
-        CardId::PA073Toucannon => Card::Pokemon(PokemonCard {
+        CardId::PA073Toucannon => CardData::Pokemon(PokemonCard {
             id: "P-A 073".to_string(),
             name: "Toucannon".to_string(),
             stage: 2,
@@ -26513,7 +26536,7 @@ pub fn get_card_by_enum(id: CardId) -> Card {
             weakness: Some(EnergyType::Lightning),
             retreat_cost: vec![EnergyType::Colorless,EnergyType::Colorless,],
             rarity: "".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         }),
     }
 }
@@ -3,6 +3,7 @@ mod tests {
     use crate::database::get_card_by_enum;
     use crate::{
         card_ids::CardId,
+        sets::SetId,
         types::{Card, EnergyType, TrainerType},
     };
 
@@ -165,14 +166,14 @@ mod tests {
         
         match a1_card {
             Card::Pokemon(pokemon) => {
-                assert_eq!(pokemon.booster_pack, "Genetic Apex (A1)");
+                assert_eq!(pokemon.set, SetId::A1);
             }
             _ => panic!("Expected Pokemon card"),
         }
         
         match promo_card {
             Card::Trainer(trainer) => {
-                assert_eq!(trainer.booster_pack, "Promo-A");
+                assert_eq!(trainer.set, SetId::PromoA);
             }
             _ => panic!("Expected Trainer card"),
         }
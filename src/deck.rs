@@ -1,13 +1,65 @@
 use rand::seq::SliceRandom;
 use rand::Rng;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
 use std::hash::{Hash, Hasher};
 
 use crate::card_ids::CardId;
-use crate::database::get_card_by_enum;
+use crate::database::{find_by_name, get_card_by_enum};
+use crate::errors::{GameError, GameResult};
+use crate::sets::{SetId, ALL_SETS};
 use crate::types::{Card, EnergyType};
 
+// Most cards a deck list may have, so that a mistyped count can't exhaust memory
+const MAX_LISTED_CARDS: usize = 60;
+
+/// Which cards a deck may contain. The default allows every set with no banned cards.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeckFormat {
+    /// Sets that are legal. `None` means all sets are.
+    pub legal_sets: Option<Vec<SetId>>,
+    pub banned: Vec<CardId>,
+}
+
+impl DeckFormat {
+    /// A format restricted to the given sets, e.g.
+    /// `DeckFormat::only_sets(&[SetId::A1, SetId::PromoA])`.
+    pub fn only_sets(sets: &[SetId]) -> Self {
+        Self {
+            legal_sets: Some(sets.to_vec()),
+            banned: vec![],
+        }
+    }
+
+    /// The sets legal on `date` ("YYYY-MM-DD").
+    pub fn on_date(date: &str) -> Self {
+        let sets: Vec<SetId> = ALL_SETS
+            .iter()
+            .map(|x| x.id)
+            .filter(|x| x.is_legal_on(date))
+            .collect();
+        Self::only_sets(&sets)
+    }
+
+    pub fn with_banned(mut self, banned: &[CardId]) -> Self {
+        self.banned.extend_from_slice(banned);
+        self
+    }
+
+    /// Whether the card is from a legal set and not banned.
+    pub fn allows(&self, card: &Card) -> bool {
+        self.is_legal_set(card.get_set()) && !self.banned.contains(&card.get_card_id())
+    }
+
+    pub fn is_legal_set(&self, set: SetId) -> bool {
+        match &self.legal_sets {
+            Some(sets) => sets.contains(&set),
+            None => true,
+        }
+    }
+}
+
 /// Represents a deck of cards.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Deck {
@@ -25,6 +77,28 @@ impl Hash for Deck {
     }
 }
 
+/// Writes the deck in DeckGym Format, so that it can be read back with `Deck::from_string`.
+impl fmt::Display for Deck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut energy_types = self.energy_types.clone();
+        energy_types.sort();
+        for energy_type in energy_types {
+            writeln!(f, "Energy: {energy_type}")?;
+        }
+        let mut counts: Vec<(&Card, u32)> = vec![];
+        for card in &self.cards {
+            match counts.iter_mut().find(|(x, _)| x.get_id() == card.get_id()) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((card, 1)),
+            }
+        }
+        for (card, count) in counts {
+            writeln!(f, "{count} {} {}", card.get_name(), card.get_id())?;
+        }
+        Ok(())
+    }
+}
+
 impl Deck {
     /// Parses a deck file and returns a `Deck` struct with cards flattened based on their counts.
     pub fn from_file(file_path: &str) -> Result<Self, String> {
@@ -34,29 +108,50 @@ impl Deck {
         Self::from_string(&contents)
     }
 
+    /// Parses a deck list, one card per line as `<count> <name> <id>`. Also accepted:
+    /// - counts written `2x`, and ids in parentheses: `2x Pikachu ex (A1 096)`.
+    /// - cards by name only, or name and set (see `Card::from_str_with_count`).
+    /// - `Energy: Fire, Lightning` lines choosing the energy types (by default, those of
+    ///   the Pokémon in the deck).
+    /// - `#` or `//` comments, blank lines and `Pokémon: 10` / `Trainer: 10` headers.
+    ///
+    /// Errors say on which line (from 1) the problem is, including when the list goes over
+    /// 60 cards.
     pub fn from_string(contents: &str) -> Result<Self, String> {
         let mut energy_types = HashSet::new();
         let mut cards = Vec::new();
-        for line in contents.lines() {
+        for (i, line) in contents.lines().enumerate() {
+            let line_error = |err: String| format!("Line {}: {err}", i + 1);
+            let line = line.split("//").next().unwrap_or_default();
+            let trimmed = line.split('#').next().unwrap_or_default().trim();
             // if line is empty or starts with "Pokemon:" or "Trainer:, skip it
-            let trimmed = line.trim();
             if trimmed.is_empty()
                 || trimmed.starts_with("Pokémon:")
+                || trimmed.starts_with("Pokemon:")
                 || trimmed.starts_with("Trainer:")
             {
                 continue;
             }
-            if trimmed.starts_with("Energy:") {
-                let energy_type: &str = trimmed
-                    .split_whitespace()
-                    .last()
-                    .expect("Energy: line should have an energy type");
-                energy_types
-                    .insert(EnergyType::from_str(energy_type).expect("Invalid energy type"));
+            if let Some(energies) = trimmed.strip_prefix("Energy:") {
+                let energies: Vec<&str> = energies
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|x| !x.is_empty())
+                    .collect();
+                if energies.is_empty() {
+                    return Err(line_error("Energy: line has no energy type".to_string()));
+                }
+                for energy in energies {
+                    energy_types.insert(parse_energy_type(energy).map_err(line_error)?);
+                }
                 continue;
             }
 
-            let (count, card) = Card::from_str_with_count(trimmed)?;
+            let (count, card) = Card::from_str_with_count(trimmed).map_err(line_error)?;
+            if cards.len() + count as usize > MAX_LISTED_CARDS {
+                return Err(line_error(format!(
+                    "Deck lists more than {MAX_LISTED_CARDS} cards"
+                )));
+            }
             cards.extend(vec![card; count as usize]);
         }
 
@@ -80,6 +175,49 @@ impl Deck {
         self.cards.len() == 20 && basic >= 1
     }
 
+    /// Like `is_valid`, but also checks the 2-copies rule and the legality of each card
+    /// in the given format. Lists every problem found in a `DeckValidationFailed` error.
+    pub fn validate(&self, format: &DeckFormat) -> GameResult<()> {
+        let mut errors = vec![];
+        if self.cards.len() != 20 {
+            errors.push(format!("Deck has {} cards, expected 20", self.cards.len()));
+        }
+        if !self.cards.iter().any(is_basic) {
+            errors.push("Deck has no Basic Pokémon".to_string());
+        }
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let mut seen = HashSet::new();
+        for card in &self.cards {
+            *counts.entry(card.get_name()).or_default() += 1;
+            let id = card.get_id();
+            if !seen.insert(id.clone()) {
+                continue;
+            }
+            let set = card.get_set();
+            if !format.is_legal_set(set) {
+                errors.push(format!(
+                    "{} ({id}) is from set {set}, not legal in this format",
+                    card.get_name()
+                ));
+            }
+            if format.banned.contains(&card.get_card_id()) {
+                errors.push(format!("{} ({id}) is banned", card.get_name()));
+            }
+        }
+        let mut too_many: Vec<_> = counts.into_iter().filter(|(_, count)| *count > 2).collect();
+        too_many.sort();
+        for (name, count) in too_many {
+            errors.push(format!("{count} copies of {name}, at most 2 allowed"));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(GameError::DeckValidationFailed { errors })
+        }
+    }
+
     /// Draws a card from the deck.
     /// Returns `Some(Card)` if the deck is not empty, otherwise returns `None`.
     pub fn draw(&mut self) -> Option<Card> {
@@ -113,26 +251,47 @@ impl Deck {
 }
 
 impl Card {
-    /// Parses a line and returns a tuple of count and a `Card`.
+    /// Parses a line and returns a tuple of count and a `Card`. The card is looked up by
+    /// its id (the last two words) or else by name (see `database::find_by_name`), as long
+    /// as the name is not ambiguous.
     pub fn from_str_with_count(line: &str) -> Result<(u32, Card), String> {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 3 {
+        let parts: Vec<&str> = line
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .filter(|x| !x.is_empty())
+            .collect();
+        if parts.len() < 2 {
             return Err(format!("Invalid card format: {line}"));
         }
 
         let count = parts[0]
+            .strip_suffix(['x', '×'])
+            .unwrap_or(parts[0])
             .parse::<u32>()
             .map_err(|_| format!("Invalid count: {}", parts[0]))?;
-        let set = parts[parts.len() - 2];
-        // maybe pad number with 0 on the left if missing 0s
-        let number = parts[parts.len() - 1];
-        let padded_number = format!("{number:0>3}");
-        let id = format!("{set} {padded_number}");
-
-        let card_id =
-            CardId::from_card_id(&id).ok_or_else(|| format!("Card ID not found for id: {id}"))?;
-        let card = get_card_by_enum(card_id);
+        if parts.len() >= 3 {
+            let set = parts[parts.len() - 2];
+            // maybe pad number with 0 on the left if missing 0s
+            let number = parts[parts.len() - 1];
+            let padded_number = format!("{number:0>3}");
+            let id = format!("{set} {padded_number}");
+            if let Some(card_id) = CardId::from_card_id(&id) {
+                return Ok((count, get_card_by_enum(card_id)));
+            }
+        }
 
+        let name = parts[1..].join(" ");
+        let cards: Vec<Card> = find_by_name(&name)
+            .into_iter()
+            .map(get_card_by_enum)
+            .collect();
+        let Some(card) = cards.first() else {
+            return Err(format!("Card not found: {name}"));
+        };
+        if cards.iter().any(|x| x.get_name() != card.get_name()) {
+            let mut names: Vec<String> = cards.iter().map(|x| x.get_name()).collect();
+            names.dedup();
+            return Err(format!("Ambiguous card {name}: {}", names.join(", ")));
+        }
         Ok((count, card.clone()))
     }
 }
@@ -141,6 +300,18 @@ pub fn is_basic(card: &Card) -> bool {
     card.is_basic()
 }
 
+// "Fire", "fire" or "FIRE"
+fn parse_energy_type(energy: &str) -> Result<EnergyType, String> {
+    let mut chars = energy.chars();
+    let capitalized: String = chars
+        .next()
+        .map(|x| x.to_ascii_uppercase())
+        .into_iter()
+        .chain(chars.flat_map(|x| x.to_lowercase()))
+        .collect();
+    energy.parse().or_else(|_| capitalized.parse())
+}
+
 #[cfg(test)]
 mod tests {
     use rand::thread_rng;
@@ -240,6 +411,113 @@ Trainer: 12
         assert_eq!(deck.energy_types[0], EnergyType::Grass);
     }
 
+    #[test]
+    fn test_deck_format_v2() {
+        let string = r#"# Raichu, for sharing
+Energy: lightning, Fire
+Pokémon: 6
+2x Pikachu ex (A1 096) // main attacker
+2x Zapdos ex (A1 104)
+2 Voltorb
+
+Trainer: 14
+2x Poké Ball (P-A 005)
+2x Professor's Research
+2 Giovanni A1 223
+2 Sabrina A1 225
+2 Potion P-A 001
+2 X Speed P-A 002
+2 Red Card P-A 006
+"#;
+        let deck = Deck::from_string(string).expect("Failed to parse deck from string");
+        assert_eq!(deck.cards.len(), 20);
+        let mut energy_types = deck.energy_types.clone();
+        energy_types.sort();
+        assert_eq!(energy_types, vec![EnergyType::Fire, EnergyType::Lightning]);
+        assert_eq!(deck.cards[0].get_id(), "A1 096");
+        assert_eq!(deck.cards[4].get_name(), "Voltorb");
+
+        let err = Deck::from_string("2 Bulbasaur A1 001\n2x\n").unwrap_err();
+        assert!(err.starts_with("Line 2: "), "{err}");
+        let err = Deck::from_string("# Energy\nEnergy: Fyre\n").unwrap_err();
+        assert_eq!(err, "Line 2: Invalid energy type: Fyre");
+        let err = Deck::from_string("1 Bulbasaur\n\ntwo Ivysaur\n").unwrap_err();
+        assert_eq!(err, "Line 3: Invalid count: two");
+    }
+
+    #[test]
+    fn test_cards_can_be_written_by_name() {
+        let (count, card) = Card::from_str_with_count("2 Bulbasor").unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(card.get_id(), "A1 001");
+        let (_, card) = Card::from_str_with_count("1 Pikachu ex A1 96").unwrap();
+        assert_eq!(card.get_id(), "A1 096");
+        let (_, card) = Card::from_str_with_count("1 Pikachu ex A2b").unwrap();
+        assert_eq!(card.get_set(), SetId::A2b);
+        assert!(Card::from_str_with_count("1 Not A Pokémon")
+            .unwrap_err()
+            .contains("not found"));
+    }
+
+    #[test]
+    fn test_validate_format() {
+        let deck = Deck::from_file("example_decks/arceusdialga.txt").unwrap();
+        assert_eq!(deck.validate(&DeckFormat::default()), Ok(()));
+
+        let format = DeckFormat::only_sets(&[SetId::A1, SetId::A1a, SetId::PromoA])
+            .with_banned(&[CardId::A1225Sabrina]);
+        let Err(GameError::DeckValidationFailed { errors }) = deck.validate(&format) else {
+            panic!("Deck should not be legal in this format");
+        };
+        // Dialga ex, Giant Cape, Cyrus, Arceus ex and Sabrina (but not its full art)
+        assert_eq!(errors.len(), 5);
+        assert!(errors
+            .iter()
+            .any(|x| x.contains("A2 147") && x.contains("set A2")));
+        assert!(errors
+            .iter()
+            .any(|x| x.contains("A1 225") && x.contains("banned")));
+
+        // Space-Time Smackdown (A2) was not out yet
+        let format = DeckFormat::on_date("2025-01-01");
+        assert!(format.is_legal_set(SetId::A1a));
+        assert!(!format.is_legal_set(SetId::A2));
+    }
+
+    #[test]
+    fn test_validate_copies_and_size() {
+        let mut deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        deck.cards.push(deck.cards[0].clone());
+        let Err(GameError::DeckValidationFailed { errors }) = deck.validate(&DeckFormat::default())
+        else {
+            panic!("Deck should be invalid");
+        };
+        assert_eq!(
+            errors,
+            vec![
+                "Deck has 21 cards, expected 20".to_string(),
+                "3 copies of Bulbasaur, at most 2 allowed".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_huge_counts_are_rejected() {
+        let err = Deck::from_string("2 Bulbasaur A1 1\n4000000000 Ivysaur A1 2").unwrap_err();
+        assert_eq!(err, "Line 2: Deck lists more than 60 cards");
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let deck = Deck::from_file("example_decks/weezing-arbok.txt").unwrap();
+        let mut parsed = Deck::from_string(&deck.to_string()).unwrap();
+        // Parsed energy types come out of a HashSet
+        parsed.energy_types.sort();
+        assert_eq!(parsed.cards, deck.cards);
+        assert_eq!(parsed.energy_types, deck.energy_types);
+        assert!(deck.to_string().contains("2 Weezing A1 177\n"));
+    }
+
     #[test]
     fn test_from_string_without_leading_zeros() {
         let string = r#"Energy: Grass
@@ -0,0 +1,514 @@
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashMap;
+
+use crate::{
+    card_ids::CardId,
+    card_query::CardQuery,
+    database::{get_card_by_enum, query},
+    deck::DeckFormat,
+    errors::{GameError, GameResult},
+    generate_possible_trainer_actions,
+    tool_ids::ToolId,
+    types::{Card, EnergyType, TrainerType},
+    AbilityId, AttackEffect, AttackId, Deck, State,
+};
+
+/// Rarities from most to least common. Promos have an empty rarity.
+pub const RARITIES: [&str; 9] = ["", "◊", "◊◊", "◊◊◊", "◊◊◊◊", "☆", "☆☆", "☆☆☆", "Crown Rare"];
+
+/// Energy types that can come out of the Energy Zone.
+const ZONE_ENERGIES: [EnergyType; 8] = [
+    EnergyType::Grass,
+    EnergyType::Fire,
+    EnergyType::Water,
+    EnergyType::Lightning,
+    EnergyType::Psychic,
+    EnergyType::Fighting,
+    EnergyType::Darkness,
+    EnergyType::Metal,
+];
+
+/// Pack Points it takes to get a card of each rarity of `RARITIES`. Promos are free.
+pub const RARITY_COSTS: [u32; 9] = [0, 35, 70, 150, 500, 400, 1250, 1500, 2500];
+
+/// What `Deck::random` and the optimizers are allowed to put in a deck.
+#[derive(Debug, Clone, Default)]
+pub struct DeckConstraints {
+    /// Only use Pokémon of this type (and Colorless ones), and use it as the deck's energy.
+    pub energy_type: Option<EnergyType>,
+    /// Whether every evolution must come with the Pokémon it evolves from.
+    pub complete_evolution_lines: bool,
+    /// Most rare rarity allowed (one of `RARITIES`).
+    pub max_rarity: Option<String>,
+    pub format: DeckFormat,
+    /// Cards every deck must have, once per time they are listed (any printing of the
+    /// same name counts).
+    pub required_cards: Vec<CardId>,
+    /// Most ex Pokémon cards a deck can have.
+    pub max_ex: Option<usize>,
+    /// Most a deck can cost, adding up the `rarity_cost` of its cards. Keeps decks within
+    /// reach of players who can't get every rare card.
+    pub rarity_budget: Option<u32>,
+}
+
+impl DeckConstraints {
+    /// Checks what the deck as a whole has to satisfy: the required cards, the number of ex
+    /// Pokémon and the rarity budget. Cards are checked one by one with `allows`.
+    pub fn validate(&self, cards: &[Card]) -> GameResult<()> {
+        let mut errors = vec![];
+        let mut required: HashMap<String, usize> = HashMap::new();
+        for card_id in &self.required_cards {
+            *required
+                .entry(get_card_by_enum(*card_id).get_name())
+                .or_default() += 1;
+        }
+        for (name, count) in required {
+            let found = cards.iter().filter(|x| x.get_name() == name).count();
+            if found < count {
+                errors.push(format!("Has {found} {name}, {count} required"));
+            }
+        }
+        let ex = cards.iter().filter(|x| x.is_ex()).count();
+        if self.max_ex.is_some_and(|max_ex| ex > max_ex) {
+            errors.push(format!(
+                "Has {ex} ex Pokémon, at most {} allowed",
+                self.max_ex.unwrap()
+            ));
+        }
+        let cost = deck_cost(cards);
+        if self.rarity_budget.is_some_and(|budget| cost > budget) {
+            errors.push(format!(
+                "Costs {cost}, over the budget of {}",
+                self.rarity_budget.unwrap()
+            ));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(GameError::DeckValidationFailed { errors })
+        }
+    }
+
+    // Whether adding the cards keeps the deck within the ex and rarity limits
+    fn within_limits(&self, cards: &[Card], added: &[Card]) -> bool {
+        let ex = cards.iter().chain(added).filter(|x| x.is_ex()).count();
+        self.max_ex.is_none_or(|max_ex| ex <= max_ex)
+            && self
+                .rarity_budget
+                .is_none_or(|budget| deck_cost(cards) + deck_cost(added) <= budget)
+    }
+
+    pub(crate) fn allows(&self, card: &Card) -> bool {
+        if !is_implemented(card) || !self.format.allows(card) {
+            return false;
+        }
+        if let Some(max_rarity) = &self.max_rarity {
+            if rarity_rank(card_rarity(card)) > rarity_rank(max_rarity) {
+                return false;
+            }
+        }
+        match (card, self.energy_type) {
+            (Card::Pokemon(pokemon_card), Some(energy_type)) => {
+                pokemon_card.energy_type == energy_type
+                    || pokemon_card.energy_type == EnergyType::Colorless
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Whether the engine knows how to play this card (all its attacks, its ability, or its
+/// trainer effect), so that random decks don't panic mid-game.
+pub fn is_implemented(card: &Card) -> bool {
+    match card {
+        Card::Pokemon(pokemon_card) => {
+            let id = &pokemon_card.id[..];
+            let attacks_implemented = pokemon_card.attacks.iter().enumerate().all(|(i, x)| {
+                x.effect.is_none()
+                    || AttackId::from_pokemon_index(id, i).is_some()
+                    || AttackEffect::from_attack(id, i, x).is_some()
+            });
+            let ability_implemented =
+                pokemon_card.ability.is_none() || AbilityId::from_pokemon_id(id).is_some();
+            attacks_implemented && ability_implemented
+        }
+        Card::Trainer(trainer_card) => match trainer_card.trainer_card_type {
+            TrainerType::Tool => ToolId::from_trainer_card(trainer_card).is_some(),
+            _ => generate_possible_trainer_actions(&State::default(), trainer_card).is_some(),
+        },
+    }
+}
+
+/// Position of the rarity in `RARITIES`. Unknown rarities rank above all others.
+pub fn rarity_rank(rarity: &str) -> usize {
+    RARITIES
+        .iter()
+        .position(|x| *x == rarity)
+        .unwrap_or(RARITIES.len())
+}
+
+/// Pack Points it takes to get a card of the rarity (see `RARITY_COSTS`). Unknown rarities
+/// cost as much as the rarest one.
+pub fn rarity_cost(rarity: &str) -> u32 {
+    RARITY_COSTS[rarity_rank(rarity).min(RARITY_COSTS.len() - 1)]
+}
+
+fn deck_cost(cards: &[Card]) -> u32 {
+    cards.iter().map(|x| rarity_cost(card_rarity(x))).sum()
+}
+
+pub(crate) fn card_rarity(card: &Card) -> &str {
+    match card {
+        Card::Pokemon(pokemon_card) => &pokemon_card.rarity,
+        Card::Trainer(trainer_card) => &trainer_card.rarity,
+    }
+}
+
+impl Deck {
+    /// Generates a random legal 20-card deck out of the implemented cards that satisfy the
+    /// constraints. Errors if there aren't enough such cards to fill a deck, or if the
+    /// deck-wide constraints can't be met.
+    pub fn random(rng: &mut impl Rng, constraints: &DeckConstraints) -> GameResult<Deck> {
+        DeckBuilder::new(vec![]).complete(rng, constraints)
+    }
+}
+
+/// Adds cards to a partial deck while keeping it legal (see `DeckConstraints`).
+pub(crate) struct DeckBuilder {
+    cards: Vec<Card>,
+    counts: HashMap<String, u8>,
+}
+
+impl DeckBuilder {
+    pub(crate) fn new(cards: Vec<Card>) -> Self {
+        let mut counts = HashMap::new();
+        for card in &cards {
+            *counts.entry(card.get_name()).or_default() += 1;
+        }
+        Self { cards, counts }
+    }
+
+    /// Randomly fills the deck up to 20 cards, making sure it has a Basic Pokémon and the
+    /// required cards, within the deck-wide constraints.
+    pub(crate) fn complete(
+        mut self,
+        rng: &mut impl Rng,
+        constraints: &DeckConstraints,
+    ) -> GameResult<Deck> {
+        let mut pool: Vec<Card> = query(CardQuery::new())
+            .filter(|card| constraints.allows(card))
+            .collect();
+        pool.shuffle(rng);
+
+        for card_id in &constraints.required_cards {
+            let card = get_card_by_enum(*card_id);
+            let required = constraints
+                .required_cards
+                .iter()
+                .filter(|x| get_card_by_enum(**x).get_name() == card.get_name())
+                .count();
+            if self.count(&card.get_name()) as usize >= required {
+                continue;
+            }
+            let line = self
+                .evolution_line(&card, &pool, constraints, rng)
+                .ok_or_else(|| GameError::DeckValidationFailed {
+                    errors: vec![format!("Can't fit the required {}", card.get_name())],
+                })?;
+            self.add_line(line, 1);
+        }
+
+        if !self.cards.iter().any(|x| x.is_basic()) {
+            let basics: Vec<&Card> = pool
+                .iter()
+                .filter(|x| x.is_basic() && self.count(&x.get_name()) < 2)
+                .filter(|x| constraints.within_limits(&self.cards, &[(*x).clone()]))
+                .collect();
+            let basic = (*basics
+                .choose(rng)
+                .ok_or_else(|| GameError::DeckValidationFailed {
+                    errors: vec!["No Basic Pokémon satisfies the constraints".to_string()],
+                })?)
+            .clone();
+            if self.cards.len() == 20 {
+                self.remove(rng.gen_range(0..20));
+            }
+            let copies = rng.gen_range(1..=2);
+            self.add_line(vec![basic], copies);
+        }
+
+        // Keep going through the pool until full, or until a whole pass adds nothing.
+        while self.cards.len() < 20 {
+            let size_before = self.cards.len();
+            for card in &pool {
+                if self.cards.len() == 20 {
+                    break;
+                }
+                let Some(line) = self.evolution_line(card, &pool, constraints, rng) else {
+                    continue;
+                };
+                let copies = rng.gen_range(1..=2);
+                let Some(copies) = (1..=copies)
+                    .rev()
+                    .find(|x| constraints.within_limits(&self.cards, &self.copies_of(&line, *x)))
+                else {
+                    continue;
+                };
+                self.add_line(line, copies);
+            }
+            if self.cards.len() == size_before {
+                return Err(GameError::InsufficientCards {
+                    required: 20,
+                    found: self.cards.len(),
+                });
+            }
+        }
+
+        let mut energy_types = match constraints.energy_type {
+            Some(energy_type) => vec![energy_type],
+            None => {
+                let mut energy_types: Vec<EnergyType> = self
+                    .cards
+                    .iter()
+                    .filter_map(|x| x.get_type())
+                    .filter(|x| ZONE_ENERGIES.contains(x))
+                    .collect();
+                energy_types.sort();
+                energy_types.dedup();
+                energy_types
+            }
+        };
+        if energy_types.is_empty() {
+            energy_types.push(*ZONE_ENERGIES.choose(rng).unwrap());
+        }
+        constraints.validate(&self.cards)?;
+
+        Ok(Deck {
+            cards: self.cards,
+            energy_types,
+        })
+    }
+
+    /// Adds all the cards if they fit (in deck size and copies), otherwise none of them.
+    pub(crate) fn add_cards(&mut self, cards: Vec<Card>) -> bool {
+        if self.cards.len() + cards.len() > 20 {
+            return false;
+        }
+        let mut added: HashMap<String, u8> = HashMap::new();
+        for card in &cards {
+            *added.entry(card.get_name()).or_default() += 1;
+        }
+        if added
+            .iter()
+            .any(|(name, count)| self.count(name) + count > 2)
+        {
+            return false;
+        }
+        for card in cards {
+            *self.counts.entry(card.get_name()).or_default() += 1;
+            self.cards.push(card);
+        }
+        true
+    }
+
+    fn remove(&mut self, index: usize) {
+        let card = self.cards.remove(index);
+        *self.counts.get_mut(&card.get_name()).unwrap() -= 1;
+    }
+
+    fn count(&self, name: &str) -> u8 {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+
+    /// The card plus, if needed, whatever it evolves from (basic first). None if the card
+    /// can't be added (at 2 copies already, or a missing pre-evolution).
+    fn evolution_line(
+        &self,
+        card: &Card,
+        pool: &[Card],
+        constraints: &DeckConstraints,
+        rng: &mut impl Rng,
+    ) -> Option<Vec<Card>> {
+        if self.count(&card.get_name()) >= 2 {
+            return None;
+        }
+        let mut line = vec![card.clone()];
+        if constraints.complete_evolution_lines {
+            while let Card::Pokemon(pokemon_card) = &line[0] {
+                let Some(evolves_from) = &pokemon_card.evolves_from else {
+                    break;
+                };
+                if self.count(evolves_from) > 0 {
+                    break;
+                }
+                let options: Vec<&Card> = pool
+                    .iter()
+                    .filter(|x| x.get_name() == *evolves_from)
+                    .collect();
+                line.insert(0, (*options.choose(rng)?).clone());
+            }
+        }
+        if self.cards.len() + line.len() > 20 {
+            return None;
+        }
+        Some(line)
+    }
+
+    // The cards `add_line` would add
+    fn copies_of(&self, line: &[Card], copies: u8) -> Vec<Card> {
+        let copies = copies.min(((20 - self.cards.len()) / line.len()) as u8);
+        line.iter()
+            .flat_map(|card| {
+                let to_add = copies.min(2 - self.count(&card.get_name()));
+                std::iter::repeat_n(card.clone(), to_add as usize)
+            })
+            .collect()
+    }
+
+    fn add_line(&mut self, line: Vec<Card>, copies: u8) {
+        let copies = copies.min(((20 - self.cards.len()) / line.len()) as u8);
+        for card in line {
+            let name = card.get_name();
+            let to_add = copies.min(2 - self.count(&name));
+            for _ in 0..to_add {
+                self.cards.push(card.clone());
+            }
+            *self.counts.entry(name).or_default() += to_add;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        card_ids::{CardId, ALL_CARD_IDS},
+        players::{Player, RandomPlayer},
+        sets::SetId,
+        Game,
+    };
+
+    #[test]
+    fn test_random_decks_are_legal() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..50 {
+            let deck = Deck::random(&mut rng, &DeckConstraints::default()).unwrap();
+            assert_eq!(deck.validate(&DeckFormat::default()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_random_deck_constraints() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let constraints = DeckConstraints {
+            energy_type: Some(EnergyType::Fire),
+            complete_evolution_lines: true,
+            max_rarity: Some("◊◊".to_string()),
+            format: DeckFormat::only_sets(&[SetId::A1, SetId::PromoA]),
+            ..Default::default()
+        };
+        for _ in 0..20 {
+            let deck = Deck::random(&mut rng, &constraints).unwrap();
+            assert_eq!(deck.validate(&constraints.format), Ok(()));
+            assert_eq!(deck.energy_types, vec![EnergyType::Fire]);
+            for card in &deck.cards {
+                assert!(rarity_rank(card_rarity(card)) <= 2);
+                if let Card::Pokemon(pokemon_card) = card {
+                    assert!(matches!(
+                        pokemon_card.energy_type,
+                        EnergyType::Fire | EnergyType::Colorless
+                    ));
+                    if let Some(evolves_from) = &pokemon_card.evolves_from {
+                        assert!(deck.cards.iter().any(|x| x.get_name() == *evolves_from));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deck_wide_constraints() {
+        let pikachu = get_card_by_enum(CardId::A1096PikachuEx);
+        let mewtwo = get_card_by_enum(CardId::A1129MewtwoEx);
+        let constraints = DeckConstraints {
+            required_cards: vec![CardId::A1096PikachuEx, CardId::A1096PikachuEx],
+            max_ex: Some(1),
+            rarity_budget: Some(rarity_cost(card_rarity(&pikachu))),
+            ..Default::default()
+        };
+        let Err(GameError::DeckValidationFailed { errors }) =
+            constraints.validate(&[pikachu.clone(), mewtwo])
+        else {
+            panic!("Expected the deck to break the constraints");
+        };
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            constraints.validate(std::slice::from_ref(&pikachu)),
+            Err(GameError::DeckValidationFailed {
+                errors: vec!["Has 1 Pikachu ex, 2 required".to_string()]
+            })
+        );
+
+        // Within the budget, ex limit and with both copies
+        let constraints = DeckConstraints {
+            max_ex: Some(2),
+            rarity_budget: Some(rarity_cost(card_rarity(&pikachu)) * 2 + 500),
+            ..constraints
+        };
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..20 {
+            let deck = Deck::random(&mut rng, &constraints).unwrap();
+            assert_eq!(constraints.validate(&deck.cards), Ok(()));
+            assert_eq!(deck.validate(&DeckFormat::default()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_unsatisfiable_constraints() {
+        let mut rng = StdRng::seed_from_u64(2);
+        // Promos have no rarity, so nothing in A1 is allowed
+        let constraints = DeckConstraints {
+            format: DeckFormat::only_sets(&[SetId::A1]),
+            max_rarity: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Deck::random(&mut rng, &constraints),
+            Err(GameError::DeckValidationFailed { .. })
+        ));
+
+        // Only Bulbasaur is left
+        let banned: Vec<CardId> = ALL_CARD_IDS
+            .iter()
+            .copied()
+            .filter(|x| *x != CardId::A1001Bulbasaur)
+            .collect();
+        let constraints = DeckConstraints {
+            format: DeckFormat::default().with_banned(&banned),
+            ..Default::default()
+        };
+        assert_eq!(
+            Deck::random(&mut rng, &constraints),
+            Err(GameError::InsufficientCards {
+                required: 20,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_random_decks_can_be_played() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for seed in 0..20 {
+            let players: Vec<Box<dyn Player>> = (0..2)
+                .map(|_| {
+                    let deck = Deck::random(&mut rng, &DeckConstraints::default()).unwrap();
+                    Box::new(RandomPlayer { deck }) as Box<dyn Player>
+                })
+                .collect();
+            Game::new(players, seed).play();
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{types::Card, Deck};
+
+/// Cards in the opening hand.
+const OPENING_HAND_SIZE: usize = 5;
+
+/// Number of ways to choose `k` out of `n` items.
+fn choose(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (0..k.min(n - k)).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Hypergeometric probability of drawing at least `at_least` of the `successes` cards
+/// when drawing `draws` cards out of `population`.
+pub fn hypergeometric_at_least(
+    population: usize,
+    successes: usize,
+    draws: usize,
+    at_least: usize,
+) -> f64 {
+    let draws = draws.min(population);
+    let total = choose(population, draws);
+    (at_least..=successes.min(draws))
+        .map(|k| choose(successes, k) * choose(population - successes, draws - k) / total)
+        .sum()
+}
+
+/// Chance that 5 cards drawn at random include a Basic Pokémon, i.e. how often the game
+/// has to step in to give one (the engine never deals an opening hand without one).
+pub fn natural_basic_probability(deck: &Deck) -> f64 {
+    let basics = deck.cards.iter().filter(|x| x.is_basic()).count();
+    hypergeometric_at_least(deck.cards.len(), basics, OPENING_HAND_SIZE, 1)
+}
+
+/// Chance of having seen at least one card named each of `names` by the player's `turn`
+/// (0 for the opening hand, 1 for their first turn), e.g. `&["Pikachu ex", "Giovanni"]`,
+/// computed exactly instead of by simulating games.
+///
+/// Openings follow the engine (see `Deck::shuffle`): one of the Basic Pokémon is put in the
+/// opening hand, and the other 4 cards come from the rest of the deck. Players then draw a
+/// card at the start of each of their turns, so by their turn `n` they have seen `5 + n`
+/// cards.
+pub fn combo_probability(deck: &Deck, names: &[&str], turn: usize) -> f64 {
+    let basics: Vec<&Card> = deck.cards.iter().filter(|x| x.is_basic()).collect();
+    if basics.is_empty() || deck.cards.len() < OPENING_HAND_SIZE {
+        return 0.0;
+    }
+    // Average over which Basic the opening hand got, the rest is drawn at random
+    let draws = (OPENING_HAND_SIZE - 1 + turn).min(deck.cards.len() - 1);
+    let mut probability = 0.0;
+    for forced in &basics {
+        let remaining: Vec<usize> = names
+            .iter()
+            .filter(|name| forced.get_name() != **name)
+            .map(|name| deck.cards.iter().filter(|x| x.get_name() == **name).count())
+            .collect();
+        probability += all_seen_probability(deck.cards.len() - 1, &remaining, draws);
+    }
+    probability / basics.len() as f64
+}
+
+// Chance of drawing at least one card of each group, by inclusion-exclusion over the
+// groups that are missed.
+fn all_seen_probability(population: usize, groups: &[usize], draws: usize) -> f64 {
+    let total = choose(population, draws);
+    (0..1usize << groups.len())
+        .map(|missed| {
+            let missed_cards: usize = (0..groups.len())
+                .filter(|i| missed & (1 << i) != 0)
+                .map(|i| groups[i])
+                .sum();
+            let sign = if missed.count_ones() % 2 == 0 {
+                1.0
+            } else {
+                -1.0
+            };
+            sign * choose(population - missed_cards, draws) / total
+        })
+        .sum()
+}
+
+/// How much energy the attacks of a deck's Pokémon cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnergyCurve {
+    /// Number of Pokémon cards whose most expensive attack costs as many energies as the
+    /// index.
+    pub counts: Vec<u32>,
+    /// Average cost of the most expensive attack, over Pokémon with attacks.
+    pub average: f64,
+}
+
+pub fn energy_curve(deck: &Deck) -> EnergyCurve {
+    let costs: Vec<usize> = deck
+        .cards
+        .iter()
+        .filter_map(|card| match card {
+            Card::Pokemon(pokemon_card) => pokemon_card
+                .attacks
+                .iter()
+                .map(|x| x.energy_required.len())
+                .max(),
+            Card::Trainer(_) => None,
+        })
+        .collect();
+    let mut counts = vec![0; costs.iter().max().map_or(0, |x| x + 1)];
+    for cost in &costs {
+        counts[*cost] += 1;
+    }
+    let average = if costs.is_empty() {
+        0.0
+    } else {
+        costs.iter().sum::<usize>() as f64 / costs.len() as f64
+    };
+    EnergyCurve { counts, average }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::State;
+
+    #[test]
+    fn test_hypergeometric() {
+        // 1 - C(18, 5) / C(20, 5)
+        let expected = 1.0 - (15.0 * 14.0) / (20.0 * 19.0);
+        assert!((hypergeometric_at_least(20, 2, 5, 1) - expected).abs() < 1e-9);
+        assert_eq!(hypergeometric_at_least(20, 0, 5, 1), 0.0);
+        assert!((hypergeometric_at_least(20, 20, 5, 5) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_combo_probability_matches_dealt_hands() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let names = ["Venusaur ex", "Erika"];
+        let expected = combo_probability(&deck, &names, 2);
+
+        // Deal the opening hand and two draws like the engine does
+        let mut rng = StdRng::seed_from_u64(0);
+        let trials = 4000;
+        let mut hits = 0;
+        for _ in 0..trials {
+            let state = State::initialize(&deck, &deck, &mut rng);
+            let mut seen: Vec<String> = state.hands[0].iter().map(|x| x.get_name()).collect();
+            seen.extend(state.decks[0].cards.iter().take(2).map(|x| x.get_name()));
+            if names.iter().all(|name| seen.iter().any(|x| x == name)) {
+                hits += 1;
+            }
+        }
+        let simulated = hits as f64 / trials as f64;
+        assert!(
+            (simulated - expected).abs() < 0.03,
+            "{simulated} vs {expected}"
+        );
+
+        // Basics are more likely than their share of the deck, thanks to the forced Basic
+        assert!(combo_probability(&deck, &["Bulbasaur"], 0) > hypergeometric_at_least(20, 2, 5, 1));
+        assert!(combo_probability(&deck, &["Venusaur ex"], 20) > 0.999);
+        assert!(natural_basic_probability(&deck) < 1.0);
+    }
+
+    #[test]
+    fn test_energy_curve() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let curve = energy_curve(&deck);
+        assert_eq!(curve.counts.iter().sum::<u32>(), 10);
+        // Venusaur ex's Giant Bloom costs 4
+        assert_eq!(curve.counts.len(), 5);
+        assert!(curve.average > 1.0 && curve.average < 4.0);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    attack_effects::AttackEffect,
+    types::{EnergyType, StatusCondition},
+};
+
+/// What `parse_attack_effect` understood of an effect text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedEffect {
+    pub effects: Vec<AttackEffect>,
+    /// Sentences that matched no effect, as written.
+    pub unparsed: Vec<String>,
+}
+
+impl ParsedEffect {
+    /// Whether the whole text was understood.
+    pub fn is_complete(&self) -> bool {
+        self.unparsed.is_empty()
+    }
+}
+
+/// Parses an effect text such as "Flip 2 coins. This attack does 30 damage for each heads."
+/// into `AttackEffect`s, on a best-effort basis, so that attacks of new sets that only have
+/// common effects can be played before they are added to the `attack_effects` table.
+///
+/// Text is parsed one sentence at a time ("Flip ..." sentences together with the next one).
+/// Sentences that don't match a known effect are returned as `unparsed`, and the attack
+/// should then not be trusted to the interpreter.
+///
+/// "X damage for each heads" is read as extra damage: such attacks have no fixed damage
+/// in the database.
+pub fn parse_attack_effect(text: &str) -> ParsedEffect {
+    // "Flip 2 coins, this attack does..." is the same as two sentences
+    let text = text
+        .replace(" coins, ", " coins. ")
+        .replace(" coin, ", " coin. ");
+    let sentences: Vec<&str> = text
+        .split('.')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .collect();
+
+    let mut parsed = ParsedEffect::default();
+    let mut i = 0;
+    while i < sentences.len() {
+        let sentence = sentences[i].to_lowercase();
+        if let Some(coins) = parse_flip(&sentence) {
+            let effect = sentences
+                .get(i + 1)
+                .and_then(|next| parse_coin_flip_effect(coins, &next.to_lowercase()));
+            match effect.clone() {
+                Some(effect) => parsed.effects.push(effect),
+                None => parsed
+                    .unparsed
+                    .push(sentences[i..(i + 2).min(sentences.len())].join(". ")),
+            }
+            i += 2;
+            // Once tails means nothing, what heads does is just what the attack does
+            if effect == Some(AttackEffect::CoinFlipOrNothing) {
+                while let Some(heads) = sentences
+                    .get(i)
+                    .map(|x| x.to_lowercase())
+                    .and_then(|x| Some(x.strip_prefix("if heads, ")?.to_string()))
+                {
+                    match parse_sentence(&heads) {
+                        Some(effects) => parsed.effects.extend(effects),
+                        None => parsed.unparsed.push(sentences[i].to_string()),
+                    }
+                    i += 1;
+                }
+            }
+            continue;
+        }
+        match parse_sentence(&sentence) {
+            Some(effects) => parsed.effects.extend(effects),
+            None => parsed.unparsed.push(sentences[i].to_string()),
+        }
+        i += 1;
+    }
+    parsed
+}
+
+// "flip a coin" or "flip 2 coins"
+fn parse_flip(sentence: &str) -> Option<u8> {
+    if sentence == "flip a coin" {
+        return Some(1);
+    }
+    sentence
+        .strip_prefix("flip ")?
+        .strip_suffix(" coins")?
+        .parse()
+        .ok()
+}
+
+// The sentence following a coin flip
+fn parse_coin_flip_effect(coins: u8, sentence: &str) -> Option<AttackEffect> {
+    if let Some(damage) = sentence
+        .strip_prefix("this attack does ")
+        .and_then(|x| x.strip_suffix(" damage for each heads"))
+    {
+        let damage = damage.strip_suffix(" more").unwrap_or(damage);
+        return Some(AttackEffect::CoinFlipExtraDamage {
+            coins,
+            damage: damage.parse().ok()?,
+        });
+    }
+    if coins != 1 {
+        return None;
+    }
+    if sentence == "if tails, this attack does nothing" {
+        return Some(AttackEffect::CoinFlipOrNothing);
+    }
+    if let Some(damage) = sentence
+        .strip_prefix("if heads, this attack does ")
+        .and_then(|x| x.strip_suffix(" more damage"))
+    {
+        return Some(AttackEffect::CoinFlipExtraDamage {
+            coins,
+            damage: damage.parse().ok()?,
+        });
+    }
+    let status = sentence.strip_prefix("if heads, your opponent's active pokémon is now ")?;
+    Some(AttackEffect::CoinFlipStatus(parse_status(status)?))
+}
+
+fn parse_sentence(sentence: &str) -> Option<Vec<AttackEffect>> {
+    if let Some(statuses) = sentence.strip_prefix("your opponent's active pokémon is now ") {
+        return statuses
+            .split(" and ")
+            .map(|x| parse_status(x).map(AttackEffect::Status))
+            .collect();
+    }
+    if let Some(damage) = sentence
+        .strip_prefix("this attack also does ")
+        .and_then(|x| x.strip_suffix(" damage to each of your opponent's benched pokémon"))
+    {
+        return Some(vec![AttackEffect::BenchDamage(damage.parse().ok()?)]);
+    }
+    if let Some(damage) = sentence
+        .strip_prefix("this pokémon also does ")
+        .and_then(|x| x.strip_suffix(" damage to itself"))
+    {
+        return Some(vec![AttackEffect::SelfDamage(damage.parse().ok()?)]);
+    }
+    if let Some(status) = sentence.strip_prefix("this pokémon is now ") {
+        return Some(vec![AttackEffect::SelfStatus(parse_status(status)?)]);
+    }
+    if let Some(restriction) = sentence.strip_prefix("during your next turn, this pokémon can't ")
+    {
+        // Attacks can only forbid themselves ("can't use [this attack's name]")
+        return match restriction {
+            "attack" => Some(vec![AttackEffect::CantAttackNextTurn]),
+            _ if restriction.starts_with("use ") => Some(vec![AttackEffect::CantUseAgainNextTurn]),
+            _ => None,
+        };
+    }
+    if let Some(amount) = sentence
+        .strip_prefix("heal ")
+        .and_then(|x| x.strip_suffix(" damage from this pokémon"))
+    {
+        return Some(vec![AttackEffect::SelfHeal(amount.parse().ok()?)]);
+    }
+    let energy = sentence
+        .strip_prefix("discard ")?
+        .strip_suffix(" energy from this pokémon")?;
+    // "a [R]", "2 [R]" or "an" (of the player's choice)
+    let (amount, energy_type) = match energy.split_once(' ') {
+        Some((amount, symbol)) => (parse_amount(amount)?, parse_energy_symbol(symbol)?),
+        None => (parse_amount(energy)?, EnergyType::Colorless),
+    };
+    Some(vec![AttackEffect::DiscardOwnEnergy(vec![
+        energy_type;
+        amount
+    ])])
+}
+
+fn parse_amount(amount: &str) -> Option<usize> {
+    match amount {
+        "a" | "an" => Some(1),
+        _ => amount.parse().ok(),
+    }
+}
+
+fn parse_status(status: &str) -> Option<StatusCondition> {
+    match status {
+        "poisoned" => Some(StatusCondition::Poisoned),
+        "paralyzed" => Some(StatusCondition::Paralyzed),
+        "asleep" => Some(StatusCondition::Asleep),
+        "burned" => Some(StatusCondition::Burned),
+        "confused" => Some(StatusCondition::Confused),
+        _ => None,
+    }
+}
+
+// Energy symbols as written in effect texts, e.g. "[r]" once lowercased
+fn parse_energy_symbol(symbol: &str) -> Option<EnergyType> {
+    match symbol {
+        "[g]" => Some(EnergyType::Grass),
+        "[r]" => Some(EnergyType::Fire),
+        "[w]" => Some(EnergyType::Water),
+        "[l]" => Some(EnergyType::Lightning),
+        "[p]" => Some(EnergyType::Psychic),
+        "[f]" => Some(EnergyType::Fighting),
+        "[d]" => Some(EnergyType::Darkness),
+        "[m]" => Some(EnergyType::Metal),
+        "[c]" => Some(EnergyType::Colorless),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::ALL_CARD_IDS,
+        database::get_card_by_enum,
+        types::{Attack, Card},
+    };
+
+    #[test]
+    fn test_parses_common_effects() {
+        let parsed = parse_attack_effect("Flip 2 coins, this attack does 30 damage for each heads");
+        assert_eq!(
+            parsed.effects,
+            vec![AttackEffect::CoinFlipExtraDamage {
+                coins: 2,
+                damage: 30
+            }]
+        );
+        assert!(parsed.is_complete());
+
+        let parsed = parse_attack_effect(
+            "Heal 20 damage from this Pokémon. Your opponent's Active Pokémon is now Poisoned and Burned.",
+        );
+        assert_eq!(
+            parsed.effects,
+            vec![
+                AttackEffect::SelfHeal(20),
+                AttackEffect::Status(StatusCondition::Poisoned),
+                AttackEffect::Status(StatusCondition::Burned),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parses_effects_on_the_attacker() {
+        let parsed = parse_attack_effect("This Pokémon is now Confused.");
+        assert_eq!(
+            parsed.effects,
+            vec![AttackEffect::SelfStatus(StatusCondition::Confused)]
+        );
+        let parsed = parse_attack_effect(
+            "This Pokémon also does 20 damage to itself. During your next turn, this Pokémon can't attack.",
+        );
+        assert_eq!(
+            parsed.effects,
+            vec![
+                AttackEffect::SelfDamage(20),
+                AttackEffect::CantAttackNextTurn
+            ]
+        );
+        let parsed = parse_attack_effect("During your next turn, this Pokémon can't use Big Beat.");
+        assert_eq!(parsed.effects, vec![AttackEffect::CantUseAgainNextTurn]);
+        assert!(
+            !parse_attack_effect("During your next turn, this Pokémon can't retreat.")
+                .is_complete()
+        );
+    }
+
+    #[test]
+    fn test_flags_unparseable_sentences() {
+        let parsed = parse_attack_effect(
+            "Discard a [R] Energy from this Pokémon. Draw a card. Flip a coin. If heads, discard a random card from your opponent's hand.",
+        );
+        assert_eq!(
+            parsed.effects,
+            vec![AttackEffect::DiscardOwnEnergy(vec![EnergyType::Fire])]
+        );
+        assert_eq!(
+            parsed.unparsed,
+            vec![
+                "Draw a card",
+                "Flip a coin. If heads, discard a random card from your opponent's hand"
+            ]
+        );
+        assert!(!parsed.is_complete());
+    }
+
+    #[test]
+    fn test_agrees_with_the_attack_effects_table() {
+        for card_id in ALL_CARD_IDS {
+            let card = get_card_by_enum(*card_id);
+            if !matches!(card, Card::Pokemon(_)) {
+                continue;
+            }
+            for (index, attack) in card.get_attacks().iter().enumerate() {
+                let Some(effects) = AttackEffect::from_pokemon_index(&card.get_id(), index) else {
+                    continue;
+                };
+                let text = attack.effect.as_deref().unwrap_or_default();
+                assert_eq!(parse_attack_effect(text).effects, effects, "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_attacks_missing_from_the_table_fall_back_to_their_text() {
+        let attack = |effect: &str| Attack {
+            energy_required: vec![EnergyType::Colorless],
+            title: "Test".to_string(),
+            fixed_damage: 10,
+            effect: Some(effect.to_string()),
+        };
+        assert_eq!(
+            AttackEffect::from_attack("Z9 999", 0, &attack("Heal 20 damage from this Pokémon.")),
+            Some(vec![AttackEffect::SelfHeal(20)])
+        );
+        assert_eq!(
+            AttackEffect::from_attack("Z9 999", 0, &attack("Draw a card.")),
+            None
+        );
+    }
+}
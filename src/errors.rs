@@ -36,7 +36,11 @@ pub enum GameError {
     // Configuration errors
     InvalidConfiguration { setting: String, value: String },
     MissingRequiredField { field: String },
-    
+
+    // Saved data errors
+    UnsupportedVersion { found: u32, supported: u32 },
+    InvalidSavedData { reason: String },
+
     // AI/Player errors
     PlayerError { player_type: String, error: String },
     AICalculationError { description: String },
@@ -115,6 +119,16 @@ impl fmt::Display for GameError {
             GameError::MissingRequiredField { field } => {
                 write!(f, "Missing required field: {}", field)
             }
+            GameError::UnsupportedVersion { found, supported } => {
+                write!(
+                    f,
+                    "Saved with format version {}, this engine reads up to version {}",
+                    found, supported
+                )
+            }
+            GameError::InvalidSavedData { reason } => {
+                write!(f, "Invalid saved data: {}", reason)
+            }
             GameError::PlayerError { player_type, error } => {
                 write!(f, "Player error ({}): {}", player_type, error)
             }
@@ -183,6 +197,12 @@ impl GameError {
         }
     }
     
+    pub fn invalid_saved_data(reason: impl Into<String>) -> Self {
+        GameError::InvalidSavedData {
+            reason: reason.into(),
+        }
+    }
+    
     pub fn internal(context: impl Into<String>, details: impl Into<String>) -> Self {
         GameError::InternalError {
             context: context.into(),
@@ -0,0 +1,229 @@
+use std::{
+    ffi::{c_char, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use serde::Serialize;
+
+use crate::{deck::DeckFormat, Deck, Game, GameRules};
+
+/// A game driven through the C API, to embed the simulator in other engines (see
+/// `include/deckgym.h`). Build it as a C library with
+/// `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+///
+/// Games are opaque pointers, decided one action at a time: list the legal actions, then
+/// apply one of them by its index. Strings handed out are JSON and must be given back to
+/// `deckgym_string_free`. Should the engine panic, functions return NULL (or -1) instead,
+/// and the game should not be used anymore.
+pub struct DeckgymGame {
+    game: Game,
+}
+
+#[derive(Serialize)]
+struct LegalAction {
+    index: usize,
+    actor: usize,
+    action: String,
+}
+
+// Unwinding into C is undefined behavior, so a panic gives `on_panic` instead
+fn catch_panic<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(on_panic)
+}
+
+fn into_c_string(json: String) -> *mut c_char {
+    CString::new(json)
+        .expect("JSON should have no interior NUL")
+        .into_raw()
+}
+
+/// Creates a game between two decks (in the deck file format), or returns NULL if either
+/// can't be parsed or isn't legal (see `Deck::validate`). Free it with `deckgym_game_free`.
+///
+/// # Safety
+///
+/// `deck_a` and `deck_b` must be valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn deckgym_game_new(
+    deck_a: *const c_char,
+    deck_b: *const c_char,
+    seed: u64,
+) -> *mut DeckgymGame {
+    if deck_a.is_null() || deck_b.is_null() {
+        return ptr::null_mut();
+    }
+    catch_panic(ptr::null_mut(), || {
+        let parse = |deck: *const c_char| -> Result<Deck, String> {
+            let contents = CStr::from_ptr(deck).to_str().map_err(|x| x.to_string())?;
+            let deck = Deck::from_string(contents)?;
+            deck.validate(&DeckFormat::default())
+                .map_err(|x| x.to_string())?;
+            Ok(deck)
+        };
+        let (Ok(deck_a), Ok(deck_b)) = (parse(deck_a), parse(deck_b)) else {
+            return ptr::null_mut();
+        };
+        let game = Game::without_players(&deck_a, &deck_b, seed, GameRules::default());
+        Box::into_raw(Box::new(DeckgymGame { game }))
+    })
+}
+
+/// The actions that can be applied next, as a JSON array of
+/// `{"index": 0, "actor": 1, "action": "EndTurn"}` objects (empty once the game is over).
+/// Returns NULL on a NULL game.
+///
+/// # Safety
+///
+/// `game` must be NULL or come from `deckgym_game_new` and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn deckgym_game_legal_actions_json(game: *const DeckgymGame) -> *mut c_char {
+    if game.is_null() {
+        return ptr::null_mut();
+    }
+    catch_panic(ptr::null_mut(), || {
+        let game = &(*game).game;
+        let actions = if game.get_state().is_game_over() {
+            vec![]
+        } else {
+            game.legal_actions().1
+        };
+        let actions = actions
+            .iter()
+            .enumerate()
+            .map(|(index, x)| LegalAction {
+                index,
+                actor: x.actor,
+                action: x.action.to_string(),
+            })
+            .collect::<Vec<_>>();
+        into_c_string(serde_json::to_string(&actions).expect("Actions should serialize"))
+    })
+}
+
+/// Applies the legal action at `index` (see `deckgym_game_legal_actions_json`). Returns 0
+/// on success, or -1 if there is no such action (e.g. the game is over) or on a NULL game.
+/// The first opening board is only played once the other player chose theirs, so that
+/// neither sees the other's before choosing.
+///
+/// # Safety
+///
+/// `game` must be NULL or come from `deckgym_game_new` and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn deckgym_game_apply_action(game: *mut DeckgymGame, index: usize) -> i32 {
+    if game.is_null() {
+        return -1;
+    }
+    catch_panic(-1, || {
+        let game = &mut (*game).game;
+        let (_, actions) = game.legal_actions();
+        let Some(action) = actions.get(index) else {
+            return -1;
+        };
+        match game.play_tick_with(action) {
+            Ok(_) => 0,
+            Err(_) => -1,
+        }
+    })
+}
+
+/// The game as `player` (0 or 1) sees it, as the JSON of a `MaskedState`. Its `winner`
+/// tells whether the game is over. Returns NULL for any other player, or on a NULL game.
+///
+/// # Safety
+///
+/// `game` must be NULL or come from `deckgym_game_new` and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn deckgym_game_state_json(
+    game: *const DeckgymGame,
+    player: usize,
+) -> *mut c_char {
+    if game.is_null() || player > 1 {
+        return ptr::null_mut();
+    }
+    catch_panic(ptr::null_mut(), || {
+        let state = (*game).game.get_state().masked_for(player);
+        into_c_string(serde_json::to_string(&state).expect("States should serialize"))
+    })
+}
+
+/// Frees a game. Does nothing on NULL.
+///
+/// # Safety
+///
+/// `game` must come from `deckgym_game_new` and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn deckgym_game_free(game: *mut DeckgymGame) {
+    if !game.is_null() {
+        catch_panic((), || drop(Box::from_raw(game)));
+    }
+}
+
+/// Frees a string returned by this API. Does nothing on NULL.
+///
+/// # Safety
+///
+/// `string` must come from this API and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn deckgym_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        catch_panic((), || drop(CString::from_raw(string)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    unsafe fn take_json(string: *mut c_char) -> serde_json::Value {
+        let json = serde_json::from_str(CStr::from_ptr(string).to_str().unwrap()).unwrap();
+        deckgym_string_free(string);
+        json
+    }
+
+    #[test]
+    fn test_plays_a_game_through_the_c_api() {
+        let deck = fs::read_to_string("example_decks/venusaur-exeggutor.txt").unwrap();
+        let deck = CString::new(deck).unwrap();
+        unsafe {
+            let game = deckgym_game_new(deck.as_ptr(), deck.as_ptr(), 0);
+            assert!(!game.is_null());
+            loop {
+                let actions = take_json(deckgym_game_legal_actions_json(game));
+                let actions = actions.as_array().unwrap();
+                if actions.is_empty() {
+                    break;
+                }
+                // Always take the last one, to keep the game moving (it's usually EndTurn)
+                assert_eq!(deckgym_game_apply_action(game, actions.len() - 1), 0);
+            }
+            assert_eq!(deckgym_game_apply_action(game, 0), -1);
+            let state = take_json(deckgym_game_state_json(game, 1));
+            assert_eq!(state["perspective"], 1);
+            assert!(deckgym_game_state_json(game, 2).is_null());
+            deckgym_game_free(game);
+        }
+    }
+
+    #[test]
+    fn test_null_games_are_rejected() {
+        unsafe {
+            assert!(deckgym_game_legal_actions_json(ptr::null()).is_null());
+            assert_eq!(deckgym_game_apply_action(ptr::null_mut(), 0), -1);
+            assert!(deckgym_game_state_json(ptr::null(), 0).is_null());
+        }
+    }
+
+    #[test]
+    fn test_invalid_decks_give_null() {
+        let deck = CString::new("Not a card").unwrap();
+        // Parses, but has no Basic Pokémon to start with
+        let no_basic = CString::new("2 Potion P-A 1").unwrap();
+        unsafe {
+            assert!(deckgym_game_new(deck.as_ptr(), deck.as_ptr(), 0).is_null());
+            assert!(deckgym_game_new(no_basic.as_ptr(), no_basic.as_ptr(), 0).is_null());
+        }
+    }
+}
@@ -1,53 +1,129 @@
+use std::{
+    io::Write,
+    time::{Duration, Instant},
+};
+
 use colored::Colorize;
-use log::{debug, info, trace};
-use rand::{rngs::StdRng, SeedableRng};
+use log::{debug, info, trace, warn};
+use rand::seq::SliceRandom;
 
 use crate::{
-    actions::{apply_action, Action},
+    actions::{apply_action, apply_action_with_outcome_rng, Action, CoinFlips, SimpleAction},
+    audit::AuditLog,
+    clock::{GameClock, TimeControl, TimeoutPolicy},
+    errors::{GameError, GameResult},
+    game_log::{GameLogEntry, GameLogger},
+    game_rules::{GameRules, TieBreak},
     generate_possible_actions,
+    move_generation::generate_setup_actions,
     players::Player,
-    state::GameOutcome,
+    rng_registry::{RngRegistry, RngStream},
+    state::{GameOutcome, TurnPhase, MAX_BENCH_SIZE},
     types::EnergyType,
-    State,
+    Deck, State,
 };
 
+/// What a game waits on, see `Game::poll_decision`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingChoice {
+    /// The player has to choose one of the actions and give it to `Game::submit_choice`.
+    Decision { actor: usize, actions: Vec<Action> },
+    /// The game is over, with this outcome (None if it ran out of turns without a winner).
+    GameOver(Option<GameOutcome>),
+}
+
 pub struct Game {
     seed: u64,
-    rng: StdRng,
+    rngs: RngRegistry,
     players: Vec<Box<dyn Player>>,
 
     state: State,
 
     // keeping statistics for Game analysis here (outside of "State")
     degrees_per_ply: Vec<u32>,
+    // (ply, actor, flips) for every action that flipped coins, so replays can show them
+    coin_flip_log: Vec<(u32, usize, Vec<CoinFlips>)>,
+    // Hash chain of every decision, only recorded if auditing was enabled
+    audit: Option<AuditLog>,
+    // JSONL log of every applied action, only written if logging was enabled
+    logger: Option<GameLogger>,
+    // Time left to each player, only kept if a time control was set
+    clock: Option<GameClock>,
+    // Whether the game already got its `sudden_death_turns` extension
+    in_sudden_death: bool,
+    // When the pending external decision was first polled, to time it on the clock
+    decision_started: Option<Instant>,
+    // Opening boards chosen but not played yet, so that neither player sees the other's
+    // before choosing theirs (see play_tick)
+    setup_choices: [Option<Action>; 2],
 
     debug: bool,
 }
 
 impl Game {
     pub fn from_state(state: State, players: Vec<Box<dyn Player>>, seed: u64) -> Self {
-        let rng = StdRng::seed_from_u64(seed);
         Game {
             seed,
-            rng,
+            rngs: RngRegistry::new(seed),
             players,
             state,
             degrees_per_ply: vec![],
+            coin_flip_log: vec![],
+            audit: None,
+            logger: None,
+            clock: None,
+            in_sudden_death: false,
+            decision_started: None,
+            setup_choices: [None, None],
             debug: false,
         }
     }
 
     pub fn new(players: Vec<Box<dyn Player>>, seed: u64) -> Self {
-        let mut rng = StdRng::seed_from_u64(seed);
+        Self::with_rules(players, seed, GameRules::default())
+    }
+
+    /// Like `new`, but playing under a variant of the rules (see `GameRules`).
+    pub fn with_rules(players: Vec<Box<dyn Player>>, seed: u64, rules: GameRules) -> Self {
         let deck_a = players[0].get_deck();
         let deck_b = players[1].get_deck();
-        let state = State::initialize(&deck_a, &deck_b, &mut rng);
+        Self::with_decks(players, &deck_a, &deck_b, seed, rules)
+    }
+
+    /// A game whose decisions are all taken outside of it (e.g. by remote clients), to be
+    /// played with `play_tick_with`.
+    pub fn without_players(deck_a: &Deck, deck_b: &Deck, seed: u64, rules: GameRules) -> Self {
+        Self::with_decks(vec![], deck_a, deck_b, seed, rules)
+    }
+
+    fn with_decks(
+        players: Vec<Box<dyn Player>>,
+        deck_a: &Deck,
+        deck_b: &Deck,
+        seed: u64,
+        rules: GameRules,
+    ) -> Self {
+        assert!(
+            rules.bench_size <= MAX_BENCH_SIZE,
+            "Bench size can be at most {MAX_BENCH_SIZE}, got {}",
+            rules.bench_size
+        );
+        let mut rngs = RngRegistry::new(seed);
+        let mut state = State::initialize(deck_a, deck_b, rngs.get(RngStream::DeckShuffle));
+        state.rules = rules;
         Game {
             seed,
-            rng,
+            rngs,
             players,
             state,
             degrees_per_ply: vec![],
+            coin_flip_log: vec![],
+            audit: None,
+            logger: None,
+            clock: None,
+            in_sudden_death: false,
+            decision_started: None,
+            setup_choices: [None, None],
             debug: true,
         }
     }
@@ -60,51 +136,451 @@ impl Game {
         while !self.state.is_game_over() {
             self.play_tick();
         }
+        if let Some(logger) = &mut self.logger {
+            logger.flush().expect("Failed to write game log");
+        }
         self.state.winner
     }
 
+    /// Like `play`, but also returns the audit chain of every decision taken from now on
+    /// (see `AuditLog`), to verify the game by replaying it.
+    pub fn play_audited(&mut self) -> (Option<GameOutcome>, AuditLog) {
+        self.audit = Some(AuditLog::new());
+        let outcome = self.play();
+        let audit = self
+            .audit
+            .take()
+            .expect("Audit should be recorded while playing");
+        (outcome, audit)
+    }
+
+    /// Applies the tie-break rules to a game that ran out of turns, possibly extending it
+    /// into sudden death (in which case the game is no longer over).
+    fn resolve_time_limit(&mut self) {
+        if self.state.winner.is_some() {
+            return;
+        }
+        self.state.winner = tie_break_winner(&self.state);
+        if self.state.winner.is_some() {
+            debug!("Time limit reached, tie broken: {:?}", self.state.winner);
+            return;
+        }
+
+        let rules = &mut self.state.rules;
+        if rules.sudden_death_turns > 0 && !self.in_sudden_death {
+            self.in_sudden_death = true;
+            rules.max_turns = rules.max_turns.saturating_add(rules.sudden_death_turns);
+            rules.points_to_win = self.state.points.iter().max().unwrap() + 1;
+            // The turn that hit the limit had already started
+            self.state.phase = TurnPhase::Draw;
+            debug!(
+                "Time limit reached, sudden death until turn {} (first to {} points)",
+                rules.max_turns, rules.points_to_win
+            );
+        }
+    }
+
+    /// Plays the next decision. A player who runs out of time (see `enable_clock`) with
+    /// `TimeoutPolicy::Forfeit` loses the game, and their late action is returned without
+    /// being played.
+    ///
+    /// Setup is played one player at a time, but chosen blind: when the first player sets
+    /// up, the second one also chooses their opening board, on the same state (so without
+    /// seeing the first one's), and it is played on the next tick.
     pub fn play_tick(&mut self) -> Action {
         let (actor, actions) = generate_possible_actions(&self.state);
         self.degrees_per_ply.push(actions.len() as u32);
 
+        // Only pay for cloning the legal actions when auditing
+        let audited_actions = self.audit.as_ref().map(|_| actions.clone());
+
         let player = &self.players[actor];
         let color = self.get_color(actor);
         self.print_turn_header(actor, player.as_ref(), &color);
-        let action = if actions.len() == 1 {
+        let setup_choice = self.setup_choices[actor].take();
+        let action = if let Some(action) = setup_choice.filter(|x| actions.contains(x)) {
+            action
+        } else if actions.len() == 1 {
             debug!("Only one possible action, selecting it.");
             actions[0].clone()
         } else {
-            let player = self.players[actor].as_mut();
             trace!(
                 "Possible Actions: {:?}",
                 actions.iter().map(|x| x.action.clone()).collect::<Vec<_>>()
             );
-            player.decision_fn(&mut self.rng, &self.state, actions)
+            match self.timed_decision(actor, actions) {
+                Ok(action) => action,
+                Err(late_action) => {
+                    self.forfeit_on_time(actor);
+                    return late_action;
+                }
+            }
         };
-        let player = &self.players[actor];
-        self.print_action(&action, actor, player.as_ref(), &color);
-        self.apply_action(&action);
-        self.print_state();
+        if let Err(late_action) = self.choose_blind_setup(actor) {
+            return late_action;
+        }
+        self.finish_tick(actor, audited_actions, &action);
+        if self.state.is_game_over() {
+            self.resolve_time_limit();
+        }
         action
     }
 
+    // Asks the player to decide, on the clock if there is one. Err with the late action if
+    // they lost on time.
+    fn timed_decision(&mut self, actor: usize, actions: Vec<Action>) -> Result<Action, Action> {
+        let player = self.players[actor].as_mut();
+        let rng = self.rngs.get(RngStream::Player(actor));
+        let Some(clock) = &mut self.clock else {
+            return Ok(player.decision_fn(rng, &self.state, actions));
+        };
+        let on_timeout = clock.time_control.on_timeout;
+        if on_timeout == TimeoutPolicy::RandomMove && !clock.has_time(actor) {
+            clock.record_skipped(actor);
+            return Ok(random_action(rng, &actions));
+        }
+
+        let start = Instant::now();
+        let action = player.decision_fn(rng, &self.state, actions.clone());
+        if clock.record(actor, start.elapsed()) {
+            return Ok(action);
+        }
+        warn!("Player {actor} ran out of time");
+        match on_timeout {
+            TimeoutPolicy::Forfeit => Err(action),
+            TimeoutPolicy::RandomMove => Ok(random_action(rng, &actions)),
+        }
+    }
+
+    // Has the opponent choose their opening board on the current state, if they set up
+    // after the actor and haven't chosen yet. Err with their late action if they lost on
+    // time.
+    fn choose_blind_setup(&mut self, actor: usize) -> Result<(), Action> {
+        let opponent = (actor + 1) % 2;
+        if !self.waits_on_setup(opponent) || self.is_external(opponent) {
+            return Ok(());
+        }
+        let actions = generate_setup_actions(&self.state, opponent);
+        let action = if actions.len() == 1 {
+            actions[0].clone()
+        } else {
+            self.timed_decision(opponent, actions).inspect_err(|_| {
+                self.forfeit_on_time(opponent);
+            })?
+        };
+        self.setup_choices[opponent] = Some(action);
+        Ok(())
+    }
+
+    // Whether the player has yet to choose their opening board
+    fn waits_on_setup(&self, player: usize) -> bool {
+        self.state.turn_count == 0
+            && self.state.in_play_pokemon[player][0].is_none()
+            && self.setup_choices[player].is_none()
+    }
+
+    fn forfeit_on_time(&mut self, actor: usize) {
+        if let Some(clock) = &mut self.clock {
+            clock.forfeited = Some(actor);
+        }
+        self.state.winner = Some(GameOutcome::Win((actor + 1) % 2));
+        self.state.phase = TurnPhase::GameOver;
+    }
+
+    /// Plays the next tick with an action chosen outside of the game's players (e.g. by a
+    /// remote client), and returns the actions played. Fails, leaving the game untouched, if
+    /// the action isn't one of the `legal_actions`.
+    ///
+    /// Opening boards are chosen blind, as in `poll_decision`: the board of a player from
+    /// outside who sets up first is kept, and nothing is played, until the other player
+    /// chose theirs, then both are played. If a game's own player choosing their board along
+    /// with the action runs out of time, they lose, and this fails with `GameAlreadyOver`
+    /// without playing the action.
+    pub fn play_tick_with(&mut self, action: &Action) -> GameResult<Vec<Action>> {
+        if self.state.is_game_over() {
+            return Err(GameError::GameAlreadyOver);
+        }
+        let (_, actions) = self.legal_actions();
+        if !actions.contains(action) {
+            return Err(GameError::IllegalMove {
+                description: format!("{action:?} is not one of the legal actions"),
+            });
+        }
+        self.commit_choice(action.clone())
+    }
+
+    /// Plays the game until a decision has to come from outside of it: one of an
+    /// `ExternalPlayer` (or of any player, in a game `without_players`) with more than one
+    /// legal action. Other players decide as in `play_tick`. Polling again before
+    /// `submit_choice` returns the same choice.
+    ///
+    /// With a clock (see `enable_clock`), the decision's time runs from the first poll to
+    /// its submission.
+    ///
+    /// Opening boards are chosen blind, as in `play_tick`: a board submitted during setup
+    /// is only played once the other player chose theirs, and players from outside choose
+    /// before the game's own players, so no one sees the other's board before choosing.
+    pub fn poll_decision(&mut self) -> PendingChoice {
+        loop {
+            if self.state.is_game_over() {
+                if let Some(logger) = &mut self.logger {
+                    logger.flush().expect("Failed to write game log");
+                }
+                return PendingChoice::GameOver(self.state.winner);
+            }
+            let (actor, actions) = self.legal_actions();
+            if !self.is_external(actor) {
+                self.play_tick();
+                continue;
+            }
+            // Losing on time while playing these ends the game, reported by the next loop
+            if let Some(action) = self.setup_choices[actor].take() {
+                let _ = self.play_external_tick(&action);
+                continue;
+            }
+            if actions.len() == 1 {
+                debug!("Only one possible action, selecting it.");
+                let _ = self.commit_choice(actions[0].clone());
+                continue;
+            }
+            if let Some(clock) = &mut self.clock {
+                if clock.time_control.on_timeout == TimeoutPolicy::RandomMove
+                    && !clock.has_time(actor)
+                {
+                    clock.record_skipped(actor);
+                    let action = random_action(self.rngs.get(RngStream::Player(actor)), &actions);
+                    let _ = self.commit_choice(action);
+                    continue;
+                }
+            }
+            self.decision_started.get_or_insert_with(Instant::now);
+            return PendingChoice::Decision { actor, actions };
+        }
+    }
+
+    /// Plays the action chosen for the decision `poll_decision` is waiting on. Fails,
+    /// leaving the game untouched, if the game isn't waiting on an outside decision or the
+    /// action isn't legal. A player who ran out of time loses (or has a random action
+    /// played), as in `play_tick`, and the game's own players choosing their opening board
+    /// along with the action can lose on time as in `play_tick_with`.
+    pub fn submit_choice(&mut self, action: Action) -> GameResult<()> {
+        if self.state.is_game_over() {
+            return Err(GameError::GameAlreadyOver);
+        }
+        let (actor, actions) = self.legal_actions();
+        if !self.is_external(actor) {
+            return Err(GameError::IllegalMove {
+                description: format!("Player {actor} takes their own decisions"),
+            });
+        }
+        if self.setup_choices[actor].is_some() {
+            return Err(GameError::IllegalMove {
+                description: format!("Player {actor} already chose their opening board"),
+            });
+        }
+        if !actions.contains(&action) {
+            return Err(GameError::IllegalMove {
+                description: format!("{action:?} is not one of the legal actions"),
+            });
+        }
+        let elapsed = self
+            .decision_started
+            .take()
+            .map_or(Duration::ZERO, |x| x.elapsed());
+        let mut action = action;
+        if let Some(clock) = &mut self.clock {
+            if !clock.record(actor, elapsed) {
+                warn!("Player {actor} ran out of time");
+                match clock.time_control.on_timeout {
+                    TimeoutPolicy::Forfeit => {
+                        self.forfeit_on_time(actor);
+                        return Ok(());
+                    }
+                    TimeoutPolicy::RandomMove => {
+                        action = random_action(self.rngs.get(RngStream::Player(actor)), &actions);
+                    }
+                }
+            }
+        }
+        self.commit_choice(action).map(|_| ())
+    }
+
+    /// The player who decides next and their legal actions (see `play_tick_with`). During
+    /// setup, a player from outside who hasn't chosen their opening board yet decides before
+    /// the other player's board is played.
+    pub fn legal_actions(&self) -> (usize, Vec<Action>) {
+        let (actor, actions) = generate_possible_actions(&self.state);
+        let opponent = (actor + 1) % 2;
+        let actor_chose = self.setup_choices[actor].is_some() || !self.is_external(actor);
+        if actor_chose && self.waits_on_setup(opponent) && self.is_external(opponent) {
+            return (opponent, generate_setup_actions(&self.state, opponent));
+        }
+        (actor, actions)
+    }
+
+    // Plays a legal action from outside, or keeps it for later if it is an opening board
+    // that the other player shouldn't see before choosing theirs, then any kept boards due
+    // to be played. Returns the actions played.
+    fn commit_choice(&mut self, action: Action) -> GameResult<Vec<Action>> {
+        let (next, _) = generate_possible_actions(&self.state);
+        let actor = action.actor;
+        let opponent = (actor + 1) % 2;
+        let opponent_to_choose = self.waits_on_setup(opponent) && self.is_external(opponent);
+        let mut played = vec![];
+        if self.state.turn_count == 0 && (actor != next || opponent_to_choose) {
+            self.setup_choices[actor] = Some(action);
+        } else {
+            self.play_external_tick(&action)?;
+            played.push(action);
+        }
+        while !self.state.is_game_over() {
+            let (next, _) = self.legal_actions();
+            if !self.is_external(next) {
+                break;
+            }
+            let Some(action) = self.setup_choices[next].take() else {
+                break;
+            };
+            self.play_external_tick(&action)?;
+            played.push(action);
+        }
+        Ok(played)
+    }
+
+    // What a player needs to decide, borrowed together
+    #[cfg(feature = "tokio")]
+    pub(crate) fn decision_context(&mut self, actor: usize) -> (&mut rand::rngs::StdRng, &State) {
+        (self.rngs.get(RngStream::Player(actor)), &self.state)
+    }
+
+    fn is_external(&self, actor: usize) -> bool {
+        self.players.get(actor).is_none_or(|x| x.is_external())
+    }
+
+    // Plays a legal action decided outside of `play_tick`. Fails without playing it if the
+    // other player lost on time choosing their opening board along with it.
+    fn play_external_tick(&mut self, action: &Action) -> GameResult<()> {
+        let (actor, actions) = generate_possible_actions(&self.state);
+        assert!(
+            actions.contains(action),
+            "Action should be one of the legal actions"
+        );
+        self.setup_choices[actor] = None;
+        if self.choose_blind_setup(actor).is_err() {
+            return Err(GameError::GameAlreadyOver);
+        }
+        self.degrees_per_ply.push(actions.len() as u32);
+        let audited_actions = self.audit.as_ref().map(|_| actions);
+        self.finish_tick(actor, audited_actions, action);
+        if self.state.is_game_over() {
+            self.resolve_time_limit();
+        }
+        Ok(())
+    }
+
+    // Everything a tick does once the action is chosen
+    fn finish_tick(&mut self, actor: usize, audited_actions: Option<Vec<Action>>, action: &Action) {
+        if let (Some(audit), Some(actions)) = (&mut self.audit, audited_actions) {
+            audit.record(&self.state, &actions, action);
+        }
+        if let Some(player) = self.players.get(actor) {
+            let color = self.get_color(actor);
+            self.print_action(action, actor, player.as_ref(), &color);
+        }
+        self.apply_action(action);
+        for player in &mut self.players {
+            player.observe_action(action);
+        }
+        if let Some(logger) = &mut self.logger {
+            let entry = GameLogEntry::new(
+                self.seed,
+                self.degrees_per_ply.len() as u32 - 1,
+                action,
+                &self.state,
+            );
+            logger.log(&entry).expect("Failed to write game log");
+        }
+        if !self.state.coin_flips.is_empty() {
+            debug!("Coin flips: {:?}", self.state.coin_flips);
+            self.coin_flip_log.push((
+                self.get_num_plys() - 1,
+                actor,
+                self.state.coin_flips.clone(),
+            ));
+        }
+        if !self.state.checkup_events.is_empty() {
+            debug!("Pokemon Checkup: {:?}", self.state.checkup_events);
+        }
+        self.print_state();
+    }
+
+    /// Starts timing the players' decisions, see `GameClock`.
+    pub fn enable_clock(&mut self, time_control: TimeControl) {
+        self.clock = Some(GameClock::new(time_control));
+    }
+
+    /// Time used and left by each player, if a clock was enabled.
+    pub fn get_clock(&self) -> Option<&GameClock> {
+        self.clock.as_ref()
+    }
+
+    /// Starts writing every applied action to `writer` as JSON lines, see `GameLogger`.
+    pub fn enable_logging(&mut self, writer: Box<dyn Write>) {
+        self.logger = Some(GameLogger::new(writer));
+    }
+
+    /// Overrides the coin flip that decides who goes first. Only valid before setup starts.
+    pub fn set_starting_player(&mut self, player: usize) {
+        assert!(
+            self.state.turn_count == 0 && self.degrees_per_ply.is_empty(),
+            "Starting player can only be set before the game starts"
+        );
+        self.state.current_player = player;
+    }
+
+    /// Gives back the players, e.g. to reuse them in the next game of a match.
+    pub fn into_players(self) -> Vec<Box<dyn Player>> {
+        self.players
+    }
+
+    pub fn get_state(&self) -> &State {
+        &self.state
+    }
+
     pub fn get_state_clone(&self) -> State {
         self.state.clone()
     }
 
     /// Get mutable reference to state - USE ONLY FOR TESTING
-    /// WARNING: This breaks encapsulation and should only be used in tests
+    /// WARNING: This breaks encapsulation and should only be used in tests. Since cards may
+    /// be added or removed, they are no longer checked against the decklists.
     pub fn get_state_mut(&mut self) -> &mut State {
+        self.state.decklist_checksums = None;
         &mut self.state
     }
 
     // TODO: Maybe make these only available for testing?
     pub fn apply_action(&mut self, action: &Action) {
-        apply_action(&mut self.rng, &mut self.state, action);
+        // Starting a turn refills the energy zone, apart from the coin flips of the checkup
+        // that ends the previous one
+        match action.action {
+            SimpleAction::EndTurn | SimpleAction::Setup { .. } => {
+                let (coins, energy) = self
+                    .rngs
+                    .get_pair(RngStream::CoinFlips, RngStream::EnergyZone);
+                apply_action_with_outcome_rng(coins, energy, &mut self.state, action);
+            }
+            _ => apply_action(self.rngs.get(RngStream::CoinFlips), &mut self.state, action),
+        }
+        #[cfg(feature = "strict-invariants")]
+        if let Err(error) = self.state.check_invariants() {
+            panic!("{action:?} broke an invariant: {error:?}\n{:?}", self.state);
+        }
     }
 
     pub fn set_state(&mut self, state: State) {
         self.state = state;
+        self.setup_choices = [None, None];
     }
 
     fn print_turn_header(&self, actor: usize, player: &dyn Player, color: &str) {
@@ -137,6 +613,11 @@ impl Game {
         self.degrees_per_ply.len() as u32
     }
 
+    /// Coins flipped so far, as (ply index, actor, flips) for each action that flipped any.
+    pub fn get_coin_flip_log(&self) -> &[(u32, usize, Vec<CoinFlips>)] {
+        &self.coin_flip_log
+    }
+
     pub fn get_degrees_per_ply(&self) -> Vec<u32> {
         self.degrees_per_ply.clone()
     }
@@ -159,32 +640,434 @@ impl Game {
     }
 }
 
+fn random_action(rng: &mut rand::rngs::StdRng, actions: &[Action]) -> Action {
+    actions
+        .choose(rng)
+        .expect("There should be a legal action")
+        .clone()
+}
+
+fn tie_break_winner(state: &State) -> Option<GameOutcome> {
+    let points = state.points;
+    let hp = |player| {
+        state
+            .enumerate_in_play_pokemon(player)
+            .map(|(_, x)| x.remaining_hp)
+            .sum::<u32>()
+    };
+    let scores = match state.rules.tie_break {
+        TieBreak::None => return None,
+        TieBreak::Points => [(points[0], 0), (points[1], 0)],
+        TieBreak::RemainingHp => [(points[0], hp(0)), (points[1], hp(1))],
+    };
+    match scores[0].cmp(&scores[1]) {
+        std::cmp::Ordering::Greater => Some(GameOutcome::Win(0)),
+        std::cmp::Ordering::Less => Some(GameOutcome::Win(1)),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rand::rngs::StdRng;
+
+    use super::{generate_setup_actions, tie_break_winner, PendingChoice};
     use crate::{
-        players::{AttachAttackPlayer, EndTurnPlayer, Player},
+        actions::{Action, SimpleAction},
+        card_ids::CardId,
+        database::get_card_by_enum,
+        game_rules::{GameRules, TieBreak},
+        hooks::to_playable_card,
+        players::{
+            create_players, AttachAttackPlayer, EndTurnPlayer, ExternalPlayer, Player, PlayerCode,
+            RandomPlayer,
+        },
         state::GameOutcome,
-        test_helpers::load_test_decks,
-        Game,
+        test_helpers::{load_test_decks, PokemonSetup, ScenarioBuilder},
+        types::EnergyType,
+        Deck, Game, State,
     };
 
     #[test]
-    fn test_poison() {
+    fn test_seed_determines_game() {
+        let play = |seed| {
+            let (deck_a, deck_b) = load_test_decks();
+            let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+            let mut game = Game::new(players, seed);
+            let mut history = vec![];
+            while !game.get_state().is_game_over() {
+                let action = game.play_tick();
+                history.push((action, game.get_state().current_energy));
+            }
+            history
+        };
+        assert_eq!(play(11), play(11));
+    }
+
+    #[test]
+    fn test_same_seed_same_energy_sequence() {
+        let energy_sequence = |seed| {
+            let (mut deck_a, mut deck_b) = load_test_decks();
+            deck_a.energy_types = vec![EnergyType::Grass, EnergyType::Psychic];
+            deck_b.energy_types = vec![EnergyType::Water, EnergyType::Fire, EnergyType::Lightning];
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(RandomPlayer { deck: deck_a }),
+                Box::new(RandomPlayer { deck: deck_b }),
+            ];
+            let mut game = Game::new(players, seed);
+            let mut energies = vec![];
+            while !game.state.is_game_over() {
+                game.play_tick();
+                energies.push((game.state.turn_count, game.state.current_energy));
+            }
+            energies.dedup();
+            energies
+        };
+
+        let energies = energy_sequence(424242);
+        assert!(energies.len() > 2);
+        assert_eq!(energies, energy_sequence(424242));
+    }
+
+    #[test]
+    fn test_checkup_flips_do_not_shift_energy() {
+        let energy_sequence = |asleep: bool| {
+            let (mut deck_a, mut deck_b) = load_test_decks();
+            deck_a.energy_types = vec![EnergyType::Grass, EnergyType::Psychic];
+            deck_b.energy_types = vec![EnergyType::Water, EnergyType::Fire, EnergyType::Lightning];
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(EndTurnPlayer { deck: deck_a }),
+                Box::new(EndTurnPlayer { deck: deck_b }),
+            ];
+            let mut game = Game::new(players, 5);
+            let mut energies = vec![];
+            while game.state.turn_count < 10 {
+                if asleep && game.state.turn_count > 0 {
+                    game.get_state_mut().get_active_mut(0).asleep = true;
+                }
+                game.play_tick();
+                energies.push((game.state.turn_count, game.state.current_energy));
+            }
+            energies.dedup();
+            (energies, game.get_coin_flip_log().len())
+        };
+
+        let (energies, flips) = energy_sequence(false);
+        let (energies_asleep, flips_asleep) = energy_sequence(true);
+        assert_eq!(flips, 0);
+        assert!(flips_asleep > 0);
+        assert_eq!(energies, energies_asleep);
+    }
+
+    #[test]
+    fn test_external_decisions_play_like_a_player() {
         let (deck_a, deck_b) = load_test_decks();
-        let player_a = Box::new(AttachAttackPlayer { deck: deck_a });
-        let player_b = Box::new(EndTurnPlayer { deck: deck_b });
-        let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+        let mut expected = Game::new(
+            vec![
+                Box::new(EndTurnPlayer {
+                    deck: deck_a.clone(),
+                }),
+                Box::new(AttachAttackPlayer {
+                    deck: deck_b.clone(),
+                }),
+            ],
+            3,
+        );
+        let outcome = expected.play();
+
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(ExternalPlayer { deck: deck_a }),
+            Box::new(AttachAttackPlayer { deck: deck_b }),
+        ];
         let mut game = Game::new(players, 3);
+        let mut decisions = 0;
+        loop {
+            let (actor, actions) = match game.poll_decision() {
+                PendingChoice::Decision { actor, actions } => (actor, actions),
+                PendingChoice::GameOver(winner) => {
+                    assert_eq!(winner, outcome);
+                    break;
+                }
+            };
+            assert_eq!(actor, 0);
+            assert_eq!(
+                game.poll_decision(),
+                PendingChoice::Decision {
+                    actor,
+                    actions: actions.clone()
+                }
+            );
+            // Same choices as EndTurnPlayer
+            let action = actions
+                .iter()
+                .find(|x| x.action == SimpleAction::EndTurn)
+                .unwrap_or(&actions[0])
+                .clone();
+            game.submit_choice(action).unwrap();
+            decisions += 1;
+        }
+        assert!(decisions > 0);
+        assert_eq!(game.get_state(), expected.get_state());
+    }
 
-        // Play initial setup phase
-        while game.get_state_clone().turn_count == 0 {
-            game.play_tick();
+    #[test]
+    fn test_submit_choice_needs_a_pending_decision() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(ExternalPlayer { deck: deck_a }),
+            Box::new(ExternalPlayer { deck: deck_b }),
+        ];
+        let mut game = Game::new(players, 0);
+        let PendingChoice::Decision { actor, .. } = game.poll_decision() else {
+            panic!("Setup should need a decision");
+        };
+        let before = game.get_state_clone();
+        let illegal = Action {
+            actor,
+            action: SimpleAction::Attack(0),
+            is_stack: false,
+        };
+        assert!(game.submit_choice(illegal).is_err());
+        assert_eq!(game.get_state(), &before);
+
+        // Bots take their own decisions
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let mut game = Game::new(players, 0);
+        let (_, actions) = crate::generate_possible_actions(game.get_state());
+        assert!(game.submit_choice(actions[0].clone()).is_err());
+        assert!(matches!(game.poll_decision(), PendingChoice::GameOver(_)));
+    }
+
+    #[test]
+    fn test_setup_is_chosen_blind() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let mut game = Game::new(players, 3);
+        let first = game.state.current_player;
+        let before = game.get_state_clone();
+
+        // The second player chose their board along with the first one's
+        let action = game.play_tick();
+        assert_eq!(action.actor, first);
+        let blind_setup = game.setup_choices[(first + 1) % 2].clone().unwrap();
+        assert_eq!(blind_setup.actor, (first + 1) % 2);
+        assert!(generate_setup_actions(&before, blind_setup.actor).contains(&blind_setup));
+        assert!(game.state.in_play_pokemon[blind_setup.actor][0].is_none());
+
+        assert_eq!(game.play_tick(), blind_setup);
+        assert_eq!(game.setup_choices, [None, None]);
+        assert_eq!(game.state.turn_count, 1);
+        assert_eq!(game.state.current_player, first);
+    }
+
+    // Chooses the biggest opening board, peeking at the whole state to see whether the
+    // opponent's board is already in play
+    #[derive(Debug)]
+    struct CheatingPlayer {
+        deck: Deck,
+        saw_opponent_board: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl Player for CheatingPlayer {
+        fn get_deck(&self) -> Deck {
+            self.deck.clone()
         }
 
-        // Manually poison the opponent's Koffing
-        let mut state = game.get_state_clone();
-        state.in_play_pokemon[1][0].as_mut().unwrap().poisoned = true;
-        game.set_state(state);
+        fn decision_fn(
+            &mut self,
+            _: &mut StdRng,
+            state: &State,
+            possible_actions: Vec<Action>,
+        ) -> Action {
+            let action = possible_actions.last().unwrap().clone();
+            if let SimpleAction::Setup { .. } = action.action {
+                let opponent = (action.actor + 1) % 2;
+                let seen = state.in_play_pokemon[opponent][0].is_some();
+                self.saw_opponent_board.borrow_mut().push(seen);
+            }
+            action
+        }
+    }
+
+    fn cheater(deck: Deck, saw_opponent_board: &Rc<RefCell<Vec<bool>>>) -> Box<dyn Player> {
+        Box::new(CheatingPlayer {
+            deck,
+            saw_opponent_board: saw_opponent_board.clone(),
+        })
+    }
+
+    #[test]
+    fn test_cheaters_cannot_see_opening_boards() {
+        let (deck_a, deck_b) = load_test_decks();
+        let saw_opponent_board = Rc::new(RefCell::new(vec![]));
+        // Seeds with several boards to choose from, going first with either player
+        for seed in [3, 4, 5, 10] {
+            let players = vec![
+                cheater(deck_a.clone(), &saw_opponent_board),
+                cheater(deck_b.clone(), &saw_opponent_board),
+            ];
+            let mut game = Game::new(players, seed);
+            while game.state.turn_count == 0 {
+                game.play_tick();
+            }
+        }
+        let saw_opponent_board = saw_opponent_board.borrow();
+        assert_eq!(saw_opponent_board.len(), 6);
+        assert!(!saw_opponent_board.contains(&true));
+    }
+
+    #[test]
+    fn test_external_players_cannot_see_opening_boards() {
+        let (deck_a, deck_b) = load_test_decks();
+        let saw_opponent_board = Rc::new(RefCell::new(vec![]));
+        let mut external_setups = 0;
+        // Both seats, so setting up first or second
+        for (seed, external) in [(3, 0), (3, 1), (4, 0), (4, 1)] {
+            let mut players = vec![
+                cheater(deck_a.clone(), &saw_opponent_board),
+                cheater(deck_b.clone(), &saw_opponent_board),
+            ];
+            players[external] = Box::new(ExternalPlayer {
+                deck: players[external].get_deck(),
+            });
+            let mut game = Game::new(players, seed);
+            while game.state.turn_count == 0 {
+                let PendingChoice::Decision { actor, actions } = game.poll_decision() else {
+                    panic!("Game should not be over during setup");
+                };
+                if game.state.turn_count > 0 {
+                    break;
+                }
+                assert_eq!(actor, external);
+                assert!(game.get_state().in_play_pokemon[(actor + 1) % 2][0].is_none());
+                game.submit_choice(actions.last().unwrap().clone()).unwrap();
+                external_setups += 1;
+            }
+        }
+        assert_eq!(external_setups, 4);
+        assert!(!saw_opponent_board.borrow().contains(&true));
+
+        // With only outside players, the first board waits for the second one
+        let mut game = Game::without_players(&deck_a, &deck_b, 3, GameRules::default());
+        let PendingChoice::Decision { actor, actions } = game.poll_decision() else {
+            panic!("Game should not be over during setup");
+        };
+        game.submit_choice(actions[0].clone()).unwrap();
+        assert!(game.submit_choice(actions[0].clone()).is_err());
+        let PendingChoice::Decision { actor: second, .. } = game.poll_decision() else {
+            panic!("Game should not be over during setup");
+        };
+        assert_eq!(second, (actor + 1) % 2);
+        assert!(game.get_state().in_play_pokemon[actor][0].is_none());
+    }
+
+    #[test]
+    fn test_boards_played_from_outside_stay_hidden() {
+        let (deck_a, deck_b) = load_test_decks();
+        let saw_opponent_board = Rc::new(RefCell::new(vec![]));
+        let mut outside_setups = 0;
+        // Both seats, so setting up first or second
+        for (seed, external) in [(3, 0), (3, 1), (4, 0), (4, 1)] {
+            let mut players = vec![
+                cheater(deck_a.clone(), &saw_opponent_board),
+                cheater(deck_b.clone(), &saw_opponent_board),
+            ];
+            players[external] = Box::new(ExternalPlayer {
+                deck: players[external].get_deck(),
+            });
+            let mut game = Game::new(players, seed);
+            let mut chose = false;
+            while game.state.turn_count == 0 {
+                let (actor, actions) = game.legal_actions();
+                // A kept board is played by the tick of whoever is next
+                if actor != external || chose {
+                    game.play_tick();
+                    continue;
+                }
+                assert!(game.get_state().in_play_pokemon[(actor + 1) % 2][0].is_none());
+                game.play_tick_with(actions.last().unwrap()).unwrap();
+                chose = true;
+                outside_setups += 1;
+            }
+        }
+        assert_eq!(outside_setups, 4);
+        assert!(!saw_opponent_board.borrow().contains(&true));
+
+        // With only outside players, the first board waits for the second one
+        let mut game = Game::without_players(&deck_a, &deck_b, 3, GameRules::default());
+        let (first, actions) = game.legal_actions();
+        let first_board = actions[0].clone();
+        assert_eq!(game.play_tick_with(&first_board), Ok(vec![]));
+        assert!(game.play_tick_with(&first_board).is_err());
+        let (second, actions) = game.legal_actions();
+        assert_eq!(second, (first + 1) % 2);
+        assert!(game.get_state().in_play_pokemon[first][0].is_none());
+        let second_board = actions[0].clone();
+        let played = game.play_tick_with(&second_board).unwrap();
+        assert_eq!(played, vec![first_board, second_board]);
+        assert_eq!(game.get_state().turn_count, 1);
+    }
+
+    #[test]
+    fn test_tie_break_winner() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut damaged = to_playable_card(&bulbasaur, false);
+        damaged.apply_damage(30);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[1][0] = Some(damaged);
+        state.points = [1, 1];
+
+        assert_eq!(tie_break_winner(&state), None);
+        state.rules.tie_break = TieBreak::Points;
+        assert_eq!(tie_break_winner(&state), None);
+        state.rules.tie_break = TieBreak::RemainingHp;
+        assert_eq!(tie_break_winner(&state), Some(GameOutcome::Win(0)));
+
+        // Points come before HP
+        state.points = [1, 2];
+        assert_eq!(tie_break_winner(&state), Some(GameOutcome::Win(1)));
+        state.rules.tie_break = TieBreak::Points;
+        assert_eq!(tie_break_winner(&state), Some(GameOutcome::Win(1)));
+    }
+
+    #[test]
+    fn test_sudden_death_extends_game() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(EndTurnPlayer { deck: deck_a }),
+            Box::new(EndTurnPlayer { deck: deck_b }),
+        ];
+        let rules = GameRules {
+            max_turns: 4,
+            sudden_death_turns: 2,
+            ..Default::default()
+        };
+        let mut game = Game::with_rules(players, 0, rules);
+        assert_eq!(game.play(), None);
+        let state = game.get_state();
+        assert_eq!(state.turn_count, 6);
+        assert_eq!(state.rules.points_to_win, 1);
+    }
+
+    #[test]
+    fn test_poison() {
+        let (deck_a, deck_b) = load_test_decks();
+        let player_a = Box::new(AttachAttackPlayer { deck: deck_a });
+        let player_b = Box::new(EndTurnPlayer { deck: deck_b });
+        let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+        // The opponent's Koffing is poisoned, with no bench to fall back on
+        let state = ScenarioBuilder::new()
+            .active(0, CardId::A1001Bulbasaur, [])
+            .active(1, CardId::A1176Koffing, [PokemonSetup::Poisoned])
+            .turn(1)
+            .current_player(0)
+            .build();
+        let mut game = Game::from_state(state, players, 0);
 
         // The game starts with AA playing. After each turn 10 damage should be subtracted.
         // So ending 1 Koffing should have 60HP, 2 => 50HP, 3 => 40HP, 4 => 30HP, 5 => 20HP
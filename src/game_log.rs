@@ -0,0 +1,125 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::{
+    actions::{Action, CheckupEvent},
+    State,
+};
+
+/// One applied action and where it left the game, as written to a JSONL game log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameLogEntry {
+    /// Seed of the game, to tell games apart when several share a log.
+    pub seed: u64,
+    pub ply: u32,
+    pub turn: u8,
+    pub actor: usize,
+    pub action: String,
+    pub points: [u8; 2],
+    /// Total remaining HP of each player's Pokémon in play.
+    pub hp: [u32; 2],
+    /// Number of cards in each player's hand.
+    pub hand_sizes: [usize; 2],
+    /// What the Pokémon Checkup did, for actions that ended a turn.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checkup: Vec<CheckupEvent>,
+}
+
+impl GameLogEntry {
+    /// The entry for `action` (the `ply`-th of the game), given the state after applying it.
+    pub fn new(seed: u64, ply: u32, action: &Action, state: &State) -> Self {
+        let hp = |player| {
+            state
+                .enumerate_in_play_pokemon(player)
+                .map(|(_, x)| x.remaining_hp)
+                .sum()
+        };
+        Self {
+            seed,
+            ply,
+            turn: state.turn_count,
+            actor: action.actor,
+            action: action.action.to_string(),
+            points: state.points,
+            hp: [hp(0), hp(1)],
+            hand_sizes: [state.hands[0].len(), state.hands[1].len()],
+            checkup: state.checkup_events.clone(),
+        }
+    }
+}
+
+/// Writes one JSON object per line for every action of a game (see `Game::enable_logging`),
+/// so that simulations can be loaded with e.g. `pandas.read_json(path, lines=True)`.
+pub struct GameLogger {
+    writer: Box<dyn Write>,
+}
+
+impl GameLogger {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self { writer }
+    }
+
+    pub fn log(&mut self, entry: &GameLogEntry) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, entry)?;
+        self.writer.write_all(b"\n")
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::{
+        players::{AttachAttackPlayer, EndTurnPlayer, Player},
+        test_helpers::load_test_decks,
+        Game,
+    };
+
+    // Writer whose contents can still be read after handing it to the game
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_one_line_per_action() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(AttachAttackPlayer { deck: deck_a }),
+            Box::new(EndTurnPlayer { deck: deck_b }),
+        ];
+        let mut game = Game::new(players, 3);
+        let buffer = SharedBuffer::default();
+        game.enable_logging(Box::new(buffer.clone()));
+        game.play();
+
+        let text = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|x| serde_json::from_str(x).unwrap())
+            .collect();
+        assert_eq!(lines.len() as u32, game.get_num_plys());
+        assert_eq!(lines[0]["seed"], 3);
+        assert_eq!(lines[0]["ply"], 0);
+        let last = lines.last().unwrap();
+        let state = game.get_state();
+        assert_eq!(last["turn"], state.turn_count);
+        assert_eq!(last["points"][0], state.points[0]);
+        assert_eq!(last["points"][1], state.points[1]);
+        assert_eq!(last["hand_sizes"][1], state.hands[1].len());
+    }
+}
@@ -0,0 +1,232 @@
+use serde::Serialize;
+
+/// Rules that vary between formats or that we want to toggle when experimenting.
+/// Defaults match the official TCG Pocket rules.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
+pub struct GameRules {
+    /// Points needed to win the game.
+    pub points_to_win: u8,
+    /// The game ends (with no winner) once the global turn count reaches this.
+    pub max_turns: u8,
+    /// Number of Bench slots, at most `MAX_BENCH_SIZE` (the size of `State.in_play_pokemon`
+    /// beyond the Active Spot).
+    pub bench_size: usize,
+    /// Whether the player going first gets energy from the Energy Zone on turn 1.
+    pub first_turn_energy: bool,
+    /// Whether the player going first can attack on turn 1.
+    pub first_turn_attack: bool,
+    /// Maximum cards in hand (10 in TCG Pocket). Draws are skipped while the hand is full,
+    /// leaving the card in the deck.
+    pub max_hand_size: Option<usize>,
+    /// How `Game::play` decides a game that reaches `max_turns` without a winner.
+    pub tie_break: TieBreak,
+    /// Extra turns to play if the game is still undecided after `tie_break`. During these
+    /// turns the next point scored by the leader (or either player, if tied) wins.
+    pub sudden_death_turns: u8,
+}
+
+/// Ways to pick a winner for a game that ran out of turns.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, Serialize)]
+pub enum TieBreak {
+    /// Nobody wins.
+    #[default]
+    None,
+    /// The player with more points wins.
+    Points,
+    /// Like `Points`, but equal points go to the player with more remaining HP in play.
+    RemainingHp,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            points_to_win: 3,
+            max_turns: 100,
+            bench_size: 3,
+            first_turn_energy: false,
+            first_turn_attack: false,
+            max_hand_size: Some(10),
+            tie_break: TieBreak::None,
+            sudden_death_turns: 0,
+        }
+    }
+}
+
+impl GameRules {
+    /// Whether the current player can attach energy from the Energy Zone this turn.
+    pub(crate) fn can_attach_turn_energy(&self, turn_count: u8) -> bool {
+        turn_count > 1 || (turn_count == 1 && self.first_turn_energy)
+    }
+
+    /// Whether the current player can attack this turn.
+    pub(crate) fn can_attack(&self, turn_count: u8) -> bool {
+        turn_count > 1 || (turn_count == 1 && self.first_turn_attack)
+    }
+
+    /// Whether a Pokémon can be placed in the given in-play slot (0 is the Active Spot).
+    pub(crate) fn is_usable_slot(&self, index: usize) -> bool {
+        index <= self.bench_size
+    }
+
+    pub(crate) fn can_draw(&self, hand_size: usize) -> bool {
+        self.max_hand_size.is_none_or(|max| hand_size < max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::SimpleAction,
+        card_ids::CardId,
+        database::get_card_by_enum,
+        generate_possible_actions,
+        hooks::to_playable_card,
+        players::{Player, RandomPlayer},
+        state::MAX_BENCH_SIZE,
+        test_helpers::load_test_decks,
+        types::EnergyType,
+        Game, State,
+    };
+
+    fn first_turn_state(rules: GameRules) -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.rules = rules;
+        state.turn_count = 1;
+        state.current_energy = Some(EnergyType::Grass);
+        let mut bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        bulbasaur.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        state.in_play_pokemon[0][0] = Some(bulbasaur);
+        state
+    }
+
+    fn simple_actions(state: &State) -> Vec<SimpleAction> {
+        let (_, actions) = generate_possible_actions(state);
+        actions.into_iter().map(|x| x.action).collect()
+    }
+
+    #[test]
+    fn test_first_player_cannot_attach_or_attack_on_turn_one() {
+        let actions = simple_actions(&first_turn_state(GameRules::default()));
+        assert!(!actions
+            .iter()
+            .any(|x| matches!(x, SimpleAction::Attach { .. } | SimpleAction::Attack(_))));
+
+        let mut state = first_turn_state(GameRules::default());
+        state.turn_count = 2;
+        let actions = simple_actions(&state);
+        assert!(actions.contains(&SimpleAction::Attack(0)));
+        assert!(actions
+            .iter()
+            .any(|x| matches!(x, SimpleAction::Attach { .. })));
+    }
+
+    #[test]
+    fn test_relaxed_first_turn_rules() {
+        let rules = GameRules {
+            first_turn_energy: true,
+            first_turn_attack: true,
+            ..Default::default()
+        };
+        let actions = simple_actions(&first_turn_state(rules));
+        assert!(actions.contains(&SimpleAction::Attack(0)));
+        assert!(actions
+            .iter()
+            .any(|x| matches!(x, SimpleAction::Attach { .. })));
+    }
+
+    #[test]
+    fn test_bench_size_limits_place_actions() {
+        let mut state = first_turn_state(GameRules {
+            bench_size: 1,
+            ..Default::default()
+        });
+        state.hands[0] = vec![get_card_by_enum(CardId::A1001Bulbasaur)];
+        let actions = simple_actions(&state);
+        let places: Vec<_> = actions
+            .iter()
+            .filter(|x| matches!(x, SimpleAction::Place(_, _)))
+            .collect();
+        assert_eq!(places.len(), 1);
+        assert!(matches!(places[0], SimpleAction::Place(_, 1)));
+    }
+
+    #[test]
+    fn test_larger_benches() {
+        let mut state = first_turn_state(GameRules {
+            bench_size: MAX_BENCH_SIZE,
+            ..Default::default()
+        });
+        state.hands[0] = vec![get_card_by_enum(CardId::A1001Bulbasaur)];
+        let places = simple_actions(&state)
+            .iter()
+            .filter(|x| matches!(x, SimpleAction::Place(_, _)))
+            .count();
+        assert_eq!(places, MAX_BENCH_SIZE);
+
+        // Games play out the same way with the extra slots
+        for seed in 0..10 {
+            let (deck_a, deck_b) = load_test_decks();
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(RandomPlayer { deck: deck_a }),
+                Box::new(RandomPlayer { deck: deck_b }),
+            ];
+            let rules = GameRules {
+                bench_size: MAX_BENCH_SIZE,
+                ..Default::default()
+            };
+            let mut game = Game::with_rules(players, seed, rules);
+            game.play();
+            assert_eq!(game.get_state().check_invariants(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_max_turns_and_hand_size() {
+        let mut state = first_turn_state(GameRules {
+            max_turns: 10,
+            max_hand_size: Some(1),
+            ..Default::default()
+        });
+        state.turn_count = 10;
+        assert!(state.is_game_over());
+
+        state.maybe_draw_card(0);
+        state.maybe_draw_card(0);
+        assert_eq!(state.hands[0].len(), 1);
+    }
+
+    #[test]
+    fn test_full_hands_leave_draws_in_the_deck() {
+        let mut state = first_turn_state(GameRules::default());
+        state.hands[0] = state.deck_mut(0).cards.drain(..10).collect();
+        state.maybe_draw_card(0);
+        assert_eq!(state.hands[0].len(), 10);
+        assert_eq!(state.decks[0].cards.len(), 10);
+
+        state.hands[0].pop();
+        state.maybe_draw_card(0);
+        assert_eq!(state.hands[0].len(), 10);
+        assert_eq!(state.decks[0].cards.len(), 9);
+    }
+
+    #[test]
+    fn test_game_with_rules() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer { deck: deck_a }),
+            Box::new(RandomPlayer { deck: deck_b }),
+        ];
+        let rules = GameRules {
+            points_to_win: 1,
+            ..Default::default()
+        };
+        let mut game = Game::with_rules(players, 5, rules);
+        game.play();
+        let state = game.get_state_clone();
+        assert_eq!(state.rules, rules);
+        assert!(state.winner.is_some());
+        assert!(state.points.iter().all(|x| *x <= 2));
+    }
+}
@@ -0,0 +1,141 @@
+use super::proto;
+use crate::{
+    actions::Action,
+    state::GameOutcome,
+    types::{Card, EnergyType, PlayedCard},
+    State,
+};
+
+fn card_ids(cards: &[Card]) -> Vec<String> {
+    cards.iter().map(|x| x.get_id()).collect()
+}
+
+impl From<EnergyType> for proto::EnergyType {
+    fn from(energy: EnergyType) -> Self {
+        match energy {
+            EnergyType::Grass => proto::EnergyType::Grass,
+            EnergyType::Fire => proto::EnergyType::Fire,
+            EnergyType::Water => proto::EnergyType::Water,
+            EnergyType::Lightning => proto::EnergyType::Lightning,
+            EnergyType::Psychic => proto::EnergyType::Psychic,
+            EnergyType::Fighting => proto::EnergyType::Fighting,
+            EnergyType::Darkness => proto::EnergyType::Darkness,
+            EnergyType::Metal => proto::EnergyType::Metal,
+            EnergyType::Dragon => proto::EnergyType::Dragon,
+            EnergyType::Colorless => proto::EnergyType::Colorless,
+        }
+    }
+}
+
+impl From<&PlayedCard> for proto::PlayedCard {
+    fn from(card: &PlayedCard) -> Self {
+        proto::PlayedCard {
+            card_id: card.card.get_id(),
+            remaining_hp: card.remaining_hp,
+            total_hp: card.total_hp,
+            attached_energy: card
+                .attached_energy
+                .iter()
+                .map(|x| proto::EnergyType::from(*x) as i32)
+                .collect(),
+            attached_tool: card.attached_tool.map(|x| format!("{x:?}")),
+            played_this_turn: card.played_this_turn,
+            ability_used: card.ability_used,
+            poisoned: card.poisoned,
+            paralyzed: card.paralyzed,
+            asleep: card.asleep,
+            burned: card.burned,
+            confused: card.confused,
+            cards_behind: card_ids(&card.cards_behind),
+        }
+    }
+}
+
+impl From<GameOutcome> for proto::GameOutcome {
+    fn from(outcome: GameOutcome) -> Self {
+        let outcome = match outcome {
+            GameOutcome::Win(winner) => proto::game_outcome::Outcome::Winner(winner as u32),
+            GameOutcome::Tie => proto::game_outcome::Outcome::Tie(true),
+        };
+        proto::GameOutcome {
+            outcome: Some(outcome),
+        }
+    }
+}
+
+impl From<&State> for proto::State {
+    fn from(state: &State) -> Self {
+        let players = (0..2)
+            .map(|player| proto::PlayerState {
+                points: state.points[player] as u32,
+                hand: card_ids(&state.hands[player]),
+                deck: card_ids(&state.decks[player].cards),
+                discard_pile: card_ids(&state.discard_piles[player]),
+                discarded_energy: state.discarded_energy[player]
+                    .iter()
+                    .map(|(energy, amount)| (format!("{energy:?}"), *amount))
+                    .collect(),
+                in_play: state.in_play_pokemon[player]
+                    .iter()
+                    .map(|x| proto::InPlaySlot {
+                        pokemon: x.as_ref().map(proto::PlayedCard::from),
+                    })
+                    .collect(),
+            })
+            .collect();
+        proto::State {
+            turn_count: state.turn_count as u32,
+            current_player: state.current_player as u32,
+            current_energy: state
+                .current_energy
+                .map_or(proto::EnergyType::Unspecified, proto::EnergyType::from)
+                as i32,
+            players,
+            winner: state.winner.map(proto::GameOutcome::from),
+            has_played_support: state.has_played_support,
+            has_retreated: state.has_retreated,
+        }
+    }
+}
+
+impl From<&Action> for proto::Action {
+    fn from(action: &Action) -> Self {
+        proto::Action {
+            actor: action.actor as u32,
+            description: action.action.to_string(),
+            is_stack: action.is_stack,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::{actions::SimpleAction, state::MAX_IN_PLAY, test_helpers::load_test_decks};
+
+    #[test]
+    fn test_state_round_trips_through_protobuf() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.current_energy = Some(EnergyType::Fire);
+        let proto_state = proto::State::from(&state);
+        assert_eq!(proto_state.players.len(), 2);
+        assert_eq!(proto_state.players[0].in_play.len(), MAX_IN_PLAY);
+        assert_eq!(proto_state.current_energy(), proto::EnergyType::Fire);
+
+        let bytes = proto_state.encode_to_vec();
+        assert_eq!(proto::State::decode(&bytes[..]).unwrap(), proto_state);
+    }
+
+    #[test]
+    fn test_action_keeps_its_description() {
+        let action = Action {
+            actor: 1,
+            action: SimpleAction::EndTurn,
+            is_stack: false,
+        };
+        assert_eq!(proto::Action::from(&action).description, "EndTurn");
+    }
+}
@@ -0,0 +1,122 @@
+mod convert;
+
+use std::net::SocketAddr;
+
+use tonic::{transport::Server, Request, Response, Status};
+
+use crate::{
+    players::{create_players, fill_code_array, parse_player_code},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// Types generated from `proto/deckgym.proto`.
+pub mod proto {
+    tonic::include_proto!("deckgym");
+}
+
+use proto::{
+    simulation_service_server::{SimulationService, SimulationServiceServer},
+    SimulationRequest, SimulationResult,
+};
+
+/// Plays the games asked for by `SimulationService` clients. `State` and `Action` convert
+/// to their `proto` counterparts.
+#[derive(Debug, Default)]
+pub struct Simulator;
+
+#[tonic::async_trait]
+impl SimulationService for Simulator {
+    async fn simulate(
+        &self,
+        request: Request<SimulationRequest>,
+    ) -> Result<Response<SimulationResult>, Status> {
+        let request = request.into_inner();
+        // Games are CPU bound, so keep them off the async workers
+        let result = tokio::task::spawn_blocking(move || run_simulation(&request))
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        result.map(Response::new).map_err(Status::invalid_argument)
+    }
+}
+
+/// Plays the games of a simulation request, or explains what is wrong with it.
+pub fn run_simulation(request: &SimulationRequest) -> Result<SimulationResult, String> {
+    let deck_a = Deck::from_string(&request.deck_a)?;
+    let deck_b = Deck::from_string(&request.deck_b)?;
+    if request.players.len() > 2 {
+        return Err(format!(
+            "Expected up to 2 players, got {}",
+            request.players.len()
+        ));
+    }
+    let players = request
+        .players
+        .iter()
+        .map(|x| parse_player_code(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    let players = fill_code_array((!players.is_empty()).then_some(players));
+    let first_seed = request.seed.unwrap_or_else(rand::random);
+
+    let mut result = SimulationResult {
+        games: request.num_games,
+        wins: vec![0, 0],
+        ..Default::default()
+    };
+    let mut total_turns = 0;
+    for i in 0..request.num_games {
+        let players = create_players(deck_a.clone(), deck_b.clone(), players.clone());
+        let mut game = Game::new(players, first_seed.wrapping_add(i as u64));
+        match game.play() {
+            Some(GameOutcome::Win(winner)) => result.wins[winner] += 1,
+            Some(GameOutcome::Tie) | None => result.ties += 1,
+        }
+        total_turns += game.get_state().turn_count as u32;
+    }
+    if request.num_games > 0 {
+        result.average_turns = total_turns as f64 / request.num_games as f64;
+    }
+    Ok(result)
+}
+
+/// Serves `SimulationService` on `addr` until it fails.
+pub async fn serve(addr: SocketAddr) -> Result<(), tonic::transport::Error> {
+    Server::builder()
+        .add_service(SimulationServiceServer::new(Simulator))
+        .serve(addr)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn request(num_games: u32) -> SimulationRequest {
+        let deck = fs::read_to_string("example_decks/venusaur-exeggutor.txt").unwrap();
+        SimulationRequest {
+            deck_a: deck.clone(),
+            deck_b: deck,
+            players: vec!["aa".to_string()],
+            num_games,
+            seed: Some(3),
+        }
+    }
+
+    #[test]
+    fn test_run_simulation_accounts_for_every_game() {
+        let result = run_simulation(&request(5)).unwrap();
+        assert_eq!(result.games, 5);
+        assert_eq!(result.wins.iter().sum::<u32>() + result.ties, 5);
+        assert!(result.average_turns > 0.0);
+        assert_eq!(result, run_simulation(&request(5)).unwrap());
+    }
+
+    #[test]
+    fn test_run_simulation_rejects_unknown_players() {
+        let mut request = request(1);
+        request.players = vec!["nobody".to_string()];
+        assert!(run_simulation(&request).is_err());
+    }
+}
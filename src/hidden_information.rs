@@ -48,6 +48,21 @@ impl HiddenKnowledge {
         knowledge
     }
     
+    /// What the players know at this point of the game, assuming decklists are public:
+    /// the cards of each player not seen yet (in hand or deck) and the hand sizes.
+    pub fn from_state(state: &State) -> Self {
+        let mut knowledge = Self::default();
+        for player in 0..2 {
+            let unseen = state.hands[player].iter().chain(&state.decks[player].cards);
+            let known_contents = &mut knowledge.known_deck_contents[(player + 1) % 2];
+            for card in unseen {
+                *known_contents.entry(card.clone()).or_insert(0) += 1;
+            }
+            knowledge.known_hand_sizes[player] = state.hands[player].len();
+        }
+        knowledge
+    }
+
     /// Update knowledge when a card is played from hand
     pub fn card_played_from_hand(&mut self, player: usize, card: &Card) {
         let opponent = (player + 1) % 2;
@@ -217,11 +232,13 @@ pub fn resolve_hidden_outcome(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sets::SetId;
+    use crate::test_helpers::{to_pokemon_card, to_trainer_card};
     use crate::types::{PokemonCard, TrainerCard, TrainerType};
     
     #[test]
     fn test_hidden_knowledge_tracking() {
-        let card1 = Card::Pokemon(PokemonCard {
+        let card1 = to_pokemon_card(PokemonCard {
             id: "1".to_string(),
             name: "Pikachu".to_string(),
             hp: 60,
@@ -233,17 +250,17 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
-        let card2 = Card::Trainer(TrainerCard {
+        let card2 = to_trainer_card(TrainerCard {
             id: "2".to_string(),
             numeric_id: 2,
             name: "Potion".to_string(),
             trainer_card_type: TrainerType::Item,
             effect: "Heal 20 damage".to_string(),
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         let deck1 = vec![card1.clone(); 10];
@@ -264,7 +281,7 @@ mod tests {
     
     #[test]
     fn test_deck_probabilities() {
-        let card1 = Card::Pokemon(PokemonCard {
+        let card1 = to_pokemon_card(PokemonCard {
             id: "1".to_string(),
             name: "Pikachu".to_string(),
             hp: 60,
@@ -276,10 +293,10 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
-        let card2 = Card::Pokemon(PokemonCard {
+        let card2 = to_pokemon_card(PokemonCard {
             id: "2".to_string(),
             name: "Raichu".to_string(),
             hp: 90,
@@ -291,7 +308,7 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         let mut knowledge = HiddenKnowledge::default();
@@ -4,24 +4,27 @@ use std::vec;
 use log::debug;
 
 use crate::{
+    ability_ids::AbilityId,
+    attack_effects::AttackEffect,
     card_ids::CardId,
     tool_ids::ToolId,
-    types::{Card, EnergyType, PlayedCard},
+    types::{Attack, Card, EnergyType, PlayedCard, FOSSIL_HP},
     State,
 };
 
-const PLAYABLE_TRAINER_CARD_NAMES: [&str; 3] = ["Helix Fossil", "Dome Fossil", "Old Amber"];
+/// HP a card has when put into play, or None if the card can't be put into play.
+pub(crate) fn get_base_hp(card: &Card) -> Option<u32> {
+    match card {
+        Card::Pokemon(pokemon_card) => Some(pokemon_card.hp),
+        Card::Trainer(_) if card.is_fossil() => Some(FOSSIL_HP),
+        Card::Trainer(_) => None,
+    }
+}
 
 pub(crate) fn to_playable_card(card: &crate::types::Card, played_this_turn: bool) -> PlayedCard {
-    let total_hp = match card {
-        Card::Pokemon(pokemon_card) => pokemon_card.hp,
-        Card::Trainer(trainer_card) => {
-            if PLAYABLE_TRAINER_CARD_NAMES.contains(&trainer_card.name.as_str()) {
-                40
-            } else {
-                panic!("Unplayable Trainer Card: {:?}", trainer_card);
-            }
-        }
+    let total_hp = match get_base_hp(card) {
+        Some(hp) => hp,
+        None => panic!("Unplayable Trainer Card: {:?}", card),
     };
     PlayedCard {
         card: card.clone(),
@@ -34,26 +37,45 @@ pub(crate) fn to_playable_card(card: &crate::types::Card, played_this_turn: bool
         poisoned: false,
         paralyzed: false,
         asleep: false,
+        burned: false,
+        confused: false,
         cards_behind: vec![],
+        effects: vec![],
     }
 }
 
-pub(crate) fn on_attach_tool(state: &mut State, actor: usize, in_play_idx: usize, tool_id: ToolId) {
+/// What `old_pokemon` becomes when evolving into `card`. It keeps its attachments and
+/// its damage (not its HP), so a 70 HP Pokémon with 30 damage evolves into a 130 HP one
+/// with 100 HP left. Special conditions and effects are gone.
+pub(crate) fn to_evolved_card(old_pokemon: &PlayedCard, card: &Card) -> PlayedCard {
+    let mut played_card = to_playable_card(card, true);
+    played_card.attached_energy = old_pokemon.attached_energy.clone();
+    played_card.attached_tool = old_pokemon.attached_tool;
+    played_card.total_hp += old_pokemon.attached_tool.map_or(0, tool_hp_bonus);
+    played_card.remaining_hp = played_card
+        .total_hp
+        .saturating_sub(old_pokemon.damage_taken());
+    played_card.cards_behind = old_pokemon.cards_behind.clone();
+    played_card.cards_behind.push(old_pokemon.card.clone());
+    played_card
+}
+
+fn tool_hp_bonus(tool_id: ToolId) -> u32 {
     match tool_id {
-        ToolId::A2147GiantCape => {
-            // Add +20 to remaining_hp and total_hp
-            let card = state.in_play_pokemon[actor][in_play_idx]
-                .as_mut()
-                .expect("Active Pokemon should be there");
-            card.remaining_hp += 20;
-            card.total_hp += 20;
-        }
-        // Many tools do nothing on attach
-        ToolId::A2148RockyHelmet => {}
+        ToolId::A2147GiantCape => 20,
+        ToolId::A2148RockyHelmet | ToolId::A2149LumBerry => 0,
     }
 }
 
-// TODO: Implement Gengars ability that disallow playing support cards.
+pub(crate) fn on_attach_tool(state: &mut State, actor: usize, in_play_idx: usize, tool_id: ToolId) {
+    // Tools raising the max HP raise the HP left as well, keeping the damage taken
+    let card = state.in_play_pokemon[actor][in_play_idx]
+        .as_mut()
+        .expect("Pokemon should be there if attaching a tool to it");
+    card.remaining_hp += tool_hp_bonus(tool_id);
+    card.total_hp += tool_hp_bonus(tool_id);
+}
+
 pub(crate) fn can_play_support(state: &State) -> bool {
     let psyduck_headache = state
         .get_current_turn_effects()
@@ -62,14 +84,66 @@ pub(crate) fn can_play_support(state: &State) -> bool {
     !state.has_played_support && !psyduck_headache
 }
 
+/// Whether the player can evolve the Pokémon at `position` into `card` right now. Evolutions
+/// have to match the Pokémon they evolve from, and can't be played during each player's
+/// first turn nor on a Pokémon that was put into play (or evolved) this turn.
+pub(crate) fn can_evolve(state: &State, player: usize, card: &Card, position: usize) -> bool {
+    let Card::Pokemon(pokemon_card) = card else {
+        return false;
+    };
+    let (Some(evolves_from), Some(pokemon)) = (
+        &pokemon_card.evolves_from,
+        &state.in_play_pokemon[player][position],
+    ) else {
+        return false;
+    };
+    state.turn_count > 2 && !pokemon.played_this_turn && pokemon.get_name() == *evolves_from
+}
+
+/// Whether the player can use the Ability of their Pokémon at `in_play_idx` right now, as
+/// told by its `AbilityUsage`.
+pub(crate) fn can_use_ability(state: &State, player: usize, in_play_idx: usize) -> bool {
+    let Some(pokemon) = &state.in_play_pokemon[player][in_play_idx] else {
+        return false;
+    };
+    let Some(usage) = AbilityId::from_pokemon_id(&pokemon.get_id()).and_then(|x| x.usage()) else {
+        return false;
+    };
+    !pokemon.ability_used && usage.positions.contains(in_play_idx)
+}
+
+/// Whether the Pokémon can use its attack at `index`, unless the attack it used during the
+/// player's last turn forbids it (see `AttackEffect::CantAttackNextTurn` and
+/// `AttackEffect::CantUseAgainNextTurn`). Those attacks leave an effect with their index on
+/// the Pokémon, which lasts until the end of the player's next turn.
+pub(crate) fn can_use_attack(pokemon: &PlayedCard, index: usize) -> bool {
+    let attacks = pokemon.get_attacks();
+    let has_effect = |i: usize, effect: &AttackEffect| {
+        attacks.get(i).is_some_and(|attack| {
+            AttackEffect::from_attack(&pokemon.get_id(), i, attack)
+                .is_some_and(|x| x.contains(effect))
+        })
+    };
+    !pokemon
+        .effects
+        .iter()
+        .filter(|x| x.card == pokemon.card)
+        .filter_map(|x| x.attack_index)
+        .any(|used| {
+            has_effect(used, &AttackEffect::CantAttackNextTurn)
+                || (used == index && has_effect(used, &AttackEffect::CantUseAgainNextTurn))
+        })
+}
+
+/// Damage the player's Active Pokémon does with `attack` (one of its own, or one it copies)
+/// to the opponent's Pokémon at `receiving_index`.
 pub(crate) fn get_damage_from_attack(
     state: &State,
     player: usize,
-    index: usize,
+    attack: &Attack,
     receiving_index: usize,
 ) -> u32 {
     let active = state.get_active(player);
-    let attack = active.card.get_attacks()[index].clone();
 
     // If attack is 0, not even Giovanni takes it to 10.
     if attack.fixed_damage == 0 {
@@ -94,6 +168,18 @@ pub(crate) fn get_damage_from_attack(
         giovanni_modifier = 10;
     }
 
+    // Cynthia's Modifier
+    let mut cynthia_modifier = 0;
+    let cynthia_ids = [CardId::A2152Cynthia, CardId::A2192Cynthia];
+    if ["Garchomp", "Togekiss"].contains(&active.get_name().as_str())
+        && state
+            .get_current_turn_effects()
+            .iter()
+            .any(|x| cynthia_ids.contains(&CardId::from_card_id(&x.get_id()).unwrap()))
+    {
+        cynthia_modifier = 50;
+    }
+
     // Weakness Modifier
     let opponent = (player + 1) % 2;
     let mut weakness_modifier = 0;
@@ -110,10 +196,10 @@ pub(crate) fn get_damage_from_attack(
     }
 
     debug!(
-        "Attack: {:?}, Weakness: {}, Giovanni: {}",
-        attack.fixed_damage, weakness_modifier, giovanni_modifier
+        "Attack: {:?}, Weakness: {}, Giovanni: {}, Cynthia: {}",
+        attack.fixed_damage, weakness_modifier, giovanni_modifier, cynthia_modifier
     );
-    attack.fixed_damage + weakness_modifier + giovanni_modifier
+    attack.fixed_damage + weakness_modifier + giovanni_modifier + cynthia_modifier
 }
 
 // Check if attached satisfies cost (considering Colorless)
@@ -190,6 +276,22 @@ mod tests {
         assert!(!can_play_support(&state));
     }
 
+    #[test]
+    fn test_can_use_ability_follows_its_usage() {
+        let mut state = State::default();
+        let weezing = to_playable_card(&get_card_by_enum(CardId::A1177Weezing), false);
+        let melmetal = to_playable_card(&get_card_by_enum(CardId::A1182Melmetal), false);
+        state.in_play_pokemon[0][0] = Some(weezing.clone());
+        state.in_play_pokemon[0][1] = Some(weezing);
+        state.in_play_pokemon[0][2] = Some(melmetal);
+        assert!(can_use_ability(&state, 0, 0));
+        assert!(!can_use_ability(&state, 0, 1)); // Weezing only from the Active Spot
+        assert!(!can_use_ability(&state, 0, 2)); // Passive abilities aren't used
+
+        state.in_play_pokemon[0][0].as_mut().unwrap().ability_used = true;
+        assert!(!can_use_ability(&state, 0, 0));
+    }
+
     #[test]
     fn test_giovanni_modifier() {
         // Create a basic state with attacking and defending Pokémon
@@ -206,13 +308,14 @@ mod tests {
         state.in_play_pokemon[1][0] = Some(played_defender);
 
         // Get base damage without Giovanni effect
-        let base_damage = get_damage_from_attack(&state, 0, 0, 0);
+        let attack = attacker.get_attacks()[0].clone();
+        let base_damage = get_damage_from_attack(&state, 0, &attack, 0);
 
         // Add Giovanni effect
         state.add_turn_effect(get_card_by_enum(CardId::A1223Giovanni), 0);
 
         // Get damage with Giovanni effect
-        let damage_with_giovanni = get_damage_from_attack(&state, 0, 0, 0);
+        let damage_with_giovanni = get_damage_from_attack(&state, 0, &attack, 0);
 
         // Verify Giovanni adds exactly 10 damage
         assert_eq!(
@@ -221,4 +324,28 @@ mod tests {
             "Giovanni should add exactly 10 damage to attacks"
         );
     }
+
+    #[test]
+    fn test_attack_restrictions_follow_the_attack_used() {
+        let buzzwole = to_playable_card(&get_card_by_enum(CardId::A3a006BuzzwoleEx), false);
+        let usable = |pokemon: &PlayedCard| {
+            (0..pokemon.get_attacks().len())
+                .filter(|&i| can_use_attack(pokemon, i))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(usable(&buzzwole), vec![0, 1]);
+
+        // Only Big Beat is restricted, and only after using it
+        let mut pokemon = buzzwole.clone();
+        pokemon.add_attack_effect(0, 5);
+        assert_eq!(usable(&pokemon), vec![0, 1]);
+        let mut pokemon = buzzwole.clone();
+        pokemon.add_attack_effect(1, 5);
+        assert_eq!(usable(&pokemon), vec![0]);
+
+        // Giga Impact blocks every attack
+        let mut aggron = to_playable_card(&get_card_by_enum(CardId::A3a050Aggron), false);
+        aggron.add_attack_effect(0, 5);
+        assert_eq!(usable(&aggron), Vec::<usize>::new());
+    }
 }
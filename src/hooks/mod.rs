@@ -1,15 +1,26 @@
 /// These are the places/functions in the framework that custom logic is to be implemented per card.
 /// That is those special "if Psyduck, do this", "if Darkrai, do that" kind of logic.
 /// We call these "hooks" (like on_attach_tool, on_attach_energy, on_play, on_knockout, etc...).
+/// Abilities that react to the game (e.g. to being damaged) are registered in `triggers`, and
+/// the rules passive ones change are looked up in `passives`.
 mod core;
 mod counterattack;
+mod passives;
 mod retreat;
+mod triggers;
 
+pub(crate) use core::can_evolve;
 pub(crate) use core::can_play_support;
+pub(crate) use core::can_use_ability;
+pub(crate) use core::can_use_attack;
 pub(crate) use core::contains_energy;
+pub(crate) use core::get_base_hp;
 pub(crate) use core::get_damage_from_attack;
 pub(crate) use core::on_attach_tool;
+pub(crate) use core::to_evolved_card;
 pub(crate) use core::to_playable_card;
 pub(crate) use counterattack::get_counterattack_damage;
+pub(crate) use passives::{get_damage_reduction, is_trainer_type_blocked};
 pub(crate) use retreat::can_retreat;
 pub(crate) use retreat::get_retreat_cost;
+pub(crate) use triggers::{fire_trigger, fire_turn_start, Trigger};
@@ -0,0 +1,108 @@
+use crate::{
+    ability_ids::{AbilityId, PassiveEffect},
+    types::{PlayedCard, TrainerType},
+    State,
+};
+
+fn get_passive_effect(pokemon: &PlayedCard) -> Option<&'static PassiveEffect> {
+    AbilityId::from_pokemon_id(&pokemon.get_id())?.passive_effect()
+}
+
+/// How much less damage the Pokémon at (defending_player, in_play_idx) takes from an attack
+/// of the attacking player's Active Pokémon.
+pub(crate) fn get_damage_reduction(
+    state: &State,
+    attacking_player: usize,
+    defending_player: usize,
+    in_play_idx: usize,
+) -> u32 {
+    let Some(defending) = &state.in_play_pokemon[defending_player][in_play_idx] else {
+        return 0;
+    };
+    let Some(PassiveEffect::DamageReduction { amount, from_types }) = get_passive_effect(defending)
+    else {
+        return 0;
+    };
+    let attacking_type = state.in_play_pokemon[attacking_player][0]
+        .as_ref()
+        .and_then(|attacking| attacking.card.get_type());
+    if from_types.is_empty() || attacking_type.is_some_and(|x| from_types.contains(&x)) {
+        *amount
+    } else {
+        0
+    }
+}
+
+/// How much the passives in play lower the Retreat Cost of the current player's Active
+/// Pokémon, or None if they make it free.
+pub(crate) fn get_retreat_reduction(state: &State, active: &PlayedCard) -> Option<usize> {
+    if get_passive_effect(active) == Some(&PassiveEffect::NoRetreatCostWithEnergy)
+        && !active.attached_energy.is_empty()
+    {
+        return None;
+    }
+    if !active.card.is_basic() {
+        return Some(0);
+    }
+    let reduction = state
+        .enumerate_bench_pokemon(state.current_player)
+        .filter_map(|(_, pokemon)| match get_passive_effect(pokemon) {
+            Some(PassiveEffect::BenchedBasicRetreatReduction(amount)) => Some(*amount),
+            _ => None,
+        })
+        .sum();
+    Some(reduction)
+}
+
+/// Whether the opponent's Active Pokémon keeps the player from playing this kind of Trainer.
+pub(crate) fn is_trainer_type_blocked(
+    state: &State,
+    player: usize,
+    trainer_type: &TrainerType,
+) -> bool {
+    let opponent = (player + 1) % 2;
+    let Some(opponent_active) = &state.in_play_pokemon[opponent][0] else {
+        return false;
+    };
+    matches!(
+        get_passive_effect(opponent_active),
+        Some(PassiveEffect::ActiveBlocksOpponentTrainers(blocked)) if blocked == trainer_type
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card};
+
+    fn played(card_id: CardId) -> Option<PlayedCard> {
+        Some(to_playable_card(&get_card_by_enum(card_id), false))
+    }
+
+    #[test]
+    fn test_damage_reduction_can_depend_on_attacking_type() {
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = played(CardId::A1033Charmander);
+        state.in_play_pokemon[1][0] = played(CardId::A2032Piloswine);
+        state.in_play_pokemon[1][1] = played(CardId::A1182Melmetal);
+        assert_eq!(get_damage_reduction(&state, 0, 1, 0), 20);
+        assert_eq!(get_damage_reduction(&state, 0, 1, 1), 20);
+
+        state.in_play_pokemon[0][0] = played(CardId::A1001Bulbasaur);
+        assert_eq!(get_damage_reduction(&state, 0, 1, 0), 0);
+        assert_eq!(get_damage_reduction(&state, 0, 1, 1), 20);
+    }
+
+    #[test]
+    fn test_gengar_ex_blocks_supporters_only_from_active_spot() {
+        let mut state = State::default();
+        state.in_play_pokemon[1][0] = played(CardId::A1123GengarEx);
+        assert!(is_trainer_type_blocked(&state, 0, &TrainerType::Supporter));
+        assert!(!is_trainer_type_blocked(&state, 0, &TrainerType::Item));
+        assert!(!is_trainer_type_blocked(&state, 1, &TrainerType::Supporter));
+
+        state.in_play_pokemon[1].swap(0, 1);
+        state.in_play_pokemon[1][0] = played(CardId::A1001Bulbasaur);
+        assert!(!is_trainer_type_blocked(&state, 0, &TrainerType::Supporter));
+    }
+}
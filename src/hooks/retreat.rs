@@ -1,6 +1,7 @@
 use crate::{
     card_ids::CardId,
     database::get_card_by_enum,
+    hooks::passives::get_retreat_reduction,
     types::{Card, EnergyType, PlayedCard},
     State,
 };
@@ -13,25 +14,21 @@ pub(crate) fn can_retreat(state: &State) -> bool {
     !state.has_retreated && no_arbok_corner
 }
 
+/// Retreat Cost of the current player's Active Pokémon `card`.
 pub(crate) fn get_retreat_cost(state: &State, card: &PlayedCard) -> Vec<EnergyType> {
     if let Card::Pokemon(pokemon_card) = &card.card {
+        let Some(ability_reduction) = get_retreat_reduction(state, card) else {
+            return vec![];
+        };
         let mut normal_cost = pokemon_card.retreat_cost.clone();
         // Implement Retreat Cost Modifiers here
-        let x_speed = state
-            .get_current_turn_effects()
-            .iter()
-            .filter(|x| **x == get_card_by_enum(CardId::PA002XSpeed))
-            .count();
-        let leafs = state
-            .get_current_turn_effects()
-            .iter()
-            .filter(|x| {
-                **x == get_card_by_enum(CardId::A1a068Leaf)
-                    || **x == get_card_by_enum(CardId::A1a082Leaf)
-            })
-            .count();
+        let x_speed = card.count_effects(&[get_card_by_enum(CardId::PA002XSpeed)]);
+        let leafs = card.count_effects(&[
+            get_card_by_enum(CardId::A1a068Leaf),
+            get_card_by_enum(CardId::A1a082Leaf),
+        ]);
         // Retreat Effects accumulate so we add them.
-        let to_subtract = leafs * 2 + x_speed;
+        let to_subtract = leafs * 2 + x_speed + ability_reduction;
         for _ in 0..to_subtract {
             normal_cost.pop(); // Remove one colorless energy from retreat cost
         }
@@ -66,10 +63,10 @@ mod tests {
 
     #[test]
     fn test_retreat_costs_with_xspeed() {
-        let mut state = State::default();
-        state.add_turn_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
+        let state = State::default();
         let card = get_card_by_enum(CardId::A1055Blastoise);
-        let playable_card = to_playable_card(&card, false);
+        let mut playable_card = to_playable_card(&card, false);
+        playable_card.add_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
         let retreat_cost = get_retreat_cost(&state, &playable_card);
         assert_eq!(
             retreat_cost,
@@ -79,13 +76,30 @@ mod tests {
 
     #[test]
     fn test_retreat_costs_with_two_xspeed_and_two_leafs() {
-        let mut state = State::default();
-        state.add_turn_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
-        state.add_turn_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
-        state.add_turn_effect(get_card_by_enum(CardId::A1a068Leaf), 0);
+        let state = State::default();
         let card = get_card_by_enum(CardId::A1211Snorlax);
-        let playable_card = to_playable_card(&card, false);
+        let mut playable_card = to_playable_card(&card, false);
+        playable_card.add_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
+        playable_card.add_effect(get_card_by_enum(CardId::PA002XSpeed), 0);
+        playable_card.add_effect(get_card_by_enum(CardId::A1a068Leaf), 0);
         let retreat_cost = get_retreat_cost(&state, &playable_card);
         assert_eq!(retreat_cost, vec![]);
     }
+
+    #[test]
+    fn test_retreat_costs_with_passive_abilities() {
+        let mut state = State::default();
+        let mut giratina = to_playable_card(&get_card_by_enum(CardId::A2078Giratina), false);
+        assert_eq!(get_retreat_cost(&state, &giratina).len(), 3);
+        giratina.attach_energy(&EnergyType::Psychic, 1);
+        assert_eq!(get_retreat_cost(&state, &giratina), vec![]);
+
+        // Shaymin on the Bench only helps Basic Pokémon
+        let shaymin = get_card_by_enum(CardId::A2a069Shaymin);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&shaymin, false));
+        let snorlax = to_playable_card(&get_card_by_enum(CardId::A1211Snorlax), false);
+        let blastoise = to_playable_card(&get_card_by_enum(CardId::A1055Blastoise), false);
+        assert_eq!(get_retreat_cost(&state, &snorlax).len(), 3);
+        assert_eq!(get_retreat_cost(&state, &blastoise).len(), 3);
+    }
 }
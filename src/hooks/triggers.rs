@@ -0,0 +1,225 @@
+use log::debug;
+
+use crate::{ability_ids::AbilityId, types::EnergyType, State};
+
+/// Something that happened to a Pokémon, that its Ability might react to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Trigger {
+    /// An attack damaged it (whether it survived or not).
+    AttackDamaged {
+        attacking_player: usize,
+        damage: u32,
+    },
+    /// An attack Knocked it Out. Goes off before it leaves play.
+    KnockedOutByAttack { attacking_player: usize },
+    /// Its player attached Energy from their Energy Zone to it.
+    EnergyAttached { energy: EnergyType },
+    /// Its player evolved it from their hand.
+    Evolved,
+    /// Its player's turn started.
+    TurnStart,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TriggerKind {
+    AttackDamaged,
+    KnockedOutByAttack,
+    EnergyAttached,
+    Evolved,
+    TurnStart,
+}
+
+impl Trigger {
+    fn kind(&self) -> TriggerKind {
+        match self {
+            Trigger::AttackDamaged { .. } => TriggerKind::AttackDamaged,
+            Trigger::KnockedOutByAttack { .. } => TriggerKind::KnockedOutByAttack,
+            Trigger::EnergyAttached { .. } => TriggerKind::EnergyAttached,
+            Trigger::Evolved => TriggerKind::Evolved,
+            Trigger::TurnStart => TriggerKind::TurnStart,
+        }
+    }
+}
+
+/// What a triggered Ability does, given its Pokémon as (player, in_play_idx). Effects only
+/// change HP; whoever fires the trigger takes care of any Knock Out.
+type TriggeredEffect = fn(&mut State, usize, usize, &Trigger);
+
+/// Abilities that go off on their own, instead of being used. Adding one is a line here
+/// (and its `AbilityId`).
+const TRIGGERED_ABILITIES: &[(AbilityId, TriggerKind, TriggeredEffect)] = &[
+    (
+        AbilityId::A1061Poliwrath,
+        TriggerKind::AttackDamaged,
+        counterattack::<20>,
+    ),
+    (
+        AbilityId::A1a056Druddigon,
+        TriggerKind::AttackDamaged,
+        counterattack::<20>,
+    ),
+    (
+        AbilityId::A2b028Pawmot,
+        TriggerKind::AttackDamaged,
+        counterattack::<20>,
+    ),
+    (
+        AbilityId::A3a052Ferrothorn,
+        TriggerKind::AttackDamaged,
+        counterattack::<20>,
+    ),
+    (
+        AbilityId::A3054Pyukumuku,
+        TriggerKind::KnockedOutByAttack,
+        counterattack::<50>,
+    ),
+    (
+        AbilityId::A2110DarkraiEx,
+        TriggerKind::EnergyAttached,
+        darkrai_nightmare_aura,
+    ),
+    (
+        AbilityId::PA037CresseliaEx,
+        TriggerKind::EnergyAttached,
+        cresselia_lunar_plumage,
+    ),
+    (
+        AbilityId::A3b034SylveonEx,
+        TriggerKind::Evolved,
+        sylveon_happy_ribbon,
+    ),
+];
+
+/// Lets the Ability of the Pokémon at (player, in_play_idx) react to the trigger, if it has
+/// one for it.
+pub(crate) fn fire_trigger(state: &mut State, player: usize, in_play_idx: usize, trigger: Trigger) {
+    let Some(pokemon) = &state.in_play_pokemon[player][in_play_idx] else {
+        return;
+    };
+    let Some(ability_id) = AbilityId::from_pokemon_id(&pokemon.get_id()) else {
+        return;
+    };
+    for (id, kind, effect) in TRIGGERED_ABILITIES {
+        if *id == ability_id && *kind == trigger.kind() {
+            debug!(
+                "{:?} reacts to {:?} ({}'s Pokemon {})",
+                ability_id, trigger, player, in_play_idx
+            );
+            effect(state, player, in_play_idx, &trigger);
+        }
+    }
+}
+
+/// Fires `Trigger::TurnStart` for each of the player's Pokémon.
+pub(crate) fn fire_turn_start(state: &mut State, player: usize) {
+    for in_play_idx in 0..state.in_play_pokemon[player].len() {
+        fire_trigger(state, player, in_play_idx, Trigger::TurnStart);
+    }
+}
+
+// If this Pokémon is in the Active Spot and is damaged (or Knocked Out) by an attack from
+// your opponent's Pokémon, do DAMAGE damage to the Attacking Pokémon.
+fn counterattack<const DAMAGE: u32>(
+    state: &mut State,
+    _: usize,
+    in_play_idx: usize,
+    trigger: &Trigger,
+) {
+    let (Trigger::AttackDamaged {
+        attacking_player, ..
+    }
+    | Trigger::KnockedOutByAttack { attacking_player }) = trigger
+    else {
+        return;
+    };
+    if in_play_idx != 0 {
+        return;
+    }
+    if let Some(attacking_pokemon) = state.in_play_pokemon[*attacking_player][0].as_mut() {
+        attacking_pokemon.apply_damage(DAMAGE);
+    }
+}
+
+// Whenever you attach a [D] Energy from your Energy Zone to this Pokémon, do 20 damage to
+// your opponent's Active Pokémon.
+fn darkrai_nightmare_aura(state: &mut State, player: usize, _: usize, trigger: &Trigger) {
+    if *trigger
+        != (Trigger::EnergyAttached {
+            energy: EnergyType::Darkness,
+        })
+    {
+        return;
+    }
+    let opponent = (player + 1) % 2;
+    if let Some(opponent_active) = state.in_play_pokemon[opponent][0].as_mut() {
+        opponent_active.apply_damage(20);
+    }
+}
+
+// Whenever you attach a [P] Energy from your Energy Zone to this Pokémon, heal 20 damage
+// from this Pokémon.
+fn cresselia_lunar_plumage(
+    state: &mut State,
+    player: usize,
+    in_play_idx: usize,
+    trigger: &Trigger,
+) {
+    if *trigger
+        != (Trigger::EnergyAttached {
+            energy: EnergyType::Psychic,
+        })
+    {
+        return;
+    }
+    if let Some(pokemon) = state.in_play_pokemon[player][in_play_idx].as_mut() {
+        pokemon.heal(20);
+    }
+}
+
+// Once during your turn, when you play this Pokémon from your hand to evolve 1 of your
+// Pokémon, you may draw 2 cards.
+fn sylveon_happy_ribbon(state: &mut State, player: usize, _: usize, _: &Trigger) {
+    for _ in 0..2 {
+        state.queue_draw_action(player);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card};
+
+    fn state_with(attacker: CardId, defender: CardId) -> State {
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&get_card_by_enum(attacker), false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&get_card_by_enum(defender), false));
+        state
+    }
+
+    #[test]
+    fn test_counterattack_only_from_the_active_spot() {
+        let mut state = state_with(CardId::A1001Bulbasaur, CardId::A1061Poliwrath);
+        state.in_play_pokemon[1][1] = state.in_play_pokemon[1][0].clone();
+        let trigger = Trigger::AttackDamaged {
+            attacking_player: 0,
+            damage: 10,
+        };
+        fire_trigger(&mut state, 1, 1, trigger);
+        assert_eq!(state.get_active(0).remaining_hp, 70);
+        fire_trigger(&mut state, 1, 0, trigger);
+        assert_eq!(state.get_active(0).remaining_hp, 50);
+        // Other triggers don't set it off
+        fire_trigger(&mut state, 1, 0, Trigger::TurnStart);
+        assert_eq!(state.get_active(0).remaining_hp, 50);
+    }
+
+    #[test]
+    fn test_energy_attached_triggers_check_the_energy_type() {
+        let mut state = state_with(CardId::A2110DarkraiEx, CardId::A1001Bulbasaur);
+        let attach = |energy| Trigger::EnergyAttached { energy };
+        fire_trigger(&mut state, 0, 0, attach(EnergyType::Grass));
+        assert_eq!(state.get_active(1).remaining_hp, 70);
+        fire_trigger(&mut state, 0, 0, attach(EnergyType::Darkness));
+        assert_eq!(state.get_active(1).remaining_hp, 50);
+    }
+}
@@ -1,28 +1,66 @@
 mod ability_ids;
 pub mod actions;
+pub mod analysis;
+#[cfg(feature = "tokio")]
+pub mod async_game;
+mod attack_effects;
 mod attack_ids;
+pub mod audit;
 pub mod card_ids;
+pub mod card_query;
+pub mod clock;
+mod compact_state;
 pub mod database;
 pub mod deck;
+pub mod deck_generation;
+pub mod deck_stats;
+pub mod effect_text;
 mod errors;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod game;
+pub mod game_log;
+pub mod game_rules;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 mod hidden_information;
 mod hooks;
+pub mod masked_state;
+pub mod match_runner;
+#[cfg(feature = "meta-decks")]
+pub mod meta_decks;
 pub mod move_generation;
-mod optimize;
-mod optimized_state;
+pub mod observation;
+pub mod optimize;
+pub mod packs;
 pub mod players;
+pub mod progress;
+pub mod quickstart;
+pub mod replay;
+pub mod rng_registry;
+pub mod rulings;
 mod safe_state;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod sets;
 pub mod simulate;
 pub mod state;
+pub mod state_diff;
+mod state_validation;
 pub mod test_helpers; // TODO: Compile/Expose only in test mode?
 pub mod tool_ids;
 pub mod types;
 
 pub use ability_ids::AbilityId;
+pub use attack_effects::AttackEffect;
 pub use attack_ids::AttackId;
-pub use deck::Deck;
-pub use game::Game;
+pub use deck::{Deck, DeckFormat};
+pub use deck_generation::DeckConstraints;
+pub use game::{Game, PendingChoice};
+pub use game_rules::{GameRules, TieBreak};
+pub use hidden_information::HiddenKnowledge;
+pub use masked_state::MaskedState;
+pub use match_runner::{Match, MatchOutcome};
 pub use move_generation::generate_possible_actions;
 pub use move_generation::generate_possible_trainer_actions;
 pub use optimize::optimize;
@@ -1,10 +1,18 @@
-use clap::{ArgAction, Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use colored::Colorize;
-use deckgym::players::{parse_player_code, PlayerCode};
-use deckgym::{optimize, simulate};
+use deckgym::analysis::{Analysis, Replay};
+use deckgym::card_ids::CardId;
+use deckgym::optimize::{evolve, parse_card_id, Gauntlet, GeneticConfig};
+use deckgym::packs::{packs_to_complete, Collection, PackPlan};
+use deckgym::players::{fill_code_array, parse_player_code, PlayerCode};
+use deckgym::simulate::{compare_decks, goldfish, matchup_matrix, GoldfishConfig};
+use deckgym::{optimize, simulate, Deck, DeckConstraints};
+use deckgym::{DeckFormat, GameError};
 use env_logger::{Builder, Env};
 use log::warn;
+use std::fs;
 use std::io::Write;
+use std::path::Path;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -13,6 +21,14 @@ struct Cli {
     command: Commands,
 }
 
+/// How results are printed to stdout.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Csv,
+    Json,
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Simulate games between two decks
@@ -35,6 +51,15 @@ enum Commands {
         #[arg(short, long)]
         seed: Option<u64>,
 
+        /// Write every action of every game as JSON lines to this file
+        #[arg(long)]
+        log: Option<String>,
+
+        /// Append the result of every game to this SQLite file
+        #[cfg(feature = "sqlite")]
+        #[arg(long)]
+        db: Option<String>,
+
         /// Increase verbosity (-v, -vv, -vvv, etc.)
         #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
         verbose: u8,
@@ -62,6 +87,248 @@ enum Commands {
         #[arg(short, long)]
         seed: Option<u64>,
 
+        /// Most ex Pokémon the completed deck can have
+        #[arg(long)]
+        max_ex: Option<usize>,
+
+        /// Most Pack Points the completed deck can cost, counting its cards' rarities
+        #[arg(long)]
+        budget: Option<u32>,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Play a deck alone against an opponent that does nothing, and report how fast it sets up
+    Goldfish {
+        /// Path to the deck file
+        deck: String,
+
+        /// Number of games to play
+        #[arg(short, long)]
+        num: u32,
+
+        /// Strategy playing the deck
+        #[arg(long, value_parser = parse_player_code, default_value = "w")]
+        player: PlayerCode,
+
+        /// Number of Benched Pokémon to reach
+        #[arg(long, default_value_t = 2)]
+        bench: usize,
+
+        /// Comma-separated names of Pokémon to report the first turn in play of
+        #[arg(long, value_delimiter = ',')]
+        key_pokemon: Vec<String>,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Estimate how many booster packs it takes to collect the cards of a deck
+    Packs {
+        /// Path to the deck file
+        deck: String,
+
+        /// Number of collections to simulate
+        #[arg(short, long, default_value_t = 1000)]
+        num: u32,
+
+        /// Keep opening packs instead of buying the last cards with Pack Points
+        #[arg(long)]
+        no_pack_points: bool,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Compare two similar decks by playing them in the same games against opponents
+    Compare {
+        /// Path to the first deck file
+        deck_a: String,
+
+        /// Path to the second deck file
+        deck_b: String,
+
+        /// Paths to the opponent deck files
+        #[arg(long, required = true, num_args = 1..)]
+        against: Vec<String>,
+
+        /// Number of pairs of games to play against each opponent
+        #[arg(short, long)]
+        num: u32,
+
+        /// Players' strategies as a comma-separated list
+        #[arg(long, value_delimiter = ',', value_parser = parse_player_code)]
+        players: Option<Vec<PlayerCode>>,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Step through a game interactively: list and play actions, undo, and run rollouts
+    Analyze {
+        /// Path to the first deck file (not needed with --replay)
+        #[arg(required_unless_present = "replay")]
+        deck_a: Option<String>,
+
+        /// Path to the second deck file (not needed with --replay)
+        #[arg(required_unless_present = "replay")]
+        deck_b: Option<String>,
+
+        /// Continue a game saved with the "save" command
+        #[arg(long)]
+        replay: Option<String>,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Simulate every deck against every other deck and report the win-rate matrix
+    Matchups {
+        /// Paths to the deck files
+        #[arg(required = true, num_args = 2..)]
+        decks: Vec<String>,
+
+        /// Number of simulations to run per matchup
+        #[arg(short, long)]
+        num: u32,
+
+        /// Players' strategies as a comma-separated list
+        #[arg(long, value_delimiter = ',', value_parser = parse_player_code)]
+        players: Option<Vec<PlayerCode>>,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Write the matrix as CSV to this file
+        #[arg(long)]
+        csv: Option<String>,
+
+        /// Write the matchups as JSON to this file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Play a round robin between decks and rank them by win rate
+    Tournament {
+        /// Paths to the deck files
+        #[arg(required = true, num_args = 2..)]
+        decks: Vec<String>,
+
+        /// Number of games to play per pair of decks
+        #[arg(short, long)]
+        num: u32,
+
+        /// Players' strategies as a comma-separated list
+        #[arg(long, value_delimiter = ',', value_parser = parse_player_code)]
+        players: Option<Vec<PlayerCode>>,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Increase verbosity (-v, -vv, -vvv, etc.)
+        #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
+        verbose: u8,
+    },
+    /// Check that deck files are legal, exiting with an error if one is not
+    ValidateDeck {
+        /// Paths to the deck files
+        #[arg(required = true)]
+        decks: Vec<String>,
+
+        /// Only allow sets legal on this date ("YYYY-MM-DD")
+        #[arg(long)]
+        date: Option<String>,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Play back a game saved by the "analyze" command, one action per line
+    Replay {
+        /// Path to the replay file
+        replay: String,
+
+        /// Text prints the actions, JSON prints a game log line per action
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Only check that the game still ends in the recorded state (exits with 1 if not)
+        #[arg(long)]
+        verify: bool,
+    },
+    /// Search for a whole new deck against enemy decks with a genetic algorithm
+    Evolve {
+        /// Folder containing enemy deck files
+        enemy_decks_folder: String,
+
+        /// Number of simulations to run per enemy deck for each candidate deck
+        #[arg(short, long)]
+        num: u32,
+
+        /// Number of decks in each generation
+        #[arg(long, default_value_t = 24)]
+        population: usize,
+
+        /// Maximum number of generations
+        #[arg(long, default_value_t = 30)]
+        generations: usize,
+
+        /// Only use Pokémon of this energy type (e.g. "Fire")
+        #[arg(long)]
+        energy: Option<String>,
+
+        /// Cards every deck must have, as a comma-separated list of ids (e.g. "A1033,A1033")
+        #[arg(long, value_delimiter = ',', value_parser = parse_card_id)]
+        require: Vec<CardId>,
+
+        /// Most ex Pokémon a deck can have
+        #[arg(long)]
+        max_ex: Option<usize>,
+
+        /// Most Pack Points a deck can cost, counting its cards' rarities
+        #[arg(long)]
+        budget: Option<u32>,
+
+        /// File to save the search to after every generation
+        #[arg(long)]
+        checkpoint: Option<String>,
+
+        /// Continue the search saved in the checkpoint file, if there is one
+        #[arg(long, requires = "checkpoint")]
+        resume: bool,
+
+        /// Players' strategies as a comma-separated list (e.g. "e,e")
+        #[arg(long, value_delimiter = ',', value_parser = parse_player_code)]
+        players: Option<Vec<PlayerCode>>,
+
+        /// Seed for random number generation
+        #[arg(short, long)]
+        seed: Option<u64>,
+
         /// Increase verbosity (-v, -vv, -vvv, etc.)
         #[arg(short, long, action = ArgAction::Count, default_value_t = 1)]
         verbose: u8,
@@ -79,13 +346,32 @@ fn main() {
             players,
             num,
             seed,
+            log,
+            #[cfg(feature = "sqlite")]
+            db,
             verbose,
         } => {
             initialize_logger(verbose);
 
             warn!("Welcome to {} simulation!", "deckgym".blue().bold());
 
-            simulate(&deck_a, &deck_b, players, num, seed);
+            #[cfg(feature = "sqlite")]
+            if let Some(path) = db {
+                let db = deckgym::simulate::ResultsDb::open(path)
+                    .expect("Failed to open results database");
+                let mut control = deckgym::progress::RunControl::new().store_results(&db);
+                deckgym::simulate::simulate_with(
+                    &deck_a,
+                    &deck_b,
+                    players,
+                    num,
+                    seed,
+                    log.as_deref(),
+                    &mut control,
+                );
+                return;
+            }
+            simulate(&deck_a, &deck_b, players, num, seed, log.as_deref());
         }
         Commands::Optimize {
             incomplete_deck,
@@ -94,12 +380,19 @@ fn main() {
             num,
             players,
             seed,
+            max_ex,
+            budget,
             verbose,
         } => {
             initialize_logger(verbose);
 
             warn!("Welcome to {} optimizer!", "deckgym".blue().bold());
 
+            let constraints = DeckConstraints {
+                max_ex,
+                rarity_budget: budget,
+                ..Default::default()
+            };
             optimize(
                 &incomplete_deck,
                 &candidate_cards,
@@ -107,12 +400,331 @@ fn main() {
                 num,
                 players,
                 seed,
+                &constraints,
+            );
+        }
+        Commands::Goldfish {
+            deck,
+            num,
+            player,
+            bench,
+            key_pokemon,
+            seed,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            warn!("Welcome to {} goldfish!", "deckgym".blue().bold());
+
+            let deck = Deck::from_file(&deck).expect("Failed to parse deck from file");
+            let config = GoldfishConfig {
+                bench_target: bench,
+                key_pokemon,
+            };
+            let report = goldfish(
+                &deck,
+                player,
+                &config,
+                num,
+                seed.unwrap_or(rand::random::<u64>()),
+            );
+            warn!("{report}");
+        }
+        Commands::Packs {
+            deck,
+            num,
+            no_pack_points,
+            seed,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            warn!("Welcome to {} pack simulator!", "deckgym".blue().bold());
+
+            let deck = Deck::from_file(&deck).expect("Failed to parse deck from file");
+            let plan = PackPlan {
+                trials: num,
+                use_pack_points: !no_pack_points,
+                ..Default::default()
+            };
+            let report = packs_to_complete(
+                &deck,
+                &Collection::new(),
+                &plan,
+                seed.unwrap_or(rand::random::<u64>()),
+            );
+            warn!("{report}");
+        }
+        Commands::Compare {
+            deck_a,
+            deck_b,
+            against,
+            num,
+            players,
+            seed,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            warn!("Welcome to {} comparison!", "deckgym".blue().bold());
+
+            let read =
+                |path: &String| Deck::from_file(path).expect("Failed to parse deck from file");
+            let opponents: Vec<Deck> = against.iter().map(read).collect();
+            let comparison = compare_decks(
+                &read(&deck_a),
+                &read(&deck_b),
+                &opponents,
+                fill_code_array(players),
+                num,
+                seed.unwrap_or(rand::random::<u64>()),
+            );
+            warn!("{comparison}");
+        }
+        Commands::Analyze {
+            deck_a,
+            deck_b,
+            replay,
+            seed,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            warn!(
+                "Welcome to {} analysis! Type \"help\" for commands.",
+                "deckgym".blue().bold()
+            );
+
+            let mut analysis = match (replay, deck_a, deck_b) {
+                (Some(path), _, _) => {
+                    let json = fs::read_to_string(&path).expect("Failed to read replay");
+                    let replay = Replay::from_json(&json).expect("Invalid replay");
+                    Analysis::from_replay(&replay).expect("Failed to load replay")
+                }
+                (None, Some(deck_a), Some(deck_b)) => Analysis::new(
+                    &Deck::from_file(&deck_a).expect("Failed to parse deck from file"),
+                    &Deck::from_file(&deck_b).expect("Failed to parse deck from file"),
+                    seed.unwrap_or(rand::random::<u64>()),
+                ),
+                _ => unreachable!("clap requires both decks without a replay"),
+            };
+            analysis
+                .run(std::io::stdin().lock(), std::io::stdout())
+                .expect("Failed to read commands");
+        }
+        Commands::Matchups {
+            decks,
+            num,
+            players,
+            seed,
+            csv,
+            json,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            warn!("Welcome to {} matchups!", "deckgym".blue().bold());
+
+            let matrix = matchup_matrix(
+                &named_decks(&decks),
+                fill_code_array(players),
+                num,
+                seed.unwrap_or(rand::random::<u64>()),
+            );
+            warn!("{}", matrix.to_csv());
+            if let Some(path) = csv {
+                fs::write(&path, matrix.to_csv()).expect("Failed to write CSV");
+            }
+            if let Some(path) = json {
+                fs::write(&path, matrix.to_json()).expect("Failed to write JSON");
+            }
+        }
+        Commands::Tournament {
+            decks,
+            num,
+            players,
+            seed,
+            format,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            let matrix = matchup_matrix(
+                &named_decks(&decks),
+                fill_code_array(players),
+                num,
+                seed.unwrap_or(rand::random::<u64>()),
+            );
+            match format {
+                OutputFormat::Text => {
+                    for (rank, standing) in matrix.standings().iter().enumerate() {
+                        println!(
+                            "{}. {} {:.2}% ({}-{}-{})",
+                            rank + 1,
+                            standing.deck,
+                            standing.win_rate * 100.0,
+                            standing.record.wins,
+                            standing.record.losses,
+                            standing.record.ties
+                        );
+                    }
+                }
+                OutputFormat::Csv => print!("{}", matrix.standings_csv()),
+                OutputFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&matrix.standings())
+                        .expect("Standings should serialize")
+                ),
+            }
+        }
+        Commands::ValidateDeck {
+            decks,
+            date,
+            format,
+        } => {
+            let deck_format = date.map_or_else(DeckFormat::default, |x| DeckFormat::on_date(&x));
+            let results: Vec<(String, Vec<String>)> = decks
+                .into_iter()
+                .map(|path| {
+                    let errors = match Deck::from_file(&path) {
+                        Ok(deck) => match deck.validate(&deck_format) {
+                            Ok(()) => vec![],
+                            Err(GameError::DeckValidationFailed { errors }) => errors,
+                            Err(error) => vec![error.to_string()],
+                        },
+                        Err(error) => vec![error],
+                    };
+                    (path, errors)
+                })
+                .collect();
+            match format {
+                OutputFormat::Text => {
+                    for (path, errors) in &results {
+                        if errors.is_empty() {
+                            println!("{path}: OK");
+                        }
+                        for error in errors {
+                            println!("{path}: {error}");
+                        }
+                    }
+                }
+                OutputFormat::Csv => {
+                    println!("deck,valid,errors");
+                    for (path, errors) in &results {
+                        println!("{path},{},\"{}\"", errors.is_empty(), errors.join("; "));
+                    }
+                }
+                OutputFormat::Json => {
+                    let json: Vec<_> = results
+                        .iter()
+                        .map(|(path, errors)| {
+                            serde_json::json!({
+                                "deck": path,
+                                "valid": errors.is_empty(),
+                                "errors": errors,
+                            })
+                        })
+                        .collect();
+                    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+                }
+            }
+            if results.iter().any(|(_, errors)| !errors.is_empty()) {
+                std::process::exit(1);
+            }
+        }
+        Commands::Replay {
+            replay,
+            format,
+            verify,
+        } => {
+            let json = fs::read_to_string(&replay).expect("Failed to read replay");
+            let replay = Replay::from_json(&json).expect("Invalid replay");
+            if verify {
+                match deckgym::replay::verify(&replay) {
+                    Ok(()) => println!("Replay verified"),
+                    Err(error) => {
+                        println!("Replay failed verification: {error}");
+                        std::process::exit(1);
+                    }
+                }
+                return;
+            }
+            let log = Analysis::replay_log(&replay).expect("Failed to play replay");
+            for entry in log {
+                match format {
+                    OutputFormat::Json => {
+                        println!("{}", serde_json::to_string(&entry).unwrap())
+                    }
+                    OutputFormat::Text | OutputFormat::Csv => println!(
+                        "{:>4} turn {:>2} player {}: {}",
+                        entry.ply, entry.turn, entry.actor, entry.action
+                    ),
+                }
+            }
+        }
+        Commands::Evolve {
+            enemy_decks_folder,
+            num,
+            population,
+            generations,
+            energy,
+            require,
+            max_ex,
+            budget,
+            checkpoint,
+            resume,
+            players,
+            seed,
+            verbose,
+        } => {
+            initialize_logger(verbose);
+
+            warn!("Welcome to {} deck evolution!", "deckgym".blue().bold());
+
+            let gauntlet =
+                Gauntlet::from_folder(&enemy_decks_folder, num, fill_code_array(players));
+            let energy_type = energy.map(|x| x.parse().expect("Invalid energy type"));
+            let config = GeneticConfig {
+                population_size: population,
+                max_generations: generations,
+                constraints: DeckConstraints {
+                    energy_type,
+                    required_cards: require,
+                    max_ex,
+                    rarity_budget: budget,
+                    ..Default::default()
+                },
+                checkpoint_path: checkpoint,
+                resume,
+                seed: seed.unwrap_or(rand::random::<u64>()),
+                ..Default::default()
+            };
+            let result = evolve(&gauntlet, &config);
+            warn!(
+                "Best deck ({:.2}% win rate after {} generations):\n{}",
+                result.best_win_rate * 100.0,
+                result.generations,
+                result.best_deck
             );
         }
     }
 }
 
 // Set up the logger according to the given verbosity.
+// Decks named after their file, e.g. "weezing-arbok"
+fn named_decks(paths: &[String]) -> Vec<(String, Deck)> {
+    paths
+        .iter()
+        .map(|path| {
+            let name = Path::new(path)
+                .file_stem()
+                .map_or(path.clone(), |x| x.to_string_lossy().to_string());
+            let deck = Deck::from_file(path).expect("Failed to parse deck from file");
+            (name, deck)
+        })
+        .collect()
+}
+
 fn initialize_logger(verbose: u8) {
     let level = match verbose {
         1 => "warn",
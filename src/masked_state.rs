@@ -0,0 +1,95 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::{
+    game_rules::GameRules,
+    state::{GameOutcome, TurnPhase, MAX_IN_PLAY},
+    types::{Card, EnergyType, PlayedCard},
+    State,
+};
+
+/// What one player can see of a `State`: the board, discard piles and scores, their own
+/// hand, and only the sizes of the opponent's hand and of the decks. Safe to send to
+/// clients and bots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MaskedState {
+    /// The player this state is seen by.
+    pub perspective: usize,
+    pub winner: Option<GameOutcome>,
+    pub points: [u8; 2],
+    pub turn_count: u8,
+    pub current_player: usize,
+    pub phase: TurnPhase,
+    pub current_energy: Option<EnergyType>,
+    /// The perspective player's hand.
+    pub hand: Vec<Card>,
+    pub hand_sizes: [usize; 2],
+    pub deck_sizes: [usize; 2],
+    /// What is left in the perspective player's deck, sorted by id (its order is hidden).
+    pub deck_contents: Vec<Card>,
+    pub discard_piles: [Vec<Card>; 2],
+    pub discarded_energy: [BTreeMap<EnergyType, u32>; 2],
+    pub in_play_pokemon: [[Option<PlayedCard>; MAX_IN_PLAY]; 2],
+    pub has_played_support: bool,
+    pub has_retreated: bool,
+    pub rules: GameRules,
+}
+
+impl State {
+    /// The state as seen by `player` (see `MaskedState`).
+    pub fn masked_for(&self, player: usize) -> MaskedState {
+        let mut deck_contents = self.decks[player].cards.clone();
+        deck_contents.sort_by_key(|x| x.get_id());
+        MaskedState {
+            perspective: player,
+            winner: self.winner,
+            points: self.points,
+            turn_count: self.turn_count,
+            current_player: self.current_player,
+            current_energy: self.current_energy,
+            hand: self.hands[player].clone(),
+            hand_sizes: [self.hands[0].len(), self.hands[1].len()],
+            deck_sizes: [self.decks[0].cards.len(), self.decks[1].cards.len()],
+            deck_contents,
+            discard_piles: self.discard_piles.clone(),
+            discarded_energy: self.discarded_energy.clone(),
+            in_play_pokemon: self.in_play_pokemon.clone(),
+            has_played_support: self.has_played_support,
+            has_retreated: self.has_retreated,
+            phase: self.phase,
+            rules: self.rules,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::test_helpers::load_test_decks;
+
+    #[test]
+    fn test_masked_for() {
+        let (deck_a, deck_b) = load_test_decks();
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        let masked = state.masked_for(0);
+        assert_eq!(masked.hand, state.hands[0]);
+        assert_eq!(masked.hand_sizes, [5, 5]);
+        assert_eq!(masked.deck_sizes, [15, 15]);
+        assert_eq!(masked.deck_contents.len(), 15);
+
+        // Neither the opponent's hand nor the order of the decks leak
+        let mut other = state.clone();
+        other.hands[1].rotate_left(1);
+        other.hands[1][0] = other.decks[1].cards[0].clone();
+        other.deck_mut(0).cards.reverse();
+        other.deck_mut(1).cards.reverse();
+        assert_eq!(other.masked_for(0), masked);
+        assert_ne!(other.masked_for(1), state.masked_for(1));
+
+        let json = serde_json::to_value(&masked).unwrap();
+        assert!(json.get("hands").is_none());
+        assert_eq!(json["deck_sizes"][1], 15);
+    }
+}
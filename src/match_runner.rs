@@ -0,0 +1,165 @@
+use log::debug;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    clock::{GameClock, TimeControl},
+    game_rules::GameRules,
+    players::Player,
+    state::GameOutcome,
+    Game,
+};
+
+/// A single game played as part of a match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchGame {
+    pub seed: u64,
+    pub first_player: usize,
+    pub outcome: Option<GameOutcome>,
+    /// Time used by each player, if the match has a time control.
+    pub clock: Option<GameClock>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchOutcome {
+    /// None if neither player reached the needed wins (e.g. because of ties).
+    pub winner: Option<usize>,
+    pub wins: [u8; 2],
+    pub games: Vec<MatchGame>,
+}
+
+/// Best-of-N match (Bo3 by default) between two players. The same players (and decks)
+/// play every game, and who goes first alternates between games.
+pub struct Match {
+    players: Vec<Box<dyn Player>>,
+    seed: u64,
+    best_of: u8,
+    rules: GameRules,
+    time_control: Option<TimeControl>,
+}
+
+impl Match {
+    pub fn new(players: Vec<Box<dyn Player>>, seed: u64) -> Self {
+        Self {
+            players,
+            seed,
+            best_of: 3,
+            rules: GameRules::default(),
+            time_control: None,
+        }
+    }
+
+    pub fn with_best_of(mut self, best_of: u8) -> Self {
+        assert!(best_of % 2 == 1, "Matches should be best of an odd number");
+        self.best_of = best_of;
+        self
+    }
+
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Times every game's decisions, each game starting with a fresh clock.
+    pub fn with_time_control(mut self, time_control: TimeControl) -> Self {
+        self.time_control = Some(time_control);
+        self
+    }
+
+    pub fn play(self) -> MatchOutcome {
+        let wins_needed = self.best_of / 2 + 1;
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut first_player = rng.gen_range(0..2);
+        let mut players = self.players;
+        let mut wins = [0, 0];
+        let mut games = vec![];
+        while games.len() < self.best_of as usize && wins.iter().all(|x| *x < wins_needed) {
+            let seed = rng.gen::<u64>();
+            let mut game = Game::with_rules(players, seed, self.rules);
+            game.set_starting_player(first_player);
+            if let Some(time_control) = self.time_control {
+                game.enable_clock(time_control);
+            }
+            let outcome = game.play();
+            if let Some(GameOutcome::Win(winner)) = outcome {
+                wins[winner] += 1;
+            }
+            debug!(
+                "Game {} (seed {}, player {} first): {:?}",
+                games.len() + 1,
+                seed,
+                first_player,
+                outcome
+            );
+            games.push(MatchGame {
+                seed,
+                first_player,
+                outcome,
+                clock: game.get_clock().cloned(),
+            });
+            players = game.into_players();
+            first_player = (first_player + 1) % 2;
+        }
+
+        let winner = (0..2).find(|&player| wins[player] >= wins_needed);
+        MatchOutcome {
+            winner,
+            wins,
+            games,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        players::{AttachAttackPlayer, EndTurnPlayer, RandomPlayer},
+        test_helpers::load_test_decks,
+    };
+
+    #[test]
+    fn test_match_alternates_first_player() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer { deck: deck_a }),
+            Box::new(RandomPlayer { deck: deck_b }),
+        ];
+        let outcome = Match::new(players, 11).with_best_of(5).play();
+        assert!(outcome.games.len() >= 3);
+        for pair in outcome.games.windows(2) {
+            assert_ne!(pair[0].first_player, pair[1].first_player);
+            assert_ne!(pair[0].seed, pair[1].seed);
+        }
+    }
+
+    #[test]
+    fn test_stronger_player_wins_match() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(EndTurnPlayer { deck: deck_a }),
+            Box::new(AttachAttackPlayer { deck: deck_b }),
+        ];
+        let outcome = Match::new(players, 3).play();
+        assert_eq!(outcome.winner, Some(1));
+        assert_eq!(outcome.wins, [0, 2]);
+        assert_eq!(outcome.games.len(), 2);
+    }
+
+    #[test]
+    fn test_match_reports_clocks() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer { deck: deck_a }),
+            Box::new(RandomPlayer { deck: deck_b }),
+        ];
+        let time_control = TimeControl::new(std::time::Duration::from_secs(60));
+        let outcome = Match::new(players, 5)
+            .with_time_control(time_control)
+            .play();
+        for game in &outcome.games {
+            let clock = game.clock.as_ref().unwrap();
+            assert_eq!(clock.forfeited, None);
+            assert!(clock.decisions.iter().sum::<u32>() > 0);
+        }
+    }
+}
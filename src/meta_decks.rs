@@ -0,0 +1,93 @@
+use crate::Deck;
+
+/// Name (the file name in `meta_decks`) and contents of every list.
+const DECK_FILES: &[(&str, &str)] = &[
+    ("pikachu-ex", include_str!("../meta_decks/pikachu-ex.txt")),
+    (
+        "mewtwo-gardevoir",
+        include_str!("../meta_decks/mewtwo-gardevoir.txt"),
+    ),
+    (
+        "charizard-moltres",
+        include_str!("../meta_decks/charizard-moltres.txt"),
+    ),
+    (
+        "celebi-exeggutor",
+        include_str!("../meta_decks/celebi-exeggutor.txt"),
+    ),
+    (
+        "misty-starmie",
+        include_str!("../meta_decks/misty-starmie.txt"),
+    ),
+    (
+        "darkrai-arbok",
+        include_str!("../meta_decks/darkrai-arbok.txt"),
+    ),
+];
+
+/// A decklist of a popular archetype, bundled with the crate (see the `meta_decks` folder)
+/// so that optimizers and tournaments have a standard gauntlet, e.g.
+/// `Gauntlet::meta(100, players)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaDeck {
+    pub name: &'static str,
+    pub deck: Deck,
+}
+
+/// Every bundled deck.
+pub fn all() -> Vec<MetaDeck> {
+    DECK_FILES
+        .iter()
+        .map(|(name, contents)| MetaDeck {
+            name,
+            deck: parse(name, contents),
+        })
+        .collect()
+}
+
+/// The bundled deck with this name, e.g. "pikachu-ex".
+pub fn get(name: &str) -> Option<Deck> {
+    DECK_FILES
+        .iter()
+        .find(|(x, _)| *x == name)
+        .map(|(name, contents)| parse(name, contents))
+}
+
+fn parse(name: &str, contents: &str) -> Deck {
+    Deck::from_string(contents).unwrap_or_else(|err| panic!("Meta deck {name} is invalid: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deck_generation::is_implemented, optimize::Gauntlet, players::PlayerCode, DeckFormat,
+    };
+
+    #[test]
+    fn test_meta_decks_are_legal_and_playable() {
+        let decks = all();
+        assert_eq!(decks.len(), DECK_FILES.len());
+        for meta_deck in &decks {
+            assert_eq!(
+                meta_deck.deck.validate(&DeckFormat::default()),
+                Ok(()),
+                "{}",
+                meta_deck.name
+            );
+            for card in &meta_deck.deck.cards {
+                assert!(is_implemented(card), "{} in {}", card, meta_deck.name);
+            }
+        }
+        assert_eq!(get("pikachu-ex"), Some(decks[0].deck.clone()));
+        assert_eq!(get("unknown"), None);
+    }
+
+    #[test]
+    fn test_meta_gauntlet() {
+        let gauntlet = Gauntlet::meta(2, vec![PlayerCode::R, PlayerCode::R]);
+        assert_eq!(gauntlet.enemy_decks.len(), DECK_FILES.len());
+        let win_rate = gauntlet.win_rate(&get("pikachu-ex").unwrap(), 0);
+        assert!((0.0..=1.0).contains(&win_rate));
+    }
+}
@@ -1,13 +1,20 @@
-use crate::{actions::SimpleAction, hooks::contains_energy, State};
+use crate::{
+    actions::SimpleAction,
+    hooks::{can_use_attack, contains_energy},
+    State,
+};
 
 pub(crate) fn generate_attack_actions(state: &State) -> Vec<SimpleAction> {
-    if state.turn_count <= 1 {
+    if !state.rules.can_attack(state.turn_count) {
         return vec![];
     }
 
     let current_player = state.current_player;
     let mut actions = Vec::new();
     if let Some(active_pokemon) = &state.in_play_pokemon[current_player][0] {
+        if !active_pokemon.can_attack_or_retreat() {
+            return vec![];
+        }
         active_pokemon
             .get_attacks()
             .iter()
@@ -16,7 +23,8 @@ pub(crate) fn generate_attack_actions(state: &State) -> Vec<SimpleAction> {
                 if contains_energy(
                     active_pokemon.attached_energy.as_slice(),
                     &attack.energy_required,
-                ) {
+                ) && can_use_attack(active_pokemon, i)
+                {
                     actions.push(SimpleAction::Attack(i));
                 }
             });
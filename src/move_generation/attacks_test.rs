@@ -26,6 +26,22 @@ mod tests {
         assert_eq!(actions.len(), 0, "Should not generate attacks on turn 1");
     }
 
+    #[test]
+    fn test_first_turn_attack_rule() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 1;
+        state.rules.first_turn_attack = true;
+
+        let card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut played_card = to_playable_card(&card, false);
+        played_card.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        state.in_play_pokemon[state.current_player][0] = Some(played_card);
+
+        let actions = generate_attack_actions(&state);
+        assert_eq!(actions, vec![SimpleAction::Attack(0)]);
+    }
+
     #[test]
     fn test_no_attacks_without_active_pokemon() {
         let (deck_a, deck_b) = load_test_decks();
@@ -260,4 +276,36 @@ mod tests {
         assert!(actions_sufficient.len() >= actions_insufficient.len(), 
             "More energy should enable more or equal attacks");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_asleep_or_paralyzed_pokemon_cannot_attack_or_retreat() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 3;
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.attached_energy = vec![EnergyType::Grass; 2];
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&bulbasaur, false));
+        let attacks_or_retreats = |state: &State| {
+            let (_, actions) = crate::generate_possible_actions(state);
+            actions
+                .into_iter()
+                .filter(|x| matches!(x.action, SimpleAction::Attack(_) | SimpleAction::Retreat(_)))
+                .count()
+        };
+        assert_eq!(attacks_or_retreats(&state), 2);
+
+        state.get_active_mut(0).asleep = true;
+        assert_eq!(attacks_or_retreats(&state), 0);
+        state.get_active_mut(0).asleep = false;
+        state.get_active_mut(0).paralyzed = true;
+        assert_eq!(attacks_or_retreats(&state), 0);
+
+        // Other conditions don't stop it
+        state.get_active_mut(0).paralyzed = false;
+        state.get_active_mut(0).poisoned = true;
+        state.get_active_mut(0).burned = true;
+        assert_eq!(attacks_or_retreats(&state), 2);
+    }
+}
@@ -5,13 +5,15 @@ mod move_generation_trainer;
 #[cfg(test)]
 mod attacks_test;
 #[cfg(test)]
+mod setup_test;
+#[cfg(test)]
 mod trainer_test;
 #[cfg(test)]
 mod abilities_test;
 
 use crate::actions::{Action, SimpleAction};
-use crate::hooks::{can_retreat, contains_energy, get_retreat_cost};
-use crate::state::State;
+use crate::hooks::{can_evolve, can_retreat, contains_energy, get_retreat_cost};
+use crate::state::{State, MAX_IN_PLAY};
 use crate::types::Card;
 
 use attacks::generate_attack_actions;
@@ -28,15 +30,12 @@ pub use move_generation_trainer::generate_possible_trainer_actions;
 pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     let in_initial_setup_phase = state.turn_count == 0;
     if in_initial_setup_phase {
-        let possible_actions = generate_initial_setup_actions(state)
-            .iter()
-            .map(|action| Action {
-                actor: state.current_player,
-                action: action.clone(),
-                is_stack: false,
-            })
-            .collect();
-        return (state.current_player, possible_actions);
+        // The player going first sets up first
+        let actor = [state.current_player, (state.current_player + 1) % 2]
+            .into_iter()
+            .find(|x| state.in_play_pokemon[*x][0].is_none())
+            .unwrap_or(state.current_player);
+        return (actor, generate_setup_actions(state, actor));
     }
 
     // If there are moves in the generation stack, short-circuit to that
@@ -60,8 +59,9 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     let hand_actions = generate_hand_actions(state);
     actions.extend(hand_actions);
 
-    // Maybe attach energy to in play cards
-    if let Some(energy) = state.current_energy {
+    // Maybe attach energy to in play cards (the player going first gets none on turn 1)
+    let can_attach_energy = state.rules.can_attach_turn_energy(state.turn_count);
+    if let Some(energy) = state.current_energy.filter(|_| can_attach_energy) {
         state.in_play_pokemon[current_player]
             .iter()
             .enumerate()
@@ -75,9 +75,11 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
             })
     }
 
-    // Maybe retreat pokemon
+    // Maybe retreat pokemon (fossils and Asleep or Paralyzed Pokémon can't)
     if let Some(card) = &state.in_play_pokemon[current_player][0] {
         if can_retreat(state)
+            && !card.card.is_fossil()
+            && card.can_attack_or_retreat()
             && contains_energy(
                 card.attached_energy.as_slice(),
                 &get_retreat_cost(state, card),
@@ -91,7 +93,7 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
         }
     }
 
-    // Maybe attack (only starting on turn 2)
+    // Maybe attack (only starting on turn 2, see GameRules)
     let attack_actions = generate_attack_actions(state);
     actions.extend(attack_actions);
 
@@ -110,33 +112,40 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     (current_player, possible_actions)
 }
 
-fn generate_initial_setup_actions(state: &State) -> Vec<SimpleAction> {
-    let current_player = state.current_player;
-    let hand_actions = generate_hand_actions(state);
-    if state.in_play_pokemon[current_player][0].is_none() {
-        let place_active_actions: Vec<SimpleAction> = hand_actions
-            .iter()
-            .filter(|x| matches!(x, SimpleAction::Place(_, 0)))
-            .cloned()
-            .collect();
-        place_active_actions
-    } else {
-        let mut actions = Vec::new();
-        let place_bench_actions: Vec<SimpleAction> = hand_actions
-            .iter()
-            .filter(|x| {
-                if let SimpleAction::Place(_, position) = x {
-                    *position != 0
-                } else {
-                    false
-                }
-            })
-            .cloned()
-            .collect();
-        actions.extend(place_bench_actions);
-        actions.push(SimpleAction::EndTurn);
-        actions
+/// The opening boards the player can set up (see `SimpleAction::Setup`): any Basic Pokémon
+/// of their hand as the Active Pokémon, with any of the others on the Bench. Boards with
+/// the same cards are only listed once. Since players set up without seeing each other's
+/// board, the choices don't depend on the opponent's setup.
+pub(crate) fn generate_setup_actions(state: &State, player: usize) -> Vec<Action> {
+    let hand = &state.hands[player];
+    let basics: Vec<usize> = (0..hand.len()).filter(|x| hand[*x].is_basic()).collect();
+    let mut boards: Vec<(String, Vec<String>)> = vec![];
+    let mut actions = vec![];
+    for &active in &basics {
+        let others: Vec<usize> = basics.iter().copied().filter(|x| *x != active).collect();
+        for subset in 0..1usize << others.len() {
+            if subset.count_ones() as usize > state.rules.bench_size {
+                continue;
+            }
+            let bench: Vec<usize> = (0..others.len())
+                .filter(|x| subset & (1 << x) != 0)
+                .map(|x| others[x])
+                .collect();
+            let mut bench_ids: Vec<String> = bench.iter().map(|x| hand[*x].get_id()).collect();
+            bench_ids.sort();
+            let board = (hand[active].get_id(), bench_ids);
+            if boards.contains(&board) {
+                continue;
+            }
+            boards.push(board);
+            actions.push(Action {
+                actor: player,
+                action: SimpleAction::Setup { active, bench },
+                is_stack: false,
+            });
+        }
     }
+    actions
 }
 
 fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
@@ -153,31 +162,17 @@ fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
                         .iter()
                         .enumerate()
                         .for_each(|(i, x)| {
-                            if x.is_none() {
+                            if x.is_none() && state.rules.is_usable_slot(i) {
                                 actions.push(SimpleAction::Place(hand_card.clone(), i));
                             }
                         });
                 } else {
-                    // Evolutions can only be played if previous stage
-                    // is there, and wasn't played this turn, and isn't the first 2 turns.
-                    if state.turn_count <= 2 {
-                        return;
-                    }
-                    // For each non-zero stage pokemon in hand, check if the evolves_from is in play
-                    // if so, add evolve action
-                    if let Some(evolves_from) = &pokemon_card.evolves_from {
-                        state.in_play_pokemon[current_player]
-                            .iter()
-                            .enumerate()
-                            .for_each(|(i, x)| {
-                                if let Some(pokemon) = x {
-                                    if !pokemon.played_this_turn
-                                        && pokemon.get_name() == *evolves_from
-                                    {
-                                        actions.push(SimpleAction::Evolve(hand_card.clone(), i));
-                                    }
-                                }
-                            });
+                    // Evolutions can only be played on the Pokémon they evolve from,
+                    // if it wasn't played this turn and it isn't the first 2 turns.
+                    for i in 0..MAX_IN_PLAY {
+                        if can_evolve(state, current_player, hand_card, i) {
+                            actions.push(SimpleAction::Evolve(hand_card.clone(), i));
+                        }
                     }
                 }
             }
@@ -2,8 +2,9 @@ use crate::{
     actions::SimpleAction,
     card_ids::CardId,
     database::get_card_by_enum,
-    hooks::can_play_support,
-    types::{EnergyType, TrainerCard, TrainerType},
+    hooks::{can_play_support, is_trainer_type_blocked},
+    state::MAX_IN_PLAY,
+    types::{Card, EnergyType, TrainerCard, TrainerType},
     State,
 };
 
@@ -13,11 +14,14 @@ use crate::{
 /// WASM module can do "feature detection", and know if a card is implemented.
 pub fn generate_possible_trainer_actions(
     state: &State,
-    trainer_card: &TrainerCard,
+    trainer_card: &'static TrainerCard,
 ) -> Option<Vec<SimpleAction>> {
     if trainer_card.trainer_card_type == TrainerType::Supporter && !can_play_support(state) {
         return Some(vec![]); // dont even check which type it is
     }
+    if is_trainer_type_blocked(state, state.current_player, &trainer_card.trainer_card_type) {
+        return Some(vec![]);
+    }
 
     // Pokemon tools can be played if there is a space in the mat for them.
     if trainer_card.trainer_card_type == TrainerType::Tool {
@@ -27,13 +31,26 @@ pub fn generate_possible_trainer_actions(
             .count();
         if in_play_without_tools > 0 {
             return Some(vec![SimpleAction::Play {
-                trainer_card: trainer_card.clone(),
+                trainer_card,
             }]);
         } else {
             return Some(vec![]);
         }
     }
 
+    // Fossils are put into play like Basic Pokémon
+    let card = Card::Trainer(trainer_card);
+    if card.is_fossil() {
+        let player = state.current_player;
+        let places = (0..MAX_IN_PLAY)
+            .filter(|&i| {
+                state.in_play_pokemon[player][i].is_none() && state.rules.is_usable_slot(i)
+            })
+            .map(|i| SimpleAction::Place(card.clone(), i))
+            .collect();
+        return Some(places);
+    }
+
     let trainer_id = match CardId::from_numeric_id(trainer_card.numeric_id) {
         Some(id) => id,
         None => return None, // Unimplemented trainer card
@@ -47,7 +64,7 @@ pub fn generate_possible_trainer_actions(
                 .count();
             if damaged_count > 0 {
                 Some(vec![SimpleAction::Play {
-                    trainer_card: trainer_card.clone(),
+                    trainer_card,
                 }])
             } else {
                 Some(vec![])
@@ -61,7 +78,7 @@ pub fn generate_possible_trainer_actions(
                 .count();
             if damaged_grass_count > 0 {
                 Some(vec![SimpleAction::Play {
-                    trainer_card: trainer_card.clone(),
+                    trainer_card,
                 }])
             } else {
                 Some(vec![])
@@ -73,7 +90,7 @@ pub fn generate_possible_trainer_actions(
                 state.num_in_play_of_type(state.current_player, EnergyType::Water);
             if water_in_player_count > 0 {
                 Some(vec![SimpleAction::Play {
-                    trainer_card: trainer_card.clone(),
+                    trainer_card,
                 }])
             } else {
                 Some(vec![])
@@ -90,7 +107,7 @@ pub fn generate_possible_trainer_actions(
                 ];
                 if kogable_cards.contains(&played_card.card) {
                     return Some(vec![SimpleAction::Play {
-                        trainer_card: trainer_card.clone(),
+                        trainer_card,
                     }]);
                 }
             }
@@ -102,7 +119,7 @@ pub fn generate_possible_trainer_actions(
             let opponent_has_bench = state.enumerate_bench_pokemon(opponent).count() > 0;
             if opponent_has_bench {
                 Some(vec![SimpleAction::Play {
-                    trainer_card: trainer_card.clone(),
+                    trainer_card,
                 }])
             } else {
                 Some(vec![])
@@ -117,12 +134,48 @@ pub fn generate_possible_trainer_actions(
                 .count();
             if damaged_bench_count > 0 {
                 Some(vec![SimpleAction::Play {
-                    trainer_card: trainer_card.clone(),
+                    trainer_card,
                 }])
             } else {
                 Some(vec![])
             }
         }
+        CardId::A2146PokemonCommunication => {
+            // There must be a Pokemon in hand to switch
+            let has_pokemon_in_hand = state.hands[state.current_player]
+                .iter()
+                .any(|x| matches!(x, Card::Pokemon(_)));
+            if has_pokemon_in_hand {
+                Some(vec![SimpleAction::Play { trainer_card }])
+            } else {
+                Some(vec![])
+            }
+        }
+        CardId::A2153Volkner | CardId::A2193Volkner => {
+            // Needs an Electivire or Luxray in play, and Lightning Energy in the discard pile
+            let player = state.current_player;
+            let has_target = state
+                .enumerate_in_play_pokemon(player)
+                .any(|(_, x)| ["Electivire", "Luxray"].contains(&x.get_name().as_str()));
+            let has_energy = state.discarded_energy[player].contains_key(&EnergyType::Lightning);
+            if has_target && has_energy {
+                Some(vec![SimpleAction::Play { trainer_card }])
+            } else {
+                Some(vec![])
+            }
+        }
+        CardId::A2154Dawn | CardId::A2194Dawn => {
+            // Needs an Active Pokemon and a Benched one with Energy to move
+            let player = state.current_player;
+            let has_bench_energy = state
+                .enumerate_bench_pokemon(player)
+                .any(|(_, x)| !x.attached_energy.is_empty());
+            if state.in_play_pokemon[player][0].is_some() && has_bench_energy {
+                Some(vec![SimpleAction::Play { trainer_card }])
+            } else {
+                Some(vec![])
+            }
+        }
         // These can always be played (support check already done)
         CardId::PA002XSpeed
         | CardId::PA005PokeBall
@@ -132,8 +185,14 @@ pub fn generate_possible_trainer_actions(
         | CardId::A1270Giovanni
         | CardId::A1a065MythicalSlab
         | CardId::A1a068Leaf
-        | CardId::A1a082Leaf => Some(vec![SimpleAction::Play {
-            trainer_card: trainer_card.clone(),
+        | CardId::A1a082Leaf
+        | CardId::A2151TeamGalacticGrunt
+        | CardId::A2191TeamGalacticGrunt
+        | CardId::A2152Cynthia
+        | CardId::A2192Cynthia
+        | CardId::A2155Mars
+        | CardId::A2195Mars => Some(vec![SimpleAction::Play {
+            trainer_card,
         }]),
         _ => None,
     }
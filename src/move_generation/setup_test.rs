@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::super::{generate_possible_actions, generate_setup_actions};
+    use crate::{
+        actions::{apply_action, SimpleAction},
+        card_ids::CardId,
+        state::TurnPhase,
+        test_helpers::ScenarioBuilder,
+        GameRules, State,
+    };
+
+    fn setup_state() -> State {
+        ScenarioBuilder::new()
+            .hand(
+                0,
+                [
+                    CardId::A1001Bulbasaur,
+                    CardId::A1002Ivysaur,
+                    CardId::A1001Bulbasaur,
+                    CardId::A1033Charmander,
+                    CardId::PA001Potion,
+                ],
+            )
+            .hand(1, [CardId::A1053Squirtle, CardId::PA001Potion])
+            .turn(0)
+            .current_player(1)
+            .build()
+    }
+
+    fn boards(state: &State, player: usize) -> Vec<SimpleAction> {
+        generate_setup_actions(state, player)
+            .into_iter()
+            .map(|x| x.action)
+            .collect()
+    }
+
+    #[test]
+    fn test_setup_boards() {
+        let state = setup_state();
+        let setup = |active, bench: &[usize]| SimpleAction::Setup {
+            active,
+            bench: bench.to_vec(),
+        };
+        // Both Bulbasaurs make the same boards, only the first one is listed
+        assert_eq!(
+            boards(&state, 0),
+            vec![
+                setup(0, &[]),
+                setup(0, &[2]),
+                setup(0, &[3]),
+                setup(0, &[2, 3]),
+                setup(3, &[]),
+                setup(3, &[0]),
+                setup(3, &[0, 2]),
+            ]
+        );
+        assert_eq!(boards(&state, 1), vec![setup(0, &[])]);
+
+        // Bench size limits the boards
+        let mut state = state;
+        state.rules = GameRules {
+            bench_size: 1,
+            ..Default::default()
+        };
+        assert!(!boards(&state, 0).contains(&setup(0, &[2, 3])));
+        assert!(boards(&state, 0).contains(&setup(3, &[0])));
+    }
+
+    #[test]
+    fn test_setup_starts_game_with_player_going_first() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = setup_state();
+        let (actor, actions) = generate_possible_actions(&state);
+        assert_eq!(actor, 1);
+        apply_action(&mut rng, &mut state, &actions[0]);
+        assert_eq!(state.turn_count, 0);
+        assert_eq!(state.current_player, 1);
+
+        let (actor, actions) = generate_possible_actions(&state);
+        assert_eq!(actor, 0);
+        let action = actions
+            .into_iter()
+            .find(|x| {
+                x.action
+                    == SimpleAction::Setup {
+                        active: 3,
+                        bench: vec![0, 2],
+                    }
+            })
+            .unwrap();
+        apply_action(&mut rng, &mut state, &action);
+        let names: Vec<String> = state
+            .enumerate_in_play_pokemon(0)
+            .map(|(_, x)| x.get_name())
+            .collect();
+        assert_eq!(names, ["Charmander", "Bulbasaur", "Bulbasaur"]);
+        assert_eq!(state.hands[0].len(), 2);
+        assert_eq!(state.turn_count, 1);
+        assert_eq!(state.current_player, 1);
+        assert_eq!(state.phase, TurnPhase::Draw);
+    }
+}
@@ -11,7 +11,7 @@ mod tests {
         State,
     };
 
-    fn get_trainer_card(card_id: CardId) -> TrainerCard {
+    fn get_trainer_card(card_id: CardId) -> &'static TrainerCard {
         match get_card_by_enum(card_id) {
             Card::Trainer(trainer) => trainer,
             _ => panic!("Expected trainer card"),
@@ -28,7 +28,7 @@ mod tests {
         
         // Try to play any supporter card
         let giovanni = get_trainer_card(CardId::A1223Giovanni);
-        let actions = generate_possible_trainer_actions(&state, &giovanni);
+        let actions = generate_possible_trainer_actions(&state, giovanni);
         
         assert!(actions.is_some(), "Should return Some even when blocked");
         assert_eq!(actions.unwrap().len(), 0, "Should not allow supporter when already played");
@@ -44,7 +44,7 @@ mod tests {
         
         // Try to play supporter card
         let giovanni = get_trainer_card(CardId::A1223Giovanni);
-        let actions = generate_possible_trainer_actions(&state, &giovanni);
+        let actions = generate_possible_trainer_actions(&state, giovanni);
         
         assert!(actions.is_some());
         assert_eq!(actions.unwrap().len(), 1, "Should allow supporter when not played");
@@ -59,7 +59,7 @@ mod tests {
         let tool_card = get_trainer_card(CardId::A2147GiantCape);
         
         // No Pokemon in play
-        let actions_no_pokemon = generate_possible_trainer_actions(&state, &tool_card);
+        let actions_no_pokemon = generate_possible_trainer_actions(&state, tool_card);
         assert_eq!(actions_no_pokemon.unwrap().len(), 0, "No Pokemon to attach tool to");
         
         // Add Pokemon without tool
@@ -67,7 +67,7 @@ mod tests {
         state.in_play_pokemon[state.current_player][0] = Some(to_playable_card(&card, true));
         
         // Should allow tool attachment now
-        let actions_with_pokemon = generate_possible_trainer_actions(&state, &tool_card);
+        let actions_with_pokemon = generate_possible_trainer_actions(&state, tool_card);
         assert_eq!(actions_with_pokemon.unwrap().len(), 1, "Should allow tool attachment");
     }
 
@@ -79,14 +79,14 @@ mod tests {
         let potion = get_trainer_card(CardId::PA001Potion);
         
         // No Pokemon in play
-        let actions_no_pokemon = generate_possible_trainer_actions(&state, &potion);
+        let actions_no_pokemon = generate_possible_trainer_actions(&state, potion);
         assert_eq!(actions_no_pokemon.unwrap().len(), 0, "No Pokemon to heal");
         
         // Add healthy Pokemon
         let card = get_card_by_enum(CardId::A1001Bulbasaur);
         state.in_play_pokemon[state.current_player][0] = Some(to_playable_card(&card, true));
         
-        let actions_healthy = generate_possible_trainer_actions(&state, &potion);
+        let actions_healthy = generate_possible_trainer_actions(&state, potion);
         assert_eq!(actions_healthy.unwrap().len(), 0, "Healthy Pokemon doesn't need potion");
         
         // Damage the Pokemon
@@ -94,7 +94,7 @@ mod tests {
             pokemon.apply_damage(20);
         }
         
-        let actions_damaged = generate_possible_trainer_actions(&state, &potion);
+        let actions_damaged = generate_possible_trainer_actions(&state, potion);
         assert_eq!(actions_damaged.unwrap().len(), 1, "Should allow potion for damaged Pokemon");
     }
 
@@ -111,7 +111,7 @@ mod tests {
         played_card.apply_damage(20);
         state.in_play_pokemon[state.current_player][0] = Some(played_card);
         
-        let actions_wrong_type = generate_possible_trainer_actions(&state, &erika);
+        let actions_wrong_type = generate_possible_trainer_actions(&state, erika);
         assert_eq!(actions_wrong_type.unwrap().len(), 0, "Fire Pokemon can't use Erika");
         
         // Replace with damaged Grass Pokemon
@@ -120,7 +120,7 @@ mod tests {
         played_card.apply_damage(20);
         state.in_play_pokemon[state.current_player][0] = Some(played_card);
         
-        let actions_grass = generate_possible_trainer_actions(&state, &erika);
+        let actions_grass = generate_possible_trainer_actions(&state, erika);
         assert_eq!(actions_grass.unwrap().len(), 1, "Damaged Grass Pokemon can use Erika");
     }
 
@@ -132,24 +132,100 @@ mod tests {
         let misty = get_trainer_card(CardId::A1220Misty);
         
         // No Pokemon in play
-        let actions_no_pokemon = generate_possible_trainer_actions(&state, &misty);
+        let actions_no_pokemon = generate_possible_trainer_actions(&state, misty);
         assert_eq!(actions_no_pokemon.unwrap().len(), 0, "No Water Pokemon for Misty");
         
         // Add non-Water Pokemon
         let fire_card = get_card_by_enum(CardId::A1033Charmander);
         state.in_play_pokemon[state.current_player][0] = Some(to_playable_card(&fire_card, true));
         
-        let actions_wrong_type = generate_possible_trainer_actions(&state, &misty);
+        let actions_wrong_type = generate_possible_trainer_actions(&state, misty);
         assert_eq!(actions_wrong_type.unwrap().len(), 0, "Fire Pokemon can't use Misty");
         
         // Add Water Pokemon
         let water_card = get_card_by_enum(CardId::A1053Squirtle);
         state.in_play_pokemon[state.current_player][1] = Some(to_playable_card(&water_card, true));
         
-        let actions_water = generate_possible_trainer_actions(&state, &misty);
+        let actions_water = generate_possible_trainer_actions(&state, misty);
         assert_eq!(actions_water.unwrap().len(), 1, "Water Pokemon can use Misty");
     }
 
+    #[test]
+    fn test_pokemon_communication_requires_pokemon_in_hand() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+
+        let communication = get_trainer_card(CardId::A2146PokemonCommunication);
+        state.hands[0] = vec![get_card_by_enum(CardId::PA001Potion)];
+        let actions_no_pokemon = generate_possible_trainer_actions(&state, communication);
+        assert_eq!(
+            actions_no_pokemon.unwrap().len(),
+            0,
+            "No Pokemon in hand to switch"
+        );
+
+        state.hands[0].push(get_card_by_enum(CardId::A1001Bulbasaur));
+        let actions_pokemon = generate_possible_trainer_actions(&state, communication);
+        assert_eq!(
+            actions_pokemon.unwrap().len(),
+            1,
+            "Pokemon in hand can be switched"
+        );
+    }
+
+    #[test]
+    fn test_volkner_requires_target_and_discarded_lightning_energy() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+
+        let volkner = get_trainer_card(CardId::A2153Volkner);
+        let luxray = get_card_by_enum(CardId::A2060Luxray);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&luxray, false));
+        let actions_no_energy = generate_possible_trainer_actions(&state, volkner);
+        assert_eq!(
+            actions_no_energy.unwrap().len(),
+            0,
+            "No Lightning Energy in the discard pile"
+        );
+
+        state.discarded_energy[0].insert(EnergyType::Lightning, 1);
+        let actions_energy = generate_possible_trainer_actions(&state, volkner);
+        assert_eq!(actions_energy.unwrap().len(), 1, "Luxray can use Volkner");
+
+        let pikachu = get_card_by_enum(CardId::A1094Pikachu);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&pikachu, false));
+        let actions_wrong_pokemon = generate_possible_trainer_actions(&state, volkner);
+        assert_eq!(
+            actions_wrong_pokemon.unwrap().len(),
+            0,
+            "Only Electivire and Luxray can use Volkner"
+        );
+    }
+
+    #[test]
+    fn test_dawn_requires_benched_pokemon_with_energy() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+
+        let dawn = get_trainer_card(CardId::A2154Dawn);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[0][2] = Some(to_playable_card(&bulbasaur, false));
+        let actions_no_energy = generate_possible_trainer_actions(&state, dawn);
+        assert_eq!(
+            actions_no_energy.unwrap().len(),
+            0,
+            "No Energy on the Bench to move"
+        );
+
+        state.in_play_pokemon[0][2]
+            .as_mut()
+            .unwrap()
+            .attached_energy = vec![EnergyType::Grass];
+        let actions_energy = generate_possible_trainer_actions(&state, dawn);
+        assert_eq!(actions_energy.unwrap().len(), 1, "Can move Bench Energy");
+    }
+
     #[test]
     fn test_koga_requires_specific_active_pokemon() {
         let (deck_a, deck_b) = load_test_decks();
@@ -158,28 +234,28 @@ mod tests {
         let koga = get_trainer_card(CardId::A1222Koga);
         
         // No active Pokemon
-        let actions_no_active = generate_possible_trainer_actions(&state, &koga);
+        let actions_no_active = generate_possible_trainer_actions(&state, koga);
         assert_eq!(actions_no_active.unwrap().len(), 0, "No active Pokemon for Koga");
         
         // Wrong active Pokemon
         let wrong_card = get_card_by_enum(CardId::A1001Bulbasaur);
         state.in_play_pokemon[state.current_player][0] = Some(to_playable_card(&wrong_card, true));
         
-        let actions_wrong = generate_possible_trainer_actions(&state, &koga);
+        let actions_wrong = generate_possible_trainer_actions(&state, koga);
         assert_eq!(actions_wrong.unwrap().len(), 0, "Wrong Pokemon can't use Koga");
         
         // Correct active Pokemon (Weezing)
         let weezing_card = get_card_by_enum(CardId::A1177Weezing);
         state.in_play_pokemon[state.current_player][0] = Some(to_playable_card(&weezing_card, true));
         
-        let actions_weezing = generate_possible_trainer_actions(&state, &koga);
+        let actions_weezing = generate_possible_trainer_actions(&state, koga);
         assert_eq!(actions_weezing.unwrap().len(), 1, "Weezing can use Koga");
         
         // Also test with Muk
         let muk_card = get_card_by_enum(CardId::A1175Muk);
         state.in_play_pokemon[state.current_player][0] = Some(to_playable_card(&muk_card, true));
         
-        let actions_muk = generate_possible_trainer_actions(&state, &koga);
+        let actions_muk = generate_possible_trainer_actions(&state, koga);
         assert_eq!(actions_muk.unwrap().len(), 1, "Muk can use Koga");
     }
 
@@ -192,14 +268,14 @@ mod tests {
         let opponent = (state.current_player + 1) % 2;
         
         // No opponent bench
-        let actions_no_bench = generate_possible_trainer_actions(&state, &sabrina);
+        let actions_no_bench = generate_possible_trainer_actions(&state, sabrina);
         assert_eq!(actions_no_bench.unwrap().len(), 0, "Can't use Sabrina without opponent bench");
         
         // Add opponent bench Pokemon
         let card = get_card_by_enum(CardId::A1001Bulbasaur);
         state.in_play_pokemon[opponent][1] = Some(to_playable_card(&card, true));
         
-        let actions_with_bench = generate_possible_trainer_actions(&state, &sabrina);
+        let actions_with_bench = generate_possible_trainer_actions(&state, sabrina);
         assert_eq!(actions_with_bench.unwrap().len(), 1, "Can use Sabrina with opponent bench");
     }
 
@@ -212,14 +288,14 @@ mod tests {
         let opponent = (state.current_player + 1) % 2;
         
         // No opponent bench
-        let actions_no_bench = generate_possible_trainer_actions(&state, &cyrus);
+        let actions_no_bench = generate_possible_trainer_actions(&state, cyrus);
         assert_eq!(actions_no_bench.unwrap().len(), 0, "Can't use Cyrus without opponent bench");
         
         // Add healthy opponent bench Pokemon
         let card = get_card_by_enum(CardId::A1001Bulbasaur);
         state.in_play_pokemon[opponent][1] = Some(to_playable_card(&card, true));
         
-        let actions_healthy = generate_possible_trainer_actions(&state, &cyrus);
+        let actions_healthy = generate_possible_trainer_actions(&state, cyrus);
         assert_eq!(actions_healthy.unwrap().len(), 0, "Can't use Cyrus on healthy bench");
         
         // Damage the bench Pokemon
@@ -227,7 +303,7 @@ mod tests {
             pokemon.apply_damage(30);
         }
         
-        let actions_damaged = generate_possible_trainer_actions(&state, &cyrus);
+        let actions_damaged = generate_possible_trainer_actions(&state, cyrus);
         assert_eq!(actions_damaged.unwrap().len(), 1, "Can use Cyrus on damaged bench");
     }
 
@@ -246,11 +322,17 @@ mod tests {
             CardId::A1a065MythicalSlab,
             CardId::A1a068Leaf,
             CardId::A1a082Leaf,
+            CardId::A2151TeamGalacticGrunt,
+            CardId::A2191TeamGalacticGrunt,
+            CardId::A2152Cynthia,
+            CardId::A2192Cynthia,
+            CardId::A2155Mars,
+            CardId::A2195Mars,
         ];
         
         for card_id in always_playable {
             let trainer = get_trainer_card(card_id);
-            let actions = generate_possible_trainer_actions(&state, &trainer);
+            let actions = generate_possible_trainer_actions(&state, trainer);
             
             assert!(actions.is_some(), "Card {:?} should be implemented", card_id);
             assert_eq!(actions.unwrap().len(), 1, "Card {:?} should always be playable", card_id);
@@ -263,10 +345,11 @@ mod tests {
         let state = State::new(&deck_a, &deck_b);
         
         // Create a fake trainer card with unimplemented ID
-        let mut unimplemented_trainer = get_trainer_card(CardId::PA001Potion);
+        let mut unimplemented_trainer = get_trainer_card(CardId::PA001Potion).clone();
         unimplemented_trainer.numeric_id = 9999; // Assuming this doesn't exist
+        let unimplemented_trainer = Box::leak(Box::new(unimplemented_trainer));
         
-        let actions = generate_possible_trainer_actions(&state, &unimplemented_trainer);
+        let actions = generate_possible_trainer_actions(&state, unimplemented_trainer);
         assert!(actions.is_none(), "Unimplemented trainer should return None");
     }
 
@@ -276,7 +359,7 @@ mod tests {
         let state = State::new(&deck_a, &deck_b);
         
         let giovanni = get_trainer_card(CardId::A1223Giovanni);
-        let actions = generate_possible_trainer_actions(&state, &giovanni).unwrap();
+        let actions = generate_possible_trainer_actions(&state, giovanni).unwrap();
         
         assert_eq!(actions.len(), 1);
         match &actions[0] {
@@ -302,7 +385,7 @@ mod tests {
             state.in_play_pokemon[state.current_player][i] = Some(pokemon);
         }
         
-        let actions = generate_possible_trainer_actions(&state, &potion);
+        let actions = generate_possible_trainer_actions(&state, potion);
         assert_eq!(actions.unwrap().len(), 1, "Should still only generate one Play action");
     }
 
@@ -322,7 +405,7 @@ mod tests {
         state.in_play_pokemon[state.current_player][0] = Some(played_card);
         
         // The actual behavior depends on has_tool_attached() implementation
-        let actions = generate_possible_trainer_actions(&state, &tool_card);
+        let actions = generate_possible_trainer_actions(&state, tool_card);
         assert!(actions.is_some(), "Should return Some for tool cards");
     }
 
@@ -343,7 +426,41 @@ mod tests {
         state.in_play_pokemon[opponent][1] = Some(to_playable_card(&card, true));
         
         // Should still be blocked by supporter check
-        let actions = generate_possible_trainer_actions(&state, &sabrina);
+        let actions = generate_possible_trainer_actions(&state, sabrina);
         assert_eq!(actions.unwrap().len(), 0, "Supporter check should happen before other checks");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fossils_are_placed_like_basics() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 3;
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[0][2] = Some(to_playable_card(&bulbasaur, false));
+
+        let dome_fossil = get_trainer_card(CardId::A1217DomeFossil);
+        let actions = generate_possible_trainer_actions(&state, dome_fossil).unwrap();
+        let card = Card::Trainer(dome_fossil);
+        assert_eq!(
+            actions,
+            vec![
+                SimpleAction::Place(card.clone(), 1),
+                SimpleAction::Place(card.clone(), 3)
+            ]
+        );
+
+        // Once in play, it can't attack nor retreat, but can be evolved from
+        state.in_play_pokemon[0].swap(0, 1);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&card, false));
+        state.get_active_mut(0).attached_energy = vec![EnergyType::Fighting; 3];
+        let kabuto = get_card_by_enum(CardId::A1158Kabuto);
+        state.hands[0] = vec![kabuto.clone()];
+        let (_, actions) = crate::generate_possible_actions(&state);
+        let actions: Vec<_> = actions.into_iter().map(|x| x.action).collect();
+        assert!(actions.contains(&SimpleAction::Evolve(kabuto, 0)));
+        assert!(!actions
+            .iter()
+            .any(|x| matches!(x, SimpleAction::Attack(_) | SimpleAction::Retreat(_))));
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+
+use rand::rngs::StdRng;
+
+use crate::{
+    card_ids::{CardId, ALL_CARD_IDS},
+    game_rules::GameRules,
+    hidden_information::HiddenKnowledge,
+    masked_state::MaskedState,
+    players::{determinize, ArchetypeModel},
+    state::MAX_IN_PLAY,
+    types::{Card, EnergyType, PlayedCard},
+    State,
+};
+
+pub const NUM_CARDS: usize = ALL_CARD_IDS.len();
+pub(crate) const NUM_ENERGY_TYPES: usize = 10;
+pub(crate) const NUM_SLOTS: usize = MAX_IN_PLAY;
+
+pub const GLOBAL_FEATURES: usize = 4 + NUM_ENERGY_TYPES;
+pub const SLOT_FEATURES: usize = 3 + NUM_ENERGY_TYPES + 9 + NUM_CARDS;
+pub const PLAYER_FEATURES: usize = 4 + NUM_SLOTS * SLOT_FEATURES;
+pub const OBSERVATION_SIZE: usize = GLOBAL_FEATURES + 2 * PLAYER_FEATURES + NUM_CARDS;
+
+/// Position of the card in `ALL_CARD_IDS` (and in the card one-hots).
+pub fn card_index(card: &Card) -> Option<usize> {
+    CardId::from_card_id(&card.get_id()).map(|x| x as usize)
+}
+
+impl State {
+    /// Encodes the part of the state visible to `player` in a fixed layout, for machine
+    /// learning. Always `OBSERVATION_SIZE` long, laid out as:
+    ///
+    /// | Offset | Size | Features |
+    /// |---|---|---|
+    /// | 0 | `GLOBAL_FEATURES` | turn count, is it our turn, support played, retreated, current energy one-hot (`EnergyType` order) |
+    /// | `GLOBAL_FEATURES` | `PLAYER_FEATURES` | our side (see below) |
+    /// | `GLOBAL_FEATURES + PLAYER_FEATURES` | `PLAYER_FEATURES` | opponent's side |
+    /// | `OBSERVATION_SIZE - NUM_CARDS` | `NUM_CARDS` | copies of each card in our hand (`ALL_CARD_IDS` order) |
+    ///
+    /// Each side is its points, hand size, deck size and discard pile size, followed by
+    /// `SLOT_FEATURES` for each of the `NUM_SLOTS` in-play slots (active first, then the
+    /// bench up to `MAX_BENCH_SIZE`, so that the layout doesn't depend on the rules):
+    /// occupied, remaining HP, total HP, attached energy count per `EnergyType`, has a tool,
+    /// poisoned, paralyzed, asleep, burned, confused, played this turn, ability used, is ex,
+    /// then the card one-hot (`ALL_CARD_IDS` order).
+    ///
+    /// Values are raw counts (not normalized). Hidden information (the opponent's hand,
+    /// both decks' order) is not encoded, other than sizes.
+    pub fn encode_observation(&self, player: usize) -> Vec<f32> {
+        let mut features = Vec::with_capacity(OBSERVATION_SIZE);
+        features.push(self.turn_count as f32);
+        features.push(flag(self.current_player == player));
+        features.push(flag(self.has_played_support));
+        features.push(flag(self.has_retreated));
+        let mut energy = [0.0; NUM_ENERGY_TYPES];
+        if let Some(energy_type) = self.current_energy {
+            energy[energy_type as usize] = 1.0;
+        }
+        features.extend(energy);
+
+        for side in [player, (player + 1) % 2] {
+            features.push(self.points[side] as f32);
+            features.push(self.hands[side].len() as f32);
+            features.push(self.decks[side].cards.len() as f32);
+            features.push(self.discard_piles[side].len() as f32);
+            for slot in &self.in_play_pokemon[side] {
+                encode_slot(&mut features, slot.as_ref());
+            }
+        }
+
+        let mut hand = vec![0.0; NUM_CARDS];
+        for card in &self.hands[player] {
+            if let Some(index) = card_index(card) {
+                hand[index] += 1.0;
+            }
+        }
+        features.extend(hand);
+        debug_assert_eq!(features.len(), OBSERVATION_SIZE);
+        features
+    }
+}
+
+/// Read-only view of what one player can see of a state, handed to `FairPlayer`s.
+/// Unlike `MaskedState` it borrows the state, so building one is free.
+#[derive(Debug, Clone, Copy)]
+pub struct Observation<'a> {
+    state: &'a State,
+    player: usize,
+}
+
+impl<'a> Observation<'a> {
+    pub fn new(state: &'a State, player: usize) -> Self {
+        Self { state, player }
+    }
+
+    /// The player observing.
+    pub fn player(&self) -> usize {
+        self.player
+    }
+
+    pub fn hand(&self) -> &'a [Card] {
+        &self.state.hands[self.player]
+    }
+
+    pub fn hand_sizes(&self) -> [usize; 2] {
+        [self.state.hands[0].len(), self.state.hands[1].len()]
+    }
+
+    pub fn deck_sizes(&self) -> [usize; 2] {
+        [
+            self.state.decks[0].cards.len(),
+            self.state.decks[1].cards.len(),
+        ]
+    }
+
+    pub fn in_play_pokemon(&self) -> &'a [[Option<PlayedCard>; MAX_IN_PLAY]; 2] {
+        &self.state.in_play_pokemon
+    }
+
+    pub fn discard_piles(&self) -> &'a [Vec<Card>; 2] {
+        &self.state.discard_piles
+    }
+
+    pub fn discarded_energy(&self) -> &'a [BTreeMap<EnergyType, u32>; 2] {
+        &self.state.discarded_energy
+    }
+
+    pub fn points(&self) -> [u8; 2] {
+        self.state.points
+    }
+
+    pub fn turn_count(&self) -> u8 {
+        self.state.turn_count
+    }
+
+    pub fn current_player(&self) -> usize {
+        self.state.current_player
+    }
+
+    pub fn current_energy(&self) -> Option<EnergyType> {
+        self.state.current_energy
+    }
+
+    pub fn rules(&self) -> &'a GameRules {
+        &self.state.rules
+    }
+
+    /// Owned copy of everything visible, e.g. to send to a client.
+    pub fn masked(&self) -> MaskedState {
+        self.state.masked_for(self.player)
+    }
+
+    /// What is known about the cards not seen yet.
+    pub fn knowledge(&self) -> HiddenKnowledge {
+        HiddenKnowledge::from_state(self.state)
+    }
+
+    /// A copy of the state with the hidden cards dealt again from the decklists (see
+    /// `determinize`), to simulate actions without peeking at the real ones.
+    pub fn determinized(&self, rng: &mut StdRng) -> State {
+        determinize(&ArchetypeModel::new(vec![]), rng, self.state, self.player)
+    }
+
+    /// See `State::debug_string_for`.
+    pub fn debug_string(&self) -> String {
+        self.state.debug_string_for(self.player)
+    }
+
+    /// See `State::encode_observation`.
+    pub fn encode(&self) -> Vec<f32> {
+        self.state.encode_observation(self.player)
+    }
+}
+
+fn encode_slot(features: &mut Vec<f32>, slot: Option<&PlayedCard>) {
+    let start = features.len();
+    features.resize(start + SLOT_FEATURES, 0.0);
+    let Some(played_card) = slot else {
+        return;
+    };
+    let slot_features = &mut features[start..];
+    slot_features[0] = 1.0;
+    slot_features[1] = played_card.remaining_hp as f32;
+    slot_features[2] = played_card.total_hp as f32;
+    for energy_type in &played_card.attached_energy {
+        slot_features[3 + *energy_type as usize] += 1.0;
+    }
+    let flags = [
+        played_card.attached_tool.is_some(),
+        played_card.poisoned,
+        played_card.paralyzed,
+        played_card.asleep,
+        played_card.burned,
+        played_card.confused,
+        played_card.played_this_turn,
+        played_card.ability_used,
+        played_card.card.is_ex(),
+    ];
+    for (i, value) in flags.into_iter().enumerate() {
+        slot_features[3 + NUM_ENERGY_TYPES + i] = flag(value);
+    }
+    if let Some(index) = card_index(&played_card.card) {
+        slot_features[3 + NUM_ENERGY_TYPES + 9 + index] = 1.0;
+    }
+}
+
+fn flag(value: bool) -> f32 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::get_card_by_enum,
+        hooks::to_playable_card,
+        test_helpers::{load_test_decks, ScenarioBuilder},
+        types::EnergyType,
+    };
+
+    #[test]
+    fn test_debug_string_hides_the_opponent_hand() {
+        let state = ScenarioBuilder::new()
+            .active(0, CardId::A1001Bulbasaur, [])
+            .active(1, CardId::A1001Bulbasaur, [])
+            .hand(0, [CardId::A1002Ivysaur])
+            .hand(1, [CardId::A1033Charmander])
+            .build();
+        let text = Observation::new(&state, 0).debug_string();
+        assert!(text.contains("Ivysaur"));
+        assert!(!text.contains("Charmander"));
+        assert!(text.ends_with("P2 Hand:\t1 cards"));
+    }
+
+    #[test]
+    fn test_encode_observation() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut played_card = to_playable_card(&bulbasaur, false);
+        played_card.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        played_card.remaining_hp = 50;
+        played_card.poisoned = true;
+        state.in_play_pokemon[0][0] = Some(played_card);
+        state.hands[0] = vec![bulbasaur.clone(), bulbasaur.clone()];
+        state.hands[1] = vec![get_card_by_enum(CardId::A1033Charmander)];
+        state.points = [1, 2];
+        state.current_player = 1;
+
+        let observation = state.encode_observation(0);
+        assert_eq!(observation.len(), OBSERVATION_SIZE);
+        assert_eq!(observation[1], 0.0);
+        let me = GLOBAL_FEATURES;
+        assert_eq!(observation[me..me + 2], [1.0, 2.0]);
+        let active = me + 4;
+        assert_eq!(observation[active..active + 3], [1.0, 50.0, 70.0]);
+        assert_eq!(observation[active + 3 + EnergyType::Grass as usize], 2.0);
+        assert_eq!(observation[active + 3 + NUM_ENERGY_TYPES + 1], 1.0);
+        assert_eq!(observation[active + 3 + NUM_ENERGY_TYPES + 9], 1.0);
+        let hand = OBSERVATION_SIZE - NUM_CARDS;
+        assert_eq!(observation[hand + CardId::A1001Bulbasaur as usize], 2.0);
+        // The opponent's hand is only visible as a size
+        assert_eq!(observation[hand..].iter().sum::<f32>(), 2.0);
+
+        let opponent_view = state.encode_observation(1);
+        assert_eq!(opponent_view[1], 1.0);
+        assert_eq!(opponent_view[me..me + 2], [2.0, 1.0]);
+        assert_eq!(
+            opponent_view[me + PLAYER_FEATURES + 4..me + PLAYER_FEATURES + 4 + SLOT_FEATURES],
+            observation[active..active + SLOT_FEATURES]
+        );
+
+        // The last bench slot of the biggest bench is encoded too
+        let last_slot = MAX_IN_PLAY - 1;
+        state.in_play_pokemon[0][last_slot] = Some(to_playable_card(&bulbasaur, false));
+        let observation = state.encode_observation(0);
+        assert_eq!(observation[active + last_slot * SLOT_FEATURES], 1.0);
+    }
+}
@@ -0,0 +1,239 @@
+use std::{collections::HashMap, thread};
+
+use log::{info, warn};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    card_query::CardQuery,
+    database::query,
+    deck_generation::DeckConstraints,
+    types::{Card, EnergyType},
+    Deck,
+};
+
+use super::Gauntlet;
+
+/// Parameters of `anneal`. Temperatures are in win rate, so a temperature of 0.05 accepts
+/// a swap losing 5% of win rate with probability 1/e.
+#[derive(Debug, Clone)]
+pub struct AnnealingSchedule {
+    pub initial_temperature: f64,
+    /// Factor applied to the temperature after every step.
+    pub cooling: f64,
+    pub steps: usize,
+    /// Cards that can be swapped in. By default, Pokémon of the deck's energy types (or
+    /// Colorless) and every trainer.
+    pub constraints: DeckConstraints,
+    pub seed: u64,
+}
+
+impl Default for AnnealingSchedule {
+    fn default() -> Self {
+        Self {
+            initial_temperature: 0.05,
+            cooling: 0.95,
+            steps: 100,
+            constraints: DeckConstraints::default(),
+            seed: 0,
+        }
+    }
+}
+
+impl AnnealingSchedule {
+    /// Never accepts a worse deck: plain hill climbing.
+    pub fn hill_climbing(steps: usize, seed: u64) -> Self {
+        Self {
+            initial_temperature: 0.0,
+            steps,
+            seed,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AnnealingResult {
+    pub best_deck: Deck,
+    pub best_win_rate: f32,
+    /// Win rate of the current deck after each step.
+    pub history: Vec<f32>,
+    /// Steps whose swap was kept.
+    pub accepted: usize,
+}
+
+/// Refines a deck by swapping one card at a time, keeping better decks and, while the
+/// temperature is high, sometimes worse ones to get out of local optima.
+///
+/// Each step plays the current deck and its neighbor with the same seeds, so that their
+/// win rates are compared on the same games.
+pub fn anneal(
+    start_deck: &Deck,
+    gauntlet: &Gauntlet,
+    schedule: &AnnealingSchedule,
+) -> AnnealingResult {
+    let mut rng = StdRng::seed_from_u64(schedule.seed);
+    let pool = swap_pool(start_deck, &schedule.constraints);
+
+    let mut current = start_deck.clone();
+    let mut current_win_rate = gauntlet.win_rate(&current, rng.gen());
+    let mut best = (current.clone(), current_win_rate);
+    let mut temperature = schedule.initial_temperature;
+    let mut history = vec![];
+    let mut accepted = 0;
+    for step in 0..schedule.steps {
+        let Some(neighbor) = swap_one_card(&current, &pool, &schedule.constraints, &mut rng) else {
+            warn!("No card can be swapped, stopping.");
+            break;
+        };
+        let seed = rng.gen();
+        let (win_rate, neighbor_win_rate) = thread::scope(|scope| {
+            let handle = scope.spawn(|| gauntlet.win_rate(&current, seed));
+            let neighbor_win_rate = gauntlet.win_rate(&neighbor, seed);
+            let win_rate = handle.join().expect("Simulation thread panicked");
+            (win_rate, neighbor_win_rate)
+        });
+        current_win_rate = win_rate;
+
+        let delta = (neighbor_win_rate - win_rate) as f64;
+        if delta >= 0.0 || (temperature > 0.0 && rng.gen::<f64>() < (delta / temperature).exp()) {
+            current = neighbor;
+            current_win_rate = neighbor_win_rate;
+            accepted += 1;
+        }
+        if current_win_rate > best.1 {
+            best = (current.clone(), current_win_rate);
+        }
+        history.push(current_win_rate);
+        info!(
+            "Step {}: win rate {:.2}% (best {:.2}%), temperature {:.4}",
+            step,
+            current_win_rate * 100.0,
+            best.1 * 100.0,
+            temperature
+        );
+        temperature *= schedule.cooling;
+    }
+
+    warn!(
+        "Best deck ({:.2}% win rate, {} of {} swaps kept):\n{}",
+        best.1 * 100.0,
+        accepted,
+        history.len(),
+        best.0
+    );
+    AnnealingResult {
+        best_deck: best.0,
+        best_win_rate: best.1,
+        history,
+        accepted,
+    }
+}
+
+/// Cards allowed by the constraints, restricted to the deck's energy types when the
+/// constraints don't choose one.
+pub(super) fn swap_pool(deck: &Deck, constraints: &DeckConstraints) -> Vec<Card> {
+    query(CardQuery::new())
+        .filter(|card| constraints.allows(card))
+        .filter(|card| match (card, constraints.energy_type) {
+            (Card::Pokemon(pokemon_card), None) => {
+                pokemon_card.energy_type == EnergyType::Colorless
+                    || deck.energy_types.contains(&pokemon_card.energy_type)
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+/// The deck with one card replaced by one of the pool, keeping it legal (at most 2 copies,
+/// a Basic Pokémon, and what every Pokémon evolves from) and within the deck-wide
+/// constraints. None if no swap was found.
+fn swap_one_card(
+    deck: &Deck,
+    pool: &[Card],
+    constraints: &DeckConstraints,
+    rng: &mut impl Rng,
+) -> Option<Deck> {
+    for _ in 0..100 {
+        let index = rng.gen_range(0..deck.cards.len());
+        let card = pool.choose(rng)?;
+        if card.get_name() == deck.cards[index].get_name() {
+            continue;
+        }
+        let mut cards = deck.cards.clone();
+        cards[index] = card.clone();
+        if is_legal_swap(&cards) && constraints.validate(&cards).is_ok() {
+            return Some(Deck {
+                cards,
+                energy_types: deck.energy_types.clone(),
+            });
+        }
+    }
+    None
+}
+
+pub(super) fn is_legal_swap(cards: &[Card]) -> bool {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for card in cards {
+        *counts.entry(card.get_name()).or_default() += 1;
+    }
+    counts.values().all(|x| *x <= 2)
+        && cards.iter().any(|x| x.is_basic())
+        && cards.iter().all(|card| match card {
+            Card::Pokemon(pokemon_card) => pokemon_card
+                .evolves_from
+                .as_ref()
+                .is_none_or(|x| counts.contains_key(x)),
+            Card::Trainer(_) => true,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{deck::DeckFormat, players::PlayerCode};
+
+    #[test]
+    fn test_swaps_keep_decks_legal() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let pool = swap_pool(&deck, &DeckConstraints::default());
+        assert!(pool.iter().all(|x| x.get_type() != Some(EnergyType::Fire)));
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut current = deck.clone();
+        for _ in 0..50 {
+            let neighbor =
+                swap_one_card(&current, &pool, &DeckConstraints::default(), &mut rng).unwrap();
+            assert_eq!(neighbor.validate(&DeckFormat::default()), Ok(()));
+            let differences = neighbor
+                .cards
+                .iter()
+                .zip(&current.cards)
+                .filter(|(a, b)| a != b)
+                .count();
+            assert_eq!(differences, 1);
+            assert!(is_legal_swap(&neighbor.cards));
+            current = neighbor;
+        }
+    }
+
+    #[test]
+    fn test_anneal() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let gauntlet = Gauntlet::new(
+            vec![Deck::from_file("example_decks/weezing-arbok.txt").unwrap()],
+            4,
+            vec![PlayerCode::R, PlayerCode::R],
+        );
+        let schedule = AnnealingSchedule {
+            steps: 5,
+            ..Default::default()
+        };
+        let result = anneal(&deck, &gauntlet, &schedule);
+        assert_eq!(result.history.len(), 5);
+        assert!(result.best_win_rate >= result.history.iter().cloned().fold(0.0, f32::max));
+        assert_eq!(result.best_deck.validate(&DeckFormat::default()), Ok(()));
+
+        // Hill climbing is annealing at zero temperature
+        let result = anneal(&deck, &gauntlet, &AnnealingSchedule::hill_climbing(5, 0));
+        assert_eq!(result.history.len(), 5);
+    }
+}
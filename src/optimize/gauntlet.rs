@@ -0,0 +1,146 @@
+use std::{fs, thread};
+
+use log::warn;
+use num_format::{Locale, ToFormattedString};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    players::{create_players, PlayerCode},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// A set of opponent decks to measure candidate decks against.
+#[derive(Debug, Clone)]
+pub struct Gauntlet {
+    pub enemy_decks: Vec<Deck>,
+    /// Games played against each enemy deck.
+    pub games_per_deck: u32,
+    /// Player codes for (candidate deck, enemy deck).
+    pub players: Vec<PlayerCode>,
+}
+
+impl Gauntlet {
+    pub fn new(enemy_decks: Vec<Deck>, games_per_deck: u32, players: Vec<PlayerCode>) -> Self {
+        Self {
+            enemy_decks,
+            games_per_deck,
+            players,
+        }
+    }
+
+    /// Builds a gauntlet out of every valid deck file in the folder.
+    pub fn from_folder(folder: &str, games_per_deck: u32, players: Vec<PlayerCode>) -> Self {
+        Self::new(load_enemy_decks(folder), games_per_deck, players)
+    }
+
+    /// Builds a gauntlet out of the bundled meta decks (see `meta_decks`).
+    #[cfg(feature = "meta-decks")]
+    pub fn meta(games_per_deck: u32, players: Vec<PlayerCode>) -> Self {
+        let decks = crate::meta_decks::all()
+            .into_iter()
+            .map(|x| x.deck)
+            .collect();
+        Self::new(decks, games_per_deck, players)
+    }
+
+    /// Win rate (between 0 and 1) of the deck across all games against the gauntlet.
+    pub fn win_rate(&self, deck: &Deck, seed: u64) -> f32 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut wins = 0;
+        let mut games = 0;
+        for enemy_deck in &self.enemy_decks {
+            for _ in 0..self.games_per_deck {
+                let players =
+                    create_players(deck.clone(), enemy_deck.clone(), self.players.clone());
+                let mut game = Game::new(players, rng.gen());
+                if let Some(GameOutcome::Win(0)) = game.play() {
+                    wins += 1;
+                }
+                games += 1;
+            }
+        }
+        if games == 0 {
+            return 0.0;
+        }
+        wins as f32 / games as f32
+    }
+
+    /// Games played by each deck measured against the gauntlet.
+    pub fn games(&self) -> u32 {
+        self.enemy_decks.len() as u32 * self.games_per_deck
+    }
+
+    /// Like `win_rate`, for many decks at once, spread across all available cores.
+    pub fn win_rates(&self, decks: &[Deck], seed: u64) -> Vec<f32> {
+        self.win_rates_by(decks, |i| seed.wrapping_add(i as u64))
+    }
+
+    /// Like `win_rates`, but every deck plays with the same seed, so that differences
+    /// between similar decks aren't drowned in the luck of the draw.
+    pub fn paired_win_rates(&self, decks: &[Deck], seed: u64) -> Vec<f32> {
+        self.win_rates_by(decks, |_| seed)
+    }
+
+    fn win_rates_by(&self, decks: &[Deck], seed_of: impl Fn(usize) -> u64 + Sync) -> Vec<f32> {
+        let num_threads = thread::available_parallelism().map_or(1, |x| x.get());
+        let chunk_size = decks.len().div_ceil(num_threads).max(1);
+        let seed_of = &seed_of;
+        thread::scope(|scope| {
+            let handles: Vec<_> = decks
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(i, chunk)| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .enumerate()
+                            .map(|(j, deck)| self.win_rate(deck, seed_of(i * chunk_size + j)))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|x| x.join().expect("Simulation thread panicked"))
+                .collect()
+        })
+    }
+}
+
+/// Reads all 20-card decks in the folder, skipping (and warning about) the rest.
+pub(crate) fn load_enemy_decks(folder: &str) -> Vec<Deck> {
+    let enemy_deck_paths: Vec<String> = fs::read_dir(folder)
+        .expect("Failed to read enemy decks folder")
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if entry.path().is_file() {
+                Some(entry.path().to_str()?.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    let enemy_valid_decks: Vec<Deck> = enemy_deck_paths
+        .iter()
+        .filter_map(|path| {
+            let deck = Deck::from_file(path).ok()?;
+            if deck.cards.len() == 20 {
+                Some(deck)
+            } else {
+                warn!("Skipping enemy deck {} since not valid", path);
+                None
+            }
+        })
+        .collect();
+    warn!(
+        "Found {} enemy deck files ({} valid). {:?}",
+        enemy_deck_paths.len().to_formatted_string(&Locale::en),
+        enemy_valid_decks.len(),
+        enemy_deck_paths
+            .iter()
+            .map(|s| s.split('/').next_back().unwrap())
+            .collect::<Vec<_>>()
+    );
+    enemy_valid_decks
+}
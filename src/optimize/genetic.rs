@@ -0,0 +1,450 @@
+use std::fs;
+
+use indexmap::IndexMap;
+use log::{info, warn};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    card_ids::CardId,
+    database::get_card_by_enum,
+    deck_generation::{DeckBuilder, DeckConstraints},
+    types::{Card, EnergyType},
+    Deck,
+};
+
+use super::Gauntlet;
+
+/// Parameters of the genetic deck search (see `evolve`).
+#[derive(Debug, Clone)]
+pub struct GeneticConfig {
+    pub population_size: usize,
+    pub max_generations: usize,
+    /// Best decks carried over unchanged to the next generation.
+    pub elite_count: usize,
+    /// Decks competing in each tournament when selecting parents.
+    pub tournament_size: usize,
+    /// Probability of mutating a child after crossover.
+    pub mutation_rate: f64,
+    /// Stop after this many generations without improving the best win rate.
+    pub patience: usize,
+    /// Cards the decks can be made of. Evolution lines are always kept complete.
+    pub constraints: DeckConstraints,
+    /// If set, the search is saved here after every generation (see `resume`).
+    pub checkpoint_path: Option<String>,
+    /// Continue the search saved at `checkpoint_path`, if there is one, instead of
+    /// starting over. The resumed run plays out exactly as if it was never interrupted.
+    pub resume: bool,
+    pub seed: u64,
+}
+
+impl Default for GeneticConfig {
+    fn default() -> Self {
+        Self {
+            population_size: 24,
+            max_generations: 30,
+            elite_count: 2,
+            tournament_size: 3,
+            mutation_rate: 0.5,
+            patience: 5,
+            constraints: DeckConstraints::default(),
+            checkpoint_path: None,
+            resume: false,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GeneticResult {
+    pub best_deck: Deck,
+    pub best_win_rate: f32,
+    pub generations: usize,
+    /// Best win rate of each generation.
+    pub history: Vec<f32>,
+}
+
+/// Searches the whole deck space with a genetic algorithm: starting from random decks,
+/// each generation keeps the elite and breeds the rest out of tournament-selected parents.
+/// Fitness is the win rate against the gauntlet, simulated in parallel.
+pub fn evolve(gauntlet: &Gauntlet, config: &GeneticConfig) -> GeneticResult {
+    assert!(config.population_size > config.elite_count);
+    let constraints = DeckConstraints {
+        complete_evolution_lines: true,
+        ..config.constraints.clone()
+    };
+
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut first_generation = 0;
+    let mut population: Vec<Deck>;
+    let mut best: Option<(Deck, f32)> = None;
+    let mut history = vec![];
+    let mut stale_generations = 0;
+    match config.checkpoint_path.as_deref().filter(|_| config.resume) {
+        Some(path) if fs::exists(path).unwrap_or(false) => {
+            let checkpoint = read_checkpoint(path, config.seed);
+            warn!("Resuming from generation {}", checkpoint.generation);
+            rng = StdRng::seed_from_u64(checkpoint.rng_seed);
+            first_generation = checkpoint.generation;
+            population = checkpoint
+                .population
+                .iter()
+                .map(SavedDeck::to_deck)
+                .collect();
+            best = checkpoint.best.map(|(deck, x)| (deck.to_deck(), x));
+            history = checkpoint.history;
+            stale_generations = checkpoint.stale_generations;
+        }
+        _ => {
+            population = (0..config.population_size)
+                .map(|_| {
+                    Deck::random(&mut rng, &constraints).expect("Constraints should allow a deck")
+                })
+                .collect();
+        }
+    }
+    for generation in first_generation..config.max_generations {
+        if stale_generations >= config.patience {
+            warn!(
+                "No improvement in {} generations, stopping.",
+                stale_generations
+            );
+            break;
+        }
+        let win_rates = gauntlet.win_rates(&population, rng.gen());
+        let mut ranked: Vec<(Deck, f32)> = population.into_iter().zip(win_rates).collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let generation_best = ranked[0].1;
+        history.push(generation_best);
+        warn!(
+            "Generation {}: best win rate {:.2}%",
+            generation,
+            generation_best * 100.0
+        );
+        info!("Best deck:\n{}", ranked[0].0);
+        if best.as_ref().is_none_or(|(_, x)| generation_best > *x) {
+            best = Some(ranked[0].clone());
+            stale_generations = 0;
+        } else {
+            stale_generations += 1;
+        }
+
+        population = ranked
+            .iter()
+            .take(config.elite_count)
+            .map(|(deck, _)| deck.clone())
+            .collect();
+        while population.len() < config.population_size {
+            let parent_a = tournament(&ranked, config.tournament_size, &mut rng);
+            let parent_b = tournament(&ranked, config.tournament_size, &mut rng);
+            let mut child = crossover(parent_a, parent_b, &constraints, &mut rng);
+            if rng.gen_bool(config.mutation_rate) {
+                child = mutate(&child, &constraints, &mut rng);
+            }
+            population.push(child);
+        }
+
+        // Reseeding makes the generator's state a single number to save
+        let rng_seed = rng.gen();
+        rng = StdRng::seed_from_u64(rng_seed);
+        if let Some(path) = &config.checkpoint_path {
+            let checkpoint = Checkpoint {
+                seed: config.seed,
+                generation: generation + 1,
+                rng_seed,
+                population: population.iter().map(SavedDeck::new).collect(),
+                ranked: ranked.iter().map(save_ranked).collect(),
+                best: best.as_ref().map(save_ranked),
+                history: history.clone(),
+                stale_generations,
+            };
+            write_checkpoint(path, &checkpoint);
+        }
+    }
+
+    let (best_deck, best_win_rate) = best.expect("At least one generation should run");
+    GeneticResult {
+        best_deck,
+        best_win_rate,
+        generations: history.len(),
+        history,
+    }
+}
+
+fn tournament<'a>(ranked: &'a [(Deck, f32)], size: usize, rng: &mut impl Rng) -> &'a Deck {
+    let (deck, _) = ranked
+        .choose_multiple(rng, size)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("Population should not be empty");
+    deck
+}
+
+/// Child made of whole evolution lines picked from both parents, topped up with random cards.
+fn crossover(
+    parent_a: &Deck,
+    parent_b: &Deck,
+    constraints: &DeckConstraints,
+    rng: &mut impl Rng,
+) -> Deck {
+    let mut lines = evolution_lines(&parent_a.cards);
+    lines.extend(evolution_lines(&parent_b.cards));
+    lines.shuffle(rng);
+
+    let mut builder = DeckBuilder::new(vec![]);
+    for line in lines.into_iter().take(rng.gen_range(1..=12)) {
+        builder.add_cards(line);
+    }
+    builder
+        .complete(rng, constraints)
+        .unwrap_or_else(|_| parent_a.clone())
+}
+
+/// Replaces one or two whole evolution lines (or trainers) by random cards.
+fn mutate(deck: &Deck, constraints: &DeckConstraints, rng: &mut impl Rng) -> Deck {
+    let mut lines = evolution_lines(&deck.cards);
+    lines.shuffle(rng);
+    let removed = rng.gen_range(1..=2).min(lines.len() - 1);
+    let cards = lines.into_iter().skip(removed).flatten().collect();
+    DeckBuilder::new(cards)
+        .complete(rng, constraints)
+        .unwrap_or_else(|_| deck.clone())
+}
+
+/// Groups cards by the Basic Pokémon their evolution line starts with. Each trainer is
+/// its own group.
+fn evolution_lines(cards: &[Card]) -> Vec<Vec<Card>> {
+    let names: Vec<String> = cards.iter().map(|x| x.get_name()).collect();
+    let line_root = |card: &Card| -> String {
+        let mut current = card.clone();
+        loop {
+            let Card::Pokemon(pokemon_card) = &current else {
+                return current.get_name();
+            };
+            let Some(evolves_from) = &pokemon_card.evolves_from else {
+                return current.get_name();
+            };
+            match names.iter().position(|x| x == evolves_from) {
+                Some(index) => current = cards[index].clone(),
+                None => return current.get_name(),
+            }
+        }
+    };
+
+    let mut lines: IndexMap<String, Vec<Card>> = IndexMap::new();
+    for card in cards {
+        lines.entry(line_root(card)).or_default().push(card.clone());
+    }
+    lines.into_values().collect()
+}
+
+/// Where a search is at between two generations, as saved in its checkpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Checkpoint {
+    /// Seed of the run, to tell the checkpoints of different runs apart.
+    seed: u64,
+    /// Next generation to evaluate.
+    generation: usize,
+    /// Seed of the random generator for the next generation.
+    rng_seed: u64,
+    population: Vec<SavedDeck>,
+    /// The last generation evaluated, best first.
+    ranked: Vec<(SavedDeck, f32)>,
+    best: Option<(SavedDeck, f32)>,
+    history: Vec<f32>,
+    stale_generations: usize,
+}
+
+fn write_checkpoint(path: &str, checkpoint: &Checkpoint) {
+    let contents = serde_json::to_string_pretty(checkpoint).expect("Checkpoints should serialize");
+    if let Err(err) = fs::write(path, contents) {
+        warn!("Failed to write checkpoint {}: {}", path, err);
+    }
+}
+
+// Panics rather than starting over, which would overwrite the checkpoint
+fn read_checkpoint(path: &str, seed: u64) -> Checkpoint {
+    let contents = fs::read_to_string(path).expect("Failed to read checkpoint");
+    let checkpoint: Checkpoint = serde_json::from_str(&contents).expect("Invalid checkpoint");
+    assert_eq!(
+        checkpoint.seed, seed,
+        "Checkpoint {path} is of a run with another seed"
+    );
+    checkpoint
+}
+
+/// A deck as saved in a checkpoint. Unlike decklists, it keeps the order of the cards, so
+/// that a resumed search breeds the very same decks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SavedDeck {
+    cards: Vec<String>,
+    energy_types: Vec<EnergyType>,
+}
+
+impl SavedDeck {
+    fn new(deck: &Deck) -> Self {
+        Self {
+            cards: deck.cards.iter().map(|x| x.get_id()).collect(),
+            energy_types: deck.energy_types.clone(),
+        }
+    }
+
+    fn to_deck(&self) -> Deck {
+        let cards = self
+            .cards
+            .iter()
+            .map(|id| {
+                let card_id = CardId::from_card_id(id).expect("Checkpoint cards should exist");
+                get_card_by_enum(card_id)
+            })
+            .collect();
+        Deck {
+            cards,
+            energy_types: self.energy_types.clone(),
+        }
+    }
+}
+
+fn save_ranked((deck, win_rate): &(Deck, f32)) -> (SavedDeck, f32) {
+    (SavedDeck::new(deck), *win_rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card_ids::CardId, deck::DeckFormat, players::PlayerCode, types::EnergyType};
+
+    #[test]
+    fn test_evolution_lines() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let lines = evolution_lines(&deck.cards);
+        // Bulbasaur line, Exeggcute line, and 6 different trainers
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines.iter().map(|x| x.len()).sum::<usize>(), 20);
+        let bulbasaur_line = &lines[0];
+        assert_eq!(bulbasaur_line.len(), 6);
+    }
+
+    #[test]
+    fn test_operators_keep_decks_legal() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let constraints = DeckConstraints {
+            complete_evolution_lines: true,
+            energy_type: Some(EnergyType::Grass),
+            ..Default::default()
+        };
+        for _ in 0..20 {
+            let parent_a = Deck::random(&mut rng, &constraints).unwrap();
+            let parent_b = Deck::random(&mut rng, &constraints).unwrap();
+            let child = crossover(&parent_a, &parent_b, &constraints, &mut rng);
+            let mutant = mutate(&child, &constraints, &mut rng);
+            for deck in [child, mutant] {
+                assert_eq!(deck.validate(&DeckFormat::default()), Ok(()));
+                for card in &deck.cards {
+                    if let Card::Pokemon(pokemon_card) = card {
+                        if let Some(evolves_from) = &pokemon_card.evolves_from {
+                            assert!(deck.cards.iter().any(|x| x.get_name() == *evolves_from));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_operators_respect_deck_wide_constraints() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let constraints = DeckConstraints {
+            energy_type: Some(EnergyType::Lightning),
+            required_cards: vec![CardId::A1096PikachuEx, CardId::A1096PikachuEx],
+            max_ex: Some(2),
+            rarity_budget: Some(2000),
+            ..Default::default()
+        };
+        for _ in 0..20 {
+            let parent_a = Deck::random(&mut rng, &constraints).unwrap();
+            let parent_b = Deck::random(&mut rng, &constraints).unwrap();
+            let child = crossover(&parent_a, &parent_b, &constraints, &mut rng);
+            let mutant = mutate(&child, &constraints, &mut rng);
+            for deck in [parent_a, child, mutant] {
+                assert_eq!(constraints.validate(&deck.cards), Ok(()));
+                assert_eq!(deck.energy_types, vec![EnergyType::Lightning]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evolve() {
+        let gauntlet = Gauntlet::new(
+            vec![Deck::from_file("example_decks/weezing-arbok.txt").unwrap()],
+            2,
+            vec![PlayerCode::R, PlayerCode::R],
+        );
+        let checkpoint = std::env::temp_dir().join("deckgym_test_evolve_checkpoint.txt");
+        let config = GeneticConfig {
+            population_size: 6,
+            max_generations: 3,
+            patience: 10,
+            checkpoint_path: Some(checkpoint.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let result = evolve(&gauntlet, &config);
+        assert_eq!(result.generations, 3);
+        assert_eq!(
+            result.best_win_rate,
+            result.history.iter().cloned().fold(0.0, f32::max)
+        );
+        assert_eq!(result.best_deck.validate(&DeckFormat::default()), Ok(()));
+
+        let contents = fs::read_to_string(&checkpoint).unwrap();
+        let saved: Checkpoint = serde_json::from_str(&contents).unwrap();
+        assert_eq!(saved.generation, 3);
+        assert_eq!(saved.ranked.len(), 6);
+        assert_eq!(saved.history, result.history);
+        fs::remove_file(checkpoint).unwrap();
+    }
+
+    #[test]
+    fn test_resume_plays_out_like_an_uninterrupted_run() {
+        let gauntlet = Gauntlet::new(
+            vec![Deck::from_file("example_decks/weezing-arbok.txt").unwrap()],
+            2,
+            vec![PlayerCode::R, PlayerCode::R],
+        );
+        let checkpoint = std::env::temp_dir().join("deckgym_test_resume_checkpoint.json");
+        let _ = fs::remove_file(&checkpoint);
+        let config = GeneticConfig {
+            population_size: 4,
+            max_generations: 3,
+            patience: 10,
+            checkpoint_path: Some(checkpoint.to_str().unwrap().to_string()),
+            resume: true,
+            seed: 7,
+            ..Default::default()
+        };
+        let uninterrupted = evolve(
+            &gauntlet,
+            &GeneticConfig {
+                checkpoint_path: None,
+                ..config.clone()
+            },
+        );
+
+        // Interrupted after the first generation
+        evolve(
+            &gauntlet,
+            &GeneticConfig {
+                max_generations: 1,
+                ..config.clone()
+            },
+        );
+        let resumed = evolve(&gauntlet, &config);
+        assert_eq!(resumed.history, uninterrupted.history);
+        assert_eq!(resumed.best_deck, uninterrupted.best_deck);
+
+        let other_run = GeneticConfig { seed: 8, ..config };
+        let result = std::panic::catch_unwind(|| evolve(&gauntlet, &other_run));
+        assert!(result.is_err());
+        fs::remove_file(checkpoint).unwrap();
+    }
+}
@@ -1,17 +1,32 @@
-use std::{collections::HashMap, fs, time::Duration};
+use std::{collections::HashMap, time::Duration};
 
 use log::warn;
-use num_format::{Locale, ToFormattedString};
+
+mod annealing;
+mod gauntlet;
+mod genetic;
+mod swaps;
+
+pub use annealing::{anneal, AnnealingResult, AnnealingSchedule};
+pub use gauntlet::Gauntlet;
+pub use genetic::{evolve, GeneticConfig, GeneticResult};
+pub use swaps::{suggest_swaps, suggest_swaps_from, SwapSuggestion};
+
+use gauntlet::load_enemy_decks;
 
 use crate::{
     card_ids::CardId,
     database::get_card_by_enum,
+    deck_generation::DeckConstraints,
     players::{create_players, fill_code_array, PlayerCode},
+    progress::{Progress, RunControl},
     state::GameOutcome,
     Deck, Game,
 };
 
 /// Optimizes a deck by simulating games with different combinations of candidate cards.
+/// Candidates the constraints don't allow are left out, and so are completed decks that
+/// break the deck-wide constraints (e.g. too many ex Pokémon).
 pub fn optimize(
     incomplete_deck_path: &str,
     candidate_cards_str: &str,
@@ -19,20 +34,43 @@ pub fn optimize(
     num: u32,
     players: Option<Vec<PlayerCode>>,
     seed: Option<u64>,
+    constraints: &DeckConstraints,
+) {
+    optimize_with(
+        incomplete_deck_path,
+        candidate_cards_str,
+        enemy_decks_folder,
+        num,
+        players,
+        seed,
+        constraints,
+        &mut RunControl::default(),
+    )
+}
+
+/// Like `optimize`, reporting progress (with the current combination's win rate) after
+/// every game and stopping early if cancelled.
+#[allow(clippy::too_many_arguments)]
+pub fn optimize_with(
+    incomplete_deck_path: &str,
+    candidate_cards_str: &str,
+    enemy_decks_folder: &str,
+    num: u32,
+    players: Option<Vec<PlayerCode>>,
+    seed: Option<u64>,
+    constraints: &DeckConstraints,
+    control: &mut RunControl,
 ) {
     // Parse the candidate cards list.
     let candidate_cards: Vec<CardId> = candidate_cards_str
         .split(',')
-        .map(|s| {
-            // take last 3 to be id, then the rest of prefix will be set
-            let s = s.trim();
-            if s.len() < 3 {
-                panic!("Card ID should be at least 3 characters long");
+        .map(|s| parse_card_id(s).expect("Card ID should be valid"))
+        .filter(|card_id| {
+            let allowed = constraints.allows(&get_card_by_enum(*card_id));
+            if !allowed {
+                warn!("Candidate {:?} is not allowed by the constraints", card_id);
             }
-            let number = &s[s.len() - 3..];
-            let prefix = &s[..s.len() - 3];
-            let id = format!("{prefix} {number}");
-            CardId::from_card_id(id.as_str()).expect("Card ID should be valid")
+            allowed
         })
         .collect();
 
@@ -64,38 +102,7 @@ pub fn optimize(
     }
 
     // Read enemy decks from the specified folder.
-    let enemy_deck_paths: Vec<String> = fs::read_dir(enemy_decks_folder)
-        .expect("Failed to read enemy decks folder")
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            if entry.path().is_file() {
-                Some(entry.path().to_str()?.to_string())
-            } else {
-                None
-            }
-        })
-        .collect();
-    let enemy_valid_decks: Vec<Deck> = enemy_deck_paths
-        .iter()
-        .filter_map(|path| {
-            let deck = Deck::from_file(path).ok()?;
-            if deck.cards.len() == 20 {
-                Some(deck)
-            } else {
-                warn!("Skipping enemy deck {} since not valid", path);
-                None
-            }
-        })
-        .collect();
-    warn!(
-        "Found {} enemy deck files ({} valid). {:?}",
-        enemy_deck_paths.len().to_formatted_string(&Locale::en),
-        enemy_valid_decks.len(),
-        enemy_deck_paths
-            .iter()
-            .map(|s| s.split('/').next_back().unwrap())
-            .collect::<Vec<_>>()
-    );
+    let enemy_valid_decks = load_enemy_decks(enemy_decks_folder);
 
     // Generate all valid combinations (multiset selections) of candidate cards that sum to missing_count.
     let combinations = generate_combinations(&candidate_cards, &allowed_map, missing_count as u32);
@@ -107,9 +114,9 @@ pub fn optimize(
 
     // Estimate the time it will take to run all simulations
     let player_codes = fill_code_array(players.clone());
-    let total_games = combinations.len() as u64 * num as u64 * enemy_valid_decks.len() as u64;
+    let planned_games = combinations.len() as u64 * num as u64 * enemy_valid_decks.len() as u64;
     let time_per_game = estimate_time_per_game(&player_codes);
-    let total_time = time_per_game.mul_f64(total_games as f64);
+    let total_time = time_per_game.mul_f64(planned_games as f64);
 
     warn!(
         "Estimated time: {} ({} combinations × {} enemy decks × {} games per deck)",
@@ -129,7 +136,8 @@ pub fn optimize(
     let mut best_win_percent = 0.0;
     let mut best_combination = None;
     let mut results = Vec::new();
-    for comb in combinations {
+    let mut games_completed = 0;
+    'combinations: for comb in combinations {
         // Create a completed deck by cloning the incomplete one and adding the candidate cards.
         let mut completed_deck = incomplete_deck.clone();
         for card_id in &comb {
@@ -144,12 +152,20 @@ pub fn optimize(
             );
             continue;
         }
+        if let Err(err) = constraints.validate(&completed_deck.cards) {
+            warn!("Combination {:?} breaks the constraints: {}", comb, err);
+            continue;
+        }
 
         // Simulate games for each enemy deck.
         let mut total_wins = 0;
         let mut total_games = 0;
         for enemy_deck in &enemy_valid_decks {
             for _ in 0..num {
+                if control.is_cancelled() {
+                    warn!("Cancelled after {} games", games_completed);
+                    break 'combinations;
+                }
                 let players = create_players(
                     completed_deck.clone(),
                     enemy_deck.clone(),
@@ -166,6 +182,12 @@ pub fn optimize(
                     }
                 }
                 total_games += 1;
+                games_completed += 1;
+                control.report(Progress {
+                    games_completed,
+                    total_games: planned_games,
+                    win_rate: total_wins as f64 / total_games as f64,
+                });
             }
         }
 
@@ -193,6 +215,19 @@ pub fn optimize(
 }
 
 /// Estimates time per game based on player types
+/// Parses a card id written without its space, e.g. "A2147" for "A2 147".
+pub fn parse_card_id(s: &str) -> Result<CardId, String> {
+    // take last 3 to be id, then the rest of prefix will be set
+    let s = s.trim();
+    if s.len() < 3 {
+        return Err("Card ID should be at least 3 characters long".to_string());
+    }
+    let number = &s[s.len() - 3..];
+    let prefix = &s[..s.len() - 3];
+    let id = format!("{prefix} {number}");
+    CardId::from_card_id(id.as_str()).ok_or(format!("Unknown card ID: {s}"))
+}
+
 fn estimate_time_per_game(player_codes: &[PlayerCode]) -> Duration {
     let non_r_count = count_player_types(player_codes, false) as u64;
     let r_count = count_player_types(player_codes, true) as u64;
@@ -0,0 +1,187 @@
+use std::fmt;
+
+use log::warn;
+
+use crate::{deck_generation::DeckConstraints, simulate::Z_95, types::Card, Deck};
+
+use super::{
+    annealing::{is_legal_swap, swap_pool},
+    Gauntlet,
+};
+
+/// Replacing one copy of `remove` by `add`, and how it did against the gauntlet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapSuggestion {
+    pub remove: Card,
+    pub add: Card,
+    /// Win rate of the deck after the swap.
+    pub win_rate: f32,
+    /// Win rate gained over the original deck (negative if lost).
+    pub delta: f32,
+    /// Two-proportion z-score of the difference with the original deck.
+    pub z_score: f64,
+    /// Whether the difference is significant at 95%.
+    pub significant: bool,
+}
+
+impl fmt::Display for SwapSuggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "-1 {} ({}), +1 {} ({}): {:.1}% ({:+.1}%, z = {:.2}{})",
+            self.remove.get_name(),
+            self.remove.get_id(),
+            self.add.get_name(),
+            self.add.get_id(),
+            self.win_rate * 100.0,
+            self.delta * 100.0,
+            self.z_score,
+            if self.significant {
+                ", significant"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// The `k` best single-card swaps of the deck, best first. Candidates are Pokémon of the
+/// deck's energy types (or Colorless) and every trainer, see `suggest_swaps_from`.
+pub fn suggest_swaps(deck: &Deck, gauntlet: &Gauntlet, k: usize, seed: u64) -> Vec<SwapSuggestion> {
+    let pool = swap_pool(deck, &DeckConstraints::default());
+    suggest_swaps_from(deck, gauntlet, &pool, k, seed)
+}
+
+/// Tries replacing one copy of each card of the deck by each card of the pool (one
+/// printing per name), keeping the deck legal, and returns the `k` swaps that raise the
+/// win rate the most.
+///
+/// Every deck plays the same seeds as the original one, so that the deltas measure the
+/// swap rather than the luck of the draw.
+pub fn suggest_swaps_from(
+    deck: &Deck,
+    gauntlet: &Gauntlet,
+    pool: &[Card],
+    k: usize,
+    seed: u64,
+) -> Vec<SwapSuggestion> {
+    let mut candidates: Vec<&Card> = vec![];
+    for card in pool {
+        if candidates.iter().all(|x| x.get_name() != card.get_name()) {
+            candidates.push(card);
+        }
+    }
+    let mut removals: Vec<usize> = vec![];
+    for (i, card) in deck.cards.iter().enumerate() {
+        if removals
+            .iter()
+            .all(|x| deck.cards[*x].get_name() != card.get_name())
+        {
+            removals.push(i);
+        }
+    }
+
+    let mut swaps = vec![];
+    let mut decks = vec![deck.clone()];
+    for index in &removals {
+        for card in &candidates {
+            if card.get_name() == deck.cards[*index].get_name() {
+                continue;
+            }
+            let mut cards = deck.cards.clone();
+            cards[*index] = (*card).clone();
+            if is_legal_swap(&cards) {
+                swaps.push((*index, *card));
+                decks.push(Deck {
+                    cards,
+                    energy_types: deck.energy_types.clone(),
+                });
+            }
+        }
+    }
+    warn!("Trying {} swaps...", swaps.len());
+
+    let win_rates = gauntlet.paired_win_rates(&decks, seed);
+    let baseline = win_rates[0];
+    let games = gauntlet.games();
+    let mut suggestions: Vec<SwapSuggestion> = swaps
+        .into_iter()
+        .zip(&win_rates[1..])
+        .map(|((index, card), win_rate)| {
+            let z_score = two_proportion_z(baseline as f64, *win_rate as f64, games);
+            SwapSuggestion {
+                remove: deck.cards[index].clone(),
+                add: card.clone(),
+                win_rate: *win_rate,
+                delta: win_rate - baseline,
+                z_score,
+                significant: z_score.abs() > Z_95,
+            }
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.delta.total_cmp(&a.delta));
+    suggestions.truncate(k);
+    suggestions
+}
+
+// z-score of the difference between two win rates over `games` games each, with a
+// pooled standard error. 0 when there is no variance (e.g. both decks always win).
+fn two_proportion_z(before: f64, after: f64, games: u32) -> f64 {
+    let pooled = (before + after) / 2.0;
+    let standard_error = (pooled * (1.0 - pooled) * 2.0 / games as f64).sqrt();
+    if standard_error == 0.0 || !standard_error.is_finite() {
+        return 0.0;
+    }
+    (after - before) / standard_error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::CardId, database::get_card_by_enum, deck::DeckFormat, players::PlayerCode,
+    };
+
+    #[test]
+    fn test_two_proportion_z() {
+        assert_eq!(two_proportion_z(0.5, 0.5, 100), 0.0);
+        assert_eq!(two_proportion_z(1.0, 1.0, 100), 0.0);
+        // 60% vs 40% over 100 games each is significant, over 10 it isn't
+        assert!(two_proportion_z(0.4, 0.6, 100) > Z_95);
+        assert!(two_proportion_z(0.4, 0.6, 10) < Z_95);
+        assert!(two_proportion_z(0.6, 0.4, 100) < -Z_95);
+    }
+
+    #[test]
+    fn test_suggest_swaps() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let gauntlet = Gauntlet::new(
+            vec![Deck::from_file("example_decks/weezing-arbok.txt").unwrap()],
+            4,
+            vec![PlayerCode::R, PlayerCode::R],
+        );
+        let pool = vec![
+            get_card_by_enum(CardId::A1001Bulbasaur),
+            get_card_by_enum(CardId::A1219Erika),
+            get_card_by_enum(CardId::PA001Potion),
+        ];
+        let suggestions = suggest_swaps_from(&deck, &gauntlet, &pool, 5, 0);
+        assert_eq!(suggestions.len(), 5);
+        assert!(suggestions.windows(2).all(|x| x[0].delta >= x[1].delta));
+        for suggestion in &suggestions {
+            assert_ne!(suggestion.remove.get_name(), suggestion.add.get_name());
+            let mut cards = deck.cards.clone();
+            let index = cards.iter().position(|x| *x == suggestion.remove).unwrap();
+            cards[index] = suggestion.add.clone();
+            let swapped = Deck {
+                cards,
+                energy_types: deck.energy_types.clone(),
+            };
+            assert_eq!(swapped.validate(&DeckFormat::default()), Ok(()));
+        }
+        assert_eq!(
+            suggestions,
+            suggest_swaps_from(&deck, &gauntlet, &pool, 5, 0)
+        );
+    }
+}
@@ -0,0 +1,452 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt,
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    card_ids::CardId,
+    card_query::CardQuery,
+    database::query,
+    deck_generation::{card_rarity, rarity_cost, rarity_rank, RARITIES},
+    sets::SetId,
+    types::Card,
+    Deck,
+};
+
+/// Chance of each rarity of `RARITIES` in a card slot of a pack, adding up to 1.
+pub type RarityOdds = [f64; 9];
+
+/// Pack Points earned with every pack opened.
+pub const PACK_POINTS_PER_PACK: u32 = 5;
+
+/// How the cards of a pack are drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackOdds {
+    /// Rarity odds of each card of a pack, in order.
+    pub slots: Vec<RarityOdds>,
+    /// Chance that a pack is a rare pack, whose cards all use `rare_pack_slot`.
+    pub rare_pack_chance: f64,
+    pub rare_pack_slot: RarityOdds,
+}
+
+impl Default for PackOdds {
+    /// The odds published in the game: three ◊ cards, then a 4th and 5th card that can be
+    /// rarer, and the odd pack full of ☆ and better.
+    fn default() -> Self {
+        let common = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let fourth = [
+            0.0, 0.0, 0.9, 0.05, 0.01666, 0.02572, 0.005, 0.00222, 0.0004,
+        ];
+        let fifth = [0.0, 0.0, 0.6, 0.2, 0.06664, 0.10288, 0.02, 0.00888, 0.0016];
+        Self {
+            slots: vec![common, common, common, fourth, fifth],
+            rare_pack_chance: 0.0005,
+            rare_pack_slot: [0.0, 0.0, 0.0, 0.0, 0.0, 0.5, 0.3, 0.15, 0.05],
+        }
+    }
+}
+
+/// The booster pack of a set: the cards it can hold, by rarity.
+#[derive(Debug, Clone)]
+pub struct BoosterPack {
+    set: SetId,
+    cards_by_rarity: Vec<Vec<Card>>,
+    odds: PackOdds,
+}
+
+impl BoosterPack {
+    /// The pack of the set, with the default odds. None for sets that aren't sold in
+    /// packs, like promos.
+    pub fn new(set: SetId) -> Option<Self> {
+        let mut cards_by_rarity = vec![vec![]; RARITIES.len()];
+        for card in query(CardQuery::new().sets(&[set])) {
+            let rank = rarity_rank(card_rarity(&card));
+            // Promos have no rarity
+            if rank > 0 && rank < RARITIES.len() {
+                cards_by_rarity[rank].push(card);
+            }
+        }
+        if cards_by_rarity.iter().all(|x| x.is_empty()) {
+            return None;
+        }
+        Some(Self {
+            set,
+            cards_by_rarity,
+            odds: PackOdds::default(),
+        })
+    }
+
+    pub fn with_odds(mut self, odds: PackOdds) -> Self {
+        self.odds = odds;
+        self
+    }
+
+    pub fn set(&self) -> SetId {
+        self.set
+    }
+
+    /// Opens a pack. Rarities the set has no card of are never drawn, the odds of the
+    /// others growing in proportion.
+    pub fn open(&self, rng: &mut impl Rng) -> Vec<Card> {
+        let rare_pack = rng.gen::<f64>() < self.odds.rare_pack_chance;
+        (0..self.odds.slots.len())
+            .map(|slot| {
+                let odds = if rare_pack {
+                    &self.odds.rare_pack_slot
+                } else {
+                    &self.odds.slots[slot]
+                };
+                let rarity = self.draw_rarity(odds, rng);
+                self.cards_by_rarity[rarity].choose(rng).unwrap().clone()
+            })
+            .collect()
+    }
+
+    /// A Wonder Pick out of someone else's pack: its cards are shuffled face down, and
+    /// one of them is picked blind.
+    pub fn wonder_pick(&self, rng: &mut impl Rng) -> Card {
+        let mut cards = self.open(rng);
+        cards.swap_remove(rng.gen_range(0..cards.len()))
+    }
+
+    /// Chance that a pack holds at least one copy of the card. 0 if it's not in the pack.
+    pub fn pull_chance(&self, card: &Card) -> f64 {
+        let rank = rarity_rank(card_rarity(card));
+        let Some(cards) = self.cards_by_rarity.get(rank) else {
+            return 0.0;
+        };
+        if !cards.contains(card) {
+            return 0.0;
+        }
+        let miss_chance = |slots: &mut dyn Iterator<Item = &RarityOdds>| -> f64 {
+            slots
+                .map(|odds| 1.0 - self.rarity_odds(odds)[rank] / cards.len() as f64)
+                .product()
+        };
+        let regular = miss_chance(&mut self.odds.slots.iter());
+        let rare = miss_chance(&mut std::iter::repeat_n(
+            &self.odds.rare_pack_slot,
+            self.odds.slots.len(),
+        ));
+        1.0 - (1.0 - self.odds.rare_pack_chance) * regular - self.odds.rare_pack_chance * rare
+    }
+
+    // The odds, left with only the rarities the set has cards of
+    fn rarity_odds(&self, odds: &RarityOdds) -> RarityOdds {
+        let mut odds = *odds;
+        for (rank, cards) in self.cards_by_rarity.iter().enumerate() {
+            if cards.is_empty() {
+                odds[rank] = 0.0;
+            }
+        }
+        let total: f64 = odds.iter().sum();
+        if total > 0.0 {
+            odds.iter_mut().for_each(|x| *x /= total);
+        } else {
+            // Nothing the slot can hold is in the set, fall back to any of its cards
+            for (rank, cards) in self.cards_by_rarity.iter().enumerate() {
+                odds[rank] = cards.len() as f64;
+            }
+            let total: f64 = odds.iter().sum();
+            odds.iter_mut().for_each(|x| *x /= total);
+        }
+        odds
+    }
+
+    fn draw_rarity(&self, odds: &RarityOdds, rng: &mut impl Rng) -> usize {
+        let odds = self.rarity_odds(odds);
+        let mut roll = rng.gen::<f64>();
+        for (rank, chance) in odds.iter().enumerate() {
+            if roll < *chance {
+                return rank;
+            }
+            roll -= chance;
+        }
+        // Rounding errors
+        odds.iter().rposition(|x| *x > 0.0).unwrap()
+    }
+}
+
+/// The cards a player owns, and the Pack Points they saved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Collection {
+    cards: HashMap<CardId, u32>,
+    pub pack_points: u32,
+    pub packs_opened: u32,
+}
+
+impl Collection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, card: &Card) {
+        *self.cards.entry(card.get_card_id()).or_default() += 1;
+    }
+
+    /// Copies owned of the card (of this very printing).
+    pub fn count(&self, card_id: CardId) -> u32 {
+        self.cards.get(&card_id).copied().unwrap_or(0)
+    }
+
+    /// Opens a pack, keeping its cards and the Pack Points it gives.
+    pub fn open(&mut self, pack: &BoosterPack, rng: &mut impl Rng) -> Vec<Card> {
+        let cards = pack.open(rng);
+        for card in &cards {
+            self.add(card);
+        }
+        self.pack_points += PACK_POINTS_PER_PACK;
+        self.packs_opened += 1;
+        cards
+    }
+
+    /// The copies of the deck's cards that aren't owned yet.
+    pub fn missing(&self, deck: &Deck) -> Vec<Card> {
+        let mut owned = self.cards.clone();
+        deck.cards
+            .iter()
+            .filter(|card| match owned.get_mut(&card.get_card_id()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Whether the saved Pack Points are enough to get all the missing cards of the deck.
+    pub fn can_buy_missing(&self, deck: &Deck) -> bool {
+        let cost: u32 = self
+            .missing(deck)
+            .iter()
+            .map(|x| rarity_cost(card_rarity(x)))
+            .sum();
+        cost <= self.pack_points
+    }
+}
+
+/// How `packs_to_complete` collects a deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackPlan {
+    /// Collections simulated.
+    pub trials: u32,
+    /// Whether to stop opening packs once the missing cards can be bought with Pack Points.
+    pub use_pack_points: bool,
+    /// Packs after which a collection is given up on.
+    pub max_packs: u32,
+}
+
+impl Default for PackPlan {
+    fn default() -> Self {
+        Self {
+            trials: 1000,
+            use_pack_points: true,
+            max_packs: 10_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackReport {
+    pub trials: u32,
+    /// Packs opened to complete the deck, on average over the completed collections.
+    pub mean_packs: f64,
+    pub median_packs: u32,
+    /// Packs that were enough in 90% of the completed collections.
+    pub p90_packs: u32,
+    /// Packs of each set opened, on average over the completed collections.
+    pub packs_by_set: Vec<(SetId, f64)>,
+    /// Collections that weren't completed within `max_packs`.
+    pub gave_up: u32,
+    /// Missing cards no pack holds (e.g. promos), left out of the collections.
+    pub unobtainable: Vec<Card>,
+}
+
+impl fmt::Display for PackReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Packs to complete the deck: {:.1} on average, {} median, {} in 90% of collections",
+            self.mean_packs, self.median_packs, self.p90_packs
+        )?;
+        for (set, packs) in &self.packs_by_set {
+            writeln!(f, "{}: {:.1} packs", set.info().booster_pack, packs)?;
+        }
+        if self.gave_up > 0 {
+            writeln!(f, "{} of {} collections gave up", self.gave_up, self.trials)?;
+        }
+        for card in &self.unobtainable {
+            writeln!(f, "{} ({}) can't be pulled", card.get_name(), card.get_id())?;
+        }
+        Ok(())
+    }
+}
+
+/// Simulates opening packs, starting from the collection, until the deck's cards are all
+/// owned (or, per the plan, can be bought with Pack Points). Each pack opened is of the
+/// set with the most missing copies.
+pub fn packs_to_complete(
+    deck: &Deck,
+    collection: &Collection,
+    plan: &PackPlan,
+    seed: u64,
+) -> PackReport {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut packs: HashMap<SetId, BoosterPack> = HashMap::new();
+    let mut unobtainable = vec![];
+    for card in collection.missing(deck) {
+        let set = card.get_set();
+        if let Entry::Vacant(entry) = packs.entry(set) {
+            if let Some(pack) = BoosterPack::new(set) {
+                entry.insert(pack);
+            }
+        }
+        if packs.get(&set).is_none_or(|x| x.pull_chance(&card) == 0.0)
+            && !unobtainable.contains(&card)
+        {
+            unobtainable.push(card);
+        }
+    }
+    let obtainable = Deck {
+        cards: deck
+            .cards
+            .iter()
+            .filter(|x| !unobtainable.contains(x))
+            .cloned()
+            .collect(),
+        energy_types: deck.energy_types.clone(),
+    };
+
+    let mut opened: Vec<u32> = vec![];
+    let mut packs_by_set: HashMap<SetId, u32> = HashMap::new();
+    let mut gave_up = 0;
+    for _ in 0..plan.trials {
+        let mut collection = collection.clone();
+        let start = collection.packs_opened;
+        let mut by_set: HashMap<SetId, u32> = HashMap::new();
+        loop {
+            let missing = collection.missing(&obtainable);
+            if missing.is_empty() || plan.use_pack_points && collection.can_buy_missing(&obtainable)
+            {
+                opened.push(collection.packs_opened - start);
+                for (set, count) in by_set {
+                    *packs_by_set.entry(set).or_default() += count;
+                }
+                break;
+            }
+            if collection.packs_opened - start >= plan.max_packs {
+                gave_up += 1;
+                break;
+            }
+            let set = most_missing_set(&missing);
+            collection.open(&packs[&set], &mut rng);
+            *by_set.entry(set).or_default() += 1;
+        }
+    }
+
+    opened.sort_unstable();
+    let completed = opened.len().max(1) as f64;
+    let mut packs_by_set: Vec<(SetId, f64)> = packs_by_set
+        .into_iter()
+        .map(|(set, count)| (set, count as f64 / completed))
+        .collect();
+    packs_by_set.sort_by_key(|x| x.0);
+    let percentile = |p: f64| -> u32 {
+        if opened.is_empty() {
+            return 0;
+        }
+        opened[((opened.len() - 1) as f64 * p).round() as usize]
+    };
+    PackReport {
+        trials: plan.trials,
+        mean_packs: opened.iter().sum::<u32>() as f64 / completed,
+        median_packs: percentile(0.5),
+        p90_packs: percentile(0.9),
+        packs_by_set,
+        gave_up,
+        unobtainable,
+    }
+}
+
+// Set with the most missing copies, the first released on ties
+fn most_missing_set(missing: &[Card]) -> SetId {
+    let mut counts: HashMap<SetId, usize> = HashMap::new();
+    for card in missing {
+        *counts.entry(card.get_set()).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .unwrap()
+        .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::get_card_by_enum;
+
+    #[test]
+    fn test_open_pack() {
+        let pack = BoosterPack::new(SetId::A1).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let cards = pack.open(&mut rng);
+            assert_eq!(cards.len(), 5);
+            assert!(cards.iter().all(|x| x.get_set() == SetId::A1));
+            assert!(cards[..3].iter().all(|x| card_rarity(x) == "◊"));
+            assert_eq!(pack.wonder_pick(&mut rng).get_set(), SetId::A1);
+        }
+        assert!(BoosterPack::new(SetId::PromoA).is_none());
+    }
+
+    #[test]
+    fn test_pull_chance() {
+        let pack = BoosterPack::new(SetId::A1).unwrap();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let commons = pack.cards_by_rarity[1].len() as f64;
+        let expected = 1.0 - (1.0 - 1.0 / commons).powi(3);
+        // Rare packs have no ◊ cards
+        assert!((pack.pull_chance(&bulbasaur) / expected - 1.0).abs() < 0.001);
+        assert_eq!(
+            pack.pull_chance(&get_card_by_enum(CardId::A1a001Exeggcute)),
+            0.0
+        );
+
+        let mewtwo = get_card_by_enum(CardId::A1129MewtwoEx);
+        assert!(pack.pull_chance(&mewtwo) < pack.pull_chance(&bulbasaur));
+    }
+
+    #[test]
+    fn test_packs_to_complete() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let plan = PackPlan {
+            trials: 20,
+            ..Default::default()
+        };
+        let report = packs_to_complete(&deck, &Collection::new(), &plan, 0);
+        assert_eq!(report.gave_up, 0);
+        assert!(report.mean_packs > 0.0);
+        assert!(report.median_packs <= report.p90_packs);
+        let total: f64 = report.packs_by_set.iter().map(|x| x.1).sum();
+        assert!((total - report.mean_packs).abs() < 1e-9);
+        // Same seed, same collections
+        assert_eq!(
+            packs_to_complete(&deck, &Collection::new(), &plan, 0),
+            report
+        );
+
+        // Nothing to open for a deck already owned
+        let mut collection = Collection::new();
+        for card in &deck.cards {
+            collection.add(card);
+        }
+        assert!(collection.missing(&deck).is_empty());
+        let report = packs_to_complete(&deck, &collection, &plan, 0);
+        assert_eq!(report.mean_packs, 0.0);
+    }
+}
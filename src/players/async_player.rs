@@ -0,0 +1,38 @@
+use rand::rngs::StdRng;
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+use crate::{actions::Action, Deck, State};
+
+use super::Player;
+
+/// The action an `AsyncPlayer` will choose.
+pub type ActionFuture<'a> = Pin<Box<dyn Future<Output = Action> + Send + 'a>>;
+
+/// A player whose decisions may wait on something, like a human on the other end of a
+/// connection or a bot behind an HTTP API, without blocking a thread meanwhile. Played
+/// by `AsyncGame`. Every `Player` that is `Send` is also an `AsyncPlayer`, deciding
+/// right away.
+pub trait AsyncPlayer: Debug + Send {
+    fn get_deck(&self) -> Deck;
+    fn decide<'a>(
+        &'a mut self,
+        rng: &'a mut StdRng,
+        state: &'a State,
+        possible_actions: Vec<Action>,
+    ) -> ActionFuture<'a>;
+}
+
+impl<T: Player + Send> AsyncPlayer for T {
+    fn get_deck(&self) -> Deck {
+        Player::get_deck(self)
+    }
+
+    fn decide<'a>(
+        &'a mut self,
+        rng: &'a mut StdRng,
+        state: &'a State,
+        possible_actions: Vec<Action>,
+    ) -> ActionFuture<'a> {
+        Box::pin(async move { self.decision_fn(rng, state, possible_actions) })
+    }
+}
@@ -3,10 +3,11 @@ use std::fmt::Debug;
 
 use crate::{
     actions::{Action, SimpleAction},
-    Deck, State,
+    observation::Observation,
+    Deck,
 };
 
-use super::Player;
+use super::FairPlayer;
 
 /// A player that always tries to Attach energy to active Pokemon
 ///   if it can. If it can't, it will attack with the active Pokemon.
@@ -15,8 +16,8 @@ pub struct AttachAttackPlayer {
     pub deck: Deck,
 }
 
-impl Player for AttachAttackPlayer {
-    fn decision_fn(&mut self, _: &mut StdRng, _: &State, possible_actions: Vec<Action>) -> Action {
+impl FairPlayer for AttachAttackPlayer {
+    fn decide(&mut self, _: &mut StdRng, _: &Observation, possible_actions: Vec<Action>) -> Action {
         let maybe_attach = possible_actions
             .iter()
             .find(|action| matches!(action.action, SimpleAction::Attach { .. }));
@@ -35,7 +36,7 @@ impl Player for AttachAttackPlayer {
             .clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
@@ -50,10 +51,8 @@ impl Debug for AttachAttackPlayer {
 mod tests {
     use super::*;
     use crate::{
-        card_ids::CardId,
-        database::get_card_by_enum,
-        test_helpers::load_test_decks,
-        types::EnergyType,
+        card_ids::CardId, database::get_card_by_enum, players::Player,
+        test_helpers::load_test_decks, types::EnergyType, State,
     };
     use rand::SeedableRng;
 
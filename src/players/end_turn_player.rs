@@ -3,18 +3,19 @@ use std::fmt::Debug;
 
 use crate::{
     actions::{Action, SimpleAction},
-    Deck, State,
+    observation::Observation,
+    Deck,
 };
 
-use super::Player;
+use super::FairPlayer;
 
 /// A player that just Ends its turn if it can, or selects first action.
 pub struct EndTurnPlayer {
     pub deck: Deck,
 }
 
-impl Player for EndTurnPlayer {
-    fn decision_fn(&mut self, _: &mut StdRng, _: &State, possible_actions: Vec<Action>) -> Action {
+impl FairPlayer for EndTurnPlayer {
+    fn decide(&mut self, _: &mut StdRng, _: &Observation, possible_actions: Vec<Action>) -> Action {
         let maybe_end_turn = possible_actions
             .iter()
             .find(|action| matches!(action.action, SimpleAction::EndTurn));
@@ -27,7 +28,7 @@ impl Player for EndTurnPlayer {
             .clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
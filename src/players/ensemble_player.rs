@@ -0,0 +1,210 @@
+use rand::rngs::StdRng;
+use std::fmt::Debug;
+
+use crate::{actions::Action, Deck, State};
+
+use super::Player;
+
+/// A player that asks several players for each decision and plays the action with the
+/// most votes, each member's vote counting as much as its weight (e.g. to let a search
+/// player outvote two quick heuristics). Ties go to the action voted for by the earliest
+/// member. It also keeps track of how often its members agree.
+pub struct EnsemblePlayer {
+    members: Vec<(Box<dyn Player>, f64)>,
+    // Decisions with more than one legal action, and how many of them were unanimous
+    decisions: usize,
+    unanimous: usize,
+    // Decisions in which each member voted for the action played
+    followed: Vec<usize>,
+}
+
+impl EnsemblePlayer {
+    /// Majority vote, every member weighing the same. Plays the first member's deck.
+    pub fn new(members: Vec<Box<dyn Player>>) -> Self {
+        Self::weighted(members.into_iter().map(|x| (x, 1.0)).collect())
+    }
+
+    /// Each member's vote counting as much as its weight.
+    pub fn weighted(members: Vec<(Box<dyn Player>, f64)>) -> Self {
+        assert!(!members.is_empty(), "An ensemble needs at least one member");
+        let followed = vec![0; members.len()];
+        Self {
+            members,
+            decisions: 0,
+            unanimous: 0,
+            followed,
+        }
+    }
+
+    /// Share of the decisions in which all members voted for the same action. 1 if there
+    /// was no decision yet.
+    pub fn agreement(&self) -> f64 {
+        if self.decisions == 0 {
+            return 1.0;
+        }
+        self.unanimous as f64 / self.decisions as f64
+    }
+
+    /// Share of the decisions in which each member voted for the action played, in the
+    /// order of the members.
+    pub fn member_agreement(&self) -> Vec<f64> {
+        self.followed
+            .iter()
+            .map(|x| *x as f64 / self.decisions.max(1) as f64)
+            .collect()
+    }
+}
+
+impl Player for EnsemblePlayer {
+    fn get_deck(&self) -> Deck {
+        self.members[0].0.get_deck()
+    }
+
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let votes: Vec<(Action, f64)> = self
+            .members
+            .iter_mut()
+            .map(|(player, weight)| {
+                let action = player.decision_fn(rng, state, possible_actions.clone());
+                (action, *weight)
+            })
+            .collect();
+
+        let mut tally: Vec<(&Action, f64)> = vec![];
+        for (action, weight) in &votes {
+            match tally.iter_mut().find(|(x, _)| *x == action) {
+                Some((_, total)) => *total += weight,
+                None => tally.push((action, *weight)),
+            }
+        }
+        // The first of the most voted, since max_by keeps the last of equal elements
+        let chosen = tally
+            .iter()
+            .rev()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap()
+            .0
+            .clone();
+
+        if possible_actions.len() > 1 {
+            self.decisions += 1;
+            if tally.len() == 1 {
+                self.unanimous += 1;
+            }
+            for (followed, (action, _)) in self.followed.iter_mut().zip(&votes) {
+                if *action == chosen {
+                    *followed += 1;
+                }
+            }
+        }
+        chosen
+    }
+
+    fn observe_action(&mut self, action: &Action) {
+        for (player, _) in &mut self.members {
+            player.observe_action(action);
+        }
+    }
+}
+
+impl Debug for EnsemblePlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let members: Vec<String> = self
+            .members
+            .iter()
+            .map(|(player, weight)| format!("{player:?}*{weight}"))
+            .collect();
+        write!(f, "EnsemblePlayer({})", members.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        actions::apply_action,
+        generate_possible_actions,
+        players::{AttachAttackPlayer, EndTurnPlayer},
+        test_helpers::load_test_decks,
+        Game,
+    };
+
+    // Plays a game with `player` against AttachAttackPlayer, returning the final state
+    fn play(player: Box<dyn Player>) -> State {
+        let (_, deck_b) = load_test_decks();
+        let players: Vec<Box<dyn Player>> =
+            vec![player, Box::new(AttachAttackPlayer { deck: deck_b })];
+        let mut game = Game::new(players, 6);
+        game.play();
+        game.get_state_clone()
+    }
+
+    fn members(deck: &Deck) -> Vec<Box<dyn Player>> {
+        vec![
+            Box::new(EndTurnPlayer { deck: deck.clone() }),
+            Box::new(EndTurnPlayer { deck: deck.clone() }),
+            Box::new(AttachAttackPlayer { deck: deck.clone() }),
+        ]
+    }
+
+    #[test]
+    fn test_majority_vote() {
+        let (deck_a, _) = load_test_decks();
+        let ensemble = EnsemblePlayer::new(members(&deck_a));
+        assert_eq!(
+            play(Box::new(ensemble)),
+            play(Box::new(EndTurnPlayer { deck: deck_a }))
+        );
+    }
+
+    #[test]
+    fn test_weighted_vote() {
+        let (deck_a, _) = load_test_decks();
+        let weights = [1.0, 1.0, 2.5];
+        let ensemble =
+            EnsemblePlayer::weighted(members(&deck_a).into_iter().zip(weights).collect());
+        assert_eq!(
+            play(Box::new(ensemble)),
+            play(Box::new(AttachAttackPlayer { deck: deck_a }))
+        );
+    }
+
+    #[test]
+    fn test_agreement() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut ensemble = EnsemblePlayer::new(members(&deck_a));
+        assert_eq!(ensemble.agreement(), 1.0);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+        let mut agreed = 0;
+        let mut decisions = 0;
+        while state.turn_count < 6 && !state.is_game_over() {
+            let (_, actions) = generate_possible_actions(&state);
+            let action = ensemble.decision_fn(&mut rng, &state, actions.clone());
+            if actions.len() > 1 {
+                decisions += 1;
+                let attach_attack = AttachAttackPlayer {
+                    deck: deck_a.clone(),
+                }
+                .decision_fn(&mut rng, &state, actions.clone());
+                if attach_attack == action {
+                    agreed += 1;
+                }
+            }
+            apply_action(&mut rng, &mut state, &action);
+        }
+        assert!(decisions > 0);
+        let shares = ensemble.member_agreement();
+        assert_eq!(shares[0], 1.0);
+        assert_eq!(shares[1], 1.0);
+        assert_eq!(shares[2], agreed as f64 / decisions as f64);
+        assert_eq!(ensemble.agreement(), shares[2]);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{actions::Action, State};
+
+/// Scores a state from the point of view of the given player (the higher, the better).
+/// Any `Fn(&State, usize) -> f64` is a value function, so plain functions and closures
+/// can be plugged in as well as trained models.
+pub trait ValueFunction: Send + Sync {
+    fn evaluate(&self, state: &State, player: usize) -> f64;
+}
+
+impl<F: Fn(&State, usize) -> f64 + Send + Sync> ValueFunction for F {
+    fn evaluate(&self, state: &State, player: usize) -> f64 {
+        self(state, player)
+    }
+}
+
+/// Prior probability of playing each of the legal actions (in the same order), as given
+/// by e.g. the policy head of a network. Priors should be non-negative and sum to 1.
+pub trait PolicyFunction: Send + Sync {
+    fn priors(&self, state: &State, actions: &[Action]) -> Vec<f64>;
+}
+
+impl<F: Fn(&State, &[Action]) -> Vec<f64> + Send + Sync> PolicyFunction for F {
+    fn priors(&self, state: &State, actions: &[Action]) -> Vec<f64> {
+        self(state, actions)
+    }
+}
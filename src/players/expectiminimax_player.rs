@@ -2,31 +2,56 @@ use log::{trace, LevelFilter};
 use rand::rngs::StdRng;
 use std::fmt::Debug;
 
-use crate::actions::{forecast_action, Action};
+use crate::actions::{forecast, Action};
 use crate::{generate_possible_actions, Deck, State};
 
-use super::Player;
+use super::{CheatingPlayer, ValueFunction};
 
+/// Depth-limited expectiminimax over the `forecast` tree: maximizes on our
+/// decisions, minimizes on the opponent's, and weighs chance outcomes (coin flips, etc.)
+/// by their probabilities. Leaves are scored with `value_function`.
 pub struct ExpectiMiniMaxPlayer {
     pub deck: Deck,
     pub max_depth: usize, // max_depth = 1 it should be value function player
+    pub value_function: Box<dyn ValueFunction>,
 }
 
-impl Player for ExpectiMiniMaxPlayer {
-    fn decision_fn(
+impl ExpectiMiniMaxPlayer {
+    pub fn new(deck: Deck, max_depth: usize) -> Self {
+        Self {
+            deck,
+            max_depth,
+            value_function: Box::new(value_function),
+        }
+    }
+
+    pub fn with_value_function(mut self, value_function: impl ValueFunction + 'static) -> Self {
+        self.value_function = Box::new(value_function);
+        self
+    }
+}
+
+impl CheatingPlayer for ExpectiMiniMaxPlayer {
+    fn decide_with_full_state(
         &mut self,
         rng: &mut StdRng,
         state: &State,
         possible_actions: Vec<Action>,
     ) -> Action {
         let myself = possible_actions[0].actor;
+        let search = Search {
+            myself,
+            value_function: self.value_function.as_ref(),
+        };
 
         // Get value for each possible action
         let original_level = log::max_level();
         log::set_max_level(LevelFilter::Error); // Temporarily silence debug and trace logs
         let scores: Vec<f64> = possible_actions
             .iter()
-            .map(|action| expected_value_function(rng, state, action, self.max_depth - 1, myself))
+            .map(|action| {
+                search.expected_value(rng, state, action, self.max_depth.saturating_sub(1))
+            })
             .collect();
         log::set_max_level(original_level); // Restore the original logging level
 
@@ -41,60 +66,61 @@ impl Player for ExpectiMiniMaxPlayer {
         possible_actions[best_idx].clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
 
-fn expected_value_function(
-    rng: &mut StdRng,
-    state: &State,
-    action: &Action,
-    depth: usize,
+struct Search<'a> {
     myself: usize,
-) -> f64 {
-    trace!("E({}) depth left: {} action: {:?}", myself, depth, action);
-    let (probabilities, mutations) = forecast_action(state, action);
-    let mut outcomes: Vec<State> = vec![];
-    for mutation in mutations {
-        let mut state = state.clone();
-        mutation(rng, &mut state, action);
-        outcomes.push(state);
-    }
-    let score = outcomes
-        .iter()
-        .zip(probabilities.iter())
-        .map(|(outcome, prob)| expectiminimax(rng, outcome, depth, myself) * prob)
-        .sum();
-    trace!("E({}) action: {:?} score: {}", myself, action, score);
-    score
+    value_function: &'a dyn ValueFunction,
 }
 
-fn expectiminimax(rng: &mut StdRng, state: &State, depth: usize, myself: usize) -> f64 {
-    if state.is_game_over() || depth == 0 {
-        return value_function(state, myself);
+impl Search<'_> {
+    /// Chance node: the average of the outcomes of the action, weighted by their probability.
+    fn expected_value(
+        &self,
+        rng: &mut StdRng,
+        state: &State,
+        action: &Action,
+        depth: usize,
+    ) -> f64 {
+        trace!(
+            "E({}) depth left: {} action: {:?}",
+            self.myself,
+            depth,
+            action
+        );
+        let mut score = 0.0;
+        for outcome in forecast(rng, state, action) {
+            score += self.expectiminimax(rng, &outcome.state, depth) * outcome.probability;
+        }
+        trace!("E({}) action: {:?} score: {}", self.myself, action, score);
+        score
     }
 
-    let (actor, actions) = generate_possible_actions(state);
-    if actor == myself {
-        // We are in maximing mode.
-        let scores: Vec<f64> = actions
-            .iter()
-            .map(|action| expected_value_function(rng, state, action, depth - 1, myself))
-            .collect();
-        scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
-    } else {
-        // TODO: If minimizing, we can't just generate_possible_actions since
-        //  not everything is public information. So we would have to have
-        //  our own version of it that only returns the actions that are
-        let scores: Vec<f64> = actions
+    fn expectiminimax(&self, rng: &mut StdRng, state: &State, depth: usize) -> f64 {
+        if state.is_game_over() || depth == 0 {
+            return self.value_function.evaluate(state, self.myself);
+        }
+
+        let (actor, actions) = generate_possible_actions(state);
+        let scores = actions
             .iter()
-            .map(|action| expected_value_function(rng, state, action, depth - 1, myself))
-            .collect();
-        scores.iter().cloned().fold(f64::INFINITY, f64::min)
+            .map(|action| self.expected_value(rng, state, action, depth - 1));
+        if actor == self.myself {
+            // We are in maximing mode.
+            scores.fold(f64::NEG_INFINITY, f64::max)
+        } else {
+            // TODO: If minimizing, we can't just generate_possible_actions since
+            //  not everything is public information. So we would have to have
+            //  our own version of it that only returns the actions that are
+            scores.fold(f64::INFINITY, f64::min)
+        }
     }
 }
 
+/// Default evaluation: points, then Pokémon health on the board, then attached energy.
 fn value_function(state: &State, myself: usize) -> f64 {
     // TODO: Add more features
     // Give priorities to attached energies?
@@ -156,6 +182,6 @@ fn value_function(state: &State, myself: usize) -> f64 {
 
 impl Debug for ExpectiMiniMaxPlayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ExpectiMiniMaxPlayer")
+        write!(f, "ExpectiMiniMaxPlayer with depth {}", self.max_depth)
     }
 }
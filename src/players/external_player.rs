@@ -0,0 +1,33 @@
+use rand::rngs::StdRng;
+use std::fmt::Debug;
+
+use crate::{actions::Action, Deck, State};
+
+use super::Player;
+
+/// A seat whose decisions come from outside the game, e.g. from a GUI or a web client.
+/// The game stops at its decisions in `Game::poll_decision`, and goes on once the action
+/// is given to `Game::submit_choice`, so nothing has to block waiting for it.
+pub struct ExternalPlayer {
+    pub deck: Deck,
+}
+
+impl Player for ExternalPlayer {
+    fn get_deck(&self) -> Deck {
+        self.deck.clone()
+    }
+
+    fn decision_fn(&mut self, _: &mut StdRng, _: &State, _: Vec<Action>) -> Action {
+        panic!("ExternalPlayer decides through Game::poll_decision and Game::submit_choice")
+    }
+
+    fn is_external(&self) -> bool {
+        true
+    }
+}
+
+impl Debug for ExternalPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExternalPlayer")
+    }
+}
@@ -0,0 +1,184 @@
+use rand::rngs::StdRng;
+use std::fmt::Debug;
+
+use crate::{
+    actions::{forecast, Action, SimpleAction},
+    generate_possible_actions, Deck, State,
+};
+
+use super::CheatingPlayer;
+
+/// Actions looked ahead by default, this one included.
+const DEFAULT_DEPTH: usize = 3;
+
+/// A deterministic baseline that plays, each turn, the sequence of actions with the most
+/// expected damage to the opponent's Pokémon (Knocked Out ones counting their remaining
+/// HP). It looks `depth` of its own actions ahead, averaging over coin flips and other
+/// outcomes (see `forecast`), and stops at the end of its turn. Among equally damaging
+/// actions it plays the first one, ending its turn only when nothing else is as good.
+pub struct GreedyDamagePlayer {
+    pub deck: Deck,
+    pub depth: usize,
+}
+
+impl GreedyDamagePlayer {
+    pub fn new(deck: Deck) -> Self {
+        Self {
+            deck,
+            depth: DEFAULT_DEPTH,
+        }
+    }
+}
+
+impl CheatingPlayer for GreedyDamagePlayer {
+    fn decide_with_full_state(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let myself = possible_actions[0].actor;
+        let turn = Turn {
+            player: myself,
+            count: state.turn_count,
+            opponent_hp: opponent_hp(state, myself),
+        };
+        let mut best: Option<(f64, &Action)> = None;
+        let ends_turn_last = possible_actions
+            .iter()
+            .filter(|x| x.action != SimpleAction::EndTurn)
+            .chain(
+                possible_actions
+                    .iter()
+                    .filter(|x| x.action == SimpleAction::EndTurn),
+            );
+        for action in ends_turn_last {
+            let damage = expected_damage(rng, state, action, &turn, self.depth);
+            // Ties (up to rounding) keep the earlier action
+            if best.is_none_or(|(best_damage, _)| damage > best_damage + 1e-9) {
+                best = Some((damage, action));
+            }
+        }
+        best.unwrap().1.clone()
+    }
+
+    fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+}
+
+// The turn being planned
+struct Turn {
+    player: usize,
+    count: u8,
+    // Remaining HP of the opponent's Pokémon at its start
+    opponent_hp: u32,
+}
+
+impl Turn {
+    fn damage(&self, state: &State) -> f64 {
+        self.opponent_hp
+            .saturating_sub(opponent_hp(state, self.player)) as f64
+    }
+}
+
+// Most expected damage this turn after playing the action, looking `depth` actions ahead
+fn expected_damage(
+    rng: &mut StdRng,
+    state: &State,
+    action: &Action,
+    turn: &Turn,
+    depth: usize,
+) -> f64 {
+    forecast(rng, state, action)
+        .iter()
+        .map(|outcome| outcome.probability * best_damage(rng, &outcome.state, turn, depth - 1))
+        .sum()
+}
+
+fn best_damage(rng: &mut StdRng, state: &State, turn: &Turn, depth: usize) -> f64 {
+    if depth == 0 || state.is_game_over() || state.turn_count != turn.count {
+        return turn.damage(state);
+    }
+    let (actor, actions) = generate_possible_actions(state);
+    if actor != turn.player {
+        return turn.damage(state);
+    }
+    actions
+        .iter()
+        .map(|action| expected_damage(rng, state, action, turn, depth))
+        .fold(turn.damage(state), f64::max)
+}
+
+// Pokémon Knocked Out leave play, so their remaining HP stops counting
+fn opponent_hp(state: &State, player: usize) -> u32 {
+    state
+        .enumerate_in_play_pokemon((player + 1) % 2)
+        .map(|(_, pokemon)| pokemon.remaining_hp)
+        .sum()
+}
+
+impl Debug for GreedyDamagePlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GreedyDamagePlayer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        card_ids::CardId,
+        players::{AttachAttackPlayer, CheatingPlayerAdapter, Player},
+        test_helpers::{energy, ScenarioBuilder},
+        types::EnergyType::{Colorless, Grass},
+        Game,
+    };
+
+    #[test]
+    fn test_attaches_before_attacking() {
+        // Venusaur ex needs a 4th energy for Giant Bloom, Bulbasaur on the bench none
+        let mut state = ScenarioBuilder::new()
+            .active(
+                0,
+                CardId::A1004VenusaurEx,
+                [energy([Grass, Grass, Colorless])],
+            )
+            .bench(0, CardId::A1001Bulbasaur, [])
+            .active(1, CardId::A1003Venusaur, [])
+            .turn(3)
+            .build();
+        state.current_energy = Some(Grass);
+        let (_, actions) = generate_possible_actions(&state);
+        let mut player = GreedyDamagePlayer::new(Deck::default());
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = player.decide_with_full_state(&mut rng, &state, actions);
+        assert!(matches!(
+            action.action,
+            SimpleAction::Attach { ref attachments, .. } if attachments[0].2 == 0
+        ));
+    }
+
+    #[test]
+    fn test_beats_attach_attack_player() {
+        let (deck_a, deck_b) = crate::test_helpers::load_test_decks();
+        let mut wins = 0;
+        for seed in 0..4 {
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(CheatingPlayerAdapter(GreedyDamagePlayer::new(
+                    deck_a.clone(),
+                ))),
+                Box::new(AttachAttackPlayer {
+                    deck: deck_b.clone(),
+                }),
+            ];
+            let mut game = Game::new(players, seed);
+            if game.play() == Some(crate::state::GameOutcome::Win(0)) {
+                wins += 1;
+            }
+        }
+        assert!(wins >= 2, "Won {wins} of 4");
+    }
+}
@@ -1,9 +1,9 @@
 use rand::rngs::StdRng;
 use std::fmt::Debug;
 
-use crate::{actions::Action, Deck, State};
+use crate::{actions::Action, observation::Observation, Deck};
 
-use super::Player;
+use super::FairPlayer;
 
 /// A player that asks the user to select one available
 /// action by prompting the user via STDIN.
@@ -11,11 +11,11 @@ pub struct HumanPlayer {
     pub deck: Deck,
 }
 
-impl Player for HumanPlayer {
-    fn decision_fn(
+impl FairPlayer for HumanPlayer {
+    fn decide(
         &mut self,
         _: &mut StdRng,
-        state: &State,
+        observation: &Observation,
         possible_actions: Vec<Action>,
     ) -> Action {
         if possible_actions.len() == 1 {
@@ -23,9 +23,13 @@ impl Player for HumanPlayer {
             return possible_actions[0].clone();
         }
 
-        println!("=== {}|{:?}", state.turn_count, state.points);
+        println!(
+            "=== {}|{:?}",
+            observation.turn_count(),
+            observation.points()
+        );
         println!();
-        println!("{}", state.debug_string());
+        println!("{}", observation.debug_string());
         println!();
         println!("Select an action:");
         for (i, action) in possible_actions.iter().enumerate() {
@@ -47,7 +51,7 @@ impl Player for HumanPlayer {
         possible_actions[index - 1].clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
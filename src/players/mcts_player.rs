@@ -1,68 +1,257 @@
 use log::debug;
-use rand::{rngs::StdRng, seq::SliceRandom, Rng};
-use std::{collections::HashMap, fmt::Debug};
+use rand::{rngs::StdRng, Rng};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Debug, Write},
+};
 
-use super::{Player, RandomPlayer};
+use super::{
+    node_arena::{NodeArena, NodeId},
+    opponent_model::{determinize, OpponentModel},
+    CheatingPlayer, Player, PolicyFunction, RandomPlayer, ValueFunction,
+};
 use crate::{
     actions::{apply_action, Action},
     generate_possible_actions,
+    observation::Observation,
     state::GameOutcome,
     Deck, Game, State,
 };
 
+/// Creates the player used for each seat when playing out a game from a leaf. Rollout
+/// players are only asked for decisions, so their deck is irrelevant.
+pub type RolloutPolicy = Box<dyn Fn() -> Box<dyn Player> + Send + Sync>;
+
+/// Tuning of the search, shared by `MctsPlayer` and `ParallelMctsPlayer`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MctsConfig {
+    /// Exploration constant `c` of the PUCT selection used when a policy function is set.
+    pub exploration: f64,
+    /// Progressive widening of chance outcomes: an action visited `n` times is sampled
+    /// into up to `widening_constant * n ^ widening_exponent` distinct outcomes (coin
+    /// flips, draws...), instead of keeping the first one forever.
+    pub widening_constant: f64,
+    pub widening_exponent: f64,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        Self {
+            exploration: 1.5,
+            widening_constant: 1.0,
+            widening_exponent: 0.5,
+        }
+    }
+}
+
+impl MctsConfig {
+    /// How many distinct outcomes an action visited `visits` times may have.
+    fn max_outcomes(&self, visits: usize) -> usize {
+        let outcomes = self.widening_constant * (visits as f64).powf(self.widening_exponent);
+        (outcomes.ceil() as usize).max(1)
+    }
+}
+
+/// How the search evaluates leaves and prioritizes children.
+pub(super) struct SearchHooks {
+    pub(super) config: MctsConfig,
+    pub(super) rollout_policy: RolloutPolicy,
+    /// Replaces rollouts when set.
+    pub(super) value_function: Option<Box<dyn ValueFunction>>,
+    /// Turns the uniformly random selection into PUCT when set.
+    pub(super) policy_function: Option<Box<dyn PolicyFunction>>,
+}
+
+impl Default for SearchHooks {
+    fn default() -> Self {
+        Self {
+            config: MctsConfig::default(),
+            rollout_policy: Box::new(|| {
+                Box::new(RandomPlayer {
+                    deck: Deck::default(),
+                })
+            }),
+            value_function: None,
+            policy_function: None,
+        }
+    }
+}
+
+/// Default `max_nodes` of a search tree. Each node holds a `State`, so this bounds the
+/// memory a player keeps across decisions.
+pub(super) const DEFAULT_MAX_NODES: usize = 50_000;
+
+/// Fewest nodes a search tree can work with: expanding a leaf keeps the root and the leaf,
+/// and needs room for their children.
+pub(super) const MIN_MAX_NODES: usize = 3;
+
 pub struct MctsPlayer {
     pub deck: Deck,
     pub iterations: u64, // Number of iterations for MCTS
-    node_lookup: HashMap<State, MctsNode>,
+    tree: SearchTree,
+    // Root of the previous search and the index of the action chosen from it
+    last_choice: Option<(NodeId, usize)>,
+    hooks: SearchHooks,
+    opponent_model: Option<Box<dyn OpponentModel>>,
 }
 impl MctsPlayer {
     pub fn new(deck: Deck, iterations: u64) -> Self {
         Self {
             deck,
             iterations,
-            node_lookup: HashMap::new(),
+            tree: SearchTree::new(DEFAULT_MAX_NODES),
+            last_choice: None,
+            hooks: SearchHooks::default(),
+            opponent_model: None,
+        }
+    }
+
+    /// Keeps at most `max_nodes` search nodes across decisions, evicting the least
+    /// recently used ones beyond that. Panics if `max_nodes` is below `MIN_MAX_NODES` (3).
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.tree = SearchTree::new(max_nodes);
+        self.last_choice = None;
+        self
+    }
+
+    /// Plays out leaves with the players returned by `policy` instead of uniformly random
+    /// ones. E.g. `.with_rollout_policy(|| Box::new(AttachAttackPlayer { deck: Deck::default() }))`.
+    pub fn with_rollout_policy(
+        mut self,
+        policy: impl Fn() -> Box<dyn Player> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.rollout_policy = Box::new(policy);
+        self
+    }
+
+    /// Scores leaves with `value_function` instead of playing them out. Its scores are
+    /// used as rewards, so they should be between -1 (loss) and 1 (win).
+    pub fn with_value_function(mut self, value_function: impl ValueFunction + 'static) -> Self {
+        self.hooks.value_function = Some(Box::new(value_function));
+        self
+    }
+
+    /// Selects children with PUCT, guided by the priors of `policy_function`.
+    pub fn with_policy_function(mut self, policy_function: impl PolicyFunction + 'static) -> Self {
+        self.hooks.policy_function = Some(Box::new(policy_function));
+        self
+    }
+
+    pub fn with_config(mut self, config: MctsConfig) -> Self {
+        self.hooks.config = config;
+        self
+    }
+
+    /// Searches a state dealt by `model` (see `determinize`) instead of the real one, so
+    /// that the search doesn't see the opponent's hand nor the order of the decks.
+    pub fn with_opponent_model(mut self, model: impl OpponentModel + 'static) -> Self {
+        self.opponent_model = Some(Box::new(model));
+        self
+    }
+
+    /// Like `decision_fn`, also explaining the choice with the search statistics of every
+    /// legal action.
+    pub fn decide_with_report(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> (Action, DecisionReport) {
+        let (root, index) = self.choose(rng, state, possible_actions);
+        let report = self.tree.report(root, index);
+        (self.tree.node(root).actions[index].clone(), report)
+    }
+
+    /// The tree searched for the last decision, in Graphviz DOT format (e.g. for `dot -Tsvg`),
+    /// down to `depth_limit` actions below the root. Nodes show the player to move, visits
+    /// and mean reward for this player; edges show the actions, the chosen one in bold.
+    /// An empty graph before the first decision.
+    pub fn export_tree_dot(&self, depth_limit: usize) -> String {
+        match self.last_choice {
+            Some((root, index)) if self.tree.arena.get(root).is_some() => {
+                self.tree.to_dot(root, index, depth_limit)
+            }
+            _ => "digraph mcts {\n}\n".to_string(),
+        }
+    }
+
+    // Searches the state and returns the root with the index of the best action
+    fn choose(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> (NodeId, usize) {
+        // Only positions that can follow our previous choice are worth keeping
+        if let Some((root, index)) = self.last_choice.take() {
+            self.tree.reroot(root, index);
         }
+        let imagined;
+        let state = match &mut self.opponent_model {
+            Some(model) => {
+                let player = possible_actions[0].actor;
+                model.observe(&Observation::new(state, player));
+                imagined = determinize(model.as_ref(), rng, state, player);
+                &imagined
+            }
+            None => state,
+        };
+        let root = search(
+            rng,
+            state,
+            possible_actions,
+            self.iterations,
+            &mut self.tree,
+            &self.hooks,
+        );
+        debug!("Search tree holds {} nodes", self.tree.len());
+
+        // Choose the best action from the root node
+        let index = self.tree.best_index(root);
+        self.last_choice = Some((root, index));
+        (root, index)
     }
 }
 
-impl Player for MctsPlayer {
+/// Why an `MctsPlayer` chose an action: what its search found for each legal action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionReport {
+    /// Index of the chosen action in `actions`.
+    pub chosen: usize,
+    /// One per legal action, in the order they were given.
+    pub actions: Vec<ActionReport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionReport {
+    pub action: Action,
+    pub visits: usize,
+    /// Mean reward of the searches through this action, from -1 (loss) to 1 (win) for the
+    /// deciding player. 0 if never visited.
+    pub q_value: f64,
+    /// The actions expected to follow, by always taking the most visited one.
+    pub principal_variation: Vec<Action>,
+}
+
+impl CheatingPlayer for MctsPlayer {
     /// Perform MCTS search and return the best action
-    fn decision_fn(
+    fn decide_with_full_state(
         &mut self,
         rng: &mut StdRng,
         state: &State,
         possible_actions: Vec<Action>,
     ) -> Action {
-        // Step 1: Initialize the root node of the search tree
-        let investigator = possible_actions[0].actor; // myself
-        let mut root = self
-            .node_lookup
-            .entry(state.clone())
-            .or_insert_with(|| MctsNode::new(state.clone(), possible_actions.clone()))
-            .clone();
-
-        // Step 2: Perform iterations of MCTS
-        for _ in 0..self.iterations {
-            // Selection: Traverse the tree to a leaf node
-            let leaf = root.select(rng);
-
-            // Expansion: Expand the leaf node if it is not terminal
-            if !leaf.is_terminal() {
-                leaf.expand(rng, &mut self.node_lookup);
-            }
-
-            // Simulation: Simulate a random playout from the expanded node
-            let reward = leaf.simulate(rng, investigator);
+        let (root, index) = self.choose(rng, state, possible_actions);
+        self.tree.node(root).actions[index].clone()
+    }
 
-            // Backpropagation: Update the tree with the simulation result
-            leaf.backpropagate(reward);
+    fn observe_action(&mut self, action: &Action) {
+        if let Some(model) = &mut self.opponent_model {
+            model.observe_action(action);
         }
-
-        // Step 3: Choose the best action from the root node
-        root.best_action()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
@@ -73,13 +262,411 @@ impl Debug for MctsPlayer {
     }
 }
 
+/// Runs `iterations` of MCTS from the state and returns the root of the search tree.
+pub(super) fn search(
+    rng: &mut StdRng,
+    state: &State,
+    possible_actions: Vec<Action>,
+    iterations: u64,
+    tree: &mut SearchTree,
+    hooks: &SearchHooks,
+) -> NodeId {
+    // Step 1: Find or create the root node of the search tree
+    let investigator = possible_actions[0].actor; // myself
+    let root = tree.find_or_insert(state, || possible_actions.clone(), &[]);
+
+    // Step 2: Perform iterations of MCTS
+    for _ in 0..iterations {
+        tree.arena.touch(root);
+
+        // Selection: Traverse the tree to a leaf node
+        let path = tree.select(rng, root, investigator, &hooks.config);
+        let leaf = *path.last().expect("The path should start at the root");
+
+        // Expansion: Expand the leaf node if it is not terminal
+        if !tree.node(leaf).is_terminal() && tree.node(leaf).children.is_empty() {
+            tree.expand(rng, leaf, &path, hooks.policy_function.as_deref());
+        }
+
+        // Simulation: Evaluate the expanded node, by default with a playout
+        let node = tree.node(leaf);
+        let reward = match &hooks.value_function {
+            Some(value_function) => value_function.evaluate(&node.state, investigator),
+            None => node.simulate(rng, investigator, &hooks.rollout_policy),
+        };
+
+        // Backpropagation: Update the tree with the simulation result
+        for id in path {
+            tree.node_mut(id).backpropagate(reward);
+        }
+    }
+    root
+}
+
+/// Nodes searched by a player, kept across decisions so that positions seen again reuse
+/// their statistics. Holds at most `max_nodes`, evicting the least recently used ones;
+/// children that got evicted are sampled again when selected.
+pub(super) struct SearchTree {
+    arena: NodeArena<MctsNode>,
+    node_lookup: HashMap<State, NodeId>,
+}
+
+impl SearchTree {
+    pub(super) fn new(max_nodes: usize) -> Self {
+        assert!(
+            max_nodes >= MIN_MAX_NODES,
+            "A search tree needs at least {MIN_MAX_NODES} nodes, got {max_nodes}"
+        );
+        Self {
+            arena: NodeArena::new(max_nodes),
+            node_lookup: HashMap::new(),
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    fn node(&self, id: NodeId) -> &MctsNode {
+        self.arena
+            .get(id)
+            .expect("Node should not be evicted while in use")
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut MctsNode {
+        self.arena
+            .get_mut(id)
+            .expect("Node should not be evicted while in use")
+    }
+
+    /// Node of the state, creating it (with the given actions) if it isn't in the tree.
+    fn find_or_insert(
+        &mut self,
+        state: &State,
+        actions: impl FnOnce() -> Vec<Action>,
+        pinned: &[NodeId],
+    ) -> NodeId {
+        if let Some(&id) = self.node_lookup.get(state) {
+            if self.arena.get(id).is_some() {
+                debug!("Found a node in the lookup table");
+                self.arena.touch(id);
+                return id;
+            }
+        }
+        debug!("Missed a node in the lookup table");
+        let (id, evicted) = self
+            .arena
+            .insert(MctsNode::new(state.clone(), actions()), pinned);
+        if let Some(evicted) = evicted {
+            self.node_lookup.remove(&evicted.state);
+        }
+        self.node_lookup.insert(state.clone(), id);
+        id
+    }
+
+    /// Walks down from the node through expanded ones, picking an action and one of its
+    /// outcomes at each, and returns the nodes walked through, ending at the leaf to
+    /// evaluate. Stops early rather than pin the whole tree, which expanding would need.
+    fn select(
+        &mut self,
+        rng: &mut StdRng,
+        id: NodeId,
+        investigator: usize,
+        config: &MctsConfig,
+    ) -> Vec<NodeId> {
+        let mut path = vec![id];
+        while path.len() + 1 < self.arena.max_len() {
+            let current = path[path.len() - 1];
+            let node = self.node(current);
+            if node.children.is_empty() {
+                break;
+            }
+            let index = if node.priors.len() == node.children.len() {
+                let mut stats = self.child_stats(current);
+                // Rewards are the investigator's, which the opponent wants low
+                if node.actions[0].actor != investigator {
+                    stats.iter_mut().for_each(|(_, reward)| *reward = -*reward);
+                }
+                puct_index(&stats, &node.priors, config.exploration)
+            } else {
+                rng.gen_range(0..node.children.len())
+            };
+            let child = self.child(rng, current, index, &path, config);
+            // Transpositions could lead back to a node already on the path
+            if path.contains(&child) {
+                break;
+            }
+            path.push(child);
+        }
+        path
+    }
+
+    /// An outcome of the index-th action of the node: a new one while the action has
+    /// fewer than its widening allows, otherwise one of the known ones (sampled again if
+    /// it got evicted).
+    fn child(
+        &mut self,
+        rng: &mut StdRng,
+        id: NodeId,
+        index: usize,
+        pinned: &[NodeId],
+        config: &MctsConfig,
+    ) -> NodeId {
+        let (visits, _) = self.child_stats(id)[index];
+        let outcomes = &self.node(id).children[index];
+        let slot = if outcomes.len() < config.max_outcomes(visits) {
+            None
+        } else {
+            let slot = rng.gen_range(0..outcomes.len());
+            let outcome = outcomes[slot];
+            if self.arena.get(outcome).is_some() {
+                self.arena.touch(outcome);
+                return outcome;
+            }
+            Some(slot)
+        };
+
+        let node = self.node(id);
+        let mut new_state = node.state.clone();
+        apply_action(rng, &mut new_state, &node.actions[index]);
+        let child = self.find_or_insert(
+            &new_state,
+            || generate_possible_actions(&new_state).1,
+            pinned,
+        );
+        let outcomes = &mut self.node_mut(id).children[index];
+        match slot {
+            Some(slot) => outcomes[slot] = child,
+            // Deterministic actions keep landing on the same outcome
+            None if !outcomes.contains(&child) => outcomes.push(child),
+            None => {}
+        }
+        child
+    }
+
+    fn expand(
+        &mut self,
+        rng: &mut StdRng,
+        id: NodeId,
+        pinned: &[NodeId],
+        policy_function: Option<&dyn PolicyFunction>,
+    ) {
+        let node = self.node(id);
+        let (state, actions) = (node.state.clone(), node.actions.clone());
+        if let Some(policy_function) = policy_function {
+            self.node_mut(id).priors = policy_function.priors(&state, &actions);
+        }
+        let pinned = [pinned, &[id]].concat();
+        let mut children = Vec::with_capacity(actions.len());
+        for action in &actions {
+            let mut new_state = state.clone();
+            apply_action(rng, &mut new_state, action);
+
+            // No need to have actor as part of possible_actions, since when
+            // expanding we use the .apply_action, and the first line there
+            // will take the actor from the action itself. This is different
+            // than how .play_tick does it, because there we need the actor
+            // to choose who plays.
+            let child = self.find_or_insert(
+                &new_state,
+                || generate_possible_actions(&new_state).1,
+                &pinned,
+            );
+            children.push(vec![child]);
+        }
+        self.node_mut(id).children = children;
+    }
+
+    /// (visits, total reward) of each of the node's actions, in order, adding up their
+    /// outcomes still in the tree.
+    pub(super) fn child_stats(&self, id: NodeId) -> Vec<(usize, f64)> {
+        self.node(id)
+            .children
+            .iter()
+            .map(|outcomes| {
+                outcomes
+                    .iter()
+                    .filter_map(|&child| self.arena.get(child))
+                    .fold((0, 0.0), |(visits, reward), x| {
+                        (visits + x.visits, reward + x.reward)
+                    })
+            })
+            .collect()
+    }
+
+    // The most visited of the outcomes still in the tree, if any was visited
+    fn most_visited(&self, outcomes: &[NodeId]) -> Option<NodeId> {
+        outcomes
+            .iter()
+            .filter_map(|&child| Some((child, self.arena.get(child)?.visits)))
+            .filter(|(_, visits)| *visits > 0)
+            .max_by_key(|(_, visits)| *visits)
+            .map(|(child, _)| child)
+    }
+
+    fn best_index(&self, id: NodeId) -> usize {
+        let (best_index, _) = self
+            .child_stats(id)
+            .into_iter()
+            .enumerate()
+            .max_by(|(_, (_, reward_a)), (_, (_, reward_b))| {
+                reward_a.partial_cmp(reward_b).unwrap()
+            })
+            .expect("There should be at least one child node");
+        best_index
+    }
+
+    /// Statistics of the node's actions, `chosen` being the one played.
+    fn report(&self, id: NodeId, chosen: usize) -> DecisionReport {
+        let node = self.node(id);
+        let actions = node
+            .actions
+            .iter()
+            .zip(&node.children)
+            .zip(self.child_stats(id))
+            .map(|((action, outcomes), (visits, reward))| ActionReport {
+                action: action.clone(),
+                visits,
+                q_value: if visits == 0 {
+                    0.0
+                } else {
+                    reward / visits as f64
+                },
+                principal_variation: self
+                    .most_visited(outcomes)
+                    .map_or(vec![], |child| self.principal_variation(child)),
+            })
+            .collect();
+        DecisionReport { chosen, actions }
+    }
+
+    // Follows the most visited actions from the node (to their most visited outcome), for
+    // as long as they were visited
+    fn principal_variation(&self, id: NodeId) -> Vec<Action> {
+        let mut variation = vec![];
+        let mut seen = HashSet::from([id]);
+        let mut current = id;
+        while let Some(node) = self.arena.get(current) {
+            let Some((index, _)) = self
+                .child_stats(current)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, (visits, _))| *visits > 0)
+                .max_by_key(|(_, (visits, _))| *visits)
+            else {
+                break;
+            };
+            // Transpositions could lead back to a node already in the variation
+            let child = self.most_visited(&node.children[index]);
+            let Some(child) = child.filter(|x| seen.insert(*x)) else {
+                break;
+            };
+            variation.push(node.actions[index].clone());
+            current = child;
+        }
+        variation
+    }
+
+    /// DOT graph of the nodes up to `depth_limit` actions below `id`, where the `chosen`-th
+    /// action was played. Nodes are drawn once even if reached by several paths. Unvisited
+    /// actions and evicted outcomes are left out.
+    fn to_dot(&self, id: NodeId, chosen: usize, depth_limit: usize) -> String {
+        let mut dot = String::from("digraph mcts {\n  node [shape=box, fontname=monospace];\n");
+        // DOT names, numbered in the order nodes are reached
+        let mut names = HashMap::from([(id, 0)]);
+        let mut queue = VecDeque::from([(id, 0)]);
+        while let Some((current, depth)) = queue.pop_front() {
+            let node = self.node(current);
+            let mean_reward = if node.visits == 0 {
+                0.0
+            } else {
+                node.reward / node.visits as f64
+            };
+            let outcome = match node.state.winner {
+                Some(GameOutcome::Win(winner)) => format!("\\nwinner: {winner}"),
+                Some(GameOutcome::Tie) => "\\ntie".to_string(),
+                None => String::new(),
+            };
+            let _ = writeln!(
+                dot,
+                "  n{} [label=\"turn {}, player {} to move\\nvisits: {}\\nQ: {:.3}{}\"{}];",
+                names[&current],
+                node.state.turn_count,
+                node.state.current_player,
+                node.visits,
+                mean_reward,
+                outcome,
+                if current == id { ", style=filled" } else { "" }
+            );
+            if depth == depth_limit {
+                continue;
+            }
+            for (index, (action, outcomes)) in node.actions.iter().zip(&node.children).enumerate() {
+                for &child in outcomes {
+                    let Some(child_node) = self.arena.get(child) else {
+                        continue;
+                    };
+                    if child_node.visits == 0 {
+                        continue;
+                    }
+                    let bold = current == id && index == chosen;
+                    let next_name = names.len();
+                    let child_name = *names.entry(child).or_insert_with(|| {
+                        queue.push_back((child, depth + 1));
+                        next_name
+                    });
+                    let _ = writeln!(
+                        dot,
+                        "  n{} -> n{} [label=\"{}\"{}];",
+                        names[&current],
+                        child_name,
+                        action.action.to_string().replace('"', "\\\""),
+                        if bold { ", style=bold" } else { "" }
+                    );
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Keeps only the subtrees under the outcomes of the index-th action of the node, so
+    /// that the next search starts from the statistics gathered for them. Drops nothing if
+    /// they were all evicted.
+    fn reroot(&mut self, id: NodeId, index: usize) {
+        let Some(outcomes) = self.arena.get(id).and_then(|x| x.children.get(index)) else {
+            return;
+        };
+        let mut stack: Vec<NodeId> = outcomes
+            .iter()
+            .copied()
+            .filter(|&child| self.arena.get(child).is_some())
+            .collect();
+        if stack.is_empty() {
+            return;
+        }
+        let mut reachable: HashSet<NodeId> = stack.iter().copied().collect();
+        while let Some(id) = stack.pop() {
+            for &grandchild in self.node(id).children.iter().flatten() {
+                if self.arena.get(grandchild).is_some() && reachable.insert(grandchild) {
+                    stack.push(grandchild);
+                }
+            }
+        }
+        self.arena.retain(|id, _| reachable.contains(&id));
+        self.node_lookup.retain(|_, id| reachable.contains(id));
+    }
+}
+
 // Struct to represent a node in the MCTS search tree
-#[derive(Clone)]
 struct MctsNode {
     state: State,
     actions: Vec<Action>,
 
-    children: Vec<MctsNode>,
+    // The outcomes sampled so far of each action
+    children: Vec<Vec<NodeId>>,
+    // Prior of each action, only when searching with a policy function
+    priors: Vec<f64>,
     visits: usize,
     reward: f64,
 }
@@ -90,6 +677,7 @@ impl MctsNode {
             state,
             actions,
             children: Vec::new(),
+            priors: Vec::new(),
             visits: 0,
             reward: 0.0,
         }
@@ -99,50 +687,20 @@ impl MctsNode {
         self.state.winner.is_some()
     }
 
-    fn expand(&mut self, rng: &mut StdRng, node_lookup: &mut HashMap<State, MctsNode>) {
-        for action in &self.actions {
-            let mut new_state = self.state.clone();
-            apply_action(rng, &mut new_state, action);
-
-            let node = node_lookup.get(&new_state).cloned();
-            if node.is_none() {
-                debug!("Missed a node in the lookup table");
-            } else {
-                debug!("Found a node in the lookup table");
-            }
-
-            // No need to have actor as part of possible_actions, since when
-            // expanding we use the .apply_action, and the first line there
-            // will take the actor from the action itself. This is different
-            // than how .play_tick does it, because there we need the actor
-            // to choose who plays.
-            let (_, new_actions) = generate_possible_actions(&new_state);
-            self.children.push(MctsNode::new(new_state, new_actions));
-        }
-    }
+    // Simulate a playout from the current state with the rollout policy, and return 1 or -1 or 0
+    fn simulate(&self, rng: &mut StdRng, investigator: usize, policy: &RolloutPolicy) -> f64 {
+        let rollout_players: Vec<Box<dyn Player>> = vec![policy(), policy()];
+        let seed: u64 = rng.gen();
 
-    fn select(&mut self, rng: &mut StdRng) -> &mut Self {
-        if self.children.is_empty() {
-            self
-        } else {
-            self.children.choose_mut(rng).unwrap()
+        // The order of the decks is unknown to both players, so don't let the
+        // playout rely on the one the state happens to have.
+        let mut state = self.state.clone();
+        for player in 0..2 {
+            state.deck_mut(player).shuffle(false, rng);
         }
-    }
-
-    // Simulate a random playout from the current state, and return 1 or -1 or 0
-    fn simulate(&self, rng: &mut StdRng, investigator: usize) -> f64 {
-        let random_players: Vec<Box<dyn Player>> = vec![
-            Box::new(RandomPlayer {
-                deck: Deck::default(),
-            }),
-            Box::new(RandomPlayer {
-                deck: Deck::default(),
-            }),
-        ];
-        let seed: u64 = rng.gen();
 
         // Since we emplace the state, we can keep using our "seating position" as investigator
-        let mut game_copy = Game::from_state(self.state.clone(), random_players, seed);
+        let mut game_copy = Game::from_state(state, rollout_players, seed);
         let outcome = game_copy.play();
 
         // If winner is my ID, return 1.0, if winner is opponent ID, return -1.0, else return 0.0
@@ -162,16 +720,198 @@ impl MctsNode {
         self.visits += 1;
         self.reward += reward;
     }
+}
 
-    fn best_action(&self) -> Action {
-        let (best_index, _) = self
-            .children
-            .iter()
-            .enumerate()
-            .max_by(|(_, child_a), (_, child_b)| {
-                child_a.reward.partial_cmp(&child_b.reward).unwrap()
-            })
-            .expect("There should be at least one child node");
-        self.actions[best_index].clone()
+/// Action maximizing Q + c * P * sqrt(N) / (1 + n), as in AlphaZero, given the (visits,
+/// total reward) and prior of each action.
+fn puct_index(stats: &[(usize, f64)], priors: &[f64], exploration: f64) -> usize {
+    let total_visits: usize = stats.iter().map(|x| x.0).sum();
+    let exploration = exploration * (total_visits.max(1) as f64).sqrt();
+    let scores = stats.iter().zip(priors).map(|((visits, reward), prior)| {
+        let mean_reward = if *visits == 0 {
+            0.0
+        } else {
+            reward / *visits as f64
+        };
+        mean_reward + exploration * prior / (1 + visits) as f64
+    });
+    scores
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        players::{create_players, PlayerCode},
+        test_helpers::load_test_decks,
+    };
+
+    #[test]
+    fn test_tree_stays_within_node_budget() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let mut game = Game::new(players, 7);
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut tree = SearchTree::new(40);
+        for _ in 0..20 {
+            let state = game.get_state_clone();
+            if state.is_game_over() {
+                break;
+            }
+            let (_, actions) = generate_possible_actions(&state);
+            let hooks = SearchHooks::default();
+            let root = search(&mut rng, &state, actions.clone(), 20, &mut tree, &hooks);
+            assert!(tree.len() <= 40);
+            assert_eq!(tree.child_stats(root).len(), actions.len());
+            game.play_tick();
+        }
+    }
+
+    #[test]
+    fn test_smallest_tree_can_search() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let mut game = Game::new(players, 7);
+        while game.get_state_clone().turn_count < 3 {
+            game.play_tick();
+        }
+        let state = game.get_state_clone();
+        let (_, actions) = generate_possible_actions(&state);
+        let mut tree = SearchTree::new(MIN_MAX_NODES);
+        let hooks = SearchHooks::default();
+        let mut rng = StdRng::seed_from_u64(7);
+        search(&mut rng, &state, actions, 20, &mut tree, &hooks);
+        assert!(tree.len() <= MIN_MAX_NODES);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 3 nodes")]
+    fn test_tree_too_small_to_expand_panics() {
+        SearchTree::new(2);
+    }
+
+    #[test]
+    fn test_reroot_keeps_subtree_of_chosen_action() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let state = Game::new(players, 3).get_state_clone();
+        let (_, actions) = generate_possible_actions(&state);
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut tree = SearchTree::new(DEFAULT_MAX_NODES);
+        let hooks = SearchHooks::default();
+
+        let root = search(&mut rng, &state, actions, 50, &mut tree, &hooks);
+        let index = tree.best_index(root);
+        let child = tree.node(root).children[index][0];
+        let child_state = tree.node(child).state.clone();
+        let child_stats = tree.child_stats(child);
+        let nodes_before = tree.len();
+
+        tree.reroot(root, index);
+        assert!(tree.len() < nodes_before);
+        assert!(tree.arena.get(root).is_none());
+        assert_eq!(tree.child_stats(child), child_stats);
+
+        // Searching the position after the chosen action starts from the kept node
+        let (_, child_actions) = generate_possible_actions(&child_state);
+        let new_root = search(&mut rng, &child_state, child_actions, 10, &mut tree, &hooks);
+        assert_eq!(new_root, child);
+    }
+
+    #[test]
+    fn test_decision_report_explains_the_chosen_action() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a.clone(), deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let state = Game::new(players, 5).get_state_clone();
+        let (_, actions) = generate_possible_actions(&state);
+        let mut player = MctsPlayer::new(deck_a, 100);
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let (action, report) = player.decide_with_report(&mut rng, &state, actions.clone());
+        assert_eq!(report.actions.len(), actions.len());
+        assert_eq!(report.actions[report.chosen].action, action);
+        // The first iteration only expands the root
+        assert_eq!(report.actions.iter().map(|x| x.visits).sum::<usize>(), 99);
+        for entry in &report.actions {
+            assert!((-1.0..=1.0).contains(&entry.q_value));
+        }
+
+        // The search goes deeper than the root's children, giving a principal variation
+        let variation = &report.actions[report.chosen].principal_variation;
+        assert!(!variation.is_empty());
+        let (root, _) = player.last_choice.unwrap();
+        let tree = &player.tree;
+        let child = tree.most_visited(&tree.node(root).children[report.chosen]);
+        assert!(tree.node(child.unwrap()).actions.contains(&variation[0]));
+    }
+
+    #[test]
+    fn test_progressive_widening_bounds_outcomes() {
+        let config = MctsConfig::default();
+        assert_eq!(config.max_outcomes(0), 1);
+        assert_eq!(config.max_outcomes(4), 2);
+        assert_eq!(config.max_outcomes(10), 4);
+
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let mut game = Game::new(players, 11);
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut tree = SearchTree::new(DEFAULT_MAX_NODES);
+        let hooks = SearchHooks::default();
+        while game.get_state().turn_count < 4 && !game.get_state().is_game_over() {
+            let state = game.get_state_clone();
+            let (_, actions) = generate_possible_actions(&state);
+            let root = search(&mut rng, &state, actions, 50, &mut tree, &hooks);
+            let stats = tree.child_stats(root);
+            for (outcomes, (visits, _)) in tree.node(root).children.iter().zip(stats) {
+                assert!(outcomes.len() <= config.max_outcomes(visits));
+            }
+            game.play_tick();
+        }
+    }
+
+    #[test]
+    fn test_export_tree_dot() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a.clone(), deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let state = Game::new(players, 5).get_state_clone();
+        let (_, actions) = generate_possible_actions(&state);
+        let mut player = MctsPlayer::new(deck_a, 50);
+        assert_eq!(player.export_tree_dot(3), "digraph mcts {\n}\n");
+
+        let mut rng = StdRng::seed_from_u64(5);
+        player.decide_with_full_state(&mut rng, &state, actions);
+        let root_only = player.export_tree_dot(0);
+        assert_eq!(root_only.matches(" [label=").count(), 1);
+        assert!(!root_only.contains("->"));
+
+        let dot = player.export_tree_dot(2);
+        assert!(dot.starts_with("digraph mcts {") && dot.ends_with("}\n"));
+        assert_eq!(dot.matches("style=bold").count(), 1);
+        // Every edge points to a drawn node
+        for line in dot.lines().filter(|x| x.contains(" -> ")) {
+            let target = line
+                .split(" -> ")
+                .nth(1)
+                .unwrap()
+                .split(' ')
+                .next()
+                .unwrap();
+            assert!(dot.contains(&format!("  {target} [label=")));
+        }
+        assert!(player.export_tree_dot(3).len() >= dot.len());
+    }
+
+    #[test]
+    fn test_puct_prefers_priors_of_unvisited_actions() {
+        let stats = [(10, 5.0), (0, 0.0), (0, 0.0)];
+        assert_eq!(puct_index(&stats, &[0.2, 0.1, 0.7], 1.5), 2);
+        assert_eq!(puct_index(&stats, &[0.2, 0.1, 0.7], 0.0), 0);
     }
 }
@@ -1,23 +1,56 @@
+#[cfg(feature = "tokio")]
+mod async_player;
 mod attach_attack_player;
 mod end_turn_player;
+mod ensemble_player;
+mod evaluation;
 mod expectiminimax_player;
+mod external_player;
+mod greedy_damage_player;
 mod human_player;
 mod mcts_player;
+mod node_arena;
+#[cfg(feature = "onnx")]
+mod onnx;
+mod opening_book;
+mod opponent_model;
+mod parallel_mcts_player;
 mod random_player;
+#[cfg(feature = "remote")]
+mod remote_player;
+#[cfg(feature = "scripting")]
+mod scripted_player;
+mod stall_player;
 mod value_function_player;
 mod weighted_random_player;
 
+#[cfg(feature = "tokio")]
+pub use async_player::{ActionFuture, AsyncPlayer};
 pub use attach_attack_player::AttachAttackPlayer;
 use clap::ValueEnum;
 pub use end_turn_player::EndTurnPlayer;
+pub use ensemble_player::EnsemblePlayer;
+pub use evaluation::{PolicyFunction, ValueFunction};
 pub use expectiminimax_player::ExpectiMiniMaxPlayer;
+pub use external_player::ExternalPlayer;
+pub use greedy_damage_player::GreedyDamagePlayer;
 pub use human_player::HumanPlayer;
-pub use mcts_player::MctsPlayer;
+pub use mcts_player::{ActionReport, DecisionReport, MctsConfig, MctsPlayer, RolloutPolicy};
+#[cfg(feature = "onnx")]
+pub use onnx::{ActionEncoder, OnnxPolicyFunction, OnnxValueFunction, StateEncoder};
+pub use opening_book::{OpeningBook, OpeningBookPlayer, OpeningKey, BOOK_TURNS};
+pub use opponent_model::{determinize, ArchetypeModel, OpponentModel};
+pub use parallel_mcts_player::ParallelMctsPlayer;
 pub use random_player::RandomPlayer;
+#[cfg(feature = "remote")]
+pub use remote_player::{DecisionReply, DecisionRequest, RemoteFallback, RemotePlayer};
+#[cfg(feature = "scripting")]
+pub use scripted_player::ScriptedPlayer;
+pub use stall_player::StallPlayer;
 pub use value_function_player::ValueFunctionPlayer;
 pub use weighted_random_player::WeightedRandomPlayer;
 
-use crate::{actions::Action, Deck, State};
+use crate::{actions::Action, observation::Observation, Deck, State};
 use rand::rngs::StdRng;
 use std::fmt::Debug;
 
@@ -29,6 +62,92 @@ pub trait Player: Debug {
         state: &State,
         possible_actions: Vec<Action>,
     ) -> Action;
+
+    /// Called with every action played in the game, by either player, once applied.
+    fn observe_action(&mut self, _action: &Action) {}
+
+    /// Whether the player's actions are submitted from outside the game instead of being
+    /// returned by `decision_fn`, see `Game::poll_decision`.
+    fn is_external(&self) -> bool {
+        false
+    }
+}
+
+/// A player that can only see what it would see at a real table: its hand, the board,
+/// the discard piles and the sizes of the hidden zones (see `Observation`). Every
+/// `FairPlayer` is a `Player`. Search players that simulate the game need the full
+/// state, so they are `CheatingPlayer`s instead.
+pub trait FairPlayer: Debug {
+    fn deck(&self) -> Deck;
+    fn decide(
+        &mut self,
+        rng: &mut StdRng,
+        observation: &Observation,
+        possible_actions: Vec<Action>,
+    ) -> Action;
+}
+
+impl<T: FairPlayer> Player for T {
+    fn get_deck(&self) -> Deck {
+        self.deck()
+    }
+
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let player = possible_actions
+            .first()
+            .map_or(state.current_player, |x| x.actor);
+        self.decide(rng, &Observation::new(state, player), possible_actions)
+    }
+}
+
+/// A player that decides with the full state, the opponent's hand and the order of both
+/// decks included, like the search players that simulate the game. It only becomes a
+/// `Player` through `CheatingPlayerAdapter`, so that letting a seat see everything is
+/// spelled out where the player is created.
+pub trait CheatingPlayer: Debug {
+    fn deck(&self) -> Deck;
+    fn decide_with_full_state(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action;
+
+    /// See `Player::observe_action`.
+    fn observe_action(&mut self, _action: &Action) {}
+}
+
+/// Seats a `CheatingPlayer`, e.g. `CheatingPlayerAdapter(MctsPlayer::new(deck, 100))`.
+pub struct CheatingPlayerAdapter<P>(pub P);
+
+impl<P: CheatingPlayer> Player for CheatingPlayerAdapter<P> {
+    fn get_deck(&self) -> Deck {
+        self.0.deck()
+    }
+
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        self.0.decide_with_full_state(rng, state, possible_actions)
+    }
+
+    fn observe_action(&mut self, action: &Action) {
+        self.0.observe_action(action);
+    }
+}
+
+impl<P: Debug> Debug for CheatingPlayerAdapter<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 /// Enum for allowed player strategies
@@ -40,8 +159,11 @@ pub enum PlayerCode {
     H,
     W,
     M,
+    PM,
     V,
     E,
+    GD,
+    ST,
 }
 /// Custom parser function enforcing case-insensitivity
 pub fn parse_player_code(s: &str) -> Result<PlayerCode, String> {
@@ -52,8 +174,11 @@ pub fn parse_player_code(s: &str) -> Result<PlayerCode, String> {
         "h" => Ok(PlayerCode::H),
         "w" => Ok(PlayerCode::W),
         "m" => Ok(PlayerCode::M),
+        "pm" => Ok(PlayerCode::PM),
         "v" => Ok(PlayerCode::V),
         "e" => Ok(PlayerCode::E),
+        "gd" => Ok(PlayerCode::GD),
+        "st" => Ok(PlayerCode::ST),
         _ => Err(format!("Invalid player code: {s}")),
     }
 }
@@ -86,6 +211,11 @@ pub fn create_players(
     vec![player_a, player_b]
 }
 
+/// Creates players of the given strategy, e.g. for `estimate_win_probability`.
+pub fn rollout_policy(player: PlayerCode) -> RolloutPolicy {
+    Box::new(move || get_player(Deck::default(), &player))
+}
+
 fn get_player(deck: Deck, player: &PlayerCode) -> Box<dyn Player> {
     match player {
         PlayerCode::AA => Box::new(AttachAttackPlayer { deck }),
@@ -93,8 +223,11 @@ fn get_player(deck: Deck, player: &PlayerCode) -> Box<dyn Player> {
         PlayerCode::R => Box::new(RandomPlayer { deck }),
         PlayerCode::H => Box::new(HumanPlayer { deck }),
         PlayerCode::W => Box::new(WeightedRandomPlayer { deck }),
-        PlayerCode::M => Box::new(MctsPlayer::new(deck, 100)),
+        PlayerCode::M => Box::new(CheatingPlayerAdapter(MctsPlayer::new(deck, 100))),
+        PlayerCode::PM => Box::new(CheatingPlayerAdapter(ParallelMctsPlayer::new(deck, 100))),
         PlayerCode::V => Box::new(ValueFunctionPlayer { deck }),
-        PlayerCode::E => Box::new(ExpectiMiniMaxPlayer { deck, max_depth: 3 }),
+        PlayerCode::E => Box::new(CheatingPlayerAdapter(ExpectiMiniMaxPlayer::new(deck, 3))),
+        PlayerCode::GD => Box::new(CheatingPlayerAdapter(GreedyDamagePlayer::new(deck))),
+        PlayerCode::ST => Box::new(CheatingPlayerAdapter(StallPlayer { deck })),
     }
 }
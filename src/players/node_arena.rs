@@ -0,0 +1,214 @@
+/// Handle to a value in a `NodeArena`. Handles of evicted values are detected as stale
+/// (instead of pointing to whatever reused their slot) thanks to the generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+    // Neighbours in the least-recently-used list, only meaningful while occupied
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Pool of at most `max_len` values that reuses the slots of evicted values. When full,
+/// inserting evicts the least recently inserted or touched value.
+pub(super) struct NodeArena<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    len: usize,
+    max_len: usize,
+    // Least and most recently used occupied slots
+    lru_head: Option<usize>,
+    lru_tail: Option<usize>,
+}
+
+impl<T> NodeArena<T> {
+    pub(super) fn new(max_len: usize) -> Self {
+        assert!(
+            max_len > 0,
+            "Arena should be able to hold at least one value"
+        );
+        Self {
+            slots: vec![],
+            free: vec![],
+            len: 0,
+            max_len,
+            lru_head: None,
+            lru_tail: None,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(super) fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Inserts the value as the most recently used one. If the arena is full, first evicts
+    /// the least recently used value not in `pinned`, and returns it.
+    pub(super) fn insert(&mut self, value: T, pinned: &[NodeId]) -> (NodeId, Option<T>) {
+        let evicted = if self.len == self.max_len {
+            self.evict_lru(pinned)
+        } else {
+            None
+        };
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: None,
+                    prev: None,
+                    next: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.slots[index].value = Some(value);
+        self.len += 1;
+        self.push_back(index);
+        let id = NodeId {
+            index,
+            generation: self.slots[index].generation,
+        };
+        (id, evicted)
+    }
+
+    pub(super) fn get(&self, id: NodeId) -> Option<&T> {
+        let slot = self.slots.get(id.index)?;
+        if slot.generation != id.generation {
+            return None;
+        }
+        slot.value.as_ref()
+    }
+
+    pub(super) fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        let slot = self.slots.get_mut(id.index)?;
+        if slot.generation != id.generation {
+            return None;
+        }
+        slot.value.as_mut()
+    }
+
+    /// Marks the value as the most recently used one.
+    pub(super) fn touch(&mut self, id: NodeId) {
+        if self.get(id).is_some() {
+            self.unlink(id.index);
+            self.push_back(id.index);
+        }
+    }
+
+    /// Removes the values for which `keep` returns false, freeing their slots.
+    pub(super) fn retain(&mut self, mut keep: impl FnMut(NodeId, &T) -> bool) {
+        for index in 0..self.slots.len() {
+            let slot = &self.slots[index];
+            let id = NodeId {
+                index,
+                generation: slot.generation,
+            };
+            if slot.value.as_ref().is_some_and(|value| !keep(id, value)) {
+                self.remove_slot(index);
+            }
+        }
+    }
+
+    fn evict_lru(&mut self, pinned: &[NodeId]) -> Option<T> {
+        let mut candidate = self.lru_head;
+        while let Some(index) = candidate {
+            let generation = self.slots[index].generation;
+            if !pinned.contains(&NodeId { index, generation }) {
+                break;
+            }
+            candidate = self.slots[index].next;
+        }
+        let index = candidate.expect("Arena should not be full of pinned values");
+        self.remove_slot(index)
+    }
+
+    fn remove_slot(&mut self, index: usize) -> Option<T> {
+        self.unlink(index);
+        let slot = &mut self.slots[index];
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(index);
+        self.len -= 1;
+        slot.value.take()
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.slots[index].prev, self.slots[index].next);
+        match prev {
+            Some(prev) => self.slots[prev].next = next,
+            None => self.lru_head = next,
+        }
+        match next {
+            Some(next) => self.slots[next].prev = prev,
+            None => self.lru_tail = prev,
+        }
+    }
+
+    fn push_back(&mut self, index: usize) {
+        self.slots[index].prev = self.lru_tail;
+        self.slots[index].next = None;
+        match self.lru_tail {
+            Some(tail) => self.slots[tail].next = Some(index),
+            None => self.lru_head = Some(index),
+        }
+        self.lru_tail = Some(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut arena = NodeArena::new(2);
+        let (a, _) = arena.insert("a", &[]);
+        let (b, _) = arena.insert("b", &[]);
+        arena.touch(a);
+
+        let (c, evicted) = arena.insert("c", &[]);
+        assert_eq!(evicted, Some("b"));
+        assert_eq!(arena.len(), 2);
+        assert_eq!(arena.get(a), Some(&"a"));
+        assert_eq!(arena.get(c), Some(&"c"));
+
+        // b's slot got reused, but its handle doesn't see the new value
+        assert_eq!(c.index, b.index);
+        assert_eq!(arena.get(b), None);
+    }
+
+    #[test]
+    fn test_pinned_values_are_not_evicted() {
+        let mut arena = NodeArena::new(2);
+        let (a, _) = arena.insert(1, &[]);
+        let (b, _) = arena.insert(2, &[]);
+        let (_, evicted) = arena.insert(3, &[a]);
+        assert_eq!(evicted, Some(2));
+        assert_eq!(arena.get(a), Some(&1));
+        assert_eq!(arena.get_mut(b), None);
+    }
+
+    #[test]
+    fn test_retain_frees_slots() {
+        let mut arena = NodeArena::new(3);
+        let (a, _) = arena.insert(1, &[]);
+        let (b, _) = arena.insert(2, &[]);
+        arena.retain(|_, x| *x == 2);
+        assert_eq!(arena.len(), 1);
+        assert_eq!(arena.get(a), None);
+
+        // Freed slots are reused before anything gets evicted
+        let (_, evicted) = arena.insert(3, &[]);
+        let (_, evicted_too) = arena.insert(4, &[]);
+        assert_eq!((evicted, evicted_too), (None, None));
+        assert_eq!(arena.get(b), Some(&2));
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    path::Path,
+    sync::{Mutex, PoisonError},
+};
+
+use log::warn;
+use ort::{
+    session::Session,
+    tensor::TensorElementType,
+    value::{Tensor, ValueType},
+};
+
+use super::{PolicyFunction, ValueFunction};
+use crate::{
+    actions::{Action, ActionIndex},
+    State,
+};
+
+/// Turns a state, seen by the given player, into the network's input features, e.g.
+/// `State::encode_observation`.
+pub type StateEncoder = fn(&State, usize) -> Vec<f32>;
+
+/// Position of an action in the network's policy output, e.g. `ActionIndex::encode`.
+pub type ActionEncoder = fn(&Action) -> usize;
+
+/// Loads the model, checking that it takes the `[1, features]` the encoder makes and
+/// outputs `[1, outputs]` f32s (dimensions the model leaves dynamic fit any size).
+fn load_session(
+    path: impl AsRef<Path>,
+    encoder: StateEncoder,
+    outputs: usize,
+) -> ort::Result<Mutex<Session>> {
+    let session = Session::builder()?.commit_from_file(path)?;
+    let features = encoder(&State::default(), 0).len();
+    check_shape(
+        "input",
+        session.inputs.first().map(|x| &x.input_type),
+        features,
+    )?;
+    check_shape(
+        "output",
+        session.outputs.first().map(|x| &x.output_type),
+        outputs,
+    )?;
+    Ok(Mutex::new(session))
+}
+
+fn check_shape(name: &str, value_type: Option<&ValueType>, size: usize) -> ort::Result<()> {
+    let fits = |dim: i64, size: usize| dim == -1 || dim == size as i64;
+    let shape = value_type
+        .filter(|x| x.tensor_type() == Some(TensorElementType::Float32))
+        .and_then(|x| x.tensor_shape());
+    match shape.map(|x| &x[..]) {
+        Some(&[batch, dim]) if fits(batch, 1) && fits(dim, size) => Ok(()),
+        _ => Err(ort::Error::new(format!(
+            "Model {name} should be [1, {size}] f32s, got {value_type:?}"
+        ))),
+    }
+}
+
+/// Runs the model on a batch of one state and returns its first output.
+fn run(session: &Mutex<Session>, features: Vec<f32>) -> ort::Result<Vec<f32>> {
+    let input = Tensor::from_array(([1, features.len()], features))?;
+    // A panic elsewhere doesn't leave the session half run
+    let mut session = session.lock().unwrap_or_else(PoisonError::into_inner);
+    let outputs = session.run(ort::inputs![input])?;
+    let (_, output) = outputs[0].try_extract_tensor::<f32>()?;
+    Ok(output.to_vec())
+}
+
+/// Value head of a network trained outside of the crate, run with ONNX Runtime to plug it
+/// into the searches. The model takes a `[1, features]` input and outputs a `[1, 1]` score.
+/// Scores 0 (and logs a warning) if the model fails to run.
+pub struct OnnxValueFunction {
+    session: Mutex<Session>,
+    encoder: StateEncoder,
+}
+
+impl OnnxValueFunction {
+    pub fn load(path: impl AsRef<Path>, encoder: StateEncoder) -> ort::Result<Self> {
+        Ok(Self {
+            session: load_session(path, encoder, 1)?,
+            encoder,
+        })
+    }
+}
+
+impl ValueFunction for OnnxValueFunction {
+    fn evaluate(&self, state: &State, player: usize) -> f64 {
+        match run(&self.session, (self.encoder)(state, player)) {
+            Ok(output) => output.first().map_or(0.0, |x| *x as f64),
+            Err(err) => {
+                warn!("ONNX value model failed, scoring 0: {err}");
+                0.0
+            }
+        }
+    }
+}
+
+/// Policy head of a model taking a `[1, features]` input and outputting
+/// `[1, ActionIndex::SPACE_SIZE]` logits. The logits of the legal actions are softmaxed
+/// into priors. Priors are uniform (and a warning logged) if the model fails to run or the
+/// action encoder points past its output.
+pub struct OnnxPolicyFunction {
+    session: Mutex<Session>,
+    encoder: StateEncoder,
+    action_encoder: ActionEncoder,
+}
+
+impl OnnxPolicyFunction {
+    pub fn load(
+        path: impl AsRef<Path>,
+        encoder: StateEncoder,
+        action_encoder: ActionEncoder,
+    ) -> ort::Result<Self> {
+        Ok(Self {
+            session: load_session(path, encoder, ActionIndex::SPACE_SIZE)?,
+            encoder,
+            action_encoder,
+        })
+    }
+
+    fn legal_logits(&self, state: &State, actions: &[Action]) -> Result<Vec<f64>, String> {
+        let player = actions.first().map_or(state.current_player, |x| x.actor);
+        let logits =
+            run(&self.session, (self.encoder)(state, player)).map_err(|x| x.to_string())?;
+        actions
+            .iter()
+            .map(|x| {
+                let index = (self.action_encoder)(x);
+                logits.get(index).map(|x| *x as f64).ok_or(format!(
+                    "{} is encoded as {index}, past the {} logits",
+                    x.action,
+                    logits.len()
+                ))
+            })
+            .collect()
+    }
+}
+
+impl PolicyFunction for OnnxPolicyFunction {
+    fn priors(&self, state: &State, actions: &[Action]) -> Vec<f64> {
+        let legal_logits = match self.legal_logits(state, actions) {
+            Ok(legal_logits) => legal_logits,
+            Err(err) => {
+                warn!("ONNX policy model failed, using uniform priors: {err}");
+                return vec![1.0 / actions.len() as f64; actions.len()];
+            }
+        };
+        let max = legal_logits
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let exps: Vec<f64> = legal_logits.iter().map(|x| (x - max).exp()).collect();
+        let total: f64 = exps.iter().sum();
+        exps.iter().map(|x| x / total).collect()
+    }
+}
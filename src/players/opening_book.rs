@@ -0,0 +1,224 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    actions::{Action, ActionIndex},
+    Deck, Game, State,
+};
+
+use super::{CheatingPlayerAdapter, MctsPlayer, Player};
+
+/// Last turn the book covers. Setup (turn 0) and the first turn of each player are
+/// repetitive enough to be worth precomputing.
+pub const BOOK_TURNS: u8 = 2;
+
+/// What an opening decision depends on: the turn, whether the deciding player went first,
+/// and the cards in their hand (card ids, sorted).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OpeningKey {
+    pub turn: u8,
+    pub going_first: bool,
+    pub hand: Vec<String>,
+}
+
+impl OpeningKey {
+    pub fn new(state: &State, player: usize) -> Self {
+        // The player going first plays the odd turns, and sets up first
+        let going_first = if state.turn_count == 0 {
+            state.current_player == player
+        } else {
+            (state.turn_count % 2 == 1) == (state.current_player == player)
+        };
+        let mut hand: Vec<String> = state.hands[player].iter().map(|x| x.get_id()).collect();
+        hand.sort();
+        Self {
+            turn: state.turn_count,
+            going_first,
+            hand,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct BookEntry {
+    #[serde(flatten)]
+    key: OpeningKey,
+    action: usize,
+}
+
+/// Best actions of the first turns, by `OpeningKey`. Actions are stored as their
+/// `ActionIndex`, so a book can be saved and loaded as JSON.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OpeningBook {
+    actions: HashMap<OpeningKey, ActionIndex>,
+}
+
+impl OpeningBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Remembers `action` as the best one in its position (replacing any previous one).
+    /// Ignores positions past `BOOK_TURNS`.
+    pub fn record(&mut self, state: &State, action: &Action) {
+        if state.turn_count <= BOOK_TURNS {
+            let key = OpeningKey::new(state, action.actor);
+            self.actions.insert(key, ActionIndex::of(&action.action));
+        }
+    }
+
+    /// The booked action among `possible_actions`, if the position is in the book.
+    pub fn lookup<'a>(&self, state: &State, possible_actions: &'a [Action]) -> Option<&'a Action> {
+        let actor = possible_actions.first()?.actor;
+        self.actions
+            .get(&OpeningKey::new(state, actor))?
+            .find(possible_actions)
+    }
+
+    /// Fills a book with the decisions an `MctsPlayer` of `iterations` takes in the first
+    /// turns of `num_games` games between the decks. Positions seen in several games keep
+    /// their first decision.
+    pub fn precompute(
+        deck_a: &Deck,
+        deck_b: &Deck,
+        iterations: u64,
+        num_games: u32,
+        seed: u64,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut book = Self::new();
+        for _ in 0..num_games {
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(CheatingPlayerAdapter(MctsPlayer::new(
+                    deck_a.clone(),
+                    iterations,
+                ))),
+                Box::new(CheatingPlayerAdapter(MctsPlayer::new(
+                    deck_b.clone(),
+                    iterations,
+                ))),
+            ];
+            let mut game = Game::new(players, rng.gen());
+            while game.get_state().turn_count <= BOOK_TURNS && !game.get_state().is_game_over() {
+                let state = game.get_state_clone();
+                let action = game.play_tick();
+                let key = OpeningKey::new(&state, action.actor);
+                if !book.actions.contains_key(&key) {
+                    book.record(&state, &action);
+                }
+            }
+        }
+        book
+    }
+
+    /// A flat list of `{"turn", "going_first", "hand", "action"}` entries.
+    pub fn to_json(&self) -> String {
+        let entries: Vec<BookEntry> = self
+            .actions
+            .iter()
+            .map(|(key, action)| BookEntry {
+                key: key.clone(),
+                action: action.0,
+            })
+            .collect();
+        serde_json::to_string_pretty(&entries).expect("Opening book should serialize")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let entries: Vec<BookEntry> = serde_json::from_str(json).map_err(|x| x.to_string())?;
+        let actions = entries
+            .into_iter()
+            .map(|x| (x.key, ActionIndex(x.action)))
+            .collect();
+        Ok(Self { actions })
+    }
+}
+
+/// Plays the booked action when there is one, and lets `fallback` decide otherwise.
+pub struct OpeningBookPlayer<P: Player> {
+    pub book: OpeningBook,
+    pub fallback: P,
+}
+
+impl<P: Player> OpeningBookPlayer<P> {
+    pub fn new(book: OpeningBook, fallback: P) -> Self {
+        Self { book, fallback }
+    }
+}
+
+impl<P: Player> Player for OpeningBookPlayer<P> {
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        if let Some(action) = self.book.lookup(state, &possible_actions) {
+            return action.clone();
+        }
+        self.fallback.decision_fn(rng, state, possible_actions)
+    }
+
+    fn get_deck(&self) -> Deck {
+        self.fallback.get_deck()
+    }
+}
+
+impl<P: Player> Debug for OpeningBookPlayer<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OpeningBookPlayer ({} positions) over {:?}",
+            self.book.len(),
+            self.fallback
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate_possible_actions, test_helpers::load_test_decks};
+
+    #[test]
+    fn test_book_round_trips_through_json() {
+        let (deck_a, deck_b) = load_test_decks();
+        let book = OpeningBook::precompute(&deck_a, &deck_b, 10, 2, 0);
+        assert!(!book.is_empty());
+        assert_eq!(OpeningBook::from_json(&book.to_json()).unwrap(), book);
+        assert!(OpeningBook::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_player_follows_the_book() {
+        let (deck_a, deck_b) = load_test_decks();
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(1));
+        let (_, actions) = generate_possible_actions(&state);
+        let booked = actions.last().unwrap().clone();
+        let mut book = OpeningBook::new();
+        book.record(&state, &booked);
+        assert_eq!(book.lookup(&state, &actions), Some(&booked));
+
+        let fallback = CheatingPlayerAdapter(MctsPlayer::new(deck_a, 1));
+        let mut player = OpeningBookPlayer::new(book, fallback);
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(player.decision_fn(&mut rng, &state, actions), booked);
+
+        // Positions past the opening are never booked
+        let mut late_state = state.clone();
+        late_state.turn_count = BOOK_TURNS + 1;
+        let mut book = OpeningBook::new();
+        book.record(&late_state, &booked);
+        assert!(book.is_empty());
+    }
+}
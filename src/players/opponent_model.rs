@@ -0,0 +1,302 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::{
+    actions::{Action, SimpleAction},
+    observation::Observation,
+    types::Card,
+    Deck, State,
+};
+
+/// Learns about the opponent during a game, from what a player sees before each of its
+/// decisions and from every action played, to guess the cards they hold. Search players
+/// use it to `determinize` the states they search instead of peeking at the real ones.
+pub trait OpponentModel: Debug {
+    /// What the player sees before each of its decisions.
+    fn observe(&mut self, observation: &Observation);
+
+    /// Every action played in the game, by either player (see `Player::observe_action`).
+    fn observe_action(&mut self, _action: &Action) {}
+
+    /// The cards the opponent likely holds in hand and deck. `determinize` deals them out,
+    /// so it is fine to return more or fewer than they hold. Empty if the model has no idea.
+    fn likely_unseen_cards(&self, observation: &Observation) -> Vec<Card>;
+}
+
+/// A copy of the state as `player` could imagine it: the opponent's hand and deck dealt
+/// from `model.likely_unseen_cards` (or from their decklist if the model has no idea), and
+/// the player's own deck shuffled. Hand and deck sizes are kept.
+pub fn determinize(
+    model: &dyn OpponentModel,
+    rng: &mut StdRng,
+    state: &State,
+    player: usize,
+) -> State {
+    let opponent = (player + 1) % 2;
+    let hand_size = state.hands[opponent].len();
+    let unseen = hand_size + state.decks[opponent].cards.len();
+    let mut cards = model.likely_unseen_cards(&Observation::new(state, player));
+    if cards.is_empty() {
+        cards = state.hands[opponent]
+            .iter()
+            .chain(&state.decks[opponent].cards)
+            .cloned()
+            .collect();
+    }
+    cards.shuffle(rng);
+    while !cards.is_empty() && cards.len() < unseen {
+        let card = cards[rng.gen_range(0..cards.len())].clone();
+        cards.push(card);
+    }
+    cards.truncate(unseen);
+
+    let mut state = state.clone();
+    state.deck_mut(opponent).cards = cards.split_off(hand_size.min(cards.len()));
+    state.hands[opponent] = cards;
+    state.deck_mut(player).shuffle(false, rng);
+    state
+}
+
+/// Tells apart the decks the opponent may be playing (e.g. the decks of a meta) by the
+/// cards they reveal. An archetype is as likely as drawing the revealed cards from its
+/// deck; those lacking copies of a revealed card are ruled out.
+#[derive(Debug, Clone)]
+pub struct ArchetypeModel {
+    archetypes: Vec<(String, Deck)>,
+    player: Option<usize>,
+    // Copies of each card (by name) the opponent is known to play
+    revealed: HashMap<String, usize>,
+    // Cards played by each player, counted from their actions
+    played: [HashMap<String, usize>; 2],
+}
+
+impl ArchetypeModel {
+    pub fn new(archetypes: Vec<(String, Deck)>) -> Self {
+        Self {
+            archetypes,
+            player: None,
+            revealed: HashMap::new(),
+            played: [HashMap::new(), HashMap::new()],
+        }
+    }
+
+    /// Probability of each archetype, in the order they were given. All 0 if the revealed
+    /// cards rule every archetype out.
+    pub fn archetype_probabilities(&self) -> Vec<(&str, f64)> {
+        let likelihoods: Vec<f64> = self
+            .archetypes
+            .iter()
+            .map(|(_, deck)| self.likelihood(deck))
+            .collect();
+        let total: f64 = likelihoods.iter().sum();
+        self.archetypes
+            .iter()
+            .zip(likelihoods)
+            .map(|((name, _), likelihood)| {
+                let probability = if total > 0.0 { likelihood / total } else { 0.0 };
+                (name.as_str(), probability)
+            })
+            .collect()
+    }
+
+    /// The most likely archetype, if any fits the revealed cards.
+    pub fn likely_archetype(&self) -> Option<&str> {
+        self.most_likely().map(|(name, _)| name.as_str())
+    }
+
+    fn most_likely(&self) -> Option<&(String, Deck)> {
+        self.archetypes
+            .iter()
+            .map(|archetype| (archetype, self.likelihood(&archetype.1)))
+            .filter(|(_, likelihood)| *likelihood > 0.0)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(archetype, _)| archetype)
+    }
+
+    // Chance of drawing the revealed cards from the deck, up to a factor shared by all decks
+    fn likelihood(&self, deck: &Deck) -> f64 {
+        let copies = count_by_name(&deck.cards);
+        self.revealed
+            .iter()
+            .map(|(name, &revealed)| {
+                let copies = copies.get(name).copied().unwrap_or(0);
+                (0..revealed)
+                    .map(|i| copies.saturating_sub(i) as f64)
+                    .product::<f64>()
+            })
+            .product()
+    }
+
+    fn reveal(&mut self, cards: HashMap<String, usize>) {
+        for (name, count) in cards {
+            let revealed = self.revealed.entry(name).or_insert(0);
+            *revealed = (*revealed).max(count);
+        }
+    }
+}
+
+impl OpponentModel for ArchetypeModel {
+    fn observe(&mut self, observation: &Observation) {
+        let opponent = (observation.player() + 1) % 2;
+        self.player = Some(observation.player());
+        let mut visible = visible_cards(observation, opponent);
+        for (name, count) in &self.played[opponent] {
+            let copies = visible.entry(name.clone()).or_insert(0);
+            *copies = (*copies).max(*count);
+        }
+        self.reveal(visible);
+    }
+
+    fn observe_action(&mut self, action: &Action) {
+        let name = match &action.action {
+            SimpleAction::Place(card, _) | SimpleAction::Evolve(card, _) => card.get_name(),
+            SimpleAction::Play { trainer_card } => trainer_card.name.clone(),
+            _ => return,
+        };
+        let played = self.played[action.actor].entry(name.clone()).or_insert(0);
+        *played += 1;
+        let count = *played;
+        if self.player.is_some_and(|player| player != action.actor) {
+            self.reveal(HashMap::from([(name, count)]));
+        }
+    }
+
+    /// The most likely archetype's deck, less the cards the opponent has in play or in
+    /// the discard pile.
+    fn likely_unseen_cards(&self, observation: &Observation) -> Vec<Card> {
+        let Some((_, deck)) = self.most_likely() else {
+            return vec![];
+        };
+        let mut seen = visible_cards(observation, (observation.player() + 1) % 2);
+        deck.cards
+            .iter()
+            .filter(|card| match seen.get_mut(&card.get_name()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+// Cards of the player in play (with the ones behind evolutions) and in the discard pile
+fn visible_cards(observation: &Observation, player: usize) -> HashMap<String, usize> {
+    let in_play = observation.in_play_pokemon()[player]
+        .iter()
+        .flatten()
+        .flat_map(|pokemon| std::iter::once(&pokemon.card).chain(&pokemon.cards_behind));
+    let cards: Vec<Card> = in_play
+        .chain(&observation.discard_piles()[player])
+        .cloned()
+        .collect();
+    count_by_name(&cards)
+}
+
+fn count_by_name(cards: &[Card]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for card in cards {
+        *counts.entry(card.get_name()).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        players::{AttachAttackPlayer, CheatingPlayerAdapter, MctsPlayer, Player},
+        test_helpers::{load_test_deck, load_test_decks, ScenarioBuilder},
+        Game,
+    };
+
+    fn archetypes() -> Vec<(String, Deck)> {
+        ["venusaur-exeggutor.txt", "weezing-arbok.txt", "fire.txt"]
+            .into_iter()
+            .map(|x| (x.to_string(), load_test_deck(x)))
+            .collect()
+    }
+
+    #[test]
+    fn test_revealed_cards_narrow_down_the_archetype() {
+        let mut model = ArchetypeModel::new(archetypes());
+        let state = ScenarioBuilder::new()
+            .active(1, CardId::A1177Weezing, [])
+            .build();
+        assert!(model
+            .archetype_probabilities()
+            .iter()
+            .all(|x| x.1 == 1.0 / 3.0));
+
+        model.observe(&Observation::new(&state, 0));
+        assert_eq!(model.likely_archetype(), Some("weezing-arbok.txt"));
+        let probabilities = model.archetype_probabilities();
+        assert_eq!(probabilities[1], ("weezing-arbok.txt", 1.0));
+
+        // Our own plays don't count
+        let grass = Action {
+            actor: 0,
+            action: SimpleAction::Place(get_card_by_enum(CardId::A1001Bulbasaur), 1),
+            is_stack: false,
+        };
+        model.observe_action(&grass);
+        assert_eq!(model.likely_archetype(), Some("weezing-arbok.txt"));
+
+        // No archetype plays both
+        let unknown = Action { actor: 1, ..grass };
+        model.observe_action(&unknown);
+        assert_eq!(model.likely_archetype(), None);
+        assert!(model.archetype_probabilities().iter().all(|x| x.1 == 0.0));
+    }
+
+    #[test]
+    fn test_determinize_deals_the_likely_cards() {
+        let (deck_a, _) = load_test_decks();
+        let mut rng = StdRng::seed_from_u64(0);
+        let state = State::initialize(&deck_a, &load_test_deck("fire.txt"), &mut rng);
+        let mut model = ArchetypeModel::new(vec![("grass".to_string(), deck_a.clone())]);
+        model.observe(&Observation::new(&state, 0));
+
+        let imagined = determinize(&model, &mut rng, &state, 0);
+        assert_eq!(imagined.hands[0], state.hands[0]);
+        assert_eq!(imagined.hands[1].len(), state.hands[1].len());
+        assert_eq!(imagined.decks[1].cards.len(), state.decks[1].cards.len());
+        assert!(imagined.hands[1]
+            .iter()
+            .chain(&imagined.decks[1].cards)
+            .all(|card| deck_a.cards.contains(card)));
+
+        // Without an idea, the decklist is dealt again
+        let clueless = ArchetypeModel::new(vec![]);
+        let imagined = determinize(&clueless, &mut rng, &state, 0);
+        let mut unseen: Vec<_> = imagined.hands[1]
+            .iter()
+            .chain(&imagined.decks[1].cards)
+            .collect();
+        let mut actual: Vec<_> = state.hands[1].iter().chain(&state.decks[1].cards).collect();
+        unseen.sort_by_key(|x| x.get_id());
+        actual.sort_by_key(|x| x.get_id());
+        assert_eq!(unseen, actual);
+    }
+
+    #[test]
+    fn test_mcts_player_searches_determinized_states() {
+        let (deck_a, deck_b) = load_test_decks();
+        let model = ArchetypeModel::new(archetypes());
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(CheatingPlayerAdapter(
+                MctsPlayer::new(deck_a, 10).with_opponent_model(model),
+            )),
+            Box::new(AttachAttackPlayer { deck: deck_b }),
+        ];
+        let mut game = Game::new(players, 7);
+        assert!(game.play().is_some());
+    }
+}
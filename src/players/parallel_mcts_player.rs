@@ -0,0 +1,160 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{fmt::Debug, thread};
+
+use super::{
+    mcts_player::{search, SearchHooks, SearchTree, DEFAULT_MAX_NODES, MIN_MAX_NODES},
+    CheatingPlayer, MctsConfig, Player, PolicyFunction, ValueFunction,
+};
+use crate::{actions::Action, Deck, State};
+
+/// Root-parallel MCTS: every decision runs `num_trees` independent searches (one per
+/// thread, each with its own seed), then adds up the visits and rewards of each root
+/// action across trees and picks the one with the highest total reward.
+pub struct ParallelMctsPlayer {
+    pub deck: Deck,
+    pub iterations: u64, // Number of iterations of each tree
+    pub num_trees: usize,
+    max_nodes: usize,
+    trees: Vec<SearchTree>,
+    hooks: SearchHooks,
+}
+
+impl ParallelMctsPlayer {
+    /// Uses one tree per available core.
+    pub fn new(deck: Deck, iterations: u64) -> Self {
+        let num_trees = thread::available_parallelism().map_or(1, |x| x.get());
+        Self {
+            deck,
+            iterations,
+            num_trees,
+            max_nodes: DEFAULT_MAX_NODES,
+            trees: vec![],
+            hooks: SearchHooks::default(),
+        }
+    }
+
+    pub fn with_num_trees(mut self, num_trees: usize) -> Self {
+        self.num_trees = num_trees.max(1);
+        self
+    }
+
+    /// See `MctsPlayer::with_max_nodes`. The budget is per tree.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        assert!(
+            max_nodes >= MIN_MAX_NODES,
+            "A search tree needs at least {MIN_MAX_NODES} nodes, got {max_nodes}"
+        );
+        self.max_nodes = max_nodes;
+        self.trees.clear();
+        self
+    }
+
+    /// See `MctsPlayer::with_rollout_policy`.
+    pub fn with_rollout_policy(
+        mut self,
+        policy: impl Fn() -> Box<dyn Player> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.rollout_policy = Box::new(policy);
+        self
+    }
+
+    /// See `MctsPlayer::with_value_function`.
+    pub fn with_value_function(mut self, value_function: impl ValueFunction + 'static) -> Self {
+        self.hooks.value_function = Some(Box::new(value_function));
+        self
+    }
+
+    /// See `MctsPlayer::with_policy_function`.
+    pub fn with_policy_function(mut self, policy_function: impl PolicyFunction + 'static) -> Self {
+        self.hooks.policy_function = Some(Box::new(policy_function));
+        self
+    }
+
+    /// See `MctsPlayer::with_config`.
+    pub fn with_config(mut self, config: MctsConfig) -> Self {
+        self.hooks.config = config;
+        self
+    }
+}
+
+impl CheatingPlayer for ParallelMctsPlayer {
+    fn decide_with_full_state(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let max_nodes = self.max_nodes;
+        self.trees
+            .resize_with(self.num_trees, || SearchTree::new(max_nodes));
+        let seeds: Vec<u64> = (0..self.num_trees).map(|_| rng.gen()).collect();
+        let iterations = self.iterations;
+        let hooks = &self.hooks;
+        let child_stats: Vec<Vec<(usize, f64)>> = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .trees
+                .iter_mut()
+                .zip(seeds)
+                .map(|(tree, seed)| {
+                    let possible_actions = possible_actions.clone();
+                    scope.spawn(move || {
+                        let mut rng = StdRng::seed_from_u64(seed);
+                        let root =
+                            search(&mut rng, state, possible_actions, iterations, tree, hooks);
+                        tree.child_stats(root)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|x| x.join().expect("MCTS thread panicked"))
+                .collect()
+        });
+
+        let merged = merge_child_stats(&child_stats);
+        let (best_index, _) = merged
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.1.total_cmp(&b.1))
+            .expect("There should be at least one child node");
+        possible_actions[best_index].clone()
+    }
+
+    fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+}
+
+impl Debug for ParallelMctsPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ParallelMctsPlayer with {} trees of {} iterations",
+            self.num_trees, self.iterations
+        )
+    }
+}
+
+/// Adds up the (visits, reward) of each root action across trees.
+fn merge_child_stats(child_stats: &[Vec<(usize, f64)>]) -> Vec<(usize, f64)> {
+    let mut merged = vec![(0, 0.0); child_stats.iter().map(|x| x.len()).max().unwrap_or(0)];
+    for stats in child_stats {
+        for (total, (visits, reward)) in merged.iter_mut().zip(stats) {
+            total.0 += visits;
+            total.1 += reward;
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_child_stats() {
+        let merged = merge_child_stats(&[vec![(2, 1.0), (1, -1.0)], vec![(1, 0.0), (3, 2.0)]]);
+        assert_eq!(merged, vec![(3, 1.0), (4, 1.0)]);
+        assert!(merge_child_stats(&[]).is_empty());
+    }
+}
@@ -1,19 +1,19 @@
 use rand::{rngs::StdRng, seq::SliceRandom};
 use std::fmt::Debug;
 
-use crate::{actions::Action, Deck, State};
+use crate::{actions::Action, observation::Observation, Deck};
 
-use super::Player;
+use super::FairPlayer;
 
 pub struct RandomPlayer {
     pub deck: Deck,
 }
 
-impl Player for RandomPlayer {
-    fn decision_fn(
+impl FairPlayer for RandomPlayer {
+    fn decide(
         &mut self,
         rng: &mut StdRng,
-        _: &State,
+        _: &Observation,
         possible_actions: Vec<Action>,
     ) -> Action {
         possible_actions
@@ -22,7 +22,7 @@ impl Player for RandomPlayer {
             .clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
@@ -40,8 +40,10 @@ mod tests {
         actions::{Action, SimpleAction},
         card_ids::CardId,
         database::get_card_by_enum,
+        players::Player,
         test_helpers::load_test_decks,
         types::{Card, EnergyType},
+        State,
     };
     use rand::SeedableRng;
 
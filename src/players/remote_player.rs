@@ -0,0 +1,243 @@
+use log::warn;
+use rand::{rngs::StdRng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, time::Duration};
+
+use crate::{actions::Action, observation::Observation, Deck, MaskedState};
+
+use super::FairPlayer;
+
+/// What a `RemotePlayer` plays when its bot doesn't answer in time, can't be reached or
+/// answers with an index out of range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RemoteFallback {
+    /// A random legal action.
+    #[default]
+    Random,
+    /// The first legal action.
+    First,
+    /// Panics, ending the game, e.g. when a failing bot should not go unnoticed.
+    Panic,
+}
+
+/// What a `RemotePlayer` POSTs to its bot for each decision, as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DecisionRequest {
+    /// The game as the bot's player sees it.
+    pub state: MaskedState,
+    /// The legal actions, as text (the same as the server sends).
+    pub legal_actions: Vec<String>,
+}
+
+/// What the bot answers, as JSON: the index of its action in `legal_actions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct DecisionReply {
+    pub index: usize,
+}
+
+/// A player whose decisions are taken by a bot behind an HTTP endpoint, so that bots can
+/// be written in any language. Every decision is a POST of a `DecisionRequest`, answered
+/// by a `DecisionReply`. The bot only sees what the player would see at a real table.
+pub struct RemotePlayer {
+    pub deck: Deck,
+    endpoint: String,
+    fallback: RemoteFallback,
+    agent: ureq::Agent,
+    failures: usize,
+}
+
+impl RemotePlayer {
+    /// A player asking the bot at `endpoint` (e.g. "http://localhost:8000/decide"), that
+    /// waits at most 5 seconds for each answer.
+    pub fn new(deck: Deck, endpoint: &str) -> Self {
+        Self {
+            deck,
+            endpoint: endpoint.to_string(),
+            fallback: RemoteFallback::default(),
+            agent: agent(Duration::from_secs(5)),
+            failures: 0,
+        }
+    }
+
+    /// Longest wait for an answer, connecting included.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.agent = agent(timeout);
+        self
+    }
+
+    pub fn with_fallback(mut self, fallback: RemoteFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Decisions the bot failed to take, for which the fallback was played.
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    fn ask(&self, request: &DecisionRequest) -> Result<usize, String> {
+        let mut response = self
+            .agent
+            .post(&self.endpoint)
+            .send_json(request)
+            .map_err(|x| x.to_string())?;
+        let reply: DecisionReply = response.body_mut().read_json().map_err(|x| x.to_string())?;
+        if reply.index >= request.legal_actions.len() {
+            return Err(format!(
+                "Index {} out of {} legal actions",
+                reply.index,
+                request.legal_actions.len()
+            ));
+        }
+        Ok(reply.index)
+    }
+}
+
+fn agent(timeout: Duration) -> ureq::Agent {
+    ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .build()
+        .into()
+}
+
+impl FairPlayer for RemotePlayer {
+    fn decide(
+        &mut self,
+        rng: &mut StdRng,
+        observation: &Observation,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let request = DecisionRequest {
+            state: observation.masked(),
+            legal_actions: possible_actions
+                .iter()
+                .map(|x| x.action.to_string())
+                .collect(),
+        };
+        match self.ask(&request) {
+            Ok(index) => possible_actions[index].clone(),
+            Err(error) => {
+                self.failures += 1;
+                warn!("Remote bot at {} failed: {}", self.endpoint, error);
+                match self.fallback {
+                    RemoteFallback::Random => possible_actions.choose(rng).unwrap().clone(),
+                    RemoteFallback::First => possible_actions[0].clone(),
+                    RemoteFallback::Panic => panic!("Remote bot failed: {error}"),
+                }
+            }
+        }
+    }
+
+    fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+}
+
+impl Debug for RemotePlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RemotePlayer({})", self.endpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        generate_possible_actions,
+        players::{AttachAttackPlayer, Player},
+        test_helpers::load_test_decks,
+        Game, State,
+    };
+
+    // Serves `requests` decisions, answering each with `answer(legal actions)`
+    fn serve(requests: usize, answer: fn(usize) -> String) -> (String, thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/decide", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                assert!(request["state"]["hand"].is_array());
+                let actions = request["legal_actions"].as_array().unwrap().len();
+                let reply = answer(actions);
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    reply.len(),
+                    reply
+                )
+                .unwrap();
+            }
+        });
+        (endpoint, handle)
+    }
+
+    #[test]
+    fn test_remote_bot_decides() {
+        let (deck_a, deck_b) = load_test_decks();
+        let (endpoint, server) = serve(1, |actions| format!(r#"{{"index": {}}}"#, actions - 1));
+        let mut player = RemotePlayer::new(deck_a.clone(), &endpoint);
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        let (_, actions) = generate_possible_actions(&state);
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = player.decision_fn(&mut rng, &state, actions.clone());
+        server.join().unwrap();
+        assert_eq!(action, actions[actions.len() - 1]);
+        assert_eq!(player.failures(), 0);
+    }
+
+    #[test]
+    fn test_falls_back_when_the_bot_fails() {
+        let (deck_a, deck_b) = load_test_decks();
+        // Nobody listens on a port right after it was freed
+        let endpoint = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/decide", listener.local_addr().unwrap())
+        };
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(
+                RemotePlayer::new(deck_a.clone(), &endpoint)
+                    .with_timeout(Duration::from_millis(200))
+                    .with_fallback(RemoteFallback::First),
+            ),
+            Box::new(AttachAttackPlayer {
+                deck: deck_b.clone(),
+            }),
+        ];
+        let mut game = Game::new(players, 0);
+        assert!(game.play().is_some());
+
+        // Out of range answers count as failures too
+        let (endpoint, server) = serve(1, |actions| format!(r#"{{"index": {actions}}}"#));
+        let mut player = RemotePlayer::new(deck_a.clone(), &endpoint);
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        let (_, actions) = generate_possible_actions(&state);
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = player.decision_fn(&mut rng, &state, actions.clone());
+        server.join().unwrap();
+        assert!(actions.contains(&action));
+        assert_eq!(player.failures(), 1);
+    }
+}
@@ -0,0 +1,229 @@
+use log::{debug, warn};
+use rand::{rngs::StdRng, seq::SliceRandom};
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, ParseError, Scope, AST};
+use std::{fmt::Debug, path::Path};
+
+use crate::{
+    actions::{Action, SimpleAction},
+    observation::Observation,
+    Deck,
+};
+
+use super::FairPlayer;
+
+/// Operations a script can run for one decision, so that an endless loop can't hang a
+/// game.
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// A player whose decisions are taken by a Rhai script, to try out heuristics without
+/// recompiling the crate. The script defines `decide(state, actions)`, returning the
+/// index of its action:
+///
+/// - `state` is what the player sees (a `MaskedState` as a map, e.g. `state.hand`,
+///   `state.points` or `state.in_play_pokemon`).
+/// - `actions` are the legal actions, as maps with their `kind` (e.g. "Attack" or
+///   "EndTurn"), their `text` and, for actions playing a card, the `card`'s name.
+///
+/// Scripts can't reach anything else (no files, no network). If one fails or returns an
+/// index out of range, a random action is played instead.
+///
+/// ```
+/// use deckgym::players::ScriptedPlayer;
+/// use deckgym::Deck;
+///
+/// let script = r#"
+///     fn decide(state, actions) {
+///         for (action, i) in actions {
+///             if action.kind == "Attack" { return i; }
+///         }
+///         0
+///     }
+/// "#;
+/// let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+/// assert!(ScriptedPlayer::new(deck, script).is_ok());
+/// ```
+pub struct ScriptedPlayer {
+    pub deck: Deck,
+    engine: Engine,
+    script: AST,
+    failures: usize,
+}
+
+impl ScriptedPlayer {
+    /// Fails if the script doesn't compile.
+    pub fn new(deck: Deck, script: &str) -> Result<Self, ParseError> {
+        let engine = engine();
+        let script = engine.compile(script)?;
+        Ok(Self {
+            deck,
+            engine,
+            script,
+            failures: 0,
+        })
+    }
+
+    /// Like `new`, with the script read from a file.
+    pub fn from_file(deck: Deck, path: impl AsRef<Path>) -> Result<Self, Box<EvalAltResult>> {
+        let engine = engine();
+        let script = engine.compile_file(path.as_ref().to_path_buf())?;
+        Ok(Self {
+            deck,
+            engine,
+            script,
+            failures: 0,
+        })
+    }
+
+    /// Decisions the script failed to take, for which a random action was played.
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    fn run(&self, observation: &Observation, actions: &[Action]) -> Result<usize, String> {
+        let state = rhai::serde::to_dynamic(observation.masked()).map_err(|x| x.to_string())?;
+        let actions_array: Array = actions.iter().map(to_script_action).collect();
+        let index: i64 = self
+            .engine
+            .call_fn(
+                &mut Scope::new(),
+                &self.script,
+                "decide",
+                (state, actions_array),
+            )
+            .map_err(|x| x.to_string())?;
+        usize::try_from(index)
+            .ok()
+            .filter(|x| *x < actions.len())
+            .ok_or(format!(
+                "Index {index} out of {} legal actions",
+                actions.len()
+            ))
+    }
+}
+
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.on_print(|text| debug!("Script: {text}"));
+    engine
+}
+
+fn to_script_action(action: &Action) -> Dynamic {
+    let text = action.action.to_string();
+    let mut map = Map::new();
+    let kind: String = text.chars().take_while(|x| x.is_alphanumeric()).collect();
+    map.insert("kind".into(), kind.into());
+    map.insert("text".into(), text.into());
+    let card = match &action.action {
+        SimpleAction::Place(card, _) | SimpleAction::Evolve(card, _) => Some(card.get_name()),
+        SimpleAction::Play { trainer_card } => Some(trainer_card.name.clone()),
+        _ => None,
+    };
+    if let Some(card) = card {
+        map.insert("card".into(), card.into());
+    }
+    map.into()
+}
+
+impl FairPlayer for ScriptedPlayer {
+    fn decide(
+        &mut self,
+        rng: &mut StdRng,
+        observation: &Observation,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        match self.run(observation, &possible_actions) {
+            Ok(index) => possible_actions[index].clone(),
+            Err(error) => {
+                self.failures += 1;
+                warn!("Script failed: {error}");
+                possible_actions.choose(rng).unwrap().clone()
+            }
+        }
+    }
+
+    fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+}
+
+impl Debug for ScriptedPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ScriptedPlayer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        generate_possible_actions,
+        players::{AttachAttackPlayer, EndTurnPlayer, Player},
+        test_helpers::load_test_decks,
+        Game, State,
+    };
+
+    #[test]
+    fn test_script_plays_like_the_player_it_mimics() {
+        let script = r#"
+            fn decide(state, actions) {
+                for (action, i) in actions {
+                    if action.kind == "EndTurn" { return i; }
+                }
+                0
+            }
+        "#;
+        let play = |player: Box<dyn Player>| {
+            let (_, deck_b) = load_test_decks();
+            let players = vec![player, Box::new(AttachAttackPlayer { deck: deck_b }) as _];
+            let mut game = Game::new(players, 2);
+            game.play();
+            game.get_state_clone()
+        };
+        let (deck_a, _) = load_test_decks();
+        let scripted = play(Box::new(
+            ScriptedPlayer::new(deck_a.clone(), script).unwrap(),
+        ));
+        assert_eq!(scripted, play(Box::new(EndTurnPlayer { deck: deck_a })));
+    }
+
+    #[test]
+    fn test_script_sees_the_state() {
+        let (deck_a, deck_b) = load_test_decks();
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        let (actor, actions) = generate_possible_actions(&state);
+        let script = r#"
+            fn decide(state, actions) {
+                (state.hand.len() + state.deck_sizes[0] + state.points[1]) % actions.len()
+            }
+        "#;
+        let mut player = ScriptedPlayer::new(deck_a, script).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let action = player.decision_fn(&mut rng, &state, actions.clone());
+        assert_eq!(player.failures(), 0);
+        let index = (state.hands[actor].len() + state.decks[0].cards.len()) % actions.len();
+        assert_eq!(action, actions[index]);
+    }
+
+    #[test]
+    fn test_failing_scripts() {
+        let (deck_a, deck_b) = load_test_decks();
+        assert!(ScriptedPlayer::new(deck_a.clone(), "fn decide(state, actions) {").is_err());
+
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        let (_, actions) = generate_possible_actions(&state);
+        let mut rng = StdRng::seed_from_u64(0);
+        for script in [
+            "fn decide(state, actions) { loop {} }",
+            "fn decide(state, actions) { actions.len() }",
+            "fn decide(state, actions) { state.nothing.here }",
+        ] {
+            let mut player = ScriptedPlayer::new(deck_a.clone(), script).unwrap();
+            let action = player.decision_fn(&mut rng, &state, actions.clone());
+            assert!(actions.contains(&action));
+            assert_eq!(player.failures(), 1);
+        }
+    }
+}
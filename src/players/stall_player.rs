@@ -0,0 +1,231 @@
+use rand::rngs::StdRng;
+use std::fmt::Debug;
+
+use crate::{
+    actions::{forecast, Action, SimpleAction},
+    generate_possible_actions, Deck, State,
+};
+
+use super::CheatingPlayer;
+
+/// A degenerate baseline that never tries to win, only to survive: it heals, retreats a
+/// damaged Active Pokémon to a healthier one and fills its Bench, and otherwise attaches
+/// energy (to pay for the next retreat) and ends its turn. It only attacks when that
+/// helps it survive (e.g. an attack that heals). Meant to drag games to the turn limit,
+/// to see how `GameRules::max_turns` and the tie-break rules handle them.
+pub struct StallPlayer {
+    pub deck: Deck,
+}
+
+impl CheatingPlayer for StallPlayer {
+    fn decide_with_full_state(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let myself = possible_actions[0].actor;
+        let current = Survival::of(state, myself);
+        let mut best: Option<(Survival, &Action)> = None;
+        for action in &possible_actions {
+            if action.action == SimpleAction::EndTurn {
+                continue;
+            }
+            let survival = expected_survival(rng, state, action, myself, CHOICE_DEPTH);
+            if best.is_none_or(|(best_survival, _)| survival.is_better_than(&best_survival)) {
+                best = Some((survival, action));
+            }
+        }
+
+        let end_turn = possible_actions
+            .iter()
+            .find(|x| x.action == SimpleAction::EndTurn);
+        match (best, end_turn) {
+            (Some((survival, action)), _) if survival.is_better_than(&current) => action.clone(),
+            // Nothing helps right now: get energy on the Active Pokémon for a later retreat
+            (_, Some(end_turn)) => possible_actions
+                .iter()
+                .find(|x| is_turn_energy_to_active(&x.action))
+                .unwrap_or(end_turn)
+                .clone(),
+            // A forced choice (e.g. the next Active Pokémon), so the least bad one
+            (Some((_, action)), None) => action.clone(),
+            (None, None) => unreachable!("There should always be at least one playable action"),
+        }
+    }
+
+    fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+}
+
+/// Choices an action leads to (e.g. which Pokémon a Potion heals) that are looked into.
+const CHOICE_DEPTH: usize = 2;
+
+// How well the player's Pokémon hold up, the remaining HP in play first
+#[derive(Debug, Clone, Copy)]
+struct Survival {
+    hp: f64,
+    active_hp: f64,
+}
+
+impl Survival {
+    fn of(state: &State, player: usize) -> Self {
+        Self {
+            hp: state
+                .enumerate_in_play_pokemon(player)
+                .map(|(_, x)| x.remaining_hp as f64)
+                .sum(),
+            active_hp: state.in_play_pokemon[player][0]
+                .as_ref()
+                .map_or(0.0, |x| x.remaining_hp as f64),
+        }
+    }
+
+    // Up to rounding, so that equally good actions keep their order
+    fn is_better_than(&self, other: &Self) -> bool {
+        const EPSILON: f64 = 1e-9;
+        self.hp > other.hp + EPSILON
+            || (self.hp > other.hp - EPSILON && self.active_hp > other.active_hp + EPSILON)
+    }
+}
+
+// Survival after the action, averaged over its outcomes
+fn expected_survival(
+    rng: &mut StdRng,
+    state: &State,
+    action: &Action,
+    player: usize,
+    depth: usize,
+) -> Survival {
+    let mut expected = Survival {
+        hp: 0.0,
+        active_hp: 0.0,
+    };
+    for outcome in forecast(rng, state, action) {
+        let survival = best_choice(rng, &outcome.state, player, depth);
+        expected.hp += outcome.probability * survival.hp;
+        expected.active_hp += outcome.probability * survival.active_hp;
+    }
+    expected
+}
+
+// If the action left the player a choice to make, its best option
+fn best_choice(rng: &mut StdRng, state: &State, player: usize, depth: usize) -> Survival {
+    let survival = Survival::of(state, player);
+    if depth == 0 || state.is_game_over() {
+        return survival;
+    }
+    let (actor, actions) = generate_possible_actions(state);
+    if actor != player || !actions.iter().all(|x| x.is_stack) {
+        return survival;
+    }
+    actions
+        .iter()
+        .map(|action| expected_survival(rng, state, action, player, depth - 1))
+        .reduce(|best, x| if x.is_better_than(&best) { x } else { best })
+        .unwrap_or(survival)
+}
+
+fn is_turn_energy_to_active(action: &SimpleAction) -> bool {
+    matches!(
+        action,
+        SimpleAction::Attach { attachments, is_turn_energy: true }
+            if attachments.iter().all(|(_, _, slot)| *slot == 0)
+    )
+}
+
+impl Debug for StallPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StallPlayer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        card_ids::CardId,
+        game_rules::{GameRules, TieBreak},
+        players::{CheatingPlayerAdapter, Player},
+        state::GameOutcome,
+        test_helpers::{energy, hp, load_test_decks, ScenarioBuilder},
+        types::EnergyType::Grass,
+        Game,
+    };
+
+    fn decide(state: &State) -> SimpleAction {
+        let (_, actions) = generate_possible_actions(state);
+        let mut player = StallPlayer {
+            deck: Deck::default(),
+        };
+        player
+            .decide_with_full_state(&mut StdRng::seed_from_u64(0), state, actions)
+            .action
+    }
+
+    #[test]
+    fn test_heals_then_retreats() {
+        let state = ScenarioBuilder::new()
+            .active(0, CardId::A1001Bulbasaur, [hp(20), energy([Grass])])
+            .bench(0, CardId::A1001Bulbasaur, [])
+            .active(1, CardId::A1001Bulbasaur, [])
+            .hand(0, [CardId::PA001Potion])
+            .turn(3)
+            .build();
+        assert!(matches!(decide(&state), SimpleAction::Play { .. }));
+
+        let state = ScenarioBuilder::from_state(state).hand(0, []).build();
+        assert_eq!(decide(&state), SimpleAction::Retreat(1));
+    }
+
+    #[test]
+    fn test_never_attacks() {
+        let state = ScenarioBuilder::new()
+            .active(0, CardId::A1001Bulbasaur, [energy([Grass, Grass])])
+            .active(1, CardId::A1001Bulbasaur, [hp(10)])
+            .turn(3)
+            .build();
+        assert_eq!(decide(&state), SimpleAction::EndTurn);
+    }
+
+    #[test]
+    fn test_stalls_to_the_turn_limit() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = |tie_break| -> (Vec<Box<dyn Player>>, GameRules) {
+            let players: Vec<Box<dyn Player>> = vec![
+                Box::new(CheatingPlayerAdapter(StallPlayer {
+                    deck: deck_a.clone(),
+                })),
+                Box::new(CheatingPlayerAdapter(StallPlayer {
+                    deck: deck_b.clone(),
+                })),
+            ];
+            let rules = GameRules {
+                tie_break,
+                ..Default::default()
+            };
+            (players, rules)
+        };
+
+        let (stallers, rules) = players(TieBreak::None);
+        let mut game = Game::with_rules(stallers, 3, rules);
+        assert_eq!(game.play(), None);
+        let state = game.get_state_clone();
+        assert_eq!(state.turn_count, state.rules.max_turns);
+        assert_eq!(state.points, [0, 0]);
+
+        // With no points scored, the tie goes to the healthier board
+        let hp = |player| Survival::of(&state, player).hp;
+        let (stallers, rules) = players(TieBreak::RemainingHp);
+        let mut game = Game::with_rules(stallers, 3, rules);
+        let expected = match hp(0).total_cmp(&hp(1)) {
+            std::cmp::Ordering::Greater => Some(GameOutcome::Win(0)),
+            std::cmp::Ordering::Less => Some(GameOutcome::Win(1)),
+            std::cmp::Ordering::Equal => None,
+        };
+        assert_eq!(game.play(), expected);
+    }
+}
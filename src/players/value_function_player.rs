@@ -1,24 +1,27 @@
 use rand::rngs::StdRng;
 use std::fmt::Debug;
 
-use crate::actions::{forecast_action, Action};
-use crate::{Deck, State};
+use crate::actions::{expected_value, Action};
+use crate::{observation::Observation, Deck, State};
 
-use super::Player;
+use super::FairPlayer;
 
+/// Plays the action with the best expected value of a simple evaluation of the outcomes,
+/// simulated on a state with the hidden cards dealt again (see `Observation::determinized`).
 pub struct ValueFunctionPlayer {
     pub deck: Deck,
 }
 
-impl Player for ValueFunctionPlayer {
-    fn decision_fn(
+impl FairPlayer for ValueFunctionPlayer {
+    fn decide(
         &mut self,
         rng: &mut StdRng,
-        state: &State,
+        observation: &Observation,
         possible_actions: Vec<Action>,
     ) -> Action {
         // Get value for the possible actions
         let myself = possible_actions[0].actor;
+        let state = &observation.determinized(rng);
         let scores: Vec<f64> = possible_actions
             .iter()
             .map(|action| expected_value_function(rng, state, action, myself))
@@ -35,24 +38,15 @@ impl Player for ValueFunctionPlayer {
         possible_actions[best_idx].clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
 
 fn expected_value_function(rng: &mut StdRng, state: &State, action: &Action, myself: usize) -> f64 {
-    let (probabilities, mutations) = forecast_action(state, action);
-    let mut outcomes: Vec<State> = vec![];
-    for mutation in mutations {
-        let mut state = state.clone();
-        mutation(rng, &mut state, action);
-        outcomes.push(state);
-    }
-    outcomes
-        .iter()
-        .zip(probabilities.iter())
-        .map(|(outcome, prob)| value_function(outcome, myself) * prob)
-        .sum()
+    expected_value(rng, state, action, |outcome| {
+        value_function(outcome, myself)
+    })
 }
 
 fn value_function(state: &State, myself: usize) -> f64 {
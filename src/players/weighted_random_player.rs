@@ -3,19 +3,19 @@ use rand::rngs::StdRng;
 use std::fmt::Debug;
 
 use crate::actions::{Action, SimpleAction};
-use crate::{Deck, State};
+use crate::{observation::Observation, Deck};
 
-use super::Player;
+use super::FairPlayer;
 
 pub struct WeightedRandomPlayer {
     pub deck: Deck,
 }
 
-impl Player for WeightedRandomPlayer {
-    fn decision_fn(
+impl FairPlayer for WeightedRandomPlayer {
+    fn decide(
         &mut self,
         rng: &mut StdRng,
-        _: &State,
+        _: &Observation,
         possible_actions: Vec<Action>,
     ) -> Action {
         // Get weights for the possible actions
@@ -31,7 +31,7 @@ impl Player for WeightedRandomPlayer {
         possible_actions[dist.sample(rng)].clone()
     }
 
-    fn get_deck(&self) -> Deck {
+    fn deck(&self) -> Deck {
         self.deck.clone()
     }
 }
@@ -52,10 +52,17 @@ fn get_weight(action: &SimpleAction) -> u32 {
         SimpleAction::Evolve(_, _) => 10,
         SimpleAction::UseAbility(_) => 10,
         SimpleAction::Attack(_) => 10,
+        SimpleAction::CopyAttack(_) => 10,
         SimpleAction::ApplyDamage { .. } => 10,
+        SimpleAction::MoveEnergy { .. } => 10,
+        SimpleAction::SwapWithDeck(_) => 5,
+        SimpleAction::DiscardEnergy { .. } => 1,
+        SimpleAction::ChooseDamageTarget { .. } => 10,
         SimpleAction::Retreat(_) => 2,
         SimpleAction::EndTurn => 1,
         SimpleAction::Heal { .. } => 5,
         SimpleAction::Activate { .. } => 1,
+        SimpleAction::Promote { .. } => 1,
+        SimpleAction::Setup { .. } => 1,
     }
 }
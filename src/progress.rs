@@ -0,0 +1,163 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::simulate::Metric;
+#[cfg(feature = "sqlite")]
+use crate::simulate::{GameRecord, ResultsDb};
+
+/// Where a run is at, reported after every game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    pub games_completed: u64,
+    pub total_games: u64,
+    /// Win rate of the deck being measured, over the games of the current matchup (for
+    /// `optimize`, the current combination).
+    pub win_rate: f64,
+}
+
+/// Asks a run to stop. Clones share the flag, so one can be kept by the caller (or another
+/// thread) to cancel the run holding the other.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+type ProgressCallback<'a> = Box<dyn FnMut(&Progress) + 'a>;
+
+/// Progress callback, cancellation and custom metrics of a run. The default one reports nothing and can't
+/// be cancelled. A cancelled run stops after the game in progress and reports on the games
+/// played so far.
+#[derive(Default)]
+pub struct RunControl<'a> {
+    on_progress: Option<ProgressCallback<'a>>,
+    cancellation: Option<CancellationToken>,
+    metrics: Vec<&'a mut dyn Metric>,
+    #[cfg(feature = "sqlite")]
+    results: Option<&'a ResultsDb>,
+}
+
+impl<'a> RunControl<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_progress(mut self, callback: impl FnMut(&Progress) + 'a) -> Self {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Feeds the events of every game to `metric` (only `simulate_with` plays games one
+    /// action at a time, so other runs ignore metrics).
+    pub fn metric(mut self, metric: &'a mut dyn Metric) -> Self {
+        self.metrics.push(metric);
+        self
+    }
+
+    /// Stores the result of every game in `db` (only `simulate_with` does, other runs
+    /// ignore it).
+    #[cfg(feature = "sqlite")]
+    pub fn store_results(mut self, db: &'a ResultsDb) -> Self {
+        self.results = Some(db);
+        self
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn record(&self, record: &GameRecord) {
+        if let Some(db) = self.results {
+            db.insert(record).expect("Failed to store game result");
+        }
+    }
+
+    pub(crate) fn metrics(&mut self) -> &mut [&'a mut dyn Metric] {
+        &mut self.metrics
+    }
+
+    pub(crate) fn report(&mut self, progress: Progress) {
+        if let Some(callback) = &mut self.on_progress {
+            callback(&progress);
+        }
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancellation.as_ref().is_some_and(|x| x.is_cancelled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::{
+        deck_generation::DeckConstraints, optimize::optimize_with, players::PlayerCode,
+        simulate::simulate_with,
+    };
+
+    #[test]
+    fn test_simulate_reports_progress_until_cancelled() {
+        let token = CancellationToken::new();
+        let reports = RefCell::new(vec![]);
+        let mut control = RunControl::new()
+            .on_progress(|progress| {
+                reports.borrow_mut().push(*progress);
+                if progress.games_completed == 3 {
+                    token.cancel();
+                }
+            })
+            .cancellation(token.clone());
+        simulate_with(
+            "example_decks/venusaur-exeggutor.txt",
+            "example_decks/weezing-arbok.txt",
+            Some(vec![PlayerCode::R, PlayerCode::R]),
+            100,
+            Some(0),
+            None,
+            &mut control,
+        );
+        let reports = reports.borrow();
+        assert_eq!(reports.len(), 3);
+        assert!(reports.iter().all(|x| x.total_games == 100));
+        assert!(reports.iter().all(|x| (0.0..=1.0).contains(&x.win_rate)));
+    }
+
+    #[test]
+    fn test_cancelled_optimize_plays_nothing() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut games = 0;
+        let mut control = RunControl::new()
+            .on_progress(|_| games += 1)
+            .cancellation(token);
+        optimize_with(
+            "example_decks/incomplete-chari.txt",
+            "A2147,A2148",
+            "example_decks/",
+            10,
+            Some(vec![PlayerCode::R, PlayerCode::R]),
+            Some(0),
+            &DeckConstraints::default(),
+            &mut control,
+        );
+        drop(control);
+        assert_eq!(games, 0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    players::{Player, ValueFunctionPlayer},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// Decks bundled into the library (in DeckGym Format), so they work from any working directory.
+pub const SAMPLE_DECKS: [(&str, &str); 4] = [
+    (
+        "venusaur-exeggutor",
+        include_str!("../example_decks/venusaur-exeggutor.txt"),
+    ),
+    (
+        "blastoiseex",
+        include_str!("../example_decks/blastoiseex.txt"),
+    ),
+    (
+        "weezing-arbok",
+        include_str!("../example_decks/weezing-arbok.txt"),
+    ),
+    (
+        "arceusdialga",
+        include_str!("../example_decks/arceusdialga.txt"),
+    ),
+];
+
+/// Returns one of the `SAMPLE_DECKS` by name.
+pub fn sample_deck(name: &str) -> Option<Deck> {
+    SAMPLE_DECKS
+        .iter()
+        .find(|(deck_name, _)| *deck_name == name)
+        .map(|(_, contents)| Deck::from_string(contents).expect("Sample decks should be valid"))
+}
+
+/// A ready-to-run game between Venusaur-Exeggutor and Blastoise ex, both played by
+/// value function bots. Just call `.play()` on it.
+pub fn demo_game() -> Game {
+    demo_game_with_seed(rand::random())
+}
+
+pub fn demo_game_with_seed(seed: u64) -> Game {
+    let players: Vec<Box<dyn Player>> = vec![
+        Box::new(ValueFunctionPlayer {
+            deck: sample_deck("venusaur-exeggutor").unwrap(),
+        }),
+        Box::new(ValueFunctionPlayer {
+            deck: sample_deck("blastoiseex").unwrap(),
+        }),
+    ];
+    Game::new(players, seed)
+}
+
+/// Plays `num_games` demo games and returns [wins of player 1, wins of player 2, ties or timeouts].
+pub fn simulate_demo(num_games: u32) -> [u32; 3] {
+    let mut results = [0, 0, 0];
+    for _ in 0..num_games {
+        match demo_game().play() {
+            Some(GameOutcome::Win(winner)) => results[winner] += 1,
+            Some(GameOutcome::Tie) | None => results[2] += 1,
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_decks_are_valid() {
+        for (name, _) in SAMPLE_DECKS {
+            assert!(sample_deck(name).unwrap().is_valid(), "{name} is invalid");
+        }
+        assert!(sample_deck("not-a-deck").is_none());
+    }
+}
@@ -0,0 +1,263 @@
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    analysis::Analysis, audit::stable_hash, card_ids::ALL_CARD_IDS, database::get_card_by_enum,
+    GameError, GameResult, State,
+};
+
+/// Version of the replay file format, bumped (with a migration in `Replay::from_json`)
+/// whenever it changes incompatibly. Files without a version are format 0.
+pub const REPLAY_FORMAT: u32 = 1;
+
+/// Version of this engine.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+lazy_static! {
+    static ref CARD_DATABASE_VERSION: u64 = hash_card_database();
+}
+
+fn hash_card_database() -> u64 {
+    let cards: Vec<String> = ALL_CARD_IDS
+        .iter()
+        .map(|id| serde_json::to_string(&get_card_by_enum(*id)).expect("Cards should serialize"))
+        .collect();
+    stable_hash(&cards)
+}
+
+/// Hash of the data of every card, which changes with any edit of the database.
+pub fn card_database_version() -> u64 {
+    *CARD_DATABASE_VERSION
+}
+
+/// What a file was saved with. Unknown fields are from files saved before they were tagged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaVersion {
+    pub format: u32,
+    pub engine: Option<String>,
+    pub card_database: Option<u64>,
+}
+
+impl SchemaVersion {
+    pub fn current() -> Self {
+        Self {
+            format: REPLAY_FORMAT,
+            engine: Some(ENGINE_VERSION.to_string()),
+            card_database: Some(card_database_version()),
+        }
+    }
+
+    /// Whether the cards are known to be the same as when the file was saved.
+    pub fn has_current_card_database(&self) -> bool {
+        self.card_database == Some(card_database_version())
+    }
+
+    // Adds to an error of playing the file back that the cards may have changed since
+    fn annotate(&self, error: String) -> String {
+        if self.has_current_card_database() {
+            return error;
+        }
+        let recorded = match (&self.engine, self.card_database) {
+            (Some(engine), Some(database)) => {
+                format!("engine {engine} and card database {database:016x}")
+            }
+            _ => "an unknown engine version".to_string(),
+        };
+        format!(
+            "{error}. The replay was recorded with {recorded}, this is engine {ENGINE_VERSION} \
+             and card database {:016x}: cards may have changed since",
+            card_database_version()
+        )
+    }
+}
+
+/// A game and the actions taken so far, as saved by `Analysis::replay`.
+///
+/// Replays are tagged with the engine and card database they were recorded with. Files of
+/// older formats are migrated when loaded; a different card database is not an error by
+/// itself, but explains why a replay no longer plays out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: SchemaVersion,
+    /// Decklists, in the deck file format.
+    pub deck_a: String,
+    pub deck_b: String,
+    pub seed: u64,
+    /// `ActionIndex` of every action taken.
+    pub actions: Vec<usize>,
+    /// Hash of the state after the actions (see `verify`), missing in replays saved by
+    /// older versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_state_hash: Option<u64>,
+}
+
+impl Replay {
+    /// Reads a saved replay, migrating older formats. Fails on formats newer than this
+    /// engine reads.
+    pub fn from_json(json: &str) -> GameResult<Replay> {
+        let mut value: Value = serde_json::from_str(json).map_err(invalid_replay)?;
+        let format = match value.pointer("/version/format") {
+            None => 0,
+            Some(format) => format
+                .as_u64()
+                .ok_or_else(|| invalid_replay("version.format should be a number"))?
+                as u32,
+        };
+        if format > REPLAY_FORMAT {
+            return Err(GameError::UnsupportedVersion {
+                found: format,
+                supported: REPLAY_FORMAT,
+            });
+        }
+        if format == 0 {
+            value = migrate_from_format_0(value)?;
+        }
+        serde_json::from_value(value).map_err(invalid_replay)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Replays should serialize")
+    }
+
+    /// Adds the version the replay was recorded with to an error of playing it back.
+    pub(crate) fn annotate_error(&self, error: String) -> String {
+        self.version.annotate(error)
+    }
+}
+
+// Format 0 had no version
+fn migrate_from_format_0(mut value: Value) -> GameResult<Value> {
+    let object = value
+        .as_object_mut()
+        .ok_or_else(|| invalid_replay("a replay should be an object"))?;
+    let version = SchemaVersion {
+        format: 1,
+        engine: None,
+        card_database: None,
+    };
+    object.insert(
+        "version".to_string(),
+        serde_json::to_value(version).expect("Versions should serialize"),
+    );
+    Ok(value)
+}
+
+fn invalid_replay(error: impl ToString) -> GameError {
+    GameError::InvalidConfiguration {
+        setting: "replay".to_string(),
+        value: error.to_string(),
+    }
+}
+
+/// Hash of a state as recorded in `Replay::final_state_hash`. It covers every field of the
+/// state, so it changes whenever the engine does anything differently.
+pub fn state_hash(state: &State) -> u64 {
+    stable_hash(state)
+}
+
+/// Plays the replay again and checks that it reaches the state it recorded, to catch
+/// engine changes that silently alter the rules between versions.
+pub fn verify(replay: &Replay) -> GameResult<()> {
+    let expected = replay
+        .final_state_hash
+        .ok_or_else(|| GameError::MissingRequiredField {
+            field: "final_state_hash".to_string(),
+        })?;
+    let analysis = Analysis::from_replay(replay)
+        .map_err(|error| GameError::IllegalMove { description: error })?;
+    let actual = state_hash(analysis.state());
+    if actual != expected {
+        return Err(GameError::InvalidGameState {
+            description: replay.annotate_error(format!(
+                "Replay ends in state {actual:016x} instead of the recorded {expected:016x}"
+            )),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::load_test_decks;
+
+    fn recorded_replay() -> Replay {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut analysis = Analysis::new(&deck_a, &deck_b, 4);
+        while !analysis.legal_actions().is_empty() {
+            analysis.apply(0).unwrap();
+        }
+        analysis.replay()
+    }
+
+    #[test]
+    fn test_verify_recorded_replay() {
+        let replay = recorded_replay();
+        assert_eq!(verify(&replay), Ok(()));
+
+        // Survives saving
+        let json = serde_json::to_string(&replay).unwrap();
+        assert_eq!(verify(&serde_json::from_str(&json).unwrap()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_detects_differences() {
+        let replay = recorded_replay();
+
+        let mut altered = replay.clone();
+        altered.final_state_hash = Some(replay.final_state_hash.unwrap() ^ 1);
+        assert!(matches!(
+            verify(&altered),
+            Err(GameError::InvalidGameState { .. })
+        ));
+
+        let mut reseeded = replay.clone();
+        reseeded.seed += 1;
+        assert!(verify(&reseeded).is_err());
+
+        let mut unrecorded = replay;
+        unrecorded.version.card_database = Some(0);
+        unrecorded.final_state_hash = None;
+        assert!(matches!(
+            verify(&unrecorded),
+            Err(GameError::MissingRequiredField { .. })
+        ));
+        unrecorded.seed += 1;
+        unrecorded.final_state_hash = Some(0);
+        let error = verify(&unrecorded).unwrap_err().to_string();
+        assert!(error.contains("card database 0000000000000000"), "{error}");
+    }
+
+    #[test]
+    fn test_load_replays_of_every_format() {
+        let replay = recorded_replay();
+        assert_eq!(replay.version, SchemaVersion::current());
+        assert!(replay.version.has_current_card_database());
+        assert_eq!(Replay::from_json(&replay.to_json()), Ok(replay.clone()));
+
+        // Format 0 had no version, nor final state
+        let mut json: Value = serde_json::from_str(&replay.to_json()).unwrap();
+        json.as_object_mut().unwrap().remove("version");
+        json.as_object_mut().unwrap().remove("final_state_hash");
+        let migrated = Replay::from_json(&json.to_string()).unwrap();
+        assert_eq!(migrated.version.format, REPLAY_FORMAT);
+        assert!(!migrated.version.has_current_card_database());
+        assert_eq!(migrated.actions, replay.actions);
+        let analysis = Analysis::from_replay(&migrated).unwrap();
+        assert_eq!(
+            state_hash(analysis.state()),
+            replay.final_state_hash.unwrap()
+        );
+
+        json["version"] = serde_json::json!({"format": REPLAY_FORMAT + 1});
+        assert_eq!(
+            Replay::from_json(&json.to_string()),
+            Err(GameError::UnsupportedVersion {
+                found: REPLAY_FORMAT + 1,
+                supported: REPLAY_FORMAT
+            })
+        );
+        assert!(Replay::from_json("[1, 2]").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Independent source of randomness of a game. Keeping them apart means that e.g. a player
+/// consuming more randomness doesn't change the cards drawn or the coins flipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngStream {
+    /// The opening deck shuffles and the coin toss deciding who goes first.
+    DeckShuffle,
+    /// Coin flips and other chance outcomes of the actions played, including the deck
+    /// shuffles they cause (e.g. Poké Ball or Red Card) and the flips of the Pokémon Checkup.
+    CoinFlips,
+    /// Energy generated at the start of each turn.
+    EnergyZone,
+    /// Randomness used by each player to take decisions.
+    Player(usize),
+}
+
+impl RngStream {
+    fn index(self) -> usize {
+        match self {
+            RngStream::DeckShuffle => 0,
+            RngStream::CoinFlips => 1,
+            RngStream::EnergyZone => 2,
+            RngStream::Player(player) => {
+                assert!(player < 2, "Player should be 0 or 1, got {player}");
+                3 + player
+            }
+        }
+    }
+}
+
+/// All the random number generators of a game, derived from a single seed.
+#[derive(Debug, Clone)]
+pub struct RngRegistry {
+    seed: u64,
+    streams: [StdRng; 5],
+}
+
+impl RngRegistry {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            streams: std::array::from_fn(|i| StdRng::seed_from_u64(stream_seed(seed, i))),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn get(&mut self, stream: RngStream) -> &mut StdRng {
+        &mut self.streams[stream.index()]
+    }
+
+    /// Two different streams at once. Panics if they are the same.
+    pub fn get_pair(&mut self, a: RngStream, b: RngStream) -> (&mut StdRng, &mut StdRng) {
+        let [a, b] = self
+            .streams
+            .get_disjoint_mut([a.index(), b.index()])
+            .expect("Streams should be different");
+        (a, b)
+    }
+}
+
+/// Mixes the stream index into the game seed (SplitMix64 finalizer), so that nearby seeds
+/// don't produce related streams.
+fn stream_seed(seed: u64, index: usize) -> u64 {
+    let mut z = seed.wrapping_add((index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn test_streams_are_independent() {
+        let mut a = RngRegistry::new(7);
+        let mut b = RngRegistry::new(7);
+
+        // Draining one stream doesn't affect the others
+        for _ in 0..10 {
+            a.get(RngStream::Player(0)).gen::<u64>();
+        }
+        let coins_a: u64 = a.get(RngStream::CoinFlips).gen();
+        let coins_b: u64 = b.get(RngStream::CoinFlips).gen();
+        assert_eq!(coins_a, coins_b);
+
+        let energy: u64 = b.get(RngStream::EnergyZone).gen();
+        assert_ne!(coins_b, energy);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use crate::card_ids::CardId;
+
+/// An official ruling on how a card behaves, and the tests that enforce it in the engine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ruling {
+    /// Where the ruling comes from (e.g. the card text or the in-game rules).
+    pub source: &'static str,
+    /// Plain statement of how the interaction resolves.
+    pub text: &'static str,
+    /// Conformance tests, as `<path relative to src/>::<test function>`.
+    pub tests: &'static [&'static str],
+}
+
+const CARD_TEXT: &str = "Card text";
+const GAME_RULES: &str = "TCG Pocket in-game rules (Help > Rules)";
+
+// TODO: Consider moving these into a rulings.json next to database.json.
+lazy_static::lazy_static! {
+    static ref RULINGS_MAP: HashMap<CardId, Vec<Ruling>> = {
+        let mut m = HashMap::new();
+
+        let weezing = vec![
+            Ruling {
+                source: CARD_TEXT,
+                text: "Gas Leak can only be used while Weezing is in the Active Spot, once per turn.",
+                tests: &[
+                    "move_generation/abilities_test.rs::test_weezing_ability_requires_active_position",
+                    "move_generation/abilities_test.rs::test_weezing_ability_blocked_when_used",
+                ],
+            },
+            Ruling {
+                source: CARD_TEXT,
+                text: "Gas Leak only Poisons the opponent's Active Pokémon, never the Bench.",
+                tests: &[
+                    "actions/apply_abilities_action_test.rs::test_weezing_ability_poisons_opponent",
+                    "actions/apply_abilities_action_test.rs::test_weezing_ability_only_affects_active",
+                ],
+            },
+        ];
+        m.insert(CardId::A1177Weezing, weezing.clone());
+        m.insert(CardId::A1243Weezing, weezing);
+
+        m.insert(CardId::A1007Butterfree, vec![Ruling {
+            source: CARD_TEXT,
+            text: "Powder Heal heals 20 damage from each of your Pokémon, without going over their max HP.",
+            tests: &[
+                "actions/apply_abilities_action_test.rs::test_butterfree_ability_heals_all_pokemon",
+                "actions/apply_abilities_action_test.rs::test_butterfree_ability_caps_at_full_health",
+                "move_generation/abilities_test.rs::test_butterfree_ability_blocked_when_used",
+            ],
+        }]);
+
+        m.insert(CardId::A1132Gardevoir, vec![Ruling {
+            source: CARD_TEXT,
+            text: "Psy Shadow attaches a Psychic Energy from the Energy Zone to the Active Pokémon, once per turn per Gardevoir.",
+            tests: &[
+                "actions/apply_abilities_action_test.rs::test_gardevoir_ability_attaches_psychic_energy",
+                "move_generation/abilities_test.rs::test_gardevoir_ability_blocked_when_used",
+            ],
+        }]);
+
+        let arceus_ex = vec![Ruling {
+            source: CARD_TEXT,
+            text: "Fabled Luminance is passive: it can't be activated, and Arceus ex can't be affected by Special Conditions.",
+            tests: &[
+                "actions/mutations.rs::test_arceus_avoids_status",
+                "move_generation/abilities_test.rs::test_arceus_ability_never_allowed",
+            ],
+        }];
+        m.insert(CardId::A2a071ArceusEx, arceus_ex.clone());
+        m.insert(CardId::A2a086ArceusEx, arceus_ex.clone());
+        m.insert(CardId::A2a095ArceusEx, arceus_ex.clone());
+        m.insert(CardId::A2a096ArceusEx, arceus_ex);
+
+        let giovanni = vec![Ruling {
+            source: CARD_TEXT,
+            text: "Attacks used this turn by your Pokémon do +10 damage to the opponent's Active Pokémon.",
+            tests: &["hooks/core.rs::test_giovanni_modifier"],
+        }];
+        m.insert(CardId::A1223Giovanni, giovanni.clone());
+        m.insert(CardId::A1270Giovanni, giovanni);
+
+        let misty = vec![
+            Ruling {
+                source: CARD_TEXT,
+                text: "Misty can only be played if you have a Water Pokémon in play.",
+                tests: &["move_generation/trainer_test.rs::test_misty_requires_water_pokemon"],
+            },
+            Ruling {
+                source: CARD_TEXT,
+                text: "Misty flips until tails and attaches one Water Energy per heads.",
+                tests: &["actions/safe_trainer_actions.rs::test_misty_flips_until_tails"],
+            },
+        ];
+        m.insert(CardId::A1220Misty, misty.clone());
+        m.insert(CardId::A1267Misty, misty);
+
+        let koga = vec![Ruling {
+            source: CARD_TEXT,
+            text: "Koga can only be played if your Active Pokémon is Weezing or Muk.",
+            tests: &["move_generation/trainer_test.rs::test_koga_requires_specific_active_pokemon"],
+        }];
+        m.insert(CardId::A1222Koga, koga.clone());
+        m.insert(CardId::A1269Koga, koga);
+
+        let sabrina = vec![Ruling {
+            source: GAME_RULES,
+            text: "Sabrina can't be played if the opponent has no Benched Pokémon to switch in.",
+            tests: &["move_generation/trainer_test.rs::test_sabrina_requires_opponent_bench"],
+        }];
+        m.insert(CardId::A1225Sabrina, sabrina.clone());
+        m.insert(CardId::A1272Sabrina, sabrina);
+
+        let cyrus = vec![Ruling {
+            source: CARD_TEXT,
+            text: "Cyrus can only switch in one of the opponent's Benched Pokémon that has damage on it.",
+            tests: &["move_generation/trainer_test.rs::test_cyrus_requires_damaged_opponent_bench"],
+        }];
+        m.insert(CardId::A2150Cyrus, cyrus.clone());
+        m.insert(CardId::A2190Cyrus, cyrus);
+
+        m.insert(CardId::PA001Potion, vec![Ruling {
+            source: GAME_RULES,
+            text: "Potion can't be played if none of your Pokémon have damage on them.",
+            tests: &["move_generation/trainer_test.rs::test_potion_requires_damaged_pokemon"],
+        }]);
+
+        m.insert(CardId::PA002XSpeed, vec![Ruling {
+            source: CARD_TEXT,
+            text: "X Speed reduces Retreat Cost by 1 for the turn, stacks with itself, and can't go below 0.",
+            tests: &[
+                "hooks/retreat.rs::test_retreat_costs_with_xspeed",
+                "hooks/retreat.rs::test_retreat_costs_with_two_xspeed_and_two_leafs",
+            ],
+        }]);
+
+        let leaf = vec![Ruling {
+            source: CARD_TEXT,
+            text: "Leaf reduces Retreat Cost by 2 for the turn and stacks with X Speed, never below 0.",
+            tests: &["hooks/retreat.rs::test_retreat_costs_with_two_xspeed_and_two_leafs"],
+        }];
+        m.insert(CardId::A1a068Leaf, leaf.clone());
+        m.insert(CardId::A1a082Leaf, leaf);
+
+        m
+    };
+}
+
+/// Returns the rulings registered for a card (empty if there are none).
+pub fn for_card(card_id: CardId) -> &'static [Ruling] {
+    RULINGS_MAP
+        .get(&card_id)
+        .map(|rulings| rulings.as_slice())
+        .unwrap_or(&[])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_for_card() {
+        assert_eq!(for_card(CardId::A1177Weezing).len(), 2);
+        assert_eq!(
+            for_card(CardId::A1177Weezing),
+            for_card(CardId::A1243Weezing)
+        );
+        assert!(for_card(CardId::A1001Bulbasaur).is_empty());
+    }
+
+    #[test]
+    fn test_linked_tests_exist() {
+        // file!() is relative to the crate root (or absolute), so its parent is always src/.
+        let src_dir = Path::new(file!()).parent().unwrap();
+        for (card_id, rulings) in RULINGS_MAP.iter() {
+            for ruling in rulings {
+                assert!(!ruling.tests.is_empty(), "{card_id:?} ruling has no tests");
+                for test in ruling.tests {
+                    let (file, name) = test.split_once("::").unwrap();
+                    let contents = std::fs::read_to_string(src_dir.join(file))
+                        .unwrap_or_else(|_| panic!("{card_id:?} links to missing file {file}"));
+                    assert!(
+                        contents.contains(&format!("fn {name}()")),
+                        "{card_id:?} links to missing test {test}"
+                    );
+                }
+            }
+        }
+    }
+}
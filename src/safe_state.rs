@@ -121,7 +121,7 @@ impl State {
     }
     
     /// Safely generate energy (returns error if deck has no energy types)
-    pub fn generate_energy_safe(&mut self) -> GameResult<()> {
+    pub fn generate_energy_safe(&mut self, rng: &mut impl rand::Rng) -> GameResult<()> {
         if self.current_player >= 2 {
             return Err(GameError::InvalidPlayer { player: self.current_player });
         }
@@ -140,12 +140,10 @@ impl State {
         }
         
         use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        
-        let generated = deck_energies
-            .choose(&mut rng)
-            .ok_or_game_error(|| GameError::internal("generate_energy", "Failed to choose random energy"))?;
-            
+        let generated = deck_energies.choose(rng).ok_or_game_error(|| {
+            GameError::internal("generate_energy", "Failed to choose random energy")
+        })?;
+
         self.current_energy = Some(*generated);
         Ok(())
     }
@@ -162,8 +160,8 @@ pub mod safe_operations {
         card: &Card,
         position: usize,
     ) -> GameResult<()> {
-        use crate::hooks::to_playable_card;
-        
+        use crate::hooks::to_evolved_card;
+
         // Validate inputs
         if acting_player >= 2 {
             return Err(GameError::InvalidPlayer { player: acting_player });
@@ -207,13 +205,8 @@ pub mod safe_operations {
         }
         
         // Create evolved Pokemon
-        let mut played_card = to_playable_card(card, true);
-        let damage_taken = old_pokemon.total_hp - old_pokemon.remaining_hp;
-        played_card.remaining_hp = played_card.remaining_hp.saturating_sub(damage_taken);
-        played_card.attached_energy = old_pokemon.attached_energy.clone();
-        played_card.cards_behind = old_pokemon.cards_behind.clone();
-        played_card.cards_behind.push(old_pokemon.card.clone());
-        
+        let played_card = to_evolved_card(old_pokemon, card);
+
         // Place evolved Pokemon
         state.in_play_pokemon[acting_player][position] = Some(played_card);
         
@@ -264,7 +257,8 @@ pub mod safe_operations {
             
             // Discard energy for retreat cost
             let count = retreat_cost.len();
-            attached_energy.truncate(attached_energy.len() - count);
+            let discarded = attached_energy.split_off(attached_energy.len() - count);
+            state.record_discarded_energy(acting_player, &discarded);
         }
         
         // Swap Pokemon
@@ -276,6 +270,8 @@ pub mod safe_operations {
                 poisoned: false,
                 paralyzed: false,
                 asleep: false,
+                burned: false,
+                confused: false,
                 ..pokemon.clone()
             });
         }
@@ -302,10 +298,12 @@ pub mod safe_operations {
 mod tests {
     use super::*;
     use crate::{
-        test_helpers::load_test_decks,
+        sets::SetId,
+        test_helpers::{load_test_decks, to_trainer_card},
         types::{PokemonCard, TrainerCard, TrainerType},
     };
-    
+    use rand::{rngs::StdRng, SeedableRng};
+
     #[test]
     fn test_safe_get_remaining_hp() {
         let (deck_a, deck_b) = load_test_decks();
@@ -326,14 +324,14 @@ mod tests {
         let (deck_a, deck_b) = load_test_decks();
         let mut state = State::new(&deck_a, &deck_b);
         
-        let fake_card = Card::Trainer(TrainerCard {
+        let fake_card = to_trainer_card(TrainerCard {
             id: "999".to_string(),
             numeric_id: 999,
             name: "Fake Card".to_string(),
             trainer_card_type: TrainerType::Item,
             effect: "Test effect".to_string(),
             rarity: "Common".to_string(),
-            booster_pack: "Test".to_string(),
+            set: SetId::A1,
         });
         
         // Should fail - card not in hand
@@ -350,11 +348,18 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // Should fail - no energy types
-        assert!(state.generate_energy_safe().is_err());
-        
+        assert!(state
+            .generate_energy_safe(&mut StdRng::seed_from_u64(0))
+            .is_err());
+
         // Fix and retry
-        state.decks[0].energy_types.push(crate::types::EnergyType::Grass);
-        assert!(state.generate_energy_safe().is_ok());
+        state
+            .deck_mut(0)
+            .energy_types
+            .push(crate::types::EnergyType::Grass);
+        assert!(state
+            .generate_energy_safe(&mut StdRng::seed_from_u64(0))
+            .is_ok());
         assert_eq!(state.current_energy, Some(crate::types::EnergyType::Grass));
     }
 }
\ No newline at end of file
@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+
+use log::debug;
+
+use super::protocol::{ClientMessage, MatchEvent, ServerMessage};
+use crate::{deck::DeckFormat, game_log::GameLogEntry, Deck, Game, GameRules};
+
+/// Identifies a connection to the server.
+pub type ClientId = u64;
+
+struct ServerMatch {
+    seed: u64,
+    game: Game,
+    seats: [Option<ClientId>; 2],
+    // Whether both seats were taken, after which the match is dropped once both are empty
+    started: bool,
+}
+
+/// Every match the server runs, and who sits where. Transport agnostic: it turns each
+/// client message into the messages to send back, addressed to their clients.
+#[derive(Default)]
+pub struct Lobby {
+    matches: HashMap<u64, ServerMatch>,
+    next_match_id: u64,
+    // (match_id, player) of each client that joined a match
+    seats: HashMap<ClientId, (u64, usize)>,
+}
+
+impl Lobby {
+    pub fn handle(
+        &mut self,
+        client: ClientId,
+        message: ClientMessage,
+    ) -> Vec<(ClientId, ServerMessage)> {
+        let result = match message {
+            ClientMessage::CreateMatch {
+                deck_a,
+                deck_b,
+                seed,
+            } => self.create_match(client, &deck_a, &deck_b, seed),
+            ClientMessage::Join { match_id, player } => self.join(client, match_id, player),
+            ClientMessage::SubmitAction { index } => self.submit_action(client, index),
+        };
+        result.unwrap_or_else(|message| vec![(client, ServerMessage::Error { message })])
+    }
+
+    /// Frees the seat of a client that went away, so someone else can take it. A match both
+    /// players joined is dropped once they both left.
+    pub fn disconnect(&mut self, client: ClientId) {
+        if let Some((match_id, player)) = self.seats.remove(&client) {
+            if let Some(server_match) = self.matches.get_mut(&match_id) {
+                server_match.seats[player] = None;
+                if server_match.started && server_match.seats.iter().all(Option::is_none) {
+                    debug!("Dropping abandoned match {match_id}");
+                    self.matches.remove(&match_id);
+                }
+            }
+        }
+    }
+
+    fn end_match(&mut self, match_id: u64) {
+        if let Some(server_match) = self.matches.remove(&match_id) {
+            for client in server_match.seats.iter().flatten() {
+                self.seats.remove(client);
+            }
+        }
+    }
+
+    fn create_match(
+        &mut self,
+        client: ClientId,
+        deck_a: &str,
+        deck_b: &str,
+        seed: Option<u64>,
+    ) -> Result<Vec<(ClientId, ServerMessage)>, String> {
+        let deck_a = parse_deck(deck_a).map_err(|err| format!("Deck A: {err}"))?;
+        let deck_b = parse_deck(deck_b).map_err(|err| format!("Deck B: {err}"))?;
+        let seed = seed.unwrap_or_else(rand::random);
+        let match_id = self.next_match_id;
+        self.next_match_id += 1;
+        let game = Game::without_players(&deck_a, &deck_b, seed, GameRules::default());
+        self.matches.insert(
+            match_id,
+            ServerMatch {
+                seed,
+                game,
+                seats: [None, None],
+                started: false,
+            },
+        );
+        debug!("Created match {match_id} with seed {seed}");
+        Ok(vec![(client, ServerMessage::MatchCreated { match_id })])
+    }
+
+    fn join(
+        &mut self,
+        client: ClientId,
+        match_id: u64,
+        player: usize,
+    ) -> Result<Vec<(ClientId, ServerMessage)>, String> {
+        if self.seats.contains_key(&client) {
+            return Err("Already joined a match".to_string());
+        }
+        let server_match = self
+            .matches
+            .get_mut(&match_id)
+            .ok_or(format!("No match {match_id}"))?;
+        let seat = server_match
+            .seats
+            .get_mut(player)
+            .ok_or(format!("Player should be 0 or 1, got {player}"))?;
+        if seat.is_some() {
+            return Err(format!("Player {player} is already taken"));
+        }
+        *seat = Some(client);
+        server_match.started |= server_match.seats.iter().all(Option::is_some);
+        self.seats.insert(client, (match_id, player));
+        Ok(vec![(client, state_message(&server_match.game, player))])
+    }
+
+    fn submit_action(
+        &mut self,
+        client: ClientId,
+        index: usize,
+    ) -> Result<Vec<(ClientId, ServerMessage)>, String> {
+        let (match_id, player) = *self.seats.get(&client).ok_or("Not in a match")?;
+        let server_match = self
+            .matches
+            .get_mut(&match_id)
+            .expect("Seats should only point to existing matches");
+        let game = &mut server_match.game;
+        let (actor, actions) = game.legal_actions();
+        if game.get_state().is_game_over() || actor != player {
+            return Err("Not your turn to decide".to_string());
+        }
+        let action = actions
+            .get(index)
+            .ok_or(format!("No legal action {index}"))?
+            .clone();
+        let played = game
+            .play_tick_with(&action)
+            .map_err(|err| err.to_string())?;
+
+        // A kept opening board is only told once both are played
+        let state = game.get_state();
+        let first_ply = game.get_num_plys() - played.len() as u32;
+        let events = played
+            .iter()
+            .zip(first_ply..)
+            .map(|(action, ply)| {
+                MatchEvent::from(GameLogEntry::new(server_match.seed, ply, action, state))
+            })
+            .collect::<Vec<_>>();
+        let mut outbox = vec![];
+        for (player, client) in server_match.seats.iter().enumerate() {
+            let Some(client) = client else {
+                continue;
+            };
+            for event in &events {
+                outbox.push((*client, ServerMessage::Event(event.clone())));
+            }
+            outbox.push((*client, state_message(game, player)));
+            if state.is_game_over() {
+                let winner = state.winner;
+                outbox.push((*client, ServerMessage::GameOver { winner }));
+            }
+        }
+        if state.is_game_over() {
+            self.end_match(match_id);
+        }
+        Ok(outbox)
+    }
+}
+
+fn parse_deck(contents: &str) -> Result<Deck, String> {
+    let deck = Deck::from_string(contents)?;
+    deck.validate(&DeckFormat::default())
+        .map_err(|err| err.to_string())?;
+    Ok(deck)
+}
+
+fn state_message(game: &Game, player: usize) -> ServerMessage {
+    let state = game.get_state();
+    let (actor, actions) = game.legal_actions();
+    let legal_actions = if actor == player && !state.is_game_over() {
+        actions.iter().map(|x| x.action.to_string()).collect()
+    } else {
+        vec![]
+    };
+    ServerMessage::State {
+        state: Box::new(state.masked_for(player)),
+        legal_actions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn create_match(lobby: &mut Lobby) -> u64 {
+        let deck = fs::read_to_string("example_decks/venusaur-exeggutor.txt").unwrap();
+        let message = ClientMessage::CreateMatch {
+            deck_a: deck.clone(),
+            deck_b: deck,
+            seed: Some(0),
+        };
+        match &lobby.handle(0, message)[..] {
+            [(0, ServerMessage::MatchCreated { match_id })] => *match_id,
+            other => panic!("Unexpected answer {other:?}"),
+        }
+    }
+
+    fn legal_actions(messages: &[(ClientId, ServerMessage)], client: ClientId) -> Vec<String> {
+        messages
+            .iter()
+            .rev()
+            .find_map(|(to, message)| match message {
+                ServerMessage::State { legal_actions, .. } if *to == client => {
+                    Some(legal_actions.clone())
+                }
+                _ => None,
+            })
+            .expect("Client should have been sent its state")
+    }
+
+    fn events(messages: &[(ClientId, ServerMessage)]) -> usize {
+        messages
+            .iter()
+            .filter(|(_, message)| matches!(message, ServerMessage::Event(_)))
+            .count()
+    }
+
+    #[test]
+    fn test_players_take_turns_submitting_actions() {
+        let mut lobby = Lobby::default();
+        let match_id = create_match(&mut lobby);
+        let joined_a = lobby.handle(
+            1,
+            ClientMessage::Join {
+                match_id,
+                player: 0,
+            },
+        );
+        let joined_b = lobby.handle(
+            2,
+            ClientMessage::Join {
+                match_id,
+                player: 1,
+            },
+        );
+        let (deciding, waiting) = if legal_actions(&joined_a, 1).is_empty() {
+            (2, 1)
+        } else {
+            (1, 2)
+        };
+        assert!(legal_actions(&joined_a, 1).is_empty() != legal_actions(&joined_b, 2).is_empty());
+
+        let rejected = lobby.handle(waiting, ClientMessage::SubmitAction { index: 0 });
+        assert!(matches!(&rejected[..], [(_, ServerMessage::Error { .. })]));
+
+        // The first opening board is kept from the other player until they chose theirs
+        let kept = lobby.handle(deciding, ClientMessage::SubmitAction { index: 0 });
+        assert_eq!(events(&kept), 0);
+        assert!(legal_actions(&kept, deciding).is_empty());
+        assert!(!legal_actions(&kept, waiting).is_empty());
+        let rejected = lobby.handle(deciding, ClientMessage::SubmitAction { index: 0 });
+        assert!(matches!(&rejected[..], [(_, ServerMessage::Error { .. })]));
+
+        let played = lobby.handle(waiting, ClientMessage::SubmitAction { index: 0 });
+        assert_eq!(events(&played), 4); // Both boards, for each seat
+    }
+
+    #[test]
+    fn test_invalid_decks_are_rejected() {
+        let mut lobby = Lobby::default();
+        let deck = fs::read_to_string("example_decks/venusaur-exeggutor.txt").unwrap();
+        let message = ClientMessage::CreateMatch {
+            deck_a: deck,
+            deck_b: "2 Potion P-A 1".to_string(),
+            seed: Some(0),
+        };
+        let rejected = lobby.handle(0, message);
+        assert!(matches!(&rejected[..], [(0, ServerMessage::Error { .. })]));
+        assert!(lobby.matches.is_empty());
+    }
+
+    #[test]
+    fn test_finished_matches_are_dropped() {
+        let mut lobby = Lobby::default();
+        let match_id = create_match(&mut lobby);
+        let mut messages = vec![];
+        for (client, player) in [(1, 0), (2, 1)] {
+            messages.extend(lobby.handle(client, ClientMessage::Join { match_id, player }));
+        }
+        while !messages
+            .iter()
+            .any(|(_, message)| matches!(message, ServerMessage::GameOver { .. }))
+        {
+            let deciding = if legal_actions(&messages, 1).is_empty() {
+                2
+            } else {
+                1
+            };
+            messages = lobby.handle(deciding, ClientMessage::SubmitAction { index: 0 });
+        }
+        assert!(lobby.matches.is_empty());
+        assert!(lobby.seats.is_empty());
+    }
+
+    #[test]
+    fn test_abandoned_matches_are_dropped() {
+        let mut lobby = Lobby::default();
+        let match_id = create_match(&mut lobby);
+        for (client, player) in [(1, 0), (2, 1)] {
+            lobby.handle(client, ClientMessage::Join { match_id, player });
+        }
+        lobby.disconnect(1);
+        assert!(lobby.matches.contains_key(&match_id));
+        lobby.disconnect(2);
+        assert!(lobby.matches.is_empty());
+    }
+
+    #[test]
+    fn test_seats_can_only_be_taken_once() {
+        let mut lobby = Lobby::default();
+        let match_id = create_match(&mut lobby);
+        lobby.handle(
+            1,
+            ClientMessage::Join {
+                match_id,
+                player: 0,
+            },
+        );
+        let rejected = lobby.handle(
+            2,
+            ClientMessage::Join {
+                match_id,
+                player: 0,
+            },
+        );
+        assert!(matches!(&rejected[..], [(2, ServerMessage::Error { .. })]));
+
+        lobby.disconnect(1);
+        let joined = lobby.handle(
+            2,
+            ClientMessage::Join {
+                match_id,
+                player: 0,
+            },
+        );
+        assert!(matches!(&joined[..], [(2, ServerMessage::State { .. })]));
+    }
+}
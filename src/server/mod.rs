@@ -0,0 +1,124 @@
+mod lobby;
+mod protocol;
+
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use futures_util::{SinkExt, StreamExt};
+use log::{debug, warn};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+};
+use tokio_tungstenite::tungstenite::Message;
+
+pub use lobby::{ClientId, Lobby};
+pub use protocol::{ClientMessage, MatchEvent, ServerMessage};
+
+enum Command {
+    Connect(ClientId, UnboundedSender<ServerMessage>),
+    Handle(ClientId, ClientMessage),
+    Disconnect(ClientId),
+}
+
+// Games aren't Send, so the lobby lives in its own thread and connections talk to it
+fn run_lobby(mut commands: UnboundedReceiver<Command>) {
+    let mut lobby = Lobby::default();
+    let mut clients = HashMap::new();
+    while let Some(command) = commands.blocking_recv() {
+        match command {
+            Command::Connect(client, sender) => {
+                clients.insert(client, sender);
+            }
+            Command::Handle(client, message) => {
+                for (to, message) in lobby.handle(client, message) {
+                    if let Some(sender) = clients.get(&to) {
+                        // The client may have just disconnected, so it doesn't care anymore
+                        let _ = sender.send(message);
+                    }
+                }
+            }
+            Command::Disconnect(client) => {
+                lobby.disconnect(client);
+                clients.remove(&client);
+            }
+        }
+    }
+}
+
+/// Accepts WebSocket connections on `addr` (e.g. "127.0.0.1:9001") until it fails, so that
+/// any frontend (or bot in another language) can play against the engine. Clients exchange
+/// JSON text messages:
+///
+/// 1. `create_match` with two decks, answered with the `match_created` id.
+/// 2. `join` a seat of that match, answered with the `state` the seat can see, listing the
+///    `legal_actions` when it is the one to decide.
+/// 3. `submit_action` with the index of one of those legal actions. Both seats then get
+///    each played action as an `event` (the first opening board once both are chosen), their
+///    new `state`, and `game_over` once it ends (freeing both seats to create another).
+///
+/// See `ClientMessage` and `ServerMessage` for the exact fields.
+pub async fn serve(addr: &str) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    debug!("Serving matches on {addr}");
+    let (commands, receiver) = mpsc::unbounded_channel();
+    thread::spawn(move || run_lobby(receiver));
+    let next_client_id = AtomicU64::new(0);
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let client = next_client_id.fetch_add(1, Ordering::Relaxed);
+        debug!("Client {client} connected from {peer}");
+        tokio::spawn(handle_connection(commands.clone(), client, stream));
+    }
+}
+
+async fn handle_connection(
+    commands: UnboundedSender<Command>,
+    client: ClientId,
+    stream: TcpStream,
+) {
+    let websocket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(websocket) => websocket,
+        Err(err) => {
+            warn!("Client {client} failed the WebSocket handshake: {err}");
+            return;
+        }
+    };
+    let (mut outgoing, mut incoming) = websocket.split();
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    // Sends to the lobby only fail if its thread panicked, leaving nothing to talk to
+    let _ = commands.send(Command::Connect(client, sender.clone()));
+
+    let writer = tokio::spawn(async move {
+        while let Some(message) = receiver.recv().await {
+            let json = serde_json::to_string(&message).expect("Messages should serialize");
+            if outgoing.send(Message::text(json)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(Ok(message)) = incoming.next().await {
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        match serde_json::from_str::<ClientMessage>(text.as_str()) {
+            Ok(message) => {
+                let _ = commands.send(Command::Handle(client, message));
+            }
+            Err(err) => {
+                let message = format!("Invalid message: {err}");
+                let _ = sender.send(ServerMessage::Error { message });
+            }
+        }
+    }
+
+    debug!("Client {client} disconnected");
+    let _ = commands.send(Command::Disconnect(client));
+    writer.abort();
+}
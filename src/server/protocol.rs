@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{actions::CheckupEvent, game_log::GameLogEntry, state::GameOutcome, MaskedState};
+
+/// What clients send, as JSON text messages tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Creates a match between two decks (in the deck file format), answered with
+    /// `MatchCreated`. Random if no seed is given.
+    CreateMatch {
+        deck_a: String,
+        deck_b: String,
+        seed: Option<u64>,
+    },
+    /// Takes the seat of `player` (0 or 1) in a match, answered with its `State`.
+    Join { match_id: u64, player: usize },
+    /// Plays the action at `index` of the legal actions last sent to this client.
+    SubmitAction { index: usize },
+}
+
+/// What the server sends, as JSON text messages tagged by `type`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    MatchCreated {
+        match_id: u64,
+    },
+    /// The game as the client's player sees it, and the actions they can submit (none
+    /// unless they are the one to decide).
+    State {
+        state: Box<MaskedState>,
+        legal_actions: Vec<String>,
+    },
+    /// An action was played (sent to both seats).
+    Event(MatchEvent),
+    /// The game ended, with no winner if it timed out.
+    GameOver {
+        winner: Option<GameOutcome>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// A game log entry without the seed, which would let clients replay the shuffles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MatchEvent {
+    pub ply: u32,
+    pub turn: u8,
+    pub actor: usize,
+    pub action: String,
+    pub points: [u8; 2],
+    pub hp: [u32; 2],
+    pub hand_sizes: [usize; 2],
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checkup: Vec<CheckupEvent>,
+}
+
+impl From<GameLogEntry> for MatchEvent {
+    fn from(entry: GameLogEntry) -> Self {
+        Self {
+            ply: entry.ply,
+            turn: entry.turn,
+            actor: entry.actor,
+            action: entry.action,
+            points: entry.points,
+            hp: entry.hp,
+            hand_sizes: entry.hand_sizes,
+            checkup: entry.checkup,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_are_tagged_by_type() {
+        let message: ClientMessage =
+            serde_json::from_str(r#"{"type": "submit_action", "index": 2}"#).unwrap();
+        assert_eq!(message, ClientMessage::SubmitAction { index: 2 });
+
+        let message = ServerMessage::MatchCreated { match_id: 7 };
+        assert_eq!(
+            serde_json::to_string(&message).unwrap(),
+            r#"{"type":"match_created","match_id":7}"#
+        );
+    }
+}
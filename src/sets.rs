@@ -0,0 +1,170 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An expansion of TCG Pocket, whose code prefixes its card ids (e.g. "A1a 001").
+/// Serializes as its booster pack name, like `booster_pack` in database.json.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum SetId {
+    A1,
+    PromoA,
+    A1a,
+    A2,
+    A2a,
+    A2b,
+    A3,
+    A3a,
+    A3b,
+}
+
+/// What is known about a set. Dates are ISO 8601 ("YYYY-MM-DD"), so they compare as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetInfo {
+    pub id: SetId,
+    /// Prefix of the set's card ids.
+    pub code: &'static str,
+    pub booster_pack: &'static str,
+    /// Position in release order, from 0. Promos count from the first set they came with.
+    pub release_order: u8,
+    /// First day the set's cards are legal.
+    pub legal_from: &'static str,
+    /// Last day the set's cards are legal, if it rotated out.
+    pub legal_until: Option<&'static str>,
+}
+
+const fn set(
+    id: SetId,
+    code: &'static str,
+    booster_pack: &'static str,
+    release_order: u8,
+    legal_from: &'static str,
+) -> SetInfo {
+    SetInfo {
+        id,
+        code,
+        booster_pack,
+        release_order,
+        legal_from,
+        legal_until: None,
+    }
+}
+
+/// Every set, in release order (the order of `SetId`).
+pub const ALL_SETS: &[SetInfo] = &[
+    set(SetId::A1, "A1", "Genetic Apex (A1)", 0, "2024-10-30"),
+    set(SetId::PromoA, "P-A", "Promo-A", 0, "2024-10-30"),
+    set(SetId::A1a, "A1a", "Mythical Island (A1a)", 1, "2024-12-17"),
+    set(
+        SetId::A2,
+        "A2",
+        "Space-Time Smackdown (A2)",
+        2,
+        "2025-01-29",
+    ),
+    set(SetId::A2a, "A2a", "Triumphant Light (A2a)", 3, "2025-02-28"),
+    set(SetId::A2b, "A2b", "Shining Revelry (A2b)", 4, "2025-03-27"),
+    set(SetId::A3, "A3", "Celestial Guardians (A3)", 5, "2025-04-30"),
+    set(
+        SetId::A3a,
+        "A3a",
+        "Extradimensional Crisis (A3a)",
+        6,
+        "2025-05-29",
+    ),
+    set(SetId::A3b, "A3b", "Eevee Grove (A3b)", 7, "2025-06-26"),
+];
+
+impl SetId {
+    pub fn info(self) -> &'static SetInfo {
+        &ALL_SETS[self as usize]
+    }
+
+    pub fn code(self) -> &'static str {
+        self.info().code
+    }
+
+    /// The set of a code such as "A1a" or "P-A".
+    pub fn from_code(code: &str) -> Option<Self> {
+        ALL_SETS.iter().find(|x| x.code == code).map(|x| x.id)
+    }
+
+    /// The set of a card id such as "A1a 001".
+    pub fn from_card_id(id: &str) -> Option<Self> {
+        Self::from_code(id.split_whitespace().next()?)
+    }
+
+    pub fn from_booster_pack(name: &str) -> Option<Self> {
+        ALL_SETS
+            .iter()
+            .find(|x| x.booster_pack == name)
+            .map(|x| x.id)
+    }
+
+    /// Whether the set's cards can be played on `date` ("YYYY-MM-DD").
+    pub fn is_legal_on(self, date: &str) -> bool {
+        let info = self.info();
+        info.legal_from <= date && info.legal_until.is_none_or(|until| date <= until)
+    }
+}
+
+impl fmt::Display for SetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl TryFrom<String> for SetId {
+    type Error = String;
+
+    fn try_from(booster_pack: String) -> Result<Self, Self::Error> {
+        Self::from_booster_pack(&booster_pack)
+            .ok_or_else(|| format!("Unknown booster pack: {booster_pack}"))
+    }
+}
+
+impl From<SetId> for String {
+    fn from(set: SetId) -> Self {
+        set.info().booster_pack.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card_ids::ALL_CARD_IDS, database::get_card_by_enum};
+
+    #[test]
+    fn test_sets_are_listed_in_order() {
+        for (i, info) in ALL_SETS.iter().enumerate() {
+            assert_eq!(info.id as usize, i);
+            assert_eq!(SetId::from_code(info.code), Some(info.id));
+        }
+        assert!(ALL_SETS
+            .windows(2)
+            .all(|x| x[0].release_order <= x[1].release_order));
+    }
+
+    #[test]
+    fn test_cards_ids_match_their_set() {
+        for card_id in ALL_CARD_IDS {
+            let card = get_card_by_enum(*card_id);
+            assert_eq!(SetId::from_card_id(&card.get_id()), Some(card.get_set()));
+        }
+    }
+
+    #[test]
+    fn test_serializes_as_booster_pack_name() {
+        let json = serde_json::to_string(&SetId::A2a).unwrap();
+        assert_eq!(json, "\"Triumphant Light (A2a)\"");
+        assert_eq!(serde_json::from_str::<SetId>(&json).unwrap(), SetId::A2a);
+        assert!(serde_json::from_str::<SetId>("\"Unknown (Z1)\"").is_err());
+    }
+
+    #[test]
+    fn test_legality_window() {
+        assert!(!SetId::A3b.is_legal_on("2025-06-25"));
+        assert!(SetId::A3b.is_legal_on("2025-06-26"));
+        assert!(SetId::A1.is_legal_on("2026-01-01"));
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+
+use super::{GameEvent, Metric};
+use crate::{
+    actions::{Action, SimpleAction},
+    state::GameOutcome,
+    types::Card,
+    Deck, Game,
+};
+
+/// How a card did across the games in which its deck played it. Counts are in games
+/// (e.g. `drawn` is the number of games in which at least one copy reached the hand).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CardStats {
+    pub name: String,
+    pub games: u32,
+    pub wins: u32,
+    pub drawn: u32,
+    pub wins_when_drawn: u32,
+    pub played: u32,
+    pub wins_when_played: u32,
+    /// Still in hand when the game ended.
+    pub stranded: u32,
+}
+
+impl CardStats {
+    /// Fraction of the games in which the card was drawn that it ended up stuck in hand.
+    pub fn stranded_rate(&self) -> f32 {
+        ratio(self.stranded, self.drawn)
+    }
+
+    pub fn win_rate_when_drawn(&self) -> f32 {
+        ratio(self.wins_when_drawn, self.drawn)
+    }
+
+    pub fn win_rate_when_not_drawn(&self) -> f32 {
+        ratio(self.wins - self.wins_when_drawn, self.games - self.drawn)
+    }
+
+    pub fn win_rate_when_played(&self) -> f32 {
+        ratio(self.wins_when_played, self.played)
+    }
+
+    /// How much drawing the card changes the win rate. Dead cards are close to (or below) 0.
+    pub fn draw_win_impact(&self) -> f32 {
+        self.win_rate_when_drawn() - self.win_rate_when_not_drawn()
+    }
+}
+
+fn ratio(numerator: u32, denominator: u32) -> f32 {
+    if denominator == 0 {
+        return 0.0;
+    }
+    numerator as f32 / denominator as f32
+}
+
+/// Per-card statistics of each of the two decks in a simulation, keyed by card id.
+#[derive(Debug, Clone, Default)]
+pub struct CardStatsReport {
+    pub decks: [IndexMap<String, CardStats>; 2],
+}
+
+impl CardStatsReport {
+    /// Plays the game to the end, recording which cards each player drew and played.
+    pub fn play_and_record(&mut self, game: &mut Game, decks: [&Deck; 2]) -> Option<GameOutcome> {
+        self.play_and_observe(game, decks, &mut [])
+    }
+
+    /// Like `play_and_record`, also feeding the game's events to the metrics.
+    pub fn play_and_observe(
+        &mut self,
+        game: &mut Game,
+        decks: [&Deck; 2],
+        metrics: &mut [&mut dyn Metric],
+    ) -> Option<GameOutcome> {
+        for metric in metrics.iter_mut() {
+            metric.observe(&GameEvent::GameStart {
+                state: game.get_state(),
+            });
+        }
+        let mut drawn: [HashSet<String>; 2] = Default::default();
+        let mut played: [HashSet<String>; 2] = Default::default();
+        loop {
+            for (player, drawn) in drawn.iter_mut().enumerate() {
+                drawn.extend(game.get_state().hands[player].iter().map(|x| x.get_id()));
+            }
+            if game.get_state().is_game_over() {
+                break;
+            }
+            // Only pay for cloning states when someone is watching
+            let before = (!metrics.is_empty()).then(|| game.get_state_clone());
+            let action = game.play_tick();
+            if let Some(before) = before {
+                let event = GameEvent::Action {
+                    before: &before,
+                    action: &action,
+                    after: game.get_state(),
+                };
+                for metric in metrics.iter_mut() {
+                    metric.observe(&event);
+                }
+            }
+            if let Some(card) = played_card(&action) {
+                played[action.actor].insert(card.get_id());
+            }
+        }
+
+        let state = game.get_state();
+        for player in 0..2 {
+            let won = state.winner == Some(GameOutcome::Win(player));
+            let mut counted = HashSet::new();
+            for card in &decks[player].cards {
+                let id = card.get_id();
+                if !counted.insert(id.clone()) {
+                    continue;
+                }
+                let stats = self.decks[player]
+                    .entry(id.clone())
+                    .or_insert_with(|| CardStats {
+                        name: card.get_name(),
+                        ..Default::default()
+                    });
+                stats.games += 1;
+                stats.wins += won as u32;
+                if drawn[player].contains(&id) {
+                    stats.drawn += 1;
+                    stats.wins_when_drawn += won as u32;
+                }
+                if played[player].contains(&id) {
+                    stats.played += 1;
+                    stats.wins_when_played += won as u32;
+                }
+                if state.hands[player].iter().any(|x| x.get_id() == id) {
+                    stats.stranded += 1;
+                }
+            }
+        }
+        for metric in metrics.iter_mut() {
+            metric.observe(&GameEvent::GameEnd {
+                state,
+                outcome: state.winner,
+            });
+        }
+        state.winner
+    }
+
+    /// Cards of the deck that were drawn at least `min_drawn` times, sorted from the most
+    /// often stranded in hand.
+    pub fn dead_cards(&self, player: usize, min_drawn: u32) -> Vec<&CardStats> {
+        let mut cards: Vec<&CardStats> = self.decks[player]
+            .values()
+            .filter(|x| x.drawn >= min_drawn)
+            .collect();
+        cards.sort_by(|a, b| b.stranded_rate().total_cmp(&a.stranded_rate()));
+        cards
+    }
+}
+
+fn played_card(action: &Action) -> Option<Card> {
+    match &action.action {
+        SimpleAction::Place(card, _) | SimpleAction::Evolve(card, _) => Some(card.clone()),
+        SimpleAction::Play { trainer_card } => Some(Card::Trainer(trainer_card)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::players::{create_players, PlayerCode};
+
+    #[test]
+    fn test_card_stats_report() {
+        let deck_a = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let deck_b = Deck::from_file("example_decks/weezing-arbok.txt").unwrap();
+        let mut report = CardStatsReport::default();
+        let mut wins = [0, 0];
+        for seed in 0..20 {
+            let players = create_players(
+                deck_a.clone(),
+                deck_b.clone(),
+                vec![PlayerCode::R, PlayerCode::R],
+            );
+            let mut game = Game::new(players, seed);
+            if let Some(GameOutcome::Win(winner)) =
+                report.play_and_record(&mut game, [&deck_a, &deck_b])
+            {
+                wins[winner] += 1;
+            }
+        }
+
+        // 11 different prints in each deck
+        assert_eq!(report.decks[0].len(), 11);
+        assert_eq!(report.decks[1].len(), 11);
+        for (player, stats) in report.decks.iter().enumerate() {
+            for card in stats.values() {
+                assert_eq!(card.games, 20);
+                assert_eq!(card.wins, wins[player]);
+                assert!(card.played <= card.drawn);
+                assert!(card.stranded <= card.drawn);
+                assert!(card.wins_when_drawn <= card.wins);
+            }
+        }
+        let bulbasaur = &report.decks[0]["A1 001"];
+        assert_eq!(bulbasaur.name, "Bulbasaur");
+        assert!(bulbasaur.drawn > 0);
+        assert!(bulbasaur.played > 0);
+
+        let dead_cards = report.dead_cards(0, 1);
+        assert!(dead_cards
+            .windows(2)
+            .all(|x| x[0].stranded_rate() >= x[1].stranded_rate()));
+    }
+}
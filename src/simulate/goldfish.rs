@@ -0,0 +1,226 @@
+use std::fmt;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    actions::SimpleAction,
+    players::{create_players, PlayerCode},
+    Deck, Game, State,
+};
+
+/// Own turns played in each goldfish game.
+pub const GOLDFISH_TURNS: u32 = 8;
+
+/// HP of the opponent's Active Pokémon, so that no attack ends a goldfish game.
+const GOLDFISH_HP: u32 = 100_000;
+
+/// What to look for in goldfish games, besides the first attack and evolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldfishConfig {
+    /// Number of Benched Pokémon to reach.
+    pub bench_target: usize,
+    /// Names of Pokémon (e.g. "Venusaur ex") whose first turn in play is reported.
+    pub key_pokemon: Vec<String>,
+}
+
+impl Default for GoldfishConfig {
+    fn default() -> Self {
+        Self {
+            bench_target: 2,
+            key_pokemon: vec![],
+        }
+    }
+}
+
+/// When something first happened in the games where it happened, in own turns (1 for
+/// the deck's first turn, 0 for the setup).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TurnStat {
+    /// Games in which it happened within `GOLDFISH_TURNS` turns.
+    pub reached: u32,
+    total_turns: u32,
+}
+
+impl TurnStat {
+    fn record(&mut self, turn: Option<u32>) {
+        if let Some(turn) = turn {
+            self.reached += 1;
+            self.total_turns += turn;
+        }
+    }
+
+    /// Average turn, over the games in which it happened.
+    pub fn average(&self) -> Option<f64> {
+        (self.reached > 0).then(|| self.total_turns as f64 / self.reached as f64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldfishReport {
+    pub games: u32,
+    pub first_attack: TurnStat,
+    pub first_evolution: TurnStat,
+    /// Having `bench_target` Benched Pokémon.
+    pub bench: TurnStat,
+    pub bench_target: usize,
+    pub key_pokemon: Vec<(String, TurnStat)>,
+}
+
+impl fmt::Display for GoldfishReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = |f: &mut fmt::Formatter<'_>, what: &str, stat: &TurnStat| {
+            let average = stat
+                .average()
+                .map_or("-".to_string(), |x| format!("{x:.2}"));
+            writeln!(
+                f,
+                "{what}: turn {average} on average, within {GOLDFISH_TURNS} turns in {:.1}% of games",
+                100.0 * stat.reached as f64 / self.games.max(1) as f64
+            )
+        };
+        line(f, "First attack", &self.first_attack)?;
+        line(f, "First evolution", &self.first_evolution)?;
+        line(f, &format!("{} Benched", self.bench_target), &self.bench)?;
+        for (name, stat) in &self.key_pokemon {
+            line(f, name, stat)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plays `num_games` goldfish games of `GOLDFISH_TURNS` turns with the deck, played by
+/// `player`: alone against an opponent that only ends its turn and can't be Knocked Out,
+/// to measure how fast the deck sets up.
+pub fn goldfish(
+    deck: &Deck,
+    player: PlayerCode,
+    config: &GoldfishConfig,
+    num_games: u32,
+    seed: u64,
+) -> GoldfishReport {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut report = GoldfishReport {
+        games: num_games,
+        first_attack: TurnStat::default(),
+        first_evolution: TurnStat::default(),
+        bench: TurnStat::default(),
+        bench_target: config.bench_target,
+        key_pokemon: config
+            .key_pokemon
+            .iter()
+            .map(|x| (x.clone(), TurnStat::default()))
+            .collect(),
+    };
+    for _ in 0..num_games {
+        let firsts = play_goldfish_game(deck, player.clone(), config, rng.gen());
+        report.first_attack.record(firsts.attack);
+        report.first_evolution.record(firsts.evolution);
+        report.bench.record(firsts.bench);
+        for ((_, stat), turn) in report.key_pokemon.iter_mut().zip(firsts.key_pokemon) {
+            stat.record(turn);
+        }
+    }
+    report
+}
+
+struct Firsts {
+    attack: Option<u32>,
+    evolution: Option<u32>,
+    bench: Option<u32>,
+    key_pokemon: Vec<Option<u32>>,
+}
+
+fn play_goldfish_game(
+    deck: &Deck,
+    player: PlayerCode,
+    config: &GoldfishConfig,
+    seed: u64,
+) -> Firsts {
+    let players = create_players(deck.clone(), deck.clone(), vec![player, PlayerCode::ET]);
+    let mut game = Game::new(players, seed);
+    let mut firsts = Firsts {
+        attack: None,
+        evolution: None,
+        bench: None,
+        key_pokemon: vec![None; config.key_pokemon.len()],
+    };
+    let mut going_first = None;
+    let mut invulnerable = false;
+    loop {
+        let state = game.get_state();
+        if state.turn_count >= 1 && going_first.is_none() {
+            going_first = Some(state.current_player == 0);
+        }
+        let turn = own_turn(state, going_first);
+        if state.is_game_over() || turn > GOLDFISH_TURNS {
+            break;
+        }
+        if state.turn_count >= 1 && !invulnerable {
+            let mut state = game.get_state_clone();
+            if let Some(active) = state.in_play_pokemon[1][0].as_mut() {
+                active.total_hp = GOLDFISH_HP;
+                active.remaining_hp = GOLDFISH_HP;
+            }
+            game.set_state(state);
+            invulnerable = true;
+        }
+
+        let action = game.play_tick();
+        if action.actor != 0 {
+            continue;
+        }
+        if let SimpleAction::Attack(_) = action.action {
+            firsts.attack.get_or_insert(turn);
+        }
+        if let SimpleAction::Evolve(..) = action.action {
+            firsts.evolution.get_or_insert(turn);
+        }
+        let state = game.get_state();
+        if state.enumerate_bench_pokemon(0).count() >= config.bench_target {
+            firsts.bench.get_or_insert(turn);
+        }
+        for (name, first) in config.key_pokemon.iter().zip(&mut firsts.key_pokemon) {
+            if state
+                .enumerate_in_play_pokemon(0)
+                .any(|(_, x)| x.card.get_name() == *name)
+            {
+                first.get_or_insert(turn);
+            }
+        }
+    }
+    firsts
+}
+
+// Turns of player 0: the odd ones when going first, the even ones otherwise
+fn own_turn(state: &State, going_first: Option<bool>) -> u32 {
+    let turn = state.turn_count as u32;
+    match going_first {
+        Some(true) => turn.div_ceil(2),
+        Some(false) => turn / 2,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goldfish() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let config = GoldfishConfig {
+            bench_target: 1,
+            key_pokemon: vec!["Venusaur ex".to_string()],
+        };
+        let report = goldfish(&deck, PlayerCode::W, &config, 20, 0);
+        assert_eq!(report.games, 20);
+        assert!(report.first_attack.reached > 10);
+        assert!(report.bench.reached > 15);
+        let attack = report.first_attack.average().unwrap();
+        assert!((1.0..=GOLDFISH_TURNS as f64).contains(&attack));
+        assert!(report.key_pokemon[0].1.reached <= report.first_evolution.reached);
+        assert!(report.to_string().contains("Venusaur ex: turn"));
+
+        assert_eq!(report, goldfish(&deck, PlayerCode::W, &config, 20, 0));
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::Write;
+
+use log::info;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::Serialize;
+
+use crate::{
+    players::{create_players, PlayerCode},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// z-score of the 95% confidence level used for the reported intervals.
+pub(crate) const Z_95: f64 = 1.96;
+
+/// Results of one deck against another. Ties count as half a win for the win rate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MatchupRecord {
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+}
+
+impl MatchupRecord {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.ties
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games() == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 + self.ties as f64 / 2.0) / self.games() as f64
+    }
+
+    /// Wilson score interval of the win rate, at the given z-score (1.96 for 95%).
+    pub fn wilson_interval(&self, z: f64) -> (f64, f64) {
+        let n = self.games() as f64;
+        if n == 0.0 {
+            return (0.0, 1.0);
+        }
+        let p = self.win_rate();
+        let denominator = 1.0 + z * z / n;
+        let center = (p + z * z / (2.0 * n)) / denominator;
+        let margin = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+        ((center - margin).max(0.0), (center + margin).min(1.0))
+    }
+
+    fn flipped(&self) -> Self {
+        Self {
+            wins: self.losses,
+            losses: self.wins,
+            ties: self.ties,
+        }
+    }
+}
+
+/// NxN results of every deck (rows) against every other deck (columns).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchupMatrix {
+    pub deck_names: Vec<String>,
+    pub records: Vec<Vec<MatchupRecord>>,
+}
+
+/// A deck's results against every other deck of a round robin, mirrors left out.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Standing {
+    pub deck: String,
+    #[serde(flatten)]
+    pub record: MatchupRecord,
+    pub win_rate: f64,
+}
+
+#[derive(Serialize)]
+struct MatchupJson<'a> {
+    deck: &'a str,
+    opponent: &'a str,
+    #[serde(flatten)]
+    record: MatchupRecord,
+    win_rate: f64,
+    ci_low: f64,
+    ci_high: f64,
+}
+
+impl MatchupMatrix {
+    /// One row per deck, one column per opponent, each cell as "win_rate [ci_low; ci_high]".
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("deck");
+        for name in &self.deck_names {
+            write!(csv, ",{}", csv_field(name)).unwrap();
+        }
+        csv.push('\n');
+        for (name, row) in self.deck_names.iter().zip(&self.records) {
+            csv.push_str(&csv_field(name));
+            for record in row {
+                let (low, high) = record.wilson_interval(Z_95);
+                write!(csv, ",{:.4} [{:.4}; {:.4}]", record.win_rate(), low, high).unwrap();
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// A flat list of matchups with raw counts, win rate and 95% interval.
+    pub fn to_json(&self) -> String {
+        let mut matchups = vec![];
+        for (i, row) in self.records.iter().enumerate() {
+            for (j, record) in row.iter().enumerate() {
+                let (ci_low, ci_high) = record.wilson_interval(Z_95);
+                matchups.push(MatchupJson {
+                    deck: &self.deck_names[i],
+                    opponent: &self.deck_names[j],
+                    record: *record,
+                    win_rate: record.win_rate(),
+                    ci_low,
+                    ci_high,
+                });
+            }
+        }
+        serde_json::to_string_pretty(&matchups).expect("Matchups should serialize")
+    }
+
+    /// Round-robin ranking of the decks, best win rate first.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = self
+            .deck_names
+            .iter()
+            .zip(&self.records)
+            .enumerate()
+            .map(|(i, (name, row))| {
+                let mut record = MatchupRecord::default();
+                for (j, x) in row.iter().enumerate() {
+                    if i != j {
+                        record.wins += x.wins;
+                        record.losses += x.losses;
+                        record.ties += x.ties;
+                    }
+                }
+                Standing {
+                    deck: name.clone(),
+                    record,
+                    win_rate: record.win_rate(),
+                }
+            })
+            .collect();
+        standings.sort_by(|a, b| b.win_rate.total_cmp(&a.win_rate));
+        standings
+    }
+
+    /// `standings` with one line per deck: rank, name, wins, losses, ties and win rate.
+    pub fn standings_csv(&self) -> String {
+        let mut csv = String::from("rank,deck,wins,losses,ties,win_rate\n");
+        for (rank, standing) in self.standings().iter().enumerate() {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{:.4}",
+                rank + 1,
+                csv_field(&standing.deck),
+                standing.record.wins,
+                standing.record.losses,
+                standing.record.ties,
+                standing.win_rate
+            )
+            .unwrap();
+        }
+        csv
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Plays `num_games` games for every pair of decks (including mirrors), with the row deck
+/// as player 1. Each unordered pair is only simulated once.
+pub fn matchup_matrix(
+    decks: &[(String, Deck)],
+    players: Vec<PlayerCode>,
+    num_games: u32,
+    seed: u64,
+) -> MatchupMatrix {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut records = vec![vec![MatchupRecord::default(); decks.len()]; decks.len()];
+    for i in 0..decks.len() {
+        for j in i..decks.len() {
+            let mut record = MatchupRecord::default();
+            for _ in 0..num_games {
+                let players =
+                    create_players(decks[i].1.clone(), decks[j].1.clone(), players.clone());
+                let mut game = Game::new(players, rng.gen());
+                match game.play() {
+                    Some(GameOutcome::Win(0)) => record.wins += 1,
+                    Some(GameOutcome::Win(_)) => record.losses += 1,
+                    Some(GameOutcome::Tie) | None => record.ties += 1,
+                }
+            }
+            info!(
+                "{} vs {}: {:?} ({:.2}%)",
+                decks[i].0,
+                decks[j].0,
+                record,
+                record.win_rate() * 100.0
+            );
+            records[i][j] = record;
+            if i != j {
+                records[j][i] = record.flipped();
+            }
+        }
+    }
+    MatchupMatrix {
+        deck_names: decks.iter().map(|(name, _)| name.clone()).collect(),
+        records,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wilson_interval() {
+        let record = MatchupRecord {
+            wins: 8,
+            losses: 2,
+            ties: 0,
+        };
+        let (low, high) = record.wilson_interval(Z_95);
+        assert!((low - 0.4902).abs() < 1e-4);
+        assert!((high - 0.9433).abs() < 1e-4);
+
+        let perfect = MatchupRecord {
+            wins: 5,
+            losses: 0,
+            ties: 0,
+        };
+        let (low, high) = perfect.wilson_interval(Z_95);
+        assert!(low > 0.5 && low < 1.0);
+        assert_eq!(high, 1.0);
+        assert_eq!(MatchupRecord::default().wilson_interval(Z_95), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_matchup_matrix() {
+        let decks: Vec<(String, Deck)> = ["venusaur-exeggutor", "weezing-arbok"]
+            .iter()
+            .map(|name| {
+                let path = format!("example_decks/{name}.txt");
+                (name.to_string(), Deck::from_file(&path).unwrap())
+            })
+            .collect();
+        let matrix = matchup_matrix(&decks, vec![PlayerCode::R, PlayerCode::R], 10, 0);
+        assert_eq!(matrix.records[0][1], matrix.records[1][0].flipped());
+        assert_eq!(matrix.records[0][1].games(), 10);
+        assert_eq!(matrix.records[1][1].games(), 10);
+
+        let csv = matrix.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "deck,venusaur-exeggutor,weezing-arbok");
+        assert!(lines[2].starts_with("weezing-arbok,"));
+        assert_eq!(lines[2].split(',').count(), 3);
+
+        let standings = matrix.standings();
+        assert_eq!(standings.len(), 2);
+        assert!(standings[0].win_rate >= standings[1].win_rate);
+        // Mirrors don't count
+        assert_eq!(standings[0].record.games(), 10);
+        assert_eq!(matrix.standings_csv().lines().count(), 3);
+
+        let json: serde_json::Value = serde_json::from_str(&matrix.to_json()).unwrap();
+        let matchups = json.as_array().unwrap();
+        assert_eq!(matchups.len(), 4);
+        assert_eq!(matchups[1]["deck"], "venusaur-exeggutor");
+        assert_eq!(matchups[1]["opponent"], "weezing-arbok");
+        assert_eq!(matchups[1]["wins"], matrix.records[0][1].wins);
+        assert!(
+            matchups[1]["ci_low"].as_f64().unwrap() <= matchups[1]["win_rate"].as_f64().unwrap()
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    actions::{Action, SimpleAction},
+    state::GameOutcome,
+    State,
+};
+
+/// What happens in a simulated game, as fed to `Metric`s.
+#[derive(Debug, Clone, Copy)]
+pub enum GameEvent<'a> {
+    GameStart {
+        state: &'a State,
+    },
+    /// An action was applied, turning `before` into `after`.
+    Action {
+        before: &'a State,
+        action: &'a Action,
+        after: &'a State,
+    },
+    GameEnd {
+        state: &'a State,
+        outcome: Option<GameOutcome>,
+    },
+}
+
+/// A custom statistic of a simulation, fed every game's events (see
+/// `RunControl::metric`). Metrics keep their own totals across games.
+pub trait Metric {
+    fn name(&self) -> String;
+
+    fn observe(&mut self, event: &GameEvent);
+
+    /// The statistic over the games observed so far.
+    fn value(&self) -> f64;
+}
+
+/// Average damage a player deals per turn of theirs (status damage included).
+#[derive(Debug, Clone, Default)]
+pub struct DamagePerTurn {
+    pub player: usize,
+    damage: u64,
+    turns: u64,
+}
+
+impl DamagePerTurn {
+    pub fn new(player: usize) -> Self {
+        Self {
+            player,
+            ..Default::default()
+        }
+    }
+}
+
+impl Metric for DamagePerTurn {
+    fn name(&self) -> String {
+        format!("Damage per turn of player {}", self.player)
+    }
+
+    fn observe(&mut self, event: &GameEvent) {
+        let GameEvent::Action {
+            before,
+            action,
+            after,
+        } = event
+        else {
+            return;
+        };
+        self.damage += damage_taken(before, after, 1 - self.player) as u64;
+        if action.actor == self.player
+            && action.action == SimpleAction::EndTurn
+            && before.turn_count > 0
+        {
+            self.turns += 1;
+        }
+    }
+
+    fn value(&self) -> f64 {
+        ratio(self.damage, self.turns)
+    }
+}
+
+// HP lost by the player's Pokémon, those Knocked Out losing all they had left. Pokémon that
+// changed slots or evolved are not compared.
+fn damage_taken(before: &State, after: &State, player: usize) -> u32 {
+    before.in_play_pokemon[player]
+        .iter()
+        .zip(&after.in_play_pokemon[player])
+        .map(|(before, after)| match (before, after) {
+            (Some(before), Some(after)) if before.card == after.card => {
+                before.remaining_hp.saturating_sub(after.remaining_hp)
+            }
+            (Some(before), None) => before.remaining_hp,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Average of a player's point lead (their points minus the opponent's) at the end of each
+/// turn, over all turns of all games.
+#[derive(Debug, Clone, Default)]
+pub struct PointLead {
+    pub player: usize,
+    total_lead: i64,
+    turns: u64,
+}
+
+impl PointLead {
+    pub fn new(player: usize) -> Self {
+        Self {
+            player,
+            ..Default::default()
+        }
+    }
+}
+
+impl Metric for PointLead {
+    fn name(&self) -> String {
+        format!("Point lead of player {}", self.player)
+    }
+
+    fn observe(&mut self, event: &GameEvent) {
+        if let GameEvent::Action { action, after, .. } = event {
+            if action.action == SimpleAction::EndTurn {
+                let points = after.points;
+                self.total_lead += points[self.player] as i64 - points[1 - self.player] as i64;
+                self.turns += 1;
+            }
+        }
+    }
+
+    fn value(&self) -> f64 {
+        if self.turns == 0 {
+            return 0.0;
+        }
+        self.total_lead as f64 / self.turns as f64
+    }
+}
+
+/// Average number of turns per game in which a player ended their turn without attaching
+/// the Energy of the turn.
+#[derive(Debug, Clone, Default)]
+pub struct EnergyWasted {
+    pub player: usize,
+    wasted: u64,
+    games: u64,
+}
+
+impl EnergyWasted {
+    pub fn new(player: usize) -> Self {
+        Self {
+            player,
+            ..Default::default()
+        }
+    }
+}
+
+impl Metric for EnergyWasted {
+    fn name(&self) -> String {
+        format!("Energy wasted per game by player {}", self.player)
+    }
+
+    fn observe(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::Action { before, action, .. } => {
+                if action.actor == self.player
+                    && action.action == SimpleAction::EndTurn
+                    && before.current_energy.is_some()
+                    && before.rules.can_attach_turn_energy(before.turn_count)
+                {
+                    self.wasted += 1;
+                }
+            }
+            GameEvent::GameEnd { .. } => self.games += 1,
+            GameEvent::GameStart { .. } => {}
+        }
+    }
+
+    fn value(&self) -> f64 {
+        ratio(self.wasted, self.games)
+    }
+}
+
+fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+    numerator as f64 / denominator as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        players::{create_players, PlayerCode},
+        progress::RunControl,
+        simulate::{simulate_with, CardStatsReport},
+        test_helpers::load_test_decks,
+        Deck, Game,
+    };
+
+    // Counts the events it was fed
+    #[derive(Default)]
+    struct EventCounts([u64; 3]);
+
+    impl Metric for EventCounts {
+        fn name(&self) -> String {
+            "Events".to_string()
+        }
+
+        fn observe(&mut self, event: &GameEvent) {
+            let index = match event {
+                GameEvent::GameStart { .. } => 0,
+                GameEvent::Action { .. } => 1,
+                GameEvent::GameEnd { .. } => 2,
+            };
+            self.0[index] += 1;
+        }
+
+        fn value(&self) -> f64 {
+            self.0[1] as f64
+        }
+    }
+
+    #[test]
+    fn test_simulate_feeds_metrics() {
+        let mut counts = EventCounts::default();
+        let mut damage = DamagePerTurn::new(0);
+        let mut lead = PointLead::new(0);
+        let mut wasted = EnergyWasted::new(1);
+        let mut control = RunControl::new()
+            .metric(&mut counts)
+            .metric(&mut damage)
+            .metric(&mut lead)
+            .metric(&mut wasted);
+        simulate_with(
+            "example_decks/venusaur-exeggutor.txt",
+            "example_decks/weezing-arbok.txt",
+            Some(vec![PlayerCode::AA, PlayerCode::R]),
+            10,
+            Some(0),
+            None,
+            &mut control,
+        );
+        drop(control);
+        assert_eq!(counts.0[0], 10);
+        assert_eq!(counts.0[2], 10);
+        assert!(counts.0[1] > 100);
+        // Attaching and attacking deals damage
+        assert!(damage.value() > 0.0);
+        assert!((-3.0..=3.0).contains(&lead.value()));
+        assert!(wasted.value() >= 0.0);
+    }
+
+    #[test]
+    fn test_energy_wasted_by_player_ending_turns() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut wasted = [EnergyWasted::new(0), EnergyWasted::new(1)];
+        let players = create_players(
+            deck_a.clone(),
+            deck_b.clone(),
+            vec![PlayerCode::AA, PlayerCode::ET],
+        );
+        let mut game = Game::new(players, 1);
+        let [a, b] = &mut wasted;
+        let decks: [&Deck; 2] = [&deck_a, &deck_b];
+        CardStatsReport::default().play_and_observe(&mut game, decks, &mut [a, b]);
+        assert_eq!(wasted[0].value(), 0.0);
+        assert!(wasted[1].value() > 0.0);
+    }
+}
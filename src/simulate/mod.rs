@@ -1,10 +1,36 @@
-use std::time::{Duration, Instant};
+use std::{
+    fs::File,
+    io::BufWriter,
+    time::{Duration, Instant},
+};
 
 use log::{info, warn};
 use num_format::{Locale, ToFormattedString};
 
+mod card_stats;
+mod goldfish;
+mod matchups;
+mod metrics;
+mod paired;
+#[cfg(feature = "sqlite")]
+mod results_db;
+mod selfplay;
+mod win_probability;
+
+pub use card_stats::{CardStats, CardStatsReport};
+pub use goldfish::{goldfish, GoldfishConfig, GoldfishReport, TurnStat, GOLDFISH_TURNS};
+pub(crate) use matchups::Z_95;
+pub use matchups::{matchup_matrix, MatchupMatrix, MatchupRecord, Standing};
+pub use metrics::{DamagePerTurn, EnergyWasted, GameEvent, Metric, PointLead};
+pub use paired::{compare_decks, DeckComparison};
+#[cfg(feature = "sqlite")]
+pub use results_db::{GameRecord, ResultsDb};
+pub use selfplay::{generate_selfplay_data, SelfPlaySample};
+pub use win_probability::{estimate_win_probability, WinProbability};
+
 use crate::{
     players::{create_players, fill_code_array, PlayerCode},
+    progress::{Progress, RunControl},
     state::GameOutcome,
     Deck, Game,
 };
@@ -15,11 +41,35 @@ pub fn simulate(
     players: Option<Vec<PlayerCode>>,
     num_simulations: u32,
     seed: Option<u64>,
-) {
+    log_path: Option<&str>,
+) -> CardStatsReport {
+    simulate_with(
+        deck_a_path,
+        deck_b_path,
+        players,
+        num_simulations,
+        seed,
+        log_path,
+        &mut RunControl::default(),
+    )
+}
+
+/// Like `simulate`, reporting progress (with Deck A's win rate) after every game, feeding
+/// the control's metrics and stopping early if cancelled.
+pub fn simulate_with(
+    deck_a_path: &str,
+    deck_b_path: &str,
+    players: Option<Vec<PlayerCode>>,
+    num_simulations: u32,
+    seed: Option<u64>,
+    log_path: Option<&str>,
+    control: &mut RunControl,
+) -> CardStatsReport {
     // Read the decks files and initialize Players
     let deck_a = Deck::from_file(deck_a_path).expect("Failed to parse deck from file");
     let deck_b = Deck::from_file(deck_b_path).expect("Failed to parse deck from file");
     let cli_players = fill_code_array(players);
+    let log_file = log_path.map(|x| File::create(x).expect("Failed to create game log"));
 
     // Simulate Games and accumulate statistics
     warn!(
@@ -32,15 +82,32 @@ pub fn simulate(
     let mut turns_per_game = Vec::new();
     let mut plys_per_game = Vec::new();
     let mut total_degrees = Vec::new();
+    let mut card_stats = CardStatsReport::default();
+    let mut games_played = 0;
     for i in 1..=num_simulations {
+        if control.is_cancelled() {
+            warn!("Cancelled after {} games", games_played);
+            break;
+        }
         let players = create_players(deck_a.clone(), deck_b.clone(), cli_players.clone());
         let seed = seed.unwrap_or(rand::random::<u64>());
         let mut game = Game::new(players, seed);
-        let outcome = game.play();
+        if let Some(file) = &log_file {
+            let file = file.try_clone().expect("Failed to open game log");
+            game.enable_logging(Box::new(BufWriter::new(file)));
+        }
+        let outcome = card_stats.play_and_observe(&mut game, [&deck_a, &deck_b], control.metrics());
         turns_per_game.push(game.get_state_clone().turn_count);
         plys_per_game.push(game.get_num_plys());
         total_degrees.extend(game.get_degrees_per_ply().iter());
         info!("Simulation {}: Winner is {:?}", i, outcome);
+        #[cfg(feature = "sqlite")]
+        control.record(&GameRecord::new(
+            deck_a_path,
+            deck_b_path,
+            seed,
+            game.get_state(),
+        ));
         match outcome {
             Some(GameOutcome::Win(winner_name)) => {
                 wins_per_deck[winner_name] += 1;
@@ -49,7 +116,18 @@ pub fn simulate(
                 wins_per_deck[2] += 1;
             }
         }
+        games_played += 1;
+        control.report(Progress {
+            games_completed: games_played as u64,
+            total_games: num_simulations as u64,
+            win_rate: wins_per_deck[0] as f64 / games_played as f64,
+        });
     }
+    if games_played == 0 {
+        return card_stats;
+    }
+    // Statistics are over the games actually played, fewer if cancelled
+    let num_simulations = games_played;
     let duration = start.elapsed(); // Measure elapsed time
     let avg_time_per_game = duration.as_secs_f64() / num_simulations as f64;
     let avg_duration = Duration::from_secs_f64(avg_time_per_game);
@@ -96,4 +174,20 @@ pub fn simulate(
         wins_per_deck[2].to_formatted_string(&Locale::en),
         wins_per_deck[2] as f32 / num_simulations as f32 * 100.0
     );
+    for metric in control.metrics() {
+        warn!("{}: {:.2}", metric.name(), metric.value());
+    }
+    for (player, deck_path) in [deck_a_path, deck_b_path].iter().enumerate() {
+        info!("Most stranded cards of Deck {}:", deck_path);
+        for card in card_stats.dead_cards(player, 1).iter().take(5) {
+            info!(
+                "  {}: stranded {:.2}% of the times drawn, win rate {:.2}% when drawn vs {:.2}% when not",
+                card.name,
+                card.stranded_rate() * 100.0,
+                card.win_rate_when_drawn() * 100.0,
+                card.win_rate_when_not_drawn() * 100.0
+            );
+        }
+    }
+    card_stats
 }
@@ -0,0 +1,192 @@
+use std::fmt;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::matchups::{MatchupRecord, Z_95};
+use crate::{
+    players::{create_players, PlayerCode},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// Results of `compare_decks`. Scores count a win as 1 and a tie as half.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeckComparison {
+    pub record_a: MatchupRecord,
+    pub record_b: MatchupRecord,
+    /// Average of deck A's score minus deck B's, over the paired games.
+    pub mean_difference: f64,
+    /// 95% confidence interval of `mean_difference`, from the spread of the paired
+    /// differences.
+    pub ci_low: f64,
+    pub ci_high: f64,
+    /// Games that deck A won and deck B didn't, and the other way around.
+    pub only_a_won: u32,
+    pub only_b_won: u32,
+}
+
+impl DeckComparison {
+    /// Whether the decks are different at 95% confidence.
+    pub fn is_significant(&self) -> bool {
+        self.ci_low > 0.0 || self.ci_high < 0.0
+    }
+}
+
+impl fmt::Display for DeckComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Deck A: {:.2}% win rate, Deck B: {:.2}% win rate over {} paired games",
+            self.record_a.win_rate() * 100.0,
+            self.record_b.win_rate() * 100.0,
+            self.record_a.games()
+        )?;
+        writeln!(
+            f,
+            "Difference (A - B): {:+.2}% (95% CI {:+.2}% to {:+.2}%){}",
+            self.mean_difference * 100.0,
+            self.ci_low * 100.0,
+            self.ci_high * 100.0,
+            if self.is_significant() {
+                ", significant"
+            } else {
+                ""
+            }
+        )?;
+        write!(
+            f,
+            "Games only A won: {}, only B won: {}",
+            self.only_a_won, self.only_b_won
+        )
+    }
+}
+
+/// Plays `games_per_opponent` pairs of games of each deck against each opponent, with
+/// `players` for (deck A or B, opponent). In a pair, both games use the same seed, the
+/// opponent draws the same cards and the same player goes first, so that the difference
+/// between the decks' results comes from the decks rather than from luck.
+pub fn compare_decks(
+    deck_a: &Deck,
+    deck_b: &Deck,
+    opponents: &[Deck],
+    players: Vec<PlayerCode>,
+    games_per_opponent: u32,
+    seed: u64,
+) -> DeckComparison {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut record_a = MatchupRecord::default();
+    let mut record_b = MatchupRecord::default();
+    let mut differences = vec![];
+    let mut only_a_won = 0;
+    let mut only_b_won = 0;
+    for opponent in opponents {
+        for _ in 0..games_per_opponent {
+            let game_seed = rng.gen();
+            let current_player = rng.gen_range(0..2);
+            let mut game_a = Game::new(
+                create_players(deck_a.clone(), opponent.clone(), players.clone()),
+                game_seed,
+            );
+            let mut game_b = Game::new(
+                create_players(deck_b.clone(), opponent.clone(), players.clone()),
+                game_seed,
+            );
+            let mut state_a = game_a.get_state_clone();
+            let mut state_b = game_b.get_state_clone();
+            state_a.current_player = current_player;
+            state_b.current_player = current_player;
+            // The shuffles of A and B consume randomness differently, so the opponent's
+            // cards are copied over rather than left to the seed
+            state_b.decks[1] = state_a.decks[1].clone();
+            state_b.hands[1] = state_a.hands[1].clone();
+            game_a.set_state(state_a);
+            game_b.set_state(state_b);
+
+            let score_a = play(&mut game_a, &mut record_a);
+            let score_b = play(&mut game_b, &mut record_b);
+            if score_a == 1.0 && score_b < 1.0 {
+                only_a_won += 1;
+            }
+            if score_b == 1.0 && score_a < 1.0 {
+                only_b_won += 1;
+            }
+            differences.push(score_a - score_b);
+        }
+    }
+
+    let n = differences.len() as f64;
+    let mean_difference = if n > 0.0 {
+        differences.iter().sum::<f64>() / n
+    } else {
+        0.0
+    };
+    let margin = if n > 1.0 {
+        let variance = differences
+            .iter()
+            .map(|x| (x - mean_difference).powi(2))
+            .sum::<f64>()
+            / (n - 1.0);
+        Z_95 * (variance / n).sqrt()
+    } else {
+        f64::INFINITY
+    };
+    DeckComparison {
+        record_a,
+        record_b,
+        mean_difference,
+        ci_low: (mean_difference - margin).max(-1.0),
+        ci_high: (mean_difference + margin).min(1.0),
+        only_a_won,
+        only_b_won,
+    }
+}
+
+// Plays the game, records it and returns the score of player 0
+fn play(game: &mut Game, record: &mut MatchupRecord) -> f64 {
+    match game.play() {
+        Some(GameOutcome::Win(0)) => {
+            record.wins += 1;
+            1.0
+        }
+        Some(GameOutcome::Win(_)) => {
+            record.losses += 1;
+            0.0
+        }
+        Some(GameOutcome::Tie) | None => {
+            record.ties += 1;
+            0.5
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_deck_has_no_difference() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let opponent = Deck::from_file("example_decks/weezing-arbok.txt").unwrap();
+        let players = vec![PlayerCode::R, PlayerCode::R];
+        let comparison = compare_decks(&deck, &deck, &[opponent], players, 10, 0);
+        assert_eq!(comparison.record_a, comparison.record_b);
+        assert_eq!(comparison.record_a.games(), 10);
+        assert_eq!(comparison.mean_difference, 0.0);
+        assert_eq!((comparison.only_a_won, comparison.only_b_won), (0, 0));
+        assert!(!comparison.is_significant());
+    }
+
+    #[test]
+    fn test_compare_decks() {
+        let deck_a = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let deck_b = Deck::from_file("example_decks/mewtwoex.txt").unwrap();
+        let opponent = Deck::from_file("example_decks/weezing-arbok.txt").unwrap();
+        let players = vec![PlayerCode::R, PlayerCode::R];
+        let comparison = compare_decks(&deck_a, &deck_b, &[opponent], players, 20, 1);
+        let expected = comparison.record_a.win_rate() - comparison.record_b.win_rate();
+        assert!((comparison.mean_difference - expected).abs() < 1e-9);
+        assert!(comparison.ci_low <= comparison.mean_difference);
+        assert!(comparison.mean_difference <= comparison.ci_high);
+        assert!(comparison.to_string().contains("20 paired games"));
+    }
+}
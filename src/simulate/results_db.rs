@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use rusqlite::{params, Connection, Row};
+
+use crate::{state::GameOutcome, State};
+
+/// Result of one simulated game, as stored in a `ResultsDb`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    /// The decks as given to the simulation (e.g. their file paths).
+    pub deck_a: String,
+    pub deck_b: String,
+    pub seed: u64,
+    /// 0 for Deck A, 1 for Deck B, `None` for ties and games that ran out of turns.
+    pub winner: Option<usize>,
+    pub turns: u8,
+    pub points: [u8; 2],
+}
+
+impl GameRecord {
+    /// The record of a game that ended in `state`.
+    pub fn new(deck_a: &str, deck_b: &str, seed: u64, state: &State) -> Self {
+        let winner = match state.winner {
+            Some(GameOutcome::Win(player)) => Some(player),
+            Some(GameOutcome::Tie) | None => None,
+        };
+        Self {
+            deck_a: deck_a.to_string(),
+            deck_b: deck_b.to_string(),
+            seed,
+            winner,
+            turns: state.turn_count,
+            points: state.points,
+        }
+    }
+}
+
+/// Results of simulated games kept in a SQLite file, so that they can be queried again
+/// without replaying the games (see `RunControl::store_results`). Games are appended, so
+/// several runs can share a file.
+pub struct ResultsDb {
+    connection: Connection,
+}
+
+impl ResultsDb {
+    /// Opens the file, creating it (and its table) if needed.
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// A database that only lives as long as this value, e.g. for tests.
+    pub fn in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> rusqlite::Result<Self> {
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS games (
+                id INTEGER PRIMARY KEY,
+                deck_a TEXT NOT NULL,
+                deck_b TEXT NOT NULL,
+                seed INTEGER NOT NULL,
+                winner INTEGER,
+                turns INTEGER NOT NULL,
+                points_a INTEGER NOT NULL,
+                points_b INTEGER NOT NULL
+            )",
+            [],
+        )?;
+        Ok(Self { connection })
+    }
+
+    pub fn insert(&self, record: &GameRecord) -> rusqlite::Result<()> {
+        self.connection.execute(
+            "INSERT INTO games (deck_a, deck_b, seed, winner, turns, points_a, points_b)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                record.deck_a,
+                record.deck_b,
+                // SQLite integers are signed, seeds keep their bits
+                record.seed as i64,
+                record.winner.map(|x| x as i64),
+                record.turns,
+                record.points[0],
+                record.points[1],
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Every game stored, in the order they were played.
+    pub fn games(&self) -> rusqlite::Result<Vec<GameRecord>> {
+        let mut statement = self.connection.prepare(
+            "SELECT deck_a, deck_b, seed, winner, turns, points_a, points_b
+             FROM games ORDER BY id",
+        )?;
+        let records = statement.query_map([], to_record)?;
+        records.collect()
+    }
+
+    /// The games between the two decks, in this order, in the order they were played.
+    pub fn matchup(&self, deck_a: &str, deck_b: &str) -> rusqlite::Result<Vec<GameRecord>> {
+        let mut statement = self.connection.prepare(
+            "SELECT deck_a, deck_b, seed, winner, turns, points_a, points_b
+             FROM games WHERE deck_a = ?1 AND deck_b = ?2 ORDER BY id",
+        )?;
+        let records = statement.query_map(params![deck_a, deck_b], to_record)?;
+        records.collect()
+    }
+
+    /// Games won by `deck` out of the games it played, on either side. `None` if it
+    /// played none.
+    pub fn win_rate(&self, deck: &str) -> rusqlite::Result<Option<f64>> {
+        self.connection.query_row(
+            "SELECT
+                TOTAL(deck_a = ?1 AND winner = 0) + TOTAL(deck_b = ?1 AND winner = 1),
+                COUNT(*)
+             FROM games WHERE deck_a = ?1 OR deck_b = ?1",
+            params![deck],
+            |row| {
+                let wins: f64 = row.get(0)?;
+                let games: u64 = row.get(1)?;
+                Ok((games > 0).then(|| wins / games as f64))
+            },
+        )
+    }
+}
+
+fn to_record(row: &Row) -> rusqlite::Result<GameRecord> {
+    let seed: i64 = row.get(2)?;
+    let winner: Option<i64> = row.get(3)?;
+    Ok(GameRecord {
+        deck_a: row.get(0)?,
+        deck_b: row.get(1)?,
+        seed: seed as u64,
+        winner: winner.map(|x| x as usize),
+        turns: row.get(4)?,
+        points: [row.get(5)?, row.get(6)?],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{players::PlayerCode, progress::RunControl, simulate::simulate_with};
+
+    fn record(deck_a: &str, deck_b: &str, winner: Option<usize>) -> GameRecord {
+        GameRecord {
+            deck_a: deck_a.to_string(),
+            deck_b: deck_b.to_string(),
+            seed: u64::MAX,
+            winner,
+            turns: 12,
+            points: [3, 1],
+        }
+    }
+
+    #[test]
+    fn test_query_stored_games() {
+        let db = ResultsDb::in_memory().unwrap();
+        assert_eq!(db.win_rate("grass").unwrap(), None);
+        let games = [
+            record("grass", "fire", Some(0)),
+            record("fire", "grass", Some(0)),
+            record("grass", "fire", None),
+            record("grass", "water", Some(0)),
+        ];
+        for game in &games {
+            db.insert(game).unwrap();
+        }
+
+        assert_eq!(db.games().unwrap(), games);
+        assert_eq!(
+            db.matchup("grass", "fire").unwrap(),
+            vec![games[0].clone(), games[2].clone()]
+        );
+        assert_eq!(db.win_rate("grass").unwrap(), Some(0.5));
+        assert_eq!(db.win_rate("fire").unwrap(), Some(1.0 / 3.0));
+    }
+
+    #[test]
+    fn test_simulate_stores_every_game() {
+        let path = std::env::temp_dir().join("deckgym_test_results.sqlite");
+        let _ = std::fs::remove_file(&path);
+        let db = ResultsDb::open(&path).unwrap();
+        let deck_a = "example_decks/venusaur-exeggutor.txt";
+        let deck_b = "example_decks/weezing-arbok.txt";
+        simulate_with(
+            deck_a,
+            deck_b,
+            Some(vec![PlayerCode::R, PlayerCode::R]),
+            3,
+            Some(5),
+            None,
+            &mut RunControl::new().store_results(&db),
+        );
+        drop(db);
+
+        // Survives reopening the file
+        let games = ResultsDb::open(&path)
+            .unwrap()
+            .matchup(deck_a, deck_b)
+            .unwrap();
+        assert_eq!(games.len(), 3);
+        assert!(games.iter().all(|x| x.seed == 5));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
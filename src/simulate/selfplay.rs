@@ -0,0 +1,152 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    sync::mpsc,
+    thread,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    actions::ActionIndex,
+    generate_possible_actions,
+    players::{create_players, PlayerCode},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// One decision of a self-play game, written as a JSON line. Games are played in parallel,
+/// so they may appear in any order, but the samples of a game are contiguous and in order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelfPlaySample {
+    /// Index of the game, from 0 (played with seed `seed + game`).
+    pub game: u32,
+    /// Index of the decision in the game, from 0.
+    pub ply: u32,
+    /// The player deciding.
+    pub player: usize,
+    /// `State::encode_observation(player)` before deciding.
+    pub observation: Vec<f32>,
+    /// `ActionIndex` of every legal action.
+    pub legal_actions: Vec<usize>,
+    /// `ActionIndex` of the chosen action.
+    pub action: usize,
+    /// Final result for `player`: 1 for a win, -1 for a loss, 0 for a tie.
+    pub outcome: f32,
+}
+
+/// Plays `num_games` games between the decks and writes every decision to `out_path`
+/// (see `SelfPlaySample` for the format), spread across all available cores.
+/// Returns the number of samples written.
+pub fn generate_selfplay_data(
+    deck_a: &Deck,
+    deck_b: &Deck,
+    players: Vec<PlayerCode>,
+    num_games: u32,
+    seed: u64,
+    out_path: &str,
+) -> io::Result<u64> {
+    let mut writer = BufWriter::new(File::create(out_path)?);
+    let num_workers = thread::available_parallelism().map_or(1, |x| x.get()) as u32;
+    let (sender, receiver) = mpsc::channel();
+    let mut num_samples = 0;
+    thread::scope(|scope| {
+        for worker in 0..num_workers.min(num_games) {
+            let sender = sender.clone();
+            let players = &players;
+            scope.spawn(move || {
+                for game in (worker..num_games).step_by(num_workers as usize) {
+                    let samples = play_selfplay_game(deck_a, deck_b, players, game, seed);
+                    // The writer only hangs up on errors, which are reported below
+                    if sender.send(samples).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        for samples in receiver {
+            for sample in &samples {
+                serde_json::to_writer(&mut writer, sample)?;
+                writer.write_all(b"\n")?;
+            }
+            num_samples += samples.len() as u64;
+        }
+        writer.flush()
+    })?;
+    Ok(num_samples)
+}
+
+fn play_selfplay_game(
+    deck_a: &Deck,
+    deck_b: &Deck,
+    players: &[PlayerCode],
+    game_index: u32,
+    seed: u64,
+) -> Vec<SelfPlaySample> {
+    let players = create_players(deck_a.clone(), deck_b.clone(), players.to_vec());
+    let mut game = Game::new(players, seed.wrapping_add(game_index as u64));
+    let mut samples = vec![];
+    while !game.get_state().is_game_over() {
+        let (actor, actions) = generate_possible_actions(game.get_state());
+        let observation = game.get_state().encode_observation(actor);
+        let action = game.play_tick();
+        samples.push(SelfPlaySample {
+            game: game_index,
+            ply: samples.len() as u32,
+            player: actor,
+            observation,
+            legal_actions: actions.iter().map(ActionIndex::encode).collect(),
+            action: ActionIndex::encode(&action),
+            outcome: 0.0,
+        });
+    }
+    if let Some(GameOutcome::Win(winner)) = game.get_state().winner {
+        for sample in &mut samples {
+            sample.outcome = if sample.player == winner { 1.0 } else { -1.0 };
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader},
+    };
+
+    use super::*;
+    use crate::observation::OBSERVATION_SIZE;
+
+    #[test]
+    fn test_generate_selfplay_data() {
+        let deck = Deck::from_file("example_decks/venusaur-exeggutor.txt").unwrap();
+        let path = env::temp_dir().join("deckgym_test_selfplay.jsonl");
+        let path = path.to_str().unwrap();
+        let players = vec![PlayerCode::R, PlayerCode::R];
+        let num_samples = generate_selfplay_data(&deck, &deck, players, 3, 0, path).unwrap();
+
+        let samples: Vec<SelfPlaySample> = BufReader::new(File::open(path).unwrap())
+            .lines()
+            .map(|x| serde_json::from_str(&x.unwrap()).unwrap())
+            .collect();
+        fs::remove_file(path).unwrap();
+        assert_eq!(samples.len() as u64, num_samples);
+        let mut games: Vec<u32> = samples.iter().map(|x| x.game).collect();
+        games.dedup();
+        games.sort();
+        assert_eq!(games, vec![0, 1, 2]);
+        for sample in &samples {
+            assert_eq!(sample.observation.len(), OBSERVATION_SIZE);
+            assert!(sample.legal_actions.contains(&sample.action));
+        }
+
+        // Samples match a replay of the same game
+        let replayed = play_selfplay_game(&deck, &deck, &[PlayerCode::R, PlayerCode::R], 1, 0);
+        let game_1: Vec<_> = samples.into_iter().filter(|x| x.game == 1).collect();
+        assert_eq!(game_1, replayed);
+        assert!(replayed.windows(2).all(|x| x[0].ply + 1 == x[1].ply));
+    }
+}
@@ -0,0 +1,100 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::matchups::{MatchupRecord, Z_95};
+use crate::{players::RolloutPolicy, state::GameOutcome, Game, State};
+
+/// How likely player 0 is to win from a state, with ties counting as half a win.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WinProbability {
+    pub mean: f64,
+    /// Wilson score interval of `mean` at 95% confidence.
+    pub ci_low: f64,
+    pub ci_high: f64,
+    pub record: MatchupRecord,
+}
+
+/// Estimates the chances of player 0 by playing `n_rollouts` games to the end from
+/// `state`, with the players created by each policy. Works at any point of a game (a
+/// finished one just reports its result).
+///
+/// The order of both decks is unknown at the table, so each rollout reshuffles them.
+pub fn estimate_win_probability(
+    state: &State,
+    player_a_policy: &RolloutPolicy,
+    player_b_policy: &RolloutPolicy,
+    n_rollouts: u32,
+    seed: u64,
+) -> WinProbability {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut record = MatchupRecord::default();
+    for _ in 0..n_rollouts {
+        let mut state = state.clone();
+        for player in 0..2 {
+            state.deck_mut(player).shuffle(false, &mut rng);
+        }
+        let players = vec![player_a_policy(), player_b_policy()];
+        let mut game = Game::from_state(state, players, rng.gen());
+        match game.play() {
+            Some(GameOutcome::Win(0)) => record.wins += 1,
+            Some(GameOutcome::Win(_)) => record.losses += 1,
+            Some(GameOutcome::Tie) | None => record.ties += 1,
+        }
+    }
+    let (ci_low, ci_high) = record.wilson_interval(Z_95);
+    WinProbability {
+        mean: record.win_rate(),
+        ci_low,
+        ci_high,
+        record,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        players::{EndTurnPlayer, Player, RandomPlayer},
+        test_helpers::load_test_decks,
+        Deck,
+    };
+
+    fn random_policy() -> RolloutPolicy {
+        Box::new(|| {
+            Box::new(RandomPlayer {
+                deck: Deck::default(),
+            }) as Box<dyn Player>
+        })
+    }
+
+    #[test]
+    fn test_estimate_win_probability_from_game_start() {
+        let (deck_a, deck_b) = load_test_decks();
+        let state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        let estimate = estimate_win_probability(&state, &random_policy(), &random_policy(), 20, 0);
+        assert_eq!(estimate.record.games(), 20);
+        assert!(estimate.ci_low <= estimate.mean && estimate.mean <= estimate.ci_high);
+        assert_eq!(
+            estimate,
+            estimate_win_probability(&state, &random_policy(), &random_policy(), 20, 0)
+        );
+
+        // A player that never does anything should lose to one that plays
+        let passive: RolloutPolicy = Box::new(|| {
+            Box::new(EndTurnPlayer {
+                deck: Deck::default(),
+            })
+        });
+        let estimate = estimate_win_probability(&state, &random_policy(), &passive, 20, 0);
+        assert!(estimate.mean > 0.5);
+    }
+
+    #[test]
+    fn test_finished_games_report_their_result() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.winner = Some(GameOutcome::Win(1));
+        let estimate = estimate_win_probability(&state, &random_policy(), &random_policy(), 5, 0);
+        assert_eq!(estimate.mean, 0.0);
+        assert_eq!(estimate.record.losses, 5);
+    }
+}
@@ -1,20 +1,52 @@
 use log::{debug, trace};
 use rand::{seq::SliceRandom, Rng};
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::hash::Hash;
+use std::sync::Arc;
 
 use crate::{
-    actions::SimpleAction,
+    ability_ids::{AbilityId, UsageFrequency},
+    actions::{CheckupEvent, CoinFlips, SimpleAction},
     deck::Deck,
+    game_rules::GameRules,
+    hooks::fire_turn_start,
     types::{Card, EnergyType, PlayedCard},
 };
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
 pub enum GameOutcome {
     Win(usize),
     Tie,
 }
 
+/// Most Pokémon a bench can hold under any rules (see `GameRules::bench_size`). Pocket's
+/// bench holds 3, the physical game's 5.
+pub const MAX_BENCH_SIZE: usize = 5;
+/// Slots of each player in `State::in_play_pokemon`: the Active Spot, then the bench.
+pub const MAX_IN_PLAY: usize = MAX_BENCH_SIZE + 1;
+/// Cards each player draws before setting up.
+pub const OPENING_HAND_SIZE: usize = 5;
+
+/// Where a game is at, see `State::phase`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default, Serialize)]
+pub enum TurnPhase {
+    /// Players are placing their first Pokémon (turn 0).
+    #[default]
+    Setup,
+    /// The player to move draws the card of their turn.
+    Draw,
+    /// The player to move plays cards, attaches, retreats, attacks or ends the turn,
+    /// including the choices that follow (e.g. of a trainer or an ability).
+    Main,
+    /// An attack is being resolved: choosing its targets, promoting after its Knock Outs or
+    /// ending the turn.
+    Attack,
+    /// Knock Outs of the Pokémon Checkup between turns are being resolved.
+    Checkup,
+    GameOver,
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
 pub struct State {
     // Turn State
@@ -24,21 +56,38 @@ pub struct State {
     // Player that needs to select from playable actions. Might not be aligned
     // with coin toss and the parity, see Sabrina.
     pub current_player: usize,
+    // Maintained after every action (see update_phase), so that bots and UIs don't have to
+    // infer it from the move generation stack.
+    pub phase: TurnPhase,
     pub move_generation_stack: Vec<(usize, Vec<SimpleAction>)>,
+    // Coins flipped while resolving the last applied action (e.g. to know how Misty went).
+    pub coin_flips: Vec<CoinFlips>,
+    // What the Pokémon Checkup did, if the last applied action ended a turn.
+    pub checkup_events: Vec<CheckupEvent>,
 
     // Core state
     pub(crate) current_energy: Option<EnergyType>,
     pub hands: [Vec<Card>; 2],
-    pub decks: [Deck; 2],
+    // Shared between clones until modified (see deck_mut), as search clones State a lot.
+    pub decks: [Arc<Deck>; 2],
     pub discard_piles: [Vec<Card>; 2],
-    // 0 index is the active pokemon, 1..4 are the bench
-    pub in_play_pokemon: [[Option<PlayedCard>; 4]; 2],
+    // How many Energy of each type each player has discarded (e.g. paying retreat costs or
+    // when their Pokémon got Knocked Out), since Energy are not cards.
+    pub discarded_energy: [BTreeMap<EnergyType, u32>; 2],
+    // 0 index is the active pokemon, the rest is the bench (up to rules.bench_size)
+    pub in_play_pokemon: [[Option<PlayedCard>; MAX_IN_PLAY]; 2],
 
     // Turn Flags (remember to reset these in reset_turn_states)
     pub has_played_support: bool,
     pub has_retreated: bool,
     // Maps turn to a vector of effects (cards) for that turn. Using BTreeMap to keep State hashable.
-    turn_effects: BTreeMap<u8, Vec<Card>>,
+    // Effects on a single Pokémon are kept on it instead (see PlayedCard::effects).
+    pub(crate) turn_effects: Arc<BTreeMap<u8, Vec<Card>>>,
+    // Order-independent checksum of each decklist, to check that cards are conserved (see
+    // check_invariants). Only known for states of actual games, not hand-built ones.
+    pub(crate) decklist_checksums: Option<[u64; 2]>,
+
+    pub rules: GameRules,
 }
 
 impl State {
@@ -48,15 +97,21 @@ impl State {
             points: [0, 0],
             turn_count: 0,
             current_player: 0,
+            phase: TurnPhase::Setup,
             move_generation_stack: Vec::new(),
+            coin_flips: Vec::new(),
+            checkup_events: Vec::new(),
             current_energy: None,
             hands: [Vec::new(), Vec::new()],
-            decks: [deck_a.clone(), deck_b.clone()],
+            decks: [Arc::new(deck_a.clone()), Arc::new(deck_b.clone())],
             discard_piles: [Vec::new(), Vec::new()],
-            in_play_pokemon: [[None, None, None, None], [None, None, None, None]],
+            discarded_energy: [BTreeMap::new(), BTreeMap::new()],
+            in_play_pokemon: Default::default(),
             has_played_support: false,
             has_retreated: false,
-            turn_effects: BTreeMap::new(),
+            turn_effects: Arc::new(BTreeMap::new()),
+            decklist_checksums: None,
+            rules: GameRules::default(),
         }
     }
 
@@ -73,15 +128,37 @@ impl State {
         )
     }
 
+    /// Like `debug_string`, but only shows the size of the opponent's hand.
+    pub fn debug_string_for(&self, player: usize) -> String {
+        let hand = |side: usize| {
+            if side == player {
+                format!("{:?}", to_canonical_names(self.hands[side].as_slice()))
+            } else {
+                format!("{} cards", self.hands[side].len())
+            }
+        };
+        format!(
+            "P1 Hand:\t{}\n\
+            P1 InPlay:\t{:?}\n\
+            P2 InPlay:\t{:?}\n\
+            P2 Hand:\t{}",
+            hand(0),
+            format_cards(&self.in_play_pokemon[0]),
+            format_cards(&self.in_play_pokemon[1]),
+            hand(1)
+        )
+    }
+
     pub fn initialize(deck_a: &Deck, deck_b: &Deck, rng: &mut impl Rng) -> Self {
         let mut state = Self::new(deck_a, deck_b);
+        state.decklist_checksums = Some([0, 1].map(|player| state.owned_cards_checksum(player)));
 
         // Shuffle the decks before starting the game and have players
         //  draw 5 cards each to start
-        for deck in &mut state.decks {
-            deck.shuffle(true, rng);
+        for player in 0..2 {
+            state.deck_mut(player).shuffle(true, rng);
         }
-        for _ in 0..5 {
+        for _ in 0..OPENING_HAND_SIZE {
             state.maybe_draw_card(0);
             state.maybe_draw_card(1);
         }
@@ -91,6 +168,11 @@ impl State {
         state
     }
 
+    /// Mutable access to a player's deck, copying it first if other clones share it.
+    pub fn deck_mut(&mut self, player: usize) -> &mut Deck {
+        Arc::make_mut(&mut self.decks[player])
+    }
+
     pub fn get_remaining_hp(&self, player: usize, index: usize) -> u32 {
         self.in_play_pokemon[player][index]
             .as_ref()
@@ -111,8 +193,57 @@ impl State {
         self.discard_piles[current_player].push(card.clone());
     }
 
+    /// Discards 1 Energy of this type (if attached) from one of the player's Pokémon.
+    pub(crate) fn discard_energy(&mut self, player: usize, in_play_idx: usize, energy: EnergyType) {
+        let pokemon = self.in_play_pokemon[player][in_play_idx]
+            .as_mut()
+            .expect("Pokemon should be there if discarding its energy");
+        if pokemon.attached_energy.contains(&energy) {
+            pokemon.discard_energy(&energy);
+            self.record_discarded_energy(player, &[energy]);
+        }
+    }
+
+    /// Discards every Energy attached to one of the player's Pokémon.
+    pub(crate) fn discard_all_energy(&mut self, player: usize, in_play_idx: usize) {
+        let pokemon = self.in_play_pokemon[player][in_play_idx]
+            .as_mut()
+            .expect("Pokemon should be there if discarding its energy");
+        let discarded = std::mem::take(&mut pokemon.attached_energy);
+        self.record_discarded_energy(player, &discarded);
+    }
+
+    pub(crate) fn record_discarded_energy(&mut self, player: usize, energies: &[EnergyType]) {
+        for energy in energies {
+            *self.discarded_energy[player].entry(*energy).or_insert(0) += 1;
+        }
+    }
+
+    /// Takes up to `amount` Energy of this type back out of the player's discarded Energy,
+    /// returning how many there were.
+    pub(crate) fn take_discarded_energy(
+        &mut self,
+        player: usize,
+        energy: EnergyType,
+        amount: u32,
+    ) -> u32 {
+        let Some(count) = self.discarded_energy[player].get_mut(&energy) else {
+            return 0;
+        };
+        let taken = amount.min(*count);
+        *count -= taken;
+        if *count == 0 {
+            self.discarded_energy[player].remove(&energy);
+        }
+        taken
+    }
+
     pub(crate) fn maybe_draw_card(&mut self, player: usize) {
-        if let Some(card) = self.decks[player].draw() {
+        if !self.rules.can_draw(self.hands[player].len()) {
+            debug!("Player {} cannot draw a card, hand is full", player + 1);
+            return;
+        }
+        if let Some(card) = self.deck_mut(player).draw() {
             self.hands[player].push(card.clone());
             debug!(
                 "Player {} drew: {:?}, now hand is: {:?} and deck has {} cards",
@@ -126,26 +257,32 @@ impl State {
         }
     }
 
-    pub(crate) fn generate_energy(&mut self) {
+    pub(crate) fn generate_energy(&mut self, rng: &mut impl Rng) {
         if self.decks[self.current_player].energy_types.len() == 1 {
             self.current_energy = Some(self.decks[self.current_player].energy_types[0]);
         }
 
         let deck_energies = &self.decks[self.current_player].energy_types;
-        let mut rng = rand::thread_rng();
         let generated = deck_energies
-            .choose(&mut rng)
+            .choose(rng)
             .expect("Decks should have at least 1 energy");
         self.current_energy = Some(*generated);
     }
 
     pub(crate) fn reset_turn_states(&mut self) {
-        // Reset .played_this_turn and .ability_used for all in-play pokemon
+        // Reset .played_this_turn and .ability_used (unless once per game) for all in-play
+        // pokemon, and drop their effects that ended
+        let turn_count = self.turn_count;
         for i in 0..2 {
             self.in_play_pokemon[i].iter_mut().for_each(|x| {
                 if let Some(pokemon) = x {
                     pokemon.played_this_turn = false;
-                    pokemon.ability_used = false;
+                    let usage =
+                        AbilityId::from_pokemon_id(&pokemon.get_id()).and_then(|x| x.usage());
+                    if usage.is_none_or(|x| x.frequency == UsageFrequency::OncePerTurn) {
+                        pokemon.ability_used = false;
+                    }
+                    pokemon.effects.retain(|x| x.until_turn >= turn_count);
                 }
             });
         }
@@ -164,7 +301,7 @@ impl State {
     pub(crate) fn add_turn_effect(&mut self, card: Card, duration: u8) {
         for turn_offset in 0..(duration + 1) {
             let target_turn = self.turn_count + turn_offset;
-            self.turn_effects
+            Arc::make_mut(&mut self.turn_effects)
                 .entry(target_turn)
                 .or_default()
                 .push(card.clone());
@@ -178,6 +315,28 @@ impl State {
         }
     }
 
+    /// Like `add_turn_effect`, but only for the player's Pokémon at `in_play_idx` (see
+    /// `PlayedCard::effects`).
+    pub(crate) fn add_pokemon_effect(
+        &mut self,
+        player: usize,
+        in_play_idx: usize,
+        card: Card,
+        duration: u8,
+    ) {
+        let until_turn = self.turn_count + duration;
+        trace!(
+            "Adding effect {:?} on {:?} until turn {}",
+            canonical_name(&card),
+            self.in_play_pokemon[player][in_play_idx],
+            until_turn
+        );
+        self.in_play_pokemon[player][in_play_idx]
+            .as_mut()
+            .expect("Pokemon should be there to add an effect on it")
+            .add_effect(card, until_turn);
+    }
+
     /// Retrieves all effects scheduled for the current turn
     pub(crate) fn get_current_turn_effects(&self) -> Vec<Card> {
         self.turn_effects
@@ -224,7 +383,7 @@ impl State {
     }
 
     // This function should be called only from turn 1 onwards
-    pub(crate) fn advance_turn(&mut self) {
+    pub(crate) fn advance_turn(&mut self, rng: &mut impl Rng) {
         debug!(
             "Ending turn moving from player {} to player {}",
             self.current_player,
@@ -234,11 +393,41 @@ impl State {
         self.turn_count += 1;
         self.reset_turn_states();
         self.queue_draw_action(self.current_player);
-        self.generate_energy();
+        self.generate_energy(rng);
+        fire_turn_start(self, self.current_player);
+    }
+
+    /// Moves the phase along after `action` was applied.
+    pub(crate) fn update_phase(&mut self, action: &SimpleAction) {
+        let resolving = !self.move_generation_stack.is_empty();
+        let draw_next = self
+            .move_generation_stack
+            .last()
+            .is_some_and(|(actor, actions)| {
+                *actor == self.current_player && actions[..] == [SimpleAction::DrawCard]
+            });
+        let new_turn = matches!(action, SimpleAction::EndTurn | SimpleAction::Setup { .. })
+            || self.phase == TurnPhase::Checkup;
+        self.phase = if self.is_game_over() {
+            TurnPhase::GameOver
+        } else if self.turn_count == 0 {
+            TurnPhase::Setup
+        } else if new_turn && draw_next {
+            TurnPhase::Draw
+        } else if (new_turn || self.phase == TurnPhase::Draw) && resolving {
+            // Checkup Knock Outs are queued under the draw of the next turn
+            TurnPhase::Checkup
+        } else if (matches!(action, SimpleAction::Attack(_)) || self.phase == TurnPhase::Attack)
+            && resolving
+        {
+            TurnPhase::Attack
+        } else {
+            TurnPhase::Main
+        };
     }
 
     pub fn is_game_over(&self) -> bool {
-        self.winner.is_some() || self.turn_count >= 100
+        self.winner.is_some() || self.turn_count >= self.rules.max_turns
     }
 
     pub(crate) fn num_in_play_of_type(&self, player: usize, energy: EnergyType) -> usize {
@@ -278,11 +467,15 @@ fn to_canonical_names(cards: &[Card]) -> Vec<&String> {
 #[cfg(test)]
 mod tests {
     use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
         deck::is_basic,
         hooks::to_playable_card,
-        test_helpers::load_test_decks,
-        types::{TrainerType, TrainerCard, PokemonCard, Card, EnergyType},
+        sets::SetId,
+        test_helpers::{load_test_decks, to_pokemon_card, to_trainer_card, ScenarioBuilder},
+        types::{TrainerType, TrainerCard, PokemonCard, EnergyType},
     };
+    use rand::{rngs::StdRng, SeedableRng};
 
     use super::*;
 
@@ -300,6 +493,56 @@ mod tests {
         assert_eq!(state.hands[0].len(), 1);
     }
 
+    #[test]
+    fn test_discarded_energy_is_counted_by_type() {
+        let mut state = State::default();
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        state.in_play_pokemon[1][2] = Some(bulbasaur);
+        state.in_play_pokemon[1][2]
+            .as_mut()
+            .unwrap()
+            .attached_energy = vec![EnergyType::Grass, EnergyType::Water, EnergyType::Grass];
+
+        state.discard_energy(1, 2, EnergyType::Water);
+        state.discard_energy(1, 2, EnergyType::Fire); // Not attached, nothing happens
+        assert_eq!(
+            state.discarded_energy[1],
+            BTreeMap::from([(EnergyType::Water, 1)])
+        );
+
+        state.discard_all_energy(1, 2);
+        assert!(state.in_play_pokemon[1][2]
+            .as_ref()
+            .unwrap()
+            .attached_energy
+            .is_empty());
+        assert_eq!(
+            state.discarded_energy[1],
+            BTreeMap::from([(EnergyType::Grass, 2), (EnergyType::Water, 1)])
+        );
+        assert!(state.discarded_energy[0].is_empty());
+    }
+
+    #[test]
+    fn test_clones_share_decks_until_modified() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let clone = state.clone();
+        assert!(Arc::ptr_eq(&state.decks[0], &clone.decks[0]));
+
+        state.maybe_draw_card(0);
+        assert!(!Arc::ptr_eq(&state.decks[0], &clone.decks[0]));
+        assert!(Arc::ptr_eq(&state.decks[1], &clone.decks[1]));
+        assert_eq!(state.decks[0].cards.len(), 19);
+        assert_eq!(clone.decks[0].cards.len(), 20);
+
+        // Sharing is invisible to equality and hashing
+        let mut unshared = clone.clone();
+        unshared.deck_mut(0);
+        assert!(!Arc::ptr_eq(&unshared.decks[0], &clone.decks[0]));
+        assert_eq!(unshared, clone);
+    }
+
     #[test]
     fn test_players_start_with_five_cards_one_of_which_is_basic() {
         let (deck_a, deck_b) = load_test_decks();
@@ -319,7 +562,7 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // Empty the deck
-        state.decks[0].cards.clear();
+        state.deck_mut(0).cards.clear();
         
         // Drawing from empty deck shouldn't panic
         state.maybe_draw_card(0);
@@ -335,14 +578,14 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // Create a card that's not in hand
-        let fake_card = Card::Trainer(TrainerCard {
+        let fake_card = to_trainer_card(TrainerCard {
             id: "fake1".to_string(),
             numeric_id: 0,
             name: "Fake Card".to_string(),
             trainer_card_type: TrainerType::Item,
             effect: "Test effect".to_string(),
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         // This should panic
@@ -391,7 +634,7 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // This should panic
-        state.generate_energy();
+        state.generate_energy(&mut StdRng::seed_from_u64(0));
     }
 
     #[test]
@@ -400,11 +643,29 @@ mod tests {
         deck_a.energy_types = vec![EnergyType::Grass];
         let mut state = State::new(&deck_a, &deck_b);
         
-        state.generate_energy();
+        state.generate_energy(&mut StdRng::seed_from_u64(0));
         
         assert_eq!(state.current_energy, Some(EnergyType::Grass));
     }
 
+    #[test]
+    fn test_generate_energy_follows_rng() {
+        let (mut deck_a, deck_b) = load_test_decks();
+        deck_a.energy_types = vec![EnergyType::Grass, EnergyType::Fire, EnergyType::Water];
+        let energies = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut state = State::new(&deck_a, &deck_b);
+            (0..20)
+                .map(|_| {
+                    state.generate_energy(&mut rng);
+                    state.current_energy.unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(energies(5), energies(5));
+        assert_ne!(energies(5), energies(6));
+    }
+
     #[test]
     fn test_reset_turn_states() {
         let (deck_a, deck_b) = load_test_decks();
@@ -415,7 +676,7 @@ mod tests {
         state.has_retreated = true;
         
         // Create a Pokemon and set flags
-        let pokemon_card = Card::Pokemon(PokemonCard {
+        let pokemon_card = to_pokemon_card(PokemonCard {
             id: "test1".to_string(),
             name: "Test Pokemon".to_string(),
             hp: 60,
@@ -427,7 +688,7 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         let mut played = to_playable_card(&pokemon_card, true);
@@ -450,14 +711,14 @@ mod tests {
         let (deck_a, deck_b) = load_test_decks();
         let mut state = State::new(&deck_a, &deck_b);
         
-        let effect_card = Card::Trainer(TrainerCard {
+        let effect_card = to_trainer_card(TrainerCard {
             id: "test1".to_string(),
             numeric_id: 1,
             name: "Test Effect".to_string(),
             trainer_card_type: TrainerType::Item,
             effect: "Test effect description".to_string(),
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         // Add effect for 2 turns
@@ -484,6 +745,42 @@ mod tests {
         assert_eq!(effects.len(), 0);
     }
 
+    #[test]
+    fn test_pokemon_effects() {
+        let mut state = ScenarioBuilder::new()
+            .active(0, CardId::A1001Bulbasaur, [])
+            .bench(0, CardId::A1001Bulbasaur, [])
+            .turn(3)
+            .build();
+        let x_speed = get_card_by_enum(CardId::PA002XSpeed);
+        state.add_pokemon_effect(0, 0, x_speed.clone(), 1);
+        let active = state.get_active(0);
+        assert_eq!(active.count_effects(std::slice::from_ref(&x_speed)), 1);
+        assert!(state.in_play_pokemon[0][1]
+            .as_ref()
+            .unwrap()
+            .effects
+            .is_empty());
+        // Not a global effect
+        assert!(state.get_current_turn_effects().is_empty());
+
+        // Follows the Pokémon, and ends after its duration
+        state.in_play_pokemon[0].swap(0, 1);
+        state.turn_count = 4;
+        state.reset_turn_states();
+        assert_eq!(
+            state.in_play_pokemon[0][1].as_ref().unwrap().effects.len(),
+            1
+        );
+        state.turn_count = 5;
+        state.reset_turn_states();
+        assert!(state.in_play_pokemon[0][1]
+            .as_ref()
+            .unwrap()
+            .effects
+            .is_empty());
+    }
+
     #[test]
     #[should_panic(expected = "Active Pokemon should be there")]
     fn test_get_active_no_pokemon() {
@@ -516,7 +813,7 @@ mod tests {
         state.has_retreated = true;
         
         // Advance turn
-        state.advance_turn();
+        state.advance_turn(&mut StdRng::seed_from_u64(0));
         
         // Verify changes
         assert_eq!(state.current_player, 1);
@@ -534,7 +831,7 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // Add some Pokemon
-        let pokemon_card = Card::Pokemon(PokemonCard {
+        let pokemon_card = to_pokemon_card(PokemonCard {
             id: "test1".to_string(),
             name: "Test Pokemon".to_string(),
             hp: 60,
@@ -546,7 +843,7 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         state.in_play_pokemon[0][0] = Some(to_playable_card(&pokemon_card, true));
@@ -564,7 +861,7 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // Add some Pokemon
-        let pokemon_card = Card::Pokemon(PokemonCard {
+        let pokemon_card = to_pokemon_card(PokemonCard {
             id: "test1".to_string(),
             name: "Test Pokemon".to_string(),
             hp: 60,
@@ -576,7 +873,7 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         state.in_play_pokemon[0][0] = Some(to_playable_card(&pokemon_card, true));
@@ -595,7 +892,7 @@ mod tests {
         let mut state = State::new(&deck_a, &deck_b);
         
         // Add Pokemon of different types
-        let grass_pokemon_card = Card::Pokemon(PokemonCard {
+        let grass_pokemon_card = to_pokemon_card(PokemonCard {
             id: "grass1".to_string(),
             name: "Grass Pokemon".to_string(),
             hp: 60,
@@ -607,10 +904,10 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
-        let fire_pokemon_card = Card::Pokemon(PokemonCard {
+        let fire_pokemon_card = to_pokemon_card(PokemonCard {
             id: "fire1".to_string(),
             name: "Fire Pokemon".to_string(),
             hp: 60,
@@ -622,7 +919,7 @@ mod tests {
             attacks: vec![],
             retreat_cost: vec![],
             rarity: "Common".to_string(),
-            booster_pack: "Base".to_string(),
+            set: SetId::A1,
         });
         
         state.in_play_pokemon[0][0] = Some(to_playable_card(&grass_pokemon_card, true));
@@ -669,4 +966,75 @@ mod tests {
             _ => panic!("Expected DrawCard action"),
         }
     }
+
+    #[test]
+    fn test_phase_follows_the_game() {
+        use crate::generate_possible_actions;
+
+        let (deck_a, deck_b) = load_test_decks();
+        let mut seen = std::collections::HashSet::new();
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+            loop {
+                seen.insert(state.phase);
+                assert_eq!(state.phase == TurnPhase::GameOver, state.is_game_over());
+                if state.is_game_over() {
+                    break;
+                }
+                assert_eq!(state.phase == TurnPhase::Setup, state.turn_count == 0);
+                let (_, actions) = generate_possible_actions(&state);
+                match state.phase {
+                    TurnPhase::Draw => assert_eq!(actions[0].action, SimpleAction::DrawCard),
+                    TurnPhase::Attack | TurnPhase::Checkup => assert!(actions[0].is_stack),
+                    _ => {}
+                }
+                let action = &actions[rng.gen_range(0..actions.len())];
+                crate::actions::apply_action(&mut rng, &mut state, action);
+            }
+        }
+        for phase in [
+            TurnPhase::Setup,
+            TurnPhase::Draw,
+            TurnPhase::Main,
+            TurnPhase::Attack,
+            TurnPhase::GameOver,
+        ] {
+            assert!(seen.contains(&phase), "{phase:?} never seen");
+        }
+    }
+
+    #[test]
+    fn test_checkup_phase_resolves_knockouts() {
+        use crate::{
+            actions::{apply_action, Action},
+            test_helpers::{PokemonSetup, ScenarioBuilder},
+        };
+
+        let mut state = ScenarioBuilder::new()
+            .active(
+                0,
+                CardId::A1001Bulbasaur,
+                [PokemonSetup::Hp(10), PokemonSetup::Poisoned],
+            )
+            .bench(0, CardId::A1001Bulbasaur, [])
+            .active(1, CardId::A1001Bulbasaur, [])
+            .turn(3)
+            .build();
+        assert_eq!(state.phase, TurnPhase::Main);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut play = |state: &mut State| {
+            let (actor, actions) = crate::generate_possible_actions(state);
+            let action: &Action = &actions[0];
+            assert_eq!(action.actor, actor);
+            apply_action(&mut rng, state, action);
+        };
+        play(&mut state); // Ends the turn, poison knocking Bulbasaur out
+        assert_eq!(state.phase, TurnPhase::Draw);
+        play(&mut state);
+        assert_eq!(state.phase, TurnPhase::Checkup);
+        play(&mut state); // Promotes the benched Bulbasaur
+        assert_eq!(state.phase, TurnPhase::Main);
+        assert_eq!(state.points, [0, 1]);
+    }
 }
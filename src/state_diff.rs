@@ -0,0 +1,452 @@
+use serde::Serialize;
+
+use crate::{
+    state::{GameOutcome, MAX_IN_PLAY},
+    tool_ids::ToolId,
+    types::{Card, EnergyType, PlayedCard},
+    State,
+};
+
+/// Where a player's cards can be (besides attached to a Pokémon in play).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Zone {
+    Deck,
+    Hand,
+    DiscardPile,
+    /// In play, including the cards under an evolved Pokémon.
+    InPlay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Status {
+    Poisoned,
+    Paralyzed,
+    Asleep,
+    Burned,
+    Confused,
+}
+
+/// A granular change between two states, for clients to animate instead of re-rendering.
+/// Cards are given by id (e.g. "A1 001") and slots are `in_play_pokemon` indices.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum StateDelta {
+    TurnChanged {
+        turn_count: u8,
+        current_player: usize,
+    },
+    PointsChanged {
+        player: usize,
+        from: u8,
+        to: u8,
+    },
+    GameOver(GameOutcome),
+    CurrentEnergyChanged(Option<EnergyType>),
+    /// A card changed zones. `None` is anywhere else, e.g. a tool now attached to a Pokémon.
+    CardMoved {
+        player: usize,
+        card: String,
+        from: Option<Zone>,
+        to: Option<Zone>,
+    },
+    PokemonPlaced {
+        player: usize,
+        slot: usize,
+        card: String,
+    },
+    PokemonRemoved {
+        player: usize,
+        slot: usize,
+        card: String,
+    },
+    /// The Pokémon moved between slots (e.g. retreating).
+    PokemonMoved {
+        player: usize,
+        from_slot: usize,
+        to_slot: usize,
+    },
+    PokemonEvolved {
+        player: usize,
+        slot: usize,
+        from: String,
+        to: String,
+    },
+    HpChanged {
+        player: usize,
+        slot: usize,
+        from: u32,
+        to: u32,
+    },
+    EnergyAttached {
+        player: usize,
+        slot: usize,
+        energy: EnergyType,
+    },
+    EnergyRemoved {
+        player: usize,
+        slot: usize,
+        energy: EnergyType,
+    },
+    ToolChanged {
+        player: usize,
+        slot: usize,
+        tool: Option<ToolId>,
+    },
+    StatusChanged {
+        player: usize,
+        slot: usize,
+        status: Status,
+        active: bool,
+    },
+}
+
+impl State {
+    /// What changed going from `self` to `other` (typically the state before and after
+    /// a `play_tick`). Board changes come first, then card movements, then scores and turn.
+    /// Deck order is not tracked, so shuffles produce no deltas.
+    pub fn diff(&self, other: &State) -> Vec<StateDelta> {
+        let mut deltas = vec![];
+        for player in 0..2 {
+            diff_board(&mut deltas, player, self, other);
+        }
+        for player in 0..2 {
+            diff_zones(&mut deltas, player, self, other);
+        }
+        for player in 0..2 {
+            if self.points[player] != other.points[player] {
+                deltas.push(StateDelta::PointsChanged {
+                    player,
+                    from: self.points[player],
+                    to: other.points[player],
+                });
+            }
+        }
+        if self.current_energy != other.current_energy {
+            deltas.push(StateDelta::CurrentEnergyChanged(other.current_energy));
+        }
+        if self.turn_count != other.turn_count || self.current_player != other.current_player {
+            deltas.push(StateDelta::TurnChanged {
+                turn_count: other.turn_count,
+                current_player: other.current_player,
+            });
+        }
+        if let (None, Some(outcome)) = (self.winner, other.winner) {
+            deltas.push(StateDelta::GameOver(outcome));
+        }
+        deltas
+    }
+}
+
+/// Whether `after` is the `before` Pokémon, possibly evolved.
+fn same_pokemon(before: &PlayedCard, after: &PlayedCard) -> bool {
+    (before.card == after.card && before.cards_behind == after.cards_behind)
+        || after.cards_behind.last() == Some(&before.card)
+}
+
+fn diff_board(deltas: &mut Vec<StateDelta>, player: usize, before: &State, after: &State) {
+    let slots_before = &before.in_play_pokemon[player];
+    let slots_after = &after.in_play_pokemon[player];
+
+    // Where each Pokémon went, preferring to stay in the same slot
+    let mut matched_after = [false; MAX_IN_PLAY];
+    let mut destinations = [None; MAX_IN_PLAY];
+    for (i, slot) in slots_before.iter().enumerate() {
+        let Some(pokemon) = slot else { continue };
+        if slots_after[i]
+            .as_ref()
+            .is_some_and(|x| same_pokemon(pokemon, x))
+        {
+            destinations[i] = Some(i);
+            matched_after[i] = true;
+        }
+    }
+    for (i, slot) in slots_before.iter().enumerate() {
+        let Some(pokemon) = slot else { continue };
+        if destinations[i].is_some() {
+            continue;
+        }
+        let destination = (0..MAX_IN_PLAY).find(|j| {
+            !matched_after[*j]
+                && slots_after[*j]
+                    .as_ref()
+                    .is_some_and(|x| same_pokemon(pokemon, x))
+        });
+        match destination {
+            Some(j) => {
+                destinations[i] = Some(j);
+                matched_after[j] = true;
+            }
+            None => deltas.push(StateDelta::PokemonRemoved {
+                player,
+                slot: i,
+                card: pokemon.get_id(),
+            }),
+        }
+    }
+
+    for (i, destination) in destinations.iter().enumerate() {
+        let (Some(j), Some(from)) = (*destination, &slots_before[i]) else {
+            continue;
+        };
+        let to = slots_after[j].as_ref().unwrap();
+        if i != j {
+            deltas.push(StateDelta::PokemonMoved {
+                player,
+                from_slot: i,
+                to_slot: j,
+            });
+        }
+        diff_pokemon(deltas, player, j, from, to);
+    }
+
+    for (j, slot) in slots_after.iter().enumerate() {
+        if let (Some(pokemon), false) = (slot, matched_after[j]) {
+            deltas.push(StateDelta::PokemonPlaced {
+                player,
+                slot: j,
+                card: pokemon.get_id(),
+            });
+        }
+    }
+}
+
+fn diff_pokemon(
+    deltas: &mut Vec<StateDelta>,
+    player: usize,
+    slot: usize,
+    from: &PlayedCard,
+    to: &PlayedCard,
+) {
+    if from.card != to.card {
+        deltas.push(StateDelta::PokemonEvolved {
+            player,
+            slot,
+            from: from.get_id(),
+            to: to.get_id(),
+        });
+    }
+    if from.remaining_hp != to.remaining_hp {
+        deltas.push(StateDelta::HpChanged {
+            player,
+            slot,
+            from: from.remaining_hp,
+            to: to.remaining_hp,
+        });
+    }
+
+    let mut removed = from.attached_energy.clone();
+    for energy in &to.attached_energy {
+        match removed.iter().position(|x| x == energy) {
+            Some(index) => {
+                removed.swap_remove(index);
+            }
+            None => deltas.push(StateDelta::EnergyAttached {
+                player,
+                slot,
+                energy: *energy,
+            }),
+        }
+    }
+    for energy in removed {
+        deltas.push(StateDelta::EnergyRemoved {
+            player,
+            slot,
+            energy,
+        });
+    }
+
+    if from.attached_tool != to.attached_tool {
+        deltas.push(StateDelta::ToolChanged {
+            player,
+            slot,
+            tool: to.attached_tool,
+        });
+    }
+    let statuses = [
+        (Status::Poisoned, from.poisoned, to.poisoned),
+        (Status::Paralyzed, from.paralyzed, to.paralyzed),
+        (Status::Asleep, from.asleep, to.asleep),
+        (Status::Burned, from.burned, to.burned),
+        (Status::Confused, from.confused, to.confused),
+    ];
+    for (status, was, is) in statuses {
+        if was != is {
+            deltas.push(StateDelta::StatusChanged {
+                player,
+                slot,
+                status,
+                active: is,
+            });
+        }
+    }
+}
+
+fn zone_cards(state: &State, player: usize, zone: Zone) -> Vec<&Card> {
+    match zone {
+        Zone::Deck => state.decks[player].cards.iter().collect(),
+        Zone::Hand => state.hands[player].iter().collect(),
+        Zone::DiscardPile => state.discard_piles[player].iter().collect(),
+        Zone::InPlay => state.in_play_pokemon[player]
+            .iter()
+            .flatten()
+            .flat_map(|x| x.cards_behind.iter().chain(std::iter::once(&x.card)))
+            .collect(),
+    }
+}
+
+/// Pairs up the cards that left a zone with the ones that arrived in another.
+fn diff_zones(deltas: &mut Vec<StateDelta>, player: usize, before: &State, after: &State) {
+    let zones = [Zone::Deck, Zone::Hand, Zone::DiscardPile, Zone::InPlay];
+    let mut left: Vec<(&Card, Zone)> = vec![];
+    let mut arrived: Vec<(&Card, Zone)> = vec![];
+    for zone in zones {
+        let mut removed = zone_cards(before, player, zone);
+        for card in zone_cards(after, player, zone) {
+            match removed.iter().position(|x| *x == card) {
+                Some(index) => {
+                    removed.swap_remove(index);
+                }
+                None => arrived.push((card, zone)),
+            }
+        }
+        left.extend(removed.into_iter().map(|card| (card, zone)));
+    }
+
+    for (card, from) in left {
+        let to = arrived
+            .iter()
+            .position(|(x, _)| *x == card)
+            .map(|index| arrived.remove(index).1);
+        deltas.push(StateDelta::CardMoved {
+            player,
+            card: card.get_id(),
+            from: Some(from),
+            to,
+        });
+    }
+    for (card, to) in arrived {
+        deltas.push(StateDelta::CardMoved {
+            player,
+            card: card.get_id(),
+            from: None,
+            to: Some(to),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        hooks::to_playable_card,
+        players::{create_players, PlayerCode},
+        test_helpers::load_test_decks,
+        Game,
+    };
+
+    #[test]
+    fn test_diff() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut before = State::new(&deck_a, &deck_b);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        let charmander = get_card_by_enum(CardId::A1033Charmander);
+        before.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        before.in_play_pokemon[0][1] = Some(to_playable_card(&charmander, false));
+        before.hands[0] = vec![ivysaur.clone()];
+        assert!(before.diff(&before).is_empty());
+
+        // Evolve the Bulbasaur, attach energy, then retreat it
+        let mut after = before.clone();
+        let mut evolved = to_playable_card(&ivysaur, false);
+        evolved.cards_behind = vec![bulbasaur];
+        evolved.attached_energy = vec![EnergyType::Grass];
+        evolved.remaining_hp = 60;
+        after.in_play_pokemon[0][0] = after.in_play_pokemon[0][1].take();
+        after.in_play_pokemon[0][1] = Some(evolved);
+        after.hands[0].clear();
+        after.points[1] = 1;
+
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                StateDelta::PokemonMoved {
+                    player: 0,
+                    from_slot: 0,
+                    to_slot: 1
+                },
+                StateDelta::PokemonEvolved {
+                    player: 0,
+                    slot: 1,
+                    from: "A1 001".to_string(),
+                    to: "A1 002".to_string()
+                },
+                StateDelta::HpChanged {
+                    player: 0,
+                    slot: 1,
+                    from: 70,
+                    to: 60
+                },
+                StateDelta::EnergyAttached {
+                    player: 0,
+                    slot: 1,
+                    energy: EnergyType::Grass
+                },
+                StateDelta::PokemonMoved {
+                    player: 0,
+                    from_slot: 1,
+                    to_slot: 0
+                },
+                StateDelta::CardMoved {
+                    player: 0,
+                    card: "A1 002".to_string(),
+                    from: Some(Zone::Hand),
+                    to: Some(Zone::InPlay)
+                },
+                StateDelta::PointsChanged {
+                    player: 1,
+                    from: 0,
+                    to: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_game_ticks() {
+        let (deck_a, deck_b) = load_test_decks();
+        let players = create_players(deck_a, deck_b, vec![PlayerCode::R, PlayerCode::R]);
+        let mut game = Game::new(players, 0);
+        let zones = [Zone::Deck, Zone::Hand, Zone::DiscardPile, Zone::InPlay];
+        while !game.get_state().is_game_over() {
+            let before = game.get_state_clone();
+            game.play_tick();
+            let after = game.get_state();
+
+            // Zone sizes can be replayed from the card movements alone
+            let mut sizes: Vec<[usize; 4]> = (0..2)
+                .map(|player| zones.map(|zone| zone_cards(&before, player, zone).len()))
+                .collect();
+            for delta in before.diff(after) {
+                if let StateDelta::CardMoved {
+                    player, from, to, ..
+                } = delta
+                {
+                    if let Some(from) = from {
+                        sizes[player][from as usize] -= 1;
+                    }
+                    if let Some(to) = to {
+                        sizes[player][to as usize] += 1;
+                    }
+                }
+            }
+            for (player, sizes) in sizes.iter().enumerate() {
+                assert_eq!(
+                    *sizes,
+                    zones.map(|zone| zone_cards(after, player, zone).len())
+                );
+            }
+        }
+    }
+}
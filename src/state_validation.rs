@@ -0,0 +1,455 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use crate::{
+    actions::SimpleAction,
+    errors::{GameError, GameResult},
+    hooks::get_base_hp,
+    state::{State, MAX_IN_PLAY},
+    tool_ids::ToolId,
+    types::{Card, PlayedCard, TrainerType, DAMAGE_COUNTER},
+};
+
+const MAX_COPIES_PER_NAME: usize = 2;
+const DECK_SIZE: usize = 20;
+
+/// Validation and repair for states that didn't come out of the engine itself
+/// (UIs, saves, hand-built scenarios), so corrupt inputs don't produce nonsense simulations.
+impl State {
+    /// Checks the whole state and returns every inconsistency found (empty if the state is valid).
+    pub fn validate_full(&self) -> Vec<GameError> {
+        let mut issues = vec![];
+        if self.current_player >= 2 {
+            issues.push(GameError::InvalidPlayer {
+                player: self.current_player,
+            });
+        }
+
+        for player in 0..2 {
+            // The Knock Out that ends the game can overshoot the points needed
+            if self.winner.is_none() && self.points[player] > self.rules.points_to_win {
+                issues.push(invalid(format!(
+                    "Player {} has {} points, but the game ends at {}",
+                    player + 1,
+                    self.points[player],
+                    self.rules.points_to_win
+                )));
+            }
+
+            let counts = self.count_cards_by_name(player);
+            let total: usize = counts.values().sum();
+            if total > DECK_SIZE {
+                issues.push(invalid(format!(
+                    "Player {} owns {} cards, but decks have {}",
+                    player + 1,
+                    total,
+                    DECK_SIZE
+                )));
+            }
+            for (name, count) in counts {
+                if count > MAX_COPIES_PER_NAME {
+                    issues.push(invalid(format!(
+                        "Player {} owns {} copies of {}, but at most {} are allowed",
+                        player + 1,
+                        count,
+                        name,
+                        MAX_COPIES_PER_NAME
+                    )));
+                }
+            }
+
+            for (position, pokemon) in self.enumerate_in_play_pokemon(player) {
+                if !self.rules.is_usable_slot(position) {
+                    issues.push(invalid(format!(
+                        "Player {} has a Pokémon at position {}, but the Bench only has {} slots",
+                        player + 1,
+                        position,
+                        self.rules.bench_size
+                    )));
+                }
+                issues.extend(validate_in_play(player, position, pokemon));
+            }
+
+            let has_bench = self.enumerate_bench_pokemon(player).next().is_some();
+            if self.turn_count > 0
+                && self.winner.is_none()
+                && self.in_play_pokemon[player][0].is_none()
+                && has_bench
+                && !self.is_choosing_active(player)
+            {
+                issues.push(invalid(format!(
+                    "Player {} has Benched Pokémon but no Active Pokémon",
+                    player + 1
+                )));
+            }
+        }
+        issues
+    }
+
+    /// Checks what every state coming out of the engine should satisfy: each player owns
+    /// exactly the cards of their decklist, nobody reached the points to win without the
+    /// game ending, and no Pokémon is both Asleep and Paralyzed. Cheaper and stricter than
+    /// `validate_full`, it's meant to catch rules bugs (e.g. cards duplicating when evolving).
+    pub fn check_invariants(&self) -> GameResult<()> {
+        for player in 0..2 {
+            if let Some(checksums) = self.decklist_checksums {
+                if self.owned_cards_checksum(player) != checksums[player] {
+                    return Err(invalid(format!(
+                        "Player {}'s cards don't match their decklist",
+                        player + 1
+                    )));
+                }
+            }
+            if self.winner.is_none() && self.points[player] >= self.rules.points_to_win {
+                return Err(invalid(format!(
+                    "Player {} has {} points, but the game didn't end",
+                    player + 1,
+                    self.points[player]
+                )));
+            }
+            for (position, pokemon) in self.enumerate_in_play_pokemon(player) {
+                let exclusive = [pokemon.asleep, pokemon.paralyzed, pokemon.confused];
+                if exclusive.iter().filter(|x| **x).count() > 1 {
+                    return Err(invalid(format!(
+                        "Player {}'s {} at position {} has more than one of Asleep, Paralyzed and Confused",
+                        player + 1,
+                        pokemon.get_name(),
+                        position
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Best-effort fix of the issues reported by `validate_full`. Returns the issues that
+    /// could not be repaired (e.g. points or knocked out Pokémon, which need a human decision).
+    pub fn repair(&mut self) -> Vec<GameError> {
+        for player in 0..2 {
+            // Take unplayable cards (e.g. loose tools) out of play and into the discard pile
+            for slot in self.in_play_pokemon[player].iter_mut() {
+                let Some(pokemon) = slot else {
+                    continue;
+                };
+                let (stuck, behind): (Vec<_>, Vec<_>) = pokemon
+                    .cards_behind
+                    .drain(..)
+                    .partition(|card| get_base_hp(card).is_none());
+                pokemon.cards_behind = behind;
+                self.discard_piles[player].extend(stuck);
+
+                match expected_total_hp(pokemon) {
+                    Some(total_hp) => {
+                        // Keeps the damage, in whole damage counters
+                        let damage = pokemon.damage_counters() * DAMAGE_COUNTER;
+                        pokemon.total_hp = total_hp;
+                        pokemon.remaining_hp = total_hp.saturating_sub(damage);
+                    }
+                    None => {
+                        let pokemon = slot.take().expect("Slot was just checked to be some");
+                        self.discard_piles[player].push(pokemon.card);
+                        self.discard_piles[player].extend(pokemon.cards_behind);
+                    }
+                }
+            }
+
+            // Remove extra copies, preferring cards the player can't see yet
+            for (name, count) in self.count_cards_by_name(player) {
+                let mut excess = count.saturating_sub(MAX_COPIES_PER_NAME);
+                for zone in [
+                    &mut Arc::make_mut(&mut self.decks[player]).cards,
+                    &mut self.hands[player],
+                    &mut self.discard_piles[player],
+                ] {
+                    while excess > 0 {
+                        match zone.iter().rposition(|card| card.get_name() == name) {
+                            Some(index) => {
+                                zone.remove(index);
+                                excess -= 1;
+                            }
+                            None => break,
+                        }
+                    }
+                }
+            }
+            let total: usize = self.count_cards_by_name(player).values().sum();
+            let deck = &mut self.deck_mut(player).cards;
+            deck.truncate(deck.len().saturating_sub(total.saturating_sub(DECK_SIZE)));
+
+            // Promote the first Benched Pokémon if the Active Spot is empty
+            if self.turn_count > 0 && self.in_play_pokemon[player][0].is_none() {
+                if let Some(position) =
+                    (1..MAX_IN_PLAY).find(|&i| self.in_play_pokemon[player][i].is_some())
+                {
+                    self.in_play_pokemon[player].swap(0, position);
+                }
+            }
+        }
+        self.validate_full()
+    }
+
+    // Whether the player has yet to pick a new Active Pokémon (e.g. after a Knock Out).
+    fn is_choosing_active(&self, player: usize) -> bool {
+        self.move_generation_stack.iter().any(|(actor, choices)| {
+            *actor == player
+                && choices.iter().any(|x| {
+                    matches!(
+                        x,
+                        SimpleAction::Promote { .. } | SimpleAction::Activate { .. }
+                    )
+                })
+        })
+    }
+
+    // Counts every card a player owns by name, since deck limits apply to cards with the
+    // same name.
+    fn count_cards_by_name(&self, player: usize) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for card in self.owned_cards(player) {
+            *counts.entry(card.get_name()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Sum of the hashes of the ids of every card the player owns, which doesn't depend on
+    /// where the cards are.
+    pub(crate) fn owned_cards_checksum(&self, player: usize) -> u64 {
+        self.owned_cards(player)
+            .map(|card| {
+                let mut hasher = DefaultHasher::new();
+                card.get_id().hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(0, u64::wrapping_add)
+    }
+
+    // Cards in the deck, hand, discard pile and in play (including the ones under evolutions)
+    fn owned_cards(&self, player: usize) -> impl Iterator<Item = &Card> {
+        let in_play = self.in_play_pokemon[player]
+            .iter()
+            .flatten()
+            .flat_map(|pokemon| std::iter::once(&pokemon.card).chain(&pokemon.cards_behind));
+        self.decks[player]
+            .cards
+            .iter()
+            .chain(&self.hands[player])
+            .chain(&self.discard_piles[player])
+            .chain(in_play)
+    }
+}
+
+fn validate_in_play(player: usize, position: usize, pokemon: &PlayedCard) -> Vec<GameError> {
+    let mut issues = vec![];
+    let name = pokemon.get_name();
+    for card in &pokemon.cards_behind {
+        if get_base_hp(card).is_none() {
+            issues.push(invalid(format!(
+                "Player {} has {} under {} at position {}, {}",
+                player + 1,
+                card.get_name(),
+                name,
+                position,
+                unplayable_reason(card)
+            )));
+        }
+    }
+
+    let Some(expected_total_hp) = expected_total_hp(pokemon) else {
+        issues.push(invalid(format!(
+            "Player {} has {} in play at position {}, {}",
+            player + 1,
+            name,
+            position,
+            unplayable_reason(&pokemon.card)
+        )));
+        return issues;
+    };
+    if pokemon.total_hp != expected_total_hp {
+        issues.push(invalid(format!(
+            "Player {}'s {} at position {} has {} max HP, expected {}",
+            player + 1,
+            name,
+            position,
+            pokemon.total_hp,
+            expected_total_hp
+        )));
+    }
+    if pokemon.remaining_hp > pokemon.total_hp {
+        issues.push(invalid(format!(
+            "Player {}'s {} at position {} has {} HP, more than its max of {}",
+            player + 1,
+            name,
+            position,
+            pokemon.remaining_hp,
+            pokemon.total_hp
+        )));
+    }
+    if !pokemon.damage_taken().is_multiple_of(DAMAGE_COUNTER) {
+        issues.push(invalid(format!(
+            "Player {}'s {} at position {} has {} damage, not in whole damage counters",
+            player + 1,
+            name,
+            position,
+            pokemon.damage_taken()
+        )));
+    }
+    if pokemon.remaining_hp == 0 {
+        issues.push(invalid(format!(
+            "Player {}'s {} at position {} has 0 HP and should have been Knocked Out",
+            player + 1,
+            name,
+            position
+        )));
+    }
+    issues
+}
+
+fn expected_total_hp(pokemon: &PlayedCard) -> Option<u32> {
+    let base_hp = get_base_hp(&pokemon.card)?;
+    match pokemon.attached_tool {
+        Some(ToolId::A2147GiantCape) => Some(base_hp + 20),
+        _ => Some(base_hp),
+    }
+}
+
+fn unplayable_reason(card: &Card) -> &'static str {
+    match card {
+        Card::Trainer(trainer_card) if trainer_card.trainer_card_type == TrainerType::Tool => {
+            "a Tool not attached to any Pokémon"
+        }
+        _ => "which can't be put into play",
+    }
+}
+
+fn invalid(description: String) -> GameError {
+    GameError::InvalidGameState { description }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card,
+        test_helpers::load_test_decks,
+    };
+    use rand::SeedableRng;
+
+    // Moves a card from the player's deck into play, so card counts stay consistent.
+    fn place_from_deck(state: &mut State, player: usize, card_id: CardId, position: usize) {
+        let card = get_card_by_enum(card_id);
+        let index = state.decks[player]
+            .cards
+            .iter()
+            .position(|x| *x == card)
+            .expect("Deck should contain card");
+        state.deck_mut(player).cards.remove(index);
+        state.in_play_pokemon[player][position] = Some(to_playable_card(&card, false));
+    }
+
+    fn state_with_actives() -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 3;
+        place_from_deck(&mut state, 0, CardId::A1001Bulbasaur, 0);
+        place_from_deck(&mut state, 1, CardId::A1176Koffing, 0);
+        state
+    }
+
+    #[test]
+    fn test_valid_state_has_no_issues() {
+        let state = state_with_actives();
+        assert_eq!(state.validate_full(), vec![]);
+    }
+
+    #[test]
+    fn test_reports_all_issues() {
+        let mut state = state_with_actives();
+        state.current_player = 2;
+        state.hands[0].push(get_card_by_enum(CardId::A1001Bulbasaur));
+        let cape = get_card_by_enum(CardId::A2147GiantCape);
+        state.get_active_mut(0).cards_behind.push(cape);
+        state.get_active_mut(1).remaining_hp = 500;
+
+        let issues = state.validate_full();
+        assert_eq!(issues.len(), 5);
+        assert!(issues.contains(&GameError::InvalidPlayer { player: 2 }));
+        assert!(issues
+            .iter()
+            .any(|x| x.to_string().contains("3 copies of Bulbasaur")));
+        assert!(issues
+            .iter()
+            .any(|x| x.to_string().contains("owns 22 cards")));
+        assert!(issues
+            .iter()
+            .any(|x| x.to_string().contains("Tool not attached")));
+        assert!(issues.iter().any(|x| x.to_string().contains("has 500 HP")));
+    }
+
+    #[test]
+    fn test_check_invariants() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+        assert!(state.check_invariants().is_ok());
+
+        // Cards can move around, but not appear out of nowhere
+        let card = state.deck_mut(0).cards.pop().unwrap();
+        state.discard_piles[0].push(card.clone());
+        assert!(state.check_invariants().is_ok());
+        state.hands[0].push(card);
+        assert!(state.check_invariants().is_err());
+        state.hands[0].pop();
+
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&state.hands[1][0], false));
+        state.hands[1].remove(0);
+        state.get_active_mut(1).asleep = true;
+        state.get_active_mut(1).paralyzed = true;
+        let error = state.check_invariants().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("more than one of Asleep, Paralyzed and Confused"));
+    }
+
+    #[test]
+    fn test_repair() {
+        let mut state = state_with_actives();
+        state.hands[0].push(get_card_by_enum(CardId::A1001Bulbasaur));
+        place_from_deck(&mut state, 0, CardId::A1021Exeggcute, 2);
+        state.in_play_pokemon[0][2].as_mut().unwrap().attached_tool = Some(ToolId::A2147GiantCape);
+        state.get_active_mut(1).remaining_hp = 500;
+        state.in_play_pokemon[1].swap(0, 1);
+        assert_eq!(state.validate_full().len(), 5);
+
+        assert_eq!(state.repair(), vec![]);
+        assert_eq!(state.count_cards_by_name(0)["Bulbasaur"], 2);
+        assert_eq!(state.count_cards_by_name(0).values().sum::<usize>(), 20);
+        assert_eq!(state.get_active(1).remaining_hp, 70);
+        assert_eq!(state.in_play_pokemon[0][2].as_ref().unwrap().total_hp, 70);
+    }
+
+    #[test]
+    fn test_repair_rounds_damage_up() {
+        let mut state = state_with_actives();
+        state.get_active_mut(0).remaining_hp = 45;
+        assert_eq!(state.validate_full().len(), 1);
+
+        assert_eq!(state.repair(), vec![]);
+        assert_eq!(state.get_active(0).remaining_hp, 40);
+        assert_eq!(state.get_active(0).damage_counters(), 3);
+    }
+
+    #[test]
+    fn test_repair_leaves_knocked_out_pokemon() {
+        let mut state = state_with_actives();
+        state.get_active_mut(0).remaining_hp = 0;
+
+        let issues = state.repair();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .to_string()
+            .contains("should have been Knocked Out"));
+    }
+}
@@ -1,5 +1,13 @@
-use crate::Deck;
+use crate::actions::apply_action;
+use crate::card_ids::CardId;
+use crate::database::get_card_by_enum;
+use crate::hooks::to_playable_card;
+use crate::state::TurnPhase;
+use crate::tool_ids::ToolId;
+use crate::types::{Card, EnergyType, PlayedCard, PokemonCard, TrainerCard};
+use crate::{generate_possible_actions, Deck, State};
 use lazy_static::lazy_static;
+use rand::{rngs::StdRng, SeedableRng};
 
 // Thinking of making this public and part of production code, just like
 //  numpy has some datasets for testing purposes. Tried #[cfg(test)] but
@@ -25,3 +33,231 @@ lazy_static! {
     pub static ref DECK_B: Deck =
         Deck::from_file("example_decks/weezing-arbok.txt").expect("Valid Deck Format");
 }
+
+/// Wraps made-up card data (not in the database) in a `Card`. Leaks the data, so only use
+/// it to build cards for tests.
+pub fn to_pokemon_card(data: PokemonCard) -> Card {
+    Card::Pokemon(Box::leak(Box::new(data)))
+}
+
+/// Like `to_pokemon_card`, for trainer cards.
+pub fn to_trainer_card(data: TrainerCard) -> Card {
+    Card::Trainer(Box::leak(Box::new(data)))
+}
+
+/// Decks that `play_checked_game` picks from, covering the cards the engine implements.
+pub const FUZZ_DECKS: [&str; 7] = [
+    "venusaur-exeggutor.txt",
+    "weezing-arbok.txt",
+    "blastoiseex.txt",
+    "mewtwoex.txt",
+    "fire.txt",
+    "arceusdialga.txt",
+    "fossils.txt",
+];
+
+/// Plays a game driven by `data`: the first bytes pick the seed and the decks, and each
+/// following byte picks one of the legal actions. Panics as soon as an invariant breaks
+/// (see `check_invariants`). Shared by the fuzz target and the tests, so that inputs found
+/// by fuzzing can be replayed as tests.
+pub fn play_checked_game(data: &[u8]) -> State {
+    let (header, choices) = data.split_at(data.len().min(10));
+    let mut seed_bytes = [0; 8];
+    seed_bytes[..header.len().min(8)].copy_from_slice(&header[..header.len().min(8)]);
+    let deck_index = |i: usize| header.get(8 + i).map_or(i, |&x| x as usize) % FUZZ_DECKS.len();
+    let deck_a = load_test_deck(FUZZ_DECKS[deck_index(0)]);
+    let deck_b = load_test_deck(FUZZ_DECKS[deck_index(1)]);
+
+    let mut rng = StdRng::seed_from_u64(u64::from_le_bytes(seed_bytes));
+    let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+    for &choice in choices {
+        if state.is_game_over() {
+            break;
+        }
+        let (_, actions) = generate_possible_actions(&state);
+        let action = &actions[choice as usize % actions.len()];
+        apply_action(&mut rng, &mut state, action);
+        check_invariants(&state);
+    }
+    state
+}
+
+/// How a Pokémon put in play by `ScenarioBuilder` differs from a freshly played one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PokemonSetup {
+    /// Remaining HP.
+    Hp(u32),
+    Energy(Vec<EnergyType>),
+    Tool(ToolId),
+    PlayedThisTurn,
+    Poisoned,
+    Paralyzed,
+    Asleep,
+    Burned,
+    Confused,
+}
+
+/// Remaining HP of a Pokémon, for `ScenarioBuilder`.
+pub fn hp(remaining_hp: u32) -> PokemonSetup {
+    PokemonSetup::Hp(remaining_hp)
+}
+
+/// Energy attached to a Pokémon, for `ScenarioBuilder`.
+pub fn energy(energy: impl IntoIterator<Item = EnergyType>) -> PokemonSetup {
+    PokemonSetup::Energy(energy.into_iter().collect())
+}
+
+/// Builds a mid-game state in a few lines instead of setting fields one by one:
+///
+/// ```
+/// use deckgym::card_ids::CardId;
+/// use deckgym::test_helpers::{energy, hp, ScenarioBuilder};
+/// use deckgym::types::EnergyType::Grass;
+///
+/// let state = ScenarioBuilder::new()
+///     .active(0, CardId::A1001Bulbasaur, [hp(50), energy([Grass, Grass])])
+///     .bench(0, CardId::A1001Bulbasaur, [])
+///     .active(1, CardId::A1177Weezing, [])
+///     .hand(0, [CardId::A1002Ivysaur])
+///     .turn(5)
+///     .build();
+/// assert_eq!(state.in_play_pokemon[0][0].as_ref().unwrap().remaining_hp, 50);
+/// ```
+///
+/// Starts from an empty board with the test decks (see `load_test_decks`), or from a
+/// snapshot of another state with `from_state`. Cards put in play or in hand are not taken
+/// out of the decks.
+#[derive(Debug, Clone)]
+pub struct ScenarioBuilder {
+    state: State,
+}
+
+impl Default for ScenarioBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScenarioBuilder {
+    pub fn new() -> Self {
+        let (deck_a, deck_b) = load_test_decks();
+        Self::from_state(State::new(&deck_a, &deck_b))
+    }
+
+    /// Continues from a snapshot of a state, e.g. one reached by playing a game.
+    pub fn from_state(state: State) -> Self {
+        Self { state }
+    }
+
+    /// Replaces both players' decks.
+    pub fn decks(mut self, deck_a: &Deck, deck_b: &Deck) -> Self {
+        self.state.decks = [deck_a.clone().into(), deck_b.clone().into()];
+        self
+    }
+
+    /// Puts the Pokémon in the player's Active Spot, replacing what was there.
+    pub fn active(
+        self,
+        player: usize,
+        card: CardId,
+        setup: impl IntoIterator<Item = PokemonSetup>,
+    ) -> Self {
+        self.in_play(player, 0, card, setup)
+    }
+
+    /// Puts the Pokémon on the first free spot of the player's Bench.
+    pub fn bench(
+        self,
+        player: usize,
+        card: CardId,
+        setup: impl IntoIterator<Item = PokemonSetup>,
+    ) -> Self {
+        let slot = (1..=self.state.rules.bench_size)
+            .find(|x| self.state.in_play_pokemon[player][*x].is_none())
+            .expect("Bench should have a free spot");
+        self.in_play(player, slot, card, setup)
+    }
+
+    /// Puts the Pokémon in the given spot (0 for the Active Spot, 1 to `bench_size` for the Bench).
+    pub fn in_play(
+        mut self,
+        player: usize,
+        slot: usize,
+        card: CardId,
+        setup: impl IntoIterator<Item = PokemonSetup>,
+    ) -> Self {
+        let mut played = to_playable_card(&get_card_by_enum(card), false);
+        for setup in setup {
+            apply_setup(&mut played, setup);
+        }
+        self.state.in_play_pokemon[player][slot] = Some(played);
+        self
+    }
+
+    /// Replaces the player's hand.
+    pub fn hand(mut self, player: usize, cards: impl IntoIterator<Item = CardId>) -> Self {
+        self.state.hands[player] = cards.into_iter().map(get_card_by_enum).collect();
+        self
+    }
+
+    /// Puts the cards on top of the player's deck, the first one on top.
+    pub fn deck_top(mut self, player: usize, cards: impl IntoIterator<Item = CardId>) -> Self {
+        let cards: Vec<Card> = cards.into_iter().map(get_card_by_enum).collect();
+        self.state.deck_mut(player).cards.splice(0..0, cards);
+        self
+    }
+
+    pub fn discard(mut self, player: usize, cards: impl IntoIterator<Item = CardId>) -> Self {
+        self.state.discard_piles[player] = cards.into_iter().map(get_card_by_enum).collect();
+        self
+    }
+
+    pub fn points(mut self, player: usize, points: u8) -> Self {
+        self.state.points[player] = points;
+        self
+    }
+
+    /// Also moves the phase to the main one of the turn (or setup, for turn 0).
+    pub fn turn(mut self, turn_count: u8) -> Self {
+        self.state.turn_count = turn_count;
+        self.state.phase = if turn_count == 0 {
+            TurnPhase::Setup
+        } else {
+            TurnPhase::Main
+        };
+        self
+    }
+
+    pub fn current_player(mut self, player: usize) -> Self {
+        self.state.current_player = player;
+        self
+    }
+
+    pub fn build(self) -> State {
+        self.state
+    }
+}
+
+fn apply_setup(played: &mut PlayedCard, setup: PokemonSetup) {
+    match setup {
+        PokemonSetup::Hp(remaining_hp) => played.remaining_hp = remaining_hp,
+        PokemonSetup::Energy(energy) => played.attached_energy = energy,
+        PokemonSetup::Tool(tool_id) => played.attached_tool = Some(tool_id),
+        PokemonSetup::PlayedThisTurn => played.played_this_turn = true,
+        PokemonSetup::Poisoned => played.poisoned = true,
+        PokemonSetup::Paralyzed => played.paralyzed = true,
+        PokemonSetup::Asleep => played.asleep = true,
+        PokemonSetup::Burned => played.burned = true,
+        PokemonSetup::Confused => played.confused = true,
+    }
+}
+
+/// Panics if the state is inconsistent (see `State::validate_full`) or breaks one of the
+/// engine's invariants (see `State::check_invariants`).
+pub fn check_invariants(state: &State) {
+    let issues = state.validate_full();
+    assert!(issues.is_empty(), "Invalid state: {issues:?}\n{state:?}");
+    if let Err(error) = state.check_invariants() {
+        panic!("Broken invariant: {error:?}\n{state:?}");
+    }
+}
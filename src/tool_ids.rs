@@ -1,12 +1,14 @@
+use serde::Serialize;
 use std::collections::HashMap;
 
 use crate::types::TrainerCard;
 
 // TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum ToolId {
     A2147GiantCape,
     A2148RockyHelmet,
+    A2149LumBerry,
 }
 
 lazy_static::lazy_static! {
@@ -14,6 +16,7 @@ lazy_static::lazy_static! {
         let mut m = HashMap::new();
         m.insert("A2 147", ToolId::A2147GiantCape);
         m.insert("A2 148", ToolId::A2148RockyHelmet);
+        m.insert("A2 149", ToolId::A2149LumBerry);
         m
     };
 }
@@ -3,7 +3,7 @@ use std::hash::{Hash, Hasher};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{card_ids::CardId, tool_ids::ToolId};
+use crate::{card_ids::CardId, sets::SetId, tool_ids::ToolId};
 
 /// Represents the type of energy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
@@ -36,6 +36,14 @@ impl EnergyType {
         }
     }
 }
+impl std::str::FromStr for EnergyType {
+    type Err = String;
+
+    fn from_str(energy_type: &str) -> Result<Self, Self::Err> {
+        EnergyType::from_str(energy_type)
+            .ok_or_else(|| format!("Invalid energy type: {energy_type}"))
+    }
+}
 
 /// Represents an attack of a card.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -54,6 +62,19 @@ pub struct Ability {
 }
 
 pub const BASIC_STAGE: u8 = 0;
+/// HP fossils have when put into play, see `Card::is_fossil`.
+pub const FOSSIL_HP: u32 = 40;
+/// HP of one damage counter. Damage, healing and HP all come in multiples of it.
+pub const DAMAGE_COUNTER: u32 = 10;
+const FOSSIL_NAMES: [&str; 5] = [
+    "Helix Fossil",
+    "Dome Fossil",
+    "Old Amber",
+    "Skull Fossil",
+    "Armor Fossil",
+];
+// Attacks of cards in play that have none (fossils)
+static NO_ATTACKS: Vec<Attack> = Vec::new();
 
 /// Represents the data of a single pokemon card.
 #[derive(Clone, Serialize, Deserialize)]
@@ -69,7 +90,8 @@ pub struct PokemonCard {
     pub weakness: Option<EnergyType>,
     pub retreat_cost: Vec<EnergyType>,
     pub rarity: String,
-    pub booster_pack: String,
+    #[serde(rename = "booster_pack")]
+    pub set: SetId,
 }
 impl PartialEq for PokemonCard {
     fn eq(&self, other: &Self) -> bool {
@@ -99,7 +121,8 @@ pub struct TrainerCard {
     pub name: String,
     pub effect: String,
     pub rarity: String,
-    pub booster_pack: String,
+    #[serde(rename = "booster_pack")]
+    pub set: SetId,
 }
 impl PartialEq for TrainerCard {
     fn eq(&self, other: &Self) -> bool {
@@ -113,11 +136,43 @@ impl Hash for TrainerCard {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// A card, pointing into the registry of card data built from `database` (see
+/// `get_card_by_enum`). Cloning one is as cheap as copying a pointer, which keeps hands,
+/// decks and `PlayedCard`s cheap to clone during search.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Card {
+    Pokemon(&'static PokemonCard),
+    Trainer(&'static TrainerCard),
+}
+
+/// Owned card data, as stored in database.json and in the card registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CardData {
     Pokemon(PokemonCard),
     Trainer(TrainerCard),
 }
+impl CardData {
+    pub fn as_card(&'static self) -> Card {
+        match self {
+            CardData::Pokemon(pokemon_card) => Card::Pokemon(pokemon_card),
+            CardData::Trainer(trainer_card) => Card::Trainer(trainer_card),
+        }
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self {
+            CardData::Pokemon(pokemon_card) => &pokemon_card.id,
+            CardData::Trainer(trainer_card) => &trainer_card.id,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self {
+            CardData::Pokemon(pokemon_card) => &pokemon_card.name,
+            CardData::Trainer(trainer_card) => &trainer_card.name,
+        }
+    }
+}
 impl Hash for Card {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
@@ -144,6 +199,7 @@ impl Card {
     pub(crate) fn get_attacks(&self) -> &Vec<Attack> {
         match self {
             Card::Pokemon(pokemon_card) => &pokemon_card.attacks,
+            _ if self.is_fossil() => &NO_ATTACKS,
             _ => panic!("Unsupported playable card type"),
         }
     }
@@ -175,10 +231,18 @@ impl Card {
     pub(crate) fn get_type(&self) -> Option<EnergyType> {
         match self {
             Card::Pokemon(pokemon_card) => Some(pokemon_card.energy_type),
+            _ if self.is_fossil() => Some(EnergyType::Colorless),
             _ => None,
         }
     }
 
+    pub fn get_set(&self) -> SetId {
+        match self {
+            Card::Pokemon(pokemon_card) => pokemon_card.set,
+            Card::Trainer(trainer_card) => trainer_card.set,
+        }
+    }
+
     pub fn get_card_id(&self) -> CardId {
         CardId::from_card_id(self.get_id().as_str()).expect("Card ID should be valid")
     }
@@ -189,11 +253,21 @@ impl Card {
             _ => false,
         }
     }
+
+    /// Fossils are Items played as if they were 40 HP Basic Colorless Pokémon. They have no
+    /// attacks, can't retreat and give no points when Knocked Out. They don't count as Basic
+    /// Pokémon outside of play (e.g. for the opening hand or Poké Ball).
+    pub fn is_fossil(&self) -> bool {
+        match self {
+            Card::Trainer(trainer_card) => FOSSIL_NAMES.contains(&trainer_card.name.as_str()),
+            _ => false,
+        }
+    }
 }
 
 /// This represents a card in the mat. Has a pointer to the card
 /// description, but captures the extra variable properties while in mat.
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct PlayedCard {
     pub card: Card,
     pub remaining_hp: u32,
@@ -205,8 +279,27 @@ pub struct PlayedCard {
     pub poisoned: bool,
     pub paralyzed: bool,
     pub asleep: bool,
+    pub burned: bool,
+    pub confused: bool,
     pub cards_behind: Vec<Card>,
+    // Effects on this Pokémon only (unlike State's turn effects), which follow it around
+    // the board and end when it leaves play or evolves.
+    pub effects: Vec<PokemonEffect>,
+}
+
+/// An effect on one in-play Pokémon, e.g. X Speed lowering the Retreat Cost of the Active
+/// Pokémon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct PokemonEffect {
+    /// The card causing the effect.
+    pub card: Card,
+    /// Last turn the effect is active (removed by `State::reset_turn_states` after that).
+    pub until_turn: u8,
+    /// For effects left by one of the Pokémon's own attacks, which one it was (see
+    /// `PlayedCard::add_attack_effect`).
+    pub attack_index: Option<usize>,
 }
+
 impl PlayedCard {
     pub(crate) fn get_id(&self) -> String {
         match &self.card {
@@ -223,14 +316,25 @@ impl PlayedCard {
     }
 
     pub(crate) fn get_attacks(&self) -> &Vec<Attack> {
-        match &self.card {
-            Card::Pokemon(pokemon_card) => &pokemon_card.attacks,
-            _ => panic!("Unsupported playable card type"),
-        }
+        self.card.get_attacks()
+    }
+
+    /// Damage on the Pokémon. Unlike its HP, this is what evolving keeps and what healing
+    /// removes, while a higher max HP (e.g. from a Giant Cape) leaves it as is.
+    pub fn damage_taken(&self) -> u32 {
+        self.total_hp.saturating_sub(self.remaining_hp)
     }
 
+    /// Damage counters on the Pokémon. HP and damage come in multiples of `DAMAGE_COUNTER`,
+    /// so this is exact for states played by the engine; partial counters of hand-built states
+    /// round up (see `State::repair`).
+    pub fn damage_counters(&self) -> u32 {
+        self.damage_taken().div_ceil(DAMAGE_COUNTER)
+    }
+
+    /// Heals up to `amount` damage, never past the max HP.
     pub(crate) fn heal(&mut self, amount: u32) {
-        self.remaining_hp = (self.remaining_hp + amount).min(self.total_hp);
+        self.remaining_hp += amount.min(self.damage_taken());
     }
 
     pub(crate) fn attach_energy(&mut self, energy: &EnergyType, amount: u8) {
@@ -251,19 +355,84 @@ impl PlayedCard {
 
     // Option because if playing an item card... (?)
     pub(crate) fn get_energy_type(&self) -> Option<EnergyType> {
-        match &self.card {
-            Card::Pokemon(pokemon_card) => Some(pokemon_card.energy_type),
-            _ => None,
+        self.card.get_type()
+    }
+
+    pub(crate) fn apply_status(&mut self, status: &StatusCondition) {
+        // Asleep, Paralyzed and Confused replace each other, Poisoned and Burned stack
+        // with all of them
+        match status {
+            StatusCondition::Asleep => {
+                self.asleep = true;
+                self.paralyzed = false;
+                self.confused = false;
+            }
+            StatusCondition::Paralyzed => {
+                self.paralyzed = true;
+                self.asleep = false;
+                self.confused = false;
+            }
+            StatusCondition::Confused => {
+                self.confused = true;
+                self.asleep = false;
+                self.paralyzed = false;
+            }
+            StatusCondition::Poisoned => self.poisoned = true,
+            StatusCondition::Burned => self.burned = true,
         }
     }
 
+    pub(crate) fn add_effect(&mut self, card: Card, until_turn: u8) {
+        self.effects.push(PokemonEffect {
+            card,
+            until_turn,
+            attack_index: None,
+        });
+    }
+
+    /// Marks the Pokémon as having used its attack at `attack_index`, for the attacks that
+    /// restrict what it can do next (see `hooks::can_use_attack`).
+    pub(crate) fn add_attack_effect(&mut self, attack_index: usize, until_turn: u8) {
+        self.effects.push(PokemonEffect {
+            card: self.card.clone(),
+            until_turn,
+            attack_index: Some(attack_index),
+        });
+    }
+
+    /// How many of the Pokémon's effects come from one of `cards`.
+    pub(crate) fn count_effects(&self, cards: &[Card]) -> usize {
+        self.effects
+            .iter()
+            .filter(|x| cards.contains(&x.card))
+            .count()
+    }
+
     pub(crate) fn is_damaged(&self) -> bool {
-        self.remaining_hp < self.total_hp
+        self.damage_taken() > 0
     }
 
     pub(crate) fn has_tool_attached(&self) -> bool {
         self.attached_tool.is_some()
     }
+
+    /// Asleep and Paralyzed Pokémon can't attack or retreat (Confused ones can, see
+    /// `forecast_attack`).
+    pub(crate) fn can_attack_or_retreat(&self) -> bool {
+        !self.asleep && !self.paralyzed
+    }
+
+    pub(crate) fn has_special_condition(&self) -> bool {
+        self.poisoned || self.paralyzed || self.asleep || self.burned || self.confused
+    }
+
+    pub(crate) fn cure_special_conditions(&mut self) {
+        self.poisoned = false;
+        self.paralyzed = false;
+        self.asleep = false;
+        self.burned = false;
+        self.confused = false;
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -271,6 +440,8 @@ pub enum StatusCondition {
     Poisoned,
     Paralyzed,
     Asleep,
+    Burned,
+    Confused,
 }
 
 impl fmt::Display for Card {
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+    use crate::sets::SetId;
+    use crate::test_helpers::{to_pokemon_card, to_trainer_card};
     use crate::types::*;
 
     #[test]
@@ -58,7 +60,7 @@ mod tests {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut card2 = card1.clone();
@@ -82,7 +84,7 @@ mod tests {
             name: "Potion".to_string(),
             effect: "Heal 20 damage".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         };
         
         let mut card2 = card1.clone();
@@ -99,7 +101,7 @@ mod tests {
 
     #[test]
     fn test_card_get_id() {
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -111,17 +113,17 @@ mod tests {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         assert_eq!(pokemon.get_id(), "A1 001");
@@ -130,7 +132,7 @@ mod tests {
 
     #[test]
     fn test_card_get_name() {
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -142,17 +144,17 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         assert_eq!(pokemon.get_name(), "Bulbasaur");
@@ -161,7 +163,7 @@ mod tests {
 
     #[test]
     fn test_card_is_ex() {
-        let normal_pokemon = Card::Pokemon(PokemonCard {
+        let normal_pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -173,10 +175,10 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let ex_pokemon = Card::Pokemon(PokemonCard {
+        let ex_pokemon = to_pokemon_card(PokemonCard {
             id: "A1 004".to_string(),
             name: "Venusaur ex".to_string(),
             stage: 2,
@@ -188,17 +190,17 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         assert!(!normal_pokemon.is_ex());
@@ -208,7 +210,7 @@ mod tests {
 
     #[test]
     fn test_card_is_support() {
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -220,27 +222,27 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let item_trainer = Card::Trainer(TrainerCard {
+        let item_trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
-        let supporter_trainer = Card::Trainer(TrainerCard {
+        let supporter_trainer = to_trainer_card(TrainerCard {
             id: "A1 223".to_string(),
             numeric_id: 223,
             trainer_card_type: TrainerType::Supporter,
             name: "Giovanni".to_string(),
             effect: "Effect".to_string(),
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
         assert!(!pokemon.is_support());
@@ -250,7 +252,7 @@ mod tests {
 
     #[test]
     fn test_card_is_basic() {
-        let basic_pokemon = Card::Pokemon(PokemonCard {
+        let basic_pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -262,10 +264,10 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let stage1_pokemon = Card::Pokemon(PokemonCard {
+        let stage1_pokemon = to_pokemon_card(PokemonCard {
             id: "A1 002".to_string(),
             name: "Ivysaur".to_string(),
             stage: 1,
@@ -277,17 +279,17 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         assert!(basic_pokemon.is_basic());
@@ -297,7 +299,7 @@ mod tests {
 
     #[test]
     fn test_card_get_type() {
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -309,17 +311,17 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         assert_eq!(pokemon.get_type(), Some(EnergyType::Grass));
@@ -328,7 +330,7 @@ mod tests {
 
     #[test]
     fn test_card_get_ability() {
-        let pokemon_without_ability = Card::Pokemon(PokemonCard {
+        let pokemon_without_ability = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -340,10 +342,10 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let pokemon_with_ability = Card::Pokemon(PokemonCard {
+        let pokemon_with_ability = to_pokemon_card(PokemonCard {
             id: "A1 007".to_string(),
             name: "Butterfree".to_string(),
             stage: 2,
@@ -358,7 +360,7 @@ mod tests {
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![],
             rarity: "◊◊◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
         assert!(pokemon_without_ability.get_ability().is_none());
@@ -372,14 +374,14 @@ mod tests {
     #[test]
     #[should_panic(expected = "Unsupported playable card type")]
     fn test_card_get_attacks_trainer_panics() {
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         trainer.get_attacks();
@@ -394,7 +396,7 @@ mod tests {
             effect: None,
         };
         
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -406,7 +408,7 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
         let attacks = pokemon.get_attacks();
@@ -429,11 +431,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 30,
             total_hp: 70,
             attached_energy: vec![],
@@ -443,9 +445,15 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
+        assert_eq!(played_card.damage_taken(), 40);
+        assert_eq!(played_card.damage_counters(), 4);
+
         // Heal 20
         played_card.heal(20);
         assert_eq!(played_card.remaining_hp, 50);
@@ -453,6 +461,11 @@ mod tests {
         // Heal beyond max
         played_card.heal(30);
         assert_eq!(played_card.remaining_hp, 70);
+        assert_eq!(played_card.damage_taken(), 0);
+
+        // Partial damage counters (only in hand-built states) round up
+        played_card.remaining_hp = 65;
+        assert_eq!(played_card.damage_counters(), 1);
     }
 
     #[test]
@@ -469,11 +482,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 70,
             total_hp: 70,
             attached_energy: vec![],
@@ -483,7 +496,10 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
         // Apply 20 damage
@@ -509,11 +525,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 70,
             total_hp: 70,
             attached_energy: vec![],
@@ -523,7 +539,10 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
         // Attach 1 Grass energy
@@ -552,11 +571,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 70,
             total_hp: 70,
             attached_energy: vec![
@@ -570,7 +589,10 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
         // Discard one Grass energy
@@ -600,11 +622,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 70,
             total_hp: 70,
             attached_energy: vec![],
@@ -614,7 +636,10 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
         assert!(!played_card.is_damaged());
@@ -639,11 +664,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let mut played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 70,
             total_hp: 70,
             attached_energy: vec![],
@@ -653,7 +678,10 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
         assert!(!played_card.has_tool_attached());
@@ -666,7 +694,7 @@ mod tests {
 
     #[test]
     fn test_card_display() {
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -678,17 +706,17 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         assert_eq!(format!("{}", pokemon), "Bulbasaur");
@@ -709,7 +737,7 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         assert_eq!(format!("{:?}", pokemon_card), "A1 001 Bulbasaur");
@@ -725,7 +753,7 @@ mod tests {
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         };
         
         assert_eq!(format!("{:?}", trainer_card), "P-A 001 Potion");
@@ -746,11 +774,11 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         };
         
         let played_card = PlayedCard {
-            card: Card::Pokemon(pokemon_card),
+            card: to_pokemon_card(pokemon_card),
             remaining_hp: 50,
             total_hp: 70,
             attached_energy: vec![EnergyType::Grass, EnergyType::Fire],
@@ -760,7 +788,10 @@ mod tests {
             poisoned: false,
             paralyzed: false,
             asleep: false,
+            burned: false,
+            confused: false,
             cards_behind: vec![],
+            effects: vec![],
         };
         
         let debug_str = format!("{:?}", played_card);
@@ -780,6 +811,8 @@ mod tests {
         let _poisoned = StatusCondition::Poisoned;
         let _paralyzed = StatusCondition::Paralyzed;
         let _asleep = StatusCondition::Asleep;
+        let _burned = StatusCondition::Burned;
+        let _confused = StatusCondition::Confused;
     }
 
     #[test]
@@ -794,7 +827,7 @@ mod tests {
     fn test_card_hash() {
         use std::collections::HashSet;
         
-        let pokemon = Card::Pokemon(PokemonCard {
+        let pokemon = to_pokemon_card(PokemonCard {
             id: "A1 001".to_string(),
             name: "Bulbasaur".to_string(),
             stage: 0,
@@ -806,17 +839,17 @@ mod tests {
             weakness: None,
             retreat_cost: vec![],
             rarity: "◊".to_string(),
-            booster_pack: "Genetic Apex (A1)".to_string(),
+            set: SetId::A1,
         });
         
-        let trainer = Card::Trainer(TrainerCard {
+        let trainer = to_trainer_card(TrainerCard {
             id: "P-A 001".to_string(),
             numeric_id: 1,
             trainer_card_type: TrainerType::Item,
             name: "Potion".to_string(),
             effect: "Heal".to_string(),
             rarity: "◊".to_string(),
-            booster_pack: "Promo-A".to_string(),
+            set: SetId::PromoA,
         });
         
         let mut set = HashSet::new();
@@ -0,0 +1,19 @@
+use deckgym::test_helpers::play_checked_game;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn test_random_action_sequences_keep_invariants() {
+    // Same inputs as the fuzz target, just not guided by coverage
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let data: Vec<u8> = (0..600).map(|_| rng.gen()).collect();
+        play_checked_game(&data);
+    }
+}
+
+#[test]
+fn test_short_inputs_are_valid() {
+    for len in 0..12 {
+        play_checked_game(&vec![1; len]);
+    }
+}
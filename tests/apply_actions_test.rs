@@ -4,6 +4,7 @@ use deckgym::{
     card_ids::CardId,
     database::get_card_by_enum,
     generate_possible_actions,
+    test_helpers::ScenarioBuilder,
     types::{Card, EnergyType},
     Game,
 };
@@ -119,14 +120,20 @@ fn test_draw_action() {
 
 #[test]
 fn test_play_pokeball_action() {
-    let mut game = get_initialized_game(0);
-    let state = game.get_state_clone();
+    let state = ScenarioBuilder::new()
+        .active(0, CardId::A1001Bulbasaur, [])
+        .active(1, CardId::A1001Bulbasaur, [])
+        .hand(0, [CardId::A1002Ivysaur, CardId::A1001Bulbasaur, CardId::PA005PokeBall])
+        .turn(3)
+        .current_player(0)
+        .build();
+    let mut game = Game::from_state(state.clone(), init_random_players(), 0);
     let current_player = state.current_player;
     let hand = state.hands[current_player].clone();
     let pokeball = &hand[2];
     if let Card::Trainer(trainer_card) = pokeball {
         let action = SimpleAction::Play {
-            trainer_card: trainer_card.clone(),
+            trainer_card,
         };
 
         let deck_size = state.decks[state.current_player].cards.len();
@@ -150,8 +157,14 @@ fn test_play_pokeball_action() {
 
 #[test]
 fn test_place_action() {
-    let mut game = get_initialized_game(3);
-    let state = game.get_state_clone();
+    let state = ScenarioBuilder::new()
+        .active(0, CardId::A1001Bulbasaur, [])
+        .active(1, CardId::A1001Bulbasaur, [])
+        .hand(0, [CardId::A1001Bulbasaur, CardId::A1002Ivysaur])
+        .turn(3)
+        .current_player(0)
+        .build();
+    let mut game = Game::from_state(state.clone(), init_random_players(), 0);
     let current_player = state.current_player;
     let hand = state.hands[current_player].clone();
     let bulbasaur = &hand[0];
@@ -3,7 +3,10 @@ use deckgym::{
     actions::{Action, SimpleAction},
     card_ids::CardId,
     database::get_card_by_enum,
-    players::{AttachAttackPlayer, EndTurnPlayer, MctsPlayer, Player, RandomPlayer},
+    players::{
+        AttachAttackPlayer, CheatingPlayerAdapter, EndTurnPlayer, MctsPlayer, Player,
+        RandomPlayer,
+    },
     state::GameOutcome,
     test_helpers::load_test_decks,
     types::{Card, EnergyType, PlayedCard},
@@ -256,7 +259,7 @@ fn test_deck_out_condition() {
     // Artificially empty a deck
     {
         let state = game.get_state_mut();
-        state.decks[0].cards.clear();
+        state.deck_mut(0).cards.clear();
     }
     
     // Force a draw
@@ -397,7 +400,7 @@ fn test_mcts_vs_random_performance() {
     
     for seed in 0..10 {
         let (deck_a, deck_b) = load_test_decks();
-        let player_a = Box::new(MctsPlayer::new(deck_a, 10));
+        let player_a = Box::new(CheatingPlayerAdapter(MctsPlayer::new(deck_a, 10)));
         let player_b = Box::new(RandomPlayer { deck: deck_b });
         let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
         
@@ -443,8 +446,8 @@ fn test_edge_case_one_card_deck() {
     // Reduce deck sizes drastically
     {
         let state = game.get_state_mut();
-        state.decks[0].cards.truncate(5);
-        state.decks[1].cards.truncate(5);
+        state.deck_mut(0).cards.truncate(5);
+        state.deck_mut(1).cards.truncate(5);
     }
     
     // Game should still complete
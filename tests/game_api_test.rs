@@ -1,8 +1,21 @@
 use common::init_random_players;
 use deckgym::{
-    players::{AttachAttackPlayer, EndTurnPlayer, MctsPlayer, Player, RandomPlayer},
+    actions::Action,
+    card_ids::CardId,
+    observation::Observation,
+    players::{
+        AttachAttackPlayer, CheatingPlayerAdapter, EndTurnPlayer, ExpectiMiniMaxPlayer,
+        FairPlayer, MctsPlayer, ParallelMctsPlayer, Player, RandomPlayer,
+    },
     state::GameOutcome,
-    test_helpers::load_test_decks,
+    test_helpers::{energy, load_test_decks, ScenarioBuilder},
+    types::EnergyType::Grass,
+    Deck, State,
+};
+use rand::{rngs::StdRng, seq::SliceRandom};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
 };
 
 mod common;
@@ -18,7 +31,7 @@ fn test_game_api() {
 fn test_mcts_player() {
     let (deck_a, deck_b) = load_test_decks();
     let player_a = Box::new(RandomPlayer { deck: deck_a });
-    let player_b = Box::new(MctsPlayer::new(deck_b, 5));
+    let player_b = Box::new(CheatingPlayerAdapter(MctsPlayer::new(deck_b, 5)));
     let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
     let mut game = deckgym::Game::new(players, 6);
 
@@ -40,6 +53,147 @@ fn test_mcts_player() {
         final_turn);
 }
 
+#[test]
+fn test_mcts_player_rollout_policy() {
+    let (deck_a, deck_b) = load_test_decks();
+    let rollouts = Arc::new(AtomicUsize::new(0));
+    let counter = rollouts.clone();
+    let player_a = Box::new(CheatingPlayerAdapter(
+        MctsPlayer::new(deck_a, 5).with_rollout_policy(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Box::new(AttachAttackPlayer {
+                deck: Deck::default(),
+            })
+        }),
+    ));
+    let player_b = Box::new(RandomPlayer { deck: deck_b });
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, 6);
+    game.play();
+
+    // Every MCTS iteration plays out a game with one rollout player per seat
+    let rollouts = rollouts.load(Ordering::Relaxed);
+    assert!(rollouts > 0);
+    assert_eq!(rollouts % 10, 0);
+}
+
+#[test]
+fn test_mcts_player_value_and_policy_functions() {
+    let (deck_a, deck_b) = load_test_decks();
+    let rollouts = Arc::new(AtomicUsize::new(0));
+    let priors = Arc::new(AtomicUsize::new(0));
+    let (rollout_counter, prior_counter) = (rollouts.clone(), priors.clone());
+    let player_a = Box::new(CheatingPlayerAdapter(
+        MctsPlayer::new(deck_a, 5)
+            .with_rollout_policy(move || {
+                rollout_counter.fetch_add(1, Ordering::Relaxed);
+                Box::new(RandomPlayer {
+                    deck: Deck::default(),
+                })
+            })
+            .with_value_function(|state: &State, player: usize| {
+                (state.points[player] as f64 - state.points[(player + 1) % 2] as f64) / 3.0
+            })
+            .with_policy_function(move |_: &State, actions: &[Action]| {
+                prior_counter.fetch_add(1, Ordering::Relaxed);
+                vec![1.0 / actions.len() as f64; actions.len()]
+            }),
+    ));
+    let player_b = Box::new(RandomPlayer { deck: deck_b });
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, 6);
+    game.play();
+
+    // Leaves are scored by the value function instead of played out
+    assert_eq!(rollouts.load(Ordering::Relaxed), 0);
+    assert!(priors.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+fn test_parallel_mcts_player() {
+    let (deck_a, deck_b) = load_test_decks();
+    let player_a = Box::new(RandomPlayer { deck: deck_a });
+    let player_b = Box::new(CheatingPlayerAdapter(
+        ParallelMctsPlayer::new(deck_b, 5).with_num_trees(2),
+    ));
+    assert_eq!(
+        format!("{player_b:?}"),
+        "ParallelMctsPlayer with 2 trees of 5 iterations"
+    );
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, 6);
+    let outcome = game.play();
+    assert!(outcome.is_some() || game.get_state().turn_count >= 100);
+}
+
+#[test]
+fn test_expectiminimax_player() {
+    static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+    fn points_only(state: &State, myself: usize) -> f64 {
+        EVALUATIONS.fetch_add(1, Ordering::Relaxed);
+        state.points[myself] as f64 - state.points[(myself + 1) % 2] as f64
+    }
+
+    let (deck_a, deck_b) = load_test_decks();
+    let player_a = Box::new(CheatingPlayerAdapter(
+        ExpectiMiniMaxPlayer::new(deck_a, 2).with_value_function(points_only),
+    ));
+    let player_b = Box::new(EndTurnPlayer { deck: deck_b });
+    assert_eq!(format!("{player_a:?}"), "ExpectiMiniMaxPlayer with depth 2");
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    // A Bulbasaur one Energy away from attacking, against a lone Koffing
+    let state = ScenarioBuilder::new()
+        .active(0, CardId::A1001Bulbasaur, [energy([Grass])])
+        .active(1, CardId::A1176Koffing, [])
+        .turn(3)
+        .current_player(0)
+        .build();
+    let mut game = deckgym::Game::from_state(state, players, 0);
+    assert_eq!(game.play(), Some(GameOutcome::Win(0)));
+    assert!(EVALUATIONS.load(Ordering::Relaxed) > 0);
+}
+
+/// Plays randomly, checking that what it can see is consistent.
+#[derive(Debug)]
+struct ObservingPlayer {
+    deck: Deck,
+}
+
+impl FairPlayer for ObservingPlayer {
+    fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+
+    fn decide(
+        &mut self,
+        rng: &mut StdRng,
+        observation: &Observation,
+        possible_actions: Vec<Action>,
+    ) -> Action {
+        let me = observation.player();
+        let opponent = (me + 1) % 2;
+        assert_eq!(observation.hand().len(), observation.hand_sizes()[me]);
+        let knowledge = observation.knowledge();
+        let unseen: usize = knowledge.known_deck_contents[me].values().sum();
+        assert_eq!(
+            unseen,
+            observation.hand_sizes()[opponent] + observation.deck_sizes()[opponent]
+        );
+        possible_actions.choose(rng).unwrap().clone()
+    }
+}
+
+#[test]
+fn test_fair_player() {
+    let (deck_a, deck_b) = load_test_decks();
+    let player_a = Box::new(ObservingPlayer { deck: deck_a });
+    let player_b = Box::new(RandomPlayer { deck: deck_b });
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, 0);
+    game.play();
+    assert!(game.get_num_plys() > 0);
+}
+
 #[test]
 fn test_retreat_should_cure_poison() {
     let players = init_random_players();
@@ -65,3 +219,29 @@ fn test_first_ko() {
     assert_eq!(game.get_state_clone().turn_count, 7);
     assert_eq!(winner, Some(GameOutcome::Win(0)));
 }
+
+#[test]
+fn test_coin_flip_log() {
+    // Blastoise ex deck with Misty instead of X Speed
+    let deck = Deck::from_string(
+        "Energy: Water\n2 A1 053\n2 A1 054\n2 A1 056\n1 A1 223\n2 A1 225\n2 A1 246\n1 A1 270\n2 P-A 001\n2 A1 220\n2 P-A 005\n2 P-A 007",
+    )
+    .unwrap();
+    let mut flipped = false;
+    for seed in 0..10 {
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer { deck: deck.clone() }),
+            Box::new(RandomPlayer { deck: deck.clone() }),
+        ];
+        let mut game = deckgym::Game::new(players, seed);
+        game.play();
+        let log = game.get_coin_flip_log();
+        assert!(log.windows(2).all(|x| x[0].0 < x[1].0));
+        for (ply, _, flips) in log {
+            assert!(*ply < game.get_num_plys());
+            assert!(flips.iter().all(|x| x.heads < x.flips));
+        }
+        flipped |= !log.is_empty();
+    }
+    assert!(flipped, "Some game should have played Misty");
+}
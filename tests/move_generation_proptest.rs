@@ -0,0 +1,65 @@
+use deckgym::{
+    generate_possible_actions,
+    players::{create_players, PlayerCode},
+    test_helpers::{check_invariants, load_test_deck, play_checked_game, FUZZ_DECKS},
+    Game, State,
+};
+use proptest::prelude::*;
+
+/// States reachable by playing random legal actions from the start of a game, with the
+/// same encoding as the fuzz target (see `play_checked_game`).
+fn reachable_state() -> impl Strategy<Value = State> {
+    proptest::collection::vec(any::<u8>(), 10..300).prop_map(|data| play_checked_game(&data))
+}
+
+fn player_code() -> impl Strategy<Value = PlayerCode> {
+    prop_oneof![
+        Just(PlayerCode::R),
+        Just(PlayerCode::W),
+        Just(PlayerCode::AA),
+        Just(PlayerCode::ET),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn every_generated_action_applies(state in reachable_state(), seed in any::<u64>()) {
+        prop_assume!(!state.is_game_over());
+        let (actor, actions) = generate_possible_actions(&state);
+        prop_assert!(!actions.is_empty(), "No legal action in {:?}", state);
+        for action in &actions {
+            prop_assert_eq!(action.actor, actor);
+            let mut game = Game::from_state(state.clone(), vec![], seed);
+            game.apply_action(action);
+            check_invariants(game.get_state());
+        }
+    }
+
+    #[test]
+    fn every_applied_action_was_generated(
+        seed in any::<u64>(),
+        decks in (0..FUZZ_DECKS.len(), 0..FUZZ_DECKS.len()),
+        players in (player_code(), player_code()),
+    ) {
+        let deck_a = load_test_deck(FUZZ_DECKS[decks.0]);
+        let deck_b = load_test_deck(FUZZ_DECKS[decks.1]);
+        let players = create_players(deck_a, deck_b, vec![players.0, players.1]);
+        let mut game = Game::new(players, seed);
+        for _ in 0..500 {
+            if game.get_state().is_game_over() {
+                break;
+            }
+            let (_, actions) = generate_possible_actions(game.get_state());
+            let action = game.play_tick();
+            prop_assert!(
+                actions.contains(&action),
+                "{:?} was applied but not generated: {:?}",
+                action,
+                actions
+            );
+            check_invariants(game.get_state());
+        }
+    }
+}
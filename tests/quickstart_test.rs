@@ -0,0 +1,17 @@
+use deckgym::{quickstart, state::GameOutcome};
+
+#[test]
+fn test_demo_game() {
+    let outcome = quickstart::demo_game_with_seed(42).play();
+    assert!(matches!(outcome, Some(GameOutcome::Win(_))));
+
+    // Same seed, same game
+    assert_eq!(quickstart::demo_game_with_seed(42).play(), outcome);
+    quickstart::demo_game().play();
+}
+
+#[test]
+fn test_simulate_demo() {
+    let results = quickstart::simulate_demo(5);
+    assert_eq!(results.iter().sum::<u32>(), 5);
+}
@@ -0,0 +1,69 @@
+use common::init_random_players;
+use deckgym::{
+    actions::SimpleAction,
+    card_ids::CardId,
+    generate_possible_actions,
+    state::GameOutcome,
+    test_helpers::{energy, hp, PokemonSetup, ScenarioBuilder},
+    types::EnergyType::{Colorless, Grass},
+    Game,
+};
+
+mod common;
+
+#[test]
+fn test_scenario_attack_knocks_out_last_pokemon() {
+    let state = ScenarioBuilder::new()
+        .active(0, CardId::A1001Bulbasaur, [energy([Grass, Colorless])])
+        .active(1, CardId::A1177Weezing, [hp(40)])
+        .turn(5)
+        .build();
+    let (actor, actions) = generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    let attack = actions
+        .into_iter()
+        .find(|x| x.action == SimpleAction::Attack(0))
+        .expect("Bulbasaur should be able to attack");
+
+    let mut game = Game::from_state(state, init_random_players(), 0);
+    game.apply_action(&attack);
+    assert_eq!(game.get_state().winner, Some(GameOutcome::Win(0)));
+}
+
+#[test]
+fn test_scenario_setup() {
+    let state = ScenarioBuilder::new()
+        .active(
+            0,
+            CardId::A1001Bulbasaur,
+            [hp(30), PokemonSetup::Poisoned, PokemonSetup::PlayedThisTurn],
+        )
+        .bench(0, CardId::A1021Exeggcute, [])
+        .bench(0, CardId::A1021Exeggcute, [energy([Grass])])
+        .hand(1, [CardId::A1177Weezing])
+        .deck_top(0, [CardId::A1002Ivysaur])
+        .points(1, 2)
+        .current_player(1)
+        .build();
+    let active = state.in_play_pokemon[0][0].as_ref().unwrap();
+    assert_eq!((active.remaining_hp, active.total_hp), (30, 70));
+    assert!(active.poisoned && active.played_this_turn);
+    assert!(state.in_play_pokemon[0][1].is_some());
+    assert_eq!(
+        state.in_play_pokemon[0][2]
+            .as_ref()
+            .unwrap()
+            .attached_energy,
+        vec![Grass]
+    );
+    assert_eq!(state.hands[1].len(), 1);
+    assert_eq!(state.decks[0].cards[0].get_name(), "Ivysaur");
+    assert_eq!((state.points, state.current_player), ([0, 2], 1));
+
+    // Snapshots can be built upon
+    let state = ScenarioBuilder::from_state(state)
+        .active(1, CardId::A1177Weezing, [])
+        .build();
+    assert!(state.in_play_pokemon[1][0].is_some());
+    assert!(state.in_play_pokemon[0][2].is_some());
+}
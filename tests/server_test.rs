@@ -0,0 +1,56 @@
+#![cfg(feature = "server")]
+
+use std::fs;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+const ADDR: &str = "127.0.0.1:39017";
+
+type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn connect() -> Client {
+    for _ in 0..1000 {
+        if let Ok((websocket, _)) = connect_async(format!("ws://{ADDR}")).await {
+            return websocket;
+        }
+        // Let the server start listening
+        tokio::task::yield_now().await;
+    }
+    panic!("Server should be up");
+}
+
+async fn send(client: &mut Client, message: Value) {
+    client
+        .send(Message::text(message.to_string()))
+        .await
+        .unwrap();
+}
+
+async fn receive(client: &mut Client) -> Value {
+    let message = client.next().await.expect("Server should answer").unwrap();
+    serde_json::from_str(message.to_text().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_server_answers_over_websocket() {
+    tokio::spawn(deckgym::server::serve(ADDR));
+    let mut client = connect().await;
+
+    let deck = fs::read_to_string("example_decks/venusaur-exeggutor.txt").unwrap();
+    let create = json!({"type": "create_match", "deck_a": deck, "deck_b": deck, "seed": 1});
+    send(&mut client, create).await;
+    let created = receive(&mut client).await;
+    assert_eq!(created["type"], "match_created");
+
+    let join = json!({"type": "join", "match_id": created["match_id"], "player": 0});
+    send(&mut client, join).await;
+    let state = receive(&mut client).await;
+    assert_eq!(state["type"], "state");
+    assert_eq!(state["state"]["perspective"], 0);
+
+    send(&mut client, json!("not a message")).await;
+    assert_eq!(receive(&mut client).await["type"], "error");
+}